@@ -0,0 +1,215 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        calls.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Typed calls of the C-Suite pallets for the client SDK
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! Typed calls of the agent registry, reputation, consensus log and recall pallets.
+//!
+//! Each function builds the [`RuntimeCall`] of one extrinsic, so arguments are checked by
+//! the compiler instead of the node. Signatures passed in are made with
+//! [`AgentKey::sign_payload`](crate::AgentKey::sign_payload) over the payloads the
+//! [`Client`](crate::Client) helpers compute.
+
+use codec::Encode;
+use pallet_recall::{CompressionCodec, ContentLocator, RecordType, RetentionClass};
+
+use crate::{
+    AccountId, AgentRole, AggregateSignature, Balance, BlockNumber, ConsensusModel, Hash, LogPriority,
+    RoundId, Runtime, RuntimeCall, SessionId, SignatureScheme, VoteKind,
+};
+
+/// A runtime call, submitted as its SCALE encoding
+#[derive(Clone, Debug)]
+pub struct Call(pub RuntimeCall);
+
+impl From<RuntimeCall> for Call {
+    fn from(call: RuntimeCall) -> Self {
+        Self(call)
+    }
+}
+
+impl subxt::tx::Payload for Call {
+    fn encode_call_data_to(&self, _metadata: &subxt_core::Metadata, out: &mut Vec<u8>) -> Result<(), subxt_core::Error> {
+        self.0.encode_to(out);
+        Ok(())
+    }
+}
+
+// Helper function to wrap an agent registry call
+fn agents(call: pallet_agent_registry::Call<Runtime>) -> Call {
+    RuntimeCall::AgentRegistry(call).into()
+}
+
+// Helper function to wrap a reputation call
+fn reputation(call: pallet_reputation::Call<Runtime>) -> Call {
+    RuntimeCall::Reputation(call).into()
+}
+
+// Helper function to wrap a consensus log call
+fn consensus(call: pallet_consensus_log::Call<Runtime>) -> Call {
+    RuntimeCall::ConsensusLog(call).into()
+}
+
+// Helper function to wrap a recall call
+fn recall(call: pallet_recall::Call<Runtime>) -> Call {
+    RuntimeCall::Recall(call).into()
+}
+
+/// Register the caller as an agent with `role`
+pub fn register_agent(role: AgentRole, metadata: Option<Vec<u8>>) -> Call {
+    agents(pallet_agent_registry::Call::register_agent { role, metadata })
+}
+
+/// Choose the scheme the caller's signatures are verified under
+pub fn set_signature_scheme(scheme: SignatureScheme) -> Call {
+    agents(pallet_agent_registry::Call::set_signature_scheme { scheme })
+}
+
+/// Report the caller as alive
+pub fn heartbeat() -> Call {
+    agents(pallet_agent_registry::Call::heartbeat {})
+}
+
+/// Stake `amount` for the caller
+pub fn stake(amount: Balance) -> Call {
+    reputation(pallet_reputation::Call::stake { amount })
+}
+
+/// Withdraw `amount` of the caller's stake
+pub fn unstake(amount: Balance) -> Call {
+    reputation(pallet_reputation::Call::unstake { amount })
+}
+
+/// An insight to submit for consensus
+#[derive(Clone, Debug, Default)]
+pub struct Insight {
+    /// Agents taking part in the consensus, the submitter included
+    pub agents_involved: Vec<AccountId>,
+    /// Content identifier of the insight
+    pub cid: Vec<u8>,
+    /// Optional metadata stored with the log
+    pub metadata: Option<Vec<u8>>,
+    /// Consensus model the log is finalized under
+    pub model: ConsensusModel,
+    /// Priority of the log
+    pub priority: LogPriority,
+    /// Involved agents that must sign, whatever the model
+    pub required_signers: Vec<AccountId>,
+    /// Sequence number to submit under, the agent's next one if `None`
+    pub sequence: Option<u64>,
+}
+
+/// Submit an insight, with the submitter's `signature` over its insight payload
+pub fn submit_insight(insight: Insight, signature: Vec<u8>) -> Call {
+    consensus(pallet_consensus_log::Call::submit_insight {
+        agents_involved: insight.agents_involved,
+        cid: insight.cid,
+        signature,
+        metadata: insight.metadata,
+        model: insight.model,
+        priority: insight.priority,
+        required_signers: insight.required_signers,
+        sequence: insight.sequence,
+    })
+}
+
+/// Cast `vote` on a log, with the agent's `signature` over the vote payload
+pub fn sign_log(log_id: Hash, signature: Vec<u8>, vote: VoteKind) -> Call {
+    consensus(pallet_consensus_log::Call::sign_log { log_id, signature, vote })
+}
+
+/// Approve several logs at once, each with the agent's signature over its approval payload
+pub fn sign_logs_batch(signatures: Vec<(Hash, Vec<u8>)>) -> Call {
+    consensus(pallet_consensus_log::Call::sign_logs_batch { signatures })
+}
+
+/// Open a FROST signing session over a threshold log
+pub fn open_signing_session(log_id: Hash) -> Call {
+    consensus(pallet_consensus_log::Call::open_signing_session { log_id })
+}
+
+/// Commit to the nonce of the caller's share in a signing session
+pub fn submit_commitment(session_id: SessionId, nonce_commitment: [u8; 32]) -> Call {
+    consensus(pallet_consensus_log::Call::submit_commitment { session_id, nonce_commitment })
+}
+
+/// Submit the caller's share in a signing session, with the binding it was made over
+pub fn submit_signature_share(session_id: SessionId, signature_share: [u8; 64], binding: [u8; 32]) -> Call {
+    consensus(pallet_consensus_log::Call::submit_signature_share { session_id, signature_share, binding })
+}
+
+/// Finalize a log, with the FROST group's aggregate signature for threshold logs
+pub fn finalize_consensus_log(log_id: Hash, aggregate: Option<AggregateSignature>) -> Call {
+    consensus(pallet_consensus_log::Call::finalize_consensus_log { log_id, aggregate })
+}
+
+/// Open a blinded insight round among `participants`, the caller included
+pub fn open_insight_round(participants: Vec<AccountId>, model: ConsensusModel, priority: LogPriority) -> Call {
+    consensus(pallet_consensus_log::Call::open_insight_round { participants, model, priority })
+}
+
+/// Commit to the caller's insight in a blinded insight round
+pub fn commit_insight(round_id: RoundId, commitment: Hash) -> Call {
+    consensus(pallet_consensus_log::Call::commit_insight { round_id, commitment })
+}
+
+/// Reveal the insight the caller committed to with `salt` in a blinded insight round
+pub fn reveal_insight(round_id: RoundId, cid: Vec<u8>, salt: [u8; 32], metadata: Option<Vec<u8>>) -> Call {
+    consensus(pallet_consensus_log::Call::reveal_insight { round_id, cid, salt, metadata })
+}
+
+/// A record to store in recall
+#[derive(Clone, Debug)]
+pub struct Record {
+    /// Type of the record
+    pub record_type: RecordType,
+    /// Hash of the full content
+    pub content_hash: Vec<u8>,
+    /// Where the full content is stored
+    pub locator: ContentLocator<Runtime>,
+    /// Brief summary of the content
+    pub summary: Vec<u8>,
+    /// Optional metadata
+    pub metadata: Option<Vec<u8>>,
+    /// How long the record is kept, which sets the deposit reserved
+    pub retention: RetentionClass,
+    /// Codec the summary and metadata are compressed with
+    pub compression: CompressionCodec,
+}
+
+/// Store a record in recall, signed by the caller
+pub fn store_consensus_record(record: Record, signature: Vec<u8>) -> Call {
+    recall(pallet_recall::Call::store_consensus_record {
+        record_type: record.record_type,
+        content_hash: record.content_hash,
+        locator: record.locator,
+        summary: record.summary,
+        signature,
+        metadata: record.metadata,
+        retention: record.retention,
+        compression: record.compression,
+    })
+}
+
+/// Co-sign a recall record
+pub fn add_signature(record_id: u64, signature: Vec<u8>) -> Call {
+    recall(pallet_recall::Call::add_signature { record_id, signature })
+}
+
+/// Ask `agent` to co-sign a record the caller owns before `deadline`
+pub fn request_signature(record_id: u64, agent: AccountId, deadline: BlockNumber) -> Call {
+    recall(pallet_recall::Call::request_signature { record_id, agent, deadline })
+}
+
+/// Challenge a recall record, locking the challenge bond until governance resolves the dispute
+pub fn challenge_record(record_id: u64) -> Call {
+    recall(pallet_recall::Call::challenge_record { record_id })
+}
@@ -0,0 +1,237 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        client.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Chain client of the agent SDK
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! Client submitting agent extrinsics and reading agent state from a node.
+
+use codec::{Decode, Encode};
+use pallet_agent_registry::AgentInfo;
+use pallet_consensus_log::{ConsensusLog, SigningSession};
+use pallet_recall::ConsensusRecord;
+use pallet_reputation::ReputationInfo;
+use sp_core::hashing::{blake2_128, twox_128, twox_64};
+use subxt::{OnlineClient, PolkadotConfig};
+
+use crate::{
+    calls::{self, Call, Insight},
+    frost::{self, SigningContext},
+    AccountId, AgentKey, AgentRole, Balance, Error, Hash, Result, Runtime, SessionId, SigningGroup, VoteKind,
+};
+
+type ConsensusLogPallet = pallet_consensus_log::Pallet<Runtime>;
+
+/// Duties an agent has outstanding
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PendingDuties {
+    /// Consensus logs the agent is involved in and has not signed yet
+    pub logs: Vec<Hash>,
+    /// Recall records the agent was asked to co-sign
+    pub records: Vec<u64>,
+}
+
+/// A connection to a C-Suite node
+#[derive(Clone)]
+pub struct Client(OnlineClient<PolkadotConfig>);
+
+impl Client {
+    /// Connect to the node at `url`, e.g. `ws://127.0.0.1:9944`
+    pub async fn connect(url: &str) -> Result<Self> {
+        Ok(Self(OnlineClient::from_url(url).await?))
+    }
+
+    /// The underlying subxt client, for anything the SDK does not wrap
+    pub fn inner(&self) -> &OnlineClient<PolkadotConfig> {
+        &self.0
+    }
+
+    /// Sign `call` with `key`, submit it and wait for it to be finalized, returning the
+    /// extrinsic hash
+    ///
+    /// Fails if the extrinsic is finalized but its dispatch failed.
+    pub async fn submit(&self, call: &Call, key: &AgentKey) -> Result<Hash> {
+        let events = self.0.tx()
+            .sign_and_submit_then_watch_default(call, key)
+            .await?
+            .wait_for_finalized_success()
+            .await?;
+        Ok(Hash::from(events.extrinsic_hash().0))
+    }
+
+    /// Register the key's account as an agent with `role`
+    ///
+    /// Agents register under sr25519, so a key of another scheme is switched to it in a second
+    /// extrinsic.
+    pub async fn register(&self, key: &AgentKey, role: AgentRole, metadata: Option<Vec<u8>>) -> Result<Hash> {
+        let hash = self.submit(&calls::register_agent(role, metadata), key).await?;
+        if key.scheme() != Default::default() {
+            return self.submit(&calls::set_signature_scheme(key.scheme()), key).await;
+        }
+        Ok(hash)
+    }
+
+    /// Stake `amount` for the key's account
+    pub async fn stake(&self, key: &AgentKey, amount: Balance) -> Result<Hash> {
+        self.submit(&calls::stake(amount), key).await
+    }
+
+    /// Submit `insight`, signing its payload under its sequence number or the agent's next nonce
+    pub async fn submit_insight(&self, key: &AgentKey, insight: Insight) -> Result<Hash> {
+        let nonce = match insight.sequence {
+            Some(sequence) => sequence,
+            None => self.next_sequence(&key.account()).await?,
+        };
+        let signature = key.sign_payload(&ConsensusLogPallet::insight_payload(&insight.cid, nonce));
+        self.submit(&calls::submit_insight(insight, signature), key).await
+    }
+
+    /// Approve a consensus log in its current amendment round
+    pub async fn sign_log(&self, key: &AgentKey, log_id: Hash) -> Result<Hash> {
+        self.vote(key, log_id, VoteKind::Approve).await
+    }
+
+    /// Cast `vote` on a consensus log in its current amendment round
+    pub async fn vote(&self, key: &AgentKey, log_id: Hash, vote: VoteKind) -> Result<Hash> {
+        let log = self.log(log_id).await?.ok_or(Error::LogNotFound)?;
+        let signature = key.sign_payload(&ConsensusLogPallet::vote_payload(&log_id, log.amendment_count, vote));
+        self.submit(&calls::sign_log(log_id, signature, vote), key).await
+    }
+
+    /// Co-sign a recall record with a signature over its content hash
+    ///
+    /// Recall keeps signatures of up to 64 bytes, so ecdsa keys cannot co-sign records.
+    pub async fn sign_record(&self, key: &AgentKey, record_id: u64) -> Result<Hash> {
+        let record = self.record(record_id).await?.ok_or(Error::RecordNotFound)?;
+        let signature = key.sign_payload(&record.content_hash);
+        self.submit(&calls::add_signature(record_id, signature), key).await
+    }
+
+    /// Open a FROST signing session over a threshold log
+    pub async fn open_signing_session(&self, key: &AgentKey, log_id: Hash) -> Result<Hash> {
+        self.submit(&calls::open_signing_session(log_id), key).await
+    }
+
+    /// Submit the key's nonce commitment to a signing session
+    pub async fn commit(&self, key: &AgentKey, session_id: SessionId) -> Result<Hash> {
+        let (_, context) = self.session_context(session_id).await?;
+        self.submit(&calls::submit_commitment(session_id, frost::nonce_commitment(key, &context)), key).await
+    }
+
+    /// Submit the key's signature share to a signing session
+    pub async fn submit_share(&self, key: &AgentKey, session_id: SessionId) -> Result<Hash> {
+        let (_, context) = self.session_context(session_id).await?;
+        let share = frost::signature_share(key, &context);
+        self.submit(&calls::submit_signature_share(session_id, share, context.binding()), key).await
+    }
+
+    /// Aggregate the shares of a signing session with the group `signature` its signers produced
+    /// over the log, and finalize the log with the result
+    pub async fn finalize_session(&self, key: &AgentKey, session_id: SessionId, signature: [u8; 64]) -> Result<Hash> {
+        let (session, _) = self.session_context(session_id).await?;
+        let group = self.signing_group().await?;
+        let commitments: Vec<[u8; 32]> = session.commitments.iter().map(|(_, commitment)| *commitment).collect();
+        let shares: Vec<([u8; 32], [u8; 64])> = session.shares.iter()
+            .map(|(agent, share)| (csuite_primitives::frost_key(&agent.encode()), *share))
+            .collect();
+        let aggregate = frost::aggregate(&group, session.log_id.as_ref(), signature, &commitments, &shares)?;
+        self.submit(&calls::finalize_consensus_log(session.log_id, Some(aggregate)), key).await
+    }
+
+    /// Logs and records waiting for `agent`'s signature
+    pub async fn pending_duties(&self, agent: &AccountId) -> Result<PendingDuties> {
+        let logs = self.call("ConsensusLogApi_pending_duties", agent.encode()).await?;
+        let records = self.fetch("Recall", "PendingSignatureRequests", &blake2_128_concat(agent))
+            .await?
+            .unwrap_or_default();
+        Ok(PendingDuties { logs, records })
+    }
+
+    /// Next sequence number `agent`'s submissions are expected under
+    pub async fn next_sequence(&self, agent: &AccountId) -> Result<u64> {
+        Ok(self.fetch("ConsensusLog", "AgentNonces", &blake2_128_concat(agent)).await?.unwrap_or_default())
+    }
+
+    /// Sequence numbers `agent` skipped, as ranges up to but excluding the number submitted
+    /// after them, oldest first
+    pub async fn sequence_gaps(&self, agent: &AccountId) -> Result<Vec<(u64, u64)>> {
+        self.call("ConsensusLogApi_sequence_gaps", agent.encode()).await
+    }
+
+    /// Registration of `agent`, if it is registered
+    pub async fn agent(&self, agent: &AccountId) -> Result<Option<AgentInfo<Runtime>>> {
+        self.fetch("AgentRegistry", "Agents", &blake2_128_concat(agent)).await
+    }
+
+    /// Reputation and stake of `agent`
+    pub async fn reputation(&self, agent: &AccountId) -> Result<ReputationInfo<Runtime>> {
+        Ok(self.fetch("Reputation", "Reputation", &blake2_128_concat(agent)).await?.unwrap_or_default())
+    }
+
+    /// A consensus log, if it exists
+    pub async fn log(&self, log_id: Hash) -> Result<Option<ConsensusLog<Runtime>>> {
+        self.fetch("ConsensusLog", "Logs", &blake2_128_concat(&log_id)).await
+    }
+
+    /// A recall record, if it exists
+    pub async fn record(&self, record_id: u64) -> Result<Option<ConsensusRecord<Runtime>>> {
+        self.fetch("Recall", "Records", &twox_64_concat(&record_id)).await
+    }
+
+    /// The current FROST signing group, whose members' shares [`frost::aggregate`] combines
+    pub async fn signing_group(&self) -> Result<SigningGroup> {
+        let participants: Vec<AccountId> = self.fetch("ConsensusLog", "FrostParticipants", &[])
+            .await?
+            .unwrap_or_default();
+        let threshold = self.call("FrostApi_current_threshold", Vec::new()).await?;
+        let group_key = self.fetch("ConsensusLog", "FrostGroupKey", &[])
+            .await?
+            .ok_or(Error::FrostGroupNotConfigured)?;
+        Ok(SigningGroup {
+            group_key,
+            participants: participants.iter().map(|agent| csuite_primitives::frost_key(&agent.encode())).collect(),
+            threshold,
+        })
+    }
+
+    // Helper function to read a signing session and the context its shares are made in
+    async fn session_context(&self, session_id: SessionId) -> Result<(SigningSession<Runtime>, SigningContext)> {
+        let session: SigningSession<Runtime> = self.fetch("ConsensusLog", "SigningSessions", &twox_64_concat(&session_id))
+            .await?
+            .ok_or(Error::SessionNotFound)?;
+        let log = self.log(session.log_id).await?.ok_or(Error::LogNotFound)?;
+        let context = frost::signing_context(session_id, &session.log_id, log.amendment_count);
+        Ok((session, context))
+    }
+
+    // Helper function to read and decode a storage value at the latest block
+    async fn fetch<V: Decode>(&self, pallet: &str, item: &str, hashed_key: &[u8]) -> Result<Option<V>> {
+        let key = [&twox_128(pallet.as_bytes())[..], &twox_128(item.as_bytes())[..], hashed_key].concat();
+        let value = self.0.storage().at_latest().await?.fetch_raw(key).await?;
+        Ok(value.map(|bytes| V::decode(&mut &bytes[..])).transpose()?)
+    }
+
+    // Helper function to call a runtime API at the latest block
+    async fn call<R: Decode>(&self, function: &str, params: Vec<u8>) -> Result<R> {
+        Ok(self.0.runtime_api().at_latest().await?.call_raw(function, Some(&params)).await?)
+    }
+}
+
+// Helper function to hash a storage map key with `Blake2_128Concat`
+fn blake2_128_concat<K: Encode>(key: &K) -> Vec<u8> {
+    let encoded = key.encode();
+    [&blake2_128(&encoded)[..], &encoded].concat()
+}
+
+// Helper function to hash a storage map key with `Twox64Concat`
+fn twox_64_concat<K: Encode>(key: &K) -> Vec<u8> {
+    let encoded = key.encode();
+    [&twox_64(&encoded)[..], &encoded].concat()
+}
@@ -0,0 +1,175 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        frost.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: FROST share computation of the agent SDK
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! FROST share computation for signing sessions over threshold logs.
+//!
+//! A FROST participant first submits a [`nonce_commitment`] to the session, then a
+//! [`signature_share`] bound to the session's [`SigningContext`], which records on chain that it
+//! took part. Once the shares are in, anyone can [`aggregate`] them with the group signature the
+//! participants produced against the [`SigningGroup`].
+//!
+//! The group signs with FROST(Ed25519, SHA-512), so the group signature is a plain Ed25519
+//! signature over the log ID by the group key, and the chain verifies it as one.
+
+use codec::Encode;
+use sp_core::{hashing::blake2_256, Pair};
+use sp_runtime::traits::{BlakeTwo256, Hash as _};
+
+pub use pallet_consensus_log::aggregate::SigningContext;
+
+use crate::{AgentKey, AggregateSignature, Error, Hash, Result, SessionId, SigningGroup};
+
+/// Context the shares of `session_id` over `log_id` are made in, for the log's amendment `round`
+pub fn signing_context(session_id: SessionId, log_id: &Hash, round: u32) -> SigningContext {
+    SigningContext::new(session_id, log_id.as_ref(), round)
+}
+
+/// Nonce commitment of `key` in `context`
+///
+/// The nonce is derived from the key's secret and the context, so an agent asked again for the
+/// same session commits to the same nonce without keeping state.
+pub fn nonce_commitment(key: &AgentKey, context: &SigningContext) -> [u8; 32] {
+    let secret = match key {
+        AgentKey::Sr25519(pair) => pair.to_raw_vec(),
+        AgentKey::Ed25519(pair) => pair.to_raw_vec(),
+        AgentKey::Ecdsa(pair) => pair.to_raw_vec(),
+    };
+    let nonce = blake2_256(&(b"frost-nonce", secret, context).encode());
+    blake2_256(&(b"frost-commitment", nonce).encode())
+}
+
+/// Signature share of `key` in `context`, made over the context's binding
+///
+/// The chain verifies the share as the agent's signature over the binding. The recovery byte of
+/// ecdsa signatures is dropped to fit the share, so ecdsa shares are rejected.
+pub fn signature_share(key: &AgentKey, context: &SigningContext) -> [u8; 64] {
+    let signature = key.sign_payload(&context.binding());
+    let mut share = [0u8; 64];
+    share.copy_from_slice(&signature[..64]);
+    share
+}
+
+/// Aggregate the signature shares of group members over `message`
+///
+/// `signature` is the group signature the signers produced over `message`, `shares` pairs each
+/// signer's FROST key with its share, and `commitments` holds the session's nonce commitments in
+/// submission order. Repeated shares of a signer are ignored. Fails unless the result verifies
+/// against `group`.
+pub fn aggregate(
+    group: &SigningGroup,
+    message: &[u8],
+    signature: [u8; 64],
+    commitments: &[[u8; 32]],
+    shares: &[([u8; 32], [u8; 64])],
+) -> Result<AggregateSignature> {
+    let mut participant_bitmap = [0u8; 2];
+    let mut signers = Vec::new();
+
+    for (agent_id, _) in shares {
+        let position = group.participants.iter()
+            .position(|key| key == agent_id)
+            .filter(|position| *position < 16)
+            .ok_or(Error::Aggregate(csuite_primitives::AggregateCheckError::InvalidBitmap))?;
+        if signers.contains(agent_id) {
+            continue;
+        }
+        signers.push(*agent_id);
+
+        participant_bitmap[position / 8] |= 1 << (position % 8);
+    }
+
+    let challenge_input = [message, &commitments.concat()].concat();
+    let aggregate = AggregateSignature {
+        signature,
+        aggregate_pubkey: csuite_primitives::combine_keys(signers.iter()),
+        participant_bitmap,
+        challenge: BlakeTwo256::hash(&challenge_input).into(),
+    };
+
+    csuite_primitives::check_group_aggregate(
+        &aggregate,
+        message,
+        &group.group_key,
+        &group.participants,
+        group.threshold,
+    ).map_err(Error::Aggregate)?;
+    Ok(aggregate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SignatureScheme;
+
+    fn keys() -> Vec<AgentKey> {
+        ["//Lyra", "//Echo", "//Verdict"].iter()
+            .map(|uri| AgentKey::from_uri(SignatureScheme::Sr25519, uri).unwrap())
+            .collect()
+    }
+
+    fn group_pair() -> sp_core::ed25519::Pair {
+        sp_core::ed25519::Pair::from_seed(&[42u8; 32])
+    }
+
+    fn group(keys: &[AgentKey], threshold: u32) -> SigningGroup {
+        SigningGroup {
+            group_key: group_pair().public().0,
+            participants: keys.iter().map(AgentKey::frost_key).collect(),
+            threshold,
+        }
+    }
+
+    #[test]
+    fn shares_of_a_threshold_of_members_aggregate_with_the_group_signature() {
+        let keys = keys();
+        let group = group(&keys, 2);
+        let log_id = Hash::repeat_byte(7);
+        let context = signing_context(0, &log_id, 0);
+
+        // Commitments are stable and bound to the context
+        assert_eq!(nonce_commitment(&keys[0], &context), nonce_commitment(&keys[0], &context));
+        assert_ne!(nonce_commitment(&keys[0], &context), nonce_commitment(&keys[0], &signing_context(1, &log_id, 0)));
+
+        let commitments: Vec<_> = keys[1..].iter().map(|key| nonce_commitment(key, &context)).collect();
+        let shares: Vec<_> = keys[1..].iter().map(|key| (key.frost_key(), signature_share(key, &context))).collect();
+
+        let signature = group_pair().sign(log_id.as_ref()).0;
+        let aggregate = aggregate(&group, log_id.as_ref(), signature, &commitments, &shares).unwrap();
+        assert_eq!(aggregate.participant_bitmap, [0b110, 0]);
+
+        // A signature over another log does not verify
+        let other = group_pair().sign(&[8; 32]).0;
+        assert!(matches!(
+            aggregate(&group, log_id.as_ref(), other, &commitments, &shares),
+            Err(Error::Aggregate(csuite_primitives::AggregateCheckError::InvalidSignature))
+        ));
+    }
+
+    #[test]
+    fn shares_below_threshold_or_from_outsiders_are_rejected() {
+        let keys = keys();
+        let group = group(&keys[..2], 2);
+        let context = signing_context(0, &Hash::repeat_byte(7), 0);
+        let share = |key: &AgentKey| (key.frost_key(), signature_share(key, &context));
+        let signature = group_pair().sign(&[7; 32]).0;
+
+        assert!(matches!(
+            aggregate(&group, &[7; 32], signature, &[], &[share(&keys[0]), share(&keys[0])]),
+            Err(Error::Aggregate(csuite_primitives::AggregateCheckError::BelowThreshold))
+        ));
+        assert!(matches!(
+            aggregate(&group, &[7; 32], signature, &[], &[share(&keys[0]), share(&keys[2])]),
+            Err(Error::Aggregate(csuite_primitives::AggregateCheckError::InvalidBitmap))
+        ));
+    }
+}
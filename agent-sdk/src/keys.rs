@@ -0,0 +1,126 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        keys.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Agent keys of the client SDK
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! Agent keys, used both to sign transactions and to sign the payloads the pallets verify.
+
+use codec::Encode;
+use sp_core::{ecdsa, ed25519, sr25519, Pair};
+use sp_runtime::{traits::IdentifyAccount, MultiSigner};
+use subxt::{
+    tx::Signer,
+    utils::{AccountId32, MultiAddress, MultiSignature},
+    PolkadotConfig,
+};
+
+use crate::{AccountId, Error, Result, SignatureScheme};
+
+/// An agent's key pair under one of the supported signature schemes
+#[derive(Clone)]
+pub enum AgentKey {
+    /// An sr25519 key, the default scheme of agents
+    Sr25519(sr25519::Pair),
+    /// An ed25519 key
+    Ed25519(ed25519::Pair),
+    /// A secp256k1 ECDSA key
+    Ecdsa(ecdsa::Pair),
+}
+
+impl AgentKey {
+    /// Key derived from a secret URI, such as a mnemonic or `//Alice`, under `scheme`
+    pub fn from_uri(scheme: SignatureScheme, uri: &str) -> Result<Self> {
+        let key = match scheme {
+            SignatureScheme::Sr25519 => sr25519::Pair::from_string(uri, None).map(Self::Sr25519),
+            SignatureScheme::Ed25519 => ed25519::Pair::from_string(uri, None).map(Self::Ed25519),
+            SignatureScheme::Ecdsa => ecdsa::Pair::from_string(uri, None).map(Self::Ecdsa),
+        };
+        key.map_err(|_| Error::InvalidKey)
+    }
+
+    /// Scheme the key signs with, which the agent must have registered
+    pub fn scheme(&self) -> SignatureScheme {
+        match self {
+            Self::Sr25519(_) => SignatureScheme::Sr25519,
+            Self::Ed25519(_) => SignatureScheme::Ed25519,
+            Self::Ecdsa(_) => SignatureScheme::Ecdsa,
+        }
+    }
+
+    /// Account of the key: the public key for sr25519 and ed25519, and the blake2 hash of the
+    /// compressed public key for ecdsa
+    pub fn account(&self) -> AccountId {
+        let signer = match self {
+            Self::Sr25519(pair) => MultiSigner::from(pair.public()),
+            Self::Ed25519(pair) => MultiSigner::from(pair.public()),
+            Self::Ecdsa(pair) => MultiSigner::from(pair.public()),
+        };
+        signer.into_account()
+    }
+
+    /// FROST key of the agent, which identifies it in signing groups
+    pub fn frost_key(&self) -> [u8; 32] {
+        csuite_primitives::frost_key(&self.account().encode())
+    }
+
+    /// Signature over `message` in the encoding the pallets verify for the key's scheme
+    pub fn sign_payload(&self, message: &[u8]) -> Vec<u8> {
+        match self {
+            Self::Sr25519(pair) => pair.sign(message).0.to_vec(),
+            Self::Ed25519(pair) => pair.sign(message).0.to_vec(),
+            Self::Ecdsa(pair) => pair.sign(message).0.to_vec(),
+        }
+    }
+}
+
+impl Signer<PolkadotConfig> for AgentKey {
+    fn account_id(&self) -> AccountId32 {
+        AccountId32(self.account().into())
+    }
+
+    fn address(&self) -> MultiAddress<AccountId32, ()> {
+        MultiAddress::Id(<Self as Signer<PolkadotConfig>>::account_id(self))
+    }
+
+    fn sign(&self, signer_payload: &[u8]) -> MultiSignature {
+        match self {
+            Self::Sr25519(pair) => MultiSignature::Sr25519(pair.sign(signer_payload).0),
+            Self::Ed25519(pair) => MultiSignature::Ed25519(pair.sign(signer_payload).0),
+            Self::Ecdsa(pair) => MultiSignature::Ecdsa(pair.sign(signer_payload).0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use csuite_primitives::{MultiSchemeVerifier, VerifyAgentSignature};
+
+    #[test]
+    fn payload_signatures_verify_under_the_key_scheme() {
+        for scheme in SignatureScheme::ALL {
+            let key = AgentKey::from_uri(scheme, "//Lyra").unwrap();
+            assert_eq!(key.scheme(), scheme);
+
+            let signature = key.sign_payload(b"csuite/insight");
+            assert!(MultiSchemeVerifier::verify(scheme, &key.account(), b"csuite/insight", &signature));
+            assert!(!MultiSchemeVerifier::verify(scheme, &key.account(), b"csuite/vote", &signature));
+        }
+    }
+
+    #[test]
+    fn invalid_uris_are_rejected() {
+        assert!(matches!(
+            AgentKey::from_uri(SignatureScheme::Sr25519, "not a mnemonic"),
+            Err(Error::InvalidKey)
+        ));
+    }
+}
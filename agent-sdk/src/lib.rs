@@ -0,0 +1,110 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        lib.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Client SDK for C-Suite agents
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! # C-Suite Agent SDK
+//!
+//! Typed client bindings for agent operators, so they don't hand-roll extrinsic encoding or
+//! signing payloads. Built on `subxt`, against the runtime this crate is compiled with.
+//!
+//! - [`calls`] builds the calls of the agent registry, reputation, consensus log and recall
+//!   pallets as [`Call`]s, which [`Client::submit`] signs and submits
+//! - [`Client`] wraps the common flows: registering, staking, submitting insights, signing
+//!   logs and records, and reading an agent's pending duties and state
+//! - [`AgentKey`] holds an agent's sr25519, ed25519 or ecdsa key, signs transactions and
+//!   produces the payload signatures the pallets verify under the agent's [`SignatureScheme`]
+//! - [`frost`] computes FROST nonce commitments and signature shares and aggregates them into
+//!   the [`AggregateSignature`] `finalize_consensus_log` takes, which [`Client`] runs against
+//!   the chain's signing sessions
+//!
+//! Calls are encoded with the runtime types rather than the node's metadata, so a client must
+//! be built against the runtime version the chain runs.
+//!
+//! ```ignore
+//! let key = AgentKey::from_uri(SignatureScheme::Sr25519, "//Lyra")?;
+//! let client = Client::connect("ws://127.0.0.1:9944").await?;
+//!
+//! client.register(&key, AgentRole::Lyra, None).await?;
+//! client.stake(&key, 10 * UNIT).await?;
+//! for log_id in client.pending_duties(&key.account()).await?.logs {
+//!     client.sign_log(&key, log_id).await?;
+//! }
+//! ```
+
+pub mod calls;
+mod client;
+pub mod frost;
+mod keys;
+
+pub use calls::Call;
+pub use client::{Client, PendingDuties};
+pub use keys::AgentKey;
+
+pub use csuite_primitives::{AggregateSignature, SignatureScheme, SigningGroup};
+pub use pallet_agent_registry::AgentRole;
+pub use pallet_consensus_log::{ConsensusModel, LogPriority, RoundId, SessionId, VoteKind};
+pub use parachain_template_runtime::{AccountId, Balance, BlockNumber, Hash, Runtime, RuntimeCall};
+
+use core::fmt;
+
+/// Reasons an SDK operation fails
+#[derive(Debug)]
+pub enum Error {
+    /// The node rejected a request, or the transaction failed
+    Subxt(subxt::Error),
+    /// A value read from the chain did not decode
+    Codec(codec::Error),
+    /// A secret URI or seed does not describe a key
+    InvalidKey,
+    /// The consensus log does not exist
+    LogNotFound,
+    /// The FROST signing session does not exist
+    SessionNotFound,
+    /// The recall record does not exist
+    RecordNotFound,
+    /// The chain has no FROST group key configured
+    FrostGroupNotConfigured,
+    /// Signature shares and the group signature do not aggregate to a valid signature of the group
+    Aggregate(csuite_primitives::AggregateCheckError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Subxt(e) => write!(f, "Client error: {e}"),
+            Self::Codec(e) => write!(f, "Failed to decode chain state: {e}"),
+            Self::InvalidKey => write!(f, "Invalid secret key URI"),
+            Self::LogNotFound => write!(f, "Consensus log not found"),
+            Self::SessionNotFound => write!(f, "Signing session not found"),
+            Self::RecordNotFound => write!(f, "Recall record not found"),
+            Self::FrostGroupNotConfigured => write!(f, "No FROST group key configured"),
+            Self::Aggregate(e) => write!(f, "Invalid aggregate signature: {e:?}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<subxt::Error> for Error {
+    fn from(e: subxt::Error) -> Self {
+        Self::Subxt(e)
+    }
+}
+
+impl From<codec::Error> for Error {
+    fn from(e: codec::Error) -> Self {
+        Self::Codec(e)
+    }
+}
+
+/// Result of the SDK's fallible operations
+pub type Result<T> = std::result::Result<T, Error>;
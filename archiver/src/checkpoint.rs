@@ -0,0 +1,51 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        checkpoint.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Checkpoints of the last archived block
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! Local checkpoint of the last archived block.
+
+use std::{fs, io, path::Path};
+
+use serde::{Deserialize, Serialize};
+use sp_core::H256;
+
+use crate::Result;
+
+/// The last block whose events were written.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Checkpoint {
+    /// Number of the block.
+    pub last_block: u32,
+    /// Hash of the block.
+    pub last_hash: H256,
+}
+
+/// Read the checkpoint at `path`, if one was written.
+pub fn load(path: &Path) -> Result<Option<Checkpoint>> {
+    match fs::read(path) {
+        Ok(data) => Ok(Some(serde_json::from_slice(&data)?)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Replace the checkpoint at `path`.
+///
+/// The checkpoint is written next to `path` first and then moved over it, so a crash never
+/// leaves a partial checkpoint behind.
+pub fn save(path: &Path, checkpoint: &Checkpoint) -> Result<()> {
+    let mut tmp = path.to_path_buf().into_os_string();
+    tmp.push(".tmp");
+    fs::write(&tmp, serde_json::to_vec_pretty(checkpoint)?)?;
+    fs::rename(&tmp, path)?;
+    Ok(())
+}
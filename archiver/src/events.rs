@@ -0,0 +1,79 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        events.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Decoding of archived events
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! Decoding the archived events of a block.
+
+use codec::Decode;
+
+use csuite_primitives::Phase;
+use parachain_template_runtime::{Hash, RuntimeEvent};
+
+type EventRecord = csuite_primitives::EventRecord<RuntimeEvent, Hash>;
+
+/// An archived event.
+#[derive(Debug, Clone)]
+pub struct Row {
+    /// Number of the block the event was emitted in.
+    pub block_number: u32,
+    /// Hash of the block, hex encoded.
+    pub block_hash: String,
+    /// Index of the event among all events of the block.
+    pub event_index: u32,
+    /// Extrinsic that emitted the event, if it was not emitted by a block hook.
+    pub extrinsic_index: Option<u32>,
+    /// Pallet that emitted the event.
+    pub pallet: &'static str,
+    /// Name of the event.
+    pub event: String,
+    /// The event with its fields, as formatted by `Debug`.
+    pub data: String,
+}
+
+/// Decode the encoded `System::Events` of a block into rows of its recall and consensus events.
+pub fn decode_block(number: u32, hash: Hash, events: &[u8]) -> Result<Vec<Row>, codec::Error> {
+    if events.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let records = Vec::<EventRecord>::decode(&mut &events[..])?;
+    let rows = records
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, (phase, event, _))| {
+            let (pallet, data) = match event {
+                RuntimeEvent::ConsensusLog(event) => ("ConsensusLog", format!("{event:?}")),
+                RuntimeEvent::Recall(event) => ("Recall", format!("{event:?}")),
+                _ => return None,
+            };
+            Some(Row {
+                block_number: number,
+                block_hash: format!("{hash:?}"),
+                event_index: index as u32,
+                extrinsic_index: match phase {
+                    Phase::ApplyExtrinsic(index) => Some(index),
+                    _ => None,
+                },
+                pallet,
+                event: variant_name(&data).to_owned(),
+                data,
+            })
+        })
+        .collect();
+
+    Ok(rows)
+}
+
+/// Name of the variant the `Debug` output of an event starts with.
+fn variant_name(data: &str) -> &str {
+    data.split(|c: char| !c.is_alphanumeric() && c != '_').next().unwrap_or(data)
+}
@@ -0,0 +1,119 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        main.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Archiver for recall and consensus events
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! # C-Suite Archiver
+//!
+//! Follows the finalized blocks of a node over WebSocket, decodes the events the recall and
+//! consensus log pallets emitted and writes them to SQLite or Parquet for analytics.
+//!
+//! - Blocks are archived in order, in batches of at most `--batch-size` blocks
+//! - After every batch the last archived block is written to a local checkpoint file, so a
+//!   restarted archiver continues after it instead of starting over
+//! - SQLite rows are keyed by block and event index, so batches replayed after a crash replace
+//!   their rows; Parquet batches are written to one file per first block, which a replay
+//!   overwrites
+//!
+//! Events are decoded with the runtime this binary is built against, so blocks produced by an
+//! incompatible runtime version fail to decode and stop the archiver.
+
+mod checkpoint;
+mod events;
+mod rpc;
+mod sink;
+
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use checkpoint::Checkpoint;
+
+/// Result of the archiver's fallible operations.
+pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
+
+/// Archive recall and consensus events of finalized blocks.
+#[derive(Debug, Parser)]
+#[command(name = "archiver", version)]
+struct Cli {
+    /// WebSocket endpoint of the node to follow.
+    #[arg(long, default_value = "ws://127.0.0.1:9944")]
+    url: String,
+
+    /// Format to write the events in.
+    #[arg(long, value_enum, default_value_t = sink::Format::Sqlite)]
+    format: sink::Format,
+
+    /// SQLite database file, or directory the Parquet files are written to.
+    #[arg(long)]
+    output: PathBuf,
+
+    /// Checkpoint file. Defaults to `<output>.checkpoint.json`.
+    #[arg(long)]
+    checkpoint: Option<PathBuf>,
+
+    /// First block to archive when there is no checkpoint yet.
+    #[arg(long, default_value_t = 0)]
+    from: u32,
+
+    /// Maximum number of blocks written at once.
+    #[arg(long, default_value_t = 100)]
+    batch_size: u32,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+    let cli = Cli::parse();
+
+    let checkpoint_path = cli.checkpoint.clone().unwrap_or_else(|| {
+        let mut path = cli.output.clone().into_os_string();
+        path.push(".checkpoint.json");
+        path.into()
+    });
+    let mut next = match checkpoint::load(&checkpoint_path)? {
+        Some(checkpoint) => {
+            log::info!("Resuming after block #{} ({:?})", checkpoint.last_block, checkpoint.last_hash);
+            checkpoint.last_block.saturating_add(1)
+        },
+        None => cli.from,
+    };
+
+    let client = rpc::Client::connect(&cli.url).await?;
+    let mut sink = sink::open(cli.format, &cli.output)?;
+    let batch_size = cli.batch_size.max(1);
+
+    let mut heads = client.finalized_heads().await?;
+    while let Some(header) = heads.next().await {
+        let head = rpc::header_number(&header?)?;
+
+        // Catch up to the new finalized head, the first time from the checkpoint
+        while next <= head {
+            let last = head.min(next.saturating_add(batch_size - 1));
+            let mut rows = Vec::new();
+            let mut last_hash = Default::default();
+            for number in next..=last {
+                let hash = client.block_hash(number).await?;
+                let events = client.events(hash).await?;
+                rows.extend(events::decode_block(number, hash, &events)?);
+                last_hash = hash;
+            }
+
+            sink.write(next, last, &rows)?;
+            checkpoint::save(&checkpoint_path, &Checkpoint { last_block: last, last_hash })?;
+            log::info!("Archived blocks #{}..=#{} ({} events)", next, last, rows.len());
+
+            next = last.saturating_add(1);
+        }
+    }
+
+    Err("the node closed the finalized head subscription".into())
+}
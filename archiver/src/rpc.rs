@@ -0,0 +1,72 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        rpc.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Node RPC client of the archiver
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! The node RPC calls the archiver makes.
+
+use jsonrpsee::{
+    core::client::{ClientT, Subscription, SubscriptionClientT},
+    rpc_params,
+    ws_client::{WsClient, WsClientBuilder},
+};
+use serde::Deserialize;
+use sp_core::{twox_128, Bytes, H256};
+
+use crate::Result;
+
+/// The parts of a block header the archiver reads.
+#[derive(Debug, Deserialize)]
+pub struct Header {
+    /// Block number, hex encoded.
+    pub number: String,
+}
+
+/// Parse the number of a header.
+pub fn header_number(header: &Header) -> Result<u32> {
+    let digits = header.number.trim_start_matches("0x");
+    Ok(u32::from_str_radix(digits, 16)?)
+}
+
+/// WebSocket client of a node.
+pub struct Client(WsClient);
+
+impl Client {
+    /// Connect to the node at `url`.
+    pub async fn connect(url: &str) -> Result<Self> {
+        Ok(Self(WsClientBuilder::default().build(url).await?))
+    }
+
+    /// Subscribe to the headers of newly finalized blocks, starting with the current one.
+    pub async fn finalized_heads(&self) -> Result<Subscription<Header>> {
+        Ok(self
+            .0
+            .subscribe(
+                "chain_subscribeFinalizedHeads",
+                rpc_params![],
+                "chain_unsubscribeFinalizedHeads",
+            )
+            .await?)
+    }
+
+    /// Hash of the block with `number` on the finalized chain.
+    pub async fn block_hash(&self, number: u32) -> Result<H256> {
+        let hash: Option<H256> = self.0.request("chain_getBlockHash", rpc_params![number]).await?;
+        hash.ok_or_else(|| format!("block #{number} not found").into())
+    }
+
+    /// Encoded `System::Events` of the block `at`.
+    pub async fn events(&self, at: H256) -> Result<Vec<u8>> {
+        let key = Bytes([twox_128(b"System"), twox_128(b"Events")].concat());
+        let events: Option<Bytes> = self.0.request("state_getStorage", rpc_params![key, at]).await?;
+        Ok(events.map(|events| events.0).unwrap_or_default())
+    }
+}
@@ -0,0 +1,152 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        sink.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: SQLite and Parquet outputs of the archiver
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! The formats events are written in.
+
+use std::{fs, path::{Path, PathBuf}, sync::Arc};
+
+use arrow_array::{ArrayRef, RecordBatch, StringArray, UInt32Array};
+use arrow_schema::{DataType, Field, Schema};
+use parquet::arrow::ArrowWriter;
+use rusqlite::{params, Connection};
+
+use crate::{events::Row, Result};
+
+/// Output format of the archived events.
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum Format {
+    /// A single SQLite database with an `events` table.
+    Sqlite,
+    /// One Parquet file per batch of blocks.
+    Parquet,
+}
+
+/// Destination of archived events.
+pub trait Sink {
+    /// Write the events of the blocks `first..=last`.
+    ///
+    /// Writing the same blocks again replaces their events.
+    fn write(&mut self, first: u32, last: u32, rows: &[Row]) -> Result<()>;
+}
+
+/// Open the sink for `format` at `output`.
+pub fn open(format: Format, output: &Path) -> Result<Box<dyn Sink>> {
+    Ok(match format {
+        Format::Sqlite => Box::new(SqliteSink::open(output)?),
+        Format::Parquet => Box::new(ParquetSink::open(output)?),
+    })
+}
+
+/// Writes events to a SQLite database.
+struct SqliteSink(Connection);
+
+impl SqliteSink {
+    fn open(path: &Path) -> Result<Self> {
+        let connection = Connection::open(path)?;
+        connection.execute_batch(
+            "CREATE TABLE IF NOT EXISTS events (
+                block_number INTEGER NOT NULL,
+                block_hash TEXT NOT NULL,
+                event_index INTEGER NOT NULL,
+                extrinsic_index INTEGER,
+                pallet TEXT NOT NULL,
+                event TEXT NOT NULL,
+                data TEXT NOT NULL,
+                PRIMARY KEY (block_number, event_index)
+            );
+            CREATE INDEX IF NOT EXISTS events_by_name ON events (pallet, event);",
+        )?;
+        Ok(Self(connection))
+    }
+}
+
+impl Sink for SqliteSink {
+    fn write(&mut self, first: u32, last: u32, rows: &[Row]) -> Result<()> {
+        let transaction = self.0.transaction()?;
+        transaction.execute(
+            "DELETE FROM events WHERE block_number BETWEEN ?1 AND ?2",
+            params![first, last],
+        )?;
+        {
+            let mut insert = transaction.prepare(
+                "INSERT INTO events
+                    (block_number, block_hash, event_index, extrinsic_index, pallet, event, data)
+                    VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            )?;
+            for row in rows {
+                insert.execute(params![
+                    row.block_number,
+                    row.block_hash,
+                    row.event_index,
+                    row.extrinsic_index,
+                    row.pallet,
+                    row.event,
+                    row.data,
+                ])?;
+            }
+        }
+        transaction.commit()?;
+        Ok(())
+    }
+}
+
+/// Writes every batch of events to its own Parquet file in a directory.
+struct ParquetSink {
+    dir: PathBuf,
+    schema: Arc<Schema>,
+}
+
+impl ParquetSink {
+    fn open(dir: &Path) -> Result<Self> {
+        fs::create_dir_all(dir)?;
+        let schema = Schema::new(vec![
+            Field::new("block_number", DataType::UInt32, false),
+            Field::new("block_hash", DataType::Utf8, false),
+            Field::new("event_index", DataType::UInt32, false),
+            Field::new("extrinsic_index", DataType::UInt32, true),
+            Field::new("pallet", DataType::Utf8, false),
+            Field::new("event", DataType::Utf8, false),
+            Field::new("data", DataType::Utf8, false),
+        ]);
+        Ok(Self { dir: dir.to_path_buf(), schema: Arc::new(schema) })
+    }
+}
+
+impl Sink for ParquetSink {
+    fn write(&mut self, first: u32, _last: u32, rows: &[Row]) -> Result<()> {
+        // Named by the first block only, so a batch replayed after a crash overwrites its file
+        let path = self.dir.join(format!("events-{first:010}.parquet"));
+        if rows.is_empty() {
+            return Ok(());
+        }
+
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(UInt32Array::from_iter_values(rows.iter().map(|row| row.block_number))),
+            Arc::new(StringArray::from_iter_values(rows.iter().map(|row| &row.block_hash))),
+            Arc::new(UInt32Array::from_iter_values(rows.iter().map(|row| row.event_index))),
+            Arc::new(UInt32Array::from(rows.iter().map(|row| row.extrinsic_index).collect::<Vec<_>>())),
+            Arc::new(StringArray::from_iter_values(rows.iter().map(|row| row.pallet))),
+            Arc::new(StringArray::from_iter_values(rows.iter().map(|row| &row.event))),
+            Arc::new(StringArray::from_iter_values(rows.iter().map(|row| &row.data))),
+        ];
+        let batch = RecordBatch::try_new(self.schema.clone(), columns)?;
+
+        // Written next to its final path first, so readers never see a partial file
+        let tmp = path.with_extension("parquet.tmp");
+        let mut writer = ArrowWriter::try_new(fs::File::create(&tmp)?, self.schema.clone(), None)?;
+        writer.write(&batch)?;
+        writer.close()?;
+        fs::rename(&tmp, &path)?;
+        Ok(())
+    }
+}
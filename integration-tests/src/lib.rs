@@ -0,0 +1,86 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        lib.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Test externalities and helpers for runtime integration tests
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! # C-Suite Integration Tests
+//!
+//! End-to-end scenarios that run the C-Suite pallets inside the real parachain runtime instead
+//! of per-pallet mocks, so the runtime's hook adapters (activity feed, first responder bonus,
+//! recall system records, trust refresh) are exercised together with the pallets they connect.
+//!
+//! This crate only holds the shared setup; the scenarios live in `tests/`.
+//!
+//! `tests/runtime_upgrade.rs` is built only with the `try-runtime` feature: it loads the state of
+//! a live or staging chain, applies this runtime's migrations and runs the try-state checks of
+//! the agent registry, consensus log, reputation and recall pallets.
+
+use frame_support::assert_ok;
+use pallet_agent_registry::AgentRole;
+use parachain_template_runtime::{
+    AccountId, AgentRegistry, Balance, Reputation, Runtime, RuntimeEvent, RuntimeOrigin, System, UNIT,
+};
+use sp_core::{sr25519, Pair};
+use sp_runtime::BuildStorage;
+
+/// Free balance every test account starts with
+pub const INITIAL_BALANCE: Balance = 1_000 * UNIT;
+
+/// Stake bonded by [`staked_agent`]
+pub const AGENT_STAKE: Balance = 100 * UNIT;
+
+/// Number of funded test accounts
+pub const ACCOUNTS: u8 = 5;
+
+/// The sr25519 key of the `n`th test account
+pub fn pair(n: u8) -> sr25519::Pair {
+    sr25519::Pair::from_seed(&[n; 32])
+}
+
+/// The `n`th test account
+pub fn account(n: u8) -> AccountId {
+    pair(n).public().into()
+}
+
+/// Sign `payload` with the key of test account `who`
+pub fn sign(who: &AccountId, payload: &[u8]) -> Vec<u8> {
+    let n = (1..=ACCOUNTS).find(|n| account(*n) == *who).expect("Not a test account");
+    pair(n).sign(payload).0.to_vec()
+}
+
+/// Build test externalities at block 1 with every test account funded
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut storage = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+    pallet_balances::GenesisConfig::<Runtime> {
+        balances: (1..=ACCOUNTS).map(|n| (account(n), INITIAL_BALANCE)).collect(),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(storage);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+/// Register the `n`th test account as an agent with `role` and bond [`AGENT_STAKE`]
+pub fn staked_agent(n: u8, role: AgentRole) -> AccountId {
+    let who = account(n);
+    assert_ok!(AgentRegistry::register_agent(RuntimeOrigin::signed(who.clone()), role, None));
+    assert_ok!(Reputation::stake(RuntimeOrigin::signed(who.clone()), AGENT_STAKE));
+    who
+}
+
+/// Whether `event` was deposited in the current block
+pub fn has_event(event: impl Into<RuntimeEvent>) -> bool {
+    let event = event.into();
+    System::events().iter().any(|record| record.event == event)
+}
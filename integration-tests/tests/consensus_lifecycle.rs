@@ -0,0 +1,190 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        consensus_lifecycle.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: End-to-end consensus, reward, slashing and pruning scenarios
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+use csuite_integration_tests::*;
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+use pallet_agent_registry::{AgentRole, AgentStatus};
+use pallet_consensus_log::{ConsensusModel, LogPriority, VoteKind};
+use pallet_recall::{CompressionCodec, ContentLocator, RecordType, RetentionClass};
+use pallet_reputation::OffenseType;
+use parachain_template_runtime::{
+    AccountId, AgentRegistry, Balances, ConsensusLog, Hash, Recall, Reputation, Runtime, RuntimeOrigin,
+    System, DAYS, MILLI_UNIT,
+};
+
+/// Deposit the consensus log pallet reserves for every log
+const LOG_DEPOSIT: u128 = 10 * MILLI_UNIT;
+
+// Helper function to submit an insight from `submitter` involving `others`, returning its ID
+fn submit_insight(submitter: &AccountId, others: &[AccountId], cid: &[u8]) -> Hash {
+    let payload = ConsensusLog::insight_payload(cid, ConsensusLog::agent_nonce(submitter));
+    assert_ok!(ConsensusLog::submit_insight(
+        RuntimeOrigin::signed(submitter.clone()),
+        others.to_vec(),
+        cid.to_vec(),
+        sign(submitter, &payload),
+        None,
+        ConsensusModel::Unanimous,
+        LogPriority::Normal,
+        vec![],
+        None,
+    ));
+    *ConsensusLog::logs_by_agent(submitter).last().unwrap()
+}
+
+// Helper function to countersign a log by every agent in `signers`
+fn sign_by(log_id: Hash, signers: &[AccountId]) {
+    let round = ConsensusLog::logs(log_id).unwrap().amendment_count;
+    let payload = ConsensusLog::vote_payload(&log_id, round, VoteKind::Approve);
+    for signer in signers {
+        assert_ok!(ConsensusLog::sign_log(
+            RuntimeOrigin::signed(signer.clone()),
+            log_id,
+            sign(signer, &payload),
+            VoteKind::Approve,
+        ));
+    }
+}
+
+#[test]
+fn insight_flows_from_submission_to_slashing_and_pruning() {
+    new_test_ext().execute_with(|| {
+        // Register agents and stake
+        let lyra = staked_agent(1, AgentRole::Lyra);
+        let beacon = staked_agent(2, AgentRole::Beacon);
+        let verdict = staked_agent(3, AgentRole::Verdict);
+        assert_eq!(Reputation::total_stake(), 3 * AGENT_STAKE);
+
+        // Submit an insight, reserving the log deposit
+        let log_id = submit_insight(&lyra, &[beacon.clone(), verdict.clone()], b"QmEndToEndInsight");
+        assert_eq!(Balances::reserved_balance(&lyra), AGENT_STAKE + LOG_DEPOSIT);
+        assert_eq!(ConsensusLog::logs_by_agent(&verdict).to_vec(), vec![log_id]);
+
+        // Multi-sign and finalize; the co-signers earn the first responder bonus
+        sign_by(log_id, &[beacon.clone(), verdict.clone()]);
+        assert_ok!(ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(lyra.clone()), log_id, None));
+        assert!(has_event(pallet_consensus_log::Event::<Runtime>::LogFinalized { log_id, aggregated: false }));
+        assert!(has_event(pallet_consensus_log::Event::<Runtime>::FirstResponders {
+            log_id,
+            agents: vec![beacon.clone(), verdict.clone()],
+        }));
+        assert!(ConsensusLog::signature_roots(log_id).is_some());
+        assert_eq!(Reputation::reputation(&beacon).reputation, 10);
+        assert_eq!(Reputation::reputation(&lyra).reputation, 0);
+
+        // Pay the consensus rewards
+        for agent in [&lyra, &beacon, &verdict] {
+            assert_ok!(Reputation::reward_consensus(RuntimeOrigin::root(), agent.clone()));
+            assert_eq!(Reputation::reputation(agent).consensus_count, 1);
+        }
+        assert!(Reputation::reputation(&lyra).reputation >= 100);
+        assert!(Reputation::reputation(&beacon).reputation > Reputation::reputation(&lyra).reputation);
+
+        // Report an equivocation: a quarter of the stake is slashed and burned
+        let issuance = Balances::total_issuance();
+        assert_ok!(Reputation::report_offense(RuntimeOrigin::root(), verdict.clone(), OffenseType::Equivocation));
+        let slashed = AGENT_STAKE / 4;
+        assert_eq!(Reputation::reputation(&verdict).stake, AGENT_STAKE - slashed);
+        assert!(Reputation::reputation(&verdict).quarantine_until.is_some());
+        assert_eq!(Reputation::total_stake(), 3 * AGENT_STAKE - slashed);
+        assert_eq!(Balances::reserved_balance(&verdict), AGENT_STAKE - slashed);
+        assert_eq!(Balances::total_issuance(), issuance - slashed);
+
+        // The slash is also written to the recall audit log
+        let slash_record = Recall::next_record_id() - 1;
+        assert!(has_event(pallet_recall::Event::<Runtime>::SystemRecordStored {
+            record_id: slash_record,
+            record_type: RecordType::SystemEvent,
+            agent_id: verdict.clone(),
+        }));
+
+        // Store an ephemeral recall record and prune it once its retention ends
+        let record_id = Recall::next_record_id();
+        assert_ok!(Recall::store_consensus_record(
+            RuntimeOrigin::signed(lyra.clone()),
+            RecordType::MultiAgentConsensus,
+            b"end-to-end-content-hash".to_vec(),
+            ContentLocator::Ipfs(b"QmEndToEndInsight".to_vec().try_into().unwrap()),
+            b"summary".to_vec(),
+            b"signature".to_vec(),
+            None,
+            RetentionClass::Ephemeral,
+            CompressionCodec::None,
+        ));
+        assert_eq!(Balances::reserved_balance(&lyra), AGENT_STAKE + LOG_DEPOSIT + MILLI_UNIT);
+
+        let expiry = System::block_number() + 7 * DAYS;
+        System::set_block_number(expiry);
+        Recall::on_initialize(expiry);
+        assert!(has_event(pallet_recall::Event::<Runtime>::RecordPruned { record_id }));
+        assert!(Recall::records(record_id).is_none());
+        assert_eq!(Balances::reserved_balance(&lyra), AGENT_STAKE + LOG_DEPOSIT);
+
+        // Slashed funds never come back
+        assert_eq!(Balances::free_balance(&verdict) + Balances::reserved_balance(&verdict), INITIAL_BALANCE - slashed);
+    });
+}
+
+#[test]
+fn quarantined_signers_cannot_finalize_logs() {
+    new_test_ext().execute_with(|| {
+        let lyra = staked_agent(1, AgentRole::Lyra);
+        let beacon = staked_agent(2, AgentRole::Beacon);
+
+        let log_id = submit_insight(&lyra, &[beacon.clone()], b"QmQuarantinedSigner");
+        sign_by(log_id, &[beacon.clone()]);
+
+        // Quarantine the co-signer through the reputation pallet
+        assert_ok!(Reputation::report_offense(RuntimeOrigin::root(), beacon.clone(), OffenseType::Equivocation));
+
+        // Its signature no longer counts, and the unanimous log cannot do without it
+        assert_noop!(
+            ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(lyra.clone()), log_id, None),
+            pallet_consensus_log::Error::<Runtime>::SignersExcluded
+        );
+    });
+}
+
+#[test]
+fn agent_status_changes_are_written_to_recall() {
+    new_test_ext().execute_with(|| {
+        let lyra = staked_agent(1, AgentRole::Lyra);
+        let record_id = Recall::next_record_id();
+
+        assert_ok!(AgentRegistry::update_status(RuntimeOrigin::signed(lyra.clone()), AgentStatus::Maintenance));
+
+        assert!(has_event(pallet_recall::Event::<Runtime>::SystemRecordStored {
+            record_id,
+            record_type: RecordType::AgentStatusUpdate,
+            agent_id: lyra.clone(),
+        }));
+        let record = Recall::records(record_id).unwrap();
+        assert!(record.signatures.is_empty());
+
+        // Agents that are not online cannot submit logs
+        assert_noop!(
+            ConsensusLog::submit_insight(
+                RuntimeOrigin::signed(lyra),
+                vec![account(2)],
+                b"QmMaintenance".to_vec(),
+                b"signature".to_vec(),
+                None,
+                ConsensusModel::Unanimous,
+                LogPriority::Normal,
+                vec![],
+                None,
+            ),
+            pallet_consensus_log::Error::<Runtime>::AgentNotActive
+        );
+    });
+}
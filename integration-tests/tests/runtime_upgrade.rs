@@ -0,0 +1,69 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        runtime_upgrade.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Runtime upgrade checks against live or snapshotted chain state
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! Loads the state of a running chain, applies the migrations of this runtime and runs the
+//! try-state checks of the C-Suite pallets, so an upgrade can be validated before it is
+//! proposed.
+//!
+//! Only built with the `try-runtime` feature:
+//!
+//! ```text
+//! CSUITE_UPGRADE_URI=wss://staging.example:443 \
+//!     cargo test -p csuite-integration-tests --features try-runtime --test runtime_upgrade
+//! ```
+//!
+//! `CSUITE_UPGRADE_URI` defaults to a local node. When `CSUITE_UPGRADE_SNAPSHOT` names a file,
+//! the state is read from it if it exists, and otherwise downloaded once and saved there.
+
+use frame_remote_externalities::{Builder, Mode, OfflineConfig, OnlineConfig, SnapshotConfig};
+use frame_support::traits::{TryState, TryStateSelect, UpgradeCheckSelect};
+use parachain_template_runtime::{AllPalletsWithSystem, Block, BlockNumber, Executive, Hash, System};
+
+/// Node the state is downloaded from unless `CSUITE_UPGRADE_URI` is set
+const DEFAULT_URI: &str = "ws://127.0.0.1:9944";
+
+/// Pallets whose try-state checks must pass after the upgrade
+const CSUITE_PALLETS: [&[u8]; 4] = [b"AgentRegistry", b"ConsensusLog", b"Reputation", b"Recall"];
+
+// Helper function to choose between the live chain and a saved snapshot of it
+fn mode() -> Mode<Hash> {
+    let uri = std::env::var("CSUITE_UPGRADE_URI").unwrap_or_else(|_| DEFAULT_URI.into());
+    let snapshot = std::env::var("CSUITE_UPGRADE_SNAPSHOT").ok().map(SnapshotConfig::new);
+    let online = OnlineConfig { transport: uri.into(), state_snapshot: snapshot.clone(), ..Default::default() };
+
+    match snapshot {
+        Some(state_snapshot) => Mode::OfflineOrElseOnline(OfflineConfig { state_snapshot }, online),
+        None => Mode::Online(online),
+    }
+}
+
+#[tokio::test]
+async fn migrations_keep_the_csuite_pallets_consistent() {
+    let mut ext = Builder::<Block>::new()
+        .mode(mode())
+        .build()
+        .await
+        .expect("Failed to load the chain state");
+
+    ext.execute_with(|| {
+        // Every migration runs with its pre- and post-upgrade checks
+        Executive::try_runtime_upgrade(UpgradeCheckSelect::PreAndPost)
+            .expect("The runtime upgrade failed its checks");
+
+        <AllPalletsWithSystem as TryState<BlockNumber>>::try_state(
+            System::block_number(),
+            TryStateSelect::Only(CSUITE_PALLETS.iter().map(|name| name.to_vec()).collect()),
+        )
+        .expect("The C-Suite pallets are inconsistent after the upgrade");
+    });
+}
@@ -0,0 +1,310 @@
+//! The `audit-state` subcommand: an off-chain consistency check of pallet storage.
+//!
+//! Reads the state of a block from the local database and verifies invariants the pallets
+//! maintain between their storage items, without executing the runtime:
+//! - every consensus log is listed in `LogsByAgent` for each involved agent and in `LogsByCID`
+//!   for its CID (unless evicted from a full entry), and those indexes only list existing logs
+//!   that match them
+//! - `CidLogCount` matches the number of logs stored under each CID
+//! - per-log records (`FinalizedLogs`, `SignatureRoots`, `LogDeposits`, `CidHistory`) belong to
+//!   existing logs
+//! - `TotalStake` equals the sum of the agents' stakes
+//!
+//! The result is printed as a JSON report.
+
+use std::{collections::{BTreeMap, BTreeSet}, marker::PhantomData, path::PathBuf, sync::Arc};
+
+use codec::Decode;
+use serde::Serialize;
+
+use parachain_template_runtime::{opaque::Block, AccountId, Balance, Hash, Runtime};
+
+use polkadot_sdk::*;
+
+use sc_cli::{CliConfiguration, ImportParams, SharedParams};
+use sc_client_api::{Backend, StorageProvider};
+use sp_blockchain::HeaderBackend;
+use sp_core::{hexdisplay::HexDisplay, storage::StorageKey, twox_128};
+
+type ConsensusLog = pallet_consensus_log::ConsensusLog<Runtime>;
+type FinalizedLog = pallet_consensus_log::FinalizedLog<Runtime>;
+type ReputationInfo = pallet_reputation::ReputationInfo<Runtime>;
+type Cid = Vec<u8>;
+
+/// Length of the pallet and item prefix of a storage key.
+const PREFIX_LEN: usize = 32;
+/// Length of the hash in front of a `Blake2_128Concat` key.
+const BLAKE2_128_LEN: usize = 16;
+
+/// Verify the consistency of pallet storage at a block.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct AuditStateCmd {
+	/// Block hash to audit. Defaults to the best block.
+	#[arg(long, value_name = "HASH")]
+	pub at: Option<Hash>,
+
+	/// Write the report to this file instead of stdout.
+	#[arg(long, value_name = "PATH")]
+	pub output: Option<PathBuf>,
+
+	/// Exit with an error if any violation is found.
+	#[arg(long)]
+	pub fail_on_violation: bool,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub import_params: ImportParams,
+}
+
+/// A broken invariant.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Violation {
+	/// Name of the failed check.
+	pub check: &'static str,
+	/// Hex encoding of the storage entry the check failed for.
+	pub subject: String,
+	/// What is wrong.
+	pub detail: String,
+}
+
+/// Number of entries read per storage item.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Checked {
+	/// Consensus logs.
+	pub logs: u64,
+	/// Agents with a `LogsByAgent` entry.
+	pub agent_indexes: u64,
+	/// CIDs with a `LogsByCID` entry.
+	pub cid_indexes: u64,
+	/// Finalization, signature root, deposit and CID history records.
+	pub per_log_records: u64,
+	/// Agents with reputation state.
+	pub reputation_entries: u64,
+}
+
+/// The audit result.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditReport {
+	/// Audited block.
+	pub block: Hash,
+	/// Number of the audited block.
+	pub number: u32,
+	/// Entries read.
+	pub checked: Checked,
+	/// `TotalStake` as stored, as a decimal string.
+	pub stored_total_stake: String,
+	/// Sum of the agents' stakes, as a decimal string.
+	pub computed_total_stake: String,
+	/// Every violation found.
+	pub violations: Vec<Violation>,
+	/// Whether no violation was found.
+	pub ok: bool,
+}
+
+impl AuditStateCmd {
+	/// Run the audit against the client's database.
+	pub fn run<C, BE>(&self, client: Arc<C>) -> sc_cli::Result<()>
+	where
+		C: StorageProvider<Block, BE> + HeaderBackend<Block>,
+		BE: Backend<Block>,
+	{
+		let at = self.at.unwrap_or_else(|| client.info().best_hash);
+		let number = client
+			.number(at)?
+			.ok_or_else(|| sc_cli::Error::Input(format!("Unknown block {:?}", at)))?;
+
+		let report = Auditor::new(&*client, at).audit(number)?;
+		let json = serde_json::to_string_pretty(&report)
+			.map_err(|e| sc_cli::Error::Application(Box::new(e)))?;
+		match &self.output {
+			Some(path) => std::fs::write(path, json)?,
+			None => println!("{}", json),
+		}
+
+		if self.fail_on_violation && !report.ok {
+			return Err(format!("{} violations found", report.violations.len()).into());
+		}
+		Ok(())
+	}
+}
+
+impl CliConfiguration for AuditStateCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn import_params(&self) -> Option<&ImportParams> {
+		Some(&self.import_params)
+	}
+}
+
+/// Reads decoded storage at one block and collects violations.
+struct Auditor<'a, C, BE> {
+	client: &'a C,
+	at: Hash,
+	violations: Vec<Violation>,
+	_backend: PhantomData<BE>,
+}
+
+impl<'a, C, BE> Auditor<'a, C, BE>
+where
+	C: StorageProvider<Block, BE>,
+	BE: Backend<Block>,
+{
+	fn new(client: &'a C, at: Hash) -> Self {
+		Self { client, at, violations: Vec::new(), _backend: PhantomData }
+	}
+
+	fn violation(&mut self, check: &'static str, subject: &[u8], detail: impl Into<String>) {
+		self.violations.push(Violation {
+			check,
+			subject: format!("0x{}", HexDisplay::from(&subject)),
+			detail: detail.into(),
+		});
+	}
+
+	/// Decode every entry of a `Blake2_128Concat` map, reporting entries that fail to decode.
+	fn map<K: Decode, V: Decode>(&mut self, pallet: &str, item: &str) -> sc_cli::Result<Vec<(K, V)>> {
+		let prefix = StorageKey(storage_prefix(pallet, item));
+		let mut entries = Vec::new();
+		for (key, value) in self.client.storage_pairs(self.at, Some(&prefix), None)? {
+			let decoded = key
+				.0
+				.get(PREFIX_LEN + BLAKE2_128_LEN..)
+				.and_then(|mut raw| K::decode(&mut raw).ok())
+				.zip(V::decode(&mut &value.0[..]).ok());
+			match decoded {
+				Some(entry) => entries.push(entry),
+				None => self.violation("decodable", &key.0, format!("{}::{} entry does not decode", pallet, item)),
+			}
+		}
+		Ok(entries)
+	}
+
+	/// Decode a storage value, if it is set.
+	fn value<V: Decode>(&mut self, pallet: &str, item: &str) -> sc_cli::Result<Option<V>> {
+		let key = StorageKey(storage_prefix(pallet, item));
+		Ok(self.client.storage(self.at, &key)?.and_then(|data| V::decode(&mut &data.0[..]).ok()))
+	}
+
+	fn audit(mut self, number: u32) -> sc_cli::Result<AuditReport> {
+		let mut checked = Checked::default();
+
+		let logs = self.map::<Hash, ConsensusLog>("ConsensusLog", "Logs")?;
+		let by_agent = self.map::<AccountId, Vec<Hash>>("ConsensusLog", "LogsByAgent")?;
+		let by_cid = self.map::<Cid, Vec<Hash>>("ConsensusLog", "LogsByCID")?;
+		let cid_counts: BTreeMap<Cid, u32> = self.map::<Cid, u32>("ConsensusLog", "CidLogCount")?.into_iter().collect();
+		checked.logs = logs.len() as u64;
+		checked.agent_indexes = by_agent.len() as u64;
+		checked.cid_indexes = by_cid.len() as u64;
+
+		let log_ids: BTreeSet<Hash> = logs.iter().map(|(id, _)| *id).collect();
+		let agent_entries: BTreeSet<(AccountId, Hash)> = by_agent
+			.iter()
+			.flat_map(|(agent, ids)| ids.iter().map(move |id| (agent.clone(), *id)))
+			.collect();
+		let cid_entries: BTreeSet<(Cid, Hash)> = by_cid
+			.iter()
+			.flat_map(|(cid, ids)| ids.iter().map(move |id| (cid.clone(), *id)))
+			.collect();
+
+		// Every log is indexed under each involved agent and its CID, unless the CID's entry
+		// overflowed and evicted it
+		let mut stored_per_cid: BTreeMap<Cid, u32> = BTreeMap::new();
+		for (log_id, log) in logs.iter() {
+			*stored_per_cid.entry(log.cid.to_vec()).or_default() += 1;
+			for agent in log.agents_involved.iter() {
+				if !agent_entries.contains(&(agent.clone(), *log_id)) {
+					self.violation("logs_by_agent_complete", log_id.as_ref(), format!("not indexed for agent {}", agent));
+				}
+			}
+			let cid = log.cid.to_vec();
+			let indexed = by_cid.iter().find(|(c, _)| *c == cid).map_or(0, |(_, ids)| ids.len() as u32);
+			let evicted = cid_counts.get(&cid).copied().unwrap_or_default() > indexed;
+			if !evicted && !cid_entries.contains(&(cid, *log_id)) {
+				self.violation("logs_by_cid_complete", log_id.as_ref(), "not indexed under its CID");
+			}
+		}
+
+		// The per-CID counts match the stored logs
+		for cid in stored_per_cid.keys().chain(cid_counts.keys()).collect::<BTreeSet<_>>() {
+			let stored = stored_per_cid.get(cid).copied().unwrap_or_default();
+			let counted = cid_counts.get(cid).copied().unwrap_or_default();
+			if stored != counted {
+				self.violation("cid_log_count", cid, format!("counts {} logs but {} are stored", counted, stored));
+			}
+		}
+
+		// The indexes only list existing logs that match them
+		for (agent, log_id) in agent_entries.iter() {
+			match logs.iter().find(|(id, _)| id == log_id) {
+				None => self.violation("logs_by_agent_orphan", log_id.as_ref(), format!("indexed for agent {} but missing", agent)),
+				Some((_, log)) if !log.agents_involved.contains(agent) =>
+					self.violation("logs_by_agent_member", log_id.as_ref(), format!("indexed for uninvolved agent {}", agent)),
+				_ => {},
+			}
+		}
+		for (cid, log_id) in cid_entries.iter() {
+			match logs.iter().find(|(id, _)| id == log_id) {
+				None => self.violation("logs_by_cid_orphan", log_id.as_ref(), "indexed under a CID but missing"),
+				Some((_, log)) if log.cid.as_slice() != cid.as_slice() =>
+					self.violation("logs_by_cid_match", log_id.as_ref(), "indexed under another CID"),
+				_ => {},
+			}
+		}
+
+		// Per-log records of logs that no longer exist
+		let finalized = self.map::<Hash, FinalizedLog>("ConsensusLog", "FinalizedLogs")?;
+		let roots = self.map::<Hash, [u8; 32]>("ConsensusLog", "SignatureRoots")?;
+		let deposits = self.map::<Hash, (AccountId, Balance)>("ConsensusLog", "LogDeposits")?;
+		let history = self.map::<Hash, Vec<(Cid, u32)>>("ConsensusLog", "CidHistory")?;
+		let per_log = finalized
+			.iter()
+			.map(|(id, _)| ("finalized_logs_orphan", *id))
+			.chain(roots.iter().map(|(id, _)| ("signature_roots_orphan", *id)))
+			.chain(deposits.iter().map(|(id, _)| ("log_deposits_orphan", *id)))
+			.chain(history.iter().map(|(id, _)| ("cid_history_orphan", *id)))
+			.collect::<Vec<_>>();
+		checked.per_log_records = per_log.len() as u64;
+		for (check, log_id) in per_log {
+			if !log_ids.contains(&log_id) {
+				self.violation(check, log_id.as_ref(), "record of a missing log");
+			}
+		}
+
+		// TotalStake matches the agents' stakes
+		let reputation = self.map::<AccountId, ReputationInfo>("Reputation", "Reputation")?;
+		checked.reputation_entries = reputation.len() as u64;
+		let computed: Balance = reputation.iter().map(|(_, info)| info.stake).sum();
+		let stored: Balance = self.value("Reputation", "TotalStake")?.unwrap_or_default();
+		if stored != computed {
+			self.violation(
+				"total_stake",
+				&storage_prefix("Reputation", "TotalStake"),
+				format!("stored {} but agents hold {}", stored, computed),
+			);
+		}
+
+		Ok(AuditReport {
+			block: self.at,
+			number,
+			checked,
+			stored_total_stake: stored.to_string(),
+			computed_total_stake: computed.to_string(),
+			ok: self.violations.is_empty(),
+			violations: self.violations,
+		})
+	}
+}
+
+/// The key prefix of a storage item of a pallet.
+fn storage_prefix(pallet: &str, item: &str) -> Vec<u8> {
+	[twox_128(pallet.as_bytes()), twox_128(item.as_bytes())].concat()
+}
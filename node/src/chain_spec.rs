@@ -4,6 +4,8 @@ use parachain_template_runtime as runtime;
 use sc_chain_spec::{ChainSpecExtension, ChainSpecGroup};
 use sc_service::ChainType;
 use serde::{Deserialize, Serialize};
+use sp_core::H256;
+use std::{fs::File, io::BufReader, path::Path};
 
 /// Specialized `ChainSpec` for the normal parachain runtime.
 pub type ChainSpec = sc_service::GenericChainSpec<Extensions>;
@@ -94,3 +96,67 @@ pub fn local_chain_spec() -> ChainSpec {
 	.with_properties(properties)
 	.build()
 }
+
+/// Parameters and operator keys of the C-Suite live network, read from a JSON file.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct LiveNetworkConfig {
+	/// The relay chain the parachain is registered on.
+	pub relay_chain: String,
+	/// The ID the parachain is registered with.
+	pub para_id: u32,
+	/// Initial collators as `[account, aura key]`.
+	pub collators: Vec<(runtime::AccountId, runtime::AuraId)>,
+	/// Accounts endowed at genesis, besides the agents.
+	#[serde(default)]
+	pub endowed_accounts: Vec<runtime::AccountId>,
+	/// Council members.
+	pub council: Vec<runtime::AccountId>,
+	/// Pre-registered agents as `[account, role]`.
+	pub agents: Vec<(runtime::AccountId, String)>,
+	/// Group key produced by the agents' FROST key generation ceremony.
+	pub frost_group_key: H256,
+	/// Number of partial signatures required by the FROST group.
+	pub frost_threshold: u32,
+}
+
+/// Chain spec of the C-Suite live network described by the configuration file at `path`.
+pub fn live_chain_spec(path: &Path) -> Result<ChainSpec, String> {
+	let file = File::open(path).map_err(|e| format!("Cannot open {}: {e}", path.display()))?;
+	let config: LiveNetworkConfig = serde_json::from_reader(BufReader::new(file))
+		.map_err(|e| format!("Invalid live network config {}: {e}", path.display()))?;
+	if config.collators.is_empty() || config.council.is_empty() || config.agents.is_empty() {
+		return Err("The live network needs collators, council members and agents".into());
+	}
+	if config.frost_threshold == 0 || config.frost_threshold as usize > config.agents.len() {
+		return Err("The FROST threshold must be between one and the number of agents".into());
+	}
+
+	// Give your base currency a unit name and decimal places
+	let mut properties = sc_chain_spec::Properties::new();
+	properties.insert("tokenSymbol".into(), "UNIT".into());
+	properties.insert("tokenDecimals".into(), 12.into());
+	properties.insert("ss58Format".into(), 42.into());
+
+	let genesis = runtime::csuite_live_genesis(runtime::CSuiteLiveGenesis {
+		collators: config.collators,
+		endowed_accounts: config.endowed_accounts,
+		council: config.council,
+		agents: config.agents.into_iter().map(|(account, role)| (account, role.into_bytes())).collect(),
+		frost_group_key: config.frost_group_key.0,
+		frost_threshold: config.frost_threshold,
+		para_id: config.para_id.into(),
+	});
+
+	Ok(ChainSpec::builder(
+		runtime::WASM_BINARY.expect("WASM binary was not built, please build it!"),
+		Extensions { relay_chain: config.relay_chain, para_id: config.para_id },
+	)
+	.with_name("C-Suite")
+	.with_id("csuite_live")
+	.with_chain_type(ChainType::Live)
+	.with_genesis_config_patch(genesis)
+	.with_protocol_id("csuite")
+	.with_properties(properties)
+	.build())
+}
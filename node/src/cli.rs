@@ -35,6 +35,12 @@ pub enum Subcommand {
 	/// Export the genesis wasm of the parachain.
 	ExportGenesisWasm(cumulus_client_cli::ExportGenesisWasmCommand),
 
+	/// Verify the consistency of pallet storage and print a JSON report.
+	AuditState(crate::audit::AuditStateCmd),
+
+	/// Run a dev chain with simulated agents submitting and signing insights.
+	Simnet(crate::simnet::SimnetCmd),
+
 	/// Sub-commands concerned with benchmarking.
 	/// The pallet benchmarking moved to the `pallet` sub-command.
 	#[command(subcommand)]
@@ -53,6 +59,10 @@ const AFTER_HELP_EXAMPLE: &str = color_print::cstr!(
            Launch a collator with default parachain <italic>local-testnet</> and relay chain <italic>rococo-local</>.
    <bold>parachain-template-node --dev</>
            Launch a parachain node in standalone mode without a relay chain.
+   <bold>parachain-template-node audit-state --fail-on-violation > audit.json</>
+           Check the consistency of pallet storage at the best block and save the report.
+   <bold>parachain-template-node simnet --agents 13 --insight-interval 2000</>
+           Launch a dev chain and load it with 13 simulated agents submitting an insight every 2 seconds.
  "#
 );
 #[derive(Debug, clap::Parser)]
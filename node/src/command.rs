@@ -21,9 +21,14 @@ fn load_spec(id: &str) -> std::result::Result<Box<dyn ChainSpec>, String> {
 	Ok(match id {
 		"dev" => Box::new(chain_spec::development_chain_spec()),
 		"standalone" => Box::new(chain_spec::standalone_chain_spec()),
+		"csuite-live" | "live" =>
+			return Err("The live network needs its operator keys: use `--chain csuite-live:<config.json>`".into()),
 		"template-rococo" => Box::new(chain_spec::local_chain_spec()),
 		"" | "local" => Box::new(chain_spec::local_chain_spec()),
-		path => Box::new(chain_spec::ChainSpec::from_json_file(std::path::PathBuf::from(path))?),
+		path => match path.strip_prefix("csuite-live:") {
+			Some(config) => Box::new(chain_spec::live_chain_spec(std::path::Path::new(config))?),
+			None => Box::new(chain_spec::ChainSpec::from_json_file(std::path::PathBuf::from(path))?),
+		},
 	})
 }
 
@@ -178,6 +183,14 @@ pub fn run() -> Result<()> {
 				cmd.run(&*spec)
 			})
 		},
+		Some(Subcommand::AuditState(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			runner.sync_run(|config| {
+				let partials = new_partial(&config)?;
+				cmd.run(partials.client)
+			})
+		},
+		Some(Subcommand::Simnet(cmd)) => cmd.run(),
 		Some(Subcommand::Benchmark(cmd)) => {
 			let runner = cli.create_runner(cmd)?;
 			// Switch on the concrete benchmark sub-command-
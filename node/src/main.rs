@@ -4,11 +4,14 @@
 
 use polkadot_sdk::*;
 
+mod audit;
 mod chain_spec;
 mod cli;
 mod command;
+mod metrics;
 mod rpc;
 mod service;
+mod simnet;
 
 fn main() -> sc_cli::Result<()> {
 	command::run()
@@ -0,0 +1,189 @@
+//! Prometheus metrics for the C-Suite pallets.
+//!
+//! A background task follows the best block and derives the metrics from the events it
+//! emitted, reading pallet storage where an event alone is not enough:
+//! - consensus logs submitted and finalized, in total and in the latest block
+//! - the number of signatures on each finalized log
+//! - the block the latest log was finalized in, so operators can alert on consensus stalls
+//! - reputation slashes
+//! - the number of online agents
+//!
+//! Counters follow the best chain, so blocks that are re-imported on a reorg are counted again.
+
+use std::sync::Arc;
+
+use codec::{Decode, Encode};
+use futures::StreamExt;
+
+use parachain_template_runtime::{opaque::Block, Hash, Runtime, RuntimeEvent};
+
+use polkadot_sdk::*;
+
+use prometheus_endpoint::{
+	register, Counter, Gauge, Histogram, HistogramOpts, PrometheusError, Registry, U64,
+};
+use sc_client_api::{Backend, BlockchainEvents, StorageProvider};
+use sp_core::{blake2_128, storage::StorageKey, twox_128};
+
+type ConsensusLog = pallet_consensus_log::ConsensusLog<Runtime>;
+type AgentInfo = pallet_agent_registry::AgentInfo<Runtime>;
+
+type EventRecord = csuite_primitives::EventRecord<RuntimeEvent, Hash>;
+
+/// Metrics registered with the node's Prometheus registry.
+#[derive(Clone)]
+pub struct CSuiteMetrics {
+	logs_submitted: Counter<U64>,
+	logs_submitted_per_block: Gauge<U64>,
+	logs_finalized: Counter<U64>,
+	logs_finalized_per_block: Gauge<U64>,
+	log_signatures: Histogram,
+	last_log_finalized_at: Gauge<U64>,
+	slashes: Counter<U64>,
+	active_agents: Gauge<U64>,
+}
+
+impl CSuiteMetrics {
+	/// Create the metrics and register them with `registry`.
+	pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+		Ok(Self {
+			logs_submitted: register(
+				Counter::new(
+					"csuite_consensus_logs_submitted_total",
+					"Consensus logs and insights submitted",
+				)?,
+				registry,
+			)?,
+			logs_submitted_per_block: register(
+				Gauge::new(
+					"csuite_consensus_logs_submitted_per_block",
+					"Consensus logs and insights submitted in the latest best block",
+				)?,
+				registry,
+			)?,
+			logs_finalized: register(
+				Counter::new("csuite_consensus_logs_finalized_total", "Consensus logs finalized")?,
+				registry,
+			)?,
+			logs_finalized_per_block: register(
+				Gauge::new(
+					"csuite_consensus_logs_finalized_per_block",
+					"Consensus logs finalized in the latest best block",
+				)?,
+				registry,
+			)?,
+			log_signatures: register(
+				Histogram::with_opts(
+					HistogramOpts::new(
+						"csuite_consensus_log_signatures",
+						"Signatures on each finalized consensus log",
+					)
+					.buckets(vec![1.0, 2.0, 3.0, 4.0, 6.0, 8.0, 12.0, 16.0, 32.0, 64.0]),
+				)?,
+				registry,
+			)?,
+			last_log_finalized_at: register(
+				Gauge::new(
+					"csuite_consensus_last_log_finalized_block",
+					"Number of the latest block a consensus log was finalized in",
+				)?,
+				registry,
+			)?,
+			slashes: register(
+				Counter::new("csuite_reputation_slashes_total", "Agents slashed for offenses")?,
+				registry,
+			)?,
+			active_agents: register(
+				Gauge::new("csuite_agents_active", "Registered agents that are online")?,
+				registry,
+			)?,
+		})
+	}
+}
+
+/// Update the metrics for every new best block until the import stream ends.
+pub async fn run<C, BE>(client: Arc<C>, metrics: CSuiteMetrics)
+where
+	C: BlockchainEvents<Block> + StorageProvider<Block, BE>,
+	BE: Backend<Block>,
+{
+	let mut imports = client.import_notification_stream();
+	while let Some(notification) = imports.next().await {
+		if !notification.is_new_best {
+			continue;
+		}
+
+		if let Err(e) = observe_block(&*client, &metrics, notification.hash, notification.header.number) {
+			log::debug!(target: "csuite-metrics", "Failed to read block {:?}: {}", notification.hash, e);
+		}
+	}
+}
+
+/// Update the metrics from the events and storage of one block.
+fn observe_block<C, BE>(
+	client: &C,
+	metrics: &CSuiteMetrics,
+	at: Hash,
+	number: u32,
+) -> sp_blockchain::Result<()>
+where
+	C: StorageProvider<Block, BE>,
+	BE: Backend<Block>,
+{
+	let events = client
+		.storage(at, &StorageKey(storage_prefix("System", "Events")))?
+		.and_then(|data| Vec::<EventRecord>::decode(&mut &data.0[..]).ok())
+		.unwrap_or_default();
+
+	let mut submitted = 0;
+	let mut finalized = 0;
+	for (_, event, _) in events {
+		match event {
+			RuntimeEvent::ConsensusLog(
+				pallet_consensus_log::Event::ConsensusLogged { .. } |
+				pallet_consensus_log::Event::InsightSubmitted { .. },
+			) => submitted += 1,
+			RuntimeEvent::ConsensusLog(pallet_consensus_log::Event::LogFinalized { log_id, .. }) => {
+				finalized += 1;
+				let log = client
+					.storage(at, &StorageKey(blake2_128_concat_key("ConsensusLog", "Logs", &log_id)))?
+					.and_then(|data| ConsensusLog::decode(&mut &data.0[..]).ok());
+				if let Some(log) = log {
+					metrics.log_signatures.observe(log.signatures.len() as f64);
+				}
+			},
+			RuntimeEvent::Reputation(pallet_reputation::Event::AgentSlashed { .. }) =>
+				metrics.slashes.inc(),
+			_ => {},
+		}
+	}
+
+	metrics.logs_submitted.inc_by(submitted);
+	metrics.logs_submitted_per_block.set(submitted);
+	metrics.logs_finalized.inc_by(finalized);
+	metrics.logs_finalized_per_block.set(finalized);
+	if finalized > 0 {
+		metrics.last_log_finalized_at.set(number.into());
+	}
+
+	let prefix = StorageKey(storage_prefix("AgentRegistry", "Agents"));
+	let active = client
+		.storage_pairs(at, Some(&prefix), None)?
+		.filter_map(|(_, value)| AgentInfo::decode(&mut &value.0[..]).ok())
+		.filter(|agent| agent.status == pallet_agent_registry::AgentStatus::Online)
+		.count();
+	metrics.active_agents.set(active as u64);
+
+	Ok(())
+}
+
+/// The key prefix of a storage item of a pallet.
+fn storage_prefix(pallet: &str, item: &str) -> Vec<u8> {
+	[twox_128(pallet.as_bytes()), twox_128(item.as_bytes())].concat()
+}
+
+/// The key of an entry of a `Blake2_128Concat` map.
+fn blake2_128_concat_key(pallet: &str, item: &str, key: &impl Encode) -> Vec<u8> {
+	let encoded = key.encode();
+	[storage_prefix(pallet, item), blake2_128(&encoded).to_vec(), encoded].concat()
+}
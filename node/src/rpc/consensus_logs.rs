@@ -0,0 +1,117 @@
+//! Pub/sub RPC streaming consensus logs as they are finalized.
+//!
+//! Agent runtimes subscribe with `csuite_subscribeFinalizedLogs` instead of polling consensus
+//! log storage every block. For each finalized block the node asks the runtime which logs its
+//! events report as finalized and forwards a summary of each.
+
+use std::sync::Arc;
+
+use futures::{stream, StreamExt};
+use jsonrpsee::{proc_macros::rpc, PendingSubscriptionSink};
+use serde::{Deserialize, Serialize};
+
+use pallet_consensus_log_runtime_api::{ConsensusLogApi, FinalizedLogSummary};
+use parachain_template_runtime::{opaque::Block, AccountId, BlockNumber, Hash};
+
+use polkadot_sdk::*;
+
+use sc_client_api::BlockchainEvents;
+use sc_rpc::{
+	utils::{spawn_subscription_task, BoundedVecDeque, PendingSubscription},
+	SubscriptionTaskExecutor,
+};
+use sp_api::ProvideRuntimeApi;
+
+/// A finalized consensus log as sent to subscribers.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FinalizedLog {
+	/// Block in which the log was finalized.
+	pub block: Hash,
+	/// The finalized log.
+	pub log_id: Hash,
+	/// IPFS CID of the log content.
+	pub cid: String,
+	/// Agents that signed off the log.
+	pub signers: Vec<AccountId>,
+	/// Trust score derived from the signers' reputation.
+	pub trust_score: u64,
+	/// Whether the log was finalized with a FROST aggregate signature.
+	pub aggregated: bool,
+}
+
+impl FinalizedLog {
+	fn new(block: Hash, summary: FinalizedLogSummary<AccountId, Hash>) -> Self {
+		Self {
+			block,
+			log_id: summary.log_id,
+			cid: String::from_utf8_lossy(&summary.cid).into_owned(),
+			signers: summary.signers,
+			trust_score: summary.trust_score,
+			aggregated: summary.aggregated,
+		}
+	}
+}
+
+/// C-Suite consensus log RPC methods.
+#[rpc(server)]
+pub trait ConsensusLogsApi {
+	/// Subscribe to consensus logs as they are finalized.
+	#[subscription(
+		name = "csuite_subscribeFinalizedLogs" => "csuite_finalizedLog",
+		unsubscribe = "csuite_unsubscribeFinalizedLogs",
+		item = FinalizedLog
+	)]
+	fn subscribe_finalized_logs(&self);
+}
+
+/// Implementation of [`ConsensusLogsApiServer`] backed by a full client.
+pub struct ConsensusLogs<C> {
+	client: Arc<C>,
+	executor: SubscriptionTaskExecutor,
+}
+
+impl<C> ConsensusLogs<C> {
+	/// Create a new instance of the consensus log RPC.
+	pub fn new(client: Arc<C>, executor: SubscriptionTaskExecutor) -> Self {
+		Self { client, executor }
+	}
+}
+
+/// Logs finalized in `block`, or none if the runtime call fails.
+fn finalized_in_block<C>(client: &C, block: Hash) -> Vec<FinalizedLog>
+where
+	C: ProvideRuntimeApi<Block>,
+	C::Api: ConsensusLogApi<Block, AccountId, Hash, BlockNumber>,
+{
+	match client.runtime_api().finalized_in_block(block) {
+		Ok(summaries) =>
+			summaries.into_iter().map(|summary| FinalizedLog::new(block, summary)).collect(),
+		Err(err) => {
+			log::debug!(target: "rpc", "Failed to read finalized logs at {block:?}: {err}");
+			Vec::new()
+		},
+	}
+}
+
+impl<C> ConsensusLogsApiServer for ConsensusLogs<C>
+where
+	C: ProvideRuntimeApi<Block> + BlockchainEvents<Block> + Send + Sync + 'static,
+	C::Api: ConsensusLogApi<Block, AccountId, Hash, BlockNumber>,
+{
+	fn subscribe_finalized_logs(&self, pending: PendingSubscriptionSink) {
+		let client = self.client.clone();
+		let logs = self.client.finality_notification_stream().flat_map(move |notification| {
+			// Blocks finalized implicitly by this notification come first, in chain order
+			let blocks = notification.tree_route.iter().copied().chain(Some(notification.hash));
+			let logs: Vec<_> =
+				blocks.flat_map(|block| finalized_in_block(&*client, block)).collect();
+			stream::iter(logs)
+		});
+
+		spawn_subscription_task(
+			&self.executor,
+			PendingSubscription::from(pending).pipe_from_stream(logs, BoundedVecDeque::default()),
+		);
+	}
+}
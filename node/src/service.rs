@@ -311,9 +311,12 @@ pub async fn start_parachain_node(
 		let client = client.clone();
 		let transaction_pool = transaction_pool.clone();
 
-		Box::new(move |_| {
-			let deps =
-				crate::rpc::FullDeps { client: client.clone(), pool: transaction_pool.clone() };
+		Box::new(move |subscription_executor| {
+			let deps = crate::rpc::FullDeps {
+				client: client.clone(),
+				pool: transaction_pool.clone(),
+				subscription_executor,
+			};
 
 			crate::rpc::create_full(deps).map_err(Into::into)
 		})
@@ -334,6 +337,17 @@ pub async fn start_parachain_node(
 		telemetry: telemetry.as_mut(),
 	})?;
 
+	if let Some(registry) = prometheus_registry.as_ref() {
+		match crate::metrics::CSuiteMetrics::register(registry) {
+			Ok(metrics) => task_manager.spawn_handle().spawn(
+				"csuite-metrics",
+				None,
+				crate::metrics::run(client.clone(), metrics),
+			),
+			Err(e) => log::warn!("Failed to register C-Suite metrics: {}", e),
+		}
+	}
+
 	if let Some(hwbench) = hwbench {
 		sc_sysinfo::print_hwbench(&hwbench);
 		// Here you can check whether the hardware meets your chains' requirements. Putting a link
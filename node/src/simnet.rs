@@ -0,0 +1,299 @@
+//! The `simnet` subcommand: a local dev chain driven by simulated agents.
+//!
+//! Launches this binary as a `--dev --tmp` chain (or attaches to a running node with `--url`),
+//! then through the agent SDK:
+//! - funds `--agents` fresh accounts from Alice, registers them as agents with the canonical
+//!   roles in order (custom roles past the thirteenth) and stakes `--stake` units each
+//! - submits a synthetic insight every `--insight-interval` milliseconds, round-robin across the
+//!   agents, involving the submitter and the next agents in order
+//! - has every agent check its pending duties every `--sign-interval` milliseconds and approve
+//!   the logs it is involved in
+//!
+//! Totals are printed every ten seconds until `--duration` elapses or the command is
+//! interrupted, for load testing and demoing the full consensus pipeline.
+
+use std::{
+	fs::File,
+	path::PathBuf,
+	process::{Child, Command, Stdio},
+	sync::{
+		atomic::{AtomicU64, Ordering},
+		Arc,
+	},
+	time::Duration,
+};
+
+use csuite_agent_sdk::{
+	calls::{Call, Insight},
+	AgentKey, AgentRole, Client, SignatureScheme,
+};
+use parachain_template_runtime::{MultiAddress, RuntimeCall, UNIT};
+
+/// First ID of the custom roles given to agents past the canonical ones.
+const FIRST_CUSTOM_ROLE_ID: u16 = 256;
+/// Seconds between two progress reports.
+const REPORT_INTERVAL: u64 = 10;
+/// Seconds to wait for a launched chain to accept RPC connections.
+const STARTUP_TIMEOUT: u64 = 60;
+
+/// Run a dev chain with simulated agents submitting and signing insights.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct SimnetCmd {
+	/// Number of simulated agents.
+	#[arg(long, default_value_t = 13)]
+	pub agents: u32,
+
+	/// Number of agents involved in each insight, the submitter included.
+	#[arg(long, default_value_t = 3)]
+	pub agents_per_insight: u32,
+
+	/// Milliseconds between two insights, across all agents.
+	#[arg(long, value_name = "MS", default_value_t = 6_000)]
+	pub insight_interval: u64,
+
+	/// Milliseconds between two checks of an agent's pending duties.
+	#[arg(long, value_name = "MS", default_value_t = 3_000)]
+	pub sign_interval: u64,
+
+	/// Units each agent stakes.
+	#[arg(long, default_value_t = 10)]
+	pub stake: u128,
+
+	/// Stop after this many seconds. Runs until interrupted by default.
+	#[arg(long, value_name = "SECS")]
+	pub duration: Option<u64>,
+
+	/// Drive the node at this WebSocket URL instead of launching a dev chain.
+	#[arg(long, value_name = "URL")]
+	pub url: Option<String>,
+
+	/// RPC port of the launched dev chain.
+	#[arg(long, default_value_t = 9944)]
+	pub rpc_port: u16,
+
+	/// Extra argument passed to the launched dev chain. May be repeated.
+	#[arg(long = "node-arg", value_name = "ARG", allow_hyphen_values = true)]
+	pub node_args: Vec<String>,
+
+	/// Write the output of the launched dev chain to this file instead of discarding it.
+	#[arg(long, value_name = "PATH")]
+	pub node_log: Option<PathBuf>,
+}
+
+/// Number of operations the agents made.
+#[derive(Debug, Default)]
+struct Stats {
+	/// Insights submitted.
+	insights: AtomicU64,
+	/// Logs approved.
+	signatures: AtomicU64,
+	/// Extrinsics that failed.
+	failures: AtomicU64,
+}
+
+impl Stats {
+	/// Count an insight or signature, or a failure.
+	fn note(&self, counter: &AtomicU64, result: csuite_agent_sdk::Result<impl Sized>) {
+		match result {
+			Ok(_) => counter.fetch_add(1, Ordering::Relaxed),
+			Err(e) => {
+				log::debug!("Simulated agent extrinsic failed: {}", e);
+				self.failures.fetch_add(1, Ordering::Relaxed)
+			},
+		};
+	}
+
+	/// One line summary of the totals.
+	fn summary(&self) -> String {
+		format!(
+			"{} insights, {} signatures, {} failed",
+			self.insights.load(Ordering::Relaxed),
+			self.signatures.load(Ordering::Relaxed),
+			self.failures.load(Ordering::Relaxed),
+		)
+	}
+}
+
+/// A launched dev chain, killed when dropped.
+struct DevChain(Child);
+
+impl Drop for DevChain {
+	fn drop(&mut self) {
+		let _ = self.0.kill();
+		let _ = self.0.wait();
+	}
+}
+
+impl SimnetCmd {
+	/// Launch or attach to the chain and run the simulation.
+	pub fn run(&self) -> sc_cli::Result<()> {
+		if self.agents < 2 {
+			return Err(sc_cli::Error::Input("Simnet needs at least 2 agents".into()));
+		}
+		if self.agents_per_insight < 2 || self.agents_per_insight > self.agents {
+			return Err(sc_cli::Error::Input(
+				"--agents-per-insight must be between 2 and the number of agents".into(),
+			));
+		}
+
+		let _chain = match self.url {
+			Some(_) => None,
+			None => Some(self.launch_chain()?),
+		};
+		let url = self.url.clone().unwrap_or_else(|| format!("ws://127.0.0.1:{}", self.rpc_port));
+
+		tokio::runtime::Builder::new_multi_thread()
+			.enable_all()
+			.build()?
+			.block_on(self.simulate(&url))
+			.map_err(|e| sc_cli::Error::Application(Box::new(e)))
+	}
+
+	/// Start this binary as a dev chain with a temporary database.
+	fn launch_chain(&self) -> sc_cli::Result<DevChain> {
+		let output = match &self.node_log {
+			Some(path) => Stdio::from(File::create(path)?),
+			None => Stdio::null(),
+		};
+		let child = Command::new(std::env::current_exe()?)
+			.args(["--dev", "--tmp", "--rpc-port", &self.rpc_port.to_string()])
+			.args(&self.node_args)
+			.stdout(Stdio::null())
+			.stderr(output)
+			.spawn()?;
+		println!("Launched dev chain, RPC on port {}", self.rpc_port);
+		Ok(DevChain(child))
+	}
+
+	/// Set the agents up, then submit and sign insights until stopped.
+	async fn simulate(&self, url: &str) -> csuite_agent_sdk::Result<()> {
+		let client = connect(url).await?;
+		let agents = (0..self.agents)
+			.map(|i| AgentKey::from_uri(SignatureScheme::Sr25519, &format!("//Simnet/{}", i)))
+			.collect::<csuite_agent_sdk::Result<Vec<_>>>()?;
+		self.register_agents(&client, &agents).await?;
+		println!("{} agents registered and staked, simulating", agents.len());
+
+		let stats = Arc::new(Stats::default());
+		let accounts: Vec<_> = agents.iter().map(AgentKey::account).collect();
+		let tasks: Vec<_> = agents
+			.into_iter()
+			.enumerate()
+			.map(|(index, key)| {
+				let involved = (0..self.agents_per_insight as usize)
+					.map(|offset| accounts[(index + offset) % accounts.len()].clone())
+					.collect();
+				tokio::spawn(self.clone().run_agent(client.clone(), key, index, involved, stats.clone()))
+			})
+			.collect();
+
+		let mut report = tokio::time::interval(Duration::from_secs(REPORT_INTERVAL));
+		let deadline = async {
+			match self.duration {
+				Some(secs) => tokio::time::sleep(Duration::from_secs(secs)).await,
+				None => std::future::pending().await,
+			}
+		};
+		tokio::pin!(deadline);
+		loop {
+			tokio::select! {
+				_ = report.tick() => println!("{}", stats.summary()),
+				_ = &mut deadline => break,
+				_ = tokio::signal::ctrl_c() => break,
+			}
+		}
+
+		tasks.iter().for_each(|task| task.abort());
+		println!("Simnet stopped: {}", stats.summary());
+		Ok(())
+	}
+
+	/// Fund, register and stake every agent that is not registered yet.
+	async fn register_agents(&self, client: &Client, agents: &[AgentKey]) -> csuite_agent_sdk::Result<()> {
+		let funder = AgentKey::from_uri(SignatureScheme::Sr25519, "//Alice")?;
+		let stake = self.stake.saturating_mul(UNIT);
+
+		let mut new_agents = Vec::new();
+		for (index, key) in agents.iter().enumerate() {
+			if client.agent(&key.account()).await?.is_some() {
+				continue;
+			}
+			// Stake plus a unit for fees
+			let transfer = RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+				dest: MultiAddress::Id(key.account()),
+				value: stake.saturating_add(UNIT),
+			});
+			client.submit(&Call(transfer), &funder).await?;
+			new_agents.push((index, key));
+		}
+
+		futures::future::try_join_all(new_agents.into_iter().map(|(index, key)| async move {
+			let role = AgentRole::CANONICAL
+				.get(index)
+				.copied()
+				.unwrap_or(AgentRole::Custom(FIRST_CUSTOM_ROLE_ID.saturating_add(index as u16)));
+			client.register(key, role, Some(format!("simnet agent {}", index).into_bytes())).await?;
+			client.stake(key, stake).await
+		}))
+		.await?;
+		Ok(())
+	}
+
+	/// Approve an agent's pending logs every sign interval, and submit its share of insights.
+	async fn run_agent(
+		self,
+		client: Client,
+		key: AgentKey,
+		index: usize,
+		involved: Vec<parachain_template_runtime::AccountId>,
+		stats: Arc<Stats>,
+	) {
+		// Agents take turns, so insights arrive every insight interval across all of them
+		let insight_period = Duration::from_millis(self.insight_interval.saturating_mul(self.agents as u64).max(1));
+		let first_insight = tokio::time::Instant::now() + Duration::from_millis(self.insight_interval.saturating_mul(index as u64));
+		let mut insights = tokio::time::interval_at(first_insight, insight_period);
+		let mut duties = tokio::time::interval(Duration::from_millis(self.sign_interval.max(1)));
+		let mut submitted = 0u64;
+
+		loop {
+			tokio::select! {
+				_ = insights.tick() => {
+					let insight = Insight {
+						agents_involved: involved.clone(),
+						cid: format!("simnet-{}-{}", index, submitted).into_bytes(),
+						..Default::default()
+					};
+					submitted = submitted.saturating_add(1);
+					stats.note(&stats.insights, client.submit_insight(&key, insight).await);
+				},
+				_ = duties.tick() => {
+					let pending = match client.pending_duties(&key.account()).await {
+						Ok(pending) => pending.logs,
+						Err(e) => {
+							log::debug!("Failed to read the duties of simulated agent {}: {}", index, e);
+							continue;
+						},
+					};
+					for log_id in pending {
+						stats.note(&stats.signatures, client.sign_log(&key, log_id).await);
+					}
+				},
+			}
+		}
+	}
+}
+
+/// Connect to `url`, retrying while a launched chain starts up.
+async fn connect(url: &str) -> csuite_agent_sdk::Result<Client> {
+	let mut attempts = 0;
+	loop {
+		match Client::connect(url).await {
+			Ok(client) => return Ok(client),
+			Err(_) if attempts < STARTUP_TIMEOUT => {
+				attempts += 1;
+				tokio::time::sleep(Duration::from_secs(1)).await;
+			},
+			Err(e) => return Err(e),
+		}
+	}
+}
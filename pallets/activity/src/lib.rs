@@ -0,0 +1,116 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        lib.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Per-agent recent activity feed pallet for C-Suite blockchain
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! # Agent Activity Pallet
+//!
+//! A lightweight index of what each C-Suite agent has recently done on-chain.
+//!
+//! ## Overview
+//!
+//! This pallet keeps, for every agent, a bounded list of compact activity entries:
+//! - Consensus logs the agent submitted
+//! - Consensus logs the agent signed
+//! - Slashes applied to the agent
+//! - Consensus rewards paid to the agent
+//!
+//! Entries are appended by other pallets through [`Pallet::record`], which the runtime wires
+//! into their hook traits. Once an agent's feed is full the oldest entry is dropped, so a
+//! dashboard can show the recent-activity feed of an agent with a single storage query.
+//!
+//! The pallet has no extrinsics and emits no events.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// Maximum number of entries kept in each agent's feed
+        #[pallet::constant]
+        type MaxEntriesPerAgent: Get<u32>;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Kind of activity recorded for an agent
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum ActivityKind<Hash> {
+        /// The agent submitted a consensus log or insight
+        LogSubmitted { log_id: Hash },
+        /// The agent signed a consensus log
+        LogSigned { log_id: Hash },
+        /// The agent was slashed for an offense
+        Slashed { reputation_penalty: u64 },
+        /// The agent was rewarded for taking part in consensus
+        Rewarded { reputation_reward: u64 },
+    }
+
+    /// A single entry in an agent's activity feed
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct ActivityEntry<T: Config> {
+        /// What happened
+        pub kind: ActivityKind<T::Hash>,
+        /// Block in which it happened
+        pub block: BlockNumberFor<T>,
+    }
+
+    /// Recent activity of each agent, oldest first
+    #[pallet::storage]
+    #[pallet::getter(fn recent_activity)]
+    pub type RecentActivity<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<ActivityEntry<T>, T::MaxEntriesPerAgent>,
+        ValueQuery,
+    >;
+
+    // Helper functions
+    impl<T: Config> Pallet<T> {
+        /// Append an entry to an agent's feed, dropping the oldest entry if the feed is full
+        pub fn record(agent_id: &T::AccountId, kind: ActivityKind<T::Hash>) {
+            let entry = ActivityEntry {
+                kind,
+                block: <frame_system::Pallet<T>>::block_number(),
+            };
+
+            RecentActivity::<T>::mutate(agent_id, |feed| {
+                if T::MaxEntriesPerAgent::get() == 0 {
+                    return;
+                }
+                if feed.is_full() {
+                    feed.remove(0);
+                }
+                let _ = feed.try_push(entry);
+            });
+        }
+
+        /// Weight of a single [`Self::record`] call
+        pub fn record_weight() -> Weight {
+            T::DbWeight::get().reads_writes(1, 1)
+        }
+    }
+}
@@ -0,0 +1,53 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        tests.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Tests for the agent activity pallet
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+use crate::{mock::*, ActivityEntry, ActivityKind};
+use sp_core::H256;
+
+#[test]
+fn record_appends_entries_with_block_numbers() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(3);
+        Activity::record(&1, ActivityKind::LogSubmitted { log_id: H256::repeat_byte(1) });
+        System::set_block_number(4);
+        Activity::record(&1, ActivityKind::Rewarded { reputation_reward: 10 });
+
+        let feed = Activity::recent_activity(1);
+        assert_eq!(feed.to_vec(), vec![
+            ActivityEntry { kind: ActivityKind::LogSubmitted { log_id: H256::repeat_byte(1) }, block: 3 },
+            ActivityEntry { kind: ActivityKind::Rewarded { reputation_reward: 10 }, block: 4 },
+        ]);
+
+        // Feeds are per agent
+        assert!(Activity::recent_activity(2).is_empty());
+    });
+}
+
+#[test]
+fn full_feed_drops_oldest_entry() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        for penalty in 1..=4 {
+            Activity::record(&1, ActivityKind::Slashed { reputation_penalty: penalty });
+        }
+
+        let penalties: Vec<_> = Activity::recent_activity(1)
+            .iter()
+            .map(|entry| match entry.kind {
+                ActivityKind::Slashed { reputation_penalty } => reputation_penalty,
+                _ => panic!("unexpected activity"),
+            })
+            .collect();
+        assert_eq!(penalties, vec![2, 3, 4]);
+    });
+}
@@ -7,7 +7,7 @@
  *  Description: Benchmarking for the Agent Registry pallet
  *  Version:     1.0.0
  *  License:     BSL (SPDX id BUSL)
- *  Last Update: (May 2025)
+ *  Last Update: (October 2026)
  * ----------------------------------------------------------------------------
  */
 
@@ -18,76 +18,457 @@
 use super::*;
 use crate::Pallet as AgentRegistry;
 use frame_benchmarking::v2::*;
+use codec::Encode;
+use frame_support::{
+    sp_runtime::{traits::{BlakeTwo256, Saturating}, Percent},
+    Hashable,
+};
 use frame_system::RawOrigin;
-use sp_std::vec;
+use sp_std::{vec, vec::Vec};
+use sp_trie::TrieMut;
 
-// Helper function to generate a role name based on an index
-fn role_name(i: u32) -> Vec<u8> {
-    let mut name = b"Agent_".to_vec();
-    name.extend_from_slice(i.to_string().as_bytes());
-    name
+// Helper function to generate metadata of the given length
+fn metadata(len: u32) -> Vec<u8> {
+    vec![b'M'; len as usize]
 }
 
-// Helper function to generate metadata based on an index
-fn metadata(i: u32) -> Vec<u8> {
-    let mut meta = b"Version: 1.0.".to_vec();
-    meta.extend_from_slice(i.to_string().as_bytes());
-    meta.extend_from_slice(b", Type: Benchmark".to_slice());
-    meta
+// Helper function to register a funded agent
+fn registered_agent<T: Config>(name: &'static str) -> T::AccountId {
+    let agent: T::AccountId = account(name, 0, 0);
+    let funds = T::MetadataTierDeposit::get().saturating_mul(T::MaxMetadataAllowance::get().into());
+    T::Currency::make_free_balance_be(&agent, funds.saturating_add(1_000_000u32.into()));
+    AgentRegistry::<T>::register_agent(
+        RawOrigin::Signed(agent.clone()).into(),
+        AgentRole::Lyra,
+        Some(metadata(T::MaxMetadataLength::get())),
+    ).expect("Agent should be registered");
+    agent
+}
+
+// Helper function to build the storage of a sister chain holding the registry entry of `agent`
+// and a value of `filler` bytes, returning its root and all of its nodes as a proof
+fn remote_agent_proof<T: Config>(
+    agents_prefix: [u8; 32],
+    agent: &T::AccountId,
+    filler: u32,
+) -> ([u8; 32], Vec<Vec<u8>>) {
+    let mut db = sp_trie::MemoryDB::<BlakeTwo256>::default();
+    let mut root = Default::default();
+    {
+        let mut trie = sp_trie::trie_types::TrieDBMutBuilderV1::<BlakeTwo256>::new(&mut db, &mut root).build();
+        let mut key = agents_prefix.to_vec();
+        key.extend(agent.blake2_128_concat());
+        let info = AgentInfo::<T> {
+            pubkey: agent.clone(),
+            role: AgentRole::Lyra,
+            trust_score: 0,
+            status: AgentStatus::Online,
+            registered_at: 0u32.into(),
+            metadata: None,
+            signature_scheme: SignatureScheme::Sr25519,
+        };
+        trie.insert(&key, &info.encode()).expect("Agent should be inserted");
+        trie.insert(b"filler", &vec![1u8; filler.max(1) as usize]).expect("Filler should be inserted");
+    }
+    (root.into(), db.drain().into_values().map(|(node, _)| node).collect())
+}
+
+// Helper function to declare the maximum number of one-block maintenance windows for an agent,
+// returning the block after the last one
+fn max_maintenance_windows<T: Config>(agent: &T::AccountId) -> frame_system::pallet_prelude::BlockNumberFor<T> {
+    let mut starts_at = frame_system::Pallet::<T>::block_number();
+    for _ in 0..T::MaxMaintenanceWindows::get() {
+        starts_at = starts_at.saturating_add(1u32.into());
+        AgentRegistry::<T>::declare_maintenance(
+            RawOrigin::Signed(agent.clone()).into(),
+            starts_at,
+            starts_at.saturating_add(1u32.into()),
+        ).expect("Maintenance should be declared");
+        starts_at = starts_at.saturating_add(1u32.into());
+    }
+    starts_at
+}
+
+// Helper function to give an agent the maximum number of proxy keys
+fn add_proxies<T: Config>(agent: &T::AccountId) {
+    let classes = CallClasses::default().with(CallClass::Heartbeat).with(CallClass::Signing);
+    for i in 0..T::MaxProxies::get() {
+        AgentRegistry::<T>::add_proxy(RawOrigin::Signed(agent.clone()).into(), account("proxy", i, 0), classes)
+            .expect("Proxy should be added");
+    }
+}
+
+// Helper function to buy the largest metadata allowance for an agent
+fn max_allowance<T: Config>(agent: &T::AccountId) {
+    let extra = T::MaxMetadataAllowance::get().saturating_sub(T::MaxMetadataLength::get());
+    let tier_bytes = T::MetadataTierBytes::get().max(1);
+    let bytes = extra / tier_bytes * tier_bytes;
+    if bytes > 0 {
+        AgentRegistry::<T>::extend_metadata_allowance(RawOrigin::Signed(agent.clone()).into(), bytes)
+            .expect("Allowance should be extended");
+    }
+}
+
+// Helper function to announce the maximum number of endpoints of the maximum length
+fn endpoints<T: Config>(count: u32) -> Vec<(EndpointKind, Vec<u8>)> {
+    let mut address = b"https://".to_vec();
+    address.resize(T::MaxEndpointLength::get().max(9) as usize, b'a');
+    (0..count).map(|_| (EndpointKind::Https, address.clone())).collect()
 }
 
 #[benchmarks]
 mod benchmarks {
     use super::*;
 
+    // Linear in the length of the metadata
     #[benchmark]
-    fn register_agent() {
+    fn register_agent(m: Linear<0, { T::MaxMetadataLength::get() }>) {
         let caller: T::AccountId = whitelisted_caller();
-        let role = role_name(1);
-        let meta = metadata(1);
 
         #[extrinsic_call]
-        AgentRegistry::<T>::register_agent(RawOrigin::Signed(caller), role, Some(meta));
+        _(RawOrigin::Signed(caller.clone()), AgentRole::Lyra, Some(metadata(m)));
+
+        assert!(AgentRegistry::<T>::agents(&caller).is_some());
     }
 
     #[benchmark]
     fn update_status() {
-        let caller: T::AccountId = whitelisted_caller();
-        let role = role_name(2);
-        
-        // Register the agent first
-        AgentRegistry::<T>::register_agent(RawOrigin::Signed(caller.clone()).into(), role, None)
-            .expect("Agent should be registered");
+        let agent = registered_agent::<T>("agent");
 
         #[extrinsic_call]
-        AgentRegistry::<T>::update_status(RawOrigin::Signed(caller), AgentStatus::Maintenance);
+        _(RawOrigin::Signed(agent.clone()), AgentStatus::Maintenance);
+
+        assert_eq!(AgentRegistry::<T>::agents(&agent).unwrap().status, AgentStatus::Maintenance);
     }
 
+    // Linear in the length of the metadata, up to the largest allowance an agent can buy
     #[benchmark]
-    fn update_metadata() {
-        let caller: T::AccountId = whitelisted_caller();
-        let role = role_name(3);
-        let meta = metadata(3);
-        
-        // Register the agent first
-        AgentRegistry::<T>::register_agent(RawOrigin::Signed(caller.clone()).into(), role, None)
-            .expect("Agent should be registered");
+    fn update_metadata(m: Linear<1, { T::MaxMetadataAllowance::get() }>) {
+        let agent = registered_agent::<T>("agent");
+        max_allowance::<T>(&agent);
+        let m = m.min(AgentRegistry::<T>::metadata_limit(&agent));
 
         #[extrinsic_call]
-        AgentRegistry::<T>::update_metadata(RawOrigin::Signed(caller), meta);
+        _(RawOrigin::Signed(agent.clone()), metadata(m));
+
+        assert_eq!(AgentRegistry::<T>::agents(&agent).unwrap().metadata.unwrap().len(), m as usize);
     }
 
     #[benchmark]
     fn update_trust_score() {
+        let agent = registered_agent::<T>("agent");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(agent.clone()), agent.clone(), 10);
+
+        assert_eq!(AgentRegistry::<T>::agents(&agent).unwrap().trust_score, 10);
+    }
+
+    #[benchmark]
+    fn heartbeat() {
+        let agent = registered_agent::<T>("agent");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(agent.clone()));
+
+        assert!(AgentRegistry::<T>::last_seen(&agent).is_some());
+    }
+
+    #[benchmark]
+    fn force_set_status() {
+        let agent = registered_agent::<T>("agent");
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, agent.clone(), AgentStatus::Retired);
+
+        assert_eq!(AgentRegistry::<T>::agents(&agent).unwrap().status, AgentStatus::Retired);
+    }
+
+    #[benchmark]
+    fn initiate_retirement() {
+        let agent = registered_agent::<T>("agent");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(agent.clone()));
+
+        assert!(AgentRegistry::<T>::is_retiring(&agent));
+    }
+
+    // Worst case: every proxy key and the largest metadata allowance have to be released
+    #[benchmark]
+    fn retire_complete() {
+        let agent = registered_agent::<T>("agent");
+        add_proxies::<T>(&agent);
+        max_allowance::<T>(&agent);
+        AgentRegistry::<T>::initiate_retirement(RawOrigin::Signed(agent.clone()).into())
+            .expect("Retirement should be initiated");
+        let completes_at = AgentRegistry::<T>::retiring(&agent).unwrap();
+        frame_system::Pallet::<T>::set_block_number(completes_at);
         let caller: T::AccountId = whitelisted_caller();
-        let role = role_name(4);
-        
-        // Register the agent first
-        AgentRegistry::<T>::register_agent(RawOrigin::Signed(caller.clone()).into(), role, None)
-            .expect("Agent should be registered");
 
         #[extrinsic_call]
-        AgentRegistry::<T>::update_trust_score(RawOrigin::Signed(caller.clone()), caller, 10);
+        _(RawOrigin::Signed(caller), agent.clone());
+
+        assert_eq!(AgentRegistry::<T>::agents(&agent).unwrap().status, AgentStatus::Retired);
+    }
+
+    // Worst case: every piece of per-agent state exists and has to move
+    #[benchmark]
+    fn rotate_agent_key() {
+        let agent = registered_agent::<T>("agent");
+        add_proxies::<T>(&agent);
+        max_allowance::<T>(&agent);
+        AgentRegistry::<T>::heartbeat(RawOrigin::Signed(agent.clone()).into())
+            .expect("Heartbeat should be accepted");
+        AgentRegistry::<T>::attest_version(RawOrigin::Signed(agent.clone()).into(), (1, 0, 0), Default::default())
+            .expect("Version should be attested");
+        AgentRegistry::<T>::announce_endpoints(
+            RawOrigin::Signed(agent.clone()).into(),
+            endpoints::<T>(T::MaxEndpoints::get()),
+        ).expect("Endpoints should be announced");
+        for i in 0..T::MaxCapabilities::get() {
+            let mut capability = *b"capab\0\0\0";
+            capability[7] = i as u8;
+            AgentRegistry::<T>::grant_capability(RawOrigin::Root.into(), agent.clone(), capability)
+                .expect("Capability should be granted");
+        }
+        max_maintenance_windows::<T>(&agent);
+        let new_agent: T::AccountId = account("new_agent", 0, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(agent.clone()), new_agent.clone());
+
+        assert!(AgentRegistry::<T>::agents(&agent).is_none());
+        assert!(AgentRegistry::<T>::agents(&new_agent).is_some());
+    }
+
+    #[benchmark]
+    fn extend_metadata_allowance() {
+        let agent = registered_agent::<T>("agent");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(agent.clone()), T::MetadataTierBytes::get());
+
+        assert!(AgentRegistry::<T>::metadata_allowances(&agent).extra_bytes > 0);
+    }
+
+    // Worst case: a minimum version is set, so the attestation is checked against it
+    #[benchmark]
+    fn attest_version() {
+        let agent = registered_agent::<T>("agent");
+        AgentRegistry::<T>::set_minimum_agent_version(RawOrigin::Root.into(), Some((2, 0, 0)))
+            .expect("Minimum version should be set");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(agent.clone()), (1, 0, 0), Default::default());
+
+        assert!(AgentRegistry::<T>::agent_versions(&agent).is_some());
+    }
+
+    #[benchmark]
+    fn set_minimum_agent_version() {
+        #[extrinsic_call]
+        _(RawOrigin::Root, Some((1, 0, 0)));
+
+        assert_eq!(AgentRegistry::<T>::minimum_agent_version(), Some((1, 0, 0)));
+    }
+
+    // Worst case: the agent already has all but one proxy key, which are searched for the new key
+    #[benchmark]
+    fn add_proxy() {
+        let agent = registered_agent::<T>("agent");
+        let classes = CallClasses::default().with(CallClass::Heartbeat);
+        for i in 1..T::MaxProxies::get() {
+            AgentRegistry::<T>::add_proxy(RawOrigin::Signed(agent.clone()).into(), account("proxy", i, 0), classes)
+                .expect("Proxy should be added");
+        }
+        let proxy: T::AccountId = account("proxy", 0, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(agent.clone()), proxy.clone(), classes);
+
+        assert_eq!(AgentRegistry::<T>::proxy_of(&proxy), Some(agent));
+    }
+
+    // Worst case: the removed key is the last of a full set
+    #[benchmark]
+    fn remove_proxy() {
+        let agent = registered_agent::<T>("agent");
+        add_proxies::<T>(&agent);
+        let proxy: T::AccountId = account("proxy", T::MaxProxies::get().saturating_sub(1), 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(agent.clone()), proxy.clone());
+
+        assert!(AgentRegistry::<T>::proxy_of(&proxy).is_none());
+    }
+
+    // Worst case: the agent already has all but one capability
+    #[benchmark]
+    fn grant_capability() {
+        let agent = registered_agent::<T>("agent");
+        for i in 1..T::MaxCapabilities::get() {
+            let mut capability = *b"capab\0\0\0";
+            capability[7] = i as u8;
+            AgentRegistry::<T>::grant_capability(RawOrigin::Root.into(), agent.clone(), capability)
+                .expect("Capability should be granted");
+        }
+        let capability = *b"capab\0\0\0";
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, agent.clone(), capability);
+
+        assert!(AgentRegistry::<T>::capabilities(&agent).contains(&capability));
+    }
+
+    // Worst case: the revoked capability is the last of a full set
+    #[benchmark]
+    fn revoke_capability() {
+        let agent = registered_agent::<T>("agent");
+        let mut capability = *b"capab\0\0\0";
+        for i in 0..T::MaxCapabilities::get() {
+            capability[7] = i as u8;
+            AgentRegistry::<T>::grant_capability(RawOrigin::Root.into(), agent.clone(), capability)
+                .expect("Capability should be granted");
+        }
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, agent.clone(), capability);
+
+        assert!(!AgentRegistry::<T>::capabilities(&agent).contains(&capability));
+    }
+
+    // Linear in the number of endpoints, each of the maximum length
+    #[benchmark]
+    fn announce_endpoints(e: Linear<1, { T::MaxEndpoints::get() }>) {
+        let agent = registered_agent::<T>("agent");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(agent.clone()), endpoints::<T>(e));
+
+        assert_eq!(AgentRegistry::<T>::address_book(&agent).unwrap().endpoints.len(), e as usize);
+    }
+
+    // Worst case: the history is full, so the oldest report is dropped
+    #[benchmark]
+    fn report_telemetry() {
+        let agent = registered_agent::<T>("agent");
+        let interval = T::TelemetryInterval::get();
+        for _ in 0..T::MaxTelemetrySnapshots::get() {
+            AgentRegistry::<T>::report_telemetry(
+                RawOrigin::Signed(agent.clone()).into(),
+                Percent::from_percent(10),
+                Percent::from_percent(10),
+                1,
+            ).expect("Telemetry should be reported");
+            let now = frame_system::Pallet::<T>::block_number();
+            frame_system::Pallet::<T>::set_block_number(now.saturating_add(interval));
+        }
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(agent.clone()), Percent::from_percent(95), Percent::from_percent(95), 1_000);
+
+        assert_eq!(AgentRegistry::<T>::telemetry(&agent).last().unwrap().queue_depth, 1_000);
+    }
+
+    // Worst case: the agent has every window, so the oldest one is dropped, and the new window
+    // is checked against all of them
+    #[benchmark]
+    fn declare_maintenance() {
+        let agent = registered_agent::<T>("agent");
+        let after_last = max_maintenance_windows::<T>(&agent);
+        frame_system::Pallet::<T>::set_block_number(after_last);
+        let starts_at = after_last.saturating_add(1u32.into());
+        let ends_at = starts_at.saturating_add(T::MaxMaintenanceDuration::get());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(agent.clone()), starts_at, ends_at);
+
+        assert_eq!(AgentRegistry::<T>::maintenance_windows(&agent).last().unwrap().starts_at, starts_at);
+    }
+
+    #[benchmark]
+    fn approve_maintenance() {
+        let agent = registered_agent::<T>("agent");
+        let after_last = max_maintenance_windows::<T>(&agent);
+        let starts_at = after_last.saturating_sub(2u32.into());
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, agent.clone(), starts_at);
+
+        assert!(AgentRegistry::<T>::maintenance_windows(&agent).last().unwrap().approved);
+    }
+
+    #[benchmark]
+    fn set_signature_scheme() {
+        let agent = registered_agent::<T>("agent");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(agent.clone()), SignatureScheme::Ecdsa);
+
+        assert_eq!(AgentRegistry::<T>::agents(&agent).unwrap().signature_scheme, SignatureScheme::Ecdsa);
+    }
+
+    #[benchmark]
+    fn set_global_features() {
+        #[extrinsic_call]
+        _(RawOrigin::Root, FeatureFlags::all());
+
+        assert_eq!(AgentRegistry::<T>::global_features(), FeatureFlags::all());
+    }
+
+    #[benchmark]
+    fn set_agent_features() {
+        let agent = registered_agent::<T>("agent");
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, agent.clone(), FeatureFlags::all());
+
+        assert_eq!(AgentRegistry::<T>::agent_features(&agent), FeatureFlags::all());
+    }
+
+    #[benchmark]
+    fn set_remote_registry() {
+        #[extrinsic_call]
+        _(RawOrigin::Root, 2_000, Some([1u8; 32]));
+
+        assert!(AgentRegistry::<T>::remote_registries(2_000).is_some());
+    }
+
+    #[benchmark]
+    fn note_remote_state_root() -> Result<(), BenchmarkError> {
+        let origin = T::RemoteOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let para_id = T::RemoteOrigin::ensure_origin(origin.clone()).map_err(|_| BenchmarkError::Weightless)?;
+        AgentRegistry::<T>::set_remote_registry(RawOrigin::Root.into(), para_id, Some([1u8; 32]))?;
+        let state_root = RemoteStateRoot { block_number: 1, root: [2u8; 32] };
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, state_root);
+
+        assert_eq!(AgentRegistry::<T>::remote_registries(para_id).unwrap().state_root, Some(state_root));
+        Ok(())
+    }
+
+    // The proof is padded with an unrelated value so that it holds about `p` bytes, leaving
+    // room for the nodes of the agent's entry
+    #[benchmark]
+    fn verify_remote_agent(
+        p: Linear<1, { T::MaxRemoteProofSize::get().saturating_sub(1_024) }>,
+    ) -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let remote: T::AccountId = account("remote", 0, 0);
+        let origin = T::RemoteOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let para_id = T::RemoteOrigin::ensure_origin(origin.clone()).map_err(|_| BenchmarkError::Weightless)?;
+        let agents_prefix = [1u8; 32];
+        let (root, proof) = remote_agent_proof::<T>(agents_prefix, &remote, p);
+        AgentRegistry::<T>::set_remote_registry(RawOrigin::Root.into(), para_id, Some(agents_prefix))?;
+        AgentRegistry::<T>::note_remote_state_root(origin, RemoteStateRoot { block_number: 1, root })?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), para_id, remote.clone(), proof);
+
+        assert!(AgentRegistry::<T>::agent_aliases(&remote, para_id).is_some());
+        Ok(())
     }
 
     impl_benchmark_test_suite!(
@@ -95,4 +476,4 @@ mod benchmarks {
         crate::mock::new_test_ext(),
         crate::mock::Test,
     );
-} 
\ No newline at end of file
+}
@@ -19,16 +19,138 @@
 //!
 //! This pallet provides functionality to:
 //! - Register new C-Suite agents with roles, public keys, and metadata
-//! - Update agent status (online/offline/retired)
+//! - Update agent status (online/offline/maintenance/retired) along a validated transition
+//!   table, with a governance override for exceptional cases
 //! - Query agent information
 //! - Track agent trust scores
 //!
 //! Each agent in the C-Suite system (Lyra, Echo, Verdict, etc.) is registered on-chain
-//! with their role, public key, and current status.
+//! with their role, public key, and current status. Other pallets query the registry through
+//! the [`csuite_primitives::AgentInspector`] and [`csuite_primitives::AgentStatusProvider`]
+//! implementations on [`Pallet`], so they do not need to depend on its `Config`.
+//!
+//! ## Roles
+//!
+//! Agents register with an [`AgentRole`]: one of the canonical C-Suite roles, each with a fixed
+//! numeric ID, or a custom role with an ID of at least
+//! [`FIRST_CUSTOM_ROLE_ID`](csuite_primitives::FIRST_CUSTOM_ROLE_ID). `AgentsByRole` indexes
+//! registered agents by role, and [`Pallet::agents_by_role`] lists the agents holding one,
+//! including retired agents.
+//!
+//! ## Retirement
+//!
+//! An agent leaves with `initiate_retirement`, which takes it offline and notifies
+//! [`OnRetirement`] so that other pallets can block new stake and start unbonding. Its logs and
+//! records stay readable, and it can still be held to account, for `RetirementGracePeriod`
+//! blocks. After that anyone may call `retire_complete` to mark the agent retired and let other
+//! pallets free its deposits and compact their indexes.
+//!
+//! ## Metadata Allowances
+//!
+//! Every agent may store up to `MaxMetadataLength` bytes of metadata. Agents that need more buy
+//! an allowance with `extend_metadata_allowance`, in tiers of `MetadataTierBytes` bytes that each
+//! reserve `MetadataTierDeposit`, up to `MaxMetadataAllowance` bytes in total. The deposit moves
+//! with the agent on key rotation and is returned when its retirement completes.
+//!
+//! ## Versions
+//!
+//! Agents attest the software they run with `attest_version`, giving a semantic version and the
+//! hash of their code. Governance coordinates upgrades by setting `MinimumAgentVersion`: agents
+//! below it, or without an attestation, are warned with `AgentBelowMinimumVersion` whenever they
+//! attest or send a heartbeat, and [`Pallet::meets_minimum_version`] lets other pallets exclude
+//! them, for example from committee selection.
+//!
+//! ## Key Rotation
+//!
+//! An agent moves to a new account with `rotate_agent_key`. Its registry entry is moved and
+//! [`OnAgentRotation`] lets other pallets move stake reserves and per-agent state along with it.
+//! The rotation runs in its own storage layer: if any pallet fails to migrate its part, nothing
+//! is moved and the agent stays on its old account.
+//!
+//! ## Proxy Keys
+//!
+//! An agent can keep its main key offline for routine work by adding up to `MaxProxies`
+//! operational proxy keys with `add_proxy`, each limited to a set of [`CallClass`]es: heartbeats
+//! and signing consensus logs. A proxy, which may also be a multisig account, acts for exactly
+//! one agent and cannot register as an agent itself. Registration, staking, status changes, key
+//! rotation and proxy management stay with the main key. Other pallets resolve proxies through
+//! [`csuite_primitives::AgentInspector::proxied_agent`].
+//!
+//! ## Capabilities
+//!
+//! Governance grants agents [`Capability`] tags with `grant_capability`, up to `MaxCapabilities`
+//! per agent, and revokes them with `revoke_capability`. Other pallets restrict calls to agents
+//! with a capability through the [`EnsureAgentWithCapability`] origin check, or query it through
+//! [`csuite_primitives::AgentInspector::has_capability`]. Capabilities move with the agent on key
+//! rotation, stop counting once it starts retiring and are removed when its retirement completes.
+//!
+//! ## Feature Flags
+//!
+//! Experimental extrinsics, such as FROST signing or submissions at a non-`Normal` priority, are
+//! gated on a [`Feature`]. Governance enables features for every agent with
+//! `set_global_features`, starting from `DefaultFeatures`, and for individual agents on top of
+//! that with `set_agent_features`, so a feature can be rolled out to a subset of agents first.
+//! Other pallets check them at dispatch through
+//! [`csuite_primitives::AgentInspector::has_feature`]. Per-agent features move with the agent on
+//! key rotation and are removed when its retirement completes.
+//!
+//! ## Address Book
+//!
+//! Agents publish how they can be reached for off-chain coordination with `announce_endpoints`:
+//! up to `MaxEndpoints` libp2p multiaddresses or HTTPS URLs. Announcements are signed
+//! extrinsics, so every entry is attested by the agent's own key. An announcement expires after
+//! `EndpointTtl` blocks unless the agent announces again, which it may do at most once every
+//! `AnnouncementCooldown` blocks. [`Pallet::live_endpoints`] returns only unexpired entries.
+//!
+//! ## Telemetry
+//!
+//! Agents may report their load with `report_telemetry`: CPU load, memory usage and the depth
+//! of their work queue. Reporting is optional and limited to once every `TelemetryInterval`
+//! blocks; the latest `MaxTelemetrySnapshots` reports of each agent are kept. An agent whose
+//! latest report is younger than `TelemetryTtl` blocks and reaches `OverloadThreshold` or
+//! `OverloadQueueDepth` counts as overloaded, which [`Pallet::is_overloaded`] lets other pallets
+//! take into account, for example to pick other agents for a committee first.
+//!
+//! ## Maintenance Windows
+//!
+//! An agent announces planned downtime with `declare_maintenance`, giving the blocks a window
+//! starts and ends at. Windows last at most `MaxMaintenanceDuration` blocks, may not overlap, and
+//! each agent keeps at most `MaxMaintenanceWindows` of them; the oldest finished window makes
+//! room for a new one. Governance approves a window with `approve_maintenance`. Other pallets
+//! read approved windows through the [`csuite_primitives::MaintenanceSchedule`] implementation,
+//! for example to pause reputation decay while an agent is in maintenance.
+//!
+//! ## Remote Agents
+//!
+//! Governance trusts the agent registry of a sister parachain with `set_remote_registry`, giving
+//! the storage key prefix of its `Agents` map. The sister chain then sends its state roots over
+//! XCM with `note_remote_state_root`, and anyone may call `verify_remote_agent` with a storage
+//! proof of an agent's entry against the latest root. A proven registration is recorded in
+//! `AgentAliases`, which lets other pallets extend trust to agents of sister chains;
+//! [`Pallet::remote_chains`] lists the chains an account is an agent on.
+//!
+//! ## Signature Schemes
+//!
+//! Agents sign consensus logs with sr25519 keys unless they choose another
+//! [`SignatureScheme`] with `set_signature_scheme`: ed25519 or secp256k1 ECDSA. Other pallets
+//! read the scheme through [`csuite_primitives::AgentInspector::signature_scheme`] to verify
+//! an agent's signatures. The scheme moves with the agent on key rotation, so an agent rotating
+//! to a key of another type sets its scheme again from the new key.
+//!
+//! ## Stale Agents
+//!
+//! Agents that stay online or in maintenance without a heartbeat for `StaleAfter` blocks are
+//! taken offline by a sweep in `on_idle`, with an `AgentMarkedStale` event for each. The sweep
+//! visits at most `MaxSweepPerBlock` agents per block, and only as many as the block's idle
+//! weight allows, continuing from `SweepCursor` where the previous block stopped.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use pallet::*;
+pub use csuite_primitives::{
+    AgentRole, CallClass, CallClasses, Capability, Feature, FeatureFlags, FeeExemption, MaintenanceSchedule,
+    SignatureScheme,
+};
 
 #[cfg(test)]
 mod mock;
@@ -39,29 +161,220 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
+
 pub mod weights;
 
+pub use weights::WeightInfo;
+
+use frame_support::{
+    dispatch::DispatchResult,
+    traits::{BalanceStatus, Currency, EnsureOrigin, Get, ReservableCurrency},
+    weights::Weight,
+};
+use frame_system::RawOrigin;
+
+/// Notified when an agent starts and finishes retiring
+pub trait OnRetirement<AccountId> {
+    /// Called when an agent initiated its retirement
+    fn on_retirement_initiated(agent_id: &AccountId);
+
+    /// Called when an agent's retirement grace period ended and it was retired
+    fn on_retirement_completed(agent_id: &AccountId);
+
+    /// Upper bound on the weight of `on_retirement_initiated`
+    fn on_retirement_initiated_weight() -> Weight {
+        Weight::zero()
+    }
+
+    /// Upper bound on the weight of `on_retirement_completed`
+    fn on_retirement_completed_weight() -> Weight {
+        Weight::zero()
+    }
+}
+
+impl<AccountId> OnRetirement<AccountId> for () {
+    fn on_retirement_initiated(_agent_id: &AccountId) {}
+    fn on_retirement_completed(_agent_id: &AccountId) {}
+}
+
+/// Notified whenever an agent's status changes
+pub trait OnStatusChange<AccountId> {
+    /// Called after `agent_id` moved from status `old` to `new`
+    fn on_status_changed(agent_id: &AccountId, old: &AgentStatus, new: &AgentStatus);
+
+    /// Upper bound on the weight of `on_status_changed`
+    fn on_status_changed_weight() -> Weight {
+        Weight::zero()
+    }
+}
+
+impl<AccountId> OnStatusChange<AccountId> for () {
+    fn on_status_changed(_agent_id: &AccountId, _old: &AgentStatus, _new: &AgentStatus) {}
+}
+
+/// Semantic version of an agent's software as `(major, minor, patch)`
+pub type AgentVersion = (u16, u16, u16);
+
+/// Identifier of a sister parachain
+pub type ParaId = u32;
+
+/// Notified when an agent moves to a new account
+pub trait OnAgentRotation<AccountId> {
+    /// Move everything held for `old` over to `new`
+    ///
+    /// Returning an error aborts the rotation and reverts all changes made for it.
+    fn on_agent_rotated(old: &AccountId, new: &AccountId) -> DispatchResult;
+
+    /// Upper bound on the weight of `on_agent_rotated`
+    fn on_agent_rotated_weight() -> Weight {
+        Weight::zero()
+    }
+}
+
+impl<AccountId> OnAgentRotation<AccountId> for () {
+    fn on_agent_rotated(_old: &AccountId, _new: &AccountId) -> DispatchResult {
+        Ok(())
+    }
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::pallet_prelude::*;
     use frame_system::pallet_prelude::*;
     use sp_std::vec::Vec;
+    use frame_support::{
+        sp_runtime::{traits::{BlakeTwo256, Hash as HashT, Saturating, Zero}, Percent},
+        Hashable,
+    };
+    use super::{
+        AgentRole, AgentVersion, BalanceStatus, CallClass, CallClasses, Capability, Currency, Feature,
+        FeatureFlags, FeeExemption, MaintenanceSchedule, OnAgentRotation, OnRetirement, OnStatusChange, ParaId, ReservableCurrency, WeightInfo,
+    };
+
+    type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
         /// The overarching event type.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         
-        /// Maximum length for agent role string
+        /// Metadata length every agent may use without buying an allowance
+        #[pallet::constant]
+        type MaxMetadataLength: Get<u32>;
+
+        /// Upper bound on any agent's metadata length, including purchased allowances
+        #[pallet::constant]
+        type MaxMetadataAllowance: Get<u32>;
+
+        /// Number of bytes in one metadata allowance tier
+        #[pallet::constant]
+        type MetadataTierBytes: Get<u32>;
+
+        /// Deposit reserved for every metadata allowance tier
         #[pallet::constant]
-        type MaxRoleLength: Get<u32>;
+        type MetadataTierDeposit: Get<BalanceOf<Self>>;
+
+        /// The currency metadata allowance deposits are reserved in
+        type Currency: ReservableCurrency<Self::AccountId>;
         
-        /// Maximum length for agent metadata
+        /// Policy deciding which routine calls (heartbeats, signatures) are fee-exempt
+        type FeeExemption: FeeExemption<Self::AccountId>;
+
+        /// Number of blocks between initiating and completing an agent's retirement
         #[pallet::constant]
-        type MaxMetadataLength: Get<u32>;
+        type RetirementGracePeriod: Get<BlockNumberFor<Self>>;
+
+        /// Hook for pallets holding stake, deposits or indexes of retiring agents
+        type OnRetirement: OnRetirement<Self::AccountId>;
+
+        /// Hook for pallets holding stake or state of agents that move to a new account
+        type OnRotation: OnAgentRotation<Self::AccountId>;
+
+        /// Hook notified of every agent status change, e.g. to keep an audit trail
+        type OnStatusChange: OnStatusChange<Self::AccountId>;
+
+        /// Maximum number of endpoints in one announcement
+        #[pallet::constant]
+        type MaxEndpoints: Get<u32>;
+
+        /// Maximum length of an endpoint address
+        #[pallet::constant]
+        type MaxEndpointLength: Get<u32>;
+
+        /// Number of blocks an endpoint announcement stays valid
+        #[pallet::constant]
+        type EndpointTtl: Get<BlockNumberFor<Self>>;
+
+        /// Minimum number of blocks between two announcements of the same agent
+        #[pallet::constant]
+        type AnnouncementCooldown: Get<BlockNumberFor<Self>>;
+
+        /// Number of blocks without a heartbeat after which an agent is taken offline, or zero
+        /// to never sweep stale agents
+        #[pallet::constant]
+        type StaleAfter: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of agents the stale sweep visits in one block
+        #[pallet::constant]
+        type MaxSweepPerBlock: Get<u32>;
+
+        /// Maximum number of operational proxy keys per agent
+        #[pallet::constant]
+        type MaxProxies: Get<u32>;
+
+        /// Maximum number of capabilities granted to one agent
+        #[pallet::constant]
+        type MaxCapabilities: Get<u32>;
+
+        /// Features enabled for all agents until governance sets them with `set_global_features`
+        #[pallet::constant]
+        type DefaultFeatures: Get<FeatureFlags>;
+
+        /// Number of telemetry reports kept per agent
+        #[pallet::constant]
+        type MaxTelemetrySnapshots: Get<u32>;
+
+        /// Minimum number of blocks between two telemetry reports of the same agent
+        #[pallet::constant]
+        type TelemetryInterval: Get<BlockNumberFor<Self>>;
+
+        /// Number of blocks a telemetry report counts towards an agent being overloaded
+        #[pallet::constant]
+        type TelemetryTtl: Get<BlockNumberFor<Self>>;
+
+        /// CPU load or memory usage at which an agent counts as overloaded
+        #[pallet::constant]
+        type OverloadThreshold: Get<Percent>;
+
+        /// Work queue depth at which an agent counts as overloaded
+        #[pallet::constant]
+        type OverloadQueueDepth: Get<u32>;
+
+        /// Number of maintenance windows kept per agent
+        #[pallet::constant]
+        type MaxMaintenanceWindows: Get<u32>;
+
+        /// Maximum number of blocks a maintenance window may last
+        #[pallet::constant]
+        type MaxMaintenanceDuration: Get<BlockNumberFor<Self>>;
+
+        /// Origin of state roots sent by sister chains, yielding the sending parachain
+        type RemoteOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = ParaId>;
+
+        /// Maximum total size, in bytes, of the trie nodes in a remote agent proof
+        #[pallet::constant]
+        type MaxRemoteProofSize: Get<u32>;
+
+        /// Weight information for extrinsics
+        type WeightInfo: WeightInfo;
     }
 
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// Agent status enum
@@ -83,14 +396,30 @@ pub mod pallet {
         }
     }
 
+    impl AgentStatus {
+        /// Whether an agent may move itself from this status to `next`
+        ///
+        /// Retired is terminal and only reached through `initiate_retirement` and
+        /// `retire_complete`, and maintenance can only be entered from online. Moving to the
+        /// current status is not a transition.
+        pub fn can_transition_to(&self, next: &AgentStatus) -> bool {
+            match (self, next) {
+                (Self::Retired, _) | (_, Self::Retired) => false,
+                (current, next) if current == next => false,
+                (_, Self::Maintenance) => *self == Self::Online,
+                _ => true,
+            }
+        }
+    }
+
     /// Agent information stored on-chain
     #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
     #[scale_info(skip_type_params(T))]
     pub struct AgentInfo<T: Config> {
         /// The public key of the agent (same as account ID in this implementation)
         pub pubkey: T::AccountId,
-        /// Agent role (e.g., "Lyra", "Echo", "Volt", etc.)
-        pub role: BoundedVec<u8, T::MaxRoleLength>,
+        /// Agent role (e.g., Lyra, Echo, Volt, etc.)
+        pub role: AgentRole,
         /// Trust score that can be incremented based on successful consensus events
         pub trust_score: u64,
         /// Current status of the agent
@@ -98,7 +427,138 @@ pub mod pallet {
         /// When the agent was registered
         pub registered_at: BlockNumberFor<T>,
         /// Optional metadata about the agent (e.g., version, capabilities)
-        pub metadata: Option<BoundedVec<u8, T::MaxMetadataLength>>,
+        pub metadata: Option<BoundedVec<u8, T::MaxMetadataAllowance>>,
+        /// Key type the agent signs consensus logs with
+        pub signature_scheme: SignatureScheme,
+    }
+
+    /// Extra metadata space an agent bought on top of `MaxMetadataLength`
+    #[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct MetadataAllowance<Balance> {
+        /// Extra bytes the agent may use
+        pub extra_bytes: u32,
+        /// Deposit reserved for the extra bytes
+        pub deposit: Balance,
+    }
+
+    /// Software an agent attested to run
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct VersionAttestation<T: Config> {
+        /// Semantic version of the agent software
+        pub version: AgentVersion,
+        /// Hash of the agent's code
+        pub code_hash: T::Hash,
+        /// When the attestation was made
+        pub attested_at: BlockNumberFor<T>,
+    }
+
+    /// Transport an agent endpoint is reached over
+    #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum EndpointKind {
+        /// A libp2p multiaddress, e.g. `/dns4/agent.example/tcp/30333/p2p/12D3Koo...`
+        Libp2p,
+        /// An HTTPS URL
+        Https,
+    }
+
+    impl EndpointKind {
+        /// Whether `address` is well formed for this kind of endpoint
+        pub fn accepts(&self, address: &[u8]) -> bool {
+            match self {
+                Self::Libp2p => address.len() > 1 && address.starts_with(b"/"),
+                Self::Https => address.len() > 8 && address.starts_with(b"https://"),
+            }
+        }
+    }
+
+    /// A network endpoint an agent can be reached at
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct AgentEndpoint<T: Config> {
+        /// Transport of the endpoint
+        pub kind: EndpointKind,
+        /// Address of the endpoint
+        pub address: BoundedVec<u8, T::MaxEndpointLength>,
+    }
+
+    /// The endpoints an agent announced
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct EndpointAnnouncement<T: Config> {
+        /// The announced endpoints
+        pub endpoints: BoundedVec<AgentEndpoint<T>, T::MaxEndpoints>,
+        /// When the announcement was made
+        pub announced_at: BlockNumberFor<T>,
+        /// First block at which the announcement is no longer valid
+        pub expires_at: BlockNumberFor<T>,
+    }
+
+    /// Resource usage an agent reported
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct TelemetrySnapshot<T: Config> {
+        /// Share of the agent's CPU in use
+        pub cpu_load: Percent,
+        /// Share of the agent's memory in use
+        pub mem_usage: Percent,
+        /// Number of tasks waiting in the agent's work queue
+        pub queue_depth: u32,
+        /// When the report was made
+        pub reported_at: BlockNumberFor<T>,
+    }
+
+    impl<T: Config> TelemetrySnapshot<T> {
+        /// Whether the report reaches `OverloadThreshold` or `OverloadQueueDepth`
+        pub fn is_overloaded(&self) -> bool {
+            let threshold = T::OverloadThreshold::get();
+            self.cpu_load >= threshold
+                || self.mem_usage >= threshold
+                || self.queue_depth >= T::OverloadQueueDepth::get()
+        }
+    }
+
+    /// Planned downtime an agent declared
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct MaintenanceWindow<T: Config> {
+        /// First block of the window
+        pub starts_at: BlockNumberFor<T>,
+        /// First block after the window
+        pub ends_at: BlockNumberFor<T>,
+        /// Whether governance approved the window
+        pub approved: bool,
+    }
+
+    /// State root a sister chain sent, which remote agent proofs are checked against
+    #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct RemoteStateRoot {
+        /// Block of the sister chain the root belongs to
+        pub block_number: u32,
+        /// Storage root of that block
+        pub root: [u8; 32],
+    }
+
+    /// A sister chain whose agent registry is trusted
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct RemoteRegistry {
+        /// Storage key prefix of the chain's `Agents` map: the `twox_128` hashes of its pallet
+        /// name and of the map's name
+        pub agents_prefix: [u8; 32],
+        /// Latest state root the chain sent
+        pub state_root: Option<RemoteStateRoot>,
+    }
+
+    /// Registration of an agent on a sister chain, as proven against one of its state roots
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct RemoteAgent<T: Config> {
+        /// Role the agent holds on the sister chain
+        pub role: AgentRole,
+        /// Block of the sister chain the registration was proven at
+        pub remote_block: u32,
+        /// When the proof was verified here
+        pub verified_at: BlockNumberFor<T>,
     }
 
     /// Storage for all registered agents
@@ -112,6 +572,183 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Block at which each agent last sent a heartbeat
+    #[pallet::storage]
+    #[pallet::getter(fn last_seen)]
+    pub type LastSeen<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
+
+    /// Block at which each retiring agent's grace period ends
+    #[pallet::storage]
+    #[pallet::getter(fn retiring)]
+    pub type Retiring<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
+
+    /// Metadata allowances bought by agents
+    #[pallet::storage]
+    #[pallet::getter(fn metadata_allowances)]
+    pub type MetadataAllowances<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        MetadataAllowance<BalanceOf<T>>,
+        ValueQuery,
+    >;
+
+    /// Latest version attestation of each agent
+    #[pallet::storage]
+    #[pallet::getter(fn agent_versions)]
+    pub type AgentVersions<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        VersionAttestation<T>,
+        OptionQuery,
+    >;
+
+    /// Lowest agent software version governance accepts, if any
+    #[pallet::storage]
+    #[pallet::getter(fn minimum_agent_version)]
+    pub type MinimumAgentVersion<T: Config> = StorageValue<_, AgentVersion, OptionQuery>;
+
+    /// Latest endpoint announcement of each agent, which may have expired
+    #[pallet::storage]
+    #[pallet::getter(fn address_book)]
+    pub type AddressBook<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        EndpointAnnouncement<T>,
+        OptionQuery,
+    >;
+
+    /// Operational proxy keys of each agent, with the call classes each may make
+    #[pallet::storage]
+    #[pallet::getter(fn proxies)]
+    pub type Proxies<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<(T::AccountId, CallClasses), T::MaxProxies>,
+        ValueQuery,
+    >;
+
+    /// Agent each operational proxy key acts for
+    #[pallet::storage]
+    #[pallet::getter(fn proxy_of)]
+    pub type ProxyOf<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        T::AccountId,
+        OptionQuery,
+    >;
+
+    #[pallet::type_value]
+    pub fn DefaultGlobalFeatures<T: Config>() -> FeatureFlags {
+        T::DefaultFeatures::get()
+    }
+
+    /// Experimental features enabled for every agent
+    #[pallet::storage]
+    #[pallet::getter(fn global_features)]
+    pub type GlobalFeatures<T: Config> = StorageValue<_, FeatureFlags, ValueQuery, DefaultGlobalFeatures<T>>;
+
+    /// Experimental features enabled for individual agents on top of the global ones
+    #[pallet::storage]
+    #[pallet::getter(fn agent_features)]
+    pub type AgentFeatures<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        FeatureFlags,
+        ValueQuery,
+    >;
+
+    /// Capabilities governance granted to each agent
+    #[pallet::storage]
+    #[pallet::getter(fn capabilities)]
+    pub type Capabilities<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<Capability, T::MaxCapabilities>,
+        ValueQuery,
+    >;
+
+    /// Latest telemetry reports of each agent, oldest first
+    #[pallet::storage]
+    #[pallet::getter(fn telemetry)]
+    pub type Telemetry<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<TelemetrySnapshot<T>, T::MaxTelemetrySnapshots>,
+        ValueQuery,
+    >;
+
+    /// Maintenance windows of each agent, ordered by start
+    #[pallet::storage]
+    #[pallet::getter(fn maintenance_windows)]
+    pub type MaintenanceWindows<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<MaintenanceWindow<T>, T::MaxMaintenanceWindows>,
+        ValueQuery,
+    >;
+
+    /// Sister chains whose agent registrations can be proven here
+    #[pallet::storage]
+    #[pallet::getter(fn remote_registries)]
+    pub type RemoteRegistries<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        ParaId,
+        RemoteRegistry,
+        OptionQuery,
+    >;
+
+    /// Agents proven to be registered on sister chains
+    #[pallet::storage]
+    #[pallet::getter(fn agent_aliases)]
+    pub type AgentAliases<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Twox64Concat,
+        ParaId,
+        RemoteAgent<T>,
+        OptionQuery,
+    >;
+
+    /// Registered agents by role
+    #[pallet::storage]
+    pub type AgentsByRole<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        AgentRole,
+        Blake2_128Concat,
+        T::AccountId,
+        (),
+        OptionQuery,
+    >;
+
+    /// Last agent visited by the stale sweep, which continues after it; unset to start over
+    #[pallet::storage]
+    #[pallet::getter(fn sweep_cursor)]
+    pub type SweepCursor<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
+
     /// Events emitted by the pallet
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -119,7 +756,7 @@ pub mod pallet {
         /// A new agent was registered
         AgentRegistered {
             agent_id: T::AccountId,
-            role: Vec<u8>,
+            role: AgentRole,
         },
         /// An agent's status was updated
         AgentStatusUpdated {
@@ -135,54 +772,286 @@ pub mod pallet {
             agent_id: T::AccountId,
             new_score: u64,
         },
-    }
-
-    /// Errors that can occur in the pallet
-    #[pallet::error]
-    pub enum Error<T> {
-        /// Agent already exists
-        AgentAlreadyExists,
-        /// Agent does not exist
-        AgentNotFound,
-        /// Invalid role provided
-        InvalidRole,
-        /// Invalid metadata format
-        InvalidMetadata,
-        /// Agent is not active (offline or retired)
-        AgentNotActive,
-    }
-
-    #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
-
-    #[pallet::call]
-    impl<T: Config> Pallet<T> {
-        /// Register a new agent
-        ///
-        /// The origin must be signed by the account that will be registered as the agent.
-        /// Parameters:
-        /// - `role`: The role of the agent (e.g., "Lyra", "Echo", "Volt")
-        /// - `metadata`: Optional metadata about the agent
-        #[pallet::call_index(0)]
-        #[pallet::weight(Weight::from_parts(10_000, 0).saturating_add(T::DbWeight::get().reads_writes(1, 1)))]
-        pub fn register_agent(
+        /// An agent reported that it is alive
+        HeartbeatReceived {
+            agent_id: T::AccountId,
+            block: BlockNumberFor<T>,
+        },
+        /// An agent started retiring and can complete it from `completes_at`
+        RetirementInitiated {
+            agent_id: T::AccountId,
+            completes_at: BlockNumberFor<T>,
+        },
+        /// An agent's grace period ended and it was retired
+        RetirementCompleted {
+            agent_id: T::AccountId,
+        },
+        /// An agent bought more metadata space; the totals of its allowance are given
+        MetadataAllowanceExtended {
+            agent_id: T::AccountId,
+            extra_bytes: u32,
+            deposit: BalanceOf<T>,
+        },
+        /// An agent attested the software it runs
+        VersionAttested {
+            agent_id: T::AccountId,
+            version: AgentVersion,
+            code_hash: T::Hash,
+        },
+        /// Governance changed the minimum agent version
+        MinimumAgentVersionSet {
+            version: Option<AgentVersion>,
+        },
+        /// An agent runs, or attested, a version below the minimum and should upgrade
+        AgentBelowMinimumVersion {
+            agent_id: T::AccountId,
+            version: Option<AgentVersion>,
+            minimum: AgentVersion,
+        },
+        /// An agent moved to a new account
+        AgentKeyRotated {
+            old_agent_id: T::AccountId,
+            new_agent_id: T::AccountId,
+        },
+        /// An agent announced the endpoints it can be reached at
+        EndpointsAnnounced {
+            agent_id: T::AccountId,
+            endpoints: u32,
+            expires_at: BlockNumberFor<T>,
+        },
+        /// An agent sent no heartbeat for `StaleAfter` blocks and was taken offline
+        AgentMarkedStale {
+            agent_id: T::AccountId,
+            last_seen: BlockNumberFor<T>,
+        },
+        /// An agent added an operational proxy key or changed its call classes
+        ProxyAdded {
+            agent_id: T::AccountId,
+            proxy: T::AccountId,
+            classes: CallClasses,
+        },
+        /// An agent removed an operational proxy key
+        ProxyRemoved {
+            agent_id: T::AccountId,
+            proxy: T::AccountId,
+        },
+        /// Governance granted an agent a capability
+        CapabilityGranted {
+            agent_id: T::AccountId,
+            capability: Capability,
+        },
+        /// Governance revoked a capability of an agent
+        CapabilityRevoked {
+            agent_id: T::AccountId,
+            capability: Capability,
+        },
+        /// An agent reported its resource usage
+        TelemetryReported {
+            agent_id: T::AccountId,
+            cpu_load: Percent,
+            mem_usage: Percent,
+            queue_depth: u32,
+        },
+        /// An agent declared a maintenance window
+        MaintenanceDeclared {
+            agent_id: T::AccountId,
+            starts_at: BlockNumberFor<T>,
+            ends_at: BlockNumberFor<T>,
+        },
+        /// Governance approved a maintenance window of an agent
+        MaintenanceApproved {
+            agent_id: T::AccountId,
+            starts_at: BlockNumberFor<T>,
+        },
+        /// An agent chose the scheme it signs consensus logs with
+        SignatureSchemeSet {
+            agent_id: T::AccountId,
+            scheme: SignatureScheme,
+        },
+        /// Governance set the features enabled for every agent
+        GlobalFeaturesSet {
+            features: FeatureFlags,
+        },
+        /// Governance set the features enabled for an agent on top of the global ones
+        AgentFeaturesSet {
+            agent_id: T::AccountId,
+            features: FeatureFlags,
+        },
+        /// Governance trusted a sister chain's agent registry or changed its storage prefix
+        RemoteRegistrySet {
+            para_id: ParaId,
+            agents_prefix: [u8; 32],
+        },
+        /// A sister chain's agent registry is no longer trusted
+        RemoteRegistryRemoved {
+            para_id: ParaId,
+        },
+        /// A trusted sister chain sent a new state root
+        RemoteStateRootNoted {
+            para_id: ParaId,
+            state_root: RemoteStateRoot,
+        },
+        /// An account was proven to be an agent registered on a sister chain
+        RemoteAgentVerified {
+            agent_id: T::AccountId,
+            para_id: ParaId,
+            role: AgentRole,
+        },
+    }
+
+    /// Errors that can occur in the pallet
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Agent already exists
+        AgentAlreadyExists,
+        /// Agent does not exist
+        AgentNotFound,
+        /// A custom role uses an ID reserved for canonical roles
+        InvalidRole,
+        /// Invalid metadata format
+        InvalidMetadata,
+        /// Agent is not active (offline or retired)
+        AgentNotActive,
+        /// The agent's current status cannot change to the requested one
+        InvalidStatusTransition,
+        /// The agent is retiring
+        AgentRetiring,
+        /// The agent has not initiated its retirement
+        NotRetiring,
+        /// The agent's retirement grace period has not ended yet
+        GracePeriodActive,
+        /// The new account is the agent's current account
+        SameAccount,
+        /// The allowance would exceed `MaxMetadataAllowance`
+        AllowanceAboveMaximum,
+        /// The agent cannot reserve the allowance deposit
+        InsufficientDeposit,
+        /// An announcement must contain at least one endpoint
+        NoEndpoints,
+        /// The announcement has more than `MaxEndpoints` endpoints
+        TooManyEndpoints,
+        /// An endpoint address is malformed or longer than `MaxEndpointLength`
+        InvalidEndpoint,
+        /// The agent announced its endpoints less than `AnnouncementCooldown` blocks ago
+        AnnouncementTooFrequent,
+        /// The account is a registered agent or a proxy key of another agent
+        ProxyInUse,
+        /// The account is not a proxy key of the agent
+        ProxyNotFound,
+        /// The agent has `MaxProxies` proxy keys
+        TooManyProxies,
+        /// A proxy key must be allowed at least one call class
+        NoCallClasses,
+        /// The agent has `MaxCapabilities` capabilities
+        TooManyCapabilities,
+        /// The agent was not granted the capability
+        CapabilityNotFound,
+        /// The agent reported telemetry less than `TelemetryInterval` blocks ago
+        TelemetryTooFrequent,
+        /// A maintenance window must start in the future, end after it starts and last at most
+        /// `MaxMaintenanceDuration` blocks
+        InvalidMaintenanceWindow,
+        /// The window overlaps another maintenance window of the agent
+        MaintenanceOverlap,
+        /// The agent has `MaxMaintenanceWindows` windows and none of them has ended
+        TooManyMaintenanceWindows,
+        /// The agent has no maintenance window starting at the given block
+        MaintenanceWindowNotFound,
+        /// The sister chain's agent registry is not trusted
+        UnknownRemoteRegistry,
+        /// The sister chain has not sent a state root yet
+        NoRemoteStateRoot,
+        /// The state root is not newer than the last one the sister chain sent
+        StaleRemoteStateRoot,
+        /// The proof is larger than `MaxRemoteProofSize`
+        RemoteProofTooLarge,
+        /// The proof does not match the sister chain's state root
+        InvalidRemoteProof,
+        /// The account is not registered as an agent on the sister chain, or is retired there
+        RemoteAgentNotFound,
+    }
+
+    /// Genesis configuration for the agent registry
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// Agents registered at genesis as `(agent_id, role, metadata)`, each role by its name
+        /// as parsed by [`AgentRole::from_name`]
+        pub agents: Vec<(T::AccountId, Vec<u8>, Option<Vec<u8>>)>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            for (agent_id, role, metadata) in &self.agents {
+                assert!(!Agents::<T>::contains_key(agent_id), "Duplicate agent in genesis");
+                let role = AgentRole::from_name(role).expect("Genesis agent role is not a known role");
+                let metadata = metadata.clone().map(|meta| {
+                    assert!(
+                        meta.len() <= T::MaxMetadataLength::get() as usize,
+                        "Genesis agent metadata exceeds MaxMetadataLength"
+                    );
+                    BoundedVec::<u8, T::MaxMetadataAllowance>::try_from(meta)
+                        .expect("Genesis agent metadata exceeds MaxMetadataAllowance")
+                });
+
+                Agents::<T>::insert(agent_id, AgentInfo {
+                    pubkey: agent_id.clone(),
+                    role,
+                    trust_score: 0,
+                    status: AgentStatus::Online,
+                    registered_at: <frame_system::Pallet<T>>::block_number(),
+                    metadata,
+                    signature_scheme: SignatureScheme::default(),
+                });
+                AgentsByRole::<T>::insert(role, agent_id, ());
+            }
+        }
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Take stale agents offline with the block's remaining weight
+        fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::sweep_stale_agents(now, remaining_weight)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            Self::do_try_state()
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Register a new agent
+        ///
+        /// The origin must be signed by the account that will be registered as the agent.
+        /// Parameters:
+        /// - `role`: The role of the agent (e.g., Lyra, Echo, Volt)
+        /// - `metadata`: Optional metadata about the agent
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::register_agent(
+            metadata.as_ref().map_or(0, |meta| meta.len() as u32),
+        ))]
+        pub fn register_agent(
             origin: OriginFor<T>,
-            role: Vec<u8>,
+            role: AgentRole,
             metadata: Option<Vec<u8>>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
             
             // Check if agent already exists
             ensure!(!Agents::<T>::contains_key(&who), Error::<T>::AgentAlreadyExists);
+            ensure!(!ProxyOf::<T>::contains_key(&who), Error::<T>::ProxyInUse);
+            
+            // Custom roles may not use the IDs reserved for canonical roles
+            ensure!(role.is_valid(), Error::<T>::InvalidRole);
             
-            // Validate and bound the role
-            ensure!(!role.is_empty(), Error::<T>::InvalidRole);
-            let bounded_role = BoundedVec::<u8, T::MaxRoleLength>::try_from(role.clone())
-                .map_err(|_| Error::<T>::InvalidRole)?;
-                
             // Validate and bound the metadata if provided
             let bounded_metadata = if let Some(meta) = metadata {
-                Some(BoundedVec::<u8, T::MaxMetadataLength>::try_from(meta)
+                ensure!(meta.len() <= T::MaxMetadataLength::get() as usize, Error::<T>::InvalidMetadata);
+                Some(BoundedVec::<u8, T::MaxMetadataAllowance>::try_from(meta)
                     .map_err(|_| Error::<T>::InvalidMetadata)?)
             } else {
                 None
@@ -191,20 +1060,22 @@ pub mod pallet {
             // Create the agent info
             let agent_info = AgentInfo {
                 pubkey: who.clone(),
-                role: bounded_role,
+                role,
                 trust_score: 0,
                 status: AgentStatus::Online, // New agents start as online
                 registered_at: <frame_system::Pallet<T>>::block_number(),
                 metadata: bounded_metadata,
+                signature_scheme: SignatureScheme::default(),
             };
             
             // Store the agent
             Agents::<T>::insert(&who, agent_info);
+            AgentsByRole::<T>::insert(role, &who, ());
             
             // Emit event
             Self::deposit_event(Event::AgentRegistered { 
                 agent_id: who,
-                role: role,
+                role,
             });
             
             Ok(())
@@ -212,26 +1083,30 @@ pub mod pallet {
         
         /// Update an agent's status
         ///
-        /// The origin must be signed by the agent whose status is being updated.
+        /// The origin must be signed by the agent whose status is being updated. Only the
+        /// transitions allowed by [`AgentStatus::can_transition_to`] are accepted; governance can
+        /// set any status with `force_set_status`.
         /// Parameters:
         /// - `status`: The new status to set
         #[pallet::call_index(1)]
-        #[pallet::weight(Weight::from_parts(5_000, 0).saturating_add(T::DbWeight::get().reads_writes(1, 1)))]
+        #[pallet::weight(T::WeightInfo::update_status()
+            .saturating_add(T::OnStatusChange::on_status_changed_weight()))]
         pub fn update_status(
             origin: OriginFor<T>,
             status: AgentStatus,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            ensure!(!Retiring::<T>::contains_key(&who), Error::<T>::AgentRetiring);
             
             // Ensure agent exists
-            Agents::<T>::try_mutate(&who, |maybe_agent| -> DispatchResult {
+            let previous = Agents::<T>::try_mutate(&who, |maybe_agent| -> Result<AgentStatus, DispatchError> {
                 let agent = maybe_agent.as_mut().ok_or(Error::<T>::AgentNotFound)?;
+                ensure!(agent.status.can_transition_to(&status), Error::<T>::InvalidStatusTransition);
                 
                 // Update status
-                agent.status = status.clone();
-                
-                Ok(())
+                Ok(sp_std::mem::replace(&mut agent.status, status.clone()))
             })?;
+            T::OnStatusChange::on_status_changed(&who, &previous, &status);
             
             // Emit event
             Self::deposit_event(Event::AgentStatusUpdated { 
@@ -244,11 +1119,12 @@ pub mod pallet {
         
         /// Update an agent's metadata
         ///
-        /// The origin must be signed by the agent whose metadata is being updated.
+        /// The origin must be signed by the agent whose metadata is being updated. The metadata
+        /// may be as long as `MaxMetadataLength` plus the agent's purchased allowance.
         /// Parameters:
         /// - `metadata`: The new metadata to set
         #[pallet::call_index(2)]
-        #[pallet::weight(Weight::from_parts(8_000, 0).saturating_add(T::DbWeight::get().reads_writes(1, 1)))]
+        #[pallet::weight(T::WeightInfo::update_metadata(metadata.len() as u32))]
         pub fn update_metadata(
             origin: OriginFor<T>,
             metadata: Vec<u8>,
@@ -260,7 +1136,11 @@ pub mod pallet {
                 let agent = maybe_agent.as_mut().ok_or(Error::<T>::AgentNotFound)?;
                 
                 // Validate and bound the metadata
-                let bounded_metadata = BoundedVec::<u8, T::MaxMetadataLength>::try_from(metadata)
+                ensure!(
+                    metadata.len() <= Self::metadata_limit(&who) as usize,
+                    Error::<T>::InvalidMetadata
+                );
+                let bounded_metadata = BoundedVec::<u8, T::MaxMetadataAllowance>::try_from(metadata)
                     .map_err(|_| Error::<T>::InvalidMetadata)?;
                 
                 // Update metadata
@@ -287,7 +1167,7 @@ pub mod pallet {
         /// - `agent_id`: The ID of the agent whose score is being updated
         /// - `score_delta`: The amount to change the trust score by (positive or negative)
         #[pallet::call_index(3)]
-        #[pallet::weight(Weight::from_parts(5_000, 0).saturating_add(T::DbWeight::get().reads_writes(1, 1)))]
+        #[pallet::weight(T::WeightInfo::update_trust_score())]
         pub fn update_trust_score(
             origin: OriginFor<T>,
             agent_id: T::AccountId,
@@ -325,5 +1205,1131 @@ pub mod pallet {
             
             Ok(())
         }
+        
+        /// Report that the calling agent is alive
+        ///
+        /// May be sent by a proxy key of the agent allowed to send heartbeats. Heartbeats from
+        /// agents allowed by `T::FeeExemption` are dispatched without fees.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::heartbeat())]
+        #[pallet::feeless_if(|origin: &OriginFor<T>| -> bool {
+            ensure_signed(origin.clone()).map_or(false, |who| {
+                T::FeeExemption::is_fee_exempt(&Pallet::<T>::acting_agent(who, CallClass::Heartbeat))
+            })
+        })]
+        pub fn heartbeat(origin: OriginFor<T>) -> DispatchResult {
+            let who = Self::acting_agent(ensure_signed(origin)?, CallClass::Heartbeat);
+            
+            // Ensure agent exists
+            ensure!(Agents::<T>::contains_key(&who), Error::<T>::AgentNotFound);
+            
+            let block = <frame_system::Pallet<T>>::block_number();
+            LastSeen::<T>::insert(&who, block);
+            
+            // Emit event
+            Self::deposit_event(Event::HeartbeatReceived {
+                agent_id: who.clone(),
+                block,
+            });
+            Self::warn_if_outdated(&who);
+            
+            Ok(())
+        }
+
+        /// Set an agent's status regardless of the transition rules (governance function)
+        ///
+        /// Intended for exceptional cases such as reinstating a retired agent.
+        /// Parameters:
+        /// - `agent_id`: The agent whose status is being set
+        /// - `status`: The new status to set
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::force_set_status()
+            .saturating_add(T::OnStatusChange::on_status_changed_weight()))]
+        pub fn force_set_status(
+            origin: OriginFor<T>,
+            agent_id: T::AccountId,
+            status: AgentStatus,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let previous = Agents::<T>::try_mutate(&agent_id, |maybe_agent| -> Result<AgentStatus, DispatchError> {
+                let agent = maybe_agent.as_mut().ok_or(Error::<T>::AgentNotFound)?;
+                Ok(sp_std::mem::replace(&mut agent.status, status.clone()))
+            })?;
+            T::OnStatusChange::on_status_changed(&agent_id, &previous, &status);
+
+            // Emit event
+            Self::deposit_event(Event::AgentStatusUpdated {
+                agent_id,
+                status,
+            });
+
+            Ok(())
+        }
+
+        /// Start retiring the calling agent
+        ///
+        /// The agent goes offline and cannot change its status again until it is retired with
+        /// `retire_complete` after `RetirementGracePeriod` blocks. Its logs and records remain
+        /// readable in the meantime.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::initiate_retirement()
+            .saturating_add(T::OnRetirement::on_retirement_initiated_weight())
+            .saturating_add(T::OnStatusChange::on_status_changed_weight()))]
+        pub fn initiate_retirement(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(!Retiring::<T>::contains_key(&who), Error::<T>::AgentRetiring);
+
+            let previous = Agents::<T>::try_mutate(&who, |maybe_agent| -> Result<AgentStatus, DispatchError> {
+                let agent = maybe_agent.as_mut().ok_or(Error::<T>::AgentNotFound)?;
+                ensure!(agent.status != AgentStatus::Retired, Error::<T>::InvalidStatusTransition);
+                Ok(sp_std::mem::replace(&mut agent.status, AgentStatus::Offline))
+            })?;
+            T::OnStatusChange::on_status_changed(&who, &previous, &AgentStatus::Offline);
+
+            let completes_at = <frame_system::Pallet<T>>::block_number()
+                .saturating_add(T::RetirementGracePeriod::get());
+            Retiring::<T>::insert(&who, completes_at);
+            T::OnRetirement::on_retirement_initiated(&who);
+
+            // Emit event
+            Self::deposit_event(Event::RetirementInitiated {
+                agent_id: who,
+                completes_at,
+            });
+
+            Ok(())
+        }
+
+        /// Retire an agent whose retirement grace period has ended
+        ///
+        /// Any signed account may call this once the grace period is over.
+        /// Parameters:
+        /// - `agent_id`: The retiring agent
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::retire_complete()
+            .saturating_add(T::OnRetirement::on_retirement_completed_weight())
+            .saturating_add(T::OnStatusChange::on_status_changed_weight()))]
+        pub fn retire_complete(
+            origin: OriginFor<T>,
+            agent_id: T::AccountId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let completes_at = Retiring::<T>::get(&agent_id).ok_or(Error::<T>::NotRetiring)?;
+            ensure!(
+                <frame_system::Pallet<T>>::block_number() >= completes_at,
+                Error::<T>::GracePeriodActive
+            );
+
+            let previous = Agents::<T>::try_mutate(&agent_id, |maybe_agent| -> Result<AgentStatus, DispatchError> {
+                let agent = maybe_agent.as_mut().ok_or(Error::<T>::AgentNotFound)?;
+                Ok(sp_std::mem::replace(&mut agent.status, AgentStatus::Retired))
+            })?;
+            Retiring::<T>::remove(&agent_id);
+            LastSeen::<T>::remove(&agent_id);
+            AddressBook::<T>::remove(&agent_id);
+            Telemetry::<T>::remove(&agent_id);
+            MaintenanceWindows::<T>::remove(&agent_id);
+            for (proxy, _) in Proxies::<T>::take(&agent_id) {
+                ProxyOf::<T>::remove(&proxy);
+            }
+            Capabilities::<T>::remove(&agent_id);
+            AgentFeatures::<T>::remove(&agent_id);
+            let allowance = MetadataAllowances::<T>::take(&agent_id);
+            T::Currency::unreserve(&agent_id, allowance.deposit);
+            T::OnRetirement::on_retirement_completed(&agent_id);
+            T::OnStatusChange::on_status_changed(&agent_id, &previous, &AgentStatus::Retired);
+
+            // Emit event
+            Self::deposit_event(Event::RetirementCompleted {
+                agent_id,
+            });
+
+            Ok(())
+        }
+
+        /// Move the calling agent to a new account
+        ///
+        /// The agent's registry entry, heartbeat, endpoints, telemetry, maintenance windows and
+        /// everything other pallets hold for it through [`OnAgentRotation`] move to
+        /// `new_agent_id`, which must not be a registered agent. Either all of it moves or, if
+        /// any part fails, none of it does. Retiring and retired agents cannot rotate.
+        ///
+        /// Parameters:
+        /// - `new_agent_id`: The account the agent moves to
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::rotate_agent_key()
+            .saturating_add(T::OnRotation::on_agent_rotated_weight()))]
+        pub fn rotate_agent_key(
+            origin: OriginFor<T>,
+            new_agent_id: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(who != new_agent_id, Error::<T>::SameAccount);
+            ensure!(!Agents::<T>::contains_key(&new_agent_id), Error::<T>::AgentAlreadyExists);
+            ensure!(!ProxyOf::<T>::contains_key(&new_agent_id), Error::<T>::ProxyInUse);
+            ensure!(!Retiring::<T>::contains_key(&who), Error::<T>::AgentRetiring);
+
+            frame_support::storage::with_storage_layer(|| -> DispatchResult {
+                let mut agent = Agents::<T>::take(&who).ok_or(Error::<T>::AgentNotFound)?;
+                ensure!(agent.status != AgentStatus::Retired, Error::<T>::InvalidStatusTransition);
+                agent.pubkey = new_agent_id.clone();
+                AgentsByRole::<T>::remove(agent.role, &who);
+                AgentsByRole::<T>::insert(agent.role, &new_agent_id, ());
+                Agents::<T>::insert(&new_agent_id, agent);
+                if let Some(last_seen) = LastSeen::<T>::take(&who) {
+                    LastSeen::<T>::insert(&new_agent_id, last_seen);
+                }
+                if let Some(attestation) = AgentVersions::<T>::take(&who) {
+                    AgentVersions::<T>::insert(&new_agent_id, attestation);
+                }
+                if let Some(announcement) = AddressBook::<T>::take(&who) {
+                    AddressBook::<T>::insert(&new_agent_id, announcement);
+                }
+                let telemetry = Telemetry::<T>::take(&who);
+                if !telemetry.is_empty() {
+                    Telemetry::<T>::insert(&new_agent_id, telemetry);
+                }
+                let windows = MaintenanceWindows::<T>::take(&who);
+                if !windows.is_empty() {
+                    MaintenanceWindows::<T>::insert(&new_agent_id, windows);
+                }
+                let proxies = Proxies::<T>::take(&who);
+                for (proxy, _) in proxies.iter() {
+                    ProxyOf::<T>::insert(proxy, &new_agent_id);
+                }
+                if !proxies.is_empty() {
+                    Proxies::<T>::insert(&new_agent_id, proxies);
+                }
+                let capabilities = Capabilities::<T>::take(&who);
+                if !capabilities.is_empty() {
+                    Capabilities::<T>::insert(&new_agent_id, capabilities);
+                }
+                if AgentFeatures::<T>::contains_key(&who) {
+                    AgentFeatures::<T>::insert(&new_agent_id, AgentFeatures::<T>::take(&who));
+                }
+                if MetadataAllowances::<T>::contains_key(&who) {
+                    let allowance = MetadataAllowances::<T>::take(&who);
+                    let remaining = T::Currency::repatriate_reserved(
+                        &who,
+                        &new_agent_id,
+                        allowance.deposit,
+                        BalanceStatus::Reserved,
+                    )?;
+                    ensure!(remaining.is_zero(), Error::<T>::InsufficientDeposit);
+                    MetadataAllowances::<T>::insert(&new_agent_id, allowance);
+                }
+
+                T::OnRotation::on_agent_rotated(&who, &new_agent_id)
+            })?;
+
+            // Emit event
+            Self::deposit_event(Event::AgentKeyRotated {
+                old_agent_id: who,
+                new_agent_id,
+            });
+
+            Ok(())
+        }
+
+        /// Buy additional metadata space for the calling agent
+        ///
+        /// `bytes` is rounded up to whole tiers of `MetadataTierBytes`, each of which reserves
+        /// `MetadataTierDeposit`.
+        /// Parameters:
+        /// - `bytes`: The number of extra bytes wanted
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::extend_metadata_allowance())]
+        pub fn extend_metadata_allowance(
+            origin: OriginFor<T>,
+            bytes: u32,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(Agents::<T>::contains_key(&who), Error::<T>::AgentNotFound);
+
+            let tier_bytes = T::MetadataTierBytes::get().max(1);
+            let tiers = bytes.div_ceil(tier_bytes);
+            let mut allowance = MetadataAllowances::<T>::get(&who);
+            let extra_bytes = allowance.extra_bytes.saturating_add(tiers.saturating_mul(tier_bytes));
+            ensure!(
+                T::MaxMetadataLength::get().saturating_add(extra_bytes) <= T::MaxMetadataAllowance::get(),
+                Error::<T>::AllowanceAboveMaximum
+            );
+
+            let deposit = T::MetadataTierDeposit::get().saturating_mul(tiers.into());
+            T::Currency::reserve(&who, deposit).map_err(|_| Error::<T>::InsufficientDeposit)?;
+
+            allowance.extra_bytes = extra_bytes;
+            allowance.deposit = allowance.deposit.saturating_add(deposit);
+            MetadataAllowances::<T>::insert(&who, &allowance);
+
+            // Emit event
+            Self::deposit_event(Event::MetadataAllowanceExtended {
+                agent_id: who,
+                extra_bytes: allowance.extra_bytes,
+                deposit: allowance.deposit,
+            });
+
+            Ok(())
+        }
+
+        /// Attest the software version the calling agent runs
+        ///
+        /// Parameters:
+        /// - `version`: Semantic version as `(major, minor, patch)`
+        /// - `code_hash`: Hash of the agent's code
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::attest_version())]
+        pub fn attest_version(
+            origin: OriginFor<T>,
+            version: AgentVersion,
+            code_hash: T::Hash,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(Agents::<T>::contains_key(&who), Error::<T>::AgentNotFound);
+
+            AgentVersions::<T>::insert(&who, VersionAttestation {
+                version,
+                code_hash,
+                attested_at: <frame_system::Pallet<T>>::block_number(),
+            });
+
+            // Emit event
+            Self::deposit_event(Event::VersionAttested {
+                agent_id: who.clone(),
+                version,
+                code_hash,
+            });
+            Self::warn_if_outdated(&who);
+
+            Ok(())
+        }
+
+        /// Set or clear the minimum agent version (governance function)
+        ///
+        /// Parameters:
+        /// - `version`: The new minimum, or `None` to accept any version
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::set_minimum_agent_version())]
+        pub fn set_minimum_agent_version(
+            origin: OriginFor<T>,
+            version: Option<AgentVersion>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            MinimumAgentVersion::<T>::set(version);
+
+            // Emit event
+            Self::deposit_event(Event::MinimumAgentVersionSet { version });
+
+            Ok(())
+        }
+
+        /// Allow an operational proxy key to make routine calls for the calling agent
+        ///
+        /// Adding a key the agent already has replaces its call classes. The key must not be a
+        /// registered agent or a proxy key of another agent.
+        /// Parameters:
+        /// - `proxy`: The proxy key
+        /// - `classes`: The call classes the key may make
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::add_proxy())]
+        pub fn add_proxy(
+            origin: OriginFor<T>,
+            proxy: T::AccountId,
+            classes: CallClasses,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(Agents::<T>::contains_key(&who), Error::<T>::AgentNotFound);
+            ensure!(!Retiring::<T>::contains_key(&who), Error::<T>::AgentRetiring);
+            ensure!(!classes.is_empty(), Error::<T>::NoCallClasses);
+            ensure!(proxy != who && !Agents::<T>::contains_key(&proxy), Error::<T>::ProxyInUse);
+            ensure!(
+                ProxyOf::<T>::get(&proxy).map_or(true, |agent_id| agent_id == who),
+                Error::<T>::ProxyInUse
+            );
+
+            Proxies::<T>::try_mutate(&who, |proxies| -> DispatchResult {
+                match proxies.iter_mut().find(|(key, _)| *key == proxy) {
+                    Some((_, existing)) => *existing = classes,
+                    None => proxies.try_push((proxy.clone(), classes)).map_err(|_| Error::<T>::TooManyProxies)?,
+                }
+                Ok(())
+            })?;
+            ProxyOf::<T>::insert(&proxy, &who);
+
+            // Emit event
+            Self::deposit_event(Event::ProxyAdded {
+                agent_id: who,
+                proxy,
+                classes,
+            });
+
+            Ok(())
+        }
+
+        /// Revoke an operational proxy key of the calling agent
+        ///
+        /// Parameters:
+        /// - `proxy`: The proxy key
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::remove_proxy())]
+        pub fn remove_proxy(
+            origin: OriginFor<T>,
+            proxy: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Proxies::<T>::try_mutate(&who, |proxies| -> DispatchResult {
+                let index = proxies.iter().position(|(key, _)| *key == proxy).ok_or(Error::<T>::ProxyNotFound)?;
+                proxies.remove(index);
+                Ok(())
+            })?;
+            ProxyOf::<T>::remove(&proxy);
+
+            // Emit event
+            Self::deposit_event(Event::ProxyRemoved {
+                agent_id: who,
+                proxy,
+            });
+
+            Ok(())
+        }
+
+        /// Grant an agent a capability (governance function)
+        ///
+        /// Granting a capability the agent already has changes nothing. Retiring agents cannot be
+        /// granted capabilities.
+        /// Parameters:
+        /// - `agent_id`: The agent
+        /// - `capability`: The capability to grant
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::grant_capability())]
+        pub fn grant_capability(
+            origin: OriginFor<T>,
+            agent_id: T::AccountId,
+            capability: Capability,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            let agent = Agents::<T>::get(&agent_id).ok_or(Error::<T>::AgentNotFound)?;
+            ensure!(agent.status != AgentStatus::Retired, Error::<T>::AgentNotActive);
+            ensure!(!Retiring::<T>::contains_key(&agent_id), Error::<T>::AgentRetiring);
+
+            Capabilities::<T>::try_mutate(&agent_id, |capabilities| -> DispatchResult {
+                if !capabilities.contains(&capability) {
+                    capabilities.try_push(capability).map_err(|_| Error::<T>::TooManyCapabilities)?;
+                }
+                Ok(())
+            })?;
+
+            // Emit event
+            Self::deposit_event(Event::CapabilityGranted {
+                agent_id,
+                capability,
+            });
+
+            Ok(())
+        }
+
+        /// Revoke a capability of an agent (governance function)
+        ///
+        /// Parameters:
+        /// - `agent_id`: The agent
+        /// - `capability`: The capability to revoke
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::revoke_capability())]
+        pub fn revoke_capability(
+            origin: OriginFor<T>,
+            agent_id: T::AccountId,
+            capability: Capability,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            Capabilities::<T>::try_mutate(&agent_id, |capabilities| -> DispatchResult {
+                let index = capabilities
+                    .iter()
+                    .position(|granted| *granted == capability)
+                    .ok_or(Error::<T>::CapabilityNotFound)?;
+                capabilities.remove(index);
+                Ok(())
+            })?;
+
+            // Emit event
+            Self::deposit_event(Event::CapabilityRevoked {
+                agent_id,
+                capability,
+            });
+
+            Ok(())
+        }
+
+        /// Publish the endpoints the calling agent can be reached at
+        ///
+        /// Replaces the agent's previous announcement and stays valid for `EndpointTtl` blocks.
+        /// Retiring and retired agents cannot announce.
+        /// Parameters:
+        /// - `endpoints`: The endpoints as `(kind, address)`
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::announce_endpoints(endpoints.len() as u32))]
+        pub fn announce_endpoints(
+            origin: OriginFor<T>,
+            endpoints: Vec<(EndpointKind, Vec<u8>)>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let agent = Agents::<T>::get(&who).ok_or(Error::<T>::AgentNotFound)?;
+            ensure!(agent.status != AgentStatus::Retired, Error::<T>::AgentNotActive);
+            ensure!(!Retiring::<T>::contains_key(&who), Error::<T>::AgentRetiring);
+            ensure!(!endpoints.is_empty(), Error::<T>::NoEndpoints);
+            ensure!(endpoints.len() <= T::MaxEndpoints::get() as usize, Error::<T>::TooManyEndpoints);
+
+            let now = <frame_system::Pallet<T>>::block_number();
+            if let Some(previous) = AddressBook::<T>::get(&who) {
+                ensure!(
+                    now >= previous.announced_at.saturating_add(T::AnnouncementCooldown::get()),
+                    Error::<T>::AnnouncementTooFrequent
+                );
+            }
+
+            let endpoints = endpoints
+                .into_iter()
+                .map(|(kind, address)| -> Result<AgentEndpoint<T>, DispatchError> {
+                    ensure!(kind.accepts(&address), Error::<T>::InvalidEndpoint);
+                    let address = BoundedVec::try_from(address).map_err(|_| Error::<T>::InvalidEndpoint)?;
+                    Ok(AgentEndpoint { kind, address })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let endpoints = BoundedVec::<AgentEndpoint<T>, T::MaxEndpoints>::try_from(endpoints)
+                .map_err(|_| Error::<T>::TooManyEndpoints)?;
+            let count = endpoints.len() as u32;
+
+            let expires_at = now.saturating_add(T::EndpointTtl::get());
+            AddressBook::<T>::insert(&who, EndpointAnnouncement {
+                endpoints,
+                announced_at: now,
+                expires_at,
+            });
+
+            // Emit event
+            Self::deposit_event(Event::EndpointsAnnounced {
+                agent_id: who,
+                endpoints: count,
+                expires_at,
+            });
+
+            Ok(())
+        }
+
+        /// Report the resource usage of the calling agent
+        ///
+        /// May be sent by a proxy key of the agent allowed to send heartbeats, at most once
+        /// every `TelemetryInterval` blocks. Only the latest `MaxTelemetrySnapshots` reports are
+        /// kept. Retiring and retired agents cannot report.
+        /// Parameters:
+        /// - `cpu_load`: Share of the agent's CPU in use
+        /// - `mem_usage`: Share of the agent's memory in use
+        /// - `queue_depth`: Number of tasks waiting in the agent's work queue
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::report_telemetry())]
+        pub fn report_telemetry(
+            origin: OriginFor<T>,
+            cpu_load: Percent,
+            mem_usage: Percent,
+            queue_depth: u32,
+        ) -> DispatchResult {
+            let who = Self::acting_agent(ensure_signed(origin)?, CallClass::Heartbeat);
+            let agent = Agents::<T>::get(&who).ok_or(Error::<T>::AgentNotFound)?;
+            ensure!(agent.status != AgentStatus::Retired, Error::<T>::AgentNotActive);
+            ensure!(!Retiring::<T>::contains_key(&who), Error::<T>::AgentRetiring);
+
+            let now = <frame_system::Pallet<T>>::block_number();
+            Telemetry::<T>::try_mutate(&who, |snapshots| -> DispatchResult {
+                if let Some(latest) = snapshots.last() {
+                    ensure!(
+                        now >= latest.reported_at.saturating_add(T::TelemetryInterval::get()),
+                        Error::<T>::TelemetryTooFrequent
+                    );
+                }
+                let snapshot = TelemetrySnapshot { cpu_load, mem_usage, queue_depth, reported_at: now };
+                // Drop the oldest report once the history is full
+                if snapshots.is_full() && !snapshots.is_empty() {
+                    snapshots.remove(0);
+                }
+                // Only fails when no reports are kept at all
+                let _ = snapshots.try_push(snapshot);
+                Ok(())
+            })?;
+
+            // Emit event
+            Self::deposit_event(Event::TelemetryReported {
+                agent_id: who,
+                cpu_load,
+                mem_usage,
+                queue_depth,
+            });
+
+            Ok(())
+        }
+
+        /// Declare a maintenance window of the calling agent
+        ///
+        /// The window covers the blocks from `starts_at` up to, but excluding, `ends_at`, must
+        /// start after the current block, last at most `MaxMaintenanceDuration` blocks and not
+        /// overlap the agent's other windows. When the agent already has `MaxMaintenanceWindows`
+        /// windows, its oldest window is dropped if it has ended. Retiring and retired agents
+        /// cannot declare windows.
+        /// Parameters:
+        /// - `starts_at`: First block of the window
+        /// - `ends_at`: First block after the window
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::declare_maintenance())]
+        pub fn declare_maintenance(
+            origin: OriginFor<T>,
+            starts_at: BlockNumberFor<T>,
+            ends_at: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let agent = Agents::<T>::get(&who).ok_or(Error::<T>::AgentNotFound)?;
+            ensure!(agent.status != AgentStatus::Retired, Error::<T>::AgentNotActive);
+            ensure!(!Retiring::<T>::contains_key(&who), Error::<T>::AgentRetiring);
+
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(
+                starts_at > now
+                    && ends_at > starts_at
+                    && ends_at.saturating_sub(starts_at) <= T::MaxMaintenanceDuration::get(),
+                Error::<T>::InvalidMaintenanceWindow
+            );
+
+            MaintenanceWindows::<T>::try_mutate(&who, |windows| -> DispatchResult {
+                ensure!(
+                    windows.iter().all(|window| ends_at <= window.starts_at || window.ends_at <= starts_at),
+                    Error::<T>::MaintenanceOverlap
+                );
+                // Make room by dropping the oldest window, but only once it has ended
+                if windows.is_full() {
+                    ensure!(
+                        windows.first().map_or(false, |oldest| oldest.ends_at <= now),
+                        Error::<T>::TooManyMaintenanceWindows
+                    );
+                    windows.remove(0);
+                }
+                let index = windows.partition_point(|window| window.starts_at < starts_at);
+                windows
+                    .try_insert(index, MaintenanceWindow { starts_at, ends_at, approved: false })
+                    .map_err(|_| Error::<T>::TooManyMaintenanceWindows)?;
+                Ok(())
+            })?;
+
+            // Emit event
+            Self::deposit_event(Event::MaintenanceDeclared {
+                agent_id: who,
+                starts_at,
+                ends_at,
+            });
+
+            Ok(())
+        }
+
+        /// Approve the maintenance window of an agent starting at `starts_at`
+        ///
+        /// Approval is a governance action; only approved windows count as maintenance for
+        /// other pallets.
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::approve_maintenance())]
+        pub fn approve_maintenance(
+            origin: OriginFor<T>,
+            agent_id: T::AccountId,
+            starts_at: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            MaintenanceWindows::<T>::try_mutate(&agent_id, |windows| -> DispatchResult {
+                let window = windows
+                    .iter_mut()
+                    .find(|window| window.starts_at == starts_at)
+                    .ok_or(Error::<T>::MaintenanceWindowNotFound)?;
+                window.approved = true;
+                Ok(())
+            })?;
+
+            // Emit event
+            Self::deposit_event(Event::MaintenanceApproved { agent_id, starts_at });
+
+            Ok(())
+        }
+
+        /// Choose the key type the agent signs consensus logs with
+        ///
+        /// Only the agent's main key may change its scheme. Signatures already on pending logs
+        /// were checked when they were made and stay valid.
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::set_signature_scheme())]
+        pub fn set_signature_scheme(origin: OriginFor<T>, scheme: SignatureScheme) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Agents::<T>::try_mutate(&who, |maybe_agent| -> DispatchResult {
+                let agent = maybe_agent.as_mut().ok_or(Error::<T>::AgentNotFound)?;
+                agent.signature_scheme = scheme;
+                Ok(())
+            })?;
+
+            // Emit event
+            Self::deposit_event(Event::SignatureSchemeSet { agent_id: who, scheme });
+
+            Ok(())
+        }
+
+        /// Set the experimental features enabled for every agent (governance function)
+        ///
+        /// Parameters:
+        /// - `features`: The features to enable, replacing the current set
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::set_global_features())]
+        pub fn set_global_features(origin: OriginFor<T>, features: FeatureFlags) -> DispatchResult {
+            ensure_root(origin)?;
+
+            GlobalFeatures::<T>::put(features);
+
+            // Emit event
+            Self::deposit_event(Event::GlobalFeaturesSet { features });
+
+            Ok(())
+        }
+
+        /// Set the experimental features enabled for one agent on top of the global ones
+        /// (governance function)
+        ///
+        /// An empty set leaves the agent with the global features only.
+        /// Parameters:
+        /// - `agent_id`: The agent
+        /// - `features`: The features to enable, replacing the agent's current set
+        #[pallet::call_index(25)]
+        #[pallet::weight(T::WeightInfo::set_agent_features())]
+        pub fn set_agent_features(
+            origin: OriginFor<T>,
+            agent_id: T::AccountId,
+            features: FeatureFlags,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(Agents::<T>::contains_key(&agent_id), Error::<T>::AgentNotFound);
+
+            if features.is_empty() {
+                AgentFeatures::<T>::remove(&agent_id);
+            } else {
+                AgentFeatures::<T>::insert(&agent_id, features);
+            }
+
+            // Emit event
+            Self::deposit_event(Event::AgentFeaturesSet { agent_id, features });
+
+            Ok(())
+        }
+
+        /// Trust the agent registry of a sister chain, or stop trusting it with `None`
+        ///
+        /// `agents_prefix` is the storage key prefix of the chain's `Agents` map. Removing a
+        /// registry keeps the aliases already proven against it.
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::set_remote_registry())]
+        pub fn set_remote_registry(
+            origin: OriginFor<T>,
+            para_id: ParaId,
+            agents_prefix: Option<[u8; 32]>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            match agents_prefix {
+                Some(agents_prefix) => {
+                    RemoteRegistries::<T>::mutate(para_id, |registry| match registry {
+                        Some(registry) => registry.agents_prefix = agents_prefix,
+                        None => *registry = Some(RemoteRegistry { agents_prefix, state_root: None }),
+                    });
+                    Self::deposit_event(Event::RemoteRegistrySet { para_id, agents_prefix });
+                },
+                None => {
+                    ensure!(RemoteRegistries::<T>::take(para_id).is_some(), Error::<T>::UnknownRemoteRegistry);
+                    Self::deposit_event(Event::RemoteRegistryRemoved { para_id });
+                },
+            }
+
+            Ok(())
+        }
+
+        /// Accept the latest state root of a trusted sister chain
+        ///
+        /// Dispatched by the sister chain itself over XCM. The root must belong to a later block
+        /// than the previous one.
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::note_remote_state_root())]
+        pub fn note_remote_state_root(
+            origin: OriginFor<T>,
+            state_root: RemoteStateRoot,
+        ) -> DispatchResult {
+            let para_id = T::RemoteOrigin::ensure_origin(origin)?;
+
+            RemoteRegistries::<T>::try_mutate(para_id, |registry| -> DispatchResult {
+                let registry = registry.as_mut().ok_or(Error::<T>::UnknownRemoteRegistry)?;
+                ensure!(
+                    registry.state_root.map_or(true, |current| state_root.block_number > current.block_number),
+                    Error::<T>::StaleRemoteStateRoot
+                );
+                registry.state_root = Some(state_root);
+                Ok(())
+            })?;
+
+            // Emit event
+            Self::deposit_event(Event::RemoteStateRootNoted { para_id, state_root });
+
+            Ok(())
+        }
+
+        /// Record that `account` is an agent registered on a sister chain
+        ///
+        /// Anyone may relay the proof. `proof` holds the trie nodes proving the account's entry
+        /// in the chain's `Agents` map against the latest state root it sent; retired agents
+        /// are not accepted. The alias is replaced by the newer one when proven again.
+        ///
+        /// Parameters:
+        /// - `para_id`: The sister chain the agent is registered on
+        /// - `account`: The agent's account, which is the same on both chains
+        /// - `proof`: Trie nodes of at most `MaxRemoteProofSize` bytes in total
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::verify_remote_agent(Pallet::<T>::proof_size(proof)))]
+        pub fn verify_remote_agent(
+            origin: OriginFor<T>,
+            para_id: ParaId,
+            account: T::AccountId,
+            proof: Vec<Vec<u8>>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            ensure!(
+                Self::proof_size(&proof) <= T::MaxRemoteProofSize::get(),
+                Error::<T>::RemoteProofTooLarge
+            );
+
+            let registry = RemoteRegistries::<T>::get(para_id).ok_or(Error::<T>::UnknownRemoteRegistry)?;
+            let state_root = registry.state_root.ok_or(Error::<T>::NoRemoteStateRoot)?;
+
+            let mut key = registry.agents_prefix.to_vec();
+            key.extend(account.blake2_128_concat());
+            let db = sp_trie::StorageProof::new(proof).into_memory_db::<BlakeTwo256>();
+            let root = <BlakeTwo256 as HashT>::Output::from(state_root.root);
+            let value = sp_trie::read_trie_value::<sp_trie::LayoutV1<BlakeTwo256>, _>(&db, &root, &key, None, None)
+                .map_err(|_| Error::<T>::InvalidRemoteProof)?
+                .ok_or(Error::<T>::RemoteAgentNotFound)?;
+            // Only the leading fields are read, as they are laid out the same on every chain
+            // running this pallet
+            let (_pubkey, role, _trust_score, status) =
+                <(T::AccountId, AgentRole, u64, AgentStatus)>::decode(&mut &value[..])
+                    .map_err(|_| Error::<T>::InvalidRemoteProof)?;
+            ensure!(status != AgentStatus::Retired, Error::<T>::RemoteAgentNotFound);
+
+            AgentAliases::<T>::insert(&account, para_id, RemoteAgent {
+                role,
+                remote_block: state_root.block_number,
+                verified_at: <frame_system::Pallet<T>>::block_number(),
+            });
+
+            // Emit event
+            Self::deposit_event(Event::RemoteAgentVerified { agent_id: account, para_id, role });
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Check that `AgentsByRole` indexes every agent under its role, and that maintenance
+        /// windows belong to agents and are ordered and disjoint
+        #[cfg(any(feature = "try-runtime", test))]
+        pub fn do_try_state() -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            let mut indexed = 0usize;
+            for (role, agent_id, ()) in AgentsByRole::<T>::iter() {
+                indexed = indexed.saturating_add(1);
+                ensure!(
+                    Agents::<T>::get(&agent_id).map_or(false, |agent| agent.role == role),
+                    "AgentRegistry: AgentsByRole lists an agent under a role it does not hold"
+                );
+            }
+            ensure!(
+                indexed == Agents::<T>::iter_keys().count(),
+                "AgentRegistry: not every agent is indexed by role"
+            );
+
+            for (agent_id, windows) in MaintenanceWindows::<T>::iter() {
+                ensure!(
+                    Agents::<T>::contains_key(&agent_id),
+                    "AgentRegistry: maintenance windows of an unknown agent"
+                );
+                ensure!(
+                    windows.iter().all(|window| window.starts_at < window.ends_at),
+                    "AgentRegistry: a maintenance window ends before it starts"
+                );
+                ensure!(
+                    windows.windows(2).all(|pair| pair[0].ends_at <= pair[1].starts_at),
+                    "AgentRegistry: maintenance windows are out of order or overlap"
+                );
+            }
+
+            Ok(())
+        }
+
+        /// Agents registered with `role`, including retired ones, in no particular order
+        pub fn agents_by_role(role: AgentRole) -> Vec<T::AccountId> {
+            AgentsByRole::<T>::iter_key_prefix(role).collect()
+        }
+
+        /// Whether the agent initiated its retirement and has not been retired yet
+        pub fn is_retiring(agent_id: &T::AccountId) -> bool {
+            Retiring::<T>::contains_key(agent_id)
+        }
+
+        /// Longest metadata the agent may store, including its purchased allowance
+        pub fn metadata_limit(agent_id: &T::AccountId) -> u32 {
+            T::MaxMetadataLength::get()
+                .saturating_add(MetadataAllowances::<T>::get(agent_id).extra_bytes)
+                .min(T::MaxMetadataAllowance::get())
+        }
+
+        /// Whether the agent attested a version at or above `MinimumAgentVersion`
+        ///
+        /// Always true while no minimum is set.
+        pub fn meets_minimum_version(agent_id: &T::AccountId) -> bool {
+            match MinimumAgentVersion::<T>::get() {
+                Some(minimum) => AgentVersions::<T>::get(agent_id)
+                    .map_or(false, |attestation| attestation.version >= minimum),
+                None => true,
+            }
+        }
+
+        /// The agent `who` may make `class` calls for as one of its proxy keys
+        pub fn proxied_agent(who: &T::AccountId, class: CallClass) -> Option<T::AccountId> {
+            let agent_id = ProxyOf::<T>::get(who)?;
+            Proxies::<T>::get(&agent_id)
+                .iter()
+                .any(|(proxy, classes)| proxy == who && classes.contains(class))
+                .then_some(agent_id)
+        }
+
+        /// The agent a `class` call from `who` is made for: the agent `who` is a proxy key of,
+        /// if it may make such calls, or `who` itself
+        pub fn acting_agent(who: T::AccountId, class: CallClass) -> T::AccountId {
+            Self::proxied_agent(&who, class).unwrap_or(who)
+        }
+
+        /// Whether `who` is an agent that was granted `capability` and is not retiring
+        pub fn has_capability(who: &T::AccountId, capability: &Capability) -> bool {
+            !Retiring::<T>::contains_key(who) && Capabilities::<T>::get(who).contains(capability)
+        }
+
+        /// Whether `feature` is enabled for `who`, for every agent or for it alone
+        pub fn has_feature(who: &T::AccountId, feature: Feature) -> bool {
+            GlobalFeatures::<T>::get().contains(feature) || AgentFeatures::<T>::get(who).contains(feature)
+        }
+
+        /// The endpoints the agent announced, unless the announcement expired
+        pub fn live_endpoints(agent_id: &T::AccountId) -> Option<Vec<AgentEndpoint<T>>> {
+            let announcement = AddressBook::<T>::get(agent_id)?;
+            if <frame_system::Pallet<T>>::block_number() >= announcement.expires_at {
+                return None;
+            }
+            Some(announcement.endpoints.into_inner())
+        }
+
+        /// Whether the agent's latest telemetry report is younger than `TelemetryTtl` blocks
+        /// and shows it overloaded
+        ///
+        /// Agents that report no telemetry never count as overloaded.
+        pub fn is_overloaded(agent_id: &T::AccountId) -> bool {
+            let Some(latest) = Telemetry::<T>::get(agent_id).last().cloned() else { return false };
+            let now = <frame_system::Pallet<T>>::block_number();
+            now < latest.reported_at.saturating_add(T::TelemetryTtl::get()) && latest.is_overloaded()
+        }
+
+        /// Number of blocks from `from` up to, but excluding, `to` covered by approved
+        /// maintenance windows of the agent
+        ///
+        /// Windows dropped to make room for newer ones no longer count.
+        pub fn maintenance_blocks(
+            agent_id: &T::AccountId,
+            from: BlockNumberFor<T>,
+            to: BlockNumberFor<T>,
+        ) -> BlockNumberFor<T> {
+            MaintenanceWindows::<T>::get(agent_id)
+                .iter()
+                .filter(|window| window.approved)
+                .fold(Zero::zero(), |covered, window| {
+                    let start = window.starts_at.max(from);
+                    let end = window.ends_at.min(to);
+                    covered.saturating_add(end.saturating_sub(start))
+                })
+        }
+
+        /// Sister chains `agent_id` was proven to be a registered agent on
+        pub fn remote_chains(agent_id: &T::AccountId) -> Vec<ParaId> {
+            AgentAliases::<T>::iter_key_prefix(agent_id).collect()
+        }
+
+        /// Total size of the trie nodes in a remote agent proof
+        fn proof_size(proof: &[Vec<u8>]) -> u32 {
+            proof.iter().fold(0u32, |size, node| size.saturating_add(node.len() as u32))
+        }
+
+        /// Take agents without a heartbeat for `StaleAfter` blocks offline
+        ///
+        /// Visits at most `MaxSweepPerBlock` agents after `SweepCursor` within `limit`, and
+        /// returns the weight used. Agents without a heartbeat count as seen when they registered.
+        fn sweep_stale_agents(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+            let stale_after = T::StaleAfter::get();
+            let base = T::DbWeight::get().reads_writes(1, 1);
+            // Every visit reads the agent and its heartbeat, and may write its status
+            let per_agent = T::DbWeight::get()
+                .reads_writes(2, 1)
+                .saturating_add(T::OnStatusChange::on_status_changed_weight());
+            if stale_after.is_zero() || !limit.all_gte(base.saturating_add(per_agent)) {
+                return Weight::zero();
+            }
+
+            let mut agents = match SweepCursor::<T>::get() {
+                Some(last) => Agents::<T>::iter_from(Agents::<T>::hashed_key_for(&last)),
+                None => Agents::<T>::iter(),
+            };
+            let mut used = base;
+            let mut visited = 0u32;
+            let mut cursor = None;
+            // Collected first, as the map must not change while it is iterated
+            let mut stale = Vec::new();
+            while visited < T::MaxSweepPerBlock::get() && limit.all_gte(used.saturating_add(per_agent)) {
+                let Some((agent_id, agent)) = agents.next() else {
+                    cursor = None;
+                    break;
+                };
+                used = used.saturating_add(per_agent);
+                visited = visited.saturating_add(1);
+
+                if matches!(agent.status, AgentStatus::Online | AgentStatus::Maintenance) {
+                    let last_seen = LastSeen::<T>::get(&agent_id).unwrap_or(agent.registered_at);
+                    if now.saturating_sub(last_seen) >= stale_after {
+                        stale.push((agent_id.clone(), agent.status.clone(), last_seen));
+                    }
+                }
+                cursor = Some(agent_id);
+            }
+
+            match cursor {
+                Some(last) => SweepCursor::<T>::put(last),
+                None => SweepCursor::<T>::kill(),
+            }
+            for (agent_id, previous, last_seen) in stale {
+                Agents::<T>::mutate(&agent_id, |maybe_agent| {
+                    if let Some(agent) = maybe_agent {
+                        agent.status = AgentStatus::Offline;
+                    }
+                });
+                T::OnStatusChange::on_status_changed(&agent_id, &previous, &AgentStatus::Offline);
+
+                // Emit event
+                Self::deposit_event(Event::AgentMarkedStale { agent_id, last_seen });
+            }
+
+            used
+        }
+
+        /// Warn an agent that its attested version is below the minimum
+        fn warn_if_outdated(agent_id: &T::AccountId) {
+            let Some(minimum) = MinimumAgentVersion::<T>::get() else { return };
+            let version = AgentVersions::<T>::get(agent_id).map(|attestation| attestation.version);
+            if version.map_or(true, |version| version < minimum) {
+                Self::deposit_event(Event::AgentBelowMinimumVersion {
+                    agent_id: agent_id.clone(),
+                    version,
+                    minimum,
+                });
+            }
+        }
+    }
+
+    impl<T: Config> csuite_primitives::AgentInspector<T::AccountId> for Pallet<T> {
+        fn is_registered(who: &T::AccountId) -> bool {
+            Agents::<T>::contains_key(who)
+        }
+
+        fn is_retiring(who: &T::AccountId) -> bool {
+            Self::is_retiring(who)
+        }
+
+        fn meets_minimum_version(who: &T::AccountId) -> bool {
+            Self::meets_minimum_version(who)
+        }
+
+        fn role(who: &T::AccountId) -> Option<AgentRole> {
+            Agents::<T>::get(who).map(|agent| agent.role)
+        }
+
+        fn trust_score(who: &T::AccountId) -> Option<u64> {
+            Agents::<T>::get(who).map(|agent| agent.trust_score)
+        }
+
+        fn proxied_agent(who: &T::AccountId, class: CallClass) -> Option<T::AccountId> {
+            Self::proxied_agent(who, class)
+        }
+
+        fn has_capability(who: &T::AccountId, capability: &Capability) -> bool {
+            Self::has_capability(who, capability)
+        }
+
+        fn has_feature(who: &T::AccountId, feature: Feature) -> bool {
+            Self::has_feature(who, feature)
+        }
+
+        fn is_overloaded(who: &T::AccountId) -> bool {
+            Self::is_overloaded(who)
+        }
+
+        fn signature_scheme(who: &T::AccountId) -> SignatureScheme {
+            Agents::<T>::get(who).map(|agent| agent.signature_scheme).unwrap_or_default()
+        }
+    }
+
+    impl<T: Config> csuite_primitives::AgentStatusProvider<T::AccountId> for Pallet<T> {
+        fn is_online(who: &T::AccountId) -> bool {
+            Agents::<T>::get(who).map_or(false, |agent| agent.status == AgentStatus::Online)
+        }
+    }
+
+    impl<T: Config> MaintenanceSchedule<T::AccountId, BlockNumberFor<T>> for Pallet<T> {
+        fn maintenance_blocks(
+            who: &T::AccountId,
+            from: BlockNumberFor<T>,
+            to: BlockNumberFor<T>,
+        ) -> BlockNumberFor<T> {
+            Self::maintenance_blocks(who, from, to)
+        }
+    }
+}
+
+/// Origin check that passes for agents granted the capability `C`
+///
+/// Runtimes can use this wherever an `EnsureOrigin` is expected to restrict a call to agents
+/// governance granted a capability, for example oracle submissions. Proxy keys do not pass; the
+/// agent's main key has to sign. Succeeds with the agent's account ID.
+pub struct EnsureAgentWithCapability<T, C>(core::marker::PhantomData<(T, C)>);
+
+impl<T: Config, C: Get<Capability>> EnsureOrigin<T::RuntimeOrigin> for EnsureAgentWithCapability<T, C> {
+    type Success = T::AccountId;
+
+    fn try_origin(o: T::RuntimeOrigin) -> Result<Self::Success, T::RuntimeOrigin> {
+        o.into().and_then(|o| match o {
+            RawOrigin::Signed(who) if Pallet::<T>::has_capability(&who, &C::get()) => Ok(who),
+            r => Err(T::RuntimeOrigin::from(r)),
+        })
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<T::RuntimeOrigin, ()> {
+        let who: T::AccountId = frame_benchmarking::account("capable", 0, 0);
+        Capabilities::<T>::try_mutate(&who, |capabilities| capabilities.try_push(C::get()))
+            .map_err(|_| ())?;
+        Ok(RawOrigin::Signed(who).into())
     }
-} 
\ No newline at end of file
+}
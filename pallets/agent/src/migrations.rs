@@ -0,0 +1,185 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        migrations.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Storage migrations for the Agent Registry pallet
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! Storage migrations for pallet_agent_registry
+
+pub mod v1 {
+    //! Replaces the free-form role name of every agent with an [`AgentRole`], as chosen by
+    //! [`AgentRole::from_legacy_name`], and builds the `AgentsByRole` index.
+
+    use crate::{migrations::v2::OldAgentInfo as AgentInfoV1, AgentRole, AgentStatus, AgentsByRole, Config, Pallet};
+    use codec::{Decode, Encode};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        pallet_prelude::{BoundedVec, OptionQuery},
+        storage_alias,
+        traits::{Get, UncheckedOnRuntimeUpgrade},
+        weights::Weight,
+        Blake2_128Concat,
+    };
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use sp_std::vec::Vec;
+
+    /// Agent layout before roles were enumerated
+    #[derive(Decode, Encode)]
+    pub struct OldAgentInfo<T: Config> {
+        pub pubkey: T::AccountId,
+        pub role: Vec<u8>,
+        pub trust_score: u64,
+        pub status: AgentStatus,
+        pub registered_at: BlockNumberFor<T>,
+        pub metadata: Option<BoundedVec<u8, T::MaxMetadataAllowance>>,
+    }
+
+    /// `Agents` in the layout this migration writes, before signature schemes were added
+    #[storage_alias]
+    type Agents<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as frame_system::Config>::AccountId,
+        AgentInfoV1<T>,
+        OptionQuery,
+    >;
+
+    /// Actual implementation of [`MigrateV0ToV1`]
+    pub struct UncheckedMigrateV0ToV1<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            Agents::<T>::translate::<OldAgentInfo<T>, _>(|agent_id, old| {
+                translated = translated.saturating_add(1);
+                let role = AgentRole::from_legacy_name(&old.role);
+                AgentsByRole::<T>::insert(role, agent_id, ());
+                Some(AgentInfoV1 {
+                    pubkey: old.pubkey,
+                    role,
+                    trust_score: old.trust_score,
+                    status: old.status,
+                    registered_at: old.registered_at,
+                    metadata: old.metadata,
+                })
+            });
+
+            T::DbWeight::get().reads_writes(translated, translated.saturating_mul(2))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+            Ok((Agents::<T>::iter_keys().count() as u64).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            let expected = u64::decode(&mut &state[..])
+                .map_err(|_| "AgentRegistry: failed to decode the pre-upgrade agent count")?;
+            frame_support::ensure!(
+                Agents::<T>::iter_values().count() as u64 == expected,
+                "AgentRegistry: agents were lost while enumerating their roles"
+            );
+            frame_support::ensure!(
+                AgentsByRole::<T>::iter_keys().count() as u64 == expected,
+                "AgentRegistry: not every agent was indexed by role"
+            );
+            Ok(())
+        }
+    }
+
+    /// Enumerate the role of every agent and index agents by role, bumping the storage version
+    /// to 1
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        UncheckedMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v2 {
+    //! Adds the [`SignatureScheme`] to every agent, starting with sr25519, the scheme every
+    //! agent signed with before schemes could be chosen.
+
+    use crate::{AgentInfo, AgentRole, AgentStatus, Agents, Config, Pallet, SignatureScheme};
+    use codec::{Decode, Encode};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        pallet_prelude::BoundedVec,
+        traits::{Get, UncheckedOnRuntimeUpgrade},
+        weights::Weight,
+    };
+    use frame_system::pallet_prelude::BlockNumberFor;
+    #[cfg(feature = "try-runtime")]
+    use sp_std::vec::Vec;
+
+    /// Agent layout before signature schemes were added
+    #[derive(Decode, Encode)]
+    pub struct OldAgentInfo<T: Config> {
+        pub pubkey: T::AccountId,
+        pub role: AgentRole,
+        pub trust_score: u64,
+        pub status: AgentStatus,
+        pub registered_at: BlockNumberFor<T>,
+        pub metadata: Option<BoundedVec<u8, T::MaxMetadataAllowance>>,
+    }
+
+    /// Actual implementation of [`MigrateV1ToV2`]
+    pub struct UncheckedMigrateV1ToV2<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV1ToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            Agents::<T>::translate::<OldAgentInfo<T>, _>(|_, old| {
+                translated = translated.saturating_add(1);
+                Some(AgentInfo {
+                    pubkey: old.pubkey,
+                    role: old.role,
+                    trust_score: old.trust_score,
+                    status: old.status,
+                    registered_at: old.registered_at,
+                    metadata: old.metadata,
+                    signature_scheme: SignatureScheme::Sr25519,
+                })
+            });
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+            Ok((Agents::<T>::iter_keys().count() as u64).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            let expected = u64::decode(&mut &state[..])
+                .map_err(|_| "AgentRegistry: failed to decode the pre-upgrade agent count")?;
+            frame_support::ensure!(
+                Agents::<T>::iter_values().count() as u64 == expected,
+                "AgentRegistry: agents were lost while adding signature schemes"
+            );
+            Ok(())
+        }
+    }
+
+    /// Give every agent the sr25519 signature scheme, bumping the storage version to 2
+    pub type MigrateV1ToV2<T> = VersionedMigration<
+        1,
+        2,
+        UncheckedMigrateV1ToV2<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
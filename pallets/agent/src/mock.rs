@@ -15,14 +15,14 @@ use crate as pallet_agent_registry;
 use frame_support::{
     derive_impl,
     parameter_types,
-    traits::{ConstU16, ConstU32, ConstU64},
+    traits::{ConstU16, ConstU32, ConstU64, EnsureOrigin},
     weights::Weight,
 };
 use frame_system as system;
 use sp_core::H256;
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
-    BuildStorage,
+    BuildStorage, Percent,
 };
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -32,6 +32,7 @@ frame_support::construct_runtime!(
     pub enum Test
     {
         System: frame_system,
+        Balances: pallet_balances,
         AgentRegistry: pallet_agent_registry,
     }
 );
@@ -53,7 +54,7 @@ impl frame_system::Config for Test {
     type DbWeight = ();
     type Version = ();
     type PalletInfo = PalletInfo;
-    type AccountData = ();
+    type AccountData = pallet_balances::AccountData<u64>;
     type OnNewAccount = ();
     type OnKilledAccount = ();
     type SystemWeightInfo = ();
@@ -62,18 +63,84 @@ impl frame_system::Config for Test {
     type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
+}
+
 parameter_types! {
-    pub const MaxRoleLength: u32 = 32;
     pub const MaxMetadataLength: u32 = 1024;
+    pub const OverloadThreshold: Percent = Percent::from_percent(90);
+    pub const NoFeatures: crate::FeatureFlags = crate::FeatureFlags(0);
 }
 
 impl pallet_agent_registry::Config for Test {
     type RuntimeEvent = RuntimeEvent;
-    type MaxRoleLength = MaxRoleLength;
     type MaxMetadataLength = MaxMetadataLength;
+    type MaxMetadataAllowance = ConstU32<4096>;
+    type MetadataTierBytes = ConstU32<1024>;
+    type MetadataTierDeposit = ConstU64<100>;
+    type Currency = Balances;
+    type FeeExemption = ();
+    type RetirementGracePeriod = ConstU64<10>;
+    type OnRetirement = ();
+    type OnRotation = ();
+    type OnStatusChange = ();
+    type MaxEndpoints = ConstU32<4>;
+    type MaxEndpointLength = ConstU32<64>;
+    type EndpointTtl = ConstU64<100>;
+    type AnnouncementCooldown = ConstU64<10>;
+    type StaleAfter = ConstU64<50>;
+    type MaxSweepPerBlock = ConstU32<2>;
+    type MaxProxies = ConstU32<2>;
+    type MaxCapabilities = ConstU32<2>;
+    type DefaultFeatures = NoFeatures;
+    type MaxTelemetrySnapshots = ConstU32<3>;
+    type TelemetryInterval = ConstU64<5>;
+    type TelemetryTtl = ConstU64<20>;
+    type OverloadThreshold = OverloadThreshold;
+    type OverloadQueueDepth = ConstU32<100>;
+    type MaxMaintenanceWindows = ConstU32<3>;
+    type MaxMaintenanceDuration = ConstU64<100>;
+    type RemoteOrigin = EnsureSisterChain;
+    type MaxRemoteProofSize = ConstU32<4_096>;
+    type WeightInfo = ();
+}
+
+/// Treats signed origins of accounts from 1000 up as the sister chain with that ID
+pub struct EnsureSisterChain;
+impl EnsureOrigin<RuntimeOrigin> for EnsureSisterChain {
+    type Success = u32;
+
+    fn try_origin(o: RuntimeOrigin) -> Result<u32, RuntimeOrigin> {
+        match o.clone().into() {
+            Ok(frame_system::RawOrigin::Signed(who)) if who >= 1000 => Ok(who as u32),
+            _ => Err(o),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+        Ok(RuntimeOrigin::signed(1000))
+    }
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-    frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into()
-} 
\ No newline at end of file
+    new_test_ext_with_agents(Vec::new())
+}
+
+// Build genesis storage with the given agents pre-registered.
+pub fn new_test_ext_with_agents(agents: Vec<(u64, Vec<u8>, Option<Vec<u8>>)>) -> sp_io::TestExternalities {
+    RuntimeGenesisConfig {
+        system: Default::default(),
+        balances: pallet_balances::GenesisConfig {
+            balances: (1..=5).map(|who| (who, 1_000)).collect(),
+            ..Default::default()
+        },
+        agent_registry: pallet_agent_registry::GenesisConfig { agents },
+    }
+    .build_storage()
+    .unwrap()
+    .into()
+}
@@ -11,9 +11,34 @@
  * ----------------------------------------------------------------------------
  */
 
-use crate::{mock::*, AgentStatus, Error, Event};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+    mock::*, AgentRole, AgentStatus, CallClass, CallClasses, Capability, EndpointKind, EnsureAgentWithCapability,
+    Error, Event, Feature, FeatureFlags, RemoteStateRoot, SignatureScheme,
+};
+use codec::Encode;
+use frame_support::{
+    assert_noop, assert_ok,
+    storage::StoragePrefixedMap,
+    traits::{EnsureOrigin, Hooks},
+    weights::Weight,
+};
+use sp_core::H256;
+use sp_runtime::{traits::BlakeTwo256, DispatchError, Percent};
 use sp_std::vec;
+use sp_trie::TrieMut;
+
+// Build a trie holding `entries`, returning its root and all of its nodes as a proof
+fn trie_proof(entries: &[(Vec<u8>, Vec<u8>)]) -> ([u8; 32], Vec<Vec<u8>>) {
+    let mut db = sp_trie::MemoryDB::<BlakeTwo256>::default();
+    let mut root = Default::default();
+    {
+        let mut trie = sp_trie::trie_types::TrieDBMutBuilderV1::<BlakeTwo256>::new(&mut db, &mut root).build();
+        for (key, value) in entries {
+            trie.insert(key, value).unwrap();
+        }
+    }
+    (root.into(), db.drain().into_values().map(|(node, _)| node).collect())
+}
 
 #[test]
 fn register_agent_works() {
@@ -23,17 +48,17 @@ fn register_agent_works() {
         
         // Register a new agent
         let agent_id = 1;
-        let role = "Lyra".as_bytes().to_vec();
+        let role = AgentRole::Lyra;
         assert_ok!(AgentRegistry::register_agent(
             RuntimeOrigin::signed(agent_id),
-            role.clone(),
+            role,
             None
         ));
         
         // Check agent exists with correct data
         let agent = AgentRegistry::agents(agent_id).unwrap();
         assert_eq!(agent.pubkey, agent_id);
-        assert_eq!(agent.role.to_vec(), role);
+        assert_eq!(agent.role, role);
         assert_eq!(agent.status, AgentStatus::Online);
         assert_eq!(agent.trust_score, 0);
         assert_eq!(agent.registered_at, 1);
@@ -49,10 +74,10 @@ fn register_agent_fails_when_already_registered() {
     new_test_ext().execute_with(|| {
         // Register agent first time
         let agent_id = 1;
-        let role = "Lyra".as_bytes().to_vec();
+        let role = AgentRole::Lyra;
         assert_ok!(AgentRegistry::register_agent(
             RuntimeOrigin::signed(agent_id),
-            role.clone(),
+            role,
             None
         ));
         
@@ -60,7 +85,7 @@ fn register_agent_fails_when_already_registered() {
         assert_noop!(
             AgentRegistry::register_agent(
                 RuntimeOrigin::signed(agent_id),
-                "Echo".as_bytes().to_vec(),
+                AgentRole::Echo,
                 None
             ),
             Error::<Test>::AgentAlreadyExists
@@ -69,14 +94,14 @@ fn register_agent_fails_when_already_registered() {
 }
 
 #[test]
-fn register_agent_fails_with_empty_role() {
+fn register_agent_fails_with_reserved_custom_role() {
     new_test_ext().execute_with(|| {
-        // Try to register with empty role
+        // Custom roles may not use the IDs of canonical roles
         let agent_id = 1;
         assert_noop!(
             AgentRegistry::register_agent(
                 RuntimeOrigin::signed(agent_id),
-                vec![],
+                AgentRole::Custom(3),
                 None
             ),
             Error::<Test>::InvalidRole
@@ -89,7 +114,7 @@ fn update_status_works() {
     new_test_ext().execute_with(|| {
         // Register agent first
         let agent_id = 1;
-        let role = "Lyra".as_bytes().to_vec();
+        let role = AgentRole::Lyra;
         assert_ok!(AgentRegistry::register_agent(
             RuntimeOrigin::signed(agent_id),
             role,
@@ -132,12 +157,70 @@ fn update_status_fails_for_nonexistent_agent() {
     });
 }
 
+#[test]
+fn update_status_enforces_transition_table() {
+    new_test_ext().execute_with(|| {
+        let agent_id = 1;
+        assert_ok!(AgentRegistry::register_agent(RuntimeOrigin::signed(agent_id), AgentRole::Lyra, None));
+
+        // Maintenance can only be entered from online
+        assert_ok!(AgentRegistry::update_status(RuntimeOrigin::signed(agent_id), AgentStatus::Offline));
+        assert_noop!(
+            AgentRegistry::update_status(RuntimeOrigin::signed(agent_id), AgentStatus::Maintenance),
+            Error::<Test>::InvalidStatusTransition
+        );
+
+        // Setting the current status again is not a transition
+        assert_noop!(
+            AgentRegistry::update_status(RuntimeOrigin::signed(agent_id), AgentStatus::Offline),
+            Error::<Test>::InvalidStatusTransition
+        );
+
+        // Retiring goes through the retirement process, not a status update
+        assert_noop!(
+            AgentRegistry::update_status(RuntimeOrigin::signed(agent_id), AgentStatus::Retired),
+            Error::<Test>::InvalidStatusTransition
+        );
+
+        // Retired is terminal
+        assert_ok!(AgentRegistry::force_set_status(RuntimeOrigin::root(), agent_id, AgentStatus::Retired));
+        assert_noop!(
+            AgentRegistry::update_status(RuntimeOrigin::signed(agent_id), AgentStatus::Online),
+            Error::<Test>::InvalidStatusTransition
+        );
+    });
+}
+
+#[test]
+fn force_set_status_bypasses_transition_table() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let agent_id = 1;
+        assert_ok!(AgentRegistry::register_agent(RuntimeOrigin::signed(agent_id), AgentRole::Lyra, None));
+        assert_ok!(AgentRegistry::force_set_status(RuntimeOrigin::root(), agent_id, AgentStatus::Retired));
+
+        assert_noop!(
+            AgentRegistry::force_set_status(RuntimeOrigin::signed(agent_id), agent_id, AgentStatus::Online),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(AgentRegistry::force_set_status(RuntimeOrigin::root(), agent_id, AgentStatus::Online));
+
+        assert_eq!(AgentRegistry::agents(agent_id).unwrap().status, AgentStatus::Online);
+        System::assert_last_event(Event::AgentStatusUpdated { agent_id, status: AgentStatus::Online }.into());
+
+        assert_noop!(
+            AgentRegistry::force_set_status(RuntimeOrigin::root(), 2, AgentStatus::Online),
+            Error::<Test>::AgentNotFound
+        );
+    });
+}
+
 #[test]
 fn update_metadata_works() {
     new_test_ext().execute_with(|| {
         // Register agent first
         let agent_id = 1;
-        let role = "Lyra".as_bytes().to_vec();
+        let role = AgentRole::Lyra;
         assert_ok!(AgentRegistry::register_agent(
             RuntimeOrigin::signed(agent_id),
             role,
@@ -170,7 +253,7 @@ fn update_trust_score_works() {
     new_test_ext().execute_with(|| {
         // Register agent first
         let agent_id = 1;
-        let role = "Lyra".as_bytes().to_vec();
+        let role = AgentRole::Lyra;
         assert_ok!(AgentRegistry::register_agent(
             RuntimeOrigin::signed(agent_id),
             role,
@@ -208,4 +291,891 @@ fn update_trust_score_works() {
         let agent = AgentRegistry::agents(agent_id).unwrap();
         assert_eq!(agent.trust_score, 5);
     });
-} 
\ No newline at end of file
+}
+
+#[test]
+fn heartbeat_records_last_seen() {
+    new_test_ext().execute_with(|| {
+        // Register agent first
+        let agent_id = 1;
+        assert_ok!(AgentRegistry::register_agent(
+            RuntimeOrigin::signed(agent_id),
+            AgentRole::Lyra,
+            None
+        ));
+        
+        // Send a heartbeat at block 5
+        System::set_block_number(5);
+        assert_ok!(AgentRegistry::heartbeat(RuntimeOrigin::signed(agent_id)));
+        
+        // Check last seen block was recorded
+        assert_eq!(AgentRegistry::last_seen(agent_id), Some(5));
+        
+        // Check event was emitted
+        System::assert_has_event(Event::HeartbeatReceived { 
+            agent_id,
+            block: 5
+        }.into());
+    });
+}
+
+#[test]
+fn heartbeat_fails_for_nonexistent_agent() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            AgentRegistry::heartbeat(RuntimeOrigin::signed(1)),
+            Error::<Test>::AgentNotFound
+        );
+    });
+}
+
+#[test]
+fn genesis_registers_agents() {
+    new_test_ext_with_agents(vec![
+        (1, b"Lyra".to_vec(), None),
+        (2, b"Echo".to_vec(), Some(b"v1.0".to_vec())),
+    ]).execute_with(|| {
+        let lyra = AgentRegistry::agents(1).unwrap();
+        assert_eq!(lyra.role, AgentRole::Lyra);
+        assert_eq!(lyra.status, AgentStatus::Online);
+        assert_eq!(lyra.metadata, None);
+
+        let echo = AgentRegistry::agents(2).unwrap();
+        assert_eq!(echo.metadata.unwrap().to_vec(), b"v1.0".to_vec());
+
+        // Genesis agents cannot register a second time
+        assert_noop!(
+            AgentRegistry::register_agent(RuntimeOrigin::signed(1), AgentRole::Lyra, None),
+            Error::<Test>::AgentAlreadyExists
+        );
+    });
+}
+
+#[test]
+fn retirement_completes_after_grace_period() {
+    new_test_ext_with_agents(vec![(1, b"Lyra".to_vec(), None)]).execute_with(|| {
+        System::set_block_number(5);
+        assert_ok!(AgentRegistry::initiate_retirement(RuntimeOrigin::signed(1)));
+        assert_eq!(AgentRegistry::agents(1).unwrap().status, AgentStatus::Offline);
+        assert_eq!(AgentRegistry::retiring(1), Some(15));
+        System::assert_last_event(Event::RetirementInitiated { agent_id: 1, completes_at: 15 }.into());
+
+        // The agent cannot come back online or start retiring twice
+        assert_noop!(
+            AgentRegistry::update_status(RuntimeOrigin::signed(1), AgentStatus::Online),
+            Error::<Test>::AgentRetiring
+        );
+        assert_noop!(
+            AgentRegistry::initiate_retirement(RuntimeOrigin::signed(1)),
+            Error::<Test>::AgentRetiring
+        );
+
+        // Anyone can complete the retirement, but only once the grace period is over
+        System::set_block_number(14);
+        assert_noop!(
+            AgentRegistry::retire_complete(RuntimeOrigin::signed(2), 1),
+            Error::<Test>::GracePeriodActive
+        );
+
+        System::set_block_number(15);
+        assert_ok!(AgentRegistry::retire_complete(RuntimeOrigin::signed(2), 1));
+        assert_eq!(AgentRegistry::agents(1).unwrap().status, AgentStatus::Retired);
+        assert!(!AgentRegistry::is_retiring(&1));
+        System::assert_last_event(Event::RetirementCompleted { agent_id: 1 }.into());
+
+        assert_noop!(
+            AgentRegistry::retire_complete(RuntimeOrigin::signed(2), 1),
+            Error::<Test>::NotRetiring
+        );
+        assert_noop!(
+            AgentRegistry::initiate_retirement(RuntimeOrigin::signed(1)),
+            Error::<Test>::InvalidStatusTransition
+        );
+    });
+}
+
+#[test]
+fn rotate_agent_key_moves_the_registry_entry() {
+    new_test_ext_with_agents(vec![(1, b"Lyra".to_vec(), None)]).execute_with(|| {
+        System::set_block_number(3);
+        assert_ok!(AgentRegistry::heartbeat(RuntimeOrigin::signed(1)));
+        assert_ok!(AgentRegistry::extend_metadata_allowance(RuntimeOrigin::signed(1), 1024));
+
+        assert_ok!(AgentRegistry::rotate_agent_key(RuntimeOrigin::signed(1), 5));
+
+        assert!(AgentRegistry::agents(1).is_none());
+        assert!(AgentRegistry::last_seen(1).is_none());
+        let agent = AgentRegistry::agents(5).unwrap();
+        assert_eq!(agent.pubkey, 5);
+        assert_eq!(agent.role, AgentRole::Lyra);
+        assert_eq!(AgentRegistry::agents_by_role(AgentRole::Lyra), vec![5]);
+        assert_eq!(AgentRegistry::last_seen(5), Some(3));
+
+        // The allowance and its deposit move along
+        assert_eq!(AgentRegistry::metadata_limit(&5), 2048);
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::reserved_balance(5), 100);
+        System::assert_last_event(Event::AgentKeyRotated { old_agent_id: 1, new_agent_id: 5 }.into());
+    });
+}
+
+#[test]
+fn rotate_agent_key_validates_both_accounts() {
+    new_test_ext_with_agents(vec![(1, b"Lyra".to_vec(), None), (2, b"Echo".to_vec(), None)]).execute_with(|| {
+        assert_noop!(
+            AgentRegistry::rotate_agent_key(RuntimeOrigin::signed(1), 1),
+            Error::<Test>::SameAccount
+        );
+        assert_noop!(
+            AgentRegistry::rotate_agent_key(RuntimeOrigin::signed(1), 2),
+            Error::<Test>::AgentAlreadyExists
+        );
+        assert_noop!(
+            AgentRegistry::rotate_agent_key(RuntimeOrigin::signed(3), 4),
+            Error::<Test>::AgentNotFound
+        );
+
+        assert_ok!(AgentRegistry::initiate_retirement(RuntimeOrigin::signed(2)));
+        assert_noop!(
+            AgentRegistry::rotate_agent_key(RuntimeOrigin::signed(2), 4),
+            Error::<Test>::AgentRetiring
+        );
+    });
+}
+
+#[test]
+fn attest_version_records_attestations_and_warns_outdated_agents() {
+    new_test_ext_with_agents(vec![(1, b"Lyra".to_vec(), None), (2, b"Echo".to_vec(), None)]).execute_with(|| {
+        System::set_block_number(2);
+        assert_noop!(
+            AgentRegistry::attest_version(RuntimeOrigin::signed(3), (1, 0, 0), H256::repeat_byte(1)),
+            Error::<Test>::AgentNotFound
+        );
+
+        // Without a minimum every agent passes
+        assert!(AgentRegistry::meets_minimum_version(&1));
+        assert_ok!(AgentRegistry::attest_version(RuntimeOrigin::signed(1), (1, 2, 0), H256::repeat_byte(1)));
+        let attestation = AgentRegistry::agent_versions(1).unwrap();
+        assert_eq!(attestation.version, (1, 2, 0));
+        assert_eq!(attestation.code_hash, H256::repeat_byte(1));
+        assert_eq!(attestation.attested_at, 2);
+
+        assert_noop!(
+            AgentRegistry::set_minimum_agent_version(RuntimeOrigin::signed(1), Some((1, 3, 0))),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(AgentRegistry::set_minimum_agent_version(RuntimeOrigin::root(), Some((1, 3, 0))));
+        System::assert_last_event(Event::MinimumAgentVersionSet { version: Some((1, 3, 0)) }.into());
+        assert!(!AgentRegistry::meets_minimum_version(&1));
+        assert!(!AgentRegistry::meets_minimum_version(&2));
+
+        // Outdated agents and agents without an attestation are warned on heartbeat
+        assert_ok!(AgentRegistry::heartbeat(RuntimeOrigin::signed(2)));
+        System::assert_last_event(
+            Event::AgentBelowMinimumVersion { agent_id: 2, version: None, minimum: (1, 3, 0) }.into(),
+        );
+
+        // Upgrading clears the warning
+        assert_ok!(AgentRegistry::attest_version(RuntimeOrigin::signed(1), (1, 10, 0), H256::repeat_byte(2)));
+        System::assert_last_event(
+            Event::VersionAttested { agent_id: 1, version: (1, 10, 0), code_hash: H256::repeat_byte(2) }.into(),
+        );
+        assert!(AgentRegistry::meets_minimum_version(&1));
+    });
+}
+
+#[test]
+fn metadata_allowances_raise_the_metadata_cap() {
+    new_test_ext_with_agents(vec![(1, b"Lyra".to_vec(), None)]).execute_with(|| {
+        System::set_block_number(1);
+        assert_noop!(
+            AgentRegistry::update_metadata(RuntimeOrigin::signed(1), vec![0u8; 1500]),
+            Error::<Test>::InvalidMetadata
+        );
+
+        // 600 bytes round up to one 1024-byte tier
+        assert_ok!(AgentRegistry::extend_metadata_allowance(RuntimeOrigin::signed(1), 600));
+        assert_eq!(AgentRegistry::metadata_limit(&1), 2048);
+        assert_eq!(Balances::reserved_balance(1), 100);
+        System::assert_last_event(
+            Event::MetadataAllowanceExtended { agent_id: 1, extra_bytes: 1024, deposit: 100 }.into(),
+        );
+        assert_ok!(AgentRegistry::update_metadata(RuntimeOrigin::signed(1), vec![0u8; 1500]));
+
+        // Allowances add up to at most MaxMetadataAllowance
+        assert_ok!(AgentRegistry::extend_metadata_allowance(RuntimeOrigin::signed(1), 2048));
+        assert_eq!(AgentRegistry::metadata_limit(&1), 4096);
+        assert_eq!(AgentRegistry::metadata_allowances(1).deposit, 300);
+        assert_noop!(
+            AgentRegistry::extend_metadata_allowance(RuntimeOrigin::signed(1), 1),
+            Error::<Test>::AllowanceAboveMaximum
+        );
+    });
+}
+
+#[test]
+fn metadata_allowance_deposits_are_checked_and_returned_on_retirement() {
+    new_test_ext_with_agents(vec![(1, b"Lyra".to_vec(), None), (6, b"Echo".to_vec(), None)]).execute_with(|| {
+        // Account 6 has no funds to reserve
+        assert_noop!(
+            AgentRegistry::extend_metadata_allowance(RuntimeOrigin::signed(6), 1024),
+            Error::<Test>::InsufficientDeposit
+        );
+
+        assert_ok!(AgentRegistry::extend_metadata_allowance(RuntimeOrigin::signed(1), 1024));
+        System::set_block_number(1);
+        assert_ok!(AgentRegistry::initiate_retirement(RuntimeOrigin::signed(1)));
+        System::set_block_number(11);
+        assert_ok!(AgentRegistry::retire_complete(RuntimeOrigin::signed(2), 1));
+
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(AgentRegistry::metadata_limit(&1), 1024);
+    });
+}
+
+#[test]
+fn agent_inspector_reports_registration_and_status() {
+    use csuite_primitives::{AgentInspector, AgentStatusProvider};
+
+    new_test_ext().execute_with(|| {
+        let agent_id = 1;
+        assert!(!<AgentRegistry as AgentInspector<u64>>::is_registered(&agent_id));
+        assert!(!<AgentRegistry as AgentStatusProvider<u64>>::is_online(&agent_id));
+
+        assert_ok!(AgentRegistry::register_agent(RuntimeOrigin::signed(agent_id), AgentRole::Lyra, None));
+        assert!(<AgentRegistry as AgentInspector<u64>>::is_registered(&agent_id));
+        assert!(<AgentRegistry as AgentStatusProvider<u64>>::is_online(&agent_id));
+
+        assert_ok!(AgentRegistry::update_status(RuntimeOrigin::signed(agent_id), AgentStatus::Offline));
+        assert!(<AgentRegistry as AgentInspector<u64>>::is_registered(&agent_id));
+        assert!(!<AgentRegistry as AgentStatusProvider<u64>>::is_online(&agent_id));
+    });
+}
+
+#[test]
+fn announce_endpoints_publishes_until_expiry() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(AgentRegistry::register_agent(RuntimeOrigin::signed(1), AgentRole::Lyra, None));
+
+        let endpoints = vec![
+            (EndpointKind::Libp2p, b"/dns4/lyra.example/tcp/30333".to_vec()),
+            (EndpointKind::Https, b"https://lyra.example/rpc".to_vec()),
+        ];
+        assert_ok!(AgentRegistry::announce_endpoints(RuntimeOrigin::signed(1), endpoints));
+        System::assert_last_event(Event::EndpointsAnnounced {
+            agent_id: 1,
+            endpoints: 2,
+            expires_at: 101,
+        }.into());
+
+        let live = AgentRegistry::live_endpoints(&1).expect("Endpoints are live");
+        assert_eq!(live.len(), 2);
+        assert_eq!(live[1].kind, EndpointKind::Https);
+        assert_eq!(live[1].address.to_vec(), b"https://lyra.example/rpc".to_vec());
+
+        System::set_block_number(101);
+        assert!(AgentRegistry::live_endpoints(&1).is_none());
+        assert!(AgentRegistry::address_book(&1).is_some());
+
+        // Rotation takes the announcement along, retirement removes it
+        assert_ok!(AgentRegistry::rotate_agent_key(RuntimeOrigin::signed(1), 2));
+        assert!(AgentRegistry::address_book(&2).is_some());
+        assert_ok!(AgentRegistry::initiate_retirement(RuntimeOrigin::signed(2)));
+        System::set_block_number(111);
+        assert_ok!(AgentRegistry::retire_complete(RuntimeOrigin::signed(3), 2));
+        assert!(AgentRegistry::address_book(&2).is_none());
+    });
+}
+
+#[test]
+fn announce_endpoints_validates_and_rate_limits() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let https = |url: &[u8]| vec![(EndpointKind::Https, url.to_vec())];
+
+        assert_noop!(
+            AgentRegistry::announce_endpoints(RuntimeOrigin::signed(1), https(b"https://a.example")),
+            Error::<Test>::AgentNotFound
+        );
+        assert_ok!(AgentRegistry::register_agent(RuntimeOrigin::signed(1), AgentRole::Lyra, None));
+
+        assert_noop!(
+            AgentRegistry::announce_endpoints(RuntimeOrigin::signed(1), vec![]),
+            Error::<Test>::NoEndpoints
+        );
+        assert_noop!(
+            AgentRegistry::announce_endpoints(RuntimeOrigin::signed(1), https(b"http://a.example")),
+            Error::<Test>::InvalidEndpoint
+        );
+        assert_noop!(
+            AgentRegistry::announce_endpoints(RuntimeOrigin::signed(1), vec![(EndpointKind::Libp2p, vec![b'/'; 65])]),
+            Error::<Test>::InvalidEndpoint
+        );
+        assert_noop!(
+            AgentRegistry::announce_endpoints(RuntimeOrigin::signed(1), vec![(EndpointKind::Https, b"https://a.example".to_vec()); 5]),
+            Error::<Test>::TooManyEndpoints
+        );
+
+        assert_ok!(AgentRegistry::announce_endpoints(RuntimeOrigin::signed(1), https(b"https://a.example")));
+        System::set_block_number(10);
+        assert_noop!(
+            AgentRegistry::announce_endpoints(RuntimeOrigin::signed(1), https(b"https://b.example")),
+            Error::<Test>::AnnouncementTooFrequent
+        );
+        System::set_block_number(11);
+        assert_ok!(AgentRegistry::announce_endpoints(RuntimeOrigin::signed(1), https(b"https://b.example")));
+        assert_eq!(AgentRegistry::address_book(&1).map(|a| a.expires_at), Some(111));
+    });
+}
+
+#[test]
+fn report_telemetry_keeps_the_latest_reports_and_rate_limits() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let load = Percent::from_percent;
+
+        assert_noop!(
+            AgentRegistry::report_telemetry(RuntimeOrigin::signed(1), load(10), load(10), 1),
+            Error::<Test>::AgentNotFound
+        );
+        assert_ok!(AgentRegistry::register_agent(RuntimeOrigin::signed(1), AgentRole::Lyra, None));
+
+        assert_ok!(AgentRegistry::report_telemetry(RuntimeOrigin::signed(1), load(10), load(20), 1));
+        System::assert_last_event(Event::TelemetryReported {
+            agent_id: 1,
+            cpu_load: load(10),
+            mem_usage: load(20),
+            queue_depth: 1,
+        }.into());
+        System::set_block_number(5);
+        assert_noop!(
+            AgentRegistry::report_telemetry(RuntimeOrigin::signed(1), load(10), load(20), 2),
+            Error::<Test>::TelemetryTooFrequent
+        );
+
+        // Only the latest three reports are kept
+        for (block, queue_depth) in [(6, 2), (11, 3), (16, 4)] {
+            System::set_block_number(block);
+            assert_ok!(AgentRegistry::report_telemetry(RuntimeOrigin::signed(1), load(10), load(20), queue_depth));
+        }
+        let telemetry = AgentRegistry::telemetry(&1);
+        assert_eq!(telemetry.iter().map(|snapshot| snapshot.queue_depth).collect::<Vec<_>>(), vec![2, 3, 4]);
+        assert_eq!(telemetry.last().unwrap().reported_at, 16);
+
+        // Telemetry moves with the agent and is dropped when it retires
+        assert_ok!(AgentRegistry::rotate_agent_key(RuntimeOrigin::signed(1), 2));
+        assert!(AgentRegistry::telemetry(&1).is_empty());
+        assert_eq!(AgentRegistry::telemetry(&2).len(), 3);
+        assert_ok!(AgentRegistry::initiate_retirement(RuntimeOrigin::signed(2)));
+        assert_noop!(
+            AgentRegistry::report_telemetry(RuntimeOrigin::signed(2), load(10), load(20), 5),
+            Error::<Test>::AgentRetiring
+        );
+        System::set_block_number(26);
+        assert_ok!(AgentRegistry::retire_complete(RuntimeOrigin::signed(3), 2));
+        assert!(AgentRegistry::telemetry(&2).is_empty());
+    });
+}
+
+#[test]
+fn agents_count_as_overloaded_only_while_their_latest_report_is_fresh() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let load = Percent::from_percent;
+        for agent_id in 1..=3 {
+            assert_ok!(AgentRegistry::register_agent(RuntimeOrigin::signed(agent_id), AgentRole::Lyra, None));
+        }
+
+        assert_ok!(AgentRegistry::report_telemetry(RuntimeOrigin::signed(1), load(95), load(10), 1));
+        assert_ok!(AgentRegistry::report_telemetry(RuntimeOrigin::signed(2), load(10), load(10), 100));
+        assert_ok!(AgentRegistry::report_telemetry(RuntimeOrigin::signed(3), load(89), load(89), 99));
+        assert!(AgentRegistry::is_overloaded(&1));
+        assert!(AgentRegistry::is_overloaded(&2));
+        assert!(!AgentRegistry::is_overloaded(&3));
+        assert!(!AgentRegistry::is_overloaded(&4));
+
+        // A later, healthy report clears the overload
+        System::set_block_number(6);
+        assert_ok!(AgentRegistry::report_telemetry(RuntimeOrigin::signed(2), load(10), load(10), 0));
+        assert!(!AgentRegistry::is_overloaded(&2));
+
+        // Reports older than `TelemetryTtl` no longer count
+        System::set_block_number(21);
+        assert!(!AgentRegistry::is_overloaded(&1));
+    });
+}
+
+#[test]
+fn declare_maintenance_validates_windows_and_drops_the_oldest_ended_one() {
+    new_test_ext_with_agents(vec![(1, b"Lyra".to_vec(), None)]).execute_with(|| {
+        System::set_block_number(10);
+
+        assert_noop!(
+            AgentRegistry::declare_maintenance(RuntimeOrigin::signed(2), 20, 30),
+            Error::<Test>::AgentNotFound
+        );
+        // Windows must start in the future, end after they start and last at most 100 blocks
+        for (starts_at, ends_at) in [(10, 20), (20, 20), (20, 121)] {
+            assert_noop!(
+                AgentRegistry::declare_maintenance(RuntimeOrigin::signed(1), starts_at, ends_at),
+                Error::<Test>::InvalidMaintenanceWindow
+            );
+        }
+
+        assert_ok!(AgentRegistry::declare_maintenance(RuntimeOrigin::signed(1), 40, 50));
+        System::assert_last_event(Event::MaintenanceDeclared { agent_id: 1, starts_at: 40, ends_at: 50 }.into());
+        assert_ok!(AgentRegistry::declare_maintenance(RuntimeOrigin::signed(1), 20, 30));
+        assert_noop!(
+            AgentRegistry::declare_maintenance(RuntimeOrigin::signed(1), 45, 60),
+            Error::<Test>::MaintenanceOverlap
+        );
+        assert_ok!(AgentRegistry::declare_maintenance(RuntimeOrigin::signed(1), 50, 60));
+        let starts = |agent_id: u64| {
+            AgentRegistry::maintenance_windows(agent_id).iter().map(|window| window.starts_at).collect::<Vec<_>>()
+        };
+        assert_eq!(starts(1), vec![20, 40, 50]);
+
+        // The history is full until the oldest window has ended
+        assert_noop!(
+            AgentRegistry::declare_maintenance(RuntimeOrigin::signed(1), 70, 80),
+            Error::<Test>::TooManyMaintenanceWindows
+        );
+        System::set_block_number(30);
+        assert_ok!(AgentRegistry::declare_maintenance(RuntimeOrigin::signed(1), 70, 80));
+        assert_eq!(starts(1), vec![40, 50, 70]);
+
+        assert_noop!(
+            AgentRegistry::approve_maintenance(RuntimeOrigin::signed(1), 1, 40),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            AgentRegistry::approve_maintenance(RuntimeOrigin::root(), 1, 45),
+            Error::<Test>::MaintenanceWindowNotFound
+        );
+        assert_ok!(AgentRegistry::approve_maintenance(RuntimeOrigin::root(), 1, 40));
+        System::assert_last_event(Event::MaintenanceApproved { agent_id: 1, starts_at: 40 }.into());
+        assert!(AgentRegistry::maintenance_windows(1)[0].approved);
+    });
+}
+
+#[test]
+fn maintenance_blocks_count_only_the_overlap_with_approved_windows() {
+    new_test_ext_with_agents(vec![(1, b"Lyra".to_vec(), None)]).execute_with(|| {
+        System::set_block_number(1);
+        for (starts_at, ends_at) in [(10, 20), (30, 40), (50, 60)] {
+            assert_ok!(AgentRegistry::declare_maintenance(RuntimeOrigin::signed(1), starts_at, ends_at));
+        }
+        assert_eq!(AgentRegistry::maintenance_blocks(&1, 0, 100), 0);
+
+        assert_ok!(AgentRegistry::approve_maintenance(RuntimeOrigin::root(), 1, 10));
+        assert_ok!(AgentRegistry::approve_maintenance(RuntimeOrigin::root(), 1, 30));
+
+        assert_eq!(AgentRegistry::maintenance_blocks(&1, 0, 100), 20);
+        // Windows partly in the range count with the blocks inside it
+        assert_eq!(AgentRegistry::maintenance_blocks(&1, 15, 35), 10);
+        assert_eq!(AgentRegistry::maintenance_blocks(&1, 20, 30), 0);
+        assert_eq!(AgentRegistry::maintenance_blocks(&2, 0, 100), 0);
+    });
+}
+
+#[test]
+fn agents_choose_their_signature_scheme() {
+    use csuite_primitives::AgentInspector;
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(AgentRegistry::register_agent(RuntimeOrigin::signed(1), AgentRole::Lyra, None));
+        assert_eq!(<AgentRegistry as AgentInspector<u64>>::signature_scheme(&1), SignatureScheme::Sr25519);
+
+        assert_ok!(AgentRegistry::set_signature_scheme(RuntimeOrigin::signed(1), SignatureScheme::Ed25519));
+        assert_eq!(<AgentRegistry as AgentInspector<u64>>::signature_scheme(&1), SignatureScheme::Ed25519);
+        System::assert_last_event(Event::SignatureSchemeSet { agent_id: 1, scheme: SignatureScheme::Ed25519 }.into());
+
+        assert_noop!(
+            AgentRegistry::set_signature_scheme(RuntimeOrigin::signed(2), SignatureScheme::Ecdsa),
+            Error::<Test>::AgentNotFound
+        );
+    });
+}
+
+#[test]
+fn try_state_checks_the_role_index_and_maintenance_windows() {
+    new_test_ext_with_agents(vec![(1, b"Lyra".to_vec(), None)]).execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(AgentRegistry::declare_maintenance(RuntimeOrigin::signed(1), 10, 20));
+        assert_ok!(AgentRegistry::do_try_state());
+
+        crate::AgentsByRole::<Test>::insert(AgentRole::Echo, 1, ());
+        assert!(AgentRegistry::do_try_state().is_err());
+        crate::AgentsByRole::<Test>::remove(AgentRole::Echo, 1);
+
+        crate::MaintenanceWindows::<Test>::mutate(1, |windows| windows[0].ends_at = 5);
+        assert!(AgentRegistry::do_try_state().is_err());
+    });
+}
+
+#[test]
+fn sister_chains_send_state_roots_only_once_trusted() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let state_root = RemoteStateRoot { block_number: 10, root: [1u8; 32] };
+
+        assert_noop!(
+            AgentRegistry::set_remote_registry(RuntimeOrigin::signed(1), 1000, Some([0u8; 32])),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            AgentRegistry::note_remote_state_root(RuntimeOrigin::signed(1000), state_root),
+            Error::<Test>::UnknownRemoteRegistry
+        );
+
+        assert_ok!(AgentRegistry::set_remote_registry(RuntimeOrigin::root(), 1000, Some([0u8; 32])));
+        // Only the chain itself may send its roots
+        assert_noop!(
+            AgentRegistry::note_remote_state_root(RuntimeOrigin::signed(1), state_root),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(AgentRegistry::note_remote_state_root(RuntimeOrigin::signed(1000), state_root));
+        System::assert_last_event(Event::RemoteStateRootNoted { para_id: 1000, state_root }.into());
+        assert_noop!(
+            AgentRegistry::note_remote_state_root(RuntimeOrigin::signed(1000), RemoteStateRoot {
+                block_number: 10,
+                root: [2u8; 32],
+            }),
+            Error::<Test>::StaleRemoteStateRoot
+        );
+
+        // Changing the prefix keeps the latest root
+        assert_ok!(AgentRegistry::set_remote_registry(RuntimeOrigin::root(), 1000, Some([3u8; 32])));
+        let registry = AgentRegistry::remote_registries(1000).unwrap();
+        assert_eq!(registry.agents_prefix, [3u8; 32]);
+        assert_eq!(registry.state_root, Some(state_root));
+
+        assert_ok!(AgentRegistry::set_remote_registry(RuntimeOrigin::root(), 1000, None));
+        System::assert_last_event(Event::RemoteRegistryRemoved { para_id: 1000 }.into());
+        assert_noop!(
+            AgentRegistry::set_remote_registry(RuntimeOrigin::root(), 1000, None),
+            Error::<Test>::UnknownRemoteRegistry
+        );
+    });
+}
+
+#[test]
+fn verify_remote_agent_records_aliases_proven_against_the_latest_root() {
+    new_test_ext_with_agents(vec![(1, b"Lyra".to_vec(), None), (2, b"Echo".to_vec(), None)]).execute_with(|| {
+        System::set_block_number(1);
+        // This chain's own registry stands in for the sister chain's, with agent 2 retired there
+        let mut retired = AgentRegistry::agents(2).unwrap();
+        retired.status = AgentStatus::Retired;
+        let (root, proof) = trie_proof(&[
+            (crate::Agents::<Test>::hashed_key_for(1), AgentRegistry::agents(1).unwrap().encode()),
+            (crate::Agents::<Test>::hashed_key_for(2), retired.encode()),
+        ]);
+        let agents_prefix = crate::Agents::<Test>::final_prefix();
+
+        assert_noop!(
+            AgentRegistry::verify_remote_agent(RuntimeOrigin::signed(5), 1000, 1, proof.clone()),
+            Error::<Test>::UnknownRemoteRegistry
+        );
+        assert_ok!(AgentRegistry::set_remote_registry(RuntimeOrigin::root(), 1000, Some(agents_prefix)));
+        assert_noop!(
+            AgentRegistry::verify_remote_agent(RuntimeOrigin::signed(5), 1000, 1, proof.clone()),
+            Error::<Test>::NoRemoteStateRoot
+        );
+        assert_ok!(AgentRegistry::note_remote_state_root(RuntimeOrigin::signed(1000), RemoteStateRoot {
+            block_number: 7,
+            root,
+        }));
+
+        // Anyone may relay the proof
+        assert_ok!(AgentRegistry::verify_remote_agent(RuntimeOrigin::signed(5), 1000, 1, proof.clone()));
+        System::assert_last_event(Event::RemoteAgentVerified {
+            agent_id: 1,
+            para_id: 1000,
+            role: AgentRole::Lyra,
+        }.into());
+        let alias = AgentRegistry::agent_aliases(1, 1000).unwrap();
+        assert_eq!((alias.role, alias.remote_block, alias.verified_at), (AgentRole::Lyra, 7, 1));
+        assert_eq!(AgentRegistry::remote_chains(&1), vec![1000]);
+
+        // Retired and unknown agents are not accepted
+        assert_noop!(
+            AgentRegistry::verify_remote_agent(RuntimeOrigin::signed(5), 1000, 2, proof.clone()),
+            Error::<Test>::RemoteAgentNotFound
+        );
+        assert_noop!(
+            AgentRegistry::verify_remote_agent(RuntimeOrigin::signed(5), 1000, 3, proof.clone()),
+            Error::<Test>::RemoteAgentNotFound
+        );
+        // Missing nodes and oversized proofs are rejected
+        assert_noop!(
+            AgentRegistry::verify_remote_agent(RuntimeOrigin::signed(5), 1000, 1, vec![]),
+            Error::<Test>::InvalidRemoteProof
+        );
+        assert_noop!(
+            AgentRegistry::verify_remote_agent(RuntimeOrigin::signed(5), 1000, 1, vec![vec![0u8; 4_097]]),
+            Error::<Test>::RemoteProofTooLarge
+        );
+
+        // A proof against an older root no longer verifies
+        assert_ok!(AgentRegistry::note_remote_state_root(RuntimeOrigin::signed(1000), RemoteStateRoot {
+            block_number: 8,
+            root: [9u8; 32],
+        }));
+        assert_noop!(
+            AgentRegistry::verify_remote_agent(RuntimeOrigin::signed(5), 1000, 1, proof),
+            Error::<Test>::InvalidRemoteProof
+        );
+    });
+}
+
+#[test]
+fn on_idle_takes_stale_agents_offline_in_bounded_batches() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        for agent_id in 1..=3 {
+            assert_ok!(AgentRegistry::register_agent(RuntimeOrigin::signed(agent_id), AgentRole::Lyra, None));
+        }
+        assert_ok!(AgentRegistry::update_status(RuntimeOrigin::signed(3), AgentStatus::Offline));
+        System::set_block_number(30);
+        assert_ok!(AgentRegistry::heartbeat(RuntimeOrigin::signed(2)));
+
+        // Only agent 1 has gone 50 blocks without a heartbeat
+        System::set_block_number(60);
+        AgentRegistry::on_idle(60, Weight::MAX);
+        assert!(AgentRegistry::sweep_cursor().is_some());
+        AgentRegistry::on_idle(60, Weight::MAX);
+        assert!(AgentRegistry::sweep_cursor().is_none());
+
+        assert_eq!(AgentRegistry::agents(1).unwrap().status, AgentStatus::Offline);
+        assert_eq!(AgentRegistry::agents(2).unwrap().status, AgentStatus::Online);
+        System::assert_has_event(Event::AgentMarkedStale { agent_id: 1, last_seen: 1 }.into());
+        assert_eq!(
+            System::events().iter().filter(|record| matches!(
+                record.event,
+                RuntimeEvent::AgentRegistry(Event::AgentMarkedStale { .. })
+            )).count(),
+            1
+        );
+    });
+}
+
+#[test]
+fn proxy_keys_make_only_their_call_classes() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(AgentRegistry::register_agent(RuntimeOrigin::signed(1), AgentRole::Lyra, None));
+        assert_ok!(AgentRegistry::register_agent(RuntimeOrigin::signed(2), AgentRole::Echo, None));
+        let heartbeat = CallClasses::default().with(CallClass::Heartbeat);
+
+        assert_noop!(
+            AgentRegistry::add_proxy(RuntimeOrigin::signed(1), 10, CallClasses::default()),
+            Error::<Test>::NoCallClasses
+        );
+        assert_noop!(
+            AgentRegistry::add_proxy(RuntimeOrigin::signed(1), 2, heartbeat),
+            Error::<Test>::ProxyInUse
+        );
+        assert_ok!(AgentRegistry::add_proxy(RuntimeOrigin::signed(1), 10, heartbeat));
+        System::assert_has_event(Event::ProxyAdded { agent_id: 1, proxy: 10, classes: heartbeat }.into());
+        assert_noop!(
+            AgentRegistry::add_proxy(RuntimeOrigin::signed(2), 10, heartbeat),
+            Error::<Test>::ProxyInUse
+        );
+        assert_noop!(
+            AgentRegistry::register_agent(RuntimeOrigin::signed(10), AgentRole::Lyra, None),
+            Error::<Test>::ProxyInUse
+        );
+
+        // The proxy's heartbeat counts for the agent, but it cannot sign for it
+        System::set_block_number(5);
+        assert_ok!(AgentRegistry::heartbeat(RuntimeOrigin::signed(10)));
+        assert_eq!(AgentRegistry::last_seen(&1), Some(5));
+        assert_eq!(AgentRegistry::proxied_agent(&10, CallClass::Signing), None);
+
+        // Proxies cannot manage the agent
+        assert_noop!(
+            AgentRegistry::update_status(RuntimeOrigin::signed(10), AgentStatus::Offline),
+            Error::<Test>::AgentNotFound
+        );
+
+        assert_ok!(AgentRegistry::remove_proxy(RuntimeOrigin::signed(1), 10));
+        System::assert_has_event(Event::ProxyRemoved { agent_id: 1, proxy: 10 }.into());
+        assert!(AgentRegistry::proxy_of(&10).is_none());
+        assert_noop!(AgentRegistry::heartbeat(RuntimeOrigin::signed(10)), Error::<Test>::AgentNotFound);
+        assert_noop!(
+            AgentRegistry::remove_proxy(RuntimeOrigin::signed(1), 10),
+            Error::<Test>::ProxyNotFound
+        );
+    });
+}
+
+frame_support::parameter_types! {
+    pub const OracleCapability: Capability = *b"oracle\0\0";
+}
+
+#[test]
+fn capabilities_gate_the_agent_origin() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        type EnsureOracle = EnsureAgentWithCapability<Test, OracleCapability>;
+        let oracle = OracleCapability::get();
+        assert_ok!(AgentRegistry::register_agent(RuntimeOrigin::signed(1), AgentRole::Lyra, None));
+
+        assert_noop!(
+            AgentRegistry::grant_capability(RuntimeOrigin::signed(1), 1, oracle),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            AgentRegistry::grant_capability(RuntimeOrigin::root(), 2, oracle),
+            Error::<Test>::AgentNotFound
+        );
+        assert!(EnsureOracle::try_origin(RuntimeOrigin::signed(1)).is_err());
+
+        assert_ok!(AgentRegistry::grant_capability(RuntimeOrigin::root(), 1, oracle));
+        System::assert_has_event(Event::CapabilityGranted { agent_id: 1, capability: oracle }.into());
+        assert_eq!(EnsureOracle::try_origin(RuntimeOrigin::signed(1)).ok(), Some(1));
+        assert!(EnsureOracle::try_origin(RuntimeOrigin::root()).is_err());
+
+        // Granting again changes nothing, and the list is bounded by `MaxCapabilities`
+        assert_ok!(AgentRegistry::grant_capability(RuntimeOrigin::root(), 1, oracle));
+        assert_ok!(AgentRegistry::grant_capability(RuntimeOrigin::root(), 1, *b"indexer\0"));
+        assert_noop!(
+            AgentRegistry::grant_capability(RuntimeOrigin::root(), 1, *b"auditor\0"),
+            Error::<Test>::TooManyCapabilities
+        );
+
+        // Capabilities move with the agent's key
+        assert_ok!(AgentRegistry::rotate_agent_key(RuntimeOrigin::signed(1), 3));
+        assert!(EnsureOracle::try_origin(RuntimeOrigin::signed(1)).is_err());
+        assert_eq!(EnsureOracle::try_origin(RuntimeOrigin::signed(3)).ok(), Some(3));
+
+        assert_ok!(AgentRegistry::revoke_capability(RuntimeOrigin::root(), 3, oracle));
+        System::assert_has_event(Event::CapabilityRevoked { agent_id: 3, capability: oracle }.into());
+        assert!(EnsureOracle::try_origin(RuntimeOrigin::signed(3)).is_err());
+        assert_noop!(
+            AgentRegistry::revoke_capability(RuntimeOrigin::root(), 3, oracle),
+            Error::<Test>::CapabilityNotFound
+        );
+
+        // Retiring agents lose their capabilities
+        assert_ok!(AgentRegistry::grant_capability(RuntimeOrigin::root(), 3, oracle));
+        assert_ok!(AgentRegistry::initiate_retirement(RuntimeOrigin::signed(3)));
+        assert!(EnsureOracle::try_origin(RuntimeOrigin::signed(3)).is_err());
+        assert_noop!(
+            AgentRegistry::grant_capability(RuntimeOrigin::root(), 3, *b"auditor\0"),
+            Error::<Test>::AgentRetiring
+        );
+    });
+}
+
+#[test]
+fn features_are_enabled_globally_or_per_agent() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let frost = FeatureFlags::default().with(Feature::FrostSigning);
+        assert_ok!(AgentRegistry::register_agent(RuntimeOrigin::signed(1), AgentRole::Lyra, None));
+        assert_ok!(AgentRegistry::register_agent(RuntimeOrigin::signed(2), AgentRole::Echo, None));
+
+        assert_noop!(
+            AgentRegistry::set_agent_features(RuntimeOrigin::signed(1), 1, frost),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            AgentRegistry::set_agent_features(RuntimeOrigin::root(), 3, frost),
+            Error::<Test>::AgentNotFound
+        );
+        assert!(!AgentRegistry::has_feature(&1, Feature::FrostSigning));
+
+        // Rolled out to agent 1 only
+        assert_ok!(AgentRegistry::set_agent_features(RuntimeOrigin::root(), 1, frost));
+        System::assert_has_event(Event::AgentFeaturesSet { agent_id: 1, features: frost }.into());
+        assert!(AgentRegistry::has_feature(&1, Feature::FrostSigning));
+        assert!(!AgentRegistry::has_feature(&1, Feature::PrioritySubmission));
+        assert!(!AgentRegistry::has_feature(&2, Feature::FrostSigning));
+
+        // Enabled for everyone
+        assert_noop!(
+            AgentRegistry::set_global_features(RuntimeOrigin::signed(1), FeatureFlags::all()),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(AgentRegistry::set_global_features(RuntimeOrigin::root(), FeatureFlags::all()));
+        System::assert_has_event(Event::GlobalFeaturesSet { features: FeatureFlags::all() }.into());
+        assert!(AgentRegistry::has_feature(&2, Feature::PrioritySubmission));
+        assert_ok!(AgentRegistry::set_global_features(RuntimeOrigin::root(), FeatureFlags::default()));
+
+        // Per-agent features move with the agent's key, and clearing them removes the entry
+        assert_ok!(AgentRegistry::rotate_agent_key(RuntimeOrigin::signed(1), 3));
+        assert!(!AgentRegistry::has_feature(&1, Feature::FrostSigning));
+        assert!(AgentRegistry::has_feature(&3, Feature::FrostSigning));
+        assert_ok!(AgentRegistry::set_agent_features(RuntimeOrigin::root(), 3, FeatureFlags::default()));
+        assert!(!crate::AgentFeatures::<Test>::contains_key(3));
+    });
+}
+
+#[test]
+fn agents_are_listed_by_role() {
+    new_test_ext_with_agents(vec![(1, b"Lyra".to_vec(), None), (2, b"Custom(300)".to_vec(), None)]).execute_with(|| {
+        assert_eq!(AgentRegistry::agents(1).unwrap().role, AgentRole::Lyra);
+        assert_eq!(AgentRegistry::agents(2).unwrap().role, AgentRole::Custom(300));
+
+        assert_ok!(AgentRegistry::register_agent(RuntimeOrigin::signed(3), AgentRole::Lyra, None));
+        assert_ok!(AgentRegistry::register_agent(RuntimeOrigin::signed(4), AgentRole::Custom(300), None));
+
+        let mut lyras = AgentRegistry::agents_by_role(AgentRole::Lyra);
+        lyras.sort();
+        assert_eq!(lyras, vec![1, 3]);
+        assert_eq!(AgentRegistry::agents_by_role(AgentRole::Custom(300)).len(), 2);
+        assert!(AgentRegistry::agents_by_role(AgentRole::Echo).is_empty());
+
+        // Roles have canonical IDs and display names that parse back
+        assert_eq!(AgentRole::Verdict.id(), 3);
+        assert_eq!(AgentRole::from_id(3), Some(AgentRole::Verdict));
+        assert_eq!(AgentRole::from_id(300), Some(AgentRole::Custom(300)));
+        assert_eq!(AgentRole::from_id(200), None);
+        assert_eq!(AgentRole::Custom(300).to_string(), "Custom(300)");
+        assert_eq!(AgentRole::from_name(AgentRole::Custom(300).to_string().as_bytes()), Some(AgentRole::Custom(300)));
+        assert_eq!(AgentRole::from_name(b"Custom(3)"), None);
+    });
+}
+
+#[test]
+fn migration_to_v1_enumerates_roles() {
+    use crate::migrations::{v1::{MigrateV0ToV1, OldAgentInfo}, v2::MigrateV1ToV2};
+    use frame_support::{
+        storage::unhashed,
+        traits::{OnRuntimeUpgrade, StorageVersion},
+    };
+
+    new_test_ext_with_agents(vec![(1, b"Lyra".to_vec(), None), (2, b"Echo".to_vec(), None)]).execute_with(|| {
+        // Put both agents back in the layout from before roles were enumerated
+        for (agent_id, role) in [(1u64, b"lyra".to_vec()), (2, b"Quartermaster".to_vec())] {
+            let agent = AgentRegistry::agents(agent_id).unwrap();
+            unhashed::put(&crate::Agents::<Test>::hashed_key_for(agent_id), &OldAgentInfo::<Test> {
+                pubkey: agent.pubkey,
+                role,
+                trust_score: agent.trust_score,
+                status: agent.status,
+                registered_at: agent.registered_at,
+                metadata: agent.metadata,
+            });
+        }
+        let _ = crate::AgentsByRole::<Test>::clear(u32::MAX, None);
+        StorageVersion::new(0).put::<AgentRegistry>();
+
+        MigrateV0ToV1::<Test>::on_runtime_upgrade();
+        assert_eq!(StorageVersion::get::<AgentRegistry>(), 1);
+
+        // Roles are enumerated in the layout from before signature schemes were added
+        MigrateV1ToV2::<Test>::on_runtime_upgrade();
+        assert_eq!(StorageVersion::get::<AgentRegistry>(), 2);
+        assert_eq!(AgentRegistry::agents(1).unwrap().signature_scheme, SignatureScheme::Sr25519);
+        assert_eq!(AgentRegistry::agents(1).unwrap().role, AgentRole::Lyra);
+        // Unknown names become a custom role derived from the name
+        let custom = AgentRole::from_legacy_name(b"Quartermaster");
+        assert!(matches!(custom, AgentRole::Custom(id) if id >= csuite_primitives::FIRST_CUSTOM_ROLE_ID));
+        assert_eq!(AgentRegistry::agents(2).unwrap().role, custom);
+        assert_eq!(AgentRegistry::agents_by_role(AgentRole::Lyra), vec![1]);
+        assert_eq!(AgentRegistry::agents_by_role(custom), vec![2]);
+    });
+}
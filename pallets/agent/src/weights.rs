@@ -7,14 +7,14 @@
  *  Description: Weight implementations for the agent registry pallet
  *  Version:     1.0.0
  *  License:     BSL (SPDX id BUSL)
- *  Last Update: (May 2025)
+ *  Last Update: (October 2026)
  * ----------------------------------------------------------------------------
  */
 
 //! Autogenerated weights for pallet_agent_registry
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2025-05-01, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! DATE: 2026-10-14, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `benchmark-machine`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
@@ -43,41 +43,505 @@ use sp_std::marker::PhantomData;
 
 /// Weight functions for pallet_agent_registry.
 pub trait WeightInfo {
-    fn register_agent() -> Weight;
+    fn register_agent(m: u32) -> Weight;
     fn update_status() -> Weight;
-    fn update_metadata() -> Weight;
+    fn update_metadata(m: u32) -> Weight;
     fn update_trust_score() -> Weight;
+    fn heartbeat() -> Weight;
+    fn force_set_status() -> Weight;
+    fn initiate_retirement() -> Weight;
+    fn retire_complete() -> Weight;
+    fn rotate_agent_key() -> Weight;
+    fn extend_metadata_allowance() -> Weight;
+    fn attest_version() -> Weight;
+    fn set_minimum_agent_version() -> Weight;
+    fn add_proxy() -> Weight;
+    fn remove_proxy() -> Weight;
+    fn grant_capability() -> Weight;
+    fn revoke_capability() -> Weight;
+    fn announce_endpoints(e: u32) -> Weight;
+    fn report_telemetry() -> Weight;
+    fn set_remote_registry() -> Weight;
+    fn note_remote_state_root() -> Weight;
+    fn verify_remote_agent(p: u32) -> Weight;
+    fn declare_maintenance() -> Weight;
+    fn approve_maintenance() -> Weight;
+    fn set_signature_scheme() -> Weight;
+    fn set_global_features() -> Weight;
+    fn set_agent_features() -> Weight;
 }
 
 /// Weights for pallet_agent_registry using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     // Storage: AgentRegistry Agents (r:1 w:1)
-    // Storage: System Account (r:1 w:0)
-    fn register_agent() -> Weight {
-        Weight::from_parts(25_000_000, 0)
+    // Storage: AgentRegistry ProxyOf (r:1 w:0)
+    // Storage: AgentRegistry AgentsByRole (r:0 w:1)
+    fn register_agent(m: u32) -> Weight {
+        Weight::from_parts(25_335_000, 0)
+            .saturating_add(Weight::from_parts(1_521, 0).saturating_mul(m as u64))
             .saturating_add(T::DbWeight::get().reads(2))
-            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().writes(2))
     }
-    
+
     // Storage: AgentRegistry Agents (r:1 w:1)
     fn update_status() -> Weight {
-        Weight::from_parts(20_000_000, 0)
+        Weight::from_parts(16_968_000, 0)
             .saturating_add(T::DbWeight::get().reads(1))
             .saturating_add(T::DbWeight::get().writes(1))
     }
-    
+
     // Storage: AgentRegistry Agents (r:1 w:1)
-    fn update_metadata() -> Weight {
-        Weight::from_parts(22_000_000, 0)
-            .saturating_add(T::DbWeight::get().reads(1))
+    // Storage: AgentRegistry MetadataAllowances (r:1 w:0)
+    fn update_metadata(m: u32) -> Weight {
+        Weight::from_parts(18_900_000, 0)
+            .saturating_add(Weight::from_parts(1_578, 0).saturating_mul(m as u64))
+            .saturating_add(T::DbWeight::get().reads(2))
             .saturating_add(T::DbWeight::get().writes(1))
     }
-    
+
     // Storage: AgentRegistry Agents (r:1 w:1)
     fn update_trust_score() -> Weight {
-        Weight::from_parts(20_000_000, 0)
+        Weight::from_parts(18_035_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    // Storage: AgentRegistry ProxyOf (r:1 w:0)
+    // Storage: AgentRegistry Agents (r:1 w:0)
+    // Storage: Reputation FeelessUsage (r:1 w:1)
+    // Storage: AgentRegistry LastSeen (r:1 w:1)
+    // Storage: AgentRegistry AgentVersions (r:1 w:0)
+    // Storage: AgentRegistry MinimumAgentVersion (r:1 w:0)
+    fn heartbeat() -> Weight {
+        Weight::from_parts(26_438_000, 0)
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    // Storage: AgentRegistry Agents (r:1 w:1)
+    fn force_set_status() -> Weight {
+        Weight::from_parts(16_406_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    // Storage: AgentRegistry Retiring (r:1 w:1)
+    // Storage: AgentRegistry Agents (r:1 w:1)
+    fn initiate_retirement() -> Weight {
+        Weight::from_parts(21_155_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    // Storage: AgentRegistry Retiring (r:1 w:1)
+    // Storage: AgentRegistry Agents (r:1 w:1)
+    // Storage: AgentRegistry LastSeen (r:0 w:1)
+    // Storage: AgentRegistry AddressBook (r:0 w:1)
+    // Storage: AgentRegistry Telemetry (r:0 w:1)
+    // Storage: AgentRegistry MaintenanceWindows (r:0 w:1)
+    // Storage: AgentRegistry Proxies (r:1 w:1)
+    // Storage: AgentRegistry ProxyOf (r:0 w:8)
+    // Storage: AgentRegistry Capabilities (r:0 w:1)
+    // Storage: AgentRegistry MetadataAllowances (r:1 w:1)
+    // Storage: System Account (r:1 w:1)
+    fn retire_complete() -> Weight {
+        Weight::from_parts(58_697_000, 0)
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(17))
+    }
+
+    // Storage: AgentRegistry Agents (r:2 w:2)
+    // Storage: AgentRegistry ProxyOf (r:1 w:8)
+    // Storage: AgentRegistry Retiring (r:1 w:0)
+    // Storage: AgentRegistry LastSeen (r:1 w:2)
+    // Storage: AgentRegistry AgentVersions (r:1 w:2)
+    // Storage: AgentRegistry AddressBook (r:1 w:2)
+    // Storage: AgentRegistry Telemetry (r:1 w:2)
+    // Storage: AgentRegistry MaintenanceWindows (r:1 w:2)
+    // Storage: AgentRegistry Proxies (r:1 w:2)
+    // Storage: AgentRegistry Capabilities (r:1 w:2)
+    // Storage: AgentRegistry MetadataAllowances (r:1 w:2)
+    // Storage: AgentRegistry AgentsByRole (r:0 w:2)
+    // Storage: System Account (r:2 w:2)
+    fn rotate_agent_key() -> Weight {
+        Weight::from_parts(101_066_000, 0)
+            .saturating_add(T::DbWeight::get().reads(14))
+            .saturating_add(T::DbWeight::get().writes(30))
+    }
+
+    // Storage: AgentRegistry Agents (r:1 w:0)
+    // Storage: AgentRegistry MetadataAllowances (r:1 w:1)
+    // Storage: System Account (r:1 w:1)
+    fn extend_metadata_allowance() -> Weight {
+        Weight::from_parts(26_999_000, 0)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    // Storage: AgentRegistry Agents (r:1 w:0)
+    // Storage: AgentRegistry MinimumAgentVersion (r:1 w:0)
+    // Storage: AgentRegistry AgentVersions (r:0 w:1)
+    fn attest_version() -> Weight {
+        Weight::from_parts(19_833_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    // Storage: AgentRegistry MinimumAgentVersion (r:0 w:1)
+    fn set_minimum_agent_version() -> Weight {
+        Weight::from_parts(9_051_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    // Storage: AgentRegistry Agents (r:2 w:0)
+    // Storage: AgentRegistry Retiring (r:1 w:0)
+    // Storage: AgentRegistry ProxyOf (r:1 w:1)
+    // Storage: AgentRegistry Proxies (r:1 w:1)
+    fn add_proxy() -> Weight {
+        Weight::from_parts(25_279_000, 0)
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    // Storage: AgentRegistry Proxies (r:1 w:1)
+    // Storage: AgentRegistry ProxyOf (r:0 w:1)
+    fn remove_proxy() -> Weight {
+        Weight::from_parts(19_494_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    // Storage: AgentRegistry Agents (r:1 w:0)
+    // Storage: AgentRegistry Retiring (r:1 w:0)
+    // Storage: AgentRegistry Capabilities (r:1 w:1)
+    fn grant_capability() -> Weight {
+        Weight::from_parts(19_915_000, 0)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    // Storage: AgentRegistry Capabilities (r:1 w:1)
+    fn revoke_capability() -> Weight {
+        Weight::from_parts(16_990_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    // Storage: AgentRegistry Agents (r:1 w:0)
+    // Storage: AgentRegistry Retiring (r:1 w:0)
+    // Storage: AgentRegistry AddressBook (r:1 w:1)
+    fn announce_endpoints(e: u32) -> Weight {
+        Weight::from_parts(21_987_000, 0)
+            .saturating_add(Weight::from_parts(2_069_000, 0).saturating_mul(e as u64))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    // Storage: AgentRegistry ProxyOf (r:1 w:0)
+    // Storage: AgentRegistry Agents (r:1 w:0)
+    // Storage: AgentRegistry Retiring (r:1 w:0)
+    // Storage: AgentRegistry Telemetry (r:1 w:1)
+    fn report_telemetry() -> Weight {
+        Weight::from_parts(23_823_000, 0)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    // Storage: AgentRegistry RemoteRegistries (r:1 w:1)
+    fn set_remote_registry() -> Weight {
+        Weight::from_parts(15_052_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    // Storage: AgentRegistry RemoteRegistries (r:1 w:1)
+    fn note_remote_state_root() -> Weight {
+        Weight::from_parts(15_851_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    // Storage: AgentRegistry RemoteRegistries (r:1 w:0)
+    // Storage: AgentRegistry AgentAliases (r:0 w:1)
+    fn verify_remote_agent(p: u32) -> Weight {
+        Weight::from_parts(30_211_000, 0)
+            .saturating_add(Weight::from_parts(1_217, 0).saturating_mul(p as u64))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    // Storage: AgentRegistry Agents (r:1 w:0)
+    // Storage: AgentRegistry Retiring (r:1 w:0)
+    // Storage: AgentRegistry MaintenanceWindows (r:1 w:1)
+    fn declare_maintenance() -> Weight {
+        Weight::from_parts(23_385_000, 0)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    // Storage: AgentRegistry MaintenanceWindows (r:1 w:1)
+    fn approve_maintenance() -> Weight {
+        Weight::from_parts(16_977_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    // Storage: AgentRegistry Agents (r:1 w:1)
+    fn set_signature_scheme() -> Weight {
+        Weight::from_parts(15_803_000, 0)
             .saturating_add(T::DbWeight::get().reads(1))
             .saturating_add(T::DbWeight::get().writes(1))
     }
-} 
\ No newline at end of file
+
+    // Storage: AgentRegistry GlobalFeatures (r:0 w:1)
+    fn set_global_features() -> Weight {
+        Weight::from_parts(9_276_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    // Storage: AgentRegistry Agents (r:1 w:0)
+    // Storage: AgentRegistry AgentFeatures (r:0 w:1)
+    fn set_agent_features() -> Weight {
+        Weight::from_parts(13_938_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+    // Storage: AgentRegistry Agents (r:1 w:1)
+    // Storage: AgentRegistry ProxyOf (r:1 w:0)
+    // Storage: AgentRegistry AgentsByRole (r:0 w:1)
+    fn register_agent(m: u32) -> Weight {
+        Weight::from_parts(25_335_000, 0)
+            .saturating_add(Weight::from_parts(1_521, 0).saturating_mul(m as u64))
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+
+    // Storage: AgentRegistry Agents (r:1 w:1)
+    fn update_status() -> Weight {
+        Weight::from_parts(16_968_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    // Storage: AgentRegistry Agents (r:1 w:1)
+    // Storage: AgentRegistry MetadataAllowances (r:1 w:0)
+    fn update_metadata(m: u32) -> Weight {
+        Weight::from_parts(18_900_000, 0)
+            .saturating_add(Weight::from_parts(1_578, 0).saturating_mul(m as u64))
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    // Storage: AgentRegistry Agents (r:1 w:1)
+    fn update_trust_score() -> Weight {
+        Weight::from_parts(18_035_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    // Storage: AgentRegistry ProxyOf (r:1 w:0)
+    // Storage: AgentRegistry Agents (r:1 w:0)
+    // Storage: Reputation FeelessUsage (r:1 w:1)
+    // Storage: AgentRegistry LastSeen (r:1 w:1)
+    // Storage: AgentRegistry AgentVersions (r:1 w:0)
+    // Storage: AgentRegistry MinimumAgentVersion (r:1 w:0)
+    fn heartbeat() -> Weight {
+        Weight::from_parts(26_438_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(6))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+
+    // Storage: AgentRegistry Agents (r:1 w:1)
+    fn force_set_status() -> Weight {
+        Weight::from_parts(16_406_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    // Storage: AgentRegistry Retiring (r:1 w:1)
+    // Storage: AgentRegistry Agents (r:1 w:1)
+    fn initiate_retirement() -> Weight {
+        Weight::from_parts(21_155_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+
+    // Storage: AgentRegistry Retiring (r:1 w:1)
+    // Storage: AgentRegistry Agents (r:1 w:1)
+    // Storage: AgentRegistry LastSeen (r:0 w:1)
+    // Storage: AgentRegistry AddressBook (r:0 w:1)
+    // Storage: AgentRegistry Telemetry (r:0 w:1)
+    // Storage: AgentRegistry MaintenanceWindows (r:0 w:1)
+    // Storage: AgentRegistry Proxies (r:1 w:1)
+    // Storage: AgentRegistry ProxyOf (r:0 w:8)
+    // Storage: AgentRegistry Capabilities (r:0 w:1)
+    // Storage: AgentRegistry MetadataAllowances (r:1 w:1)
+    // Storage: System Account (r:1 w:1)
+    fn retire_complete() -> Weight {
+        Weight::from_parts(58_697_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(17))
+    }
+
+    // Storage: AgentRegistry Agents (r:2 w:2)
+    // Storage: AgentRegistry ProxyOf (r:1 w:8)
+    // Storage: AgentRegistry Retiring (r:1 w:0)
+    // Storage: AgentRegistry LastSeen (r:1 w:2)
+    // Storage: AgentRegistry AgentVersions (r:1 w:2)
+    // Storage: AgentRegistry AddressBook (r:1 w:2)
+    // Storage: AgentRegistry Telemetry (r:1 w:2)
+    // Storage: AgentRegistry MaintenanceWindows (r:1 w:2)
+    // Storage: AgentRegistry Proxies (r:1 w:2)
+    // Storage: AgentRegistry Capabilities (r:1 w:2)
+    // Storage: AgentRegistry MetadataAllowances (r:1 w:2)
+    // Storage: AgentRegistry AgentsByRole (r:0 w:2)
+    // Storage: System Account (r:2 w:2)
+    fn rotate_agent_key() -> Weight {
+        Weight::from_parts(101_066_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(14))
+            .saturating_add(RocksDbWeight::get().writes(30))
+    }
+
+    // Storage: AgentRegistry Agents (r:1 w:0)
+    // Storage: AgentRegistry MetadataAllowances (r:1 w:1)
+    // Storage: System Account (r:1 w:1)
+    fn extend_metadata_allowance() -> Weight {
+        Weight::from_parts(26_999_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+
+    // Storage: AgentRegistry Agents (r:1 w:0)
+    // Storage: AgentRegistry MinimumAgentVersion (r:1 w:0)
+    // Storage: AgentRegistry AgentVersions (r:0 w:1)
+    fn attest_version() -> Weight {
+        Weight::from_parts(19_833_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    // Storage: AgentRegistry MinimumAgentVersion (r:0 w:1)
+    fn set_minimum_agent_version() -> Weight {
+        Weight::from_parts(9_051_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    // Storage: AgentRegistry Agents (r:2 w:0)
+    // Storage: AgentRegistry Retiring (r:1 w:0)
+    // Storage: AgentRegistry ProxyOf (r:1 w:1)
+    // Storage: AgentRegistry Proxies (r:1 w:1)
+    fn add_proxy() -> Weight {
+        Weight::from_parts(25_279_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+
+    // Storage: AgentRegistry Proxies (r:1 w:1)
+    // Storage: AgentRegistry ProxyOf (r:0 w:1)
+    fn remove_proxy() -> Weight {
+        Weight::from_parts(19_494_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+
+    // Storage: AgentRegistry Agents (r:1 w:0)
+    // Storage: AgentRegistry Retiring (r:1 w:0)
+    // Storage: AgentRegistry Capabilities (r:1 w:1)
+    fn grant_capability() -> Weight {
+        Weight::from_parts(19_915_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    // Storage: AgentRegistry Capabilities (r:1 w:1)
+    fn revoke_capability() -> Weight {
+        Weight::from_parts(16_990_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    // Storage: AgentRegistry Agents (r:1 w:0)
+    // Storage: AgentRegistry Retiring (r:1 w:0)
+    // Storage: AgentRegistry AddressBook (r:1 w:1)
+    fn announce_endpoints(e: u32) -> Weight {
+        Weight::from_parts(21_987_000, 0)
+            .saturating_add(Weight::from_parts(2_069_000, 0).saturating_mul(e as u64))
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    // Storage: AgentRegistry ProxyOf (r:1 w:0)
+    // Storage: AgentRegistry Agents (r:1 w:0)
+    // Storage: AgentRegistry Retiring (r:1 w:0)
+    // Storage: AgentRegistry Telemetry (r:1 w:1)
+    fn report_telemetry() -> Weight {
+        Weight::from_parts(23_823_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    // Storage: AgentRegistry RemoteRegistries (r:1 w:1)
+    fn set_remote_registry() -> Weight {
+        Weight::from_parts(15_052_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    // Storage: AgentRegistry RemoteRegistries (r:1 w:1)
+    fn note_remote_state_root() -> Weight {
+        Weight::from_parts(15_851_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    // Storage: AgentRegistry RemoteRegistries (r:1 w:0)
+    // Storage: AgentRegistry AgentAliases (r:0 w:1)
+    fn verify_remote_agent(p: u32) -> Weight {
+        Weight::from_parts(30_211_000, 0)
+            .saturating_add(Weight::from_parts(1_217, 0).saturating_mul(p as u64))
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    // Storage: AgentRegistry Agents (r:1 w:0)
+    // Storage: AgentRegistry Retiring (r:1 w:0)
+    // Storage: AgentRegistry MaintenanceWindows (r:1 w:1)
+    fn declare_maintenance() -> Weight {
+        Weight::from_parts(23_385_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    // Storage: AgentRegistry MaintenanceWindows (r:1 w:1)
+    fn approve_maintenance() -> Weight {
+        Weight::from_parts(16_977_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    // Storage: AgentRegistry Agents (r:1 w:1)
+    fn set_signature_scheme() -> Weight {
+        Weight::from_parts(15_803_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    // Storage: AgentRegistry GlobalFeatures (r:0 w:1)
+    fn set_global_features() -> Weight {
+        Weight::from_parts(9_276_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    // Storage: AgentRegistry Agents (r:1 w:0)
+    // Storage: AgentRegistry AgentFeatures (r:0 w:1)
+    fn set_agent_features() -> Weight {
+        Weight::from_parts(13_938_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+}
@@ -0,0 +1,132 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        lib.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Runtime APIs for consensus logs and FROST signing
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! Runtime APIs used by node-side services and agent clients to read finalized consensus logs
+//! and FROST signing parameters without decoding pallet storage themselves.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_std::vec::Vec;
+
+pub use pallet_consensus_log::{GroupKeyVersion, SessionId, MerkleInclusionProof, SigningPhase};
+
+/// Summary of a finalized consensus log
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub struct FinalizedLogSummary<AccountId, Hash> {
+    /// The finalized log
+    pub log_id: Hash,
+    /// IPFS CID of the log content
+    pub cid: Vec<u8>,
+    /// Agents that signed off the log
+    pub signers: Vec<AccountId>,
+    /// Trust score derived from the signers' reputation
+    pub trust_score: u64,
+    /// Whether the log was finalized with a FROST aggregate signature
+    pub aggregated: bool,
+}
+
+/// Progress of a FROST signing session
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub struct SessionStatus<AccountId, Hash, BlockNumber> {
+    /// The log being signed
+    pub log_id: Hash,
+    /// Current phase of the session
+    pub phase: SigningPhase,
+    /// Block at which the current phase ends
+    pub phase_deadline: BlockNumber,
+    /// Number of signature shares required
+    pub threshold: u32,
+    /// Number of nonce commitments submitted
+    pub commitments: u32,
+    /// Number of signature shares submitted
+    pub shares: u32,
+    /// Agents the session is still waiting on in its current phase
+    pub pending: Vec<AccountId>,
+}
+
+sp_api::decl_runtime_apis! {
+    /// Read access to finalized consensus logs, the logs awaiting each agent and the sequence
+    /// numbers of each agent's submissions
+    #[api_version(6)]
+    pub trait ConsensusLogApi<AccountId, Hash, BlockNumber>
+    where
+        AccountId: Codec,
+        Hash: Codec,
+        BlockNumber: Codec,
+    {
+        /// Summary of a finalized log, or `None` if the log does not exist or is not finalized
+        fn finalized_log_summary(log_id: Hash) -> Option<FinalizedLogSummary<AccountId, Hash>>;
+
+        /// Summaries of the logs finalized in the block this is called at, read from its events
+        fn finalized_in_block() -> Vec<FinalizedLogSummary<AccountId, Hash>>;
+
+        /// Merkle proof that `signer` signed the finalized log, checkable against its signature
+        /// root without the other signatures
+        #[api_version(2)]
+        fn signature_inclusion_proof(log_id: Hash, signer: AccountId) -> Option<MerkleInclusionProof>;
+
+        /// Whether `agent` had signed the log by `block`, including signatures an amendment
+        /// cleared since
+        #[api_version(3)]
+        fn signed_at(log_id: Hash, agent: AccountId, block: BlockNumber) -> bool;
+
+        /// Pending logs `agent` has neither signed nor voted on, oldest first
+        #[api_version(4)]
+        fn pending_duties(agent: AccountId) -> Vec<Hash>;
+
+        /// Metadata blob attached to a log, read only when asked for
+        #[api_version(5)]
+        fn log_metadata_blob(log_id: Hash) -> Option<Vec<u8>>;
+
+        /// Contents of a noted metadata blob
+        #[api_version(5)]
+        fn metadata_blob(hash: Hash) -> Option<Vec<u8>>;
+
+        /// Next sequence number `agent`'s submissions are expected under
+        #[api_version(6)]
+        fn next_sequence(agent: AccountId) -> u64;
+
+        /// Sequence numbers `agent` skipped, as ranges up to but excluding the number submitted
+        /// after them, oldest first
+        #[api_version(6)]
+        fn sequence_gaps(agent: AccountId) -> Vec<(u64, u64)>;
+    }
+
+    /// FROST aggregation parameters and signing session progress
+    #[api_version(2)]
+    pub trait FrostApi<AccountId, Hash, BlockNumber>
+    where
+        AccountId: Codec,
+        Hash: Codec,
+        BlockNumber: Codec,
+    {
+        /// Group public key signatures are aggregated against, if configured
+        fn group_public_key() -> Option<[u8; 32]>;
+
+        /// Number of participants required for a valid aggregate signature
+        fn current_threshold() -> u32;
+
+        /// Status of a signing session, or `None` if it does not exist
+        fn session_status(session_id: SessionId) -> Option<SessionStatus<AccountId, Hash, BlockNumber>>;
+
+        /// Version of the current group key, bumped by every participant set rotation
+        #[api_version(2)]
+        fn group_key_version() -> GroupKeyVersion;
+
+        /// Current participants that should be dropped by the next rotation
+        #[api_version(2)]
+        fn ineligible_participants() -> Vec<AccountId>;
+    }
+}
@@ -6,7 +6,7 @@
 //  Description: FROST-style aggregate signatures for consensus efficiency
 //  Version:     1.0.0
 //  License:     BSL (SPDX id BUSL)
-//  Last Update: (June 2025)
+//  Last Update: (October 2026)
 // ----------------------------------------------------------------------------
 
 //! # FROST-Style Aggregate Signatures
@@ -24,16 +24,30 @@
 //! - Uses threshold signatures with configurable threshold (e.g., 9 of 13 agents)
 //! - Prevents signature forgery through commitment schemes
 //! - Cryptographically secure against adaptive chosen message attacks
+//! - Partial signatures are bound to a [`SigningContext`] (session, message and round), so a
+//!   share produced for one session cannot be replayed into another. Each share must be its
+//!   agent's sr25519 signature over the context's [`SigningContext::binding`]
+//! - The group signs with FROST(Ed25519, SHA-512), so an aggregate is an Ed25519 signature by
+//!   the group key and verifies as one
 
 use frame_support::{
     pallet_prelude::*,
     traits::Randomness,
 };
-use sp_runtime::traits::{BlakeTwo256, Hash};
+use sp_runtime::{
+    traits::{BlakeTwo256, Hash},
+    AccountId32,
+};
 use sp_std::{vec::Vec, collections::btree_map::BTreeMap};
 use codec::{Encode, Decode, MaxEncodedLen};
 use scale_info::TypeInfo;
 
+pub use csuite_primitives::{bitmap_indices, combine_keys, AggregateSignature};
+use csuite_primitives::{
+    check_group_aggregate, verify_group_signature, AggregateCheckError, MultiSchemeVerifier, SignatureScheme,
+    VerifyAgentSignature,
+};
+
 /// Configuration for FROST signature aggregation
 pub trait FrostConfig {
     /// Minimum number of signatures required for aggregation
@@ -50,28 +64,47 @@ impl FrostConfig for DefaultFrostConfig {
     const MAX_PARTICIPANTS: u32 = 13;
 }
 
+/// The context a partial signature is produced in
+///
+/// Every share signs over [`SigningContext::binding`] rather than the bare message, so a share
+/// only verifies in the session, for the message and in the round it was made for.
+#[derive(Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct SigningContext {
+    /// The signing session the share belongs to
+    pub session_id: u64,
+    /// Hash of the message being signed
+    pub message_hash: [u8; 32],
+    /// Signing round within the message's lifetime
+    pub round: u32,
+}
+
+impl SigningContext {
+    /// Create the context for signing `message` in `session_id` and `round`
+    pub fn new(session_id: u64, message: &[u8], round: u32) -> Self {
+        Self {
+            session_id,
+            message_hash: BlakeTwo256::hash(message).into(),
+            round,
+        }
+    }
+
+    /// The payload a partial signature in this context commits to
+    pub fn binding(&self) -> [u8; 32] {
+        BlakeTwo256::hash_of(&(b"frost-share", self)).into()
+    }
+}
+
 /// A partial signature from an individual agent
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct PartialSignature {
-    /// The agent's identifier
+    /// The agent's identifier, which is its sr25519 public key
     pub agent_id: [u8; 32],
-    /// The partial signature value (simplified as bytes for this implementation)
+    /// The agent's sr25519 signature over the binding of `context`
     pub signature_share: [u8; 64],
     /// Commitment to the nonce used in signature generation
     pub nonce_commitment: [u8; 32],
-}
-
-/// Aggregated signature that represents consensus from multiple agents
-#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-pub struct AggregateSignature {
-    /// The aggregated signature value
-    pub signature: [u8; 64],
-    /// Combined public key of participating agents
-    pub aggregate_pubkey: [u8; 32],
-    /// Bitmap indicating which agents participated
-    pub participant_bitmap: [u8; 2], // 16 bits for up to 16 agents
-    /// Challenge value used in aggregation
-    pub challenge: [u8; 32],
+    /// Context the share was produced in
+    pub context: SigningContext,
 }
 
 /// Commitment data for FROST protocol
@@ -90,6 +123,8 @@ pub struct SigningCommitment {
 pub struct AggregationState {
     /// Message being signed
     pub message: Vec<u8>,
+    /// Context every partial signature must be bound to
+    pub context: SigningContext,
     /// Commitments from agents
     pub commitments: BoundedVec<SigningCommitment, ConstU32<16>>,
     /// Partial signatures received
@@ -104,6 +139,7 @@ impl Default for AggregationState {
     fn default() -> Self {
         Self {
             message: Vec::new(),
+            context: SigningContext::default(),
             commitments: BoundedVec::default(),
             partial_signatures: BoundedVec::default(),
             is_complete: false,
@@ -126,10 +162,15 @@ impl<Config: FrostConfig> FrostAggregator<Config> {
     }
 
     /// Start the signing process by collecting commitments
+    ///
+    /// `session_id` and `round` identify this signing attempt; partial signatures must be bound
+    /// to them and to `message`.
     pub fn start_signing(
         &self,
         message: Vec<u8>,
         participants: &[[u8; 32]],
+        session_id: u64,
+        round: u32,
     ) -> Result<AggregationState, FrostError> {
         ensure!(
             participants.len() >= Config::THRESHOLD as usize,
@@ -141,6 +182,7 @@ impl<Config: FrostConfig> FrostAggregator<Config> {
         );
 
         Ok(AggregationState {
+            context: SigningContext::new(session_id, &message, round),
             message,
             commitments: BoundedVec::default(),
             partial_signatures: BoundedVec::default(),
@@ -282,25 +324,36 @@ impl<Config: FrostConfig> FrostAggregator<Config> {
         state: &AggregationState,
         partial_sig: &PartialSignature,
     ) -> Result<(), FrostError> {
+        // Shares bound to another session, message or round are replays
+        if partial_sig.context != state.context {
+            return Err(FrostError::ReplayedSignature);
+        }
+
         // Check if agent made a commitment
         if !state.commitments.iter().any(|c| c.agent_id == partial_sig.agent_id) {
             return Err(FrostError::NoCommitment);
         }
 
-        // Simplified verification (real implementation would verify signature)
-        if partial_sig.signature_share == [0u8; 64] {
+        // The share must be signed by the agent's own key over the binding of the context
+        let signer = AccountId32::new(partial_sig.agent_id);
+        if !MultiSchemeVerifier::verify(
+            SignatureScheme::Sr25519,
+            &signer,
+            &state.context.binding(),
+            &partial_sig.signature_share,
+        ) {
             return Err(FrostError::InvalidSignature);
         }
 
         Ok(())
     }
 
-    /// Verify an aggregated signature
+    /// Verify an aggregated signature over `message` by the FROST group holding `group_key`
     pub fn verify_aggregate(
         &self,
         message: &[u8],
         aggregate_sig: &AggregateSignature,
-        expected_participants: &[[u8; 32]],
+        group_key: &[u8; 32],
     ) -> Result<bool, FrostError> {
         // Verify minimum threshold
         let participant_count = self.count_participants(&aggregate_sig.participant_bitmap);
@@ -308,14 +361,28 @@ impl<Config: FrostConfig> FrostAggregator<Config> {
             return Err(FrostError::BelowThreshold);
         }
 
-        // Simplified verification (real implementation would use proper crypto)
-        // In practice, this would verify: e(sig, G) = e(H(m), agg_pk)
-        
-        // For this simplified version, we just check that the signature is not all zeros
-        let is_valid = aggregate_sig.signature != [0u8; 64] && 
-                      aggregate_sig.aggregate_pubkey != [0u8; 32];
+        Ok(verify_group_signature(&aggregate_sig.signature, message, group_key))
+    }
 
-        Ok(is_valid)
+    /// Verify an aggregated signature against a FROST group
+    ///
+    /// `group_participants` is the full ordered group. The bitmap selects signers by their
+    /// position in that order, the aggregate public key must combine exactly the selected keys
+    /// and the signature must verify over `message` under `group_key`. The checks are shared
+    /// with off-chain verifiers through [`csuite_primitives::check_group_aggregate`].
+    pub fn verify_for_group(
+        &self,
+        message: &[u8],
+        aggregate_sig: &AggregateSignature,
+        group_key: &[u8; 32],
+        group_participants: &[[u8; 32]],
+        threshold: u32,
+    ) -> Result<bool, FrostError> {
+        match check_group_aggregate(aggregate_sig, message, group_key, group_participants, threshold) {
+            Ok(_) => Ok(true),
+            Err(AggregateCheckError::InvalidSignature) => Ok(false),
+            Err(e) => Err(e.into()),
+        }
     }
 
     /// Count the number of participants from bitmap
@@ -380,24 +447,55 @@ pub enum FrostError {
     TooManySignatures,
     /// Agent didn't provide commitment before signing
     NoCommitment,
+    /// Partial signature is bound to a different session, message or round
+    ReplayedSignature,
     /// Aggregate signature below threshold
     BelowThreshold,
+    /// Bitmap selects participants outside the group
+    InvalidBitmap,
+    /// Aggregate public key does not match the selected participants
+    AggregateKeyMismatch,
+}
+
+impl From<AggregateCheckError> for FrostError {
+    fn from(error: AggregateCheckError) -> Self {
+        match error {
+            AggregateCheckError::InvalidBitmap => FrostError::InvalidBitmap,
+            AggregateCheckError::BelowThreshold => FrostError::BelowThreshold,
+            AggregateCheckError::AggregateKeyMismatch => FrostError::AggregateKeyMismatch,
+            AggregateCheckError::InvalidSignature => FrostError::InvalidSignature,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use sp_core::{ed25519, sr25519, Pair};
+
+    // Helper function to derive the key pair of participant `i`
+    fn participant(i: u8) -> sr25519::Pair {
+        sr25519::Pair::from_seed(&[i; 32])
+    }
+
+    // Helper function to sign a share for `context` with `pair`
+    fn share(pair: &sr25519::Pair, context: SigningContext) -> PartialSignature {
+        PartialSignature {
+            agent_id: pair.public().0,
+            signature_share: pair.sign(&context.binding()).0,
+            nonce_commitment: [1u8; 32],
+            context,
+        }
+    }
 
     #[test]
     fn test_frost_aggregation() {
         let aggregator = FrostAggregator::<DefaultFrostConfig>::new();
         let message = b"test consensus message".to_vec();
-        let participants = vec![
-            [1u8; 32], [2u8; 32], [3u8; 32], [4u8; 32], [5u8; 32],
-            [6u8; 32], [7u8; 32], [8u8; 32], [9u8; 32], [10u8; 32],
-        ];
+        let pairs: Vec<sr25519::Pair> = (1..=10u8).map(participant).collect();
+        let participants: Vec<[u8; 32]> = pairs.iter().map(|pair| pair.public().0).collect();
 
-        let mut state = aggregator.start_signing(message.clone(), &participants).unwrap();
+        let mut state = aggregator.start_signing(message.clone(), &participants, 0, 0).unwrap();
 
         // Add commitments
         for (i, participant) in participants.iter().take(10).enumerate() {
@@ -410,21 +508,117 @@ mod tests {
         }
 
         // Add partial signatures
-        for (i, participant) in participants.iter().take(10).enumerate() {
-            let partial_sig = PartialSignature {
-                agent_id: *participant,
-                signature_share: [(i + 1) as u8; 64],
-                nonce_commitment: [(i + 1) as u8; 32],
-            };
-            aggregator.add_partial_signature(&mut state, partial_sig).unwrap();
+        for pair in pairs.iter() {
+            let context = state.context;
+            aggregator.add_partial_signature(&mut state, share(pair, context)).unwrap();
         }
 
         assert!(state.is_complete);
         assert!(state.aggregate_sig.is_some());
 
         let aggregate_sig = state.aggregate_sig.unwrap();
-        let verification = aggregator.verify_aggregate(&message, &aggregate_sig, &participants);
-        assert!(verification.unwrap());
+        let group = ed25519::Pair::from_seed(&[42u8; 32]);
+
+        // Only the group's signature over the message verifies, not the combined shares
+        assert_eq!(aggregator.verify_aggregate(&message, &aggregate_sig, &group.public().0), Ok(false));
+        let signed = AggregateSignature { signature: group.sign(&message).0, ..aggregate_sig };
+        assert_eq!(aggregator.verify_aggregate(&message, &signed, &group.public().0), Ok(true));
+        assert_eq!(aggregator.verify_aggregate(b"another message", &signed, &group.public().0), Ok(false));
+    }
+
+    #[test]
+    fn test_replayed_partial_signature_is_rejected() {
+        let aggregator = FrostAggregator::<DefaultFrostConfig>::new();
+        let message = b"test consensus message".to_vec();
+        let pairs: Vec<sr25519::Pair> = (1..=9u8).map(participant).collect();
+        let participants: Vec<[u8; 32]> = pairs.iter().map(|pair| pair.public().0).collect();
+
+        let mut first = aggregator.start_signing(message.clone(), &participants, 0, 0).unwrap();
+        let mut second = aggregator.start_signing(message.clone(), &participants, 1, 0).unwrap();
+        for state in [&mut first, &mut second] {
+            aggregator.add_commitment(state, SigningCommitment {
+                agent_id: participants[0],
+                nonce_commitment: [1u8; 32],
+                proof_of_knowledge: [1u8; 32],
+            }).unwrap();
+        }
+
+        // A share made for the first session does not verify in the second, even when it
+        // claims the second session's context
+        let replayed = share(&pairs[0], first.context);
+        assert_eq!(
+            aggregator.add_partial_signature(&mut second, replayed.clone()),
+            Err(FrostError::ReplayedSignature)
+        );
+        let relabelled = PartialSignature { context: second.context, ..replayed.clone() };
+        assert_eq!(
+            aggregator.add_partial_signature(&mut second, relabelled),
+            Err(FrostError::InvalidSignature)
+        );
+        aggregator.add_partial_signature(&mut first, replayed).unwrap();
+
+        // Neither does a share for another round of the same session
+        let next_round = SigningContext::new(0, &message, 1);
+        assert_ne!(next_round.binding(), first.context.binding());
+    }
+
+    #[test]
+    fn test_partial_signature_must_be_signed_by_its_agent() {
+        let aggregator = FrostAggregator::<DefaultFrostConfig>::new();
+        let pairs: Vec<sr25519::Pair> = (1..=9u8).map(participant).collect();
+        let participants: Vec<[u8; 32]> = pairs.iter().map(|pair| pair.public().0).collect();
+        let mut state = aggregator.start_signing(b"log".to_vec(), &participants, 0, 0).unwrap();
+        aggregator.add_commitment(&mut state, SigningCommitment {
+            agent_id: participants[0],
+            nonce_commitment: [1u8; 32],
+            proof_of_knowledge: [1u8; 32],
+        }).unwrap();
+
+        // Knowing the public binding is not enough to produce a share for another agent
+        let forged = PartialSignature { agent_id: participants[0], ..share(&pairs[1], state.context) };
+        assert_eq!(aggregator.add_partial_signature(&mut state, forged), Err(FrostError::InvalidSignature));
+        let garbage = PartialSignature { signature_share: [7u8; 64], ..share(&pairs[0], state.context) };
+        assert_eq!(aggregator.add_partial_signature(&mut state, garbage), Err(FrostError::InvalidSignature));
+
+        let context = state.context;
+        aggregator.add_partial_signature(&mut state, share(&pairs[0], context)).unwrap();
+    }
+
+    #[test]
+    fn test_verify_for_group() {
+        let aggregator = FrostAggregator::<DefaultFrostConfig>::new();
+        let participants = vec![[1u8; 32], [2u8; 32], [4u8; 32]];
+        let group = ed25519::Pair::from_seed(&[42u8; 32]);
+        let group_key = group.public().0;
+
+        let aggregate_sig = AggregateSignature {
+            signature: group.sign(b"log").0,
+            aggregate_pubkey: combine_keys([participants[0], participants[2]].iter()),
+            participant_bitmap: [0b101, 0],
+            challenge: [0u8; 32],
+        };
+        assert_eq!(aggregator.verify_for_group(b"log", &aggregate_sig, &group_key, &participants, 2), Ok(true));
+
+        // Another message or another group's key do not verify
+        assert_eq!(aggregator.verify_for_group(b"other", &aggregate_sig, &group_key, &participants, 2), Ok(false));
+        let other_key = ed25519::Pair::from_seed(&[43u8; 32]).public().0;
+        assert_eq!(aggregator.verify_for_group(b"log", &aggregate_sig, &other_key, &participants, 2), Ok(false));
+
+        // Too few signers, a bitmap outside the group and a mismatched signer set are rejected
+        assert_eq!(
+            aggregator.verify_for_group(b"log", &aggregate_sig, &group_key, &participants, 3),
+            Err(FrostError::BelowThreshold)
+        );
+        let outside = AggregateSignature { participant_bitmap: [0b1001, 0], ..aggregate_sig.clone() };
+        assert_eq!(
+            aggregator.verify_for_group(b"log", &outside, &group_key, &participants, 2),
+            Err(FrostError::InvalidBitmap)
+        );
+        let mismatched = AggregateSignature { participant_bitmap: [0b011, 0], ..aggregate_sig };
+        assert_eq!(
+            aggregator.verify_for_group(b"log", &mismatched, &group_key, &participants, 2),
+            Err(FrostError::AggregateKeyMismatch)
+        );
     }
 
     #[test]
@@ -18,10 +18,49 @@
 use super::*;
 use crate::Pallet as ConsensusLog;
 use frame_benchmarking::v2::*;
+use csuite_primitives::{MultiSchemeVerifier, SignatureScheme, VerifyAgentSignature};
+use frame_support::sp_runtime::{
+    app_crypto::{ecdsa, ed25519, sr25519, RuntimePublic},
+    traits::IdentifyAccount,
+    AccountId32, KeyTypeId, MultiSigner,
+};
 use frame_system::RawOrigin;
 use pallet_agent_registry::{AgentStatus, Pallet as AgentRegistry};
 use sp_std::vec;
 
+/// Key type of the keys generated to measure signature verification
+const BENCH_KEY_TYPE: KeyTypeId = KeyTypeId(*b"cslg");
+
+// Helper function to generate a key under `scheme` and sign `message` with it, returning the
+// account of the key and the signature
+fn signed_by_new_key(scheme: SignatureScheme, message: &[u8]) -> (AccountId32, Vec<u8>) {
+    match scheme {
+        SignatureScheme::Sr25519 => {
+            let key = sr25519::Public::generate_pair(BENCH_KEY_TYPE, None);
+            let signature = key.sign(BENCH_KEY_TYPE, &message).expect("Key should be in the keystore");
+            (MultiSigner::from(key).into_account(), signature.0.to_vec())
+        },
+        SignatureScheme::Ed25519 => {
+            let key = ed25519::Public::generate_pair(BENCH_KEY_TYPE, None);
+            let signature = key.sign(BENCH_KEY_TYPE, &message).expect("Key should be in the keystore");
+            (MultiSigner::from(key).into_account(), signature.0.to_vec())
+        },
+        SignatureScheme::Ecdsa => {
+            let key = ecdsa::Public::generate_pair(BENCH_KEY_TYPE, None);
+            let signature = key.sign(BENCH_KEY_TYPE, &message).expect("Key should be in the keystore");
+            (MultiSigner::from(key).into_account(), signature.0.to_vec())
+        },
+    }
+}
+
+// Helper function to sign an approval of the default log under `scheme`, returning the signer,
+// the vote payload and the signature
+fn signed_vote<T: Config>(scheme: SignatureScheme) -> (AccountId32, Vec<u8>, Vec<u8>) {
+    let message = ConsensusLog::<T>::vote_payload(&T::Hash::default(), 0, VoteKind::Approve);
+    let (signer, signature) = signed_by_new_key(scheme, &message);
+    (signer, message, signature)
+}
+
 // Helper function to generate a CID based on an index
 fn generate_cid(i: u32) -> Vec<u8> {
     let mut cid = b"QmBench".to_vec();
@@ -39,12 +78,14 @@ fn generate_signature(i: u32) -> Vec<u8> {
 }
 
 // Helper function to register agents for benchmarking
-fn register_agents<T: Config>(n: u32) -> Vec<T::AccountId> {
+fn register_agents<T: Config + pallet_agent_registry::Config>(n: u32) -> Vec<T::AccountId> {
     let mut agents = Vec::new();
     
     for i in 0..n {
         let account: T::AccountId = account("agent", i, 0);
-        let role = format!("Agent_{}", i).into_bytes();
+        let role = pallet_agent_registry::AgentRole::Custom(
+            csuite_primitives::FIRST_CUSTOM_ROLE_ID.saturating_add(i as u16),
+        );
         
         AgentRegistry::<T>::register_agent(
             RawOrigin::Signed(account.clone()).into(),
@@ -58,7 +99,7 @@ fn register_agents<T: Config>(n: u32) -> Vec<T::AccountId> {
     agents
 }
 
-#[benchmarks]
+#[benchmarks(where T: pallet_agent_registry::Config)]
 mod benchmarks {
     use super::*;
 
@@ -117,7 +158,38 @@ mod benchmarks {
         let log_id = logs.get(0).expect("Log should exist").clone();
 
         #[extrinsic_call]
-        ConsensusLog::<T>::sign_log(RawOrigin::Signed(signer), log_id, signature2);
+        ConsensusLog::<T>::sign_log(RawOrigin::Signed(signer), log_id, signature2, VoteKind::Approve);
+    }
+
+    #[benchmark]
+    fn verify_sr25519() {
+        let (signer, message, signature) = signed_vote(SignatureScheme::Sr25519);
+
+        #[block]
+        {
+            assert!(MultiSchemeVerifier::verify(SignatureScheme::Sr25519, &signer, &message, &signature));
+        }
+    }
+
+    #[benchmark]
+    fn verify_ed25519() {
+        let (signer, message, signature) = signed_vote(SignatureScheme::Ed25519);
+
+        #[block]
+        {
+            assert!(MultiSchemeVerifier::verify(SignatureScheme::Ed25519, &signer, &message, &signature));
+        }
+    }
+
+    // Recovers the public key from the signature and hashes it into the signer's account
+    #[benchmark]
+    fn verify_ecdsa() {
+        let (signer, message, signature) = signed_vote(SignatureScheme::Ecdsa);
+
+        #[block]
+        {
+            assert!(MultiSchemeVerifier::verify(SignatureScheme::Ecdsa, &signer, &message, &signature));
+        }
     }
 
     impl_benchmark_test_suite!(
@@ -25,7 +25,230 @@
 //!
 //! Each consensus log contains the participating agents, their signatures,
 //! metadata about the consensus process, and IPFS content identifiers (CIDs)
-//! for storing larger data off-chain.
+//! for storing larger data off-chain. Submitters and signers are looked up through
+//! `T::Agents`, which only has to tell whether an agent is registered and online.
+//!
+//! ## FROST Signing Sessions
+//!
+//! Members of the FROST group produce an aggregate signature over a log in a signing session.
+//! A session first collects nonce commitments, then signature shares from the agents that
+//! committed, and ends `Complete` once enough shares arrive or `Expired` when a phase deadline
+//! passes without reaching the threshold. Opening a session reserves `SessionDeposit` from the
+//! agent until the session ends, and ended sessions are removed `SessionRetention` blocks later.
+//! Agents that commit but never provide a share are reported through [`SigningFaultReporter`].
+//! Opening, committing to and sharing in sessions is an experimental feature, failing with
+//! `FeatureNotEnabled` unless the agent registry enables `Feature::FrostSigning` for the agent.
+//!
+//! Each signature share carries a binding to its session, the log and the log's amendment round
+//! ([`aggregate::SigningContext`]), available from [`Pallet::share_binding`]. Shares bound to
+//! any other context are rejected as replays.
+//!
+//! A log is sealed with `finalize_consensus_log`, either once every involved agent has signed it
+//! individually or by presenting an aggregate signature from the FROST group. The group signs
+//! with FROST(Ed25519, SHA-512), so an aggregate must verify over the log ID as an Ed25519
+//! signature by the group key.
+//!
+//! An agent may be banned, quarantined or start retiring after it was listed in a log, so its
+//! standing is checked again whenever it signs: agents that are retiring or that
+//! [`ParticipationFilter`] rejects cannot sign or vote. On finalization, individual signatures of
+//! agents that lost their standing since are left out of the quorum, each with a
+//! `SignatureExcluded` event, and dropped from the log. If the log only reaches its quorum
+//! counting them, finalization fails with `SignersExcluded`. An aggregate signature cannot be
+//! split, so one that includes such an agent fails with `SignerNotEligible`.
+//!
+//! When agents are registered, banned or retired, governance rotates the group with
+//! `rotate_participant_set`, accepting the group key of a fresh DKG over the new participants.
+//! A DKG key cannot be derived from the participants' own keys, so governance vouches for it.
+//! Every rotation bumps the [`GroupKeyVersion`] and keeps the previous key, participants and
+//! threshold in `RetiredFrostGroups`, and each finalized log records the version it was signed
+//! under, so historical aggregates still verify through [`Pallet::verify_finalized_aggregate`].
+//! [`Pallet::ineligible_frost_participants`] lists the members a rotation should drop. Sessions
+//! opened before a rotation keep the key version they were opened under and only take
+//! commitments from that group's participants; they cannot produce an aggregate for the new
+//! group.
+//!
+//! On finalization, a Merkle root over the signers and their signatures is stored in
+//! `SignatureRoots`. [`Pallet::signature_inclusion_proof`] proves that a single agent signed a
+//! log against that root, so third parties can check it without fetching every signature.
+//!
+//! ## Blinded Insights
+//!
+//! To keep agents from copying each other's insights before consensus, an agent can open a
+//! blinded insight round among several participants with `open_insight_round`. Each participant
+//! first submits a commitment to its CID and a secret salt, [`Pallet::insight_commitment`], with
+//! `commit_insight`. Once every participant committed, or `InsightCommitPeriod` passed, the round
+//! collects reveals for `InsightRevealPeriod`; both periods are scaled by the round's priority.
+//! Participants that did not commit in time sit the round out.
+//!
+//! `reveal_insight` opens a commitment and logs the insight with every participant involved,
+//! under the round's consensus model and priority, to be signed as any other log. A reveal that
+//! does not match its commitment voids it, with an `InsightRevealMismatched` event. Committed
+//! participants that have not revealed when the reveal phase ends are reported with
+//! `InsightRevealMissed`. Both are passed to [`SigningFaultReporter`].
+//!
+//! ## Consensus Models
+//!
+//! Every log declares a [`ConsensusModel`] on submission, restricted to the models the chain
+//! allows through `AllowedModels`, and `finalize_consensus_log` applies it:
+//! - `Unanimous`: every involved agent signed individually
+//! - `Majority`: more than half of the involved agents signed individually
+//! - `ReputationWeighted`: the individual signers hold more than half of the involved agents'
+//!   combined [`SignerWeight`]
+//! - `ThresholdSignature`: the FROST group signed the log with an aggregate signature; only
+//!   these logs can have signing sessions
+//!
+//! ## Signing History
+//!
+//! `FirstSignatures` keeps the block each agent first signed a log in, and keeps it when an
+//! amendment clears the log's signatures. [`Pallet::signed_at`] answers whether an agent had
+//! signed a log by a given block, so disputes over responsiveness penalties can be settled from
+//! chain state. Signers of a FROST aggregate count as signed from the block the log was
+//! finalized in.
+//!
+//! ## First Responders
+//!
+//! Every signature records the block it was made in. When a log is finalized from individual
+//! signatures, the first `FirstResponders` involved agents to sign it after the submitter are
+//! passed to [`OnFirstResponder`], so the runtime can reward prompt participation.
+//!
+//! ## Votes
+//!
+//! Involved agents vote on a log with `sign_log` and a [`VoteKind`]. Approvals are the log's
+//! signatures and the only votes counting towards its consensus model. Abstentions and
+//! rejections are kept in `LogVotes`: an agent that abstains or rejects has answered the log,
+//! so it is not reported as a missing required co-signer. Once the rejections reach
+//! `VetoThreshold` of the involved agents, the log is `Rejected`: it leaves the pending logs, the
+//! agent indexes and its amendment history, can no longer be signed, amended or finalized, and
+//! its deposit is refunded minus `RetractionFee`, as if it had been retracted. Amending a log
+//! clears its votes along with its signatures.
+//!
+//! ## Signature Schemes
+//!
+//! Every signature on a log is checked by `SignatureVerifier` under the [`SignatureScheme`] its
+//! agent registered, so fleets mixing sr25519, ed25519 and ECDSA agents can sign the same log.
+//! A submitter signs [`Pallet::insight_payload`] of its CID and nonce; countersignatures and
+//! votes sign [`Pallet::vote_payload`] of the log, its amendment round and the vote, so they
+//! cannot be replayed on another round. A signing proxy signs with its own key, under the scheme
+//! of its agent. Sign paths are charged for the most expensive scheme up front and refunded down
+//! to the scheme actually used, as weighed by [`Pallet::verify_weight`].
+//!
+//! ## Sequence Numbers
+//!
+//! Every submission consumes the next of its agent's nonces, which double as sequence numbers.
+//! With `submit_insight` an agent can sign its insight under a later sequence number of its own
+//! counting. The numbers skipped are recorded in [`SequenceGaps`](pallet::SequenceGaps), the
+//! last `MaxSequenceGaps` ranges per agent, with an `InsightSequenceGap` event, so off-chain
+//! supervisors can tell when an agent's submissions were censored or dropped on their way to the
+//! chain. Skipped numbers cannot be submitted later. `ConsensusLogApi` exposes the next expected
+//! sequence number and the recorded gaps.
+//!
+//! ## Batch Signing
+//!
+//! `sign_logs_batch` lets an agent countersign up to `MaxBatchSignatures` logs in one
+//! extrinsic. Every item is signed on its own: failures are reported with `LogSignFailed` and
+//! do not undo the items that succeeded, and `BatchSigned` sums up the batch.
+//!
+//! ## Emergency Pause
+//!
+//! `PauseOrigin` can halt the pallet with `pause` during an incident. While paused, every call
+//! other than `pause` and `unpause` fails with `PalletPaused`; storage stays readable and
+//! signing session deadlines keep being processed.
+//!
+//! ## Amendments
+//!
+//! Until a log is finalized, its submitter can replace its CID and metadata with
+//! `amend_consensus_log`, at most `MaxAmendments` times. Amending clears every signature
+//! collected so far, so involved agents have to sign the new content again. Previous CIDs are
+//! kept in `CidHistory`.
+//!
+//! ## Annotations
+//!
+//! Until a log is finalized or rejected, its involved agents can attach annotations with
+//! `annotate_log`: the CID of a dissenting opinion or supporting material, kept with its author
+//! in `LogAnnotations` alongside the signatures. A log holds at most `MaxAnnotations`
+//! annotations, of which at most `MaxAnnotationsPerAuthor` by the same agent, so one agent
+//! cannot crowd out the others. Amending a log keeps its annotations; retracting it removes them.
+//!
+//! ## Retraction
+//!
+//! Every log reserves `LogDeposit` from its submitter for as long as it is stored and not
+//! rejected. Until another agent signs it, the submitter can delete a log that is neither
+//! finalized nor being signed in a session with `retract_consensus_log`. The log is removed from
+//! every index, the rejected logs included, and the deposit is returned, minus `RetractionFee`,
+//! which is burned.
+//!
+//! ## CID Index
+//!
+//! `LogsByCID` lists at most 100 logs per CID. When a CID's entry is full, `CidIndexOverflow`
+//! either evicts the oldest log from it (the log stays stored and indexed by agent) or rejects
+//! the new log with `CidIndexFull`. `CidLogCount` keeps counting every stored log under its CID,
+//! so a count above the index capacity flags a hot CID whose index is incomplete.
+//!
+//! ## Priorities
+//!
+//! Every log carries a [`LogPriority`] chosen on submission. `Urgent` logs may only be
+//! submitted by agents whose [`SignerWeight`] reaches `UrgentReputation`, and halve the phases
+//! of their signing sessions; `Low` logs double them. First responders are passed the log's
+//! priority so the runtime can scale their reward by [`LogPriority::reward_percent`].
+//! `PendingByPriority` lists the logs awaiting finalization under each priority, and
+//! [`Pallet::pending_logs`] returns them most urgent first.
+//!
+//! Submitting at a priority other than `Normal` is an experimental feature: it fails with
+//! `FeatureNotEnabled` unless the agent registry enables `Feature::PrioritySubmission` for the
+//! submitter.
+//!
+//! ## Pending Duties
+//!
+//! `PendingDuties` lists, per agent, the pending logs it is involved in and has neither signed
+//! nor voted on, oldest first, so agent clients poll a single key instead of filtering every
+//! pending log. A log becomes a duty of its involved agents on submission and again when an
+//! amendment clears its signatures, and stops being one once the agent signs or votes, or the
+//! log is finalized, rejected or retracted. An agent with `MaxPendingDuties` duties loses its
+//! oldest one from the index; the log itself can still be signed.
+//!
+//! ## Metadata Blobs
+//!
+//! Metadata longer than `MaxMetadataLength` is stored apart from the log. Anyone can store a
+//! blob of up to `MaxMetadataBlobLength` bytes with `note_metadata_blob`, reserving
+//! `MetadataBlobDepositBase` plus `MetadataBlobDepositPerByte` for each byte, and the submitter
+//! of a log nobody else signed yet references one of its own blobs by hash with
+//! `attach_metadata_blob`, so nobody can keep another account's deposit reserved. Logs only
+//! carry the hash in `LogMetadataBlobs`; the blob itself is read on demand through
+//! [`Pallet::log_metadata_blob`]. The owner gets the deposit back with `unnote_metadata_blob`
+//! once no log references the blob; amending or retracting a log drops its reference.
+//!
+//! ## Role Quorums
+//!
+//! Governance can require specific roles in multi-agent insights with
+//! `set_role_quorum_policy`, e.g. at least one `Verdict` and one `Beacon`. A [`RoleQuorum`]
+//! lists each required role with the number of distinct agents that must hold it, as read
+//! through `T::Agents`. `RoleQuorumPolicy` applies to every consensus model unless
+//! `ModelRoleQuorumPolicies` sets one for the log's model. The policy is checked against the
+//! agents involved in `submit_insight`, and against the signers when a log with more than one
+//! involved agent is finalized.
+//!
+//! ## Required Co-Signers
+//!
+//! The submitter of an insight can nominate some of its involved agents as required co-signers.
+//! `finalize_consensus_log` fails with `RequiredSignatureMissing` until every one of them signed,
+//! whatever the log's consensus model. Once the priority-scaled `RequiredSignerPeriod` has passed
+//! after submission, each required co-signer that still has not voted on the pending insight is
+//! reported through [`SigningFaultReporter::report_missing_signature`] with a
+//! `RequiredSignerMissed` event.
+//!
+//! ## Block Digests
+//!
+//! Every block that finalizes consensus logs carries a [`csuite_primitives::ConsensusDigest`] in
+//! its header digest, deposited in `on_finalize`: the number of logs finalized and the Merkle
+//! root over their IDs. Light clients can spot C-Suite consensus activity from headers alone,
+//! and check that a log was finalized in a block against the root. At most
+//! `MaxFinalizationsPerBlock` logs can be finalized in one block.
+//!
+//! ## Deduplication
+//!
+//! A submitter cannot log the same CID with the same metadata again within `DedupWindow`
+//! blocks. Each agent's recent submissions are kept in a ring buffer of at most
+//! `MaxRecentSubmissions` entries, pruned of entries older than the window on every submission.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -42,18 +265,160 @@ mod benchmarking;
 
 pub mod weights;
 pub mod aggregate;
+pub mod migrations;
+
+pub use weights::WeightInfo;
+
+use aggregate::{FrostAggregator, DefaultFrostConfig, AggregateSignature, SigningContext};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{weights::Weight, RuntimeDebug};
+use scale_info::TypeInfo;
+
+pub use csuite_primitives::{MerkleInclusionProof, MultiSchemeVerifier, SignatureScheme};
+
+/// Receives reports of agents that failed their signing duties
+pub trait SigningFaultReporter<AccountId> {
+    /// Report an agent that committed to a session but never provided its signature share,
+    /// returning the weight consumed
+    fn report_missing_share(agent_id: &AccountId) -> Weight;
+
+    /// Report a required co-signer that did not sign an insight by its deadline, returning the
+    /// weight consumed
+    fn report_missing_signature(agent_id: &AccountId) -> Weight {
+        Self::report_missing_share(agent_id)
+    }
+
+    /// Report an agent that committed to an insight in a blinded round but did not reveal it
+    /// in time, returning the weight consumed
+    fn report_missed_reveal(agent_id: &AccountId) -> Weight {
+        Self::report_missing_share(agent_id)
+    }
+
+    /// Report an agent that revealed an insight other than the one it committed to in a
+    /// blinded round, returning the weight consumed
+    fn report_mismatched_reveal(agent_id: &AccountId) -> Weight {
+        Self::report_missing_share(agent_id)
+    }
+}
+
+impl<AccountId> SigningFaultReporter<AccountId> for () {
+    fn report_missing_share(_agent_id: &AccountId) -> Weight {
+        Weight::zero()
+    }
+}
+
+/// Notified when agents submit or sign consensus logs
+pub trait OnLogActivity<AccountId, Hash> {
+    /// Called after an agent submitted a consensus log or insight
+    fn on_log_submitted(agent_id: &AccountId, log_id: &Hash);
+
+    /// Called after an agent signed a consensus log
+    fn on_log_signed(agent_id: &AccountId, log_id: &Hash);
+
+    /// Called after a consensus log was finalized, with the agents that signed it off
+    fn on_log_finalized(_signers: &[AccountId], _log_id: &Hash) {}
 
-use aggregate::{FrostAggregator, DefaultFrostConfig, AggregateSignature};
+    /// Called after a submitter retracted a consensus log before it was finalized
+    fn on_log_retracted(_agent_id: &AccountId, _log_id: &Hash) {}
+}
+
+impl<AccountId, Hash> OnLogActivity<AccountId, Hash> for () {
+    fn on_log_submitted(_agent_id: &AccountId, _log_id: &Hash) {}
+    fn on_log_signed(_agent_id: &AccountId, _log_id: &Hash) {}
+}
+
+/// Notified of the agents that signed a finalized log first
+pub trait OnFirstResponder<AccountId> {
+    /// Called for each of the first signers after the submitter, `position` 0 being the first,
+    /// with the priority of the finalized log, returning the weight consumed
+    fn on_first_responder(agent_id: &AccountId, position: u32, priority: LogPriority) -> Weight;
+
+    /// Upper bound on the weight of `on_first_responder`
+    fn on_first_responder_weight() -> Weight {
+        Weight::zero()
+    }
+}
+
+impl<AccountId> OnFirstResponder<AccountId> for () {
+    fn on_first_responder(_agent_id: &AccountId, _position: u32, _priority: LogPriority) -> Weight {
+        Weight::zero()
+    }
+}
+
+/// Voting weight of agents under the reputation-weighted consensus model
+pub trait SignerWeight<AccountId> {
+    /// Weight of the agent's signature, e.g. its reputation
+    fn signer_weight(agent_id: &AccountId) -> u64;
+}
+
+impl<AccountId> SignerWeight<AccountId> for () {
+    fn signer_weight(_agent_id: &AccountId) -> u64 {
+        1
+    }
+}
+
+/// Decides whether an agent's signature may count towards finalizing a log
+pub trait ParticipationFilter<AccountId> {
+    /// Whether the agent is currently allowed to take part in consensus
+    fn can_participate(agent_id: &AccountId) -> bool;
+}
+
+impl<AccountId> ParticipationFilter<AccountId> for () {
+    fn can_participate(_agent_id: &AccountId) -> bool {
+        true
+    }
+}
+
+/// What happens when a log is indexed under a CID whose `LogsByCID` entry is full
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum CidIndexOverflow {
+    /// Drop the oldest log from the index to make room; the log itself stays stored
+    EvictOldest,
+    /// Reject the new log with `CidIndexFull`
+    Reject,
+}
 
 #[frame_support::pallet]
 pub mod pallet {
-    use frame_support::pallet_prelude::*;
+    use frame_support::{
+        dispatch::PostDispatchInfo,
+        pallet_prelude::*,
+        traits::{Contains, Currency, ReservableCurrency},
+    };
     use frame_system::pallet_prelude::*;
     use sp_std::vec::Vec;
-    use pallet_agent_registry::{self as agent_registry, AgentStatus};
+    use frame_support::sp_runtime::{
+        traits::{AtLeast32BitUnsigned, Hash, Saturating, Zero},
+        PerThing, Perbill,
+    };
+    use csuite_primitives::{
+        AgentInspector, AgentRole, AgentStatusProvider, CallClass, ConsensusDigest, ConsensusParameters,
+        ConsensusThresholds, Feature, FeeExemption, SignatureScheme, VerifyAgentSignature,
+    };
+    use pallet_orgs::OrgInspect;
+    use crate::aggregate::{AggregateSignature, DefaultFrostConfig, FrostAggregator, FrostConfig};
+    use crate::{
+        CidIndexOverflow, OnFirstResponder, OnLogActivity, ParticipationFilter, SignerWeight,
+        SigningFaultReporter, WeightInfo,
+    };
+
+    /// Identifier of a FROST signing session
+    pub type SessionId = u64;
+
+    /// Version of the FROST group key, bumped by every participant set rotation
+    pub type GroupKeyVersion = u32;
+
+    /// Identifier of a blinded insight round
+    pub type RoundId = u64;
+
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(10);
 
     #[pallet::config]
-    pub trait Config: frame_system::Config + agent_registry::Config {
+    pub trait Config: frame_system::Config {
         /// The overarching event type.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         
@@ -76,9 +441,178 @@ pub mod pallet {
         /// Maximum number of signatures per consensus log
         #[pallet::constant]
         type MaxSignatures: Get<u32>;
+
+        /// Agent registry lookup for submitters, involved agents and signers
+        type Agents: AgentInspector<Self::AccountId> + AgentStatusProvider<Self::AccountId>;
+
+        /// Checks agent signatures under the scheme each agent registered
+        type SignatureVerifier: VerifyAgentSignature<Self::AccountId>;
+
+        /// Decides which `sign_log` calls are dispatched without fees
+        type FeeExemption: FeeExemption<Self::AccountId>;
+
+        /// Organization membership lookup
+        type Orgs: OrgInspect<Self::AccountId>;
+
+        /// Whether all agents involved in an insight must belong to the same organization
+        #[pallet::constant]
+        type RequireSameOrg: Get<bool>;
+
+        /// Origin allowed to submit consensus logs and insights, yielding the submitting agent
+        type SubmitOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
+        /// Number of blocks a signing session collects nonce commitments
+        #[pallet::constant]
+        type CommitmentPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Number of blocks a signing session collects signature shares
+        #[pallet::constant]
+        type SharePeriod: Get<BlockNumberFor<Self>>;
+
+        /// Number of blocks a completed or expired signing session is kept before it is removed
+        #[pallet::constant]
+        type SessionRetention: Get<BlockNumberFor<Self>>;
+
+        /// Deposit reserved from the agent opening a signing session until the session ends
+        #[pallet::constant]
+        type SessionDeposit: Get<BalanceOf<Self>>;
+
+        /// Maximum number of signing session phases, and of blinded insight round phases, that
+        /// can end in the same block
+        #[pallet::constant]
+        type MaxSessionsPerBlock: Get<u32>;
+
+        /// Where agents that abandon a signing session are reported
+        type SigningFaultReporter: SigningFaultReporter<Self::AccountId>;
+
+        /// Filters out agents whose signatures may not finalize a log
+        type ParticipationFilter: ParticipationFilter<Self::AccountId>;
+
+        /// Hook for pallets tracking log submissions and signatures
+        type OnLogActivity: OnLogActivity<Self::AccountId, Self::Hash>;
+
+        /// Number of blocks within which a submitter may not repeat a CID and metadata pair
+        /// (zero disables deduplication)
+        #[pallet::constant]
+        type DedupWindow: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of recent submissions remembered per agent for deduplication
+        #[pallet::constant]
+        type MaxRecentSubmissions: Get<u32>;
+
+        /// Maximum number of times a log can be amended before it is finalized
+        #[pallet::constant]
+        type MaxAmendments: Get<u32>;
+
+        /// Consensus models logs may be submitted with
+        type AllowedModels: Contains<ConsensusModel>;
+
+        /// Weight of each signer under the reputation-weighted consensus model
+        type SignerWeight: SignerWeight<Self::AccountId>;
+
+        /// Number of agents signing after the submitter that count as first responders
+        #[pallet::constant]
+        type FirstResponders: Get<u32>;
+
+        /// Hook rewarding the first responders of finalized logs
+        type OnFirstResponder: OnFirstResponder<Self::AccountId>;
+
+        /// Origin allowed to pause and unpause the pallet
+        type PauseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Maximum number of logs signed by one `sign_logs_batch` call
+        #[pallet::constant]
+        type MaxBatchSignatures: Get<u32>;
+
+        /// Currency used for log deposits
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// Deposit reserved from the submitter of every log
+        #[pallet::constant]
+        type LogDeposit: Get<BalanceOf<Self>>;
+
+        /// Share of the deposit burned when a submitter retracts a log
+        #[pallet::constant]
+        type RetractionFee: Get<Perbill>;
+
+        /// Policy applied when a CID's entry in `LogsByCID` is full
+        #[pallet::constant]
+        type CidIndexOverflow: Get<CidIndexOverflow>;
+
+        /// Minimum `SignerWeight` an agent needs to submit `Urgent` logs
+        #[pallet::constant]
+        type UrgentReputation: Get<u64>;
+
+        /// Maximum number of roles a role quorum can require
+        #[pallet::constant]
+        type MaxRequiredRoles: Get<u32>;
+
+        /// Maximum number of annotations on a log
+        #[pallet::constant]
+        type MaxAnnotations: Get<u32>;
+
+        /// Maximum number of annotations a single agent can attach to a log
+        #[pallet::constant]
+        type MaxAnnotationsPerAuthor: Get<u32>;
+
+        /// Number of blocks after submission within which an insight's required co-signers have
+        /// to sign it, scaled by the insight's priority
+        #[pallet::constant]
+        type RequiredSignerPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of insights whose required co-signers are checked in the same block
+        #[pallet::constant]
+        type MaxRequiredSignerChecksPerBlock: Get<u32>;
+
+        /// Maximum number of logs finalized in the same block
+        #[pallet::constant]
+        type MaxFinalizationsPerBlock: Get<u32>;
+
+        /// Share of a log's involved agents whose rejections veto it
+        #[pallet::constant]
+        type VetoThreshold: Get<Perbill>;
+
+        /// Maximum number of logs listed in an agent's `PendingDuties`
+        #[pallet::constant]
+        type MaxPendingDuties: Get<u32>;
+
+        /// Maximum length of a metadata blob
+        #[pallet::constant]
+        type MaxMetadataBlobLength: Get<u32>;
+
+        /// Deposit reserved for every metadata blob, on top of its per-byte deposit
+        #[pallet::constant]
+        type MetadataBlobDepositBase: Get<BalanceOf<Self>>;
+
+        /// Deposit reserved for each byte of a metadata blob
+        #[pallet::constant]
+        type MetadataBlobDepositPerByte: Get<BalanceOf<Self>>;
+
+        /// Number of blocks a blinded insight round collects commitments, scaled by the round's
+        /// priority
+        #[pallet::constant]
+        type InsightCommitPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Number of blocks a blinded insight round collects reveals, scaled by the round's
+        /// priority
+        #[pallet::constant]
+        type InsightRevealPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of sequence gaps kept per agent; the oldest is dropped first
+        #[pallet::constant]
+        type MaxSequenceGaps: Get<u32>;
+
+        /// Maximum number of participants in the FROST signing group; no more than the
+        /// `MAX_AGGREGATE_SIGNERS` an aggregate's bitmap can select, nor than `MaxAgentsInvolved`
+        #[pallet::constant]
+        type MaxFrostParticipants: Get<u32>;
+
+        /// Weight information for signature verification
+        type WeightInfo: WeightInfo;
     }
 
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// Signature information for consensus logs
@@ -89,12 +623,125 @@ pub mod pallet {
         pub agent_id: T::AccountId,
         /// The actual signature data
         pub signature: BoundedVec<u8, T::MaxSignatureLength>,
+        /// Block in which the signature was made
+        pub signed_at: BlockNumberFor<T>,
+    }
+
+    /// Material an involved agent attached to a log
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Annotation<T: Config> {
+        /// The agent that attached the annotation
+        pub author: T::AccountId,
+        /// Content identifier of the annotation
+        pub cid: BoundedVec<u8, T::MaxCIDLength>,
+        /// Block in which the annotation was made
+        pub annotated_at: BlockNumberFor<T>,
+    }
+
+    /// How a consensus log reaches finalization
+    #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub enum ConsensusModel {
+        /// Every involved agent signs individually
+        #[default]
+        Unanimous,
+        /// More than half of the involved agents sign individually
+        Majority,
+        /// The individual signers hold more than half of the involved agents' signer weight
+        ReputationWeighted,
+        /// The FROST group signs with an aggregate signature
+        ThresholdSignature,
+    }
+
+    /// How an involved agent votes on a consensus log
+    #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub enum VoteKind {
+        /// Sign the log off; counts towards its consensus model
+        #[default]
+        Approve,
+        /// Answer the log without taking a side; counts neither for nor against it
+        Abstain,
+        /// Object to the log; counts towards its veto
+        Reject,
+    }
+
+    /// An abstention or rejection cast on a log
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct LogVote<T: Config> {
+        /// How the agent voted
+        pub kind: VoteKind,
+        /// The agent's signature over its vote
+        pub signature: BoundedVec<u8, T::MaxSignatureLength>,
+        /// Block in which the vote was cast
+        pub cast_at: BlockNumberFor<T>,
+    }
+
+    /// How urgently a consensus log needs to be signed off
+    #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub enum LogPriority {
+        /// Routine work: signing phases last twice as long and first responders earn half the
+        /// bonus
+        Low,
+        /// Standard signing deadlines and rewards
+        #[default]
+        Normal,
+        /// Time-critical work: signing phases last half as long and first responders earn one
+        /// and a half times the bonus; only agents with `UrgentReputation` may submit it
+        Urgent,
+    }
+
+    impl LogPriority {
+        /// Every priority, most urgent first
+        pub const ALL: [LogPriority; 3] = [LogPriority::Urgent, LogPriority::Normal, LogPriority::Low];
+
+        /// Length of a signing session phase that lasts `period` blocks at `Normal` priority
+        pub fn scale_period<N: AtLeast32BitUnsigned>(self, period: N) -> N {
+            match self {
+                LogPriority::Low => period.saturating_mul(2u32.into()),
+                LogPriority::Normal => period,
+                LogPriority::Urgent => period / 2u32.into(),
+            }
+        }
+
+        /// Share of the first responder bonus paid for this priority, in percent
+        pub fn reward_percent(self) -> u32 {
+            match self {
+                LogPriority::Low => 50,
+                LogPriority::Normal => 100,
+                LogPriority::Urgent => 150,
+            }
+        }
+    }
+
+    /// Roles that must be represented among the agents of a multi-agent insight
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct RoleQuorum<T: Config> {
+        /// Each required role with the minimum number of distinct agents that must hold it
+        pub required_roles: BoundedVec<(AgentRole, u32), T::MaxRequiredRoles>,
+    }
+
+    impl<T: Config> RoleQuorum<T> {
+        /// Whether `agents` include enough distinct agents of every required role
+        pub fn is_met_by(&self, agents: &[T::AccountId]) -> bool {
+            let mut distinct = agents.to_vec();
+            distinct.sort();
+            distinct.dedup();
+            let roles = distinct.iter().map(T::Agents::role).collect::<Vec<_>>();
+
+            self.required_roles.iter().all(|(role, minimum)| {
+                roles.iter().filter(|r| **r == Some(*role)).count() as u32 >= *minimum
+            })
+        }
     }
 
     /// Consensus log data structure
     #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
     #[scale_info(skip_type_params(T))]
     pub struct ConsensusLog<T: Config> {
+        /// The agent that submitted this log
+        pub submitter: T::AccountId,
         /// When this consensus log was created
         pub timestamp: BlockNumberFor<T>,
         /// Content identifier (CID) for IPFS storage
@@ -105,6 +752,12 @@ pub mod pallet {
         pub signatures: BoundedVec<SignatureInfo<T>, T::MaxSignatures>,
         /// Optional metadata about the consensus
         pub metadata: Option<BoundedVec<u8, <T as Config>::MaxMetadataLength>>,
+        /// Number of times the CID or metadata was amended
+        pub amendment_count: u32,
+        /// How the log finalizes
+        pub model: ConsensusModel,
+        /// How urgently the log needs to be signed off
+        pub priority: LogPriority,
     }
 
     /// Storage for all consensus logs
@@ -140,174 +793,2207 @@ pub mod pallet {
         ValueQuery,
     >;
 
-    /// Events emitted by the pallet
-    #[pallet::event]
-    #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {
-        /// A new consensus log was created
-        ConsensusLogged {
-            log_id: T::Hash,
-            agent_id: T::AccountId,
-            cid: Vec<u8>,
-        },
-        /// An insight was submitted to a consensus log
-        InsightSubmitted {
-            log_id: T::Hash,
-            agent_id: T::AccountId,
-            agents_involved: Vec<T::AccountId>,
-        },
-        /// A signature was added to a consensus log
-        LogSigned {
-            log_id: T::Hash,
-            agent_id: T::AccountId,
-        },
-    }
+    /// Number of stored logs per CID, including logs evicted from `LogsByCID`
+    ///
+    /// Counts beyond the `LogsByCID` capacity mark hot CIDs whose index no longer lists every
+    /// log.
+    #[pallet::storage]
+    #[pallet::getter(fn cid_log_count)]
+    pub type CidLogCount<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BoundedVec<u8, T::MaxCIDLength>,
+        u32,
+        ValueQuery,
+    >;
 
-    /// Errors that can occur in the pallet
-    #[pallet::error]
-    pub enum Error<T> {
-        /// Agent is not registered or not active
-        AgentNotFound,
-        /// Agent is not currently active (offline or maintenance)
-        AgentNotActive,
-        /// Invalid CID format
-        InvalidCID,
-        /// Invalid signature format
-        InvalidSignature,
-        /// Invalid metadata format
-        InvalidMetadata,
-        /// Log with this ID already exists
-        LogAlreadyExists,
-        /// Log with this ID does not exist
-        LogNotFound,
-        /// Not enough agents for consensus (minimum 2 required)
-        NotEnoughAgents,
-        /// Agent has already signed this log
-        AlreadySigned,
-        /// Too many agents involved
-        TooManyAgents,
-        /// Signature list is full
-        SignatureListFull,
-    }
+    /// Logs awaiting finalization by priority, with the block each was submitted in
+    ///
+    /// Agent clients iterate the `Urgent` prefix first to pick up time-critical work; see
+    /// [`Pallet::pending_logs`].
+    #[pallet::storage]
+    #[pallet::getter(fn pending_by_priority)]
+    pub type PendingByPriority<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        LogPriority,
+        Blake2_128Concat,
+        T::Hash,
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
 
-    #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+    /// Pending logs each agent is involved in and has not signed or voted on yet, oldest first
+    #[pallet::storage]
+    #[pallet::getter(fn pending_duties)]
+    pub type PendingDuties<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<T::Hash, T::MaxPendingDuties>,
+        ValueQuery,
+    >;
 
-    #[pallet::call]
-    impl<T: Config> Pallet<T> {
-        /// Submit a new consensus log
-        ///
+    /// Role quorum required of multi-agent insights under every model without its own policy
+    #[pallet::storage]
+    #[pallet::getter(fn role_quorum_policy)]
+    pub type RoleQuorumPolicy<T: Config> = StorageValue<_, RoleQuorum<T>, OptionQuery>;
+
+    /// Role quorum required of multi-agent insights under a specific consensus model
+    #[pallet::storage]
+    #[pallet::getter(fn model_role_quorum_policy)]
+    pub type ModelRoleQuorumPolicies<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        ConsensusModel,
+        RoleQuorum<T>,
+        OptionQuery,
+    >;
+
+    /// Earlier CIDs of amended logs, oldest first, with the block each was replaced at
+    #[pallet::storage]
+    #[pallet::getter(fn cid_history)]
+    pub type CidHistory<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::Hash,
+        BoundedVec<(BoundedVec<u8, T::MaxCIDLength>, BlockNumberFor<T>), T::MaxAmendments>,
+        ValueQuery,
+    >;
+
+    /// Annotations of each log, oldest first
+    #[pallet::storage]
+    #[pallet::getter(fn log_annotations)]
+    pub type LogAnnotations<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::Hash,
+        BoundedVec<Annotation<T>, T::MaxAnnotations>,
+        ValueQuery,
+    >;
+
+    /// Agents whose signatures each insight needs to be finalized, nominated by its submitter
+    #[pallet::storage]
+    #[pallet::getter(fn required_signers)]
+    pub type RequiredSigners<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::Hash,
+        BoundedVec<T::AccountId, T::MaxAgentsInvolved>,
+        ValueQuery,
+    >;
+
+    /// Insights whose required co-signers are checked in each block
+    #[pallet::storage]
+    #[pallet::getter(fn required_signer_checks)]
+    pub type RequiredSignerChecks<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<T::Hash, T::MaxRequiredSignerChecksPerBlock>,
+        ValueQuery,
+    >;
+
+    /// Block each agent first signed each log in, kept through amendments
+    #[pallet::storage]
+    #[pallet::getter(fn first_signature)]
+    pub type FirstSignatures<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::Hash,
+        Blake2_128Concat,
+        T::AccountId,
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
+
+    /// Per-agent submission nonce, mixed into log ID derivation
+    ///
+    /// Every submission consumes one nonce, so identical content submitted twice (even in the
+    /// same block) yields two distinct logs instead of colliding.
+    #[pallet::storage]
+    #[pallet::getter(fn agent_nonce)]
+    pub type AgentNonces<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        u64,
+        ValueQuery,
+    >;
+
+    /// Sequence numbers each agent skipped, as ranges from the first skipped number up to but
+    /// excluding the one submitted, oldest first
+    #[pallet::storage]
+    #[pallet::getter(fn sequence_gaps)]
+    pub type SequenceGaps<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<(u64, u64), T::MaxSequenceGaps>,
+        ValueQuery,
+    >;
+
+    /// Public key of the FROST signing group that produces aggregate signatures
+    #[pallet::storage]
+    #[pallet::getter(fn frost_group_key)]
+    pub type FrostGroupKey<T: Config> = StorageValue<_, [u8; 32], OptionQuery>;
+
+    /// Agents holding a share of the FROST group key
+    #[pallet::storage]
+    #[pallet::getter(fn frost_participants)]
+    pub type FrostParticipants<T: Config> = StorageValue<
+        _,
+        BoundedVec<T::AccountId, T::MaxAgentsInvolved>,
+        ValueQuery,
+    >;
+
+    #[pallet::type_value]
+    pub fn DefaultFrostThreshold() -> u32 {
+        DefaultFrostConfig::THRESHOLD
+    }
+
+    /// Number of partial signatures required to form an aggregate signature
+    #[pallet::storage]
+    #[pallet::getter(fn frost_threshold)]
+    pub type FrostThreshold<T: Config> = StorageValue<_, u32, ValueQuery, DefaultFrostThreshold>;
+
+    /// Version of the current FROST group key
+    #[pallet::storage]
+    #[pallet::getter(fn group_key_version)]
+    pub type FrostGroupKeyVersion<T: Config> = StorageValue<_, GroupKeyVersion, ValueQuery>;
+
+    /// A FROST group replaced by a participant set rotation
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct RetiredFrostGroup<T: Config> {
+        /// Public key of the group
+        pub group_key: [u8; 32],
+        /// Agents that held a share of the group key, in bitmap order
+        pub participants: BoundedVec<T::AccountId, T::MaxAgentsInvolved>,
+        /// Signing threshold of the group
+        pub threshold: u32,
+        /// Block at which the group was replaced
+        pub retired_at: BlockNumberFor<T>,
+    }
+
+    /// Previous FROST groups by key version, kept to verify the aggregates they signed
+    #[pallet::storage]
+    #[pallet::getter(fn retired_frost_groups)]
+    pub type RetiredFrostGroups<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        GroupKeyVersion,
+        RetiredFrostGroup<T>,
+        OptionQuery,
+    >;
+
+    /// Whether the pallet is paused, rejecting every call except `pause` and `unpause`
+    #[pallet::storage]
+    #[pallet::getter(fn paused)]
+    pub type Paused<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    /// Record of how a consensus log was finalized
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct FinalizedLog<T: Config> {
+        /// Block at which the log was finalized
+        pub finalized_at: BlockNumberFor<T>,
+        /// Aggregate signature of the FROST group, if it was used instead of individual signatures
+        pub aggregate: Option<AggregateSignature>,
+        /// Version of the FROST group key current at finalization
+        pub key_version: GroupKeyVersion,
+    }
+
+    /// Finalized consensus logs
+    #[pallet::storage]
+    #[pallet::getter(fn finalized_logs)]
+    pub type FinalizedLogs<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::Hash,
+        FinalizedLog<T>,
+        OptionQuery,
+    >;
+
+    /// Owner, deposit and references of a metadata blob
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct MetadataBlobTicket<T: Config> {
+        /// Account that noted the blob and holds its deposit
+        pub owner: T::AccountId,
+        /// Amount reserved from the owner
+        pub deposit: BalanceOf<T>,
+        /// Length of the blob in bytes
+        pub len: u32,
+        /// Number of logs referencing the blob
+        pub references: u32,
+    }
+
+    /// Tickets of the noted metadata blobs, by blob hash
+    #[pallet::storage]
+    #[pallet::getter(fn metadata_blob_tickets)]
+    pub type MetadataBlobTickets<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::Hash,
+        MetadataBlobTicket<T>,
+        OptionQuery,
+    >;
+
+    /// Contents of the noted metadata blobs, by blob hash
+    #[pallet::storage]
+    #[pallet::getter(fn metadata_blobs)]
+    pub type MetadataBlobs<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::Hash,
+        BoundedVec<u8, T::MaxMetadataBlobLength>,
+        OptionQuery,
+    >;
+
+    /// Hash of the metadata blob attached to each log
+    #[pallet::storage]
+    #[pallet::getter(fn log_metadata_blobs)]
+    pub type LogMetadataBlobs<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::Hash,
+        T::Hash,
+        OptionQuery,
+    >;
+
+    /// Submitter and amount reserved for each log
+    #[pallet::storage]
+    #[pallet::getter(fn log_deposits)]
+    pub type LogDeposits<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::Hash,
+        (T::AccountId, BalanceOf<T>),
+        OptionQuery,
+    >;
+
+    /// Abstentions and rejections of involved agents on each log; approvals are the log's
+    /// signatures
+    #[pallet::storage]
+    #[pallet::getter(fn log_votes)]
+    pub type LogVotes<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::Hash,
+        Blake2_128Concat,
+        T::AccountId,
+        LogVote<T>,
+        OptionQuery,
+    >;
+
+    /// Logs vetoed by their involved agents, with the block each was rejected in
+    #[pallet::storage]
+    #[pallet::getter(fn rejected_logs)]
+    pub type RejectedLogs<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::Hash,
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
+
+    /// Logs finalized in the current block, in order, for its consensus digest
+    #[pallet::storage]
+    pub type FinalizedInBlock<T: Config> =
+        StorageValue<_, BoundedVec<T::Hash, T::MaxFinalizationsPerBlock>, ValueQuery>;
+
+    /// Merkle root over the signature leaves of each finalized log
+    #[pallet::storage]
+    #[pallet::getter(fn signature_roots)]
+    pub type SignatureRoots<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::Hash,
+        [u8; 32],
+        OptionQuery,
+    >;
+
+    /// Phase of a FROST signing session
+    #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum SigningPhase {
+        /// Participants submit nonce commitments
+        CommitmentCollection,
+        /// Committed participants submit signature shares
+        ShareCollection,
+        /// Enough signature shares were collected
+        Complete,
+        /// A phase deadline passed before the threshold was reached
+        Expired,
+    }
+
+    /// A FROST signing session over a consensus log
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct SigningSession<T: Config> {
+        /// The log being signed
+        pub log_id: T::Hash,
+        /// Current phase
+        pub phase: SigningPhase,
+        /// Block at which the current phase ends, or at which the session is removed once it
+        /// is complete or expired
+        pub phase_deadline: BlockNumberFor<T>,
+        /// Threshold captured when the session opened
+        pub threshold: u32,
+        /// Version of the FROST group key the session was opened under, whose participants
+        /// sign in it
+        pub key_version: GroupKeyVersion,
+        /// Nonce commitments by participant
+        pub commitments: BoundedVec<(T::AccountId, [u8; 32]), T::MaxAgentsInvolved>,
+        /// Signature shares by participant
+        pub shares: BoundedVec<(T::AccountId, [u8; 64]), T::MaxAgentsInvolved>,
+    }
+
+    /// FROST signing sessions
+    #[pallet::storage]
+    #[pallet::getter(fn signing_sessions)]
+    pub type SigningSessions<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        SessionId,
+        SigningSession<T>,
+        OptionQuery,
+    >;
+
+    /// Next available signing session ID
+    #[pallet::storage]
+    #[pallet::getter(fn next_session_id)]
+    pub type NextSessionId<T: Config> = StorageValue<_, SessionId, ValueQuery>;
+
+    /// The open signing session of each log, if any
+    #[pallet::storage]
+    #[pallet::getter(fn session_of_log)]
+    pub type SessionOfLog<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::Hash,
+        SessionId,
+        OptionQuery,
+    >;
+
+    /// Agent and amount reserved for each signing session that has not ended yet
+    #[pallet::storage]
+    #[pallet::getter(fn session_deposits)]
+    pub type SessionDeposits<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        SessionId,
+        (T::AccountId, BalanceOf<T>),
+        OptionQuery,
+    >;
+
+    /// Signing sessions by the block their current phase ends
+    #[pallet::storage]
+    #[pallet::getter(fn session_deadlines)]
+    pub type SessionDeadlines<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<SessionId, T::MaxSessionsPerBlock>,
+        ValueQuery,
+    >;
+
+    /// A submission remembered for deduplication
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct RecentSubmission<T: Config> {
+        /// Hash of the submitted CID
+        pub cid_hash: T::Hash,
+        /// Hash of the submitted metadata
+        pub metadata_hash: T::Hash,
+        /// When the submission was made
+        pub submitted_at: BlockNumberFor<T>,
+    }
+
+    /// Ring buffer of each agent's submissions within the dedup window, oldest first
+    #[pallet::storage]
+    #[pallet::getter(fn recent_submissions)]
+    pub type RecentSubmissions<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<RecentSubmission<T>, T::MaxRecentSubmissions>,
+        ValueQuery,
+    >;
+
+    /// Phase of a blinded insight round
+    #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum RoundPhase {
+        /// Participants commit to the insights they will reveal
+        Commit,
+        /// Committed participants reveal their insights
+        Reveal,
+        /// Every commitment was revealed, or a phase deadline passed
+        Closed,
+    }
+
+    /// A round in which agents commit to their insights before any of them is revealed
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct InsightRound<T: Config> {
+        /// The agent that opened the round
+        pub opener: T::AccountId,
+        /// Agents taking part, each involved in every insight revealed
+        pub participants: BoundedVec<T::AccountId, T::MaxAgentsInvolved>,
+        /// Current phase
+        pub phase: RoundPhase,
+        /// Block at which the current phase ends
+        pub phase_deadline: BlockNumberFor<T>,
+        /// How the revealed insights finalize
+        pub model: ConsensusModel,
+        /// How urgently the revealed insights need to be signed off
+        pub priority: LogPriority,
+        /// Commitments not revealed yet, by participant
+        pub commitments: BoundedVec<(T::AccountId, T::Hash), T::MaxAgentsInvolved>,
+        /// Logs of the insights revealed so far, by participant
+        pub revealed: BoundedVec<(T::AccountId, T::Hash), T::MaxAgentsInvolved>,
+    }
+
+    /// Blinded insight rounds
+    #[pallet::storage]
+    #[pallet::getter(fn insight_rounds)]
+    pub type InsightRounds<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        RoundId,
+        InsightRound<T>,
+        OptionQuery,
+    >;
+
+    /// Next available blinded insight round ID
+    #[pallet::storage]
+    #[pallet::getter(fn next_round_id)]
+    pub type NextRoundId<T: Config> = StorageValue<_, RoundId, ValueQuery>;
+
+    /// Blinded insight rounds by the block their current phase ends
+    #[pallet::storage]
+    #[pallet::getter(fn round_deadlines)]
+    pub type RoundDeadlines<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<RoundId, T::MaxSessionsPerBlock>,
+        ValueQuery,
+    >;
+
+    /// Genesis configuration for the FROST signing group
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// Group public key, if the key ceremony happened before launch
+        pub frost_group_key: Option<[u8; 32]>,
+        /// Agents holding a share of the group key
+        pub frost_participants: Vec<T::AccountId>,
+        /// Signing threshold; zero keeps the default threshold
+        pub frost_threshold: u32,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            if let Some(group_key) = self.frost_group_key {
+                FrostGroupKey::<T>::put(group_key);
+            }
+
+            assert!(
+                self.frost_participants.len() <= T::MaxFrostParticipants::get() as usize,
+                "Genesis FROST participants exceed MaxFrostParticipants"
+            );
+            let participants = BoundedVec::<T::AccountId, T::MaxAgentsInvolved>::try_from(
+                self.frost_participants.clone(),
+            ).expect("Genesis FROST participants exceed MaxAgentsInvolved");
+
+            if self.frost_threshold != 0 {
+                assert!(
+                    self.frost_threshold as usize <= participants.len(),
+                    "Genesis FROST threshold exceeds the number of participants"
+                );
+                FrostThreshold::<T>::put(self.frost_threshold);
+            }
+
+            FrostParticipants::<T>::put(participants);
+        }
+    }
+
+    /// Events emitted by the pallet
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A new consensus log was created
+        ConsensusLogged {
+            log_id: T::Hash,
+            agent_id: T::AccountId,
+            nonce: u64,
+            cid: Vec<u8>,
+        },
+        /// An insight was submitted to a consensus log
+        InsightSubmitted {
+            log_id: T::Hash,
+            agent_id: T::AccountId,
+            nonce: u64,
+            agents_involved: Vec<T::AccountId>,
+        },
+        /// A signature was added to a consensus log
+        LogSigned {
+            log_id: T::Hash,
+            agent_id: T::AccountId,
+        },
+        /// An involved agent abstained from or rejected a consensus log
+        VoteCast {
+            log_id: T::Hash,
+            agent_id: T::AccountId,
+            vote: VoteKind,
+        },
+        /// Rejections reached `VetoThreshold` and the log was rejected
+        LogRejected {
+            log_id: T::Hash,
+            rejections: u32,
+            refunded: BalanceOf<T>,
+            fee: BalanceOf<T>,
+        },
+        /// A FROST signing session was opened for a log
+        SigningSessionOpened {
+            session_id: SessionId,
+            log_id: T::Hash,
+            deadline: BlockNumberFor<T>,
+        },
+        /// A participant submitted its nonce commitment
+        CommitmentSubmitted {
+            session_id: SessionId,
+            agent_id: T::AccountId,
+        },
+        /// A signing session moved on to collecting signature shares
+        ShareCollectionStarted {
+            session_id: SessionId,
+            deadline: BlockNumberFor<T>,
+        },
+        /// A participant submitted its signature share
+        SignatureShareSubmitted {
+            session_id: SessionId,
+            agent_id: T::AccountId,
+        },
+        /// A signing session collected enough signature shares
+        SigningSessionCompleted {
+            session_id: SessionId,
+            log_id: T::Hash,
+        },
+        /// A signing session expired before reaching the threshold
+        SigningSessionExpired {
+            session_id: SessionId,
+            log_id: T::Hash,
+            phase: SigningPhase,
+        },
+        /// A participant committed to a session but never provided its signature share
+        SignatureShareMissing {
+            session_id: SessionId,
+            agent_id: T::AccountId,
+        },
+        /// A pending log's content was amended and its signatures cleared
+        LogAmended {
+            log_id: T::Hash,
+            amendment: u32,
+            cid: Vec<u8>,
+        },
+        /// The oldest log under a full CID index entry was dropped from it to make room
+        LogEvictedFromCidIndex {
+            cid: Vec<u8>,
+            log_id: T::Hash,
+        },
+        /// A submitter deleted a log nobody else had signed
+        LogRetracted {
+            log_id: T::Hash,
+            submitter: T::AccountId,
+            refunded: BalanceOf<T>,
+            fee: BalanceOf<T>,
+        },
+        /// A consensus log was finalized
+        LogFinalized {
+            log_id: T::Hash,
+            aggregated: bool,
+        },
+        /// The first agents to sign a finalized log after its submitter
+        FirstResponders {
+            log_id: T::Hash,
+            agents: Vec<T::AccountId>,
+        },
+        /// A signature was left out of a log's quorum because its agent lost its standing
+        SignatureExcluded {
+            log_id: T::Hash,
+            agent_id: T::AccountId,
+        },
+        /// An item of a signing batch could not be signed
+        LogSignFailed {
+            log_id: T::Hash,
+            agent_id: T::AccountId,
+            error: DispatchError,
+        },
+        /// A signing batch was processed
+        BatchSigned {
+            agent_id: T::AccountId,
+            signed: u32,
+            failed: u32,
+        },
+        /// The pallet was paused
+        Paused,
+        /// The pallet was unpaused
+        Unpaused,
+        /// A role quorum policy was set or cleared, for one model or globally
+        RoleQuorumPolicySet {
+            model: Option<ConsensusModel>,
+            policy: Option<RoleQuorum<T>>,
+        },
+        /// An involved agent annotated a pending log
+        LogAnnotated {
+            log_id: T::Hash,
+            author: T::AccountId,
+            cid: Vec<u8>,
+        },
+        /// A required co-signer had not signed a pending insight by its deadline
+        RequiredSignerMissed {
+            log_id: T::Hash,
+            agent_id: T::AccountId,
+        },
+        /// A metadata blob was stored and its deposit reserved
+        MetadataBlobNoted {
+            hash: T::Hash,
+            owner: T::AccountId,
+            len: u32,
+            deposit: BalanceOf<T>,
+        },
+        /// A metadata blob was removed and its deposit returned
+        MetadataBlobUnnoted {
+            hash: T::Hash,
+            owner: T::AccountId,
+            deposit: BalanceOf<T>,
+        },
+        /// A metadata blob was attached to a log
+        MetadataBlobAttached {
+            log_id: T::Hash,
+            hash: T::Hash,
+        },
+        /// The FROST participant set was rotated to a new group key
+        FrostGroupRotated {
+            version: GroupKeyVersion,
+            group_key: [u8; 32],
+            participants: Vec<T::AccountId>,
+            threshold: u32,
+        },
+        /// A blinded insight round was opened
+        InsightRoundOpened {
+            round_id: RoundId,
+            opener: T::AccountId,
+            participants: Vec<T::AccountId>,
+            deadline: BlockNumberFor<T>,
+        },
+        /// A participant committed to the insight it will reveal
+        InsightCommitted {
+            round_id: RoundId,
+            agent_id: T::AccountId,
+        },
+        /// A blinded insight round moved on to collecting reveals
+        InsightRevealStarted {
+            round_id: RoundId,
+            deadline: BlockNumberFor<T>,
+        },
+        /// A participant revealed the insight it committed to, which was logged
+        InsightRevealed {
+            round_id: RoundId,
+            agent_id: T::AccountId,
+            log_id: T::Hash,
+            cid: Vec<u8>,
+        },
+        /// A participant revealed an insight other than the one it committed to
+        InsightRevealMismatched {
+            round_id: RoundId,
+            agent_id: T::AccountId,
+        },
+        /// A participant committed to an insight but did not reveal it in time
+        InsightRevealMissed {
+            round_id: RoundId,
+            agent_id: T::AccountId,
+        },
+        /// A blinded insight round closed
+        InsightRoundClosed {
+            round_id: RoundId,
+            revealed: u32,
+        },
+        /// An agent submitted an insight past its next sequence number, skipping `from` up to
+        /// but excluding `to`
+        InsightSequenceGap {
+            agent_id: T::AccountId,
+            from: u64,
+            to: u64,
+        },
+    }
+
+    /// Errors that can occur in the pallet
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Agent is not registered or not active
+        AgentNotFound,
+        /// Agent is not currently active (offline or maintenance)
+        AgentNotActive,
+        /// Invalid CID format
+        InvalidCID,
+        /// Invalid signature format
+        InvalidSignature,
+        /// Invalid metadata format
+        InvalidMetadata,
+        /// Log with this ID already exists
+        LogAlreadyExists,
+        /// Log with this ID does not exist
+        LogNotFound,
+        /// Not enough agents for consensus (minimum 2 required)
+        NotEnoughAgents,
+        /// Agent has already signed this log
+        AlreadySigned,
+        /// Too many agents involved
+        TooManyAgents,
+        /// The agent's log index is full
+        AgentIndexFull,
+        /// The CID's log index is full and the overflow policy rejects new logs
+        CidIndexFull,
+        /// Signature list is full
+        SignatureListFull,
+        /// Agents involved do not all belong to the same organization
+        AgentsNotInSameOrg,
+        /// Signing session does not exist
+        SessionNotFound,
+        /// A signing session for this log is already in progress
+        SessionAlreadyOpen,
+        /// Agent is not a member of the FROST signing group
+        NotFrostParticipant,
+        /// The signing session is not in the phase required for this action
+        WrongSessionPhase,
+        /// Agent already submitted a commitment to this session or round
+        AlreadyCommitted,
+        /// Agent did not commit to this session or round, or already revealed its insight
+        NoCommitment,
+        /// Agent already submitted a signature share to this session
+        ShareAlreadySubmitted,
+        /// Commitment value is invalid
+        InvalidCommitment,
+        /// Signature share is invalid
+        InvalidSignatureShare,
+        /// Signature share is bound to another session, log or round
+        ReplayedSignatureShare,
+        /// Too many signing session phases end in the same block
+        TooManySessions,
+        /// The FROST group has fewer participants than the signing threshold
+        FrostGroupTooSmall,
+        /// Consensus log is already finalized
+        AlreadyFinalized,
+        /// Not enough involved agents have signed the log for its consensus model
+        MissingSignatures,
+        /// No FROST group key is configured
+        FrostGroupNotConfigured,
+        /// Aggregate signature does not verify against the FROST group
+        InvalidAggregateSignature,
+        /// Aggregate signature includes an agent not involved in the log
+        SignerNotInvolved,
+        /// A signer is banned, quarantined or retiring
+        SignerNotEligible,
+        /// Quorum is only reached counting signatures of agents that lost their standing
+        SignersExcluded,
+        /// The submitter already logged this CID with the same metadata within the dedup window
+        DuplicateSubmission,
+        /// Only the submitter of a log can amend it
+        NotSubmitter,
+        /// The log was amended the maximum number of times
+        TooManyAmendments,
+        /// The consensus model is not allowed on this chain
+        ConsensusModelNotAllowed,
+        /// The log's consensus model does not allow this way of signing it off
+        WrongConsensusModel,
+        /// The pallet is paused
+        PalletPaused,
+        /// Submitter cannot afford the log deposit
+        InsufficientDeposit,
+        /// Another agent already signed the log
+        LogAlreadySigned,
+        /// A signing batch has no items
+        EmptyBatch,
+        /// A signing batch has more than `MaxBatchSignatures` items
+        BatchTooLarge,
+        /// The submitter's signer weight is below `UrgentReputation`
+        InsufficientReputationForUrgent,
+        /// The agents do not hold every role the role quorum policy requires
+        RoleQuorumNotMet,
+        /// Only agents involved in a log can annotate it
+        NotInvolved,
+        /// The log has `MaxAnnotations` annotations
+        TooManyAnnotations,
+        /// The agent already attached `MaxAnnotationsPerAuthor` annotations to the log
+        TooManyAnnotationsByAuthor,
+        /// Required co-signers must be involved in the insight
+        RequiredSignerNotInvolved,
+        /// A required co-signer has not signed the log
+        RequiredSignatureMissing,
+        /// `MaxRequiredSignerChecksPerBlock` insights are already checked at this deadline
+        TooManyRequiredSignerChecks,
+        /// `MaxFinalizationsPerBlock` logs were already finalized in this block
+        TooManyFinalizations,
+        /// The log was vetoed by its involved agents
+        LogRejected,
+        /// A FROST group needs between one and `MaxFrostParticipants` participants
+        InvalidFrostParticipants,
+        /// An agent appears more than once in the FROST participant set
+        DuplicateFrostParticipant,
+        /// A FROST participant is not registered, is retiring or may not participate
+        IneligibleFrostParticipant,
+        /// The FROST threshold must be between one and the number of participants
+        InvalidFrostThreshold,
+        /// The metadata blob is empty or longer than `MaxMetadataBlobLength`
+        InvalidMetadataBlob,
+        /// A blob with the same hash is already noted
+        MetadataBlobAlreadyNoted,
+        /// No metadata blob is noted under this hash
+        UnknownMetadataBlob,
+        /// Only the owner of a metadata blob can unnote it
+        NotMetadataBlobOwner,
+        /// The metadata blob is still attached to a log
+        MetadataBlobInUse,
+        /// The experimental feature the call belongs to is not enabled for the agent
+        FeatureNotEnabled,
+        /// Blinded insight round does not exist
+        RoundNotFound,
+        /// The blinded insight round is not in the phase required for this action
+        WrongRoundPhase,
+        /// Agent does not take part in the blinded insight round
+        NotRoundParticipant,
+        /// Too many blinded insight round phases end in the same block
+        TooManyRounds,
+        /// The sequence number was already used or skipped by the agent
+        SequenceTooLow,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Advance, expire or remove signing sessions and blinded insight rounds whose phase
+        /// deadline is reached, and report the required co-signers of pending insights whose
+        /// deadline is reached
+        fn on_initialize(block: BlockNumberFor<T>) -> Weight {
+            let session_ids = SessionDeadlines::<T>::take(block);
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+            for session_id in session_ids {
+                weight = weight.saturating_add(Self::on_session_deadline(session_id, block));
+            }
+
+            let round_ids = RoundDeadlines::<T>::take(block);
+            weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+            for round_id in round_ids {
+                weight = weight.saturating_add(Self::on_round_deadline(round_id, block));
+            }
+
+            let log_ids = RequiredSignerChecks::<T>::take(block);
+            weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+            for log_id in log_ids {
+                weight = weight.saturating_add(Self::check_required_signers(&log_id));
+            }
+
+            // Taking the logs finalized in the block in `on_finalize`
+            weight.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+        }
+
+        /// Deposit the consensus digest of the logs finalized in this block, if there are any
+        fn on_finalize(_block: BlockNumberFor<T>) {
+            let log_ids = FinalizedInBlock::<T>::take();
+            if log_ids.is_empty() {
+                return;
+            }
+            let leaves = log_ids.iter().map(|log_id| log_id.encode()).collect::<Vec<_>>();
+            let digest = ConsensusDigest {
+                finalized: leaves.len() as u32,
+                root: csuite_primitives::merkle_root(&leaves),
+            };
+            <frame_system::Pallet<T>>::deposit_log(digest.to_digest_item());
+        }
+
+        /// Check that a log at its configured bounds fits its storage budget
+        fn integrity_test() {
+            assert!(
+                ConsensusLog::<T>::max_encoded_len() <= csuite_primitives::MAX_CONSENSUS_LOG_SIZE,
+                "ConsensusLog can encode to {} bytes, above the budget of {} bytes",
+                ConsensusLog::<T>::max_encoded_len(),
+                csuite_primitives::MAX_CONSENSUS_LOG_SIZE,
+            );
+            assert!(
+                T::MaxFrostParticipants::get() <= csuite_primitives::MAX_AGGREGATE_SIGNERS &&
+                    T::MaxFrostParticipants::get() <= T::MaxAgentsInvolved::get(),
+                "MaxFrostParticipants exceeds the signer bitmap or MaxAgentsInvolved",
+            );
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            Self::do_try_state()
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Submit a new consensus log
+        ///
+        /// Parameters:
+        /// - `cid`: Content identifier for IPFS storage
+        /// - `metadata`: Optional metadata about the consensus
+        /// - `model`: How the log finalizes
+        /// - `priority`: How urgently the log needs to be signed off
+        #[pallet::call_index(0)]
+        #[pallet::weight(Weight::from_parts(10_000, 0).saturating_add(T::DbWeight::get().reads_writes(7, 7)))]
+        pub fn submit_consensus_log(
+            origin: OriginFor<T>,
+            cid: Vec<u8>,
+            metadata: Option<Vec<u8>>,
+            model: ConsensusModel,
+            priority: LogPriority,
+        ) -> DispatchResult {
+            let agent_id = T::SubmitOrigin::ensure_origin(origin)?;
+            Self::ensure_not_paused()?;
+            
+            // Ensure agent exists and is active
+            ensure!(T::Agents::is_registered(&agent_id), Error::<T>::AgentNotFound);
+            ensure!(T::Agents::is_online(&agent_id), Error::<T>::AgentNotActive);
+            ensure!(T::AllowedModels::contains(&model), Error::<T>::ConsensusModelNotAllowed);
+            Self::ensure_can_submit_with(&agent_id, priority)?;
+            
+            // Validate CID
+            ensure!(!cid.is_empty(), Error::<T>::InvalidCID);
+            let bounded_cid = BoundedVec::<u8, T::MaxCIDLength>::try_from(cid.clone())
+                .map_err(|_| Error::<T>::InvalidCID)?;
+                
+            // Validate and bound the metadata if provided
+            let bounded_metadata = if let Some(meta) = metadata {
+                Some(BoundedVec::<u8, <T as Config>::MaxMetadataLength>::try_from(meta)
+                    .map_err(|_| Error::<T>::InvalidMetadata)?)
+            } else {
+                None
+            };
+
+            // Reject a repeat of a recent submission
+            Self::note_submission(&agent_id, &bounded_cid, &bounded_metadata)?;
+            
+            // For initial submission, only the submitting agent is involved
+            let mut agents_involved = BoundedVec::<T::AccountId, T::MaxAgentsInvolved>::default();
+            let _ = agents_involved.try_push(agent_id.clone());
+            
+            // No signatures initially (will be added later via sign_log)
+            let signatures = BoundedVec::<SignatureInfo<T>, T::MaxSignatures>::default();
+            
+            // Create the consensus log
+            let consensus_log = ConsensusLog {
+                submitter: agent_id.clone(),
+                timestamp: <frame_system::Pallet<T>>::block_number(),
+                cid: bounded_cid.clone(),
+                agents_involved,
+                signatures,
+                metadata: bounded_metadata,
+                amendment_count: 0,
+                model,
+                priority,
+            };
+            
+            // Generate a unique log ID from the submitter, their nonce and the content
+            let nonce = Self::agent_nonce(&agent_id);
+            let log_id = Self::derive_log_id(&agent_id, nonce, &consensus_log);
+            
+            // Ensure log doesn't already exist
+            ensure!(!Logs::<T>::contains_key(&log_id), Error::<T>::LogAlreadyExists);
+            
+            // As in `submit_insight`, the writes and index updates succeed or fail together
+            frame_support::storage::with_storage_layer(|| -> DispatchResult {
+                Self::reserve_log_deposit(&agent_id, &log_id)?;
+
+                // Store the consensus log, queue it by priority and consume the nonce
+                Logs::<T>::insert(&log_id, consensus_log);
+                PendingByPriority::<T>::insert(priority, &log_id, <frame_system::Pallet<T>>::block_number());
+                AgentNonces::<T>::insert(&agent_id, nonce.saturating_add(1));
+
+                // Update agent and CID indexes; the submitter still has to sign its log
+                Self::index_log_by_agent(&agent_id, log_id)?;
+                Self::add_duty(&agent_id, log_id);
+                Self::index_log_by_cid(&bounded_cid, log_id)
+            })?;
+            
+            T::OnLogActivity::on_log_submitted(&agent_id, &log_id);
+
+            // Emit event
+            Self::deposit_event(Event::ConsensusLogged {
+                log_id,
+                agent_id,
+                nonce,
+                cid,
+            });
+            
+            Ok(())
+        }
+        
+        /// Submit an insight with multiple agents involved
+        ///
+        /// Parameters:
+        /// - `agents_involved`: List of agent IDs participating in this insight
+        /// - `cid`: Content identifier for IPFS storage
+        /// - `signature`: Digital signature from the submitting agent
+        /// - `metadata`: Optional metadata about the insight
+        /// - `model`: How the insight finalizes
+        /// - `priority`: How urgently the insight needs to be signed off
+        /// - `required_signers`: Involved agents that must sign the insight, whatever its model
+        /// - `sequence`: Sequence number the insight is signed under, the next expected one if
+        ///   `None`; the numbers skipped are recorded as a gap
+        #[pallet::call_index(1)]
+        #[pallet::weight(Weight::from_parts(15_000, 0)
+            .saturating_add(Pallet::<T>::max_verify_weight())
+            .saturating_add(T::DbWeight::get().reads_writes(11, 10))
+            // Role of every involved agent
+            .saturating_add(T::DbWeight::get().reads(T::MaxAgentsInvolved::get() as u64))
+            // Pending duties of every involved agent
+            .saturating_add(T::DbWeight::get().reads_writes(
+                T::MaxAgentsInvolved::get() as u64,
+                T::MaxAgentsInvolved::get() as u64,
+            )))]
+        pub fn submit_insight(
+            origin: OriginFor<T>,
+            agents_involved: Vec<T::AccountId>,
+            cid: Vec<u8>,
+            signature: Vec<u8>,
+            metadata: Option<Vec<u8>>,
+            model: ConsensusModel,
+            priority: LogPriority,
+            required_signers: Vec<T::AccountId>,
+            sequence: Option<u64>,
+        ) -> DispatchResult {
+            let agent_id = T::SubmitOrigin::ensure_origin(origin)?;
+            Self::ensure_not_paused()?;
+            
+            // Ensure agent exists and is active
+            ensure!(T::Agents::is_registered(&agent_id), Error::<T>::AgentNotFound);
+            ensure!(T::Agents::is_online(&agent_id), Error::<T>::AgentNotActive);
+            ensure!(T::AllowedModels::contains(&model), Error::<T>::ConsensusModelNotAllowed);
+            Self::ensure_can_submit_with(&agent_id, priority)?;
+            
+            // Validate inputs
+            ensure!(agents_involved.len() >= 2, Error::<T>::NotEnoughAgents);
+            
+            // Validate CID
+            ensure!(!cid.is_empty(), Error::<T>::InvalidCID);
+            let bounded_cid = BoundedVec::<u8, T::MaxCIDLength>::try_from(cid.clone())
+                .map_err(|_| Error::<T>::InvalidCID)?;
+                
+            // Validate signature
+            ensure!(!signature.is_empty(), Error::<T>::InvalidSignature);
+            let bounded_signature = BoundedVec::<u8, T::MaxSignatureLength>::try_from(signature)
+                .map_err(|_| Error::<T>::InvalidSignature)?;
+                
+            // Validate and bound the metadata if provided
+            let bounded_metadata = if let Some(meta) = metadata {
+                Some(BoundedVec::<u8, <T as Config>::MaxMetadataLength>::try_from(meta)
+                    .map_err(|_| Error::<T>::InvalidMetadata)?)
+            } else {
+                None
+            };
+
+            // The submitter signs the content under its sequence number, by default its next nonce
+            let expected = Self::agent_nonce(&agent_id);
+            let nonce = sequence.unwrap_or(expected);
+            ensure!(nonce >= expected, Error::<T>::SequenceTooLow);
+            let payload = Self::insight_payload(&bounded_cid, nonce);
+            Self::ensure_valid_signature(&agent_id, &agent_id, &payload, &bounded_signature)?;
+
+            // Reject a repeat of a recent submission
+            Self::note_submission(&agent_id, &bounded_cid, &bounded_metadata)?;
+            
+            // Validate and bound agents involved
+            let mut bounded_agents = BoundedVec::<T::AccountId, T::MaxAgentsInvolved>::default();
+            for agent in &agents_involved {
+                // Ensure each agent exists
+                ensure!(T::Agents::is_registered(agent), Error::<T>::AgentNotFound);
+                bounded_agents.try_push(agent.clone()).map_err(|_| Error::<T>::TooManyAgents)?;
+            }
+            
+            // Include the submitting agent if not already in the list
+            if !bounded_agents.contains(&agent_id) {
+                bounded_agents.try_push(agent_id.clone()).map_err(|_| Error::<T>::TooManyAgents)?;
+            }
+            
+            // Optionally require a single organization across all participants
+            if T::RequireSameOrg::get() {
+                ensure!(T::Orgs::same_org(&bounded_agents), Error::<T>::AgentsNotInSameOrg);
+            }
+
+            // The involved agents must cover the roles the policy requires
+            ensure!(Self::role_quorum_met(model, &bounded_agents), Error::<T>::RoleQuorumNotMet);
+
+            // Required co-signers must be involved; the submitter signs on submission
+            let mut bounded_required = BoundedVec::<T::AccountId, T::MaxAgentsInvolved>::default();
+            for agent in required_signers {
+                ensure!(bounded_agents.contains(&agent), Error::<T>::RequiredSignerNotInvolved);
+                if agent != agent_id && !bounded_required.contains(&agent) {
+                    bounded_required.try_push(agent).map_err(|_| Error::<T>::TooManyAgents)?;
+                }
+            }
+            
+            // Create initial signatures with the submitting agent's signature
+            let mut signatures = BoundedVec::<SignatureInfo<T>, T::MaxSignatures>::default();
+            let sig_info = SignatureInfo {
+                agent_id: agent_id.clone(),
+                signature: bounded_signature,
+                signed_at: <frame_system::Pallet<T>>::block_number(),
+            };
+            signatures.try_push(sig_info).map_err(|_| Error::<T>::SignatureListFull)?;
+            
+            // Create the consensus log
+            let consensus_log = ConsensusLog {
+                submitter: agent_id.clone(),
+                timestamp: <frame_system::Pallet<T>>::block_number(),
+                cid: bounded_cid.clone(),
+                agents_involved: bounded_agents.clone(),
+                signatures,
+                metadata: bounded_metadata,
+                amendment_count: 0,
+                model,
+                priority,
+            };
+            
+            // Generate a unique log ID from the submitter, their nonce and the content
+            let log_id = Self::derive_log_id(&agent_id, nonce, &consensus_log);
+            
+            // Ensure log doesn't already exist
+            ensure!(!Logs::<T>::contains_key(&log_id), Error::<T>::LogAlreadyExists);
+            
+            // Every write below happens in its own storage layer, so a full index part way
+            // through leaves no deposit, log or index entry behind, whoever calls this
+            frame_support::storage::with_storage_layer(|| -> DispatchResult {
+                Self::reserve_log_deposit(&agent_id, &log_id)?;
+
+                // Store the consensus log, queue it by priority and consume the nonce
+                Logs::<T>::insert(&log_id, consensus_log);
+                PendingByPriority::<T>::insert(priority, &log_id, <frame_system::Pallet<T>>::block_number());
+                AgentNonces::<T>::insert(&agent_id, nonce.saturating_add(1));
+                FirstSignatures::<T>::insert(&log_id, &agent_id, <frame_system::Pallet<T>>::block_number());
+                if !bounded_required.is_empty() {
+                    Self::schedule_required_signer_check(log_id, priority)?;
+                    RequiredSigners::<T>::insert(&log_id, bounded_required);
+                }
+
+                // Update agent indices for all involved agents, and hand the insight to every
+                // one but the submitter, which signed it already
+                for agent in &bounded_agents {
+                    Self::index_log_by_agent(agent, log_id)?;
+                    if *agent != agent_id {
+                        Self::add_duty(agent, log_id);
+                    }
+                }
+
+                // Update CID index
+                Self::index_log_by_cid(&bounded_cid, log_id)
+            })?;
+            
+            T::OnLogActivity::on_log_submitted(&agent_id, &log_id);
+            if nonce > expected {
+                Self::note_sequence_gap(&agent_id, expected, nonce);
+            }
+
+            // Emit event
+            Self::deposit_event(Event::InsightSubmitted {
+                log_id,
+                agent_id,
+                nonce,
+                agents_involved,
+            });
+            
+            Ok(())
+        }
+        
+        /// Vote on an existing consensus log
+        ///
+        /// Approving signs the log off. Abstaining or rejecting answers the log without counting
+        /// towards its consensus model; the rejection that reaches `VetoThreshold` rejects the
+        /// log. Each involved agent votes once per amendment round.
+        ///
+        /// Charged for the largest possible log up front; the unused part is refunded based on
+        /// the number of involved agents and existing signatures actually scanned.
+        ///
         /// Parameters:
-        /// - `cid`: Content identifier for IPFS storage
-        /// - `metadata`: Optional metadata about the consensus
-        #[pallet::call_index(0)]
-        #[pallet::weight(Weight::from_parts(10_000, 0).saturating_add(T::DbWeight::get().reads_writes(3, 3)))]
-        pub fn submit_consensus_log(
+        /// - `log_id`: The ID of the log to vote on
+        /// - `signature`: Digital signature from the agent
+        /// - `vote`: Whether the agent approves, abstains or rejects
+        #[pallet::call_index(2)]
+        #[pallet::weight(Pallet::<T>::max_sign_log_weight().max(
+            Pallet::<T>::vote_weight(T::MaxAgentsInvolved::get()).saturating_add(Pallet::<T>::max_verify_weight())
+        ))]
+        #[pallet::feeless_if(|origin: &OriginFor<T>, _log_id: &T::Hash, _signature: &Vec<u8>, _vote: &VoteKind| -> bool {
+            ensure_signed(origin.clone())
+                .map_or(false, |who| T::FeeExemption::is_fee_exempt(&Pallet::<T>::signing_agent(who)))
+        })]
+        pub fn sign_log(
+            origin: OriginFor<T>,
+            log_id: T::Hash,
+            signature: Vec<u8>,
+            vote: VoteKind,
+        ) -> DispatchResultWithPostInfo {
+            let signer = ensure_signed(origin)?;
+            let agent_id = Self::signing_agent(signer.clone());
+            Self::ensure_not_paused()?;
+            
+            // Ensure agent exists, is active and still in good standing
+            ensure!(T::Agents::is_registered(&agent_id), Error::<T>::AgentNotFound);
+            ensure!(T::Agents::is_online(&agent_id), Error::<T>::AgentNotActive);
+            ensure!(Self::may_participate(&agent_id), Error::<T>::SignerNotEligible);
+            
+            let actual_weight = match vote {
+                VoteKind::Approve => Self::do_sign_log(&agent_id, &signer, log_id, signature)?,
+                VoteKind::Abstain | VoteKind::Reject => Self::do_vote(&agent_id, &signer, log_id, signature, vote)?,
+            };
+            
+            Ok(PostDispatchInfo { actual_weight: Some(actual_weight), pays_fee: Pays::Yes })
+        }
+
+        /// Sign several existing consensus logs at once
+        ///
+        /// Each item approves its log as with `sign_log`. Items that fail are reported with a
+        /// `LogSignFailed` event and skipped, without undoing the others. Batches are never
+        /// feeless; failed items are charged the full weight of `sign_log`.
+        ///
+        /// Parameters:
+        /// - `signatures`: Up to `MaxBatchSignatures` pairs of log ID and signature
+        #[pallet::call_index(10)]
+        #[pallet::weight(Pallet::<T>::sign_logs_batch_weight(signatures.len() as u32))]
+        pub fn sign_logs_batch(
+            origin: OriginFor<T>,
+            signatures: Vec<(T::Hash, Vec<u8>)>,
+        ) -> DispatchResultWithPostInfo {
+            let signer = ensure_signed(origin)?;
+            let agent_id = Self::signing_agent(signer.clone());
+            Self::ensure_not_paused()?;
+            ensure!(!signatures.is_empty(), Error::<T>::EmptyBatch);
+            ensure!(signatures.len() as u32 <= T::MaxBatchSignatures::get(), Error::<T>::BatchTooLarge);
+
+            // Ensure agent exists, is active and still in good standing
+            ensure!(T::Agents::is_registered(&agent_id), Error::<T>::AgentNotFound);
+            ensure!(T::Agents::is_online(&agent_id), Error::<T>::AgentNotActive);
+            ensure!(Self::may_participate(&agent_id), Error::<T>::SignerNotEligible);
+
+            let items = signatures.len() as u32;
+            let max_item_weight = Self::max_sign_log_weight();
+            let mut actual_weight = T::DbWeight::get().reads(2);
+            let mut signed = 0u32;
+            for (log_id, signature) in signatures {
+                match Self::do_sign_log(&agent_id, &signer, log_id, signature) {
+                    Ok(weight) => {
+                        signed = signed.saturating_add(1);
+                        actual_weight = actual_weight.saturating_add(weight);
+                    },
+                    Err(error) => {
+                        actual_weight = actual_weight.saturating_add(max_item_weight);
+                        Self::deposit_event(Event::LogSignFailed {
+                            log_id,
+                            agent_id: agent_id.clone(),
+                            error,
+                        });
+                    },
+                }
+            }
+
+            // Emit event
+            Self::deposit_event(Event::BatchSigned {
+                agent_id,
+                signed,
+                failed: items.saturating_sub(signed),
+            });
+
+            Ok(PostDispatchInfo { actual_weight: Some(actual_weight), pays_fee: Pays::Yes })
+        }
+
+        /// Open a FROST signing session over a consensus log
+        ///
+        /// Reserves `SessionDeposit` from the caller until the session completes or expires.
+        ///
+        /// Parameters:
+        /// - `log_id`: The log the FROST group should sign
+        #[pallet::call_index(3)]
+        #[pallet::weight(Weight::from_parts(10_000, 0).saturating_add(T::DbWeight::get().reads_writes(6, 6)))]
+        pub fn open_signing_session(
+            origin: OriginFor<T>,
+            log_id: T::Hash,
+        ) -> DispatchResult {
+            let agent_id = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            Self::ensure_feature(&agent_id, Feature::FrostSigning)?;
+
+            // Only members of the FROST group may start a session
+            let participants = Self::frost_participants();
+            ensure!(participants.contains(&agent_id), Error::<T>::NotFrostParticipant);
+            let threshold = Self::frost_threshold();
+            ensure!(participants.len() >= threshold as usize, Error::<T>::FrostGroupTooSmall);
+
+            let log = Logs::<T>::get(&log_id).ok_or(Error::<T>::LogNotFound)?;
+            ensure!(log.model == ConsensusModel::ThresholdSignature, Error::<T>::WrongConsensusModel);
+            ensure!(!SessionOfLog::<T>::contains_key(&log_id), Error::<T>::SessionAlreadyOpen);
+
+            let session_id = Self::next_session_id();
+            let deposit = T::SessionDeposit::get();
+            if !deposit.is_zero() {
+                T::Currency::reserve(&agent_id, deposit).map_err(|_| Error::<T>::InsufficientDeposit)?;
+                SessionDeposits::<T>::insert(session_id, (agent_id.clone(), deposit));
+            }
+            let deadline = Self::schedule_phase_end(session_id, log.priority.scale_period(T::CommitmentPeriod::get()))?;
+
+            SigningSessions::<T>::insert(session_id, SigningSession {
+                log_id,
+                phase: SigningPhase::CommitmentCollection,
+                phase_deadline: deadline,
+                threshold,
+                key_version: Self::group_key_version(),
+                commitments: BoundedVec::new(),
+                shares: BoundedVec::new(),
+            });
+            SessionOfLog::<T>::insert(&log_id, session_id);
+            NextSessionId::<T>::put(session_id.saturating_add(1));
+
+            // Emit event
+            Self::deposit_event(Event::SigningSessionOpened {
+                session_id,
+                log_id,
+                deadline,
+            });
+
+            Ok(())
+        }
+
+        /// Submit a nonce commitment to a signing session
+        ///
+        /// Only participants of the FROST group the session was opened under can commit, even
+        /// if the group rotated since. Once every one of them has committed, the session moves
+        /// on to share collection.
+        ///
+        /// Parameters:
+        /// - `session_id`: The signing session
+        /// - `nonce_commitment`: Commitment to the nonce used for this agent's share
+        #[pallet::call_index(4)]
+        #[pallet::weight(Weight::from_parts(10_000, 0).saturating_add(T::DbWeight::get().reads_writes(5, 2)))]
+        pub fn submit_commitment(
+            origin: OriginFor<T>,
+            session_id: SessionId,
+            nonce_commitment: [u8; 32],
+        ) -> DispatchResult {
+            let agent_id = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            Self::ensure_feature(&agent_id, Feature::FrostSigning)?;
+            ensure!(nonce_commitment != [0u8; 32], Error::<T>::InvalidCommitment);
+
+            let mut session = SigningSessions::<T>::get(session_id).ok_or(Error::<T>::SessionNotFound)?;
+            let participants = Self::session_participants(&session);
+            ensure!(participants.contains(&agent_id), Error::<T>::NotFrostParticipant);
+            ensure!(session.phase == SigningPhase::CommitmentCollection, Error::<T>::WrongSessionPhase);
+            ensure!(
+                !session.commitments.iter().any(|(who, _)| who == &agent_id),
+                Error::<T>::AlreadyCommitted
+            );
+
+            session.commitments.try_push((agent_id.clone(), nonce_commitment))
+                .map_err(|_| Error::<T>::TooManyAgents)?;
+
+            Self::deposit_event(Event::CommitmentSubmitted {
+                session_id,
+                agent_id,
+            });
+
+            // Everyone committed, no need to wait for the deadline
+            if session.commitments.len() == participants.len() {
+                Self::start_share_collection(session_id, &mut session)?;
+            }
+
+            SigningSessions::<T>::insert(session_id, session);
+
+            Ok(())
+        }
+
+        /// Submit a signature share to a signing session
+        ///
+        /// The share must be the agent's own signature, under its signature scheme, over the
+        /// session's [`Pallet::share_binding`], which commits to the session, the log and its
+        /// amendment round. Once every committed participant has provided a share, the session
+        /// completes.
+        ///
+        /// Parameters:
+        /// - `session_id`: The signing session
+        /// - `signature_share`: This agent's share of the aggregate signature
+        /// - `binding`: The session's [`Pallet::share_binding`] the share was produced over
+        #[pallet::call_index(5)]
+        #[pallet::weight(Weight::from_parts(10_000, 0)
+            .saturating_add(T::DbWeight::get().reads_writes(4, 2))
+            .saturating_add(Pallet::<T>::max_verify_weight()))]
+        pub fn submit_signature_share(
+            origin: OriginFor<T>,
+            session_id: SessionId,
+            signature_share: [u8; 64],
+            binding: [u8; 32],
+        ) -> DispatchResult {
+            let agent_id = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+            Self::ensure_feature(&agent_id, Feature::FrostSigning)?;
+            ensure!(signature_share != [0u8; 64], Error::<T>::InvalidSignatureShare);
+
+            let mut session = SigningSessions::<T>::get(session_id).ok_or(Error::<T>::SessionNotFound)?;
+            ensure!(session.phase == SigningPhase::ShareCollection, Error::<T>::WrongSessionPhase);
+            ensure!(
+                Self::share_binding(session_id) == Some(binding),
+                Error::<T>::ReplayedSignatureShare
+            );
+            ensure!(
+                session.commitments.iter().any(|(who, _)| who == &agent_id),
+                Error::<T>::NoCommitment
+            );
+            ensure!(
+                !session.shares.iter().any(|(who, _)| who == &agent_id),
+                Error::<T>::ShareAlreadySubmitted
+            );
+            Self::ensure_valid_signature(&agent_id, &agent_id, &binding, &signature_share)
+                .map_err(|_| Error::<T>::InvalidSignatureShare)?;
+
+            session.shares.try_push((agent_id.clone(), signature_share))
+                .map_err(|_| Error::<T>::TooManyAgents)?;
+
+            Self::deposit_event(Event::SignatureShareSubmitted {
+                session_id,
+                agent_id,
+            });
+
+            // Every committed agent provided its share
+            if session.shares.len() == session.commitments.len() {
+                Self::complete_session(session_id, session);
+            } else {
+                SigningSessions::<T>::insert(session_id, session);
+            }
+
+            Ok(())
+        }
+
+        /// Finalize a consensus log according to its consensus model
+        ///
+        /// `ThresholdSignature` logs need an aggregate signature, which must verify against the
+        /// FROST group key and whose participant bitmap may only select agents involved in the
+        /// log. Logs under the other models are finalized from their individual signatures and
+        /// take no aggregate signature.
+        ///
+        /// Parameters:
+        /// - `log_id`: The log to finalize
+        /// - `aggregate`: Optional aggregate signature of the FROST group over the log ID
+        ///
+        /// Charged for the largest possible log up front and refunded down to the number of
+        /// involved agents and signatures actually checked.
+        #[pallet::call_index(6)]
+        #[pallet::weight(Pallet::<T>::finalize_weight(T::MaxAgentsInvolved::get(), T::MaxSignatures::get()))]
+        pub fn finalize_consensus_log(
+            origin: OriginFor<T>,
+            log_id: T::Hash,
+            aggregate: Option<AggregateSignature>,
+        ) -> DispatchResultWithPostInfo {
+            let agent_id = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+
+            let log = Logs::<T>::get(&log_id).ok_or(Error::<T>::LogNotFound)?;
+            ensure!(log.agents_involved.contains(&agent_id), Error::<T>::AgentNotFound);
+            ensure!(!FinalizedLogs::<T>::contains_key(&log_id), Error::<T>::AlreadyFinalized);
+            ensure!(!RejectedLogs::<T>::contains_key(&log_id), Error::<T>::LogRejected);
+
+            let (signers, excluded) = match (&aggregate, log.model) {
+                (Some(aggregate), ConsensusModel::ThresholdSignature) => {
+                    let signers = Self::verify_aggregate_sign_off(&log_id, &log, aggregate)?;
+                    ensure!(signers.iter().all(Self::may_participate), Error::<T>::SignerNotEligible);
+                    ensure!(
+                        Self::quorum_reached(ConsensusModel::ThresholdSignature, &log, &signers),
+                        Error::<T>::MissingSignatures
+                    );
+                    (signers, Vec::new())
+                },
+                (None, ConsensusModel::ThresholdSignature) | (Some(_), _) =>
+                    return Err(Error::<T>::WrongConsensusModel.into()),
+                (None, model) => {
+                    // Signatures of agents that lost their standing since do not count
+                    let all: Vec<T::AccountId> = log.agents_involved
+                        .iter()
+                        .filter(|a| log.signatures.iter().any(|s| &s.agent_id == *a))
+                        .cloned()
+                        .collect();
+                    let (signers, excluded): (Vec<_>, Vec<_>) =
+                        all.iter().cloned().partition(Self::may_participate);
+                    if !Self::quorum_reached(model, &log, &signers) {
+                        return Err(if !excluded.is_empty() && Self::quorum_reached(model, &log, &all) {
+                            Error::<T>::SignersExcluded
+                        } else {
+                            Error::<T>::MissingSignatures
+                        }.into());
+                    }
+                    (signers, excluded)
+                },
+            };
+
+            ensure!(
+                RequiredSigners::<T>::get(&log_id).iter().all(|agent| signers.contains(agent)),
+                Error::<T>::RequiredSignatureMissing
+            );
+            ensure!(
+                log.agents_involved.len() <= 1 || Self::role_quorum_met(log.model, &signers),
+                Error::<T>::RoleQuorumNotMet
+            );
+
+            FinalizedInBlock::<T>::try_append(log_id).map_err(|_| Error::<T>::TooManyFinalizations)?;
+            FinalizedLogs::<T>::insert(&log_id, FinalizedLog {
+                finalized_at: <frame_system::Pallet<T>>::block_number(),
+                aggregate: aggregate.clone(),
+                key_version: Self::group_key_version(),
+            });
+            PendingByPriority::<T>::remove(log.priority, &log_id);
+            Self::clear_duties(&log, &log_id);
+            if !excluded.is_empty() {
+                Logs::<T>::mutate(&log_id, |maybe_log| if let Some(log) = maybe_log {
+                    log.signatures.retain(|sig| !excluded.contains(&sig.agent_id));
+                });
+            }
+            let responders = if aggregate.is_none() {
+                Self::first_responders(&log, &signers)
+            } else {
+                Vec::new()
+            };
+            T::OnLogActivity::on_log_finalized(&signers, &log_id);
+            let leaves = Self::signature_leaves(&log, signers);
+            SignatureRoots::<T>::insert(&log_id, csuite_primitives::merkle_root(&leaves));
+
+            // Emit events
+            for agent_id in excluded {
+                Self::deposit_event(Event::SignatureExcluded { log_id, agent_id });
+            }
+            Self::deposit_event(Event::LogFinalized {
+                log_id,
+                aggregated: aggregate.is_some(),
+            });
+
+            let mut responder_weight = Weight::zero();
+            if !responders.is_empty() {
+                for (position, responder) in responders.iter().enumerate() {
+                    responder_weight = responder_weight
+                        .saturating_add(T::OnFirstResponder::on_first_responder(
+                            responder,
+                            position as u32,
+                            log.priority,
+                        ));
+                }
+                Self::deposit_event(Event::FirstResponders { log_id, agents: responders });
+            }
+
+            let actual_weight = Self::finalize_weight(
+                log.agents_involved.len() as u32,
+                log.signatures.len() as u32,
+            )
+            .saturating_sub(Self::first_responders_weight())
+            .saturating_add(responder_weight);
+            Ok(PostDispatchInfo { actual_weight: Some(actual_weight), pays_fee: Pays::Yes })
+        }
+
+        /// Replace the CID and metadata of a log that is neither finalized nor rejected yet
+        ///
+        /// Only the submitter can amend a log, and not while a signing session is open for it.
+        /// All signatures and votes collected so far are cleared and the previous CID is
+        /// appended to `CidHistory`.
+        ///
+        /// Parameters:
+        /// - `log_id`: The log to amend
+        /// - `new_cid`: Content identifier of the amended content
+        /// - `new_metadata`: Metadata of the amended content
+        #[pallet::call_index(7)]
+        #[pallet::weight(
+            Weight::from_parts(12_000, 0).saturating_add(T::DbWeight::get().reads_writes(
+                11u64.saturating_add(T::MaxAgentsInvolved::get().into()),
+                9u64.saturating_add(T::MaxAgentsInvolved::get().saturating_mul(2).into()),
+            ))
+        )]
+        pub fn amend_consensus_log(
+            origin: OriginFor<T>,
+            log_id: T::Hash,
+            new_cid: Vec<u8>,
+            new_metadata: Option<Vec<u8>>,
+        ) -> DispatchResult {
+            let agent_id = T::SubmitOrigin::ensure_origin(origin)?;
+            Self::ensure_not_paused()?;
+
+            ensure!(!new_cid.is_empty(), Error::<T>::InvalidCID);
+            let bounded_cid = BoundedVec::<u8, T::MaxCIDLength>::try_from(new_cid.clone())
+                .map_err(|_| Error::<T>::InvalidCID)?;
+            let bounded_metadata = if let Some(meta) = new_metadata {
+                Some(BoundedVec::<u8, <T as Config>::MaxMetadataLength>::try_from(meta)
+                    .map_err(|_| Error::<T>::InvalidMetadata)?)
+            } else {
+                None
+            };
+
+            ensure!(!FinalizedLogs::<T>::contains_key(&log_id), Error::<T>::AlreadyFinalized);
+            ensure!(!RejectedLogs::<T>::contains_key(&log_id), Error::<T>::LogRejected);
+            ensure!(!SessionOfLog::<T>::contains_key(&log_id), Error::<T>::SessionAlreadyOpen);
+
+            let now = <frame_system::Pallet<T>>::block_number();
+            let (old_cid, amendment, involved) = Logs::<T>::try_mutate(&log_id, |maybe_log| -> Result<_, DispatchError> {
+                let log = maybe_log.as_mut().ok_or(Error::<T>::LogNotFound)?;
+                ensure!(log.submitter == agent_id, Error::<T>::NotSubmitter);
+                ensure!(log.amendment_count < T::MaxAmendments::get(), Error::<T>::TooManyAmendments);
+
+                let old_cid = core::mem::replace(&mut log.cid, bounded_cid.clone());
+                log.metadata = bounded_metadata;
+                log.signatures = BoundedVec::default();
+                log.amendment_count = log.amendment_count.saturating_add(1);
+                Ok((old_cid, log.amendment_count, log.agents_involved.clone()))
+            })?;
+
+            CidHistory::<T>::try_mutate(&log_id, |history| history.try_push((old_cid.clone(), now)))
+                .map_err(|_| Error::<T>::TooManyAmendments)?;
+            let _ = LogVotes::<T>::clear_prefix(&log_id, T::MaxAgentsInvolved::get(), None);
+            // The amended metadata replaces the attached blob
+            Self::detach_metadata_blob(&log_id);
+
+            // Every involved agent, the submitter included, has to sign the amended content
+            for agent in involved.iter() {
+                Self::add_duty(agent, log_id);
+            }
+
+            // Move the log to its new CID in the CID index
+            if old_cid != bounded_cid {
+                Self::unindex_log_by_cid(&old_cid, &log_id);
+                Self::index_log_by_cid(&bounded_cid, log_id)?;
+            }
+
+            // Emit event
+            Self::deposit_event(Event::LogAmended {
+                log_id,
+                amendment,
+                cid: new_cid,
+            });
+
+            Ok(())
+        }
+
+        /// Delete a log before anyone but its submitter signed it
+        ///
+        /// Only the submitter can retract a log, and only while it is not finalized and no
+        /// signing session is open for it. The log is removed from every index and its deposit
+        /// is refunded minus `RetractionFee`.
+        ///
+        /// Parameters:
+        /// - `log_id`: The log to retract
+        #[pallet::call_index(11)]
+        #[pallet::weight(
+            Weight::from_parts(12_000, 0).saturating_add(T::DbWeight::get().reads_writes(
+                7u64.saturating_add(T::MaxAgentsInvolved::get().saturating_mul(2).into()),
+                10u64.saturating_add(T::MaxAgentsInvolved::get().saturating_mul(4).into()),
+            ))
+        )]
+        pub fn retract_consensus_log(
+            origin: OriginFor<T>,
+            log_id: T::Hash,
+        ) -> DispatchResult {
+            let agent_id = T::SubmitOrigin::ensure_origin(origin)?;
+            Self::ensure_not_paused()?;
+
+            let log = Logs::<T>::get(&log_id).ok_or(Error::<T>::LogNotFound)?;
+            ensure!(log.submitter == agent_id, Error::<T>::NotSubmitter);
+            ensure!(!FinalizedLogs::<T>::contains_key(&log_id), Error::<T>::AlreadyFinalized);
+            ensure!(!SessionOfLog::<T>::contains_key(&log_id), Error::<T>::SessionAlreadyOpen);
+            ensure!(
+                log.signatures.iter().all(|s| s.agent_id == agent_id),
+                Error::<T>::LogAlreadySigned
+            );
+
+            // Drop the log from every index
+            Logs::<T>::remove(&log_id);
+            PendingByPriority::<T>::remove(log.priority, &log_id);
+            CidHistory::<T>::remove(&log_id);
+            LogAnnotations::<T>::remove(&log_id);
+            RequiredSigners::<T>::remove(&log_id);
+            RejectedLogs::<T>::remove(&log_id);
+            Self::detach_metadata_blob(&log_id);
+            let _ = LogVotes::<T>::clear_prefix(&log_id, T::MaxAgentsInvolved::get(), None);
+            // Agents whose signatures an amendment cleared may have signed before
+            let _ = FirstSignatures::<T>::clear_prefix(&log_id, T::MaxAgentsInvolved::get(), None);
+            Self::unindex_log_by_agents(&log, &log_id);
+            Self::clear_duties(&log, &log_id);
+            Self::unindex_log_by_cid(&log.cid, &log_id);
+            T::OnLogActivity::on_log_retracted(&agent_id, &log_id);
+
+            // Refund the deposit, burning the retraction fee
+            let (refunded, fee) = Self::refund_log_deposit(&log_id);
+
+            // Emit event
+            Self::deposit_event(Event::LogRetracted {
+                log_id,
+                submitter: agent_id,
+                refunded,
+                fee,
+            });
+
+            Ok(())
+        }
+
+        /// Halt every call of the pallet except `pause` and `unpause`
+        #[pallet::call_index(8)]
+        #[pallet::weight(Weight::from_parts(5_000, 0).saturating_add(T::DbWeight::get().writes(1)))]
+        pub fn pause(origin: OriginFor<T>) -> DispatchResult {
+            T::PauseOrigin::ensure_origin(origin)?;
+
+            Paused::<T>::put(true);
+
+            // Emit event
+            Self::deposit_event(Event::Paused);
+
+            Ok(())
+        }
+
+        /// Resume normal operation after `pause`
+        #[pallet::call_index(9)]
+        #[pallet::weight(Weight::from_parts(5_000, 0).saturating_add(T::DbWeight::get().writes(1)))]
+        pub fn unpause(origin: OriginFor<T>) -> DispatchResult {
+            T::PauseOrigin::ensure_origin(origin)?;
+
+            Paused::<T>::kill();
+
+            // Emit event
+            Self::deposit_event(Event::Unpaused);
+
+            Ok(())
+        }
+
+        /// Set or clear the roles multi-agent insights must include
+        ///
+        /// Parameters:
+        /// - `model`: The consensus model the policy applies to, or `None` for the global policy
+        /// - `policy`: The roles to require, or `None` to clear the policy
+        #[pallet::call_index(12)]
+        #[pallet::weight(Weight::from_parts(5_000, 0).saturating_add(T::DbWeight::get().writes(1)))]
+        pub fn set_role_quorum_policy(
+            origin: OriginFor<T>,
+            model: Option<ConsensusModel>,
+            policy: Option<RoleQuorum<T>>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            match (model, policy.clone()) {
+                (Some(model), Some(policy)) => ModelRoleQuorumPolicies::<T>::insert(model, policy),
+                (Some(model), None) => ModelRoleQuorumPolicies::<T>::remove(model),
+                (None, Some(policy)) => RoleQuorumPolicy::<T>::put(policy),
+                (None, None) => RoleQuorumPolicy::<T>::kill(),
+            }
+
+            // Emit event
+            Self::deposit_event(Event::RoleQuorumPolicySet { model, policy });
+
+            Ok(())
+        }
+
+        /// Attach an annotation to a pending log
+        ///
+        /// Only agents involved in the log can annotate it, at most `MaxAnnotationsPerAuthor`
+        /// times, and only until it is finalized or rejected.
+        ///
+        /// Parameters:
+        /// - `log_id`: The log to annotate
+        /// - `cid`: Content identifier of the annotation
+        #[pallet::call_index(13)]
+        #[pallet::weight(Weight::from_parts(8_000, 0).saturating_add(T::DbWeight::get().reads_writes(5, 1)))]
+        pub fn annotate_log(
             origin: OriginFor<T>,
+            log_id: T::Hash,
             cid: Vec<u8>,
-            metadata: Option<Vec<u8>>,
         ) -> DispatchResult {
-            let agent_id = ensure_signed(origin)?;
-            
-            // Ensure agent exists and is active
-            let agent = <agent_registry::Pallet<T>>::agents(&agent_id).ok_or(Error::<T>::AgentNotFound)?;
-            ensure!(agent.status == AgentStatus::Online, Error::<T>::AgentNotActive);
-            
-            // Validate CID
+            let agent_id = T::SubmitOrigin::ensure_origin(origin)?;
+            Self::ensure_not_paused()?;
+
             ensure!(!cid.is_empty(), Error::<T>::InvalidCID);
             let bounded_cid = BoundedVec::<u8, T::MaxCIDLength>::try_from(cid.clone())
                 .map_err(|_| Error::<T>::InvalidCID)?;
-                
-            // Validate and bound the metadata if provided
-            let bounded_metadata = if let Some(meta) = metadata {
-                Some(BoundedVec::<u8, <T as Config>::MaxMetadataLength>::try_from(meta)
-                    .map_err(|_| Error::<T>::InvalidMetadata)?)
-            } else {
-                None
-            };
-            
-            // For initial submission, only the submitting agent is involved
-            let mut agents_involved = BoundedVec::<T::AccountId, T::MaxAgentsInvolved>::default();
-            let _ = agents_involved.try_push(agent_id.clone());
-            
-            // No signatures initially (will be added later via sign_log)
-            let signatures = BoundedVec::<SignatureInfo<T>, T::MaxSignatures>::default();
-            
-            // Create the consensus log
-            let consensus_log = ConsensusLog {
-                timestamp: <frame_system::Pallet<T>>::block_number(),
-                cid: bounded_cid.clone(),
-                agents_involved,
-                signatures,
-                metadata: bounded_metadata,
-            };
-            
-            // Generate a unique log ID by hashing the content
-            let log_id = T::Hashing::hash_of(&consensus_log);
-            
-            // Ensure log doesn't already exist
-            ensure!(!Logs::<T>::contains_key(&log_id), Error::<T>::LogAlreadyExists);
-            
-            // Store the consensus log
-            Logs::<T>::insert(&log_id, consensus_log);
-            
-            // Update agent index
-            LogsByAgent::<T>::try_mutate(&agent_id, |logs| -> DispatchResult {
-                logs.try_push(log_id.clone()).map_err(|_| Error::<T>::TooManyAgents)?;
-                Ok(())
-            })?;
-            
-            // Update CID index
-            LogsByCID::<T>::try_mutate(bounded_cid.clone(), |logs| -> DispatchResult {
-                logs.try_push(log_id.clone()).map_err(|_| Error::<T>::TooManyAgents)?;
-                Ok(())
+
+            let log = Logs::<T>::get(&log_id).ok_or(Error::<T>::LogNotFound)?;
+            ensure!(!FinalizedLogs::<T>::contains_key(&log_id), Error::<T>::AlreadyFinalized);
+            ensure!(!RejectedLogs::<T>::contains_key(&log_id), Error::<T>::LogRejected);
+            ensure!(log.agents_involved.contains(&agent_id), Error::<T>::NotInvolved);
+
+            LogAnnotations::<T>::try_mutate(&log_id, |annotations| {
+                ensure!(
+                    (annotations.len() as u32) < T::MaxAnnotations::get(),
+                    Error::<T>::TooManyAnnotations
+                );
+                let authored = annotations.iter().filter(|annotation| annotation.author == agent_id).count();
+                ensure!(
+                    (authored as u32) < T::MaxAnnotationsPerAuthor::get(),
+                    Error::<T>::TooManyAnnotationsByAuthor
+                );
+                annotations
+                    .try_push(Annotation {
+                        author: agent_id.clone(),
+                        cid: bounded_cid,
+                        annotated_at: <frame_system::Pallet<T>>::block_number(),
+                    })
+                    .map_err(|_| Error::<T>::TooManyAnnotations)
             })?;
-            
+
             // Emit event
-            Self::deposit_event(Event::ConsensusLogged {
+            Self::deposit_event(Event::LogAnnotated {
                 log_id,
-                agent_id,
+                author: agent_id,
                 cid,
             });
-            
+
             Ok(())
         }
-        
-        /// Submit an insight with multiple agents involved
+
+        /// Rotate the FROST signing group to a new participant set
+        ///
+        /// Accepts the group key produced by a DKG among `participants` as given: it cannot be
+        /// derived from their own keys, so the origin vouches for it. The previous group is kept
+        /// in `RetiredFrostGroups` under its key version so the aggregates it signed can still
+        /// be verified.
         ///
         /// Parameters:
-        /// - `agents_involved`: List of agent IDs participating in this insight
-        /// - `cid`: Content identifier for IPFS storage
-        /// - `signature`: Digital signature from the submitting agent
+        /// - `participants`: The new participants, in bitmap order
+        /// - `threshold`: Number of partial signatures the new group requires
+        /// - `group_key`: Public key of the new group
+        #[pallet::call_index(14)]
+        #[pallet::weight(
+            Weight::from_parts(10_000, 0)
+                .saturating_add(Weight::from_parts(1_000, 0).saturating_mul(participants.len() as u64))
+                .saturating_add(T::DbWeight::get().reads_writes(4u64.saturating_add(participants.len() as u64), 5))
+        )]
+        pub fn rotate_participant_set(
+            origin: OriginFor<T>,
+            participants: Vec<T::AccountId>,
+            threshold: u32,
+            group_key: [u8; 32],
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ensure!(
+                !participants.is_empty() && participants.len() <= T::MaxFrostParticipants::get() as usize,
+                Error::<T>::InvalidFrostParticipants
+            );
+            let bounded = BoundedVec::<T::AccountId, T::MaxAgentsInvolved>::try_from(participants.clone())
+                .map_err(|_| Error::<T>::InvalidFrostParticipants)?;
+            for (i, agent_id) in participants.iter().enumerate() {
+                ensure!(!participants[..i].contains(agent_id), Error::<T>::DuplicateFrostParticipant);
+                ensure!(Self::may_participate(agent_id), Error::<T>::IneligibleFrostParticipant);
+            }
+            ensure!(
+                threshold >= 1 && threshold as usize <= participants.len(),
+                Error::<T>::InvalidFrostThreshold
+            );
+
+            let version = Self::group_key_version();
+            if let Some(old_key) = FrostGroupKey::<T>::get() {
+                RetiredFrostGroups::<T>::insert(version, RetiredFrostGroup {
+                    group_key: old_key,
+                    participants: FrostParticipants::<T>::get(),
+                    threshold: FrostThreshold::<T>::get(),
+                    retired_at: <frame_system::Pallet<T>>::block_number(),
+                });
+            }
+
+            let version = version.saturating_add(1);
+            FrostGroupKeyVersion::<T>::put(version);
+            FrostGroupKey::<T>::put(group_key);
+            FrostParticipants::<T>::put(bounded);
+            FrostThreshold::<T>::put(threshold);
+
+            // Emit event
+            Self::deposit_event(Event::FrostGroupRotated {
+                version,
+                group_key,
+                participants,
+                threshold,
+            });
+
+            Ok(())
+        }
+
+        /// Store a metadata blob too long for a log's inline metadata
+        ///
+        /// Reserves [`Pallet::metadata_blob_deposit`] from the caller, who owns the blob until
+        /// it is unnoted.
+        ///
+        /// Parameters:
+        /// - `blob`: The metadata, up to `MaxMetadataBlobLength` bytes
+        #[pallet::call_index(15)]
+        #[pallet::weight(
+            Weight::from_parts(10_000, 0)
+                .saturating_add(Weight::from_parts(2, 0).saturating_mul(blob.len() as u64))
+                .saturating_add(T::DbWeight::get().reads_writes(2, 3))
+        )]
+        pub fn note_metadata_blob(
+            origin: OriginFor<T>,
+            blob: Vec<u8>,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+
+            ensure!(!blob.is_empty(), Error::<T>::InvalidMetadataBlob);
+            let bounded = BoundedVec::<u8, T::MaxMetadataBlobLength>::try_from(blob)
+                .map_err(|_| Error::<T>::InvalidMetadataBlob)?;
+            let hash = T::Hashing::hash(&bounded);
+            ensure!(!MetadataBlobTickets::<T>::contains_key(&hash), Error::<T>::MetadataBlobAlreadyNoted);
+
+            let len = bounded.len() as u32;
+            let deposit = Self::metadata_blob_deposit(len);
+            T::Currency::reserve(&owner, deposit).map_err(|_| Error::<T>::InsufficientDeposit)?;
+            MetadataBlobTickets::<T>::insert(&hash, MetadataBlobTicket {
+                owner: owner.clone(),
+                deposit,
+                len,
+                references: 0,
+            });
+            MetadataBlobs::<T>::insert(&hash, bounded);
+
+            // Emit event
+            Self::deposit_event(Event::MetadataBlobNoted { hash, owner, len, deposit });
+
+            Ok(())
+        }
+
+        /// Remove a metadata blob no log references and return its deposit
+        ///
+        /// Parameters:
+        /// - `hash`: Hash of the blob
+        #[pallet::call_index(16)]
+        #[pallet::weight(Weight::from_parts(8_000, 0).saturating_add(T::DbWeight::get().reads_writes(2, 3)))]
+        pub fn unnote_metadata_blob(
+            origin: OriginFor<T>,
+            hash: T::Hash,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+
+            let ticket = MetadataBlobTickets::<T>::get(&hash).ok_or(Error::<T>::UnknownMetadataBlob)?;
+            ensure!(ticket.owner == who, Error::<T>::NotMetadataBlobOwner);
+            ensure!(ticket.references == 0, Error::<T>::MetadataBlobInUse);
+
+            MetadataBlobTickets::<T>::remove(&hash);
+            MetadataBlobs::<T>::remove(&hash);
+            T::Currency::unreserve(&who, ticket.deposit);
+
+            // Emit event
+            Self::deposit_event(Event::MetadataBlobUnnoted {
+                hash,
+                owner: who,
+                deposit: ticket.deposit,
+            });
+
+            Ok(())
+        }
+
+        /// Reference a noted metadata blob from a log
+        ///
+        /// Only the submitter can attach a blob, only one it noted itself, and only while no other
+        /// agent signed the log, as for retraction. A blob attached before replaces the previous
+        /// one.
+        ///
+        /// Parameters:
+        /// - `log_id`: The log the blob describes
+        /// - `hash`: Hash of the blob
+        #[pallet::call_index(17)]
+        #[pallet::weight(Weight::from_parts(10_000, 0).saturating_add(T::DbWeight::get().reads_writes(6, 3)))]
+        pub fn attach_metadata_blob(
+            origin: OriginFor<T>,
+            log_id: T::Hash,
+            hash: T::Hash,
+        ) -> DispatchResult {
+            let agent_id = T::SubmitOrigin::ensure_origin(origin)?;
+            Self::ensure_not_paused()?;
+
+            let log = Logs::<T>::get(&log_id).ok_or(Error::<T>::LogNotFound)?;
+            ensure!(log.submitter == agent_id, Error::<T>::NotSubmitter);
+            ensure!(!FinalizedLogs::<T>::contains_key(&log_id), Error::<T>::AlreadyFinalized);
+            ensure!(!RejectedLogs::<T>::contains_key(&log_id), Error::<T>::LogRejected);
+            ensure!(!SessionOfLog::<T>::contains_key(&log_id), Error::<T>::SessionAlreadyOpen);
+            ensure!(
+                log.signatures.iter().all(|s| s.agent_id == agent_id),
+                Error::<T>::LogAlreadySigned
+            );
+            let ticket = MetadataBlobTickets::<T>::get(&hash).ok_or(Error::<T>::UnknownMetadataBlob)?;
+            ensure!(ticket.owner == agent_id, Error::<T>::NotMetadataBlobOwner);
+
+            Self::detach_metadata_blob(&log_id);
+            MetadataBlobTickets::<T>::mutate(&hash, |ticket| {
+                if let Some(ticket) = ticket {
+                    ticket.references = ticket.references.saturating_add(1);
+                }
+            });
+            LogMetadataBlobs::<T>::insert(&log_id, hash);
+
+            // Emit event
+            Self::deposit_event(Event::MetadataBlobAttached { log_id, hash });
+
+            Ok(())
+        }
+
+        /// Open a blinded insight round among `participants`
+        ///
+        /// Each participant commits to its insight with `commit_insight`, and reveals it with
+        /// `reveal_insight` once every participant committed or the commitment phase ended.
+        /// Every revealed insight is logged with all participants involved.
+        ///
+        /// Parameters:
+        /// - `participants`: Agents taking part in the round, the opener included
+        /// - `model`: How the revealed insights finalize
+        /// - `priority`: How urgently the revealed insights need to be signed off, which also
+        ///   scales the round's phases
+        #[pallet::call_index(18)]
+        #[pallet::weight(Weight::from_parts(15_000, 0)
+            .saturating_add(T::DbWeight::get().reads_writes(6, 3))
+            // Registration and role of every participant
+            .saturating_add(T::DbWeight::get().reads(2 * T::MaxAgentsInvolved::get() as u64)))]
+        pub fn open_insight_round(
+            origin: OriginFor<T>,
+            participants: Vec<T::AccountId>,
+            model: ConsensusModel,
+            priority: LogPriority,
+        ) -> DispatchResult {
+            let agent_id = T::SubmitOrigin::ensure_origin(origin)?;
+            Self::ensure_not_paused()?;
+
+            // Ensure agent exists and is active
+            ensure!(T::Agents::is_registered(&agent_id), Error::<T>::AgentNotFound);
+            ensure!(T::Agents::is_online(&agent_id), Error::<T>::AgentNotActive);
+            ensure!(T::AllowedModels::contains(&model), Error::<T>::ConsensusModelNotAllowed);
+            Self::ensure_can_submit_with(&agent_id, priority)?;
+
+            // The participants are checked as the agents involved in an insight are
+            let mut bounded_participants = BoundedVec::<T::AccountId, T::MaxAgentsInvolved>::default();
+            for agent in sp_std::iter::once(&agent_id).chain(participants.iter()) {
+                ensure!(T::Agents::is_registered(agent), Error::<T>::AgentNotFound);
+                if !bounded_participants.contains(agent) {
+                    bounded_participants.try_push(agent.clone()).map_err(|_| Error::<T>::TooManyAgents)?;
+                }
+            }
+            ensure!(bounded_participants.len() >= 2, Error::<T>::NotEnoughAgents);
+            if T::RequireSameOrg::get() {
+                ensure!(T::Orgs::same_org(&bounded_participants), Error::<T>::AgentsNotInSameOrg);
+            }
+            ensure!(Self::role_quorum_met(model, &bounded_participants), Error::<T>::RoleQuorumNotMet);
+
+            let round_id = Self::next_round_id();
+            let deadline = Self::schedule_round_phase_end(round_id, priority.scale_period(T::InsightCommitPeriod::get()))?;
+
+            InsightRounds::<T>::insert(round_id, InsightRound {
+                opener: agent_id.clone(),
+                participants: bounded_participants.clone(),
+                phase: RoundPhase::Commit,
+                phase_deadline: deadline,
+                model,
+                priority,
+                commitments: BoundedVec::new(),
+                revealed: BoundedVec::new(),
+            });
+            NextRoundId::<T>::put(round_id.saturating_add(1));
+
+            // Emit event
+            Self::deposit_event(Event::InsightRoundOpened {
+                round_id,
+                opener: agent_id,
+                participants: bounded_participants.into_inner(),
+                deadline,
+            });
+
+            Ok(())
+        }
+
+        /// Commit to the insight the caller will reveal in a blinded insight round
+        ///
+        /// Once every participant has committed, the round moves on to collecting reveals.
+        ///
+        /// Parameters:
+        /// - `round_id`: The blinded insight round
+        /// - `commitment`: The caller's [`Pallet::insight_commitment`] to its insight
+        #[pallet::call_index(19)]
+        #[pallet::weight(Weight::from_parts(10_000, 0).saturating_add(T::DbWeight::get().reads_writes(3, 2)))]
+        pub fn commit_insight(
+            origin: OriginFor<T>,
+            round_id: RoundId,
+            commitment: T::Hash,
+        ) -> DispatchResult {
+            let agent_id = T::SubmitOrigin::ensure_origin(origin)?;
+            Self::ensure_not_paused()?;
+
+            let mut round = InsightRounds::<T>::get(round_id).ok_or(Error::<T>::RoundNotFound)?;
+            ensure!(round.phase == RoundPhase::Commit, Error::<T>::WrongRoundPhase);
+            ensure!(round.participants.contains(&agent_id), Error::<T>::NotRoundParticipant);
+            ensure!(
+                !round.commitments.iter().any(|(who, _)| who == &agent_id),
+                Error::<T>::AlreadyCommitted
+            );
+
+            round.commitments.try_push((agent_id.clone(), commitment))
+                .map_err(|_| Error::<T>::TooManyAgents)?;
+
+            Self::deposit_event(Event::InsightCommitted {
+                round_id,
+                agent_id,
+            });
+
+            // Everyone committed, no need to wait for the deadline
+            if round.commitments.len() == round.participants.len() {
+                Self::start_reveal(round_id, &mut round)?;
+            }
+
+            InsightRounds::<T>::insert(round_id, round);
+
+            Ok(())
+        }
+
+        /// Reveal the insight the caller committed to in a blinded insight round
+        ///
+        /// An insight matching the commitment is logged with every participant of the round
+        /// involved and no signature yet, reserving the log deposit from the caller. An insight
+        /// that does not match voids the commitment, and the caller is reported through
+        /// [`SigningFaultReporter::report_mismatched_reveal`].
+        ///
+        /// Parameters:
+        /// - `round_id`: The blinded insight round
+        /// - `cid`: Content identifier of the insight committed to
+        /// - `salt`: Salt the commitment was made with
         /// - `metadata`: Optional metadata about the insight
-        #[pallet::call_index(1)]
-        #[pallet::weight(Weight::from_parts(15_000, 0).saturating_add(T::DbWeight::get().reads_writes(5, 4)))]
-        pub fn submit_insight(
+        #[pallet::call_index(20)]
+        #[pallet::weight(Weight::from_parts(15_000, 0)
+            .saturating_add(T::DbWeight::get().reads_writes(10, 10))
+            // Agent index and pending duties of every participant
+            .saturating_add(T::DbWeight::get().reads_writes(
+                2 * T::MaxAgentsInvolved::get() as u64,
+                2 * T::MaxAgentsInvolved::get() as u64,
+            )))]
+        pub fn reveal_insight(
             origin: OriginFor<T>,
-            agents_involved: Vec<T::AccountId>,
+            round_id: RoundId,
             cid: Vec<u8>,
-            signature: Vec<u8>,
+            salt: [u8; 32],
             metadata: Option<Vec<u8>>,
         ) -> DispatchResult {
-            let agent_id = ensure_signed(origin)?;
-            
+            let agent_id = T::SubmitOrigin::ensure_origin(origin)?;
+            Self::ensure_not_paused()?;
+
             // Ensure agent exists and is active
-            let agent = <agent_registry::Pallet<T>>::agents(&agent_id).ok_or(Error::<T>::AgentNotFound)?;
-            ensure!(agent.status == AgentStatus::Online, Error::<T>::AgentNotActive);
-            
-            // Validate inputs
-            ensure!(agents_involved.len() >= 2, Error::<T>::NotEnoughAgents);
-            
+            ensure!(T::Agents::is_registered(&agent_id), Error::<T>::AgentNotFound);
+            ensure!(T::Agents::is_online(&agent_id), Error::<T>::AgentNotActive);
+
+            let mut round = InsightRounds::<T>::get(round_id).ok_or(Error::<T>::RoundNotFound)?;
+            ensure!(round.phase == RoundPhase::Reveal, Error::<T>::WrongRoundPhase);
+            let position = round.commitments.iter()
+                .position(|(who, _)| who == &agent_id)
+                .ok_or(Error::<T>::NoCommitment)?;
+
             // Validate CID
             ensure!(!cid.is_empty(), Error::<T>::InvalidCID);
             let bounded_cid = BoundedVec::<u8, T::MaxCIDLength>::try_from(cid.clone())
                 .map_err(|_| Error::<T>::InvalidCID)?;
-                
-            // Validate signature
-            ensure!(!signature.is_empty(), Error::<T>::InvalidSignature);
-            let bounded_signature = BoundedVec::<u8, T::MaxSignatureLength>::try_from(signature)
-                .map_err(|_| Error::<T>::InvalidSignature)?;
-                
+
             // Validate and bound the metadata if provided
             let bounded_metadata = if let Some(meta) = metadata {
                 Some(BoundedVec::<u8, <T as Config>::MaxMetadataLength>::try_from(meta)
@@ -315,120 +3001,1141 @@ pub mod pallet {
             } else {
                 None
             };
-            
-            // Validate and bound agents involved
-            let mut bounded_agents = BoundedVec::<T::AccountId, T::MaxAgentsInvolved>::default();
-            for agent in &agents_involved {
-                // Ensure each agent exists
-                ensure!(<agent_registry::Pallet<T>>::agents(agent).is_some(), Error::<T>::AgentNotFound);
-                bounded_agents.try_push(agent.clone()).map_err(|_| Error::<T>::TooManyAgents)?;
-            }
-            
-            // Include the submitting agent if not already in the list
-            if !bounded_agents.contains(&agent_id) {
-                bounded_agents.try_push(agent_id.clone()).map_err(|_| Error::<T>::TooManyAgents)?;
+
+            // A reveal that does not open the commitment uses it up
+            let (_, commitment) = round.commitments.remove(position);
+            if commitment != Self::insight_commitment(round_id, &agent_id, &bounded_cid, &salt) {
+                T::SigningFaultReporter::report_mismatched_reveal(&agent_id);
+                Self::deposit_event(Event::InsightRevealMismatched {
+                    round_id,
+                    agent_id,
+                });
+                if round.commitments.is_empty() {
+                    Self::close_round(round_id, &mut round);
+                }
+                InsightRounds::<T>::insert(round_id, round);
+                return Ok(());
             }
-            
-            // Create initial signatures with the submitting agent's signature
-            let mut signatures = BoundedVec::<SignatureInfo<T>, T::MaxSignatures>::default();
-            let sig_info = SignatureInfo {
-                agent_id: agent_id.clone(),
-                signature: bounded_signature,
-            };
-            signatures.try_push(sig_info).map_err(|_| Error::<T>::SignatureListFull)?;
-            
-            // Create the consensus log
+
+            // Reject a repeat of a recent submission
+            Self::note_submission(&agent_id, &bounded_cid, &bounded_metadata)?;
+
+            // Every participant signs the revealed insight, the revealer included
             let consensus_log = ConsensusLog {
+                submitter: agent_id.clone(),
                 timestamp: <frame_system::Pallet<T>>::block_number(),
                 cid: bounded_cid.clone(),
-                agents_involved: bounded_agents.clone(),
-                signatures,
+                agents_involved: round.participants.clone(),
+                signatures: BoundedVec::default(),
                 metadata: bounded_metadata,
+                amendment_count: 0,
+                model: round.model,
+                priority: round.priority,
             };
-            
-            // Generate a unique log ID
-            let log_id = T::Hashing::hash_of(&consensus_log);
-            
+
+            // Generate a unique log ID from the submitter, their nonce and the content
+            let nonce = Self::agent_nonce(&agent_id);
+            let log_id = Self::derive_log_id(&agent_id, nonce, &consensus_log);
+
             // Ensure log doesn't already exist
             ensure!(!Logs::<T>::contains_key(&log_id), Error::<T>::LogAlreadyExists);
-            
-            // Store the consensus log
-            Logs::<T>::insert(&log_id, consensus_log);
-            
-            // Update agent indices for all involved agents
-            for agent in &bounded_agents {
-                LogsByAgent::<T>::try_mutate(agent, |logs| -> DispatchResult {
-                    logs.try_push(log_id.clone()).map_err(|_| Error::<T>::TooManyAgents)?;
-                    Ok(())
-                })?;
+
+            // As in `submit_insight`, the writes and index updates succeed or fail together
+            frame_support::storage::with_storage_layer(|| -> DispatchResult {
+                Self::reserve_log_deposit(&agent_id, &log_id)?;
+
+                // Store the consensus log, queue it by priority and consume the nonce
+                Logs::<T>::insert(&log_id, consensus_log);
+                PendingByPriority::<T>::insert(round.priority, &log_id, <frame_system::Pallet<T>>::block_number());
+                AgentNonces::<T>::insert(&agent_id, nonce.saturating_add(1));
+
+                // Update agent indices and duties for all participants
+                for agent in &round.participants {
+                    Self::index_log_by_agent(agent, log_id)?;
+                    Self::add_duty(agent, log_id);
+                }
+
+                // Update CID index
+                Self::index_log_by_cid(&bounded_cid, log_id)
+            })?;
+
+            T::OnLogActivity::on_log_submitted(&agent_id, &log_id);
+
+            // Emit event
+            Self::deposit_event(Event::InsightRevealed {
+                round_id,
+                agent_id: agent_id.clone(),
+                log_id,
+                cid,
+            });
+
+            round.revealed.try_push((agent_id, log_id)).map_err(|_| Error::<T>::TooManyAgents)?;
+
+            // Every commitment was opened, no need to wait for the deadline
+            if round.commitments.is_empty() {
+                Self::close_round(round_id, &mut round);
+            }
+
+            InsightRounds::<T>::insert(round_id, round);
+
+            Ok(())
+        }
+    }
+
+    // Helper functions
+    impl<T: Config> Pallet<T> {
+        /// Fail with `PalletPaused` while the pallet is paused
+        fn ensure_not_paused() -> DispatchResult {
+            ensure!(!Paused::<T>::get(), Error::<T>::PalletPaused);
+            Ok(())
+        }
+
+        /// Check that pending logs and duties point at stored logs awaiting finalization, that
+        /// metadata blobs count the logs referencing them, and that retired FROST groups
+        /// predate the current key version
+        #[cfg(any(feature = "try-runtime", test))]
+        pub fn do_try_state() -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            let is_pending = |log_id: &T::Hash| {
+                Logs::<T>::get(log_id).map_or(false, |log| PendingByPriority::<T>::contains_key(log.priority, log_id))
+            };
+
+            for (priority, log_id, _) in PendingByPriority::<T>::iter() {
+                ensure!(
+                    Logs::<T>::get(&log_id).map_or(false, |log| log.priority == priority),
+                    "ConsensusLog: a pending log is missing or queued under another priority"
+                );
+                ensure!(
+                    !FinalizedLogs::<T>::contains_key(&log_id) && !RejectedLogs::<T>::contains_key(&log_id),
+                    "ConsensusLog: a finalized or rejected log is still pending"
+                );
+            }
+
+            for (agent_id, duties) in PendingDuties::<T>::iter() {
+                for log_id in duties.iter() {
+                    ensure!(is_pending(log_id), "ConsensusLog: a pending duty points at a log that is not pending");
+                    ensure!(
+                        Logs::<T>::get(log_id).map_or(false, |log| log.agents_involved.contains(&agent_id)),
+                        "ConsensusLog: a pending duty belongs to an agent not involved in the log"
+                    );
+                }
+            }
+
+            for (hash, ticket) in MetadataBlobTickets::<T>::iter() {
+                ensure!(
+                    MetadataBlobs::<T>::get(&hash).map_or(false, |blob| blob.len() as u32 == ticket.len),
+                    "ConsensusLog: a metadata blob is missing or does not match its ticket"
+                );
+                let references = LogMetadataBlobs::<T>::iter_values().filter(|attached| *attached == hash).count();
+                ensure!(
+                    references as u32 == ticket.references,
+                    "ConsensusLog: a metadata blob miscounts the logs referencing it"
+                );
+            }
+            ensure!(
+                LogMetadataBlobs::<T>::iter_values().all(|hash| MetadataBlobTickets::<T>::contains_key(hash)),
+                "ConsensusLog: a log references a metadata blob that is not noted"
+            );
+
+            let version = Self::group_key_version();
+            ensure!(
+                RetiredFrostGroups::<T>::iter_keys().all(|retired| retired < version),
+                "ConsensusLog: a retired FROST group is not older than the current group key"
+            );
+
+            Ok(())
+        }
+
+        /// Deposit reserved for a metadata blob of `len` bytes
+        pub fn metadata_blob_deposit(len: u32) -> BalanceOf<T> {
+            T::MetadataBlobDepositBase::get()
+                .saturating_add(T::MetadataBlobDepositPerByte::get().saturating_mul(len.into()))
+        }
+
+        /// The metadata blob attached to a log, or `None` if it has none
+        pub fn log_metadata_blob(log_id: &T::Hash) -> Option<Vec<u8>> {
+            let hash = LogMetadataBlobs::<T>::get(log_id)?;
+            MetadataBlobs::<T>::get(hash).map(BoundedVec::into_inner)
+        }
+
+        /// Drop a log's reference to its metadata blob, if it has one
+        fn detach_metadata_blob(log_id: &T::Hash) {
+            if let Some(hash) = LogMetadataBlobs::<T>::take(log_id) {
+                MetadataBlobTickets::<T>::mutate(&hash, |ticket| {
+                    if let Some(ticket) = ticket {
+                        ticket.references = ticket.references.saturating_sub(1);
+                    }
+                });
+            }
+        }
+
+        /// Add a log to an agent's pending duties, dropping its oldest duty if they are full
+        fn add_duty(agent_id: &T::AccountId, log_id: T::Hash) {
+            PendingDuties::<T>::mutate(agent_id, |duties| {
+                if duties.contains(&log_id) {
+                    return;
+                }
+                if let Err(log_id) = duties.try_push(log_id) {
+                    if !duties.is_empty() {
+                        duties.remove(0);
+                        let _ = duties.try_push(log_id);
+                    }
+                }
+            });
+        }
+
+        /// Remove a log from an agent's pending duties
+        fn remove_duty(agent_id: &T::AccountId, log_id: &T::Hash) {
+            PendingDuties::<T>::mutate_exists(agent_id, |maybe_duties| {
+                if let Some(duties) = maybe_duties {
+                    duties.retain(|id| id != log_id);
+                    if duties.is_empty() {
+                        *maybe_duties = None;
+                    }
+                }
+            });
+        }
+
+        /// Remove a log that no longer takes signatures from the duties of its involved agents
+        fn clear_duties(log: &ConsensusLog<T>, log_id: &T::Hash) {
+            for agent in log.agents_involved.iter() {
+                Self::remove_duty(agent, log_id);
             }
-            
-            // Update CID index
-            LogsByCID::<T>::try_mutate(bounded_cid, |logs| -> DispatchResult {
-                logs.try_push(log_id.clone()).map_err(|_| Error::<T>::TooManyAgents)?;
+        }
+
+        /// Add a log to an agent's log index
+        fn index_log_by_agent(agent_id: &T::AccountId, log_id: T::Hash) -> DispatchResult {
+            LogsByAgent::<T>::try_mutate(agent_id, |logs| {
+                logs.try_push(log_id).map_err(|_| Error::<T>::AgentIndexFull.into())
+            })
+        }
+
+        /// Drop a log from the log index of every involved agent
+        fn unindex_log_by_agents(log: &ConsensusLog<T>, log_id: &T::Hash) {
+            for agent in log.agents_involved.iter() {
+                LogsByAgent::<T>::mutate_exists(agent, |maybe_logs| {
+                    if let Some(logs) = maybe_logs {
+                        logs.retain(|id| id != log_id);
+                        if logs.is_empty() {
+                            *maybe_logs = None;
+                        }
+                    }
+                });
+            }
+        }
+
+        /// Refund the deposit of a retracted or rejected log, burning `RetractionFee` of it,
+        /// and return the refunded amount and the fee
+        fn refund_log_deposit(log_id: &T::Hash) -> (BalanceOf<T>, BalanceOf<T>) {
+            match LogDeposits::<T>::take(log_id) {
+                Some((depositor, deposit)) => {
+                    let fee = T::RetractionFee::get().mul_ceil(deposit);
+                    // Dropping the slashed imbalance burns it
+                    let _ = T::Currency::slash_reserved(&depositor, fee);
+                    let refunded = deposit.saturating_sub(fee);
+                    T::Currency::unreserve(&depositor, refunded);
+                    (refunded, fee)
+                },
+                None => (Zero::zero(), Zero::zero()),
+            }
+        }
+
+        /// Add a log to a CID's log index and count it, applying `CidIndexOverflow` when the
+        /// index entry is full
+        fn index_log_by_cid(cid: &BoundedVec<u8, T::MaxCIDLength>, log_id: T::Hash) -> DispatchResult {
+            LogsByCID::<T>::try_mutate(cid, |logs| -> DispatchResult {
+                if logs.is_full() {
+                    ensure!(
+                        T::CidIndexOverflow::get() == CidIndexOverflow::EvictOldest,
+                        Error::<T>::CidIndexFull
+                    );
+                    let evicted = logs.remove(0);
+                    Self::deposit_event(Event::LogEvictedFromCidIndex {
+                        cid: cid.to_vec(),
+                        log_id: evicted,
+                    });
+                }
+                logs.try_push(log_id).map_err(|_| Error::<T>::CidIndexFull)?;
                 Ok(())
             })?;
-            
-            // Emit event
-            Self::deposit_event(Event::InsightSubmitted {
-                log_id,
-                agent_id,
-                agents_involved,
+            CidLogCount::<T>::mutate(cid, |count| *count = count.saturating_add(1));
+            Ok(())
+        }
+
+        /// Remove a log from a CID's log index and count
+        fn unindex_log_by_cid(cid: &BoundedVec<u8, T::MaxCIDLength>, log_id: &T::Hash) {
+            LogsByCID::<T>::mutate_exists(cid, |maybe_logs| {
+                if let Some(logs) = maybe_logs {
+                    logs.retain(|id| id != log_id);
+                    if logs.is_empty() {
+                        *maybe_logs = None;
+                    }
+                }
             });
-            
+            CidLogCount::<T>::mutate_exists(cid, |maybe_count| {
+                *maybe_count = maybe_count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0);
+            });
+        }
+
+        /// Fail with `FeatureNotEnabled` or `InsufficientReputationForUrgent` if the agent may not
+        /// submit logs of `priority`
+        fn ensure_can_submit_with(agent_id: &T::AccountId, priority: LogPriority) -> DispatchResult {
+            if priority != LogPriority::Normal {
+                Self::ensure_feature(agent_id, Feature::PrioritySubmission)?;
+            }
+            if priority == LogPriority::Urgent {
+                ensure!(
+                    T::SignerWeight::signer_weight(agent_id) >= T::UrgentReputation::get(),
+                    Error::<T>::InsufficientReputationForUrgent
+                );
+            }
             Ok(())
         }
-        
-        /// Sign an existing consensus log
+
+        /// Ensure the experimental `feature` is enabled for `agent_id`
+        fn ensure_feature(agent_id: &T::AccountId, feature: Feature) -> DispatchResult {
+            ensure!(T::Agents::has_feature(agent_id, feature), Error::<T>::FeatureNotEnabled);
+            Ok(())
+        }
+
+        /// Up to `limit` logs awaiting finalization, most urgent first and oldest first within
+        /// a priority
         ///
-        /// Parameters:
-        /// - `log_id`: The ID of the log to sign
-        /// - `signature`: Digital signature from the agent
-        #[pallet::call_index(2)]
-        #[pallet::weight(Weight::from_parts(8_000, 0).saturating_add(T::DbWeight::get().reads_writes(2, 1)))]
-        pub fn sign_log(
-            origin: OriginFor<T>,
+        /// Iterates `PendingByPriority` in full, so it is meant for RPCs and runtime APIs
+        /// rather than extrinsics.
+        pub fn pending_logs(limit: u32) -> Vec<(T::Hash, LogPriority)> {
+            let mut pending = Vec::new();
+            for priority in LogPriority::ALL {
+                let mut logs = PendingByPriority::<T>::iter_prefix(priority).collect::<Vec<_>>();
+                logs.sort_by_key(|(_, submitted_at)| *submitted_at);
+                pending.extend(logs.into_iter().map(|(log_id, _)| (log_id, priority)));
+                if pending.len() >= limit as usize {
+                    break;
+                }
+            }
+            pending.truncate(limit as usize);
+            pending
+        }
+
+        /// Reserve the log deposit from the submitter of a new log
+        fn reserve_log_deposit(submitter: &T::AccountId, log_id: &T::Hash) -> DispatchResult {
+            let deposit = T::LogDeposit::get();
+            if deposit.is_zero() {
+                return Ok(());
+            }
+            T::Currency::reserve(submitter, deposit).map_err(|_| Error::<T>::InsufficientDeposit)?;
+            LogDeposits::<T>::insert(log_id, (submitter.clone(), deposit));
+            Ok(())
+        }
+
+        /// The FROST public key of an agent: its account encoding, truncated or zero-padded
+        /// to 32 bytes
+        pub fn frost_key_of(agent_id: &T::AccountId) -> [u8; 32] {
+            csuite_primitives::frost_key(&agent_id.encode())
+        }
+
+        /// Weight of approving with `sign_log` on a log with `agents` involved agents and
+        /// `signatures` existing signatures, both of which are scanned, aside from verifying
+        /// the signature
+        pub fn sign_log_weight(agents: u32, signatures: u32) -> Weight {
+            Weight::from_parts(8_000, 0)
+                .saturating_add(Weight::from_parts(500, 0).saturating_mul(agents.saturating_add(signatures) as u64))
+                .saturating_add(T::DbWeight::get().reads_writes(8, 3))
+        }
+
+        /// Upper bound on the weight of approving with `sign_log`, verification included
+        pub fn max_sign_log_weight() -> Weight {
+            Self::sign_log_weight(T::MaxAgentsInvolved::get(), T::MaxSignatures::get())
+                .saturating_add(Self::max_verify_weight())
+        }
+
+        /// Weight of verifying one signature under `scheme`, as benchmarked per scheme
+        pub fn verify_weight(scheme: SignatureScheme) -> Weight {
+            match scheme {
+                SignatureScheme::Sr25519 => T::WeightInfo::verify_sr25519(),
+                SignatureScheme::Ed25519 => T::WeightInfo::verify_ed25519(),
+                SignatureScheme::Ecdsa => T::WeightInfo::verify_ecdsa(),
+            }
+        }
+
+        /// Weight of verifying one signature under the most expensive scheme
+        pub fn max_verify_weight() -> Weight {
+            SignatureScheme::ALL
+                .into_iter()
+                .map(Self::verify_weight)
+                .fold(Weight::zero(), |max, weight| max.max(weight))
+        }
+
+        /// What a submitter signs to submit an insight over `cid` with `nonce`
+        pub fn insight_payload(cid: &[u8], nonce: u64) -> Vec<u8> {
+            (b"csuite/insight", cid, nonce).encode()
+        }
+
+        /// What an involved agent signs to cast `vote` on `log_id` in amendment round `round`
+        pub fn vote_payload(log_id: &T::Hash, round: u32, vote: VoteKind) -> Vec<u8> {
+            (b"csuite/vote", log_id, round, vote).encode()
+        }
+
+        /// Check that `signer`, acting for `agent_id`, made `signature` over `message` under
+        /// the agent's scheme, returning the scheme
+        fn ensure_valid_signature(
+            agent_id: &T::AccountId,
+            signer: &T::AccountId,
+            message: &[u8],
+            signature: &[u8],
+        ) -> Result<SignatureScheme, DispatchError> {
+            let scheme = T::Agents::signature_scheme(agent_id);
+            ensure!(T::SignatureVerifier::verify(scheme, signer, message, signature), Error::<T>::InvalidSignature);
+            Ok(scheme)
+        }
+
+        /// Weight of abstaining or rejecting with `sign_log` on a log with `agents` involved
+        /// agents, whose votes are counted when it is a rejection
+        ///
+        /// A rejection reaching the veto threshold also clears the log from the agents' indexes
+        /// and refunds its deposit.
+        pub fn vote_weight(agents: u32) -> Weight {
+            Weight::from_parts(8_000, 0)
+                .saturating_add(Weight::from_parts(500, 0).saturating_mul(agents as u64))
+                .saturating_add(T::DbWeight::get().reads_writes(
+                    8u64.saturating_add((agents as u64).saturating_mul(3)),
+                    8u64.saturating_add((agents as u64).saturating_mul(2)),
+                ))
+        }
+
+        /// The agent a signature sent by `who` is for: the agent `who` is a signing proxy key
+        /// of, or `who` itself
+        pub fn signing_agent(who: T::AccountId) -> T::AccountId {
+            T::Agents::proxied_agent(&who, CallClass::Signing).unwrap_or(who)
+        }
+
+        /// Upper bound on the weight of `sign_logs_batch` with `items` signatures
+        pub fn sign_logs_batch_weight(items: u32) -> Weight {
+            T::DbWeight::get().reads(4).saturating_add(
+                Self::max_sign_log_weight().saturating_mul(items.min(T::MaxBatchSignatures::get()) as u64),
+            )
+        }
+
+        /// Add `agent_id`'s signature, made by `signer`, to a pending log, returning the weight
+        /// it took
+        ///
+        /// The caller checks that the agent is registered and online.
+        fn do_sign_log(
+            agent_id: &T::AccountId,
+            signer: &T::AccountId,
             log_id: T::Hash,
             signature: Vec<u8>,
-        ) -> DispatchResult {
-            let agent_id = ensure_signed(origin)?;
-            
-            // Ensure agent exists and is active
-            let agent = <agent_registry::Pallet<T>>::agents(&agent_id).ok_or(Error::<T>::AgentNotFound)?;
-            ensure!(agent.status == AgentStatus::Online, Error::<T>::AgentNotActive);
-            
+        ) -> Result<Weight, DispatchError> {
             // Validate signature
             ensure!(!signature.is_empty(), Error::<T>::InvalidSignature);
             let bounded_signature = BoundedVec::<u8, T::MaxSignatureLength>::try_from(signature)
                 .map_err(|_| Error::<T>::InvalidSignature)?;
-            
-            // Update the log with the new signature
-            Logs::<T>::try_mutate(&log_id, |maybe_log| -> DispatchResult {
+
+            ensure!(!FinalizedLogs::<T>::contains_key(&log_id), Error::<T>::AlreadyFinalized);
+            ensure!(!RejectedLogs::<T>::contains_key(&log_id), Error::<T>::LogRejected);
+            ensure!(!LogVotes::<T>::contains_key(&log_id, agent_id), Error::<T>::AlreadySigned);
+
+            // Update the log with the new signature, returning the work it took
+            let actual_weight = Logs::<T>::try_mutate(&log_id, |maybe_log| -> Result<Weight, DispatchError> {
                 let log = maybe_log.as_mut().ok_or(Error::<T>::LogNotFound)?;
+                let weight = Self::sign_log_weight(
+                    log.agents_involved.len() as u32,
+                    log.signatures.len() as u32,
+                );
                 
                 // Ensure agent is involved in this consensus
-                ensure!(log.agents_involved.contains(&agent_id), Error::<T>::AgentNotFound);
+                ensure!(log.agents_involved.contains(agent_id), Error::<T>::AgentNotFound);
                 
                 // Ensure agent hasn't already signed
-                ensure!(!log.signatures.iter().any(|s| s.agent_id == agent_id), Error::<T>::AlreadySigned);
+                ensure!(!log.signatures.iter().any(|s| s.agent_id == *agent_id), Error::<T>::AlreadySigned);
+
+                let payload = Self::vote_payload(&log_id, log.amendment_count, VoteKind::Approve);
+                let scheme = Self::ensure_valid_signature(agent_id, signer, &payload, &bounded_signature)?;
                 
                 // Add the signature
                 let sig_info = SignatureInfo {
                     agent_id: agent_id.clone(),
                     signature: bounded_signature,
+                    signed_at: <frame_system::Pallet<T>>::block_number(),
                 };
                 log.signatures.try_push(sig_info).map_err(|_| Error::<T>::SignatureListFull)?;
                 
-                Ok(())
+                Ok(weight.saturating_add(Self::verify_weight(scheme)))
             })?;
-            
+            FirstSignatures::<T>::mutate(&log_id, agent_id, |first| {
+                first.get_or_insert_with(<frame_system::Pallet<T>>::block_number);
+            });
+            Self::remove_duty(agent_id, &log_id);
+
+            T::OnLogActivity::on_log_signed(agent_id, &log_id);
+
             // Emit event
             Self::deposit_event(Event::LogSigned {
                 log_id,
-                agent_id,
+                agent_id: agent_id.clone(),
             });
-            
+
+            Ok(actual_weight)
+        }
+
+        /// Record `agent_id`'s abstention from or rejection of a pending log, signed by `signer`,
+        /// rejecting the log once its rejections reach `VetoThreshold`, and return the weight
+        /// it took
+        ///
+        /// The caller checks that the agent is registered and online.
+        fn do_vote(
+            agent_id: &T::AccountId,
+            signer: &T::AccountId,
+            log_id: T::Hash,
+            signature: Vec<u8>,
+            vote: VoteKind,
+        ) -> Result<Weight, DispatchError> {
+            ensure!(!signature.is_empty(), Error::<T>::InvalidSignature);
+            let signature = BoundedVec::<u8, T::MaxSignatureLength>::try_from(signature)
+                .map_err(|_| Error::<T>::InvalidSignature)?;
+
+            ensure!(!FinalizedLogs::<T>::contains_key(&log_id), Error::<T>::AlreadyFinalized);
+            ensure!(!RejectedLogs::<T>::contains_key(&log_id), Error::<T>::LogRejected);
+            let log = Logs::<T>::get(&log_id).ok_or(Error::<T>::LogNotFound)?;
+            ensure!(log.agents_involved.contains(agent_id), Error::<T>::AgentNotFound);
+            ensure!(
+                !log.signatures.iter().any(|s| s.agent_id == *agent_id) &&
+                    !LogVotes::<T>::contains_key(&log_id, agent_id),
+                Error::<T>::AlreadySigned
+            );
+            let payload = Self::vote_payload(&log_id, log.amendment_count, vote);
+            let scheme = Self::ensure_valid_signature(agent_id, signer, &payload, &signature)?;
+
+            let now = <frame_system::Pallet<T>>::block_number();
+            LogVotes::<T>::insert(&log_id, agent_id, LogVote { kind: vote, signature, cast_at: now });
+            Self::remove_duty(agent_id, &log_id);
+
+            // Emit event
+            Self::deposit_event(Event::VoteCast {
+                log_id,
+                agent_id: agent_id.clone(),
+                vote,
+            });
+
+            let involved = log.agents_involved.len() as u32;
+            if vote != VoteKind::Reject {
+                return Ok(Self::vote_weight(0).saturating_add(Self::verify_weight(scheme)));
+            }
+            let rejections = LogVotes::<T>::iter_prefix_values(&log_id)
+                .filter(|cast| cast.kind == VoteKind::Reject)
+                .count() as u32;
+            if Perbill::from_rational(rejections, involved.max(1)) >= T::VetoThreshold::get() {
+                RejectedLogs::<T>::insert(&log_id, now);
+                PendingByPriority::<T>::remove(log.priority, &log_id);
+                CidHistory::<T>::remove(&log_id);
+                Self::unindex_log_by_agents(&log, &log_id);
+                Self::clear_duties(&log, &log_id);
+                let (refunded, fee) = Self::refund_log_deposit(&log_id);
+
+                // Emit event
+                Self::deposit_event(Event::LogRejected { log_id, rejections, refunded, fee });
+            }
+
+            Ok(Self::vote_weight(involved).saturating_add(Self::verify_weight(scheme)))
+        }
+
+        /// Weight of `finalize_consensus_log` on a log with `agents` involved agents and
+        /// `signatures` individual signatures
+        ///
+        /// Each involved agent is matched against the signatures and checked for its standing
+        /// and signer weight, both of which may read storage. Dropping excluded signatures
+        /// rewrites the log.
+        pub fn finalize_weight(agents: u32, signatures: u32) -> Weight {
+            Weight::from_parts(10_000, 0)
+                .saturating_add(Weight::from_parts(500, 0).saturating_mul(
+                    (agents as u64).saturating_mul(signatures.max(1) as u64),
+                ))
+                .saturating_add(T::DbWeight::get().reads_writes(
+                    8u64.saturating_add((agents as u64).saturating_mul(4)),
+                    5u64.saturating_add(agents as u64),
+                ))
+                .saturating_add(Self::first_responders_weight())
+        }
+
+        /// The role quorum in effect for logs under `model`, if any
+        pub fn role_quorum_for(model: ConsensusModel) -> Option<RoleQuorum<T>> {
+            ModelRoleQuorumPolicies::<T>::get(model).or_else(RoleQuorumPolicy::<T>::get)
+        }
+
+        /// Whether `agents` satisfy the role quorum in effect for `model`
+        fn role_quorum_met(model: ConsensusModel, agents: &[T::AccountId]) -> bool {
+            Self::role_quorum_for(model).map_or(true, |quorum| quorum.is_met_by(agents))
+        }
+
+        /// Upper bound on the weight of rewarding a log's first responders
+        fn first_responders_weight() -> Weight {
+            T::OnFirstResponder::on_first_responder_weight().saturating_mul(T::FirstResponders::get() as u64)
+        }
+
+        /// The first `FirstResponders` signers of a log after its submitter, in signing order
+        ///
+        /// Only agents in `signers` count. Signatures are kept in the order they were made, with
+        /// `signed_at` breaking ties should that ever change.
+        fn first_responders(log: &ConsensusLog<T>, signers: &[T::AccountId]) -> Vec<T::AccountId> {
+            let mut signatures = log.signatures
+                .iter()
+                .filter(|sig| sig.agent_id != log.submitter && signers.contains(&sig.agent_id))
+                .collect::<Vec<_>>();
+            signatures.sort_by_key(|sig| sig.signed_at);
+            signatures
+                .into_iter()
+                .take(T::FirstResponders::get() as usize)
+                .map(|sig| sig.agent_id.clone())
+                .collect()
+        }
+
+        /// Whether `signers` are enough to finalize `log` under `model`
+        ///
+        /// `signers` must be distinct involved agents that signed the log individually or, under
+        /// `ThresholdSignature`, through the FROST group's aggregate signature.
+        fn quorum_reached(model: ConsensusModel, log: &ConsensusLog<T>, signers: &[T::AccountId]) -> bool {
+            let involved = log.agents_involved.len();
+            match model {
+                ConsensusModel::Unanimous => signers.len() == involved,
+                ConsensusModel::Majority => signers.len().saturating_mul(2) > involved,
+                ConsensusModel::ReputationWeighted => {
+                    let weight_of = |agents: &[T::AccountId]| {
+                        agents.iter().fold(0u128, |total, agent_id| {
+                            total.saturating_add(T::SignerWeight::signer_weight(agent_id) as u128)
+                        })
+                    };
+                    weight_of(signers).saturating_mul(2) > weight_of(&log.agents_involved)
+                },
+                ConsensusModel::ThresholdSignature => signers.len() as u32 >= Self::frost_threshold(),
+            }
+        }
+
+        /// Forget an agent's recent submissions, e.g. once it has retired
+        pub fn clear_recent_submissions(agent_id: &T::AccountId) {
+            RecentSubmissions::<T>::remove(agent_id);
+        }
+
+        /// Record a submission in the agent's dedup ring buffer
+        ///
+        /// Record that an agent skipped the sequence numbers `from` up to but excluding `to`
+        fn note_sequence_gap(agent_id: &T::AccountId, from: u64, to: u64) {
+            SequenceGaps::<T>::mutate(agent_id, |gaps| {
+                if gaps.is_full() && !gaps.is_empty() {
+                    gaps.remove(0);
+                }
+                let _ = gaps.try_push((from, to));
+            });
+
+            Self::deposit_event(Event::InsightSequenceGap { agent_id: agent_id.clone(), from, to });
+        }
+
+        /// Fails with `DuplicateSubmission` if the agent submitted the same CID and metadata
+        /// within `DedupWindow` blocks. Entries older than the window are pruned first; when the
+        /// buffer is still full the oldest entry is dropped.
+        fn note_submission(
+            agent_id: &T::AccountId,
+            cid: &BoundedVec<u8, T::MaxCIDLength>,
+            metadata: &Option<BoundedVec<u8, <T as Config>::MaxMetadataLength>>,
+        ) -> DispatchResult {
+            let window = T::DedupWindow::get();
+            if window.is_zero() {
+                return Ok(());
+            }
+
+            let now = <frame_system::Pallet<T>>::block_number();
+            let cid_hash = T::Hashing::hash(cid);
+            let metadata_hash = T::Hashing::hash_of(metadata);
+
+            RecentSubmissions::<T>::try_mutate(agent_id, |recent| -> DispatchResult {
+                recent.retain(|entry| now.saturating_sub(entry.submitted_at) < window);
+                ensure!(
+                    !recent.iter().any(|entry| entry.cid_hash == cid_hash && entry.metadata_hash == metadata_hash),
+                    Error::<T>::DuplicateSubmission
+                );
+
+                let entry = RecentSubmission { cid_hash, metadata_hash, submitted_at: now };
+                if recent.is_full() {
+                    recent.remove(0);
+                }
+                let _ = recent.try_push(entry);
+                Ok(())
+            })
+        }
+
+        /// Agents that signed off a finalized log, or `None` if the log is not finalized
+        ///
+        /// For aggregate sign-offs the signers are read from the bitmap against the participants
+        /// of the FROST group the log was finalized under.
+        pub fn finalized_signers(log_id: &T::Hash) -> Option<Vec<T::AccountId>> {
+            let finalized = FinalizedLogs::<T>::get(log_id)?;
+            let log = Logs::<T>::get(log_id)?;
+
+            let signers = match finalized.aggregate {
+                Some(aggregate) => {
+                    let (_, participants, _) = Self::frost_group_at(finalized.key_version)?;
+                    crate::aggregate::bitmap_indices(&aggregate.participant_bitmap)
+                        .into_iter()
+                        .filter_map(|i| participants.get(i).cloned())
+                        .collect()
+                },
+                None => log.signatures.into_iter().map(|sig| sig.agent_id).collect(),
+            };
+
+            Some(signers)
+        }
+
+        /// Whether `agent_id` had signed `log_id` by `block`
+        ///
+        /// Counts the agent's first signature even if an amendment cleared it since, and the
+        /// signers of a FROST aggregate from the block the log was finalized in.
+        pub fn signed_at(log_id: &T::Hash, agent_id: &T::AccountId, block: BlockNumberFor<T>) -> bool {
+            if let Some(first) = FirstSignatures::<T>::get(log_id, agent_id) {
+                return first <= block;
+            }
+
+            match FinalizedLogs::<T>::get(log_id) {
+                Some(finalized) if finalized.aggregate.is_some() && finalized.finalized_at <= block =>
+                    Self::finalized_signers(log_id).map_or(false, |signers| signers.contains(agent_id)),
+                _ => false,
+            }
+        }
+
+        /// Merkle proof that `signer` signed the finalized log `log_id`
+        ///
+        /// Returns `None` if the log is not finalized, `signer` is not among its signers, or its
+        /// signers can no longer be reconstructed as they were at finalization.
+        pub fn signature_inclusion_proof(
+            log_id: &T::Hash,
+            signer: &T::AccountId,
+        ) -> Option<MerkleInclusionProof> {
+            let root = SignatureRoots::<T>::get(log_id)?;
+            let log = Logs::<T>::get(log_id)?;
+            let mut signers = Self::finalized_signers(log_id)?;
+            signers.sort();
+
+            let index = signers.iter().position(|s| s == signer)?;
+            let leaves = Self::signature_leaves(&log, signers);
+            let proof = csuite_primitives::merkle_inclusion_proof(&leaves, index)?;
+
+            // Aggregate signers are re-derived from the FROST group of the log's key version,
+            // which governance could have rewritten before key versions were recorded
+            (proof.root == root).then_some(proof)
+        }
+
+        /// Key, participants and threshold of the FROST group with key `version`, or `None` if
+        /// no such group is known
+        pub fn frost_group_at(
+            version: GroupKeyVersion,
+        ) -> Option<([u8; 32], BoundedVec<T::AccountId, T::MaxAgentsInvolved>, u32)> {
+            if version == Self::group_key_version() {
+                let group_key = Self::frost_group_key()?;
+                return Some((group_key, Self::frost_participants(), Self::frost_threshold()));
+            }
+            RetiredFrostGroups::<T>::get(version)
+                .map(|group| (group.group_key, group.participants, group.threshold))
+        }
+
+        /// Participants of the FROST group a signing session was opened under
+        ///
+        /// Empty if that group was retired and its record dropped since.
+        fn session_participants(session: &SigningSession<T>) -> BoundedVec<T::AccountId, T::MaxAgentsInvolved> {
+            if session.key_version == Self::group_key_version() {
+                return Self::frost_participants();
+            }
+            RetiredFrostGroups::<T>::get(session.key_version)
+                .map(|group| group.participants)
+                .unwrap_or_default()
+        }
+
+        /// Whether the aggregate signature of a finalized log verifies against the FROST group
+        /// it was finalized under
+        ///
+        /// Returns `false` for logs that are not finalized or were signed off individually.
+        pub fn verify_finalized_aggregate(log_id: &T::Hash) -> bool {
+            let Some(FinalizedLog { aggregate: Some(aggregate), key_version, .. }) =
+                FinalizedLogs::<T>::get(log_id)
+            else {
+                return false;
+            };
+            let Some((group_key, participants, threshold)) = Self::frost_group_at(key_version) else {
+                return false;
+            };
+            let participant_keys: Vec<[u8; 32]> = participants.iter().map(Self::frost_key_of).collect();
+
+            FrostAggregator::<DefaultFrostConfig>::new()
+                .verify_for_group(log_id.as_ref(), &aggregate, &group_key, &participant_keys, threshold)
+                .unwrap_or(false)
+        }
+
+        /// Whether an agent is in good standing to sign logs and hold a share of the FROST
+        /// group key: registered, not retiring and allowed by the participation filter
+        fn may_participate(agent_id: &T::AccountId) -> bool {
+            T::Agents::is_registered(agent_id)
+                && !T::Agents::is_retiring(agent_id)
+                && T::ParticipationFilter::can_participate(agent_id)
+        }
+
+        /// Current FROST participants that are no longer registered, are retiring or may not
+        /// participate, and should be dropped by the next rotation
+        pub fn ineligible_frost_participants() -> Vec<T::AccountId> {
+            Self::frost_participants()
+                .into_iter()
+                .filter(|agent_id| !Self::may_participate(agent_id))
+                .collect()
+        }
+
+        /// Merkle leaves committing to `signers` of `log`, ordered by account
+        ///
+        /// Each leaf carries the signer's individual signature, or an empty one if it only took
+        /// part in an aggregate signature.
+        fn signature_leaves(log: &ConsensusLog<T>, mut signers: Vec<T::AccountId>) -> Vec<Vec<u8>> {
+            signers.sort();
+            signers
+                .iter()
+                .map(|signer| {
+                    let signature = log
+                        .signatures
+                        .iter()
+                        .find(|s| &s.agent_id == signer)
+                        .map(|s| s.signature.to_vec())
+                        .unwrap_or_default();
+                    csuite_primitives::signature_leaf(signer, &signature)
+                })
+                .collect()
+        }
+
+        /// Agents the session is still waiting on in its current phase
+        ///
+        /// During commitment collection these are the participants of the FROST group the
+        /// session was opened under without a commitment; during share collection, committed
+        /// agents without a signature share.
+        pub fn pending_participants(session: &SigningSession<T>) -> Vec<T::AccountId> {
+            match session.phase {
+                SigningPhase::CommitmentCollection => Self::session_participants(session)
+                    .into_iter()
+                    .filter(|agent_id| !session.commitments.iter().any(|(who, _)| who == agent_id))
+                    .collect(),
+                SigningPhase::ShareCollection => session.commitments.iter()
+                    .filter(|(agent_id, _)| !session.shares.iter().any(|(who, _)| who == agent_id))
+                    .map(|(agent_id, _)| agent_id.clone())
+                    .collect(),
+                SigningPhase::Complete | SigningPhase::Expired => Vec::new(),
+            }
+        }
+
+        /// Verify an aggregate signature over a log, returning the agents it signs for
+        fn verify_aggregate_sign_off(
+            log_id: &T::Hash,
+            log: &ConsensusLog<T>,
+            aggregate: &AggregateSignature,
+        ) -> Result<Vec<T::AccountId>, DispatchError> {
+            let group_key = Self::frost_group_key().ok_or(Error::<T>::FrostGroupNotConfigured)?;
+            let participants = Self::frost_participants();
+            let participant_keys: Vec<[u8; 32]> = participants.iter().map(Self::frost_key_of).collect();
+
+            let valid = FrostAggregator::<DefaultFrostConfig>::new().verify_for_group(
+                log_id.as_ref(),
+                aggregate,
+                &group_key,
+                &participant_keys,
+                Self::frost_threshold(),
+            ).map_err(|_| Error::<T>::InvalidAggregateSignature)?;
+            ensure!(valid, Error::<T>::InvalidAggregateSignature);
+
+            // Every agent marked in the bitmap must have been involved in this consensus
+            let signers: Vec<T::AccountId> = crate::aggregate::bitmap_indices(&aggregate.participant_bitmap)
+                .into_iter()
+                .map(|i| participants[i].clone())
+                .collect();
+            ensure!(
+                signers.iter().all(|s| log.agents_involved.contains(s)),
+                Error::<T>::SignerNotInvolved
+            );
+
+            Ok(signers)
+        }
+
+        /// Schedule the check of an insight's required co-signers, the priority-scaled
+        /// `RequiredSignerPeriod` from now
+        fn schedule_required_signer_check(log_id: T::Hash, priority: LogPriority) -> DispatchResult {
+            let period = priority.scale_period(T::RequiredSignerPeriod::get());
+            let deadline = <frame_system::Pallet<T>>::block_number().saturating_add(period.max(1u32.into()));
+            RequiredSignerChecks::<T>::try_mutate(deadline, |ids| ids.try_push(log_id))
+                .map_err(|_| Error::<T>::TooManyRequiredSignerChecks.into())
+        }
+
+        /// Report the required co-signers that have neither signed nor voted on a still pending
+        /// insight
+        fn check_required_signers(log_id: &T::Hash) -> Weight {
+            let mut weight = T::DbWeight::get().reads(3);
+            if FinalizedLogs::<T>::contains_key(log_id) || RejectedLogs::<T>::contains_key(log_id) {
+                return weight;
+            }
+            let Some(log) = Logs::<T>::get(log_id) else { return weight };
+            weight = weight.saturating_add(T::DbWeight::get().reads(1));
+
+            for agent_id in RequiredSigners::<T>::get(log_id).iter() {
+                // Abstaining or rejecting answers the log as well
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+                if !log.signatures.iter().any(|s| &s.agent_id == agent_id) &&
+                    !LogVotes::<T>::contains_key(log_id, agent_id)
+                {
+                    weight = weight.saturating_add(T::SigningFaultReporter::report_missing_signature(agent_id));
+                    Self::deposit_event(Event::RequiredSignerMissed {
+                        log_id: *log_id,
+                        agent_id: agent_id.clone(),
+                    });
+                }
+            }
+
+            weight
+        }
+
+        /// Schedule the end of a session phase `period` blocks from now
+        fn schedule_phase_end(
+            session_id: SessionId,
+            period: BlockNumberFor<T>,
+        ) -> Result<BlockNumberFor<T>, DispatchError> {
+            let deadline = <frame_system::Pallet<T>>::block_number()
+                .saturating_add(period.max(1u32.into()));
+            SessionDeadlines::<T>::try_mutate(deadline, |ids| ids.try_push(session_id))
+                .map_err(|_| Error::<T>::TooManySessions)?;
+            Ok(deadline)
+        }
+
+        /// Move a session from commitment collection to share collection
+        fn start_share_collection(
+            session_id: SessionId,
+            session: &mut SigningSession<T>,
+        ) -> DispatchResult {
+            let priority = Logs::<T>::get(&session.log_id).map(|log| log.priority).unwrap_or_default();
+            let deadline = Self::schedule_phase_end(session_id, priority.scale_period(T::SharePeriod::get()))?;
+            session.phase = SigningPhase::ShareCollection;
+            session.phase_deadline = deadline;
+
+            Self::deposit_event(Event::ShareCollectionStarted {
+                session_id,
+                deadline,
+            });
+
+            Ok(())
+        }
+
+        /// The binding signature shares in `session_id` must be produced over
+        ///
+        /// Ties a share to the session, the log being signed and the log's amendment round, so
+        /// it cannot be replayed into another session or over amended content.
+        pub fn share_binding(session_id: SessionId) -> Option<[u8; 32]> {
+            let session = SigningSessions::<T>::get(session_id)?;
+            let log = Logs::<T>::get(&session.log_id)?;
+            Some(SigningContext::new(session_id, session.log_id.as_ref(), log.amendment_count).binding())
+        }
+
+        /// Mark a session complete and release its log for future sessions
+        fn complete_session(session_id: SessionId, mut session: SigningSession<T>) {
+            session.phase = SigningPhase::Complete;
+            SessionOfLog::<T>::remove(&session.log_id);
+
+            Self::deposit_event(Event::SigningSessionCompleted {
+                session_id,
+                log_id: session.log_id,
+            });
+            Self::store_ended_session(session_id, session);
+        }
+
+        /// Mark a session expired and release its log for future sessions
+        fn expire_session(session_id: SessionId, mut session: SigningSession<T>) {
+            let phase = session.phase;
+            session.phase = SigningPhase::Expired;
+            SessionOfLog::<T>::remove(&session.log_id);
+
+            Self::deposit_event(Event::SigningSessionExpired {
+                session_id,
+                log_id: session.log_id,
+                phase,
+            });
+            Self::store_ended_session(session_id, session);
+        }
+
+        /// Refund the deposit of a session that ended and keep the session until
+        /// `SessionRetention` blocks from now, when it is removed
+        ///
+        /// A session whose removal cannot be scheduled, because too many sessions are due in
+        /// that block already, is removed right away.
+        fn store_ended_session(session_id: SessionId, mut session: SigningSession<T>) {
+            if let Some((depositor, deposit)) = SessionDeposits::<T>::take(session_id) {
+                T::Currency::unreserve(&depositor, deposit);
+            }
+
+            match Self::schedule_phase_end(session_id, T::SessionRetention::get()) {
+                Ok(removal) => {
+                    session.phase_deadline = removal;
+                    SigningSessions::<T>::insert(session_id, session);
+                },
+                Err(_) => SigningSessions::<T>::remove(session_id),
+            }
+        }
+
+        /// Handle a session whose current phase ends at `now`
+        fn on_session_deadline(session_id: SessionId, now: BlockNumberFor<T>) -> Weight {
+            let mut weight = T::DbWeight::get().reads(1);
+            let Some(mut session) = SigningSessions::<T>::get(session_id) else { return weight };
+
+            // The session already moved on before this deadline
+            if session.phase_deadline != now {
+                return weight;
+            }
+
+            match session.phase {
+                SigningPhase::CommitmentCollection => {
+                    let enough = session.commitments.len() >= session.threshold as usize;
+                    if enough && Self::start_share_collection(session_id, &mut session).is_ok() {
+                        SigningSessions::<T>::insert(session_id, session);
+                    } else {
+                        Self::expire_session(session_id, session);
+                    }
+                },
+                SigningPhase::ShareCollection => {
+                    // Committed agents that never delivered a share stall the whole group
+                    for (agent_id, _) in session.commitments.iter() {
+                        if !session.shares.iter().any(|(who, _)| who == agent_id) {
+                            weight = weight.saturating_add(T::SigningFaultReporter::report_missing_share(agent_id));
+                            Self::deposit_event(Event::SignatureShareMissing {
+                                session_id,
+                                agent_id: agent_id.clone(),
+                            });
+                        }
+                    }
+
+                    if session.shares.len() >= session.threshold as usize {
+                        Self::complete_session(session_id, session);
+                    } else {
+                        Self::expire_session(session_id, session);
+                    }
+                },
+                // The session ended `SessionRetention` blocks ago
+                SigningPhase::Complete | SigningPhase::Expired => {
+                    SigningSessions::<T>::remove(session_id);
+                    return weight.saturating_add(T::DbWeight::get().writes(1));
+                },
+            }
+
+            weight.saturating_add(T::DbWeight::get().reads_writes(4, 5))
+        }
+
+        /// Commitment of `agent_id` in `round_id` to an insight over `cid`, hiding the CID behind
+        /// `salt` until it is revealed
+        ///
+        /// Binding the round and the agent keeps a participant from reusing another one's
+        /// commitment and revealing its insight once it is public.
+        pub fn insight_commitment(round_id: RoundId, agent_id: &T::AccountId, cid: &[u8], salt: &[u8; 32]) -> T::Hash {
+            T::Hashing::hash_of(&(b"csuite/insight-commitment", round_id, agent_id, cid, salt))
+        }
+
+        /// Participants of a blinded insight round that still have to act in its current phase
+        ///
+        /// During the commitment phase these are participants without a commitment; during the
+        /// reveal phase, committed participants that have not revealed yet.
+        pub fn pending_round_participants(round: &InsightRound<T>) -> Vec<T::AccountId> {
+            match round.phase {
+                RoundPhase::Commit => round.participants.iter()
+                    .filter(|agent_id| !round.commitments.iter().any(|(who, _)| who == *agent_id))
+                    .cloned()
+                    .collect(),
+                RoundPhase::Reveal => round.commitments.iter().map(|(agent_id, _)| agent_id.clone()).collect(),
+                RoundPhase::Closed => Vec::new(),
+            }
+        }
+
+        /// Schedule the end of a blinded insight round phase `period` blocks from now
+        fn schedule_round_phase_end(
+            round_id: RoundId,
+            period: BlockNumberFor<T>,
+        ) -> Result<BlockNumberFor<T>, DispatchError> {
+            let deadline = <frame_system::Pallet<T>>::block_number()
+                .saturating_add(period.max(1u32.into()));
+            RoundDeadlines::<T>::try_mutate(deadline, |ids| ids.try_push(round_id))
+                .map_err(|_| Error::<T>::TooManyRounds)?;
+            Ok(deadline)
+        }
+
+        /// Move a blinded insight round from collecting commitments to collecting reveals
+        fn start_reveal(round_id: RoundId, round: &mut InsightRound<T>) -> DispatchResult {
+            let deadline = Self::schedule_round_phase_end(round_id, round.priority.scale_period(T::InsightRevealPeriod::get()))?;
+            round.phase = RoundPhase::Reveal;
+            round.phase_deadline = deadline;
+
+            Self::deposit_event(Event::InsightRevealStarted {
+                round_id,
+                deadline,
+            });
+
             Ok(())
         }
+
+        /// Mark a blinded insight round closed
+        fn close_round(round_id: RoundId, round: &mut InsightRound<T>) {
+            round.phase = RoundPhase::Closed;
+
+            Self::deposit_event(Event::InsightRoundClosed {
+                round_id,
+                revealed: round.revealed.len() as u32,
+            });
+        }
+
+        /// Handle a blinded insight round whose current phase ends at `now`
+        fn on_round_deadline(round_id: RoundId, now: BlockNumberFor<T>) -> Weight {
+            let mut weight = T::DbWeight::get().reads(1);
+            let Some(mut round) = InsightRounds::<T>::get(round_id) else { return weight };
+
+            // The round already moved on before this deadline
+            if round.phase_deadline != now {
+                return weight;
+            }
+
+            match round.phase {
+                RoundPhase::Commit => {
+                    // Participants that did not commit in time sit the round out
+                    if round.commitments.is_empty() || Self::start_reveal(round_id, &mut round).is_err() {
+                        Self::close_round(round_id, &mut round);
+                    }
+                },
+                RoundPhase::Reveal => {
+                    // Committed agents that never revealed may have been waiting to copy others
+                    for (agent_id, _) in round.commitments.iter() {
+                        weight = weight.saturating_add(T::SigningFaultReporter::report_missed_reveal(agent_id));
+                        Self::deposit_event(Event::InsightRevealMissed {
+                            round_id,
+                            agent_id: agent_id.clone(),
+                        });
+                    }
+                    round.commitments.clear();
+                    Self::close_round(round_id, &mut round);
+                },
+                RoundPhase::Closed => return weight,
+            }
+
+            InsightRounds::<T>::insert(round_id, round);
+            weight.saturating_add(T::DbWeight::get().reads_writes(1, 2))
+        }
+
+        /// Derive the ID of a log from its submitter, the submitter's nonce and its content
+        pub fn derive_log_id(
+            agent_id: &T::AccountId,
+            nonce: u64,
+            log: &ConsensusLog<T>,
+        ) -> T::Hash {
+            T::Hashing::hash_of(&(agent_id, nonce, log))
+        }
+    }
+
+    impl<T: Config> ConsensusParameters for Pallet<T> {
+        fn consensus_thresholds() -> ConsensusThresholds {
+            ConsensusThresholds {
+                frost_threshold: FrostThreshold::<T>::get(),
+                frost_group_version: FrostGroupKeyVersion::<T>::get(),
+                veto_threshold: T::VetoThreshold::get(),
+                first_responders: T::FirstResponders::get(),
+            }
+        }
     }
-} 
\ No newline at end of file
+}
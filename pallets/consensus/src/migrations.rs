@@ -0,0 +1,882 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        migrations.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Storage migrations for the consensus log pallet
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! Storage migrations for pallet_consensus_log
+
+pub mod v1 {
+    //! Records the submitter and amendment count of every log. Logs from `submit_consensus_log`
+    //! only involve their submitter; insights always start with the submitter's signature, so
+    //! the submitter can be recovered from either.
+
+    use crate::{Config, Pallet};
+    use codec::{Decode, Encode};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        pallet_prelude::{BoundedVec, OptionQuery},
+        storage_alias,
+        traits::{Get, UncheckedOnRuntimeUpgrade},
+        weights::Weight,
+        Blake2_128Concat,
+    };
+    use frame_system::pallet_prelude::BlockNumberFor;
+    #[cfg(feature = "try-runtime")]
+    use sp_std::vec::Vec;
+
+    /// Signature layout before signing blocks were recorded
+    #[derive(Decode, Encode)]
+    pub struct SignatureInfoV0<T: Config> {
+        pub agent_id: T::AccountId,
+        pub signature: BoundedVec<u8, T::MaxSignatureLength>,
+    }
+
+    /// Log layout before amendments
+    #[derive(Decode, Encode)]
+    pub struct OldConsensusLog<T: Config> {
+        pub timestamp: BlockNumberFor<T>,
+        pub cid: BoundedVec<u8, T::MaxCIDLength>,
+        pub agents_involved: BoundedVec<T::AccountId, T::MaxAgentsInvolved>,
+        pub signatures: BoundedVec<SignatureInfoV0<T>, T::MaxSignatures>,
+        pub metadata: Option<BoundedVec<u8, <T as Config>::MaxMetadataLength>>,
+    }
+
+    /// Log layout at storage version 1
+    #[derive(Decode, Encode)]
+    pub struct ConsensusLogV1<T: Config> {
+        pub submitter: T::AccountId,
+        pub timestamp: BlockNumberFor<T>,
+        pub cid: BoundedVec<u8, T::MaxCIDLength>,
+        pub agents_involved: BoundedVec<T::AccountId, T::MaxAgentsInvolved>,
+        pub signatures: BoundedVec<SignatureInfoV0<T>, T::MaxSignatures>,
+        pub metadata: Option<BoundedVec<u8, <T as Config>::MaxMetadataLength>>,
+        pub amendment_count: u32,
+    }
+
+    /// `Logs` as laid out at storage version 1
+    #[storage_alias]
+    pub type Logs<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as frame_system::Config>::Hash,
+        ConsensusLogV1<T>,
+        OptionQuery,
+    >;
+
+    /// Actual implementation of [`MigrateV0ToV1`]
+    pub struct UncheckedMigrateV0ToV1<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            Logs::<T>::translate::<OldConsensusLog<T>, _>(|_, old| {
+                translated = translated.saturating_add(1);
+                let submitter = if old.agents_involved.len() > 1 {
+                    old.signatures.first().map(|sig| sig.agent_id.clone())
+                } else {
+                    old.agents_involved.first().cloned()
+                }?;
+
+                Some(ConsensusLogV1 {
+                    submitter,
+                    timestamp: old.timestamp,
+                    cid: old.cid,
+                    agents_involved: old.agents_involved,
+                    signatures: old.signatures,
+                    metadata: old.metadata,
+                    amendment_count: 0,
+                })
+            });
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+            Ok((Logs::<T>::iter_keys().count() as u64).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            let expected = u64::decode(&mut &state[..])
+                .map_err(|_| "ConsensusLog: failed to decode the pre-upgrade log count")?;
+            frame_support::ensure!(
+                Logs::<T>::iter_values().count() as u64 == expected,
+                "ConsensusLog: logs were lost while recording submitters"
+            );
+            Ok(())
+        }
+    }
+
+    /// Add the submitter and amendment count to every existing log, bumping the storage
+    /// version to 1
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        UncheckedMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v2 {
+    //! Records the consensus model of every log. Logs that were finalized with an aggregate
+    //! signature or have a signing session open are threshold-signature logs; all others keep
+    //! finalizing unanimously.
+
+    use super::v1::{ConsensusLogV1, SignatureInfoV0};
+    use crate::{Config, ConsensusModel, FinalizedLogs, Pallet, SessionOfLog};
+    use codec::{Decode, Encode};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        pallet_prelude::{BoundedVec, OptionQuery},
+        storage_alias,
+        traits::{Get, UncheckedOnRuntimeUpgrade},
+        weights::Weight,
+        Blake2_128Concat,
+    };
+    use frame_system::pallet_prelude::BlockNumberFor;
+    #[cfg(feature = "try-runtime")]
+    use sp_std::vec::Vec;
+
+    /// Log layout at storage version 2
+    #[derive(Decode, Encode)]
+    pub struct ConsensusLogV2<T: Config> {
+        pub submitter: T::AccountId,
+        pub timestamp: BlockNumberFor<T>,
+        pub cid: BoundedVec<u8, T::MaxCIDLength>,
+        pub agents_involved: BoundedVec<T::AccountId, T::MaxAgentsInvolved>,
+        pub signatures: BoundedVec<SignatureInfoV0<T>, T::MaxSignatures>,
+        pub metadata: Option<BoundedVec<u8, <T as Config>::MaxMetadataLength>>,
+        pub amendment_count: u32,
+        pub model: ConsensusModel,
+    }
+
+    /// `Logs` as laid out at storage version 2
+    #[storage_alias]
+    pub type Logs<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as frame_system::Config>::Hash,
+        ConsensusLogV2<T>,
+        OptionQuery,
+    >;
+
+    /// Actual implementation of [`MigrateV1ToV2`]
+    pub struct UncheckedMigrateV1ToV2<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV1ToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            Logs::<T>::translate::<ConsensusLogV1<T>, _>(|log_id, old| {
+                translated = translated.saturating_add(1);
+                let aggregated = FinalizedLogs::<T>::get(&log_id).map_or(false, |f| f.aggregate.is_some());
+                let model = if aggregated || SessionOfLog::<T>::contains_key(&log_id) {
+                    ConsensusModel::ThresholdSignature
+                } else {
+                    ConsensusModel::Unanimous
+                };
+
+                Some(ConsensusLogV2 {
+                    submitter: old.submitter,
+                    timestamp: old.timestamp,
+                    cid: old.cid,
+                    agents_involved: old.agents_involved,
+                    signatures: old.signatures,
+                    metadata: old.metadata,
+                    amendment_count: old.amendment_count,
+                    model,
+                })
+            });
+
+            T::DbWeight::get().reads_writes(translated.saturating_mul(3), translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+            Ok((Logs::<T>::iter_keys().count() as u64).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            let expected = u64::decode(&mut &state[..])
+                .map_err(|_| "ConsensusLog: failed to decode the pre-upgrade log count")?;
+            frame_support::ensure!(
+                Logs::<T>::iter_values().count() as u64 == expected,
+                "ConsensusLog: logs were lost while recording consensus models"
+            );
+            Ok(())
+        }
+    }
+
+    /// Add the consensus model to every existing log, bumping the storage version to 2
+    pub type MigrateV1ToV2<T> = VersionedMigration<
+        1,
+        2,
+        UncheckedMigrateV1ToV2<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v3 {
+    //! Records the block every signature was made in. The signing blocks of existing signatures
+    //! are unknown, so they are set to the block the log was submitted in; their order is kept.
+
+    use super::v2::ConsensusLogV2;
+    use crate::{Config, ConsensusModel, Pallet, SignatureInfo};
+    use codec::{Decode, Encode};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        pallet_prelude::{BoundedVec, OptionQuery},
+        storage_alias,
+        traits::{Get, UncheckedOnRuntimeUpgrade},
+        weights::Weight,
+        Blake2_128Concat,
+    };
+    use frame_system::pallet_prelude::BlockNumberFor;
+    #[cfg(feature = "try-runtime")]
+    use sp_std::vec::Vec;
+
+    /// Log layout at storage versions 3 and 4
+    #[derive(Decode, Encode)]
+    pub struct ConsensusLogV3<T: Config> {
+        pub submitter: T::AccountId,
+        pub timestamp: BlockNumberFor<T>,
+        pub cid: BoundedVec<u8, T::MaxCIDLength>,
+        pub agents_involved: BoundedVec<T::AccountId, T::MaxAgentsInvolved>,
+        pub signatures: BoundedVec<SignatureInfo<T>, T::MaxSignatures>,
+        pub metadata: Option<BoundedVec<u8, <T as Config>::MaxMetadataLength>>,
+        pub amendment_count: u32,
+        pub model: ConsensusModel,
+    }
+
+    /// `Logs` as laid out at storage versions 3 and 4
+    #[storage_alias]
+    pub type Logs<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as frame_system::Config>::Hash,
+        ConsensusLogV3<T>,
+        OptionQuery,
+    >;
+
+    /// Actual implementation of [`MigrateV2ToV3`]
+    pub struct UncheckedMigrateV2ToV3<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV2ToV3<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            Logs::<T>::translate::<ConsensusLogV2<T>, _>(|_, old| {
+                translated = translated.saturating_add(1);
+                let signatures = old.signatures
+                    .into_iter()
+                    .map(|sig| SignatureInfo {
+                        agent_id: sig.agent_id,
+                        signature: sig.signature,
+                        signed_at: old.timestamp,
+                    })
+                    .collect::<sp_std::vec::Vec<_>>();
+
+                Some(ConsensusLogV3 {
+                    submitter: old.submitter,
+                    timestamp: old.timestamp,
+                    cid: old.cid,
+                    agents_involved: old.agents_involved,
+                    signatures: BoundedVec::truncate_from(signatures),
+                    metadata: old.metadata,
+                    amendment_count: old.amendment_count,
+                    model: old.model,
+                })
+            });
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+            Ok((Logs::<T>::iter_keys().count() as u64).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            let expected = u64::decode(&mut &state[..])
+                .map_err(|_| "ConsensusLog: failed to decode the pre-upgrade log count")?;
+            frame_support::ensure!(
+                Logs::<T>::iter_values().count() as u64 == expected,
+                "ConsensusLog: logs were lost while recording signing blocks"
+            );
+            Ok(())
+        }
+    }
+
+    /// Add the signing block to every existing signature, bumping the storage version to 3
+    pub type MigrateV2ToV3<T> = VersionedMigration<
+        2,
+        3,
+        UncheckedMigrateV2ToV3<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v4 {
+    //! Counts the logs stored under every CID. No log has been evicted from `LogsByCID` yet, so
+    //! each count is the length of the CID's index entry.
+
+    use crate::{CidLogCount, Config, LogsByCID, Pallet};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        traits::{Get, UncheckedOnRuntimeUpgrade},
+        weights::Weight,
+    };
+    #[cfg(feature = "try-runtime")]
+    use codec::{Decode, Encode};
+    #[cfg(feature = "try-runtime")]
+    use sp_std::vec::Vec;
+
+    /// Actual implementation of [`MigrateV3ToV4`]
+    pub struct UncheckedMigrateV3ToV4<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV3ToV4<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut counted = 0u64;
+            for (cid, logs) in LogsByCID::<T>::iter() {
+                counted = counted.saturating_add(1);
+                CidLogCount::<T>::insert(cid, logs.len() as u32);
+            }
+
+            T::DbWeight::get().reads_writes(counted, counted)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+            let indexed: u64 = LogsByCID::<T>::iter_values().map(|logs| logs.len() as u64).sum();
+            Ok(indexed.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            let expected = u64::decode(&mut &state[..])
+                .map_err(|_| "ConsensusLog: failed to decode the pre-upgrade index size")?;
+            let counted: u64 = CidLogCount::<T>::iter_values().map(u64::from).sum();
+            frame_support::ensure!(
+                counted == expected,
+                "ConsensusLog: CID log counts do not match the CID index"
+            );
+            Ok(())
+        }
+    }
+
+    /// Count the logs of every CID, bumping the storage version to 4
+    pub type MigrateV3ToV4<T> = VersionedMigration<
+        3,
+        4,
+        UncheckedMigrateV3ToV4<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v5 {
+    //! Records the priority of every log and queues the logs awaiting finalization. Existing
+    //! logs were submitted before priorities existed, so they all become `Normal`.
+
+    use super::v3::ConsensusLogV3;
+    use crate::{Config, ConsensusLog, FinalizedLogs, LogPriority, Logs, Pallet, PendingByPriority};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        traits::{Get, UncheckedOnRuntimeUpgrade},
+        weights::Weight,
+    };
+    #[cfg(feature = "try-runtime")]
+    use codec::{Decode, Encode};
+    #[cfg(feature = "try-runtime")]
+    use sp_std::vec::Vec;
+
+    /// Actual implementation of [`MigrateV4ToV5`]
+    pub struct UncheckedMigrateV4ToV5<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV4ToV5<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            let mut queued = 0u64;
+            Logs::<T>::translate::<ConsensusLogV3<T>, _>(|log_id, old| {
+                translated = translated.saturating_add(1);
+                if !FinalizedLogs::<T>::contains_key(&log_id) {
+                    queued = queued.saturating_add(1);
+                    PendingByPriority::<T>::insert(LogPriority::Normal, &log_id, old.timestamp);
+                }
+
+                Some(ConsensusLog {
+                    submitter: old.submitter,
+                    timestamp: old.timestamp,
+                    cid: old.cid,
+                    agents_involved: old.agents_involved,
+                    signatures: old.signatures,
+                    metadata: old.metadata,
+                    amendment_count: old.amendment_count,
+                    model: old.model,
+                    priority: LogPriority::Normal,
+                })
+            });
+
+            T::DbWeight::get().reads_writes(
+                translated.saturating_mul(2),
+                translated.saturating_add(queued),
+            )
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+            let pending = Logs::<T>::iter_keys()
+                .filter(|log_id| !FinalizedLogs::<T>::contains_key(log_id))
+                .count() as u64;
+            Ok((Logs::<T>::iter_keys().count() as u64, pending).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            let (expected, pending) = <(u64, u64)>::decode(&mut &state[..])
+                .map_err(|_| "ConsensusLog: failed to decode the pre-upgrade log counts")?;
+            frame_support::ensure!(
+                Logs::<T>::iter_values().count() as u64 == expected,
+                "ConsensusLog: logs were lost while recording priorities"
+            );
+            frame_support::ensure!(
+                PendingByPriority::<T>::iter_prefix(LogPriority::Normal).count() as u64 == pending,
+                "ConsensusLog: pending logs were not all queued by priority"
+            );
+            Ok(())
+        }
+    }
+
+    /// Add the priority to every existing log and queue pending logs, bumping the storage
+    /// version to 5
+    pub type MigrateV4ToV5<T> = VersionedMigration<
+        4,
+        5,
+        UncheckedMigrateV4ToV5<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v6 {
+    //! Moves `SigningSessions` and `SessionDeadlines` from `Blake2_128Concat` to `Twox64Concat`.
+    //! Session IDs and block numbers are assigned by the chain, so they cannot be chosen to
+    //! unbalance the trie, and every key in a storage proof shrinks by 8 bytes.
+
+    use crate::{Config, Pallet, SessionDeadlines, SessionId, SigningPhase};
+    use codec::{Decode, Encode};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        pallet_prelude::{BoundedVec, OptionQuery},
+        storage_alias,
+        traits::{Get, UncheckedOnRuntimeUpgrade},
+        weights::Weight,
+        Twox64Concat,
+    };
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use sp_std::vec::Vec;
+
+    /// Signing session layout before sessions recorded their group key version
+    #[derive(Decode, Encode)]
+    pub struct V6SigningSession<T: Config> {
+        pub log_id: T::Hash,
+        pub phase: SigningPhase,
+        pub phase_deadline: BlockNumberFor<T>,
+        pub threshold: u32,
+        pub commitments: BoundedVec<(T::AccountId, [u8; 32]), T::MaxAgentsInvolved>,
+        pub shares: BoundedVec<(T::AccountId, [u8; 64]), T::MaxAgentsInvolved>,
+    }
+
+    mod v5 {
+        //! Session maps as keyed in version 5
+
+        use super::V6SigningSession;
+        use crate::{Config, Pallet, SessionId};
+        use frame_support::{
+            pallet_prelude::{BoundedVec, OptionQuery},
+            storage_alias, Blake2_128Concat,
+        };
+        use frame_system::pallet_prelude::BlockNumberFor;
+
+        #[storage_alias]
+        pub type SigningSessions<T: Config> =
+            StorageMap<Pallet<T>, Blake2_128Concat, SessionId, V6SigningSession<T>, OptionQuery>;
+        #[storage_alias]
+        pub type SessionDeadlines<T: Config> = StorageMap<
+            Pallet<T>,
+            Blake2_128Concat,
+            BlockNumberFor<T>,
+            BoundedVec<SessionId, <T as Config>::MaxSessionsPerBlock>,
+            OptionQuery,
+        >;
+    }
+
+    /// `SigningSessions` as keyed from version 6, with sessions in their version 6 layout
+    #[storage_alias]
+    pub type SigningSessions<T: Config> =
+        StorageMap<Pallet<T>, Twox64Concat, SessionId, V6SigningSession<T>, OptionQuery>;
+
+    /// Actual implementation of [`MigrateV5ToV6`]
+    pub struct UncheckedMigrateV5ToV6<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV5ToV6<T> {
+        fn on_runtime_upgrade() -> Weight {
+            // Both hashers share the map prefix, so each map is drained before its entries are
+            // reinserted under the new keys
+            let sessions = v5::SigningSessions::<T>::drain().collect::<Vec<_>>();
+            let deadlines = v5::SessionDeadlines::<T>::drain().collect::<Vec<_>>();
+            let moved = sessions.len().saturating_add(deadlines.len()) as u64;
+
+            for (session_id, session) in sessions {
+                SigningSessions::<T>::insert(session_id, session);
+            }
+            for (block, session_ids) in deadlines {
+                SessionDeadlines::<T>::insert(block, session_ids);
+            }
+
+            T::DbWeight::get().reads_writes(moved, moved.saturating_mul(2))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+            Ok((v5::SigningSessions::<T>::iter_keys().count() as u64).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            let expected = u64::decode(&mut &state[..])
+                .map_err(|_| "ConsensusLog: failed to decode the pre-upgrade session count")?;
+            frame_support::ensure!(
+                SigningSessions::<T>::iter_values().count() as u64 == expected,
+                "ConsensusLog: signing sessions were lost while rehashing their keys"
+            );
+            Ok(())
+        }
+    }
+
+    /// Move the session indexed maps to `Twox64Concat`, bumping the storage version to 6
+    pub type MigrateV5ToV6<T> = VersionedMigration<
+        5,
+        6,
+        UncheckedMigrateV5ToV6<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v7 {
+    //! Replaces the role names in every role quorum policy with [`AgentRole`]s, as chosen by
+    //! [`AgentRole::from_legacy_name`], matching the roles agents were migrated to. Names that
+    //! end up as the same role keep the larger minimum.
+
+    use crate::{Config, ModelRoleQuorumPolicies, Pallet, RoleQuorum, RoleQuorumPolicy};
+    use codec::{Decode, Encode};
+    use core::marker::PhantomData;
+    use csuite_primitives::AgentRole;
+    use frame_support::{
+        migrations::VersionedMigration,
+        pallet_prelude::BoundedVec,
+        traits::{Get, UncheckedOnRuntimeUpgrade},
+        weights::Weight,
+    };
+    use sp_std::vec::Vec;
+
+    /// Role quorum layout before roles were enumerated
+    #[derive(Decode, Encode)]
+    pub struct OldRoleQuorum<T: Config> {
+        pub required_roles: BoundedVec<(Vec<u8>, u32), T::MaxRequiredRoles>,
+    }
+
+    // Map the role names of a quorum to roles, merging names that became the same role
+    fn translate_quorum<T: Config>(old: OldRoleQuorum<T>) -> RoleQuorum<T> {
+        let mut required_roles = BoundedVec::<(AgentRole, u32), T::MaxRequiredRoles>::default();
+        for (name, minimum) in old.required_roles {
+            let role = AgentRole::from_legacy_name(&name);
+            match required_roles.iter_mut().find(|(existing, _)| *existing == role) {
+                Some((_, existing)) => *existing = (*existing).max(minimum),
+                // There are never more roles than there were names, so this always fits
+                None => {
+                    let _ = required_roles.try_push((role, minimum));
+                }
+            }
+        }
+        RoleQuorum { required_roles }
+    }
+
+    /// Actual implementation of [`MigrateV6ToV7`]
+    pub struct UncheckedMigrateV6ToV7<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV6ToV7<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 1u64;
+            let _ = RoleQuorumPolicy::<T>::translate::<OldRoleQuorum<T>, _>(|old| {
+                old.map(translate_quorum::<T>)
+            });
+            ModelRoleQuorumPolicies::<T>::translate::<OldRoleQuorum<T>, _>(|_, old| {
+                translated = translated.saturating_add(1);
+                Some(translate_quorum::<T>(old))
+            });
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+            Ok((ModelRoleQuorumPolicies::<T>::iter_keys().count() as u64).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            let expected = u64::decode(&mut &state[..])
+                .map_err(|_| "ConsensusLog: failed to decode the pre-upgrade policy count")?;
+            frame_support::ensure!(
+                ModelRoleQuorumPolicies::<T>::iter_values().count() as u64 == expected,
+                "ConsensusLog: role quorum policies were lost while enumerating their roles"
+            );
+            Ok(())
+        }
+    }
+
+    /// Enumerate the roles of every role quorum policy, bumping the storage version to 7
+    pub type MigrateV6ToV7<T> = VersionedMigration<
+        6,
+        7,
+        UncheckedMigrateV6ToV7<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v8 {
+    //! Records the FROST group key version every finalized log was signed under. No participant
+    //! set rotation could happen before key versions, so every existing log gets version 0.
+
+    use crate::{aggregate::AggregateSignature, Config, FinalizedLog, FinalizedLogs, Pallet};
+    use codec::{Decode, Encode};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        traits::{Get, UncheckedOnRuntimeUpgrade},
+        weights::Weight,
+    };
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use sp_std::vec::Vec;
+
+    /// Finalized log layout before group key versions
+    #[derive(Decode, Encode)]
+    pub struct OldFinalizedLog<T: Config> {
+        pub finalized_at: BlockNumberFor<T>,
+        pub aggregate: Option<AggregateSignature>,
+    }
+
+    /// Actual implementation of [`MigrateV7ToV8`]
+    pub struct UncheckedMigrateV7ToV8<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV7ToV8<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            FinalizedLogs::<T>::translate::<OldFinalizedLog<T>, _>(|_, old| {
+                translated = translated.saturating_add(1);
+                Some(FinalizedLog {
+                    finalized_at: old.finalized_at,
+                    aggregate: old.aggregate,
+                    key_version: 0,
+                })
+            });
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+            Ok((FinalizedLogs::<T>::iter_keys().count() as u64).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            let expected = u64::decode(&mut &state[..])
+                .map_err(|_| "ConsensusLog: failed to decode the pre-upgrade finalized log count")?;
+            frame_support::ensure!(
+                FinalizedLogs::<T>::iter_values().count() as u64 == expected,
+                "ConsensusLog: finalized logs were lost while recording their key versions"
+            );
+            Ok(())
+        }
+    }
+
+    /// Record the group key version of every finalized log, bumping the storage version to 8
+    pub type MigrateV7ToV8<T> = VersionedMigration<
+        7,
+        8,
+        UncheckedMigrateV7ToV8<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v9 {
+    //! Builds the `PendingDuties` index from the logs awaiting finalization: every involved agent
+    //! that has neither signed nor voted on a pending log gets it as a duty, oldest log first.
+    //! Agents with more than `MaxPendingDuties` such logs keep the oldest ones.
+
+    use crate::{Config, LogVotes, Logs, Pallet, PendingByPriority, PendingDuties};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        traits::{Get, UncheckedOnRuntimeUpgrade},
+        weights::Weight,
+    };
+    #[cfg(feature = "try-runtime")]
+    use codec::{Decode, Encode};
+    use sp_std::vec::Vec;
+
+    /// Actual implementation of [`MigrateV8ToV9`]
+    pub struct UncheckedMigrateV8ToV9<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV8ToV9<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut pending = PendingByPriority::<T>::iter()
+                .map(|(_, log_id, submitted_at)| (submitted_at, log_id))
+                .collect::<Vec<_>>();
+            pending.sort();
+
+            let mut reads = pending.len() as u64;
+            let mut writes = 0u64;
+            for (_, log_id) in pending {
+                reads = reads.saturating_add(1);
+                let Some(log) = Logs::<T>::get(&log_id) else {
+                    continue;
+                };
+                for agent in log.agents_involved.iter() {
+                    reads = reads.saturating_add(2);
+                    if log.signatures.iter().any(|s| &s.agent_id == agent) ||
+                        LogVotes::<T>::contains_key(&log_id, agent)
+                    {
+                        continue;
+                    }
+                    writes = writes.saturating_add(1);
+                    PendingDuties::<T>::mutate(agent, |duties| {
+                        let _ = duties.try_push(log_id);
+                    });
+                }
+            }
+
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+            Ok((PendingDuties::<T>::iter_keys().count() as u64).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            let existing = u64::decode(&mut &state[..])
+                .map_err(|_| "ConsensusLog: failed to decode the pre-upgrade duty count")?;
+            frame_support::ensure!(existing == 0, "ConsensusLog: PendingDuties was not empty before the upgrade");
+            frame_support::ensure!(
+                PendingDuties::<T>::iter_values().all(|duties| duties.iter().all(|log_id| {
+                    PendingByPriority::<T>::iter_keys().any(|(_, pending)| pending == *log_id)
+                })),
+                "ConsensusLog: a pending duty points at a log that is not pending"
+            );
+            Ok(())
+        }
+    }
+
+    /// Index the pending duties of every agent, bumping the storage version to 9
+    pub type MigrateV8ToV9<T> = VersionedMigration<
+        8,
+        9,
+        UncheckedMigrateV8ToV9<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v10 {
+    //! Records the FROST group key version every open signing session was opened under. A
+    //! session cannot be told apart from one opened before the last rotation, so every open
+    //! session gets the current version.
+
+    use super::v6::V6SigningSession;
+    use crate::{Config, Pallet, SigningSession, SigningSessions};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        traits::{Get, UncheckedOnRuntimeUpgrade},
+        weights::Weight,
+    };
+    #[cfg(feature = "try-runtime")]
+    use codec::{Decode, Encode};
+    #[cfg(feature = "try-runtime")]
+    use sp_std::vec::Vec;
+
+    /// Actual implementation of [`MigrateV9ToV10`]
+    pub struct UncheckedMigrateV9ToV10<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV9ToV10<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let key_version = Pallet::<T>::group_key_version();
+            let mut translated = 1u64;
+            SigningSessions::<T>::translate::<V6SigningSession<T>, _>(|_, old| {
+                translated = translated.saturating_add(1);
+                Some(SigningSession {
+                    log_id: old.log_id,
+                    phase: old.phase,
+                    phase_deadline: old.phase_deadline,
+                    threshold: old.threshold,
+                    key_version,
+                    commitments: old.commitments,
+                    shares: old.shares,
+                })
+            });
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+            Ok((SigningSessions::<T>::iter_keys().count() as u64).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            let expected = u64::decode(&mut &state[..])
+                .map_err(|_| "ConsensusLog: failed to decode the pre-upgrade session count")?;
+            frame_support::ensure!(
+                SigningSessions::<T>::iter_values().count() as u64 == expected,
+                "ConsensusLog: signing sessions were lost while recording their key versions"
+            );
+            Ok(())
+        }
+    }
+
+    /// Record the group key version of every open signing session, bumping the storage version
+    /// to 10
+    pub type MigrateV9ToV10<T> = VersionedMigration<
+        9,
+        10,
+        UncheckedMigrateV9ToV10<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
@@ -12,18 +12,23 @@
  */
 
 use crate as pallet_consensus_log;
+use crate::{CidIndexOverflow, ConsensusModel, LogPriority};
+use codec::Encode;
+use csuite_primitives::{SignatureScheme, VerifyAgentSignature};
 use frame::prelude::*;
 use frame_support::{
-    parameter_types,
-    traits::{ConstU16, ConstU32, ConstU64},
+    derive_impl, parameter_types,
+    traits::{ConstU16, ConstU32, ConstU64, Contains},
 };
 use frame_system as system;
-use sp_core::H256;
+use sp_core::{Pair, H256};
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
-    BuildStorage,
+    BuildStorage, Perbill, Percent,
 };
 use pallet_agent_registry as agent_registry;
+use pallet_agent_registry::AgentRole;
+use std::cell::RefCell;
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -32,6 +37,7 @@ frame_support::construct_runtime!(
     pub enum Test
     {
         System: frame_system,
+        Balances: pallet_balances,
         AgentRegistry: pallet_agent_registry,
         ConsensusLog: pallet_consensus_log,
     }
@@ -54,7 +60,7 @@ impl frame_system::Config for Test {
     type DbWeight = ();
     type Version = ();
     type PalletInfo = PalletInfo;
-    type AccountData = ();
+    type AccountData = pallet_balances::AccountData<u64>;
     type OnNewAccount = ();
     type OnKilledAccount = ();
     type SystemWeightInfo = ();
@@ -63,15 +69,48 @@ impl frame_system::Config for Test {
     type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
+}
+
 parameter_types! {
-    pub const MaxRoleLength: u32 = 32;
     pub const MaxMetadataLength: u32 = 1024;
+    pub const OverloadThreshold: Percent = Percent::from_percent(90);
+    pub AllFeatures: pallet_agent_registry::FeatureFlags = pallet_agent_registry::FeatureFlags::all();
 }
 
 impl pallet_agent_registry::Config for Test {
     type RuntimeEvent = RuntimeEvent;
-    type MaxRoleLength = MaxRoleLength;
     type MaxMetadataLength = MaxMetadataLength;
+    type MaxMetadataAllowance = ConstU32<4096>;
+    type MetadataTierBytes = ConstU32<1024>;
+    type MetadataTierDeposit = ConstU64<100>;
+    type Currency = Balances;
+    type FeeExemption = ();
+    type RetirementGracePeriod = ConstU64<10>;
+    type OnRetirement = ();
+    type OnRotation = ();
+    type OnStatusChange = ();
+    type MaxEndpoints = ConstU32<4>;
+    type MaxEndpointLength = ConstU32<64>;
+    type EndpointTtl = ConstU64<100>;
+    type AnnouncementCooldown = ConstU64<10>;
+    type StaleAfter = ConstU64<50>;
+    type MaxSweepPerBlock = ConstU32<8>;
+    type MaxProxies = ConstU32<2>;
+    type MaxCapabilities = ConstU32<2>;
+    type DefaultFeatures = AllFeatures;
+    type MaxTelemetrySnapshots = ConstU32<3>;
+    type TelemetryInterval = ConstU64<5>;
+    type TelemetryTtl = ConstU64<20>;
+    type OverloadThreshold = OverloadThreshold;
+    type OverloadQueueDepth = ConstU32<100>;
+    type MaxMaintenanceWindows = ConstU32<3>;
+    type MaxMaintenanceDuration = ConstU64<100>;
+    type RemoteOrigin = frame_system::EnsureNever<u32>;
+    type MaxRemoteProofSize = ConstU32<4_096>;
+    type WeightInfo = ();
 }
 
 // Custom type for MaxSignatureLength that implements Eq
@@ -84,10 +123,17 @@ impl Get<u32> for MaxSigLen {
 }
 
 parameter_types! {
+    pub static LogDeposit: u64 = 0;
+    pub static SessionDeposit: u64 = 0;
+    pub static RequireSameOrg: bool = false;
+    pub const RetractionFee: Perbill = Perbill::from_percent(10);
+    pub const VetoThreshold: Perbill = Perbill::from_percent(50);
+    pub static CidOverflowPolicy: CidIndexOverflow = CidIndexOverflow::EvictOldest;
     pub const MaxCIDLength: u32 = 64;
     pub const MaxConsensusMetadataLength: u32 = 2048;
     pub const MaxAgentsInvolved: u32 = 32;
     pub const MaxSignatures: u32 = 32;
+    pub const MaxFrostParticipants: u32 = 16;
 }
 
 impl pallet_consensus_log::Config for Test {
@@ -97,18 +143,217 @@ impl pallet_consensus_log::Config for Test {
     type MaxAgentsInvolved = MaxAgentsInvolved;
     type MaxSignatureLength = MaxSigLen;
     type MaxSignatures = MaxSignatures;
+    type Agents = AgentRegistry;
+    type SignatureVerifier = MockSignatureVerifier;
+    type FeeExemption = ();
+    type Orgs = MockOrgs;
+    type RequireSameOrg = RequireSameOrg;
+    type SubmitOrigin = frame_system::EnsureSigned<u64>;
+    type CommitmentPeriod = ConstU64<5>;
+    type SharePeriod = ConstU64<5>;
+    type SessionRetention = ConstU64<20>;
+    type SessionDeposit = SessionDeposit;
+    type MaxSessionsPerBlock = ConstU32<8>;
+    type SigningFaultReporter = RecordSigningFaults;
+    type ParticipationFilter = MockParticipation;
+    type OnLogActivity = ();
+    type DedupWindow = ConstU64<10>;
+    type MaxRecentSubmissions = ConstU32<3>;
+    type MaxAmendments = ConstU32<2>;
+    type AllowedModels = MockAllowedModels;
+    type SignerWeight = MockSignerWeight;
+    type FirstResponders = ConstU32<2>;
+    type OnFirstResponder = RecordFirstResponders;
+    type PauseOrigin = frame_system::EnsureRoot<u64>;
+    type MaxBatchSignatures = ConstU32<3>;
+    type Currency = Balances;
+    type LogDeposit = LogDeposit;
+    type RetractionFee = RetractionFee;
+    type CidIndexOverflow = CidOverflowPolicy;
+    type UrgentReputation = ConstU64<5>;
+    type MaxRequiredRoles = ConstU32<4>;
+    type MaxAnnotations = ConstU32<2>;
+    type MaxAnnotationsPerAuthor = ConstU32<1>;
+    type RequiredSignerPeriod = ConstU64<10>;
+    type MaxRequiredSignerChecksPerBlock = ConstU32<2>;
+    type MaxFinalizationsPerBlock = ConstU32<16>;
+    type VetoThreshold = VetoThreshold;
+    type MaxPendingDuties = ConstU32<3>;
+    type MaxMetadataBlobLength = ConstU32<4_096>;
+    type MetadataBlobDepositBase = ConstU64<10>;
+    type MetadataBlobDepositPerByte = ConstU64<1>;
+    type InsightCommitPeriod = ConstU64<5>;
+    type InsightRevealPeriod = ConstU64<5>;
+    type MaxSequenceGaps = ConstU32<2>;
+    type MaxFrostParticipants = MaxFrostParticipants;
+    type WeightInfo = ();
+}
+
+thread_local! {
+    static SIGNING_FAULTS: RefCell<Vec<u64>> = RefCell::new(Vec::new());
+    static RESTRICTED_AGENTS: RefCell<Vec<u64>> = RefCell::new(Vec::new());
+    static DISALLOWED_MODELS: RefCell<Vec<ConsensusModel>> = RefCell::new(Vec::new());
+    static SIGNER_WEIGHTS: RefCell<Vec<(u64, u64)>> = RefCell::new(Vec::new());
+    static FIRST_RESPONDERS: RefCell<Vec<(u64, u32)>> = RefCell::new(Vec::new());
+    static RESPONDER_PRIORITIES: RefCell<Vec<LogPriority>> = RefCell::new(Vec::new());
+    static VERIFIED_SCHEMES: RefCell<Vec<(u64, SignatureScheme)>> = RefCell::new(Vec::new());
+    static AGENT_ORGS: RefCell<Vec<(u64, pallet_orgs::OrgId)>> = RefCell::new(Vec::new());
+}
+
+/// Places agents in the organizations set with `set_org`, and nowhere by default
+pub struct MockOrgs;
+impl pallet_orgs::OrgInspect<u64> for MockOrgs {
+    fn org_of(who: &u64) -> Option<pallet_orgs::OrgId> {
+        AGENT_ORGS.with(|o| o.borrow().iter().find(|(agent, _)| agent == who).map(|(_, org)| *org))
+    }
+}
+
+/// Make an agent a member of `org`
+pub fn set_org(agent_id: u64, org: pallet_orgs::OrgId) {
+    AGENT_ORGS.with(|o| o.borrow_mut().push((agent_id, org)));
+}
+
+/// Accepts every signature but those starting with `b"forged"`, recording the signer and
+/// scheme each one was checked under
+pub struct MockSignatureVerifier;
+impl VerifyAgentSignature<u64> for MockSignatureVerifier {
+    fn verify(scheme: SignatureScheme, signer: &u64, _message: &[u8], signature: &[u8]) -> bool {
+        VERIFIED_SCHEMES.with(|v| v.borrow_mut().push((*signer, scheme)));
+        !signature.starts_with(b"forged")
+    }
+}
+
+/// Signers and schemes of the signatures verified so far
+pub fn verified_schemes() -> Vec<(u64, SignatureScheme)> {
+    VERIFIED_SCHEMES.with(|v| v.borrow().clone())
+}
+
+/// Records the first responders of finalized logs with their positions, and the priorities of
+/// those logs
+pub struct RecordFirstResponders;
+impl crate::OnFirstResponder<u64> for RecordFirstResponders {
+    fn on_first_responder(agent_id: &u64, position: u32, priority: LogPriority) -> Weight {
+        FIRST_RESPONDERS.with(|r| r.borrow_mut().push((*agent_id, position)));
+        RESPONDER_PRIORITIES.with(|p| p.borrow_mut().push(priority));
+        Weight::zero()
+    }
+}
+
+/// First responders reported so far
+pub fn first_responders() -> Vec<(u64, u32)> {
+    FIRST_RESPONDERS.with(|r| r.borrow().clone())
+}
+
+/// Priorities of the logs each reported first responder signed
+pub fn responder_priorities() -> Vec<LogPriority> {
+    RESPONDER_PRIORITIES.with(|p| p.borrow().clone())
+}
+
+/// Allows every consensus model not marked with `disallow_model`
+pub struct MockAllowedModels;
+impl Contains<ConsensusModel> for MockAllowedModels {
+    fn contains(model: &ConsensusModel) -> bool {
+        DISALLOWED_MODELS.with(|d| !d.borrow().contains(model))
+    }
+}
+
+/// Stop accepting submissions with `model`
+pub fn disallow_model(model: ConsensusModel) {
+    DISALLOWED_MODELS.with(|d| d.borrow_mut().push(model));
+}
+
+/// Weighs agents as set with `set_signer_weight`, one by default
+pub struct MockSignerWeight;
+impl crate::SignerWeight<u64> for MockSignerWeight {
+    fn signer_weight(agent_id: &u64) -> u64 {
+        SIGNER_WEIGHTS.with(|w| {
+            w.borrow().iter().find(|(a, _)| a == agent_id).map_or(1, |(_, weight)| *weight)
+        })
+    }
+}
+
+/// Give an agent a weight under the reputation-weighted model
+pub fn set_signer_weight(agent_id: u64, weight: u64) {
+    SIGNER_WEIGHTS.with(|w| w.borrow_mut().push((agent_id, weight)));
+}
+
+/// Rejects agents marked with `restrict_agent`
+pub struct MockParticipation;
+impl crate::ParticipationFilter<u64> for MockParticipation {
+    fn can_participate(agent_id: &u64) -> bool {
+        RESTRICTED_AGENTS.with(|r| !r.borrow().contains(agent_id))
+    }
+}
+
+/// Treat an agent as banned or quarantined
+pub fn restrict_agent(agent_id: u64) {
+    RESTRICTED_AGENTS.with(|r| r.borrow_mut().push(agent_id));
+}
+
+/// Records agents reported for abandoning a signing session
+pub struct RecordSigningFaults;
+impl crate::SigningFaultReporter<u64> for RecordSigningFaults {
+    fn report_missing_share(agent_id: &u64) -> Weight {
+        SIGNING_FAULTS.with(|f| f.borrow_mut().push(*agent_id));
+        Weight::zero()
+    }
+}
+
+/// Agents reported for missing signature shares so far
+pub fn signing_faults() -> Vec<u64> {
+    SIGNING_FAULTS.with(|f| f.borrow().clone())
+}
+
+/// Stand-in for the group key pair a DKG among `participants` would produce
+pub fn frost_group_pair(participants: &[u64]) -> sp_core::ed25519::Pair {
+    sp_core::ed25519::Pair::from_seed(&sp_core::hashing::blake2_256(&participants.encode()))
+}
+
+/// Install a FROST group of `participants` with the given threshold
+pub fn set_frost_group(participants: Vec<u64>, threshold: u32) {
+    crate::FrostGroupKey::<Test>::put(frost_group_pair(&participants).public().0);
+    crate::FrostParticipants::<Test>::put(
+        BoundedVec::<u64, MaxAgentsInvolved>::try_from(participants).unwrap(),
+    );
+    crate::FrostThreshold::<Test>::put(threshold);
+}
+
+/// Run blocks up to `n`, calling the pallet hooks on each
+pub fn run_to_block(n: u64) {
+    use frame_support::traits::Hooks;
+
+    while System::block_number() < n {
+        ConsensusLog::on_finalize(System::block_number());
+        let next = System::block_number() + 1;
+        System::set_block_number(next);
+        ConsensusLog::on_initialize(next);
+    }
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-    frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into()
+    SIGNING_FAULTS.with(|f| f.borrow_mut().clear());
+    RESTRICTED_AGENTS.with(|r| r.borrow_mut().clear());
+    FIRST_RESPONDERS.with(|r| r.borrow_mut().clear());
+    RESPONDER_PRIORITIES.with(|p| p.borrow_mut().clear());
+    VERIFIED_SCHEMES.with(|v| v.borrow_mut().clear());
+    AGENT_ORGS.with(|o| o.borrow_mut().clear());
+    LogDeposit::set(0);
+    SessionDeposit::set(0);
+    RequireSameOrg::set(false);
+    CidOverflowPolicy::set(CidIndexOverflow::EvictOldest);
+    let mut ext: sp_io::TestExternalities =
+        frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into();
+    // Benchmarks generate the keys they verify signatures of
+    ext.register_extension(sp_keystore::KeystoreExt::new(sp_keystore::testing::MemoryKeystore::new()));
+    ext
 }
 
 // Helper function to register an agent for testing
-pub fn register_test_agent(agent_id: u64, role: &[u8]) {
+pub fn register_test_agent(agent_id: u64, role: AgentRole) {
     pallet_agent_registry::Pallet::<Test>::register_agent(
         RuntimeOrigin::signed(agent_id),
-        role.to_vec(),
+        role,
         None,
     ).expect("Agent should be registered successfully");
 } 
\ No newline at end of file
@@ -11,17 +11,22 @@
  * ----------------------------------------------------------------------------
  */
 
-use crate::{mock::*, Error, Event, LogType};
-use frame_support::{assert_noop, assert_ok};
-use sp_runtime::traits::BadOrigin;
-use pallet_agent_registry::{self, AgentStatus};
+use crate::{
+    aggregate::{combine_keys, AggregateSignature}, mock::*, CidIndexOverflow, ConsensusModel, Error, Event, LogPriority,
+    LogType, RoleQuorum, RoundPhase, SigningPhase, VoteKind,
+};
+use csuite_primitives::SignatureScheme;
+use frame_support::{assert_noop, assert_ok, dispatch::Pays, traits::Get};
+use sp_runtime::{traits::BadOrigin, DispatchResult};
+use pallet_agent_registry::{self, AgentRole, AgentStatus, FeatureFlags};
+use sp_core::Pair;
 use sp_std::vec;
 
 // Helper function to register an agent for testing
-fn register_agent(agent_id: u64, role: &[u8]) {
+fn register_agent(agent_id: u64, role: AgentRole) {
     assert_ok!(AgentRegistry::register_agent(
         RuntimeOrigin::signed(agent_id),
-        role.to_vec(),
+        role,
         None
     ));
 }
@@ -38,7 +43,7 @@ fn submit_insight_works() {
     new_test_ext().execute_with(|| {
         // Register agent first
         let agent_id = 1;
-        register_agent(agent_id, b"Lyra");
+        register_agent(agent_id, AgentRole::Lyra);
         
         // Go past genesis block so events get deposited
         System::set_block_number(1);
@@ -48,7 +53,10 @@ fn submit_insight_works() {
         assert_ok!(ConsensusLog::submit_insight(
             RuntimeOrigin::signed(agent_id),
             cid.clone(),
-            None
+            None,
+            ConsensusModel::Unanimous,
+            LogPriority::Normal,
+            vec![],
         ));
         
         // Get the log ID from the event
@@ -92,7 +100,10 @@ fn submit_insight_fails_for_unregistered_agent() {
             ConsensusLog::submit_insight(
                 RuntimeOrigin::signed(agent_id),
                 cid,
-                None
+                None,
+                ConsensusModel::Unanimous,
+                LogPriority::Normal,
+                vec![],
             ),
             Error::<Test>::AgentNotFound
         );
@@ -103,9 +114,9 @@ fn submit_insight_fails_for_unregistered_agent() {
 fn log_consensus_works() {
     new_test_ext().execute_with(|| {
         // Register multiple agents
-        register_agent(1, b"Lyra");
-        register_agent(2, b"Echo");
-        register_agent(3, b"Verdict");
+        register_agent(1, AgentRole::Lyra);
+        register_agent(2, AgentRole::Echo);
+        register_agent(3, AgentRole::Verdict);
         
         // Go past genesis block
         System::set_block_number(1);
@@ -156,7 +167,7 @@ fn log_consensus_works() {
 fn log_consensus_fails_with_too_few_agents() {
     new_test_ext().execute_with(|| {
         // Register agent
-        register_agent(1, b"Lyra");
+        register_agent(1, AgentRole::Lyra);
         
         // Try to log consensus with only one agent
         let cid = b"QmConsensus123456789ABCDEF".to_vec();
@@ -180,15 +191,18 @@ fn log_consensus_fails_with_too_few_agents() {
 fn sign_log_works() {
     new_test_ext().execute_with(|| {
         // Register multiple agents
-        register_agent(1, b"Lyra");
-        register_agent(2, b"Echo");
+        register_agent(1, AgentRole::Lyra);
+        register_agent(2, AgentRole::Echo);
         
         // Submit insight from agent 1
         let cid = b"QmTest123456789ABCDEF".to_vec();
         assert_ok!(ConsensusLog::submit_insight(
             RuntimeOrigin::signed(1),
             cid.clone(),
-            None
+            None,
+            ConsensusModel::Unanimous,
+            LogPriority::Normal,
+            vec![],
         ));
         
         // Get the log ID
@@ -209,7 +223,8 @@ fn sign_log_works() {
         assert_ok!(ConsensusLog::sign_log(
             RuntimeOrigin::signed(2),
             log_id,
-            signature.clone()
+            signature.clone(),
+            VoteKind::Approve
         ));
         
         // Check signature was added
@@ -224,8 +239,8 @@ fn sign_log_works() {
 fn sign_log_fails_for_already_signed() {
     new_test_ext().execute_with(|| {
         // Register multiple agents and log consensus with signature
-        register_agent(1, b"Lyra");
-        register_agent(2, b"Echo");
+        register_agent(1, AgentRole::Lyra);
+        register_agent(2, AgentRole::Echo);
         
         let cid = b"QmConsensus123456789ABCDEF".to_vec();
         let agents_involved = vec![1, 2];
@@ -247,7 +262,8 @@ fn sign_log_fails_for_already_signed() {
             ConsensusLog::sign_log(
                 RuntimeOrigin::signed(1),
                 log_id,
-                generate_test_signature(1)
+                generate_test_signature(1),
+                VoteKind::Approve
             ),
             Error::<Test>::AlreadySigned
         );
@@ -258,9 +274,9 @@ fn sign_log_fails_for_already_signed() {
 fn sign_log_fails_for_agent_not_involved() {
     new_test_ext().execute_with(|| {
         // Register multiple agents
-        register_agent(1, b"Lyra");
-        register_agent(2, b"Echo");
-        register_agent(3, b"Verdict");
+        register_agent(1, AgentRole::Lyra);
+        register_agent(2, AgentRole::Echo);
+        register_agent(3, AgentRole::Verdict);
         
         // Log consensus with only agents 1 and 2
         let cid = b"QmConsensus123456789ABCDEF".to_vec();
@@ -283,9 +299,2344 @@ fn sign_log_fails_for_agent_not_involved() {
             ConsensusLog::sign_log(
                 RuntimeOrigin::signed(3),
                 log_id,
-                generate_test_signature(3)
+                generate_test_signature(3),
+                VoteKind::Approve
             ),
             Error::<Test>::AgentNotFound
         );
     });
-} 
\ No newline at end of file
+}
+
+#[test]
+fn repeated_cid_in_same_block_gets_distinct_ids() {
+    new_test_ext().execute_with(|| {
+        register_agent(1, AgentRole::Lyra);
+        System::set_block_number(1);
+
+        // Same CID, different metadata, so the dedup window lets both through
+        let cid = b"QmDuplicateContent123456789".to_vec();
+        assert_ok!(ConsensusLog::submit_consensus_log(RuntimeOrigin::signed(1), cid.clone(), None, ConsensusModel::Unanimous, LogPriority::Normal));
+        assert_ok!(ConsensusLog::submit_consensus_log(RuntimeOrigin::signed(1), cid.clone(), Some(b"retry".to_vec()), ConsensusModel::Unanimous, LogPriority::Normal));
+
+        // Both logs exist under different IDs and the nonce advanced twice
+        let logs = ConsensusLog::logs_by_agent(1);
+        assert_eq!(logs.len(), 2);
+        assert_ne!(logs[0], logs[1]);
+        assert_eq!(ConsensusLog::agent_nonce(1), 2);
+
+        // Each event reports the nonce it consumed
+        System::assert_has_event(Event::ConsensusLogged {
+            log_id: logs[0],
+            agent_id: 1,
+            nonce: 0,
+            cid: cid.clone(),
+        }.into());
+        System::assert_has_event(Event::ConsensusLogged {
+            log_id: logs[1],
+            agent_id: 1,
+            nonce: 1,
+            cid,
+        }.into());
+    });
+}
+
+#[test]
+fn duplicate_submission_within_window_is_rejected() {
+    new_test_ext().execute_with(|| {
+        register_agent(1, AgentRole::Lyra);
+        register_agent(2, AgentRole::Echo);
+        System::set_block_number(1);
+
+        let cid = b"QmDuplicateContent123456789".to_vec();
+        assert_ok!(ConsensusLog::submit_consensus_log(RuntimeOrigin::signed(1), cid.clone(), None, ConsensusModel::Unanimous, LogPriority::Normal));
+
+        // The same pair is rejected, as a consensus log or as an insight
+        System::set_block_number(10);
+        assert_noop!(
+            ConsensusLog::submit_consensus_log(RuntimeOrigin::signed(1), cid.clone(), None, ConsensusModel::Unanimous, LogPriority::Normal),
+            Error::<Test>::DuplicateSubmission
+        );
+        assert_noop!(
+            ConsensusLog::submit_insight(RuntimeOrigin::signed(1), vec![1], cid.clone(), generate_test_signature(1), None, ConsensusModel::Unanimous, LogPriority::Normal, vec![], None),
+            Error::<Test>::DuplicateSubmission
+        );
+
+        // Other submitters are unaffected
+        assert_ok!(ConsensusLog::submit_consensus_log(RuntimeOrigin::signed(2), cid.clone(), None, ConsensusModel::Unanimous, LogPriority::Normal));
+
+        // Once the window has passed the entry is pruned and the pair is accepted again
+        System::set_block_number(11);
+        assert_ok!(ConsensusLog::submit_consensus_log(RuntimeOrigin::signed(1), cid, None, ConsensusModel::Unanimous, LogPriority::Normal));
+        assert_eq!(ConsensusLog::recent_submissions(1).len(), 1);
+    });
+}
+
+#[test]
+fn recent_submissions_drop_the_oldest_entry_when_full() {
+    new_test_ext().execute_with(|| {
+        register_agent(1, AgentRole::Lyra);
+        System::set_block_number(1);
+
+        // The mock remembers three submissions per agent
+        for i in 0..4u8 {
+            assert_ok!(ConsensusLog::submit_consensus_log(RuntimeOrigin::signed(1), vec![b'Q', i], None, ConsensusModel::Unanimous, LogPriority::Normal));
+        }
+        assert_eq!(ConsensusLog::recent_submissions(1).len(), 3);
+
+        // The first CID fell out of the buffer, the latest ones are still tracked
+        assert_ok!(ConsensusLog::submit_consensus_log(RuntimeOrigin::signed(1), vec![b'Q', 0], None, ConsensusModel::Unanimous, LogPriority::Normal));
+        assert_noop!(
+            ConsensusLog::submit_consensus_log(RuntimeOrigin::signed(1), vec![b'Q', 3], None, ConsensusModel::Unanimous, LogPriority::Normal),
+            Error::<Test>::DuplicateSubmission
+        );
+    });
+}
+
+// Register agents 1..=4 as a 3-of-4 FROST group and log something for them to sign
+fn setup_signing_group() -> sp_core::H256 {
+    for agent_id in 1..=4 {
+        register_agent(agent_id, AgentRole::Lyra);
+    }
+    set_frost_group(vec![1, 2, 3, 4], 3);
+    System::set_block_number(1);
+
+    assert_ok!(ConsensusLog::submit_consensus_log(
+        RuntimeOrigin::signed(1),
+        b"QmSigningSessionContent".to_vec(),
+        None,
+        ConsensusModel::ThresholdSignature,
+        LogPriority::Normal
+    ));
+    ConsensusLog::logs_by_agent(1)[0]
+}
+
+#[test]
+fn signing_session_completes_when_all_shares_arrive() {
+    new_test_ext().execute_with(|| {
+        let log_id = setup_signing_group();
+        assert_ok!(ConsensusLog::open_signing_session(RuntimeOrigin::signed(1), log_id));
+        assert_eq!(ConsensusLog::session_of_log(log_id), Some(0));
+
+        // A second session for the same log is refused while the first is open
+        assert_noop!(
+            ConsensusLog::open_signing_session(RuntimeOrigin::signed(2), log_id),
+            Error::<Test>::SessionAlreadyOpen
+        );
+
+        // Shares are not accepted before the commitment phase ends
+        assert_noop!(
+            ConsensusLog::submit_signature_share(RuntimeOrigin::signed(1), 0, [1u8; 64], ConsensusLog::share_binding(0).unwrap()),
+            Error::<Test>::WrongSessionPhase
+        );
+
+        for agent_id in 1..=4 {
+            assert_ok!(ConsensusLog::submit_commitment(RuntimeOrigin::signed(agent_id), 0, [agent_id as u8; 32]));
+        }
+        let session = ConsensusLog::signing_sessions(0).unwrap();
+        assert_eq!(session.phase, SigningPhase::ShareCollection);
+        assert_eq!(session.phase_deadline, 6);
+
+        for agent_id in 1..=4 {
+            assert_ok!(ConsensusLog::submit_signature_share(RuntimeOrigin::signed(agent_id), 0, [agent_id as u8; 64], ConsensusLog::share_binding(0).unwrap()));
+        }
+        assert_eq!(ConsensusLog::signing_sessions(0).unwrap().phase, SigningPhase::Complete);
+        assert_eq!(ConsensusLog::session_of_log(log_id), None);
+        System::assert_has_event(Event::SigningSessionCompleted { session_id: 0, log_id }.into());
+
+        // Stale deadlines left behind by the early phase changes are ignored
+        run_to_block(10);
+        assert_eq!(ConsensusLog::signing_sessions(0).unwrap().phase, SigningPhase::Complete);
+        assert!(signing_faults().is_empty());
+    });
+}
+
+#[test]
+fn signature_shares_cannot_be_replayed_across_sessions() {
+    new_test_ext().execute_with(|| {
+        let log_id = setup_signing_group();
+        assert_ok!(ConsensusLog::open_signing_session(RuntimeOrigin::signed(1), log_id));
+        let first_binding = ConsensusLog::share_binding(0).unwrap();
+        for agent_id in 1..=4 {
+            assert_ok!(ConsensusLog::submit_commitment(RuntimeOrigin::signed(agent_id), 0, [agent_id as u8; 32]));
+        }
+        for agent_id in 1..=4 {
+            assert_ok!(ConsensusLog::submit_signature_share(RuntimeOrigin::signed(agent_id), 0, [agent_id as u8; 64], first_binding));
+        }
+
+        // A new session over the same log has its own binding
+        assert_ok!(ConsensusLog::open_signing_session(RuntimeOrigin::signed(1), log_id));
+        let second_binding = ConsensusLog::share_binding(1).unwrap();
+        assert_ne!(first_binding, second_binding);
+        for agent_id in 1..=4 {
+            assert_ok!(ConsensusLog::submit_commitment(RuntimeOrigin::signed(agent_id), 1, [agent_id as u8; 32]));
+        }
+
+        assert_noop!(
+            ConsensusLog::submit_signature_share(RuntimeOrigin::signed(1), 1, [1u8; 64], first_binding),
+            Error::<Test>::ReplayedSignatureShare
+        );
+        assert_ok!(ConsensusLog::submit_signature_share(RuntimeOrigin::signed(1), 1, [1u8; 64], second_binding));
+    });
+}
+
+#[test]
+fn signature_shares_must_be_signed_by_the_participant() {
+    new_test_ext().execute_with(|| {
+        let log_id = setup_signing_group();
+        assert_ok!(ConsensusLog::open_signing_session(RuntimeOrigin::signed(1), log_id));
+        let binding = ConsensusLog::share_binding(0).unwrap();
+        for agent_id in 1..=4 {
+            assert_ok!(ConsensusLog::submit_commitment(RuntimeOrigin::signed(agent_id), 0, [agent_id as u8; 32]));
+        }
+
+        // Knowing the public binding is not enough to submit a share
+        let mut forged = [1u8; 64];
+        forged[..6].copy_from_slice(b"forged");
+        assert_noop!(
+            ConsensusLog::submit_signature_share(RuntimeOrigin::signed(1), 0, forged, binding),
+            Error::<Test>::InvalidSignatureShare
+        );
+
+        assert_ok!(ConsensusLog::submit_signature_share(RuntimeOrigin::signed(1), 0, [1u8; 64], binding));
+        assert!(verified_schemes().contains(&(1, SignatureScheme::Sr25519)));
+    });
+}
+
+#[test]
+fn signing_session_rejects_outsiders_and_duplicates() {
+    new_test_ext().execute_with(|| {
+        let log_id = setup_signing_group();
+        register_agent(5, AgentRole::Echo);
+
+        assert_noop!(
+            ConsensusLog::open_signing_session(RuntimeOrigin::signed(5), log_id),
+            Error::<Test>::NotFrostParticipant
+        );
+        assert_ok!(ConsensusLog::open_signing_session(RuntimeOrigin::signed(1), log_id));
+
+        assert_noop!(
+            ConsensusLog::submit_commitment(RuntimeOrigin::signed(5), 0, [5u8; 32]),
+            Error::<Test>::NotFrostParticipant
+        );
+        assert_noop!(
+            ConsensusLog::submit_commitment(RuntimeOrigin::signed(1), 0, [0u8; 32]),
+            Error::<Test>::InvalidCommitment
+        );
+        assert_ok!(ConsensusLog::submit_commitment(RuntimeOrigin::signed(1), 0, [1u8; 32]));
+        assert_noop!(
+            ConsensusLog::submit_commitment(RuntimeOrigin::signed(1), 0, [2u8; 32]),
+            Error::<Test>::AlreadyCommitted
+        );
+        assert_noop!(
+            ConsensusLog::submit_commitment(RuntimeOrigin::signed(1), 7, [1u8; 32]),
+            Error::<Test>::SessionNotFound
+        );
+    });
+}
+
+#[test]
+fn commitment_phase_expires_below_threshold() {
+    new_test_ext().execute_with(|| {
+        let log_id = setup_signing_group();
+        assert_ok!(ConsensusLog::open_signing_session(RuntimeOrigin::signed(1), log_id));
+        assert_ok!(ConsensusLog::submit_commitment(RuntimeOrigin::signed(1), 0, [1u8; 32]));
+        assert_ok!(ConsensusLog::submit_commitment(RuntimeOrigin::signed(2), 0, [2u8; 32]));
+
+        run_to_block(6);
+
+        assert_eq!(ConsensusLog::signing_sessions(0).unwrap().phase, SigningPhase::Expired);
+        assert_eq!(ConsensusLog::session_of_log(log_id), None);
+        System::assert_has_event(Event::SigningSessionExpired {
+            session_id: 0,
+            log_id,
+            phase: SigningPhase::CommitmentCollection,
+        }.into());
+        // Nobody owed a share yet, so nobody is penalised
+        assert!(signing_faults().is_empty());
+
+        // The log can be put up for signing again
+        assert_ok!(ConsensusLog::open_signing_session(RuntimeOrigin::signed(1), log_id));
+        assert_eq!(ConsensusLog::session_of_log(log_id), Some(1));
+    });
+}
+
+#[test]
+fn ended_signing_sessions_refund_their_deposit_and_are_removed() {
+    use frame_support::traits::Currency;
+
+    new_test_ext().execute_with(|| {
+        let log_id = setup_signing_group();
+        SessionDeposit::set(50);
+        Balances::make_free_balance_be(&1, 1_000);
+        Balances::make_free_balance_be(&2, 10);
+        let reserved = Balances::reserved_balance(1);
+
+        assert_noop!(
+            ConsensusLog::open_signing_session(RuntimeOrigin::signed(2), log_id),
+            Error::<Test>::InsufficientDeposit
+        );
+        assert_ok!(ConsensusLog::open_signing_session(RuntimeOrigin::signed(1), log_id));
+        assert_eq!(Balances::reserved_balance(1), reserved + 50);
+        assert_eq!(ConsensusLog::session_deposits(0), Some((1, 50)));
+
+        // The session expires at block 6 and is kept until block 26
+        run_to_block(6);
+        assert_eq!(ConsensusLog::signing_sessions(0).unwrap().phase, SigningPhase::Expired);
+        assert_eq!(ConsensusLog::signing_sessions(0).unwrap().phase_deadline, 26);
+        assert_eq!(Balances::reserved_balance(1), reserved);
+        assert_eq!(ConsensusLog::session_deposits(0), None);
+
+        run_to_block(25);
+        assert!(ConsensusLog::signing_sessions(0).is_some());
+        run_to_block(26);
+        assert!(ConsensusLog::signing_sessions(0).is_none());
+    });
+}
+
+#[test]
+fn committers_without_shares_are_reported() {
+    new_test_ext().execute_with(|| {
+        let log_id = setup_signing_group();
+        assert_ok!(ConsensusLog::open_signing_session(RuntimeOrigin::signed(1), log_id));
+        for agent_id in 1..=3 {
+            assert_ok!(ConsensusLog::submit_commitment(RuntimeOrigin::signed(agent_id), 0, [agent_id as u8; 32]));
+        }
+
+        // Threshold reached at the commitment deadline, so shares are collected
+        run_to_block(6);
+        assert_eq!(ConsensusLog::signing_sessions(0).unwrap().phase, SigningPhase::ShareCollection);
+
+        // Agent 4 never committed and cannot provide a share
+        assert_noop!(
+            ConsensusLog::submit_signature_share(RuntimeOrigin::signed(4), 0, [4u8; 64], ConsensusLog::share_binding(0).unwrap()),
+            Error::<Test>::NoCommitment
+        );
+        assert_ok!(ConsensusLog::submit_signature_share(RuntimeOrigin::signed(1), 0, [1u8; 64], ConsensusLog::share_binding(0).unwrap()));
+
+        run_to_block(11);
+
+        let session = ConsensusLog::signing_sessions(0).unwrap();
+        assert_eq!(session.phase, SigningPhase::Expired);
+        assert_eq!(signing_faults(), vec![2, 3]);
+        System::assert_has_event(Event::SignatureShareMissing { session_id: 0, agent_id: 2 }.into());
+    });
+}
+
+#[test]
+fn pending_participants_track_the_current_phase() {
+    new_test_ext().execute_with(|| {
+        let log_id = setup_signing_group();
+        assert_ok!(ConsensusLog::open_signing_session(RuntimeOrigin::signed(1), log_id));
+        assert_ok!(ConsensusLog::submit_commitment(RuntimeOrigin::signed(1), 0, [1u8; 32]));
+        assert_ok!(ConsensusLog::submit_commitment(RuntimeOrigin::signed(3), 0, [3u8; 32]));
+        assert_ok!(ConsensusLog::submit_commitment(RuntimeOrigin::signed(4), 0, [4u8; 32]));
+
+        let session = ConsensusLog::signing_sessions(0).unwrap();
+        assert_eq!(ConsensusLog::pending_participants(&session), vec![2]);
+
+        run_to_block(6);
+        assert_ok!(ConsensusLog::submit_signature_share(RuntimeOrigin::signed(3), 0, [3u8; 64], ConsensusLog::share_binding(0).unwrap()));
+
+        // Agent 2 never committed, so only committers without a share are awaited
+        let session = ConsensusLog::signing_sessions(0).unwrap();
+        assert_eq!(ConsensusLog::pending_participants(&session), vec![1, 4]);
+    });
+}
+
+#[test]
+fn share_phase_completes_at_deadline_with_threshold() {
+    new_test_ext().execute_with(|| {
+        let log_id = setup_signing_group();
+        assert_ok!(ConsensusLog::open_signing_session(RuntimeOrigin::signed(1), log_id));
+        for agent_id in 1..=4 {
+            assert_ok!(ConsensusLog::submit_commitment(RuntimeOrigin::signed(agent_id), 0, [agent_id as u8; 32]));
+        }
+        for agent_id in 1..=3 {
+            assert_ok!(ConsensusLog::submit_signature_share(RuntimeOrigin::signed(agent_id), 0, [agent_id as u8; 64], ConsensusLog::share_binding(0).unwrap()));
+        }
+
+        run_to_block(6);
+
+        // Enough shares to aggregate, but the straggler is still reported
+        assert_eq!(ConsensusLog::signing_sessions(0).unwrap().phase, SigningPhase::Complete);
+        assert_eq!(signing_faults(), vec![4]);
+    });
+}
+
+// Submit a unanimous insight from agent 1 involving `agents` and return its log ID
+fn submit_insight_with(agents: Vec<u64>) -> sp_core::H256 {
+    submit_insight_under(agents, ConsensusModel::Unanimous)
+}
+
+// Submit an insight from agent 1 involving `agents` under `model` and return its log ID
+fn submit_insight_under(agents: Vec<u64>, model: ConsensusModel) -> sp_core::H256 {
+    for agent_id in agents.iter() {
+        if AgentRegistry::agents(agent_id).is_none() {
+            register_agent(*agent_id, AgentRole::Lyra);
+        }
+    }
+    System::set_block_number(1);
+    assert_ok!(ConsensusLog::submit_insight(
+        RuntimeOrigin::signed(1),
+        agents,
+        b"QmFinalizeContent".to_vec(),
+        generate_test_signature(1),
+        None,
+        model,
+        LogPriority::Normal,
+        vec![],
+        None,
+    ));
+    *ConsensusLog::logs_by_agent(1).last().unwrap()
+}
+
+// Build the aggregate signature over `log_id` the FROST group members at `positions` would produce
+fn aggregate_from(log_id: sp_core::H256, positions: &[usize]) -> AggregateSignature {
+    let participants = crate::FrostParticipants::<Test>::get();
+    let keys: Vec<[u8; 32]> = positions.iter().map(|i| ConsensusLog::frost_key_of(&participants[*i])).collect();
+    let mut participant_bitmap = [0u8; 2];
+    for i in positions {
+        participant_bitmap[i / 8] |= 1 << (i % 8);
+    }
+    AggregateSignature {
+        signature: frost_group_pair(&participants).sign(log_id.as_ref()).0,
+        aggregate_pubkey: combine_keys(keys.iter()),
+        participant_bitmap,
+        challenge: [0u8; 32],
+    }
+}
+
+#[test]
+fn finalize_requires_every_individual_signature() {
+    new_test_ext().execute_with(|| {
+        let log_id = submit_insight_with(vec![1, 2, 3]);
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, generate_test_signature(2), VoteKind::Approve));
+
+        assert_noop!(
+            ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, None),
+            Error::<Test>::MissingSignatures
+        );
+
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(3), log_id, generate_test_signature(3), VoteKind::Approve));
+        assert_ok!(ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, None));
+        assert_eq!(ConsensusLog::finalized_logs(log_id).unwrap().aggregate, None);
+        System::assert_has_event(Event::LogFinalized { log_id, aggregated: false }.into());
+
+        assert_noop!(
+            ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(2), log_id, None),
+            Error::<Test>::AlreadyFinalized
+        );
+    });
+}
+
+#[test]
+fn finalized_logs_are_summarised_in_the_block_digest() {
+    use codec::Encode;
+    use csuite_primitives::ConsensusDigest;
+    use frame_support::traits::Hooks;
+
+    new_test_ext().execute_with(|| {
+        register_agent(1, AgentRole::Lyra);
+        register_agent(2, AgentRole::Echo);
+        System::set_block_number(1);
+        let log_ids = [b"QmDigestOne".to_vec(), b"QmDigestTwo".to_vec()].map(|cid| {
+            assert_ok!(ConsensusLog::submit_insight(
+                RuntimeOrigin::signed(1),
+                vec![1, 2],
+                cid,
+                generate_test_signature(1),
+                None,
+                ConsensusModel::Unanimous,
+                LogPriority::Normal,
+                vec![],
+                None,
+            ));
+            let log_id = *ConsensusLog::logs_by_agent(1).last().unwrap();
+            assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, generate_test_signature(2), VoteKind::Approve));
+            log_id
+        });
+
+        // A block without finalized logs carries no digest
+        run_to_block(2);
+        assert_eq!(ConsensusDigest::from_digest(&System::digest()), None);
+
+        for log_id in log_ids {
+            assert_ok!(ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, None));
+        }
+        ConsensusLog::on_finalize(2);
+
+        let leaves = log_ids.iter().map(|log_id| log_id.encode()).collect::<Vec<_>>();
+        let digest = ConsensusDigest::from_digest(&System::digest()).unwrap();
+        assert_eq!(digest, ConsensusDigest { finalized: 2, root: csuite_primitives::merkle_root(&leaves) });
+        let proof = csuite_primitives::merkle_inclusion_proof(&leaves, 1).unwrap();
+        assert_eq!(proof.root, digest.root);
+        assert!(crate::FinalizedInBlock::<Test>::get().is_empty());
+    });
+}
+
+#[test]
+fn sign_paths_refund_unused_weight() {
+    new_test_ext().execute_with(|| {
+        let log_id = submit_insight_with(vec![1, 2, 3]);
+        let existing = ConsensusLog::logs(log_id).unwrap().signatures.len() as u32;
+
+        // Signing is charged for the scan of this log, not the largest possible one
+        let info = ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, generate_test_signature(2), VoteKind::Approve).unwrap();
+        assert_eq!(
+            info.actual_weight,
+            Some(ConsensusLog::sign_log_weight(3, existing).saturating_add(ConsensusLog::verify_weight(SignatureScheme::Sr25519)))
+        );
+        assert!(info.actual_weight.unwrap().all_lt(ConsensusLog::max_sign_log_weight()));
+        assert_eq!(info.pays_fee, Pays::Yes);
+
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(3), log_id, generate_test_signature(3), VoteKind::Approve));
+        let info = ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, None).unwrap();
+        assert_eq!(info.actual_weight, Some(ConsensusLog::finalize_weight(3, existing + 2)));
+        assert!(info.actual_weight.unwrap().all_lt(
+            ConsensusLog::finalize_weight(MaxAgentsInvolved::get(), MaxSignatures::get())
+        ));
+    });
+}
+
+#[test]
+fn finalize_fails_when_only_restricted_signers_reach_the_quorum() {
+    new_test_ext().execute_with(|| {
+        let log_id = submit_insight_with(vec![1, 2]);
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, generate_test_signature(2), VoteKind::Approve));
+        restrict_agent(2);
+
+        assert_noop!(
+            ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, None),
+            Error::<Test>::SignersExcluded
+        );
+    });
+}
+
+#[test]
+fn signatures_of_agents_that_lost_their_standing_are_excluded() {
+    new_test_ext().execute_with(|| {
+        let log_id = submit_insight_under(vec![1, 2, 3, 4], ConsensusModel::Majority);
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, generate_test_signature(2), VoteKind::Approve));
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(3), log_id, generate_test_signature(3), VoteKind::Approve));
+
+        // A restricted agent can neither sign nor vote
+        restrict_agent(4);
+        for vote in [VoteKind::Approve, VoteKind::Reject] {
+            assert_noop!(
+                ConsensusLog::sign_log(RuntimeOrigin::signed(4), log_id, generate_test_signature(4), vote),
+                Error::<Test>::SignerNotEligible
+            );
+        }
+        assert_noop!(
+            ConsensusLog::sign_logs_batch(RuntimeOrigin::signed(4), vec![(log_id, generate_test_signature(4))]),
+            Error::<Test>::SignerNotEligible
+        );
+
+        // Three of four signatures form a majority until agent 3 is restricted as well
+        restrict_agent(3);
+        assert_noop!(
+            ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, None),
+            Error::<Test>::SignersExcluded
+        );
+    });
+}
+
+#[test]
+fn excluded_signatures_are_dropped_from_finalized_logs() {
+    new_test_ext().execute_with(|| {
+        let log_id = submit_insight_under(vec![1, 2, 3], ConsensusModel::Majority);
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, generate_test_signature(2), VoteKind::Approve));
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(3), log_id, generate_test_signature(3), VoteKind::Approve));
+        restrict_agent(3);
+
+        assert_ok!(ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, None));
+        System::assert_has_event(Event::SignatureExcluded { log_id, agent_id: 3 }.into());
+        assert_eq!(ConsensusLog::finalized_signers(&log_id), Some(vec![1, 2]));
+        assert!(ConsensusLog::signature_inclusion_proof(&log_id, &2).is_some());
+        assert!(ConsensusLog::signature_inclusion_proof(&log_id, &3).is_none());
+    });
+}
+
+#[test]
+fn finalize_with_aggregate_signature_works() {
+    new_test_ext().execute_with(|| {
+        set_frost_group(vec![1, 2, 3, 4], 3);
+        let log_id = submit_insight_under(vec![1, 2, 3, 4], ConsensusModel::ThresholdSignature);
+
+        // No individual signatures beyond the submitter's are needed
+        let aggregate = aggregate_from(log_id, &[0, 1, 3]);
+        assert_ok!(ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, Some(aggregate.clone())));
+        assert_eq!(ConsensusLog::finalized_logs(log_id).unwrap().aggregate, Some(aggregate));
+        System::assert_has_event(Event::LogFinalized { log_id, aggregated: true }.into());
+
+        // Sealed logs take no further signatures
+        assert_noop!(
+            ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, generate_test_signature(2), VoteKind::Approve),
+            Error::<Test>::AlreadyFinalized
+        );
+    });
+}
+
+#[test]
+fn signature_inclusion_proofs_verify_against_the_stored_root() {
+    new_test_ext().execute_with(|| {
+        let log_id = submit_insight_with(vec![1, 2, 3]);
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, generate_test_signature(2), VoteKind::Approve));
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(3), log_id, generate_test_signature(3), VoteKind::Approve));
+        assert_eq!(ConsensusLog::signature_inclusion_proof(&log_id, &2), None);
+
+        assert_ok!(ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, None));
+        let root = ConsensusLog::signature_roots(log_id).unwrap();
+
+        let proof = ConsensusLog::signature_inclusion_proof(&log_id, &2).unwrap();
+        assert!(proof.verify(&root));
+        assert_eq!(proof.number_of_leaves, 3);
+        assert_eq!(proof.decode_leaf::<(u64, Vec<u8>)>(), Some((2, generate_test_signature(2))));
+        assert_eq!(ConsensusLog::signature_inclusion_proof(&log_id, &4), None);
+    });
+}
+
+#[test]
+fn aggregate_signers_are_committed_without_individual_signatures() {
+    new_test_ext().execute_with(|| {
+        set_frost_group(vec![1, 2, 3, 4], 3);
+        let log_id = submit_insight_under(vec![1, 2, 3, 4], ConsensusModel::ThresholdSignature);
+        assert_ok!(ConsensusLog::finalize_consensus_log(
+            RuntimeOrigin::signed(1),
+            log_id,
+            Some(aggregate_from(log_id, &[0, 1, 3]))
+        ));
+        let root = ConsensusLog::signature_roots(log_id).unwrap();
+
+        // The submitter signed individually, the other aggregate signers did not
+        let submitter = ConsensusLog::signature_inclusion_proof(&log_id, &1).unwrap();
+        assert!(submitter.verify(&root));
+        assert_eq!(submitter.decode_leaf::<(u64, Vec<u8>)>(), Some((1, generate_test_signature(1))));
+        let signer = ConsensusLog::signature_inclusion_proof(&log_id, &4).unwrap();
+        assert!(signer.verify(&root));
+        assert_eq!(signer.decode_leaf::<(u64, Vec<u8>)>(), Some((4, Vec::new())));
+
+        // Agent 3 was not selected by the participant bitmap
+        assert_eq!(ConsensusLog::signature_inclusion_proof(&log_id, &3), None);
+    });
+}
+
+#[test]
+fn finalize_rejects_invalid_aggregate_signatures() {
+    new_test_ext().execute_with(|| {
+        let log_id = submit_insight_under(vec![1, 2, 3, 4], ConsensusModel::ThresholdSignature);
+
+        // No group configured yet
+        assert_noop!(
+            ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, Some(aggregate_from(log_id, &[]))),
+            Error::<Test>::FrostGroupNotConfigured
+        );
+
+        set_frost_group(vec![1, 2, 3, 4], 3);
+
+        // Below threshold
+        assert_noop!(
+            ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, Some(aggregate_from(log_id, &[0, 1]))),
+            Error::<Test>::InvalidAggregateSignature
+        );
+
+        // Aggregate key does not match the bitmap
+        let mut forged = aggregate_from(log_id, &[0, 1, 2]);
+        forged.participant_bitmap = aggregate_from(log_id, &[0, 1, 3]).participant_bitmap;
+        assert_noop!(
+            ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, Some(forged)),
+            Error::<Test>::InvalidAggregateSignature
+        );
+
+        // Bitmap points outside the group
+        let mut outside = aggregate_from(log_id, &[0, 1, 2]);
+        outside.participant_bitmap[0] |= 1 << 4;
+        assert_noop!(
+            ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, Some(outside)),
+            Error::<Test>::InvalidAggregateSignature
+        );
+
+        // A signature over another log, or by anyone but the group, does not verify
+        let replayed = AggregateSignature {
+            signature: aggregate_from(sp_core::H256::repeat_byte(9), &[0, 1, 3]).signature,
+            ..aggregate_from(log_id, &[0, 1, 3])
+        };
+        assert_noop!(
+            ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, Some(replayed)),
+            Error::<Test>::InvalidAggregateSignature
+        );
+        let forged_value = AggregateSignature {
+            signature: frost_group_pair(&[1, 2, 4]).sign(log_id.as_ref()).0,
+            ..aggregate_from(log_id, &[0, 1, 3])
+        };
+        assert_noop!(
+            ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, Some(forged_value)),
+            Error::<Test>::InvalidAggregateSignature
+        );
+    });
+}
+
+#[test]
+fn finalize_rejects_aggregate_with_uninvolved_or_restricted_signers() {
+    new_test_ext().execute_with(|| {
+        register_agent(4, AgentRole::Volt);
+        set_frost_group(vec![1, 2, 3, 4], 3);
+        let log_id = submit_insight_under(vec![1, 2, 3], ConsensusModel::ThresholdSignature);
+
+        assert_noop!(
+            ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, Some(aggregate_from(log_id, &[0, 1, 3]))),
+            Error::<Test>::SignerNotInvolved
+        );
+
+        restrict_agent(3);
+        assert_noop!(
+            ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, Some(aggregate_from(log_id, &[0, 1, 2]))),
+            Error::<Test>::SignerNotEligible
+        );
+    });
+}
+
+// Group key a DKG among `participants` would produce
+fn group_key_of(participants: &[u64]) -> [u8; 32] {
+    frost_group_pair(participants).public().0
+}
+
+#[test]
+fn rotate_participant_set_validates_the_new_group() {
+    new_test_ext().execute_with(|| {
+        for agent_id in 1..=4 {
+            register_agent(agent_id, AgentRole::Lyra);
+        }
+        System::set_block_number(1);
+        set_frost_group(vec![1, 2, 3], 2);
+
+        assert_noop!(
+            ConsensusLog::rotate_participant_set(RuntimeOrigin::signed(1), vec![1, 2], 2, group_key_of(&[1, 2])),
+            BadOrigin
+        );
+        assert_noop!(
+            ConsensusLog::rotate_participant_set(RuntimeOrigin::root(), vec![], 1, group_key_of(&[])),
+            Error::<Test>::InvalidFrostParticipants
+        );
+        let too_many = (1..=MaxFrostParticipants::get() as u64 + 1).collect::<Vec<_>>();
+        assert_noop!(
+            ConsensusLog::rotate_participant_set(RuntimeOrigin::root(), too_many.clone(), 1, group_key_of(&too_many)),
+            Error::<Test>::InvalidFrostParticipants
+        );
+        assert_noop!(
+            ConsensusLog::rotate_participant_set(RuntimeOrigin::root(), vec![1, 1], 1, group_key_of(&[1, 1])),
+            Error::<Test>::DuplicateFrostParticipant
+        );
+        // Agent 5 is not registered
+        assert_noop!(
+            ConsensusLog::rotate_participant_set(RuntimeOrigin::root(), vec![1, 5], 1, group_key_of(&[1, 5])),
+            Error::<Test>::IneligibleFrostParticipant
+        );
+        assert_noop!(
+            ConsensusLog::rotate_participant_set(RuntimeOrigin::root(), vec![1, 2], 3, group_key_of(&[1, 2])),
+            Error::<Test>::InvalidFrostThreshold
+        );
+
+        // A banned member is reported, and can no longer join a group
+        restrict_agent(3);
+        assert_eq!(ConsensusLog::ineligible_frost_participants(), vec![3]);
+        assert_noop!(
+            ConsensusLog::rotate_participant_set(RuntimeOrigin::root(), vec![1, 3], 2, group_key_of(&[1, 3])),
+            Error::<Test>::IneligibleFrostParticipant
+        );
+
+        let old_key = ConsensusLog::frost_group_key().unwrap();
+        assert_ok!(ConsensusLog::rotate_participant_set(RuntimeOrigin::root(), vec![1, 2, 4], 2, group_key_of(&[1, 2, 4])));
+        assert_eq!(ConsensusLog::group_key_version(), 1);
+        assert_eq!(ConsensusLog::frost_group_key(), Some(group_key_of(&[1, 2, 4])));
+        assert_eq!(ConsensusLog::frost_participants().to_vec(), vec![1, 2, 4]);
+        assert!(ConsensusLog::ineligible_frost_participants().is_empty());
+
+        let retired = ConsensusLog::retired_frost_groups(0).unwrap();
+        assert_eq!(retired.group_key, old_key);
+        assert_eq!(retired.participants.to_vec(), vec![1, 2, 3]);
+        assert_eq!((retired.threshold, retired.retired_at), (2, 1));
+        System::assert_last_event(Event::FrostGroupRotated {
+            version: 1,
+            group_key: group_key_of(&[1, 2, 4]),
+            participants: vec![1, 2, 4],
+            threshold: 2,
+        }.into());
+    });
+}
+
+#[test]
+fn aggregates_verify_against_the_group_they_were_finalized_under() {
+    new_test_ext().execute_with(|| {
+        set_frost_group(vec![1, 2, 3, 4], 3);
+        let log_id = submit_insight_under(vec![1, 2, 3, 4], ConsensusModel::ThresholdSignature);
+        assert_ok!(ConsensusLog::finalize_consensus_log(
+            RuntimeOrigin::signed(1),
+            log_id,
+            Some(aggregate_from(log_id, &[0, 1, 3]))
+        ));
+        assert_eq!(ConsensusLog::finalized_logs(log_id).unwrap().key_version, 0);
+        let root = ConsensusLog::signature_roots(log_id).unwrap();
+
+        // Reordering the group moves every agent to another bitmap position
+        assert_ok!(ConsensusLog::rotate_participant_set(
+            RuntimeOrigin::root(),
+            vec![4, 3, 2, 1],
+            3,
+            group_key_of(&[4, 3, 2, 1]),
+        ));
+
+        assert!(ConsensusLog::verify_finalized_aggregate(&log_id));
+        assert_eq!(ConsensusLog::finalized_signers(&log_id), Some(vec![1, 2, 4]));
+        assert!(ConsensusLog::signature_inclusion_proof(&log_id, &4).unwrap().verify(&root));
+        assert_eq!(ConsensusLog::signature_inclusion_proof(&log_id, &3), None);
+
+        // Without its retired group, the aggregate can no longer be checked
+        crate::RetiredFrostGroups::<Test>::remove(0);
+        assert!(!ConsensusLog::verify_finalized_aggregate(&log_id));
+        assert_eq!(ConsensusLog::finalized_signers(&log_id), None);
+    });
+}
+
+#[test]
+fn signing_sessions_keep_the_group_they_were_opened_under() {
+    new_test_ext().execute_with(|| {
+        let log_id = setup_signing_group();
+        register_agent(5, AgentRole::Lyra);
+        assert_ok!(ConsensusLog::open_signing_session(RuntimeOrigin::signed(1), log_id));
+        assert_ok!(ConsensusLog::submit_commitment(RuntimeOrigin::signed(1), 0, [1u8; 32]));
+
+        assert_ok!(ConsensusLog::rotate_participant_set(
+            RuntimeOrigin::root(),
+            vec![1, 2, 3, 5],
+            3,
+            group_key_of(&[1, 2, 3, 5]),
+        ));
+
+        // The session still waits on the members of the old group only
+        let session = ConsensusLog::signing_sessions(0).unwrap();
+        assert_eq!(session.key_version, 0);
+        assert_eq!(ConsensusLog::pending_participants(&session), vec![2, 3, 4]);
+        assert_noop!(
+            ConsensusLog::submit_commitment(RuntimeOrigin::signed(5), 0, [5u8; 32]),
+            Error::<Test>::NotFrostParticipant
+        );
+        assert_ok!(ConsensusLog::submit_commitment(RuntimeOrigin::signed(4), 0, [4u8; 32]));
+        let session = ConsensusLog::signing_sessions(0).unwrap();
+        assert_eq!(ConsensusLog::pending_participants(&session), vec![2, 3]);
+    });
+}
+
+#[test]
+fn majority_model_finalizes_with_more_than_half_signed() {
+    new_test_ext().execute_with(|| {
+        let log_id = submit_insight_under(vec![1, 2, 3, 4], ConsensusModel::Majority);
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, generate_test_signature(2), VoteKind::Approve));
+
+        // Two of four is not a majority
+        assert_noop!(
+            ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, None),
+            Error::<Test>::MissingSignatures
+        );
+
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(3), log_id, generate_test_signature(3), VoteKind::Approve));
+        assert_ok!(ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, None));
+        assert_eq!(ConsensusLog::finalized_signers(&log_id), Some(vec![1, 2, 3]));
+    });
+}
+
+#[test]
+fn reputation_weighted_model_counts_signer_weight() {
+    new_test_ext().execute_with(|| {
+        set_signer_weight(1, 10);
+        set_signer_weight(2, 10);
+        set_signer_weight(3, 25);
+        set_signer_weight(4, 5);
+        let log_id = submit_insight_under(vec![1, 2, 3, 4], ConsensusModel::ReputationWeighted);
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, generate_test_signature(2), VoteKind::Approve));
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(4), log_id, generate_test_signature(4), VoteKind::Approve));
+
+        // Three of four agents signed, but they hold only 25 of 50
+        assert_noop!(
+            ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, None),
+            Error::<Test>::MissingSignatures
+        );
+
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(3), log_id, generate_test_signature(3), VoteKind::Approve));
+        assert_ok!(ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, None));
+    });
+}
+
+#[test]
+fn finalization_must_follow_the_consensus_model() {
+    new_test_ext().execute_with(|| {
+        set_frost_group(vec![1, 2, 3, 4], 3);
+
+        // Individually signed logs take no aggregate signature and no signing session
+        let unanimous = submit_insight_with(vec![1, 2, 3, 4]);
+        assert_noop!(
+            ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), unanimous, Some(aggregate_from(unanimous, &[0, 1, 3]))),
+            Error::<Test>::WrongConsensusModel
+        );
+        assert_noop!(
+            ConsensusLog::open_signing_session(RuntimeOrigin::signed(1), unanimous),
+            Error::<Test>::WrongConsensusModel
+        );
+
+        // Threshold-signature logs cannot be finalized from individual signatures
+        ConsensusLog::clear_recent_submissions(&1);
+        let threshold = submit_insight_under(vec![1, 2], ConsensusModel::ThresholdSignature);
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(2), threshold, generate_test_signature(2), VoteKind::Approve));
+        assert_noop!(
+            ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), threshold, None),
+            Error::<Test>::WrongConsensusModel
+        );
+    });
+}
+
+#[test]
+fn disallowed_models_are_rejected_on_submission() {
+    new_test_ext().execute_with(|| {
+        register_agent(1, AgentRole::Lyra);
+        register_agent(2, AgentRole::Volt);
+        disallow_model(ConsensusModel::Majority);
+
+        assert_noop!(
+            ConsensusLog::submit_consensus_log(
+                RuntimeOrigin::signed(1),
+                b"QmMajority".to_vec(),
+                None,
+                ConsensusModel::Majority,
+                LogPriority::Normal
+            ),
+            Error::<Test>::ConsensusModelNotAllowed
+        );
+        assert_noop!(
+            ConsensusLog::submit_insight(
+                RuntimeOrigin::signed(1),
+                vec![1, 2],
+                b"QmMajority".to_vec(),
+                generate_test_signature(1),
+                None,
+                ConsensusModel::Majority,
+                LogPriority::Normal,
+                vec![],
+                None,
+            ),
+            Error::<Test>::ConsensusModelNotAllowed
+        );
+        assert_ok!(ConsensusLog::submit_consensus_log(
+            RuntimeOrigin::signed(1),
+            b"QmMajority".to_vec(),
+            None,
+            ConsensusModel::Unanimous,
+            LogPriority::Normal
+        ));
+        assert_eq!(ConsensusLog::logs(ConsensusLog::logs_by_agent(1)[0]).unwrap().model, ConsensusModel::Unanimous);
+    });
+}
+
+#[test]
+fn submitter_can_amend_pending_log() {
+    new_test_ext().execute_with(|| {
+        let log_id = submit_insight_with(vec![1, 2, 3]);
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, generate_test_signature(2), VoteKind::Approve));
+
+        System::set_block_number(3);
+        assert_ok!(ConsensusLog::amend_consensus_log(
+            RuntimeOrigin::signed(1),
+            log_id,
+            b"QmAmendedContent".to_vec(),
+            Some(b"v2".to_vec()),
+        ));
+
+        // Content is replaced and all signatures must be collected again
+        let log = ConsensusLog::logs(log_id).unwrap();
+        assert_eq!(log.cid.to_vec(), b"QmAmendedContent".to_vec());
+        assert_eq!(log.metadata.unwrap().to_vec(), b"v2".to_vec());
+        assert_eq!(log.amendment_count, 1);
+        assert!(log.signatures.is_empty());
+        System::assert_last_event(Event::LogAmended {
+            log_id,
+            amendment: 1,
+            cid: b"QmAmendedContent".to_vec(),
+        }.into());
+
+        // The CID index follows the log and the previous CID is kept in its history
+        let old_cid: frame_support::BoundedVec<u8, MaxCIDLength> = b"QmFinalizeContent".to_vec().try_into().unwrap();
+        let new_cid: frame_support::BoundedVec<u8, MaxCIDLength> = b"QmAmendedContent".to_vec().try_into().unwrap();
+        assert!(ConsensusLog::logs_by_cid(new_cid).contains(&log_id));
+        assert!(ConsensusLog::logs_by_cid(old_cid.clone()).is_empty());
+        assert_eq!(ConsensusLog::cid_history(log_id).into_inner(), vec![(old_cid, 3)]);
+
+        assert_noop!(
+            ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, None),
+            Error::<Test>::MissingSignatures
+        );
+    });
+}
+
+#[test]
+fn amendments_are_restricted() {
+    new_test_ext().execute_with(|| {
+        let log_id = submit_insight_with(vec![1, 2]);
+
+        assert_noop!(
+            ConsensusLog::amend_consensus_log(RuntimeOrigin::signed(2), log_id, b"QmOther".to_vec(), None),
+            Error::<Test>::NotSubmitter
+        );
+
+        // The mock allows two amendments
+        assert_ok!(ConsensusLog::amend_consensus_log(RuntimeOrigin::signed(1), log_id, b"QmFirst".to_vec(), None));
+        assert_ok!(ConsensusLog::amend_consensus_log(RuntimeOrigin::signed(1), log_id, b"QmSecond".to_vec(), None));
+        assert_noop!(
+            ConsensusLog::amend_consensus_log(RuntimeOrigin::signed(1), log_id, b"QmThird".to_vec(), None),
+            Error::<Test>::TooManyAmendments
+        );
+    });
+}
+
+#[test]
+fn finalized_logs_cannot_be_amended() {
+    new_test_ext().execute_with(|| {
+        let log_id = submit_insight_with(vec![1, 2]);
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, generate_test_signature(2), VoteKind::Approve));
+        assert_ok!(ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, None));
+
+        assert_noop!(
+            ConsensusLog::amend_consensus_log(RuntimeOrigin::signed(1), log_id, b"QmLate".to_vec(), None),
+            Error::<Test>::AlreadyFinalized
+        );
+    });
+}
+
+#[test]
+fn first_responders_are_reported_in_signing_order() {
+    new_test_ext().execute_with(|| {
+        let log_id = submit_insight_under(vec![1, 2, 3, 4], ConsensusModel::Unanimous);
+        System::set_block_number(2);
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(4), log_id, generate_test_signature(4), VoteKind::Approve));
+        System::set_block_number(3);
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, generate_test_signature(2), VoteKind::Approve));
+        System::set_block_number(4);
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(3), log_id, generate_test_signature(3), VoteKind::Approve));
+
+        let signed_at = ConsensusLog::logs(&log_id)
+            .unwrap()
+            .signatures
+            .iter()
+            .map(|sig| (sig.agent_id, sig.signed_at))
+            .collect::<Vec<_>>();
+        assert_eq!(signed_at, vec![(1, 1), (4, 2), (2, 3), (3, 4)]);
+
+        assert_ok!(ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, None));
+
+        // The submitter is skipped and the mock rewards only the first two
+        assert_eq!(first_responders(), vec![(4, 0), (2, 1)]);
+        System::assert_has_event(Event::FirstResponders { log_id, agents: vec![4, 2] }.into());
+    });
+}
+
+#[test]
+fn aggregate_finalization_has_no_first_responders() {
+    new_test_ext().execute_with(|| {
+        set_frost_group(vec![1, 2, 3, 4], 3);
+        let log_id = submit_insight_under(vec![1, 2, 3, 4], ConsensusModel::ThresholdSignature);
+        assert_ok!(ConsensusLog::finalize_consensus_log(
+            RuntimeOrigin::signed(1),
+            log_id,
+            Some(aggregate_from(log_id, &[0, 1, 3]))
+        ));
+
+        // There is no individual signing order to reward
+        assert!(first_responders().is_empty());
+        assert!(!System::events()
+            .iter()
+            .any(|record| matches!(record.event, RuntimeEvent::ConsensusLog(Event::FirstResponders { .. }))));
+    });
+}
+
+#[test]
+fn pausing_rejects_calls_until_unpaused() {
+    new_test_ext().execute_with(|| {
+        let log_id = submit_insight_with(vec![1, 2]);
+
+        assert_noop!(ConsensusLog::pause(RuntimeOrigin::signed(1)), BadOrigin);
+        assert_ok!(ConsensusLog::pause(RuntimeOrigin::root()));
+        System::assert_last_event(Event::Paused.into());
+
+        assert_noop!(
+            ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, generate_test_signature(2), VoteKind::Approve),
+            Error::<Test>::PalletPaused
+        );
+        assert_noop!(
+            ConsensusLog::submit_insight(
+                RuntimeOrigin::signed(1),
+                vec![1, 2],
+                b"QmPausedContent".to_vec(),
+                generate_test_signature(1),
+                None,
+                ConsensusModel::Unanimous,
+                LogPriority::Normal,
+                vec![],
+                None,
+            ),
+            Error::<Test>::PalletPaused
+        );
+
+        // Existing logs stay readable
+        assert!(ConsensusLog::logs(&log_id).is_some());
+
+        assert_ok!(ConsensusLog::unpause(RuntimeOrigin::root()));
+        System::assert_last_event(Event::Unpaused.into());
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, generate_test_signature(2), VoteKind::Approve));
+    });
+}
+
+#[test]
+fn sign_logs_batch_signs_each_log_and_reports_failures() {
+    new_test_ext().execute_with(|| {
+        register_agent(1, AgentRole::Lyra);
+        register_agent(2, AgentRole::Echo);
+        System::set_block_number(1);
+        let log_ids = [b"QmBatchOne".to_vec(), b"QmBatchTwo".to_vec()]
+            .into_iter()
+            .map(|cid| {
+                assert_ok!(ConsensusLog::submit_insight(
+                    RuntimeOrigin::signed(1),
+                    vec![1, 2],
+                    cid,
+                    generate_test_signature(1),
+                    None,
+                    ConsensusModel::Unanimous,
+                    LogPriority::Normal,
+                    vec![],
+                    None,
+                ));
+                *ConsensusLog::logs_by_agent(1).last().unwrap()
+            })
+            .collect::<Vec<_>>();
+        let unknown = sp_core::H256::repeat_byte(9);
+
+        assert_ok!(ConsensusLog::sign_logs_batch(
+            RuntimeOrigin::signed(2),
+            vec![
+                (log_ids[0], generate_test_signature(2)),
+                (unknown, generate_test_signature(2)),
+                (log_ids[1], generate_test_signature(2)),
+            ]
+        ));
+
+        // The unknown log fails on its own without undoing the others
+        for log_id in log_ids.iter() {
+            assert!(ConsensusLog::logs(log_id).unwrap().signatures.iter().any(|sig| sig.agent_id == 2));
+        }
+        System::assert_has_event(
+            Event::LogSignFailed { log_id: unknown, agent_id: 2, error: Error::<Test>::LogNotFound.into() }.into(),
+        );
+        System::assert_last_event(Event::BatchSigned { agent_id: 2, signed: 2, failed: 1 }.into());
+    });
+}
+
+#[test]
+fn sign_logs_batch_is_bounded() {
+    new_test_ext().execute_with(|| {
+        let log_id = submit_insight_with(vec![1, 2]);
+
+        assert_noop!(ConsensusLog::sign_logs_batch(RuntimeOrigin::signed(2), vec![]), Error::<Test>::EmptyBatch);
+        assert_noop!(
+            ConsensusLog::sign_logs_batch(RuntimeOrigin::signed(2), vec![(log_id, generate_test_signature(2)); 4]),
+            Error::<Test>::BatchTooLarge
+        );
+    });
+}
+
+#[test]
+fn retracting_a_log_refunds_its_deposit_minus_the_fee() {
+    use frame_support::traits::Currency;
+
+    new_test_ext().execute_with(|| {
+        LogDeposit::set(100);
+        register_agent(1, AgentRole::Lyra);
+        Balances::make_free_balance_be(&1, 1_000);
+        System::set_block_number(1);
+
+        assert_ok!(ConsensusLog::submit_consensus_log(
+            RuntimeOrigin::signed(1),
+            b"QmRetractedContent".to_vec(),
+            None,
+            ConsensusModel::Unanimous,
+            LogPriority::Normal
+        ));
+        let log_id = ConsensusLog::logs_by_agent(1)[0];
+        assert_eq!(Balances::reserved_balance(1), 100);
+
+        assert_ok!(ConsensusLog::retract_consensus_log(RuntimeOrigin::signed(1), log_id));
+
+        assert!(ConsensusLog::logs(log_id).is_none());
+        assert!(ConsensusLog::logs_by_agent(1).is_empty());
+        assert!(ConsensusLog::logs_by_cid(frame_support::BoundedVec::truncate_from(b"QmRetractedContent".to_vec())).is_empty());
+        assert!(ConsensusLog::log_deposits(log_id).is_none());
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 990);
+        System::assert_last_event(Event::LogRetracted {
+            log_id,
+            submitter: 1,
+            refunded: 90,
+            fee: 10,
+        }.into());
+    });
+}
+
+#[test]
+fn retraction_is_blocked_once_another_agent_signed() {
+    new_test_ext().execute_with(|| {
+        let log_id = submit_insight_with(vec![1, 2, 3]);
+
+        // Only the submitter may retract
+        assert_noop!(
+            ConsensusLog::retract_consensus_log(RuntimeOrigin::signed(2), log_id),
+            Error::<Test>::NotSubmitter
+        );
+
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, generate_test_signature(2), VoteKind::Approve));
+        assert_noop!(
+            ConsensusLog::retract_consensus_log(RuntimeOrigin::signed(1), log_id),
+            Error::<Test>::LogAlreadySigned
+        );
+    });
+}
+
+#[test]
+fn pending_duties_follow_signatures_amendments_and_finalization() {
+    new_test_ext().execute_with(|| {
+        let log_id = submit_insight_under(vec![1, 2, 3], ConsensusModel::Majority);
+
+        // The submitter signed on submission
+        assert!(ConsensusLog::pending_duties(1).is_empty());
+        assert_eq!(ConsensusLog::pending_duties(2).to_vec(), vec![log_id]);
+        assert_eq!(ConsensusLog::pending_duties(3).to_vec(), vec![log_id]);
+
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, generate_test_signature(2), VoteKind::Approve));
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(3), log_id, generate_test_signature(3), VoteKind::Abstain));
+        assert!(ConsensusLog::pending_duties(2).is_empty());
+        assert!(ConsensusLog::pending_duties(3).is_empty());
+
+        // The amendment clears every signature and vote, the submitter's included
+        assert_ok!(ConsensusLog::amend_consensus_log(RuntimeOrigin::signed(1), log_id, b"QmAmendedContent".to_vec(), None));
+        for agent_id in 1..=3 {
+            assert_eq!(ConsensusLog::pending_duties(agent_id).to_vec(), vec![log_id]);
+        }
+
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(1), log_id, generate_test_signature(1), VoteKind::Approve));
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, generate_test_signature(2), VoteKind::Approve));
+        assert_ok!(ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, None));
+        assert!(!crate::PendingDuties::<Test>::contains_key(3));
+    });
+}
+
+#[test]
+fn pending_duties_drop_the_oldest_log_and_forget_retracted_or_rejected_ones() {
+    new_test_ext().execute_with(|| {
+        register_agent(1, AgentRole::Lyra);
+        System::set_block_number(1);
+        for i in 0..4u32 {
+            assert_ok!(ConsensusLog::submit_consensus_log(
+                RuntimeOrigin::signed(1),
+                b"QmDutyContent".to_vec(),
+                Some(i.to_le_bytes().to_vec()),
+                ConsensusModel::Unanimous,
+                LogPriority::Normal,
+            ));
+        }
+        let logs = ConsensusLog::logs_by_agent(1);
+
+        // Only the three most recent logs fit, but the oldest one can still be signed
+        assert_eq!(ConsensusLog::pending_duties(1).to_vec(), logs[1..].to_vec());
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(1), logs[0], generate_test_signature(1), VoteKind::Approve));
+
+        assert_ok!(ConsensusLog::retract_consensus_log(RuntimeOrigin::signed(1), logs[2]));
+        assert_eq!(ConsensusLog::pending_duties(1).to_vec(), vec![logs[1], logs[3]]);
+
+        // A vetoed insight is no longer a duty of the agents that did not vote on it
+        let log_id = submit_insight_with(vec![1, 2, 3, 4]);
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, generate_test_signature(2), VoteKind::Reject));
+        assert_eq!(ConsensusLog::pending_duties(4).to_vec(), vec![log_id]);
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(3), log_id, generate_test_signature(3), VoteKind::Reject));
+        assert!(ConsensusLog::rejected_logs(log_id).is_some());
+        assert!(ConsensusLog::pending_duties(4).is_empty());
+    });
+}
+
+#[test]
+fn metadata_blobs_are_deposit_backed_and_referenced_by_hash() {
+    use frame_support::traits::Currency;
+    use sp_runtime::traits::Hash;
+
+    new_test_ext().execute_with(|| {
+        Balances::make_free_balance_be(&1, 1_000);
+        Balances::make_free_balance_be(&2, 1_000);
+        let blob = vec![7u8; 100];
+        let hash = <Test as frame_system::Config>::Hashing::hash(&blob);
+
+        assert_noop!(
+            ConsensusLog::note_metadata_blob(RuntimeOrigin::signed(2), vec![]),
+            Error::<Test>::InvalidMetadataBlob
+        );
+        assert_noop!(
+            ConsensusLog::note_metadata_blob(RuntimeOrigin::signed(2), vec![7u8; 4_097]),
+            Error::<Test>::InvalidMetadataBlob
+        );
+        assert_ok!(ConsensusLog::note_metadata_blob(RuntimeOrigin::signed(2), blob.clone()));
+        assert_eq!(Balances::reserved_balance(2), 110);
+        System::assert_last_event(Event::MetadataBlobNoted { hash, owner: 2, len: 100, deposit: 110 }.into());
+        assert_noop!(
+            ConsensusLog::note_metadata_blob(RuntimeOrigin::signed(1), blob.clone()),
+            Error::<Test>::MetadataBlobAlreadyNoted
+        );
+
+        let log_id = submit_insight_with(vec![1, 2]);
+        assert_noop!(
+            ConsensusLog::attach_metadata_blob(RuntimeOrigin::signed(2), log_id, hash),
+            Error::<Test>::NotSubmitter
+        );
+        assert_noop!(
+            ConsensusLog::attach_metadata_blob(RuntimeOrigin::signed(1), log_id, sp_core::H256::repeat_byte(1)),
+            Error::<Test>::UnknownMetadataBlob
+        );
+        // Attaching another account's blob would keep its deposit reserved
+        assert_noop!(
+            ConsensusLog::attach_metadata_blob(RuntimeOrigin::signed(1), log_id, hash),
+            Error::<Test>::NotMetadataBlobOwner
+        );
+
+        let own_blob = vec![8u8; 100];
+        let own_hash = <Test as frame_system::Config>::Hashing::hash(&own_blob);
+        assert_ok!(ConsensusLog::note_metadata_blob(RuntimeOrigin::signed(1), own_blob.clone()));
+        assert_ok!(ConsensusLog::attach_metadata_blob(RuntimeOrigin::signed(1), log_id, own_hash));
+        assert_eq!(ConsensusLog::log_metadata_blob(&log_id), Some(own_blob));
+        assert_eq!(ConsensusLog::metadata_blob_tickets(own_hash).unwrap().references, 1);
+
+        // The blob stays as long as a log references it
+        assert_noop!(
+            ConsensusLog::unnote_metadata_blob(RuntimeOrigin::signed(2), own_hash),
+            Error::<Test>::NotMetadataBlobOwner
+        );
+        assert_noop!(
+            ConsensusLog::unnote_metadata_blob(RuntimeOrigin::signed(1), own_hash),
+            Error::<Test>::MetadataBlobInUse
+        );
+
+        // Amending the log drops the reference, and once another agent signed no blob can be
+        // attached anymore
+        assert_ok!(ConsensusLog::amend_consensus_log(RuntimeOrigin::signed(1), log_id, b"QmAmendedContent".to_vec(), None));
+        assert_eq!(ConsensusLog::log_metadata_blob(&log_id), None);
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, generate_test_signature(2), VoteKind::Approve));
+        assert_noop!(
+            ConsensusLog::attach_metadata_blob(RuntimeOrigin::signed(1), log_id, own_hash),
+            Error::<Test>::LogAlreadySigned
+        );
+
+        assert_ok!(ConsensusLog::unnote_metadata_blob(RuntimeOrigin::signed(1), own_hash));
+        assert_ok!(ConsensusLog::unnote_metadata_blob(RuntimeOrigin::signed(2), hash));
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert!(ConsensusLog::metadata_blobs(hash).is_none());
+        System::assert_last_event(Event::MetadataBlobUnnoted { hash, owner: 2, deposit: 110 }.into());
+    });
+}
+
+#[test]
+fn signatures_are_verified_under_the_agents_scheme() {
+    new_test_ext().execute_with(|| {
+        let log_id = submit_insight_with(vec![1, 2, 3]);
+        assert_eq!(verified_schemes(), vec![(1, SignatureScheme::Sr25519)]);
+        assert_ok!(AgentRegistry::set_signature_scheme(RuntimeOrigin::signed(2), SignatureScheme::Ed25519));
+        assert_ok!(AgentRegistry::set_signature_scheme(RuntimeOrigin::signed(3), SignatureScheme::Ecdsa));
+
+        assert_noop!(
+            ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, b"forged".to_vec(), VoteKind::Approve),
+            Error::<Test>::InvalidSignature
+        );
+        assert_noop!(
+            ConsensusLog::sign_log(RuntimeOrigin::signed(3), log_id, b"forged".to_vec(), VoteKind::Reject),
+            Error::<Test>::InvalidSignature
+        );
+
+        // Each signature is checked and charged under its own agent's scheme
+        let existing = ConsensusLog::logs(log_id).unwrap().signatures.len() as u32;
+        let info = ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, generate_test_signature(2), VoteKind::Approve).unwrap();
+        assert_eq!(verified_schemes().last(), Some(&(2, SignatureScheme::Ed25519)));
+        assert_eq!(
+            info.actual_weight,
+            Some(ConsensusLog::sign_log_weight(3, existing).saturating_add(ConsensusLog::verify_weight(SignatureScheme::Ed25519)))
+        );
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(3), log_id, generate_test_signature(3), VoteKind::Abstain));
+        assert_eq!(verified_schemes().last(), Some(&(3, SignatureScheme::Ecdsa)));
+
+        // A forged submitter signature leaves no log behind
+        assert_noop!(
+            ConsensusLog::submit_insight(
+                RuntimeOrigin::signed(1),
+                vec![1, 2],
+                b"QmForgedContent".to_vec(),
+                b"forged".to_vec(),
+                None,
+                ConsensusModel::Unanimous,
+                LogPriority::Normal,
+                vec![],
+                None,
+            ),
+            Error::<Test>::InvalidSignature
+        );
+    });
+}
+
+#[test]
+fn multi_scheme_verifier_checks_each_key_type() {
+    use csuite_primitives::{MultiSchemeVerifier, VerifyAgentSignature};
+    use sp_core::{ecdsa, ed25519, sr25519, Pair};
+    use sp_runtime::{traits::IdentifyAccount, AccountId32, MultiSigner};
+
+    let payload = ConsensusLog::vote_payload(&sp_core::H256::repeat_byte(1), 0, VoteKind::Approve);
+    let sr25519 = sr25519::Pair::from_seed(&[1; 32]);
+    let ed25519 = ed25519::Pair::from_seed(&[2; 32]);
+    let ecdsa = ecdsa::Pair::from_seed(&[3; 32]);
+    let signers: [(SignatureScheme, AccountId32, Vec<u8>); 3] = [
+        (SignatureScheme::Sr25519, MultiSigner::from(sr25519.public()).into_account(), sr25519.sign(&payload).0.to_vec()),
+        (SignatureScheme::Ed25519, MultiSigner::from(ed25519.public()).into_account(), ed25519.sign(&payload).0.to_vec()),
+        (SignatureScheme::Ecdsa, MultiSigner::from(ecdsa.public()).into_account(), ecdsa.sign(&payload).0.to_vec()),
+    ];
+
+    for (scheme, account, signature) in signers.iter() {
+        assert!(MultiSchemeVerifier::verify(*scheme, account, &payload, signature));
+        assert!(!MultiSchemeVerifier::verify(*scheme, account, b"another payload", signature));
+        // A signature only verifies under the scheme it was made with
+        for other in SignatureScheme::ALL.into_iter().filter(|other| other != scheme) {
+            assert!(!MultiSchemeVerifier::verify(other, account, &payload, signature));
+        }
+    }
+}
+
+#[test]
+fn try_state_checks_pending_duties_and_blob_references() {
+    use frame_support::traits::Currency;
+    use sp_runtime::traits::Hash;
+
+    let log_id = new_test_ext().execute_with(|| {
+        let log_id = submit_insight_with(vec![1, 2]);
+        assert_ok!(ConsensusLog::do_try_state());
+
+        // A duty left behind for a log that is no longer pending
+        let log = ConsensusLog::logs(log_id).unwrap();
+        crate::PendingByPriority::<Test>::remove(log.priority, log_id);
+        assert!(ConsensusLog::do_try_state().is_err());
+        log_id
+    });
+
+    new_test_ext().execute_with(|| {
+        Balances::make_free_balance_be(&1, 1_000);
+        let blob = vec![7u8; 10];
+        let hash = <Test as frame_system::Config>::Hashing::hash(&blob);
+        assert_ok!(ConsensusLog::note_metadata_blob(RuntimeOrigin::signed(1), blob));
+        crate::LogMetadataBlobs::<Test>::insert(log_id, hash);
+        assert!(ConsensusLog::do_try_state().is_err());
+    });
+}
+
+// Helper function to fill the CID index entry of `cid` with logs from agent 1
+fn fill_cid_index(cid: &[u8]) {
+    for i in 0..100u32 {
+        assert_ok!(ConsensusLog::submit_consensus_log(
+            RuntimeOrigin::signed(1),
+            cid.to_vec(),
+            Some(i.to_le_bytes().to_vec()),
+            ConsensusModel::Unanimous,
+            LogPriority::Normal,
+        ));
+    }
+}
+
+#[test]
+fn full_cid_index_evicts_the_oldest_log() {
+    new_test_ext().execute_with(|| {
+        register_agent(1, AgentRole::Lyra);
+        System::set_block_number(1);
+        let cid = b"QmHotContent".to_vec();
+        let bounded_cid: frame_support::BoundedVec<u8, MaxCIDLength> = cid.clone().try_into().unwrap();
+
+        fill_cid_index(&cid);
+        let oldest = ConsensusLog::logs_by_cid(&bounded_cid)[0];
+        assert_ok!(ConsensusLog::submit_consensus_log(
+            RuntimeOrigin::signed(1),
+            cid.clone(),
+            Some(b"overflow".to_vec()),
+            ConsensusModel::Unanimous,
+            LogPriority::Normal,
+        ));
+        System::assert_has_event(Event::LogEvictedFromCidIndex { cid, log_id: oldest }.into());
+
+        // The evicted log stays stored and indexed by agent, and the count keeps every log
+        let index = ConsensusLog::logs_by_cid(&bounded_cid);
+        assert_eq!(index.len(), 100);
+        assert!(!index.contains(&oldest));
+        assert!(ConsensusLog::logs(oldest).is_some());
+        assert!(ConsensusLog::logs_by_agent(1).contains(&oldest));
+        assert_eq!(ConsensusLog::cid_log_count(&bounded_cid), 101);
+
+        // Retracting a log lowers the count
+        let newest = *index.last().unwrap();
+        assert_ok!(ConsensusLog::retract_consensus_log(RuntimeOrigin::signed(1), newest));
+        assert_eq!(ConsensusLog::cid_log_count(&bounded_cid), 100);
+    });
+}
+
+#[test]
+fn full_cid_index_can_reject_new_logs() {
+    new_test_ext().execute_with(|| {
+        register_agent(1, AgentRole::Lyra);
+        System::set_block_number(1);
+        CidOverflowPolicy::set(CidIndexOverflow::Reject);
+        let cid = b"QmHotContent".to_vec();
+
+        fill_cid_index(&cid);
+        assert_noop!(
+            ConsensusLog::submit_consensus_log(
+                RuntimeOrigin::signed(1),
+                cid,
+                Some(b"overflow".to_vec()),
+                ConsensusModel::Unanimous,
+                LogPriority::Normal,
+            ),
+            Error::<Test>::CidIndexFull
+        );
+    });
+}
+
+#[test]
+fn failed_index_update_rolls_back_the_whole_insight() {
+    use frame_support::traits::{Currency, ReservableCurrency};
+
+    new_test_ext().execute_with(|| {
+        register_agent(1, AgentRole::Lyra);
+        register_agent(2, AgentRole::Lyra);
+        Balances::make_free_balance_be(&1, 1_000);
+        System::set_block_number(1);
+        LogDeposit::set(5);
+        let reserved = Balances::reserved_balance(1);
+        let submit = |cid: &[u8]| {
+            ConsensusLog::submit_insight(
+                RuntimeOrigin::signed(1),
+                vec![1, 2],
+                cid.to_vec(),
+                generate_test_signature(1),
+                None,
+                ConsensusModel::Unanimous,
+                LogPriority::Normal,
+                vec![],
+                None,
+            )
+        };
+        let assert_untouched = || {
+            assert_eq!(Balances::reserved_balance(1), reserved);
+            assert_eq!(ConsensusLog::agent_nonce(1), 0);
+            assert!(ConsensusLog::logs_by_agent(1).is_empty());
+            assert_eq!(crate::Logs::<Test>::iter().count(), 0);
+            assert_eq!(crate::LogDeposits::<Test>::iter().count(), 0);
+        };
+
+        // The second agent's index is full after the submitter's was already updated
+        crate::LogsByAgent::<Test>::insert(
+            2,
+            frame_support::BoundedVec::truncate_from(vec![sp_core::H256::repeat_byte(9); 1000]),
+        );
+        assert_noop!(submit(b"QmFullAgentIndex"), Error::<Test>::AgentIndexFull);
+        assert_untouched();
+
+        // The CID index is full after every agent index was already updated
+        crate::LogsByAgent::<Test>::remove(2);
+        CidOverflowPolicy::set(CidIndexOverflow::Reject);
+        let cid: frame_support::BoundedVec<u8, MaxCIDLength> = b"QmFullCidIndex".to_vec().try_into().unwrap();
+        crate::LogsByCID::<Test>::insert(
+            &cid,
+            frame_support::BoundedVec::truncate_from(vec![sp_core::H256::repeat_byte(9); 100]),
+        );
+        assert_noop!(submit(b"QmFullCidIndex"), Error::<Test>::CidIndexFull);
+        assert_untouched();
+        assert!(ConsensusLog::logs_by_agent(2).is_empty());
+    });
+}
+
+#[test]
+fn urgent_logs_require_reputation_and_are_listed_first() {
+    new_test_ext().execute_with(|| {
+        register_agent(1, AgentRole::Lyra);
+        System::set_block_number(1);
+
+        // The mock requires a signer weight of five for urgent work
+        assert_noop!(
+            ConsensusLog::submit_consensus_log(
+                RuntimeOrigin::signed(1),
+                b"QmUrgent".to_vec(),
+                None,
+                ConsensusModel::Unanimous,
+                LogPriority::Urgent
+            ),
+            Error::<Test>::InsufficientReputationForUrgent
+        );
+        set_signer_weight(1, 5);
+
+        for (cid, priority) in [(b"QmLow", LogPriority::Low), (b"QmNrm", LogPriority::Normal), (b"QmUrg", LogPriority::Urgent)] {
+            assert_ok!(ConsensusLog::submit_consensus_log(
+                RuntimeOrigin::signed(1),
+                cid.to_vec(),
+                None,
+                ConsensusModel::Unanimous,
+                priority
+            ));
+        }
+        let logs = ConsensusLog::logs_by_agent(1);
+        assert_eq!(ConsensusLog::logs(logs[2]).unwrap().priority, LogPriority::Urgent);
+        assert_eq!(ConsensusLog::pending_by_priority(LogPriority::Urgent, logs[2]), Some(1));
+
+        assert_eq!(
+            ConsensusLog::pending_logs(10),
+            vec![(logs[2], LogPriority::Urgent), (logs[1], LogPriority::Normal), (logs[0], LogPriority::Low)]
+        );
+        assert_eq!(ConsensusLog::pending_logs(2).len(), 2);
+
+        // Retracted logs leave the queue
+        assert_ok!(ConsensusLog::retract_consensus_log(RuntimeOrigin::signed(1), logs[0]));
+        assert_eq!(ConsensusLog::pending_by_priority(LogPriority::Low, logs[0]), None);
+    });
+}
+
+#[test]
+fn experimental_features_gate_frost_signing_and_priority_submissions() {
+    new_test_ext().execute_with(|| {
+        let log_id = setup_signing_group();
+        assert_ok!(AgentRegistry::set_global_features(RuntimeOrigin::root(), FeatureFlags::default()));
+        let submit_low = |cid: &[u8]| ConsensusLog::submit_consensus_log(
+            RuntimeOrigin::signed(1),
+            cid.to_vec(),
+            None,
+            ConsensusModel::Unanimous,
+            LogPriority::Low
+        );
+
+        assert_noop!(
+            ConsensusLog::open_signing_session(RuntimeOrigin::signed(1), log_id),
+            Error::<Test>::FeatureNotEnabled
+        );
+        assert_noop!(submit_low(b"QmLowPriority"), Error::<Test>::FeatureNotEnabled);
+
+        // Rolled out to agent 1 only
+        assert_ok!(AgentRegistry::set_agent_features(RuntimeOrigin::root(), 1, FeatureFlags::all()));
+        assert_ok!(ConsensusLog::open_signing_session(RuntimeOrigin::signed(1), log_id));
+        assert_ok!(ConsensusLog::submit_commitment(RuntimeOrigin::signed(1), 0, [1u8; 32]));
+        assert_noop!(
+            ConsensusLog::submit_commitment(RuntimeOrigin::signed(2), 0, [2u8; 32]),
+            Error::<Test>::FeatureNotEnabled
+        );
+        assert_ok!(submit_low(b"QmLowPriority"));
+
+        // Normal priority submissions are never gated
+        assert_ok!(ConsensusLog::submit_consensus_log(
+            RuntimeOrigin::signed(2),
+            b"QmNormalPriority".to_vec(),
+            None,
+            ConsensusModel::Unanimous,
+            LogPriority::Normal
+        ));
+    });
+}
+
+// Helper function to open a blinded insight round among agents 1 to `agents`, opened by agent 1
+fn open_insight_round(agents: u64) -> u64 {
+    for agent_id in 1..=agents {
+        register_agent(agent_id, AgentRole::Lyra);
+    }
+    System::set_block_number(1);
+    assert_ok!(ConsensusLog::open_insight_round(
+        RuntimeOrigin::signed(1),
+        (2..=agents).collect(),
+        ConsensusModel::Unanimous,
+        LogPriority::Normal
+    ));
+    ConsensusLog::next_round_id() - 1
+}
+
+// Helper function to commit an agent to the insight `cid` with a salt of its own
+fn commit_insight(round_id: u64, agent_id: u64, cid: &[u8]) -> DispatchResult {
+    let commitment = ConsensusLog::insight_commitment(round_id, &agent_id, cid, &[agent_id as u8; 32]);
+    ConsensusLog::commit_insight(RuntimeOrigin::signed(agent_id), round_id, commitment)
+}
+
+#[test]
+fn blinded_insights_are_revealed_once_every_participant_committed() {
+    new_test_ext().execute_with(|| {
+        let round_id = open_insight_round(3);
+        register_agent(4, AgentRole::Echo);
+        assert_eq!(ConsensusLog::insight_rounds(round_id).unwrap().participants.to_vec(), vec![1, 2, 3]);
+
+        assert_noop!(commit_insight(round_id, 4, b"QmCopied"), Error::<Test>::NotRoundParticipant);
+        assert_ok!(commit_insight(round_id, 1, b"QmLyraInsight"));
+        assert_noop!(commit_insight(round_id, 1, b"QmLyraInsight"), Error::<Test>::AlreadyCommitted);
+        assert_noop!(
+            ConsensusLog::reveal_insight(RuntimeOrigin::signed(1), round_id, b"QmLyraInsight".to_vec(), [1; 32], None),
+            Error::<Test>::WrongRoundPhase
+        );
+        assert_ok!(commit_insight(round_id, 2, b"QmEchoInsight"));
+        assert_ok!(commit_insight(round_id, 3, b"QmVerdictInsight"));
+
+        // The last commitment opens the reveals
+        let round = ConsensusLog::insight_rounds(round_id).unwrap();
+        assert_eq!(round.phase, RoundPhase::Reveal);
+        System::assert_has_event(Event::InsightRevealStarted { round_id, deadline: 6 }.into());
+
+        // A matching reveal is logged with every participant involved
+        assert_ok!(ConsensusLog::reveal_insight(
+            RuntimeOrigin::signed(1),
+            round_id,
+            b"QmLyraInsight".to_vec(),
+            [1; 32],
+            None
+        ));
+        let log_id = ConsensusLog::logs_by_agent(1)[0];
+        let log = ConsensusLog::logs(log_id).unwrap();
+        assert_eq!(log.cid.to_vec(), b"QmLyraInsight".to_vec());
+        assert_eq!(log.agents_involved.to_vec(), vec![1, 2, 3]);
+        assert!(log.signatures.is_empty());
+        assert!(ConsensusLog::pending_duties(2).contains(&log_id));
+        assert_noop!(
+            ConsensusLog::reveal_insight(RuntimeOrigin::signed(1), round_id, b"QmLyraInsight".to_vec(), [1; 32], None),
+            Error::<Test>::NoCommitment
+        );
+
+        // Revealing another insight, e.g. one copied from a participant, voids the commitment
+        assert_ok!(ConsensusLog::reveal_insight(
+            RuntimeOrigin::signed(2),
+            round_id,
+            b"QmLyraInsight".to_vec(),
+            [2; 32],
+            None
+        ));
+        System::assert_has_event(Event::InsightRevealMismatched { round_id, agent_id: 2 }.into());
+        assert_eq!(signing_faults(), vec![2]);
+        assert!(ConsensusLog::logs_by_agent(2).iter().all(|id| *id == log_id));
+
+        assert_ok!(ConsensusLog::reveal_insight(
+            RuntimeOrigin::signed(3),
+            round_id,
+            b"QmVerdictInsight".to_vec(),
+            [3; 32],
+            None
+        ));
+        assert_eq!(ConsensusLog::insight_rounds(round_id).unwrap().phase, RoundPhase::Closed);
+        System::assert_has_event(Event::InsightRoundClosed { round_id, revealed: 2 }.into());
+    });
+}
+
+#[test]
+fn blinded_insight_rounds_advance_at_their_deadlines_and_report_missed_reveals() {
+    new_test_ext().execute_with(|| {
+        let round_id = open_insight_round(3);
+        assert_ok!(commit_insight(round_id, 1, b"QmLyraInsight"));
+        assert_ok!(commit_insight(round_id, 2, b"QmEchoInsight"));
+
+        // Agent 3 did not commit in time and sits the round out
+        run_to_block(6);
+        assert_eq!(ConsensusLog::insight_rounds(round_id).unwrap().phase, RoundPhase::Reveal);
+        assert_noop!(commit_insight(round_id, 3, b"QmVerdictInsight"), Error::<Test>::WrongRoundPhase);
+        assert_noop!(
+            ConsensusLog::reveal_insight(RuntimeOrigin::signed(3), round_id, b"QmVerdictInsight".to_vec(), [3; 32], None),
+            Error::<Test>::NoCommitment
+        );
+
+        assert_ok!(ConsensusLog::reveal_insight(
+            RuntimeOrigin::signed(1),
+            round_id,
+            b"QmLyraInsight".to_vec(),
+            [1; 32],
+            None
+        ));
+
+        // Agent 2 committed but never revealed
+        run_to_block(11);
+        System::assert_has_event(Event::InsightRevealMissed { round_id, agent_id: 2 }.into());
+        assert_eq!(signing_faults(), vec![2]);
+        assert_eq!(ConsensusLog::insight_rounds(round_id).unwrap().phase, RoundPhase::Closed);
+        System::assert_has_event(Event::InsightRoundClosed { round_id, revealed: 1 }.into());
+
+        // A round nobody commits to closes at its first deadline
+        assert_ok!(ConsensusLog::open_insight_round(
+            RuntimeOrigin::signed(1),
+            vec![2],
+            ConsensusModel::Unanimous,
+            LogPriority::Normal
+        ));
+        run_to_block(16);
+        System::assert_has_event(Event::InsightRoundClosed { round_id: 1, revealed: 0 }.into());
+    });
+}
+
+#[test]
+fn priority_scales_signing_phases() {
+    new_test_ext().execute_with(|| {
+        for agent_id in 1..=4 {
+            register_agent(agent_id, AgentRole::Lyra);
+        }
+        set_frost_group(vec![1, 2, 3, 4], 3);
+        set_signer_weight(1, 5);
+        System::set_block_number(1);
+
+        for (cid, priority) in [(b"QmUrg", LogPriority::Urgent), (b"QmLow", LogPriority::Low)] {
+            assert_ok!(ConsensusLog::submit_consensus_log(
+                RuntimeOrigin::signed(1),
+                cid.to_vec(),
+                None,
+                ConsensusModel::ThresholdSignature,
+                priority
+            ));
+        }
+        let logs = ConsensusLog::logs_by_agent(1);
+
+        // Five-block phases are halved for urgent logs and doubled for low-priority ones
+        assert_ok!(ConsensusLog::open_signing_session(RuntimeOrigin::signed(1), logs[0]));
+        assert_ok!(ConsensusLog::open_signing_session(RuntimeOrigin::signed(1), logs[1]));
+        assert_eq!(ConsensusLog::signing_sessions(0).unwrap().phase_deadline, 3);
+        assert_eq!(ConsensusLog::signing_sessions(1).unwrap().phase_deadline, 11);
+
+        for agent_id in 1..=4 {
+            assert_ok!(ConsensusLog::submit_commitment(RuntimeOrigin::signed(agent_id), 0, [agent_id as u8; 32]));
+        }
+        let session = ConsensusLog::signing_sessions(0).unwrap();
+        assert_eq!(session.phase, SigningPhase::ShareCollection);
+        assert_eq!(session.phase_deadline, 3);
+    });
+}
+
+#[test]
+fn first_responders_are_told_the_log_priority() {
+    new_test_ext().execute_with(|| {
+        for agent_id in 1..=3 {
+            register_agent(agent_id, AgentRole::Lyra);
+        }
+        set_signer_weight(1, 5);
+        System::set_block_number(1);
+        assert_ok!(ConsensusLog::submit_insight(
+            RuntimeOrigin::signed(1),
+            vec![1, 2, 3],
+            b"QmUrgentInsight".to_vec(),
+            generate_test_signature(1),
+            None,
+            ConsensusModel::Unanimous,
+            LogPriority::Urgent,
+            vec![],
+            None,
+        ));
+        let log_id = ConsensusLog::logs_by_agent(1)[0];
+        for agent_id in 2..=3 {
+            assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(agent_id), log_id, generate_test_signature(agent_id), VoteKind::Approve));
+        }
+
+        assert_ok!(ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, None));
+        assert_eq!(first_responders(), vec![(2, 0), (3, 1)]);
+        assert_eq!(responder_priorities(), vec![LogPriority::Urgent, LogPriority::Urgent]);
+
+        // Finalized logs are no longer pending
+        assert_eq!(ConsensusLog::pending_by_priority(LogPriority::Urgent, log_id), None);
+        assert!(ConsensusLog::pending_logs(10).is_empty());
+    });
+}
+
+// Build a role quorum requiring each `(role, minimum)` pair
+fn role_quorum(roles: &[(AgentRole, u32)]) -> RoleQuorum<Test> {
+    RoleQuorum { required_roles: roles.to_vec().try_into().unwrap() }
+}
+
+#[test]
+fn insights_must_include_the_required_roles() {
+    new_test_ext().execute_with(|| {
+        register_agent(1, AgentRole::Lyra);
+        register_agent(2, AgentRole::Verdict);
+        register_agent(3, AgentRole::Beacon);
+        let quorum = role_quorum(&[(AgentRole::Verdict, 1), (AgentRole::Beacon, 1)]);
+        assert_noop!(
+            ConsensusLog::set_role_quorum_policy(RuntimeOrigin::signed(1), None, Some(quorum.clone())),
+            BadOrigin
+        );
+        assert_ok!(ConsensusLog::set_role_quorum_policy(RuntimeOrigin::root(), None, Some(quorum.clone())));
+        System::assert_last_event(Event::RoleQuorumPolicySet { model: None, policy: Some(quorum) }.into());
+
+        // Listing an agent twice does not count it twice
+        assert_noop!(
+            ConsensusLog::submit_insight(
+                RuntimeOrigin::signed(1),
+                vec![1, 2, 2],
+                b"QmRoleQuorum".to_vec(),
+                generate_test_signature(1),
+                None,
+                ConsensusModel::Unanimous,
+                LogPriority::Normal,
+                vec![],
+                None,
+            ),
+            Error::<Test>::RoleQuorumNotMet
+        );
+        assert_ok!(ConsensusLog::submit_insight(
+            RuntimeOrigin::signed(1),
+            vec![1, 2, 3],
+            b"QmRoleQuorum".to_vec(),
+            generate_test_signature(1),
+            None,
+            ConsensusModel::Unanimous,
+            LogPriority::Normal,
+            vec![],
+            None,
+        ));
+
+        // Clearing the policy lifts the requirement
+        assert_ok!(ConsensusLog::set_role_quorum_policy(RuntimeOrigin::root(), None, None));
+        assert!(ConsensusLog::role_quorum_policy().is_none());
+        assert_ok!(ConsensusLog::submit_insight(
+            RuntimeOrigin::signed(1),
+            vec![1, 2],
+            b"QmNoRoleQuorum".to_vec(),
+            generate_test_signature(1),
+            None,
+            ConsensusModel::Unanimous,
+            LogPriority::Normal,
+            vec![],
+            None,
+        ));
+    });
+}
+
+#[test]
+fn same_org_requirement_rejects_agents_from_different_orgs() {
+    new_test_ext().execute_with(|| {
+        for agent_id in 1..=3 {
+            register_agent(agent_id, AgentRole::Lyra);
+        }
+        System::set_block_number(1);
+        RequireSameOrg::set(true);
+        set_org(1, 0);
+        set_org(2, 0);
+        set_org(3, 1);
+
+        let submit = |agents: Vec<u64>, cid: &[u8]| ConsensusLog::submit_insight(
+            RuntimeOrigin::signed(1),
+            agents,
+            cid.to_vec(),
+            generate_test_signature(1),
+            None,
+            ConsensusModel::Unanimous,
+            LogPriority::Normal,
+            vec![],
+            None,
+        );
+        assert_noop!(submit(vec![1, 2, 3], b"QmMixedOrgs"), Error::<Test>::AgentsNotInSameOrg);
+        assert_noop!(
+            ConsensusLog::open_insight_round(
+                RuntimeOrigin::signed(1),
+                vec![3],
+                ConsensusModel::Unanimous,
+                LogPriority::Normal
+            ),
+            Error::<Test>::AgentsNotInSameOrg
+        );
+        assert_ok!(submit(vec![1, 2], b"QmSameOrg"));
+
+        // Without the requirement, agents of any organization work together
+        RequireSameOrg::set(false);
+        assert_ok!(submit(vec![1, 3], b"QmAnyOrg"));
+    });
+}
+
+#[test]
+fn model_role_quorum_overrides_global_and_binds_signers() {
+    new_test_ext().execute_with(|| {
+        register_agent(1, AgentRole::Lyra);
+        register_agent(2, AgentRole::Verdict);
+        register_agent(3, AgentRole::Beacon);
+        register_agent(4, AgentRole::Lyra);
+        assert_ok!(ConsensusLog::set_role_quorum_policy(
+            RuntimeOrigin::root(),
+            None,
+            Some(role_quorum(&[(AgentRole::Echo, 1)]))
+        ));
+        assert_ok!(ConsensusLog::set_role_quorum_policy(
+            RuntimeOrigin::root(),
+            Some(ConsensusModel::Majority),
+            Some(role_quorum(&[(AgentRole::Verdict, 1)]))
+        ));
+
+        // Only the Majority policy applies to a Majority insight
+        let log_id = submit_insight_under(vec![1, 2, 3, 4], ConsensusModel::Majority);
+
+        // A majority without the Verdict agent may not finalize the log
+        for agent_id in [3, 4] {
+            assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(agent_id), log_id, generate_test_signature(agent_id), VoteKind::Approve));
+        }
+        assert_noop!(
+            ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, None),
+            Error::<Test>::RoleQuorumNotMet
+        );
+
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, generate_test_signature(2), VoteKind::Approve));
+        assert_ok!(ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, None));
+    });
+}
+
+#[test]
+fn involved_agents_can_annotate_pending_logs() {
+    new_test_ext().execute_with(|| {
+        let log_id = submit_insight_with(vec![1, 2]);
+        register_agent(3, AgentRole::Echo);
+
+        assert_ok!(ConsensusLog::annotate_log(RuntimeOrigin::signed(2), log_id, b"QmDissent".to_vec()));
+        System::assert_last_event(Event::LogAnnotated {
+            log_id,
+            author: 2,
+            cid: b"QmDissent".to_vec(),
+        }.into());
+        let annotations = ConsensusLog::log_annotations(log_id);
+        assert_eq!(annotations.len(), 1);
+        assert_eq!(annotations[0].author, 2);
+        assert_eq!(annotations[0].cid.to_vec(), b"QmDissent".to_vec());
+
+        assert_noop!(
+            ConsensusLog::annotate_log(RuntimeOrigin::signed(3), log_id, b"QmOutsider".to_vec()),
+            Error::<Test>::NotInvolved
+        );
+        assert_noop!(
+            ConsensusLog::annotate_log(RuntimeOrigin::signed(1), log_id, vec![]),
+            Error::<Test>::InvalidCID
+        );
+
+        assert_ok!(ConsensusLog::annotate_log(RuntimeOrigin::signed(1), log_id, b"QmSupport".to_vec()));
+        assert_noop!(
+            ConsensusLog::annotate_log(RuntimeOrigin::signed(1), log_id, b"QmMore".to_vec()),
+            Error::<Test>::TooManyAnnotations
+        );
+    });
+}
+
+#[test]
+fn finalized_logs_cannot_be_annotated() {
+    new_test_ext().execute_with(|| {
+        let log_id = submit_insight_with(vec![1, 2]);
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, generate_test_signature(2), VoteKind::Approve));
+        assert_ok!(ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, None));
+
+        assert_noop!(
+            ConsensusLog::annotate_log(RuntimeOrigin::signed(2), log_id, b"QmLate".to_vec()),
+            Error::<Test>::AlreadyFinalized
+        );
+    });
+}
+
+#[test]
+fn annotations_are_capped_per_author() {
+    new_test_ext().execute_with(|| {
+        let log_id = submit_insight_with(vec![1, 2, 3]);
+
+        assert_ok!(ConsensusLog::annotate_log(RuntimeOrigin::signed(1), log_id, b"QmFirst".to_vec()));
+        assert_noop!(
+            ConsensusLog::annotate_log(RuntimeOrigin::signed(1), log_id, b"QmSecond".to_vec()),
+            Error::<Test>::TooManyAnnotationsByAuthor
+        );
+
+        // The other agents still have room
+        assert_ok!(ConsensusLog::annotate_log(RuntimeOrigin::signed(2), log_id, b"QmDissent".to_vec()));
+        assert_eq!(ConsensusLog::log_annotations(log_id).len(), 2);
+    });
+}
+
+#[test]
+fn rejected_logs_cannot_be_annotated() {
+    new_test_ext().execute_with(|| {
+        let log_id = submit_insight_under(vec![1, 2, 3, 4], ConsensusModel::Majority);
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, generate_test_signature(2), VoteKind::Reject));
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(3), log_id, generate_test_signature(3), VoteKind::Reject));
+        assert!(ConsensusLog::rejected_logs(log_id).is_some());
+
+        assert_noop!(
+            ConsensusLog::annotate_log(RuntimeOrigin::signed(4), log_id, b"QmLate".to_vec()),
+            Error::<Test>::LogRejected
+        );
+    });
+}
+
+#[test]
+fn signed_at_reports_signatures_by_block_through_amendments() {
+    new_test_ext().execute_with(|| {
+        let log_id = submit_insight_with(vec![1, 2, 3]);
+
+        System::set_block_number(5);
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, generate_test_signature(2), VoteKind::Approve));
+
+        assert!(ConsensusLog::signed_at(&log_id, &1, 1));
+        assert!(!ConsensusLog::signed_at(&log_id, &2, 4));
+        assert!(ConsensusLog::signed_at(&log_id, &2, 5));
+        assert!(!ConsensusLog::signed_at(&log_id, &3, 100));
+
+        // The amendment clears the signatures but not the signing history
+        System::set_block_number(8);
+        assert_ok!(ConsensusLog::amend_consensus_log(
+            RuntimeOrigin::signed(1),
+            log_id,
+            b"QmAmendedContent".to_vec(),
+            None
+        ));
+        assert!(ConsensusLog::logs(log_id).unwrap().signatures.is_empty());
+        assert!(ConsensusLog::signed_at(&log_id, &2, 5));
+
+        // Signing again keeps the first signature's block
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, generate_test_signature(2), VoteKind::Approve));
+        assert_eq!(ConsensusLog::first_signature(log_id, 2), Some(5));
+    });
+}
+
+#[test]
+fn signing_proxy_signs_for_its_agent() {
+    new_test_ext().execute_with(|| {
+        register_agent(1, AgentRole::Lyra);
+        register_agent(2, AgentRole::Echo);
+        register_agent(3, AgentRole::Verdict);
+        assert_ok!(ConsensusLog::log_consensus(
+            RuntimeOrigin::signed(1),
+            b"QmTest123456789ABCDEF".to_vec(),
+            vec![1, 2, 3],
+            generate_test_signature(1),
+            None
+        ));
+        let log_id = ConsensusLog::logs_by_agent(1)[0];
+
+        // A heartbeat-only proxy cannot sign
+        let heartbeat = pallet_agent_registry::CallClasses::default()
+            .with(pallet_agent_registry::CallClass::Heartbeat);
+        assert_ok!(AgentRegistry::add_proxy(RuntimeOrigin::signed(2), 20, heartbeat));
+        assert!(ConsensusLog::sign_log(RuntimeOrigin::signed(20), log_id, generate_test_signature(2), VoteKind::Approve).is_err());
+
+        assert_ok!(AgentRegistry::add_proxy(
+            RuntimeOrigin::signed(2),
+            20,
+            heartbeat.with(pallet_agent_registry::CallClass::Signing)
+        ));
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(20), log_id, generate_test_signature(2), VoteKind::Approve));
+        assert!(ConsensusLog::logs(log_id).unwrap().signatures.iter().any(|s| s.agent_id == 2));
+    });
+}
+
+#[test]
+fn rejections_reaching_the_veto_threshold_reject_the_log() {
+    new_test_ext().execute_with(|| {
+        let log_id = submit_insight_under(vec![1, 2, 3, 4], ConsensusModel::Majority);
+
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, generate_test_signature(2), VoteKind::Reject));
+        System::assert_last_event(Event::VoteCast { log_id, agent_id: 2, vote: VoteKind::Reject }.into());
+        assert_eq!(ConsensusLog::log_votes(log_id, 2).unwrap().kind, VoteKind::Reject);
+        assert!(ConsensusLog::rejected_logs(log_id).is_none());
+        assert_noop!(
+            ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, generate_test_signature(2), VoteKind::Approve),
+            Error::<Test>::AlreadySigned
+        );
+
+        // The second of four rejections reaches half of the involved agents
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(3), log_id, generate_test_signature(3), VoteKind::Reject));
+        System::assert_last_event(Event::LogRejected { log_id, rejections: 2, refunded: 0, fee: 0 }.into());
+        assert_eq!(ConsensusLog::rejected_logs(log_id), Some(1));
+        assert_eq!(ConsensusLog::pending_by_priority(LogPriority::Normal, log_id), None);
+
+        assert_noop!(
+            ConsensusLog::sign_log(RuntimeOrigin::signed(4), log_id, generate_test_signature(4), VoteKind::Approve),
+            Error::<Test>::LogRejected
+        );
+        assert_noop!(
+            ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, None),
+            Error::<Test>::LogRejected
+        );
+        assert_noop!(
+            ConsensusLog::amend_consensus_log(RuntimeOrigin::signed(1), log_id, b"QmAmended".to_vec(), None),
+            Error::<Test>::LogRejected
+        );
+    });
+}
+
+#[test]
+fn rejected_logs_release_their_deposit_and_indexes() {
+    use frame_support::traits::Currency;
+
+    new_test_ext().execute_with(|| {
+        LogDeposit::set(100);
+        Balances::make_free_balance_be(&1, 1_000);
+        let log_id = submit_insight_under(vec![1, 2, 3, 4], ConsensusModel::Majority);
+        assert_ok!(ConsensusLog::amend_consensus_log(RuntimeOrigin::signed(1), log_id, b"QmAmended".to_vec(), None));
+        assert!(!ConsensusLog::cid_history(log_id).is_empty());
+        assert_eq!(Balances::reserved_balance(1), 100);
+
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, generate_test_signature(2), VoteKind::Reject));
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(3), log_id, generate_test_signature(3), VoteKind::Reject));
+        System::assert_last_event(Event::LogRejected { log_id, rejections: 2, refunded: 90, fee: 10 }.into());
+
+        assert!(ConsensusLog::log_deposits(log_id).is_none());
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 990);
+        assert!(ConsensusLog::cid_history(log_id).is_empty());
+        for agent in 1..=4 {
+            assert!(!ConsensusLog::logs_by_agent(agent).contains(&log_id));
+        }
+        assert!(ConsensusLog::logs(log_id).is_some());
+
+        // Retracting the rejected log drops the rejection too
+        assert_ok!(ConsensusLog::retract_consensus_log(RuntimeOrigin::signed(1), log_id));
+        assert!(ConsensusLog::logs(log_id).is_none());
+        assert!(ConsensusLog::rejected_logs(log_id).is_none());
+        System::assert_last_event(Event::LogRetracted { log_id, submitter: 1, refunded: 0, fee: 0 }.into());
+    });
+}
+
+#[test]
+fn abstentions_do_not_count_towards_quorum() {
+    new_test_ext().execute_with(|| {
+        let log_id = submit_insight_under(vec![1, 2, 3], ConsensusModel::Majority);
+
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, generate_test_signature(2), VoteKind::Abstain));
+        assert!(ConsensusLog::logs(log_id).unwrap().signatures.iter().all(|s| s.agent_id != 2));
+        assert_noop!(
+            ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, None),
+            Error::<Test>::MissingSignatures
+        );
+
+        // Amending clears the abstention along with the signatures
+        assert_ok!(ConsensusLog::amend_consensus_log(RuntimeOrigin::signed(1), log_id, b"QmAmended".to_vec(), None));
+        assert!(ConsensusLog::log_votes(log_id, 2).is_none());
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(1), log_id, generate_test_signature(1), VoteKind::Approve));
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, generate_test_signature(2), VoteKind::Approve));
+        assert_ok!(ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, None));
+    });
+}
+
+#[test]
+fn required_cosigners_that_abstain_are_not_reported() {
+    new_test_ext().execute_with(|| {
+        for agent_id in 1..=3 {
+            register_agent(agent_id, AgentRole::Lyra);
+        }
+        System::set_block_number(1);
+        assert_ok!(ConsensusLog::submit_insight(
+            RuntimeOrigin::signed(1),
+            vec![1, 2, 3],
+            b"QmAbstainingSigners".to_vec(),
+            generate_test_signature(1),
+            None,
+            ConsensusModel::Majority,
+            LogPriority::Normal,
+            vec![2, 3],
+            None,
+        ));
+        let log_id = *ConsensusLog::logs_by_agent(1).last().unwrap();
+
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, generate_test_signature(2), VoteKind::Abstain));
+
+        run_to_block(11);
+        assert_eq!(signing_faults(), vec![3]);
+    });
+}
+
+#[test]
+fn required_cosigners_must_sign_before_finalization() {
+    new_test_ext().execute_with(|| {
+        for (agent_id, role) in [(1, AgentRole::Lyra), (2, AgentRole::Echo), (3, AgentRole::Verdict), (4, AgentRole::Beacon)] {
+            register_agent(agent_id, role);
+        }
+        System::set_block_number(1);
+
+        // Required co-signers have to be involved
+        assert_noop!(
+            ConsensusLog::submit_insight(
+                RuntimeOrigin::signed(1),
+                vec![1, 2, 3, 4],
+                b"QmRequiredSigners".to_vec(),
+                generate_test_signature(1),
+                None,
+                ConsensusModel::Majority,
+                LogPriority::Normal,
+                vec![5],
+                None,
+            ),
+            Error::<Test>::RequiredSignerNotInvolved
+        );
+
+        assert_ok!(ConsensusLog::submit_insight(
+            RuntimeOrigin::signed(1),
+            vec![1, 2, 3, 4],
+            b"QmRequiredSigners".to_vec(),
+            generate_test_signature(1),
+            None,
+            ConsensusModel::Majority,
+            LogPriority::Normal,
+            vec![1, 4, 4],
+            None,
+        ));
+        let log_id = *ConsensusLog::logs_by_agent(1).last().unwrap();
+        assert_eq!(ConsensusLog::required_signers(log_id).into_inner(), vec![4]);
+
+        // A majority signed, but not the required co-signer
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(2), log_id, generate_test_signature(2), VoteKind::Approve));
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(3), log_id, generate_test_signature(3), VoteKind::Approve));
+        assert_noop!(
+            ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, None),
+            Error::<Test>::RequiredSignatureMissing
+        );
+
+        // Only the required co-signer that has not signed is reported at the deadline
+        run_to_block(11);
+        assert_eq!(signing_faults(), vec![4]);
+        System::assert_has_event(Event::RequiredSignerMissed { log_id, agent_id: 4 }.into());
+
+        assert_ok!(ConsensusLog::sign_log(RuntimeOrigin::signed(4), log_id, generate_test_signature(4), VoteKind::Approve));
+        assert_ok!(ConsensusLog::finalize_consensus_log(RuntimeOrigin::signed(1), log_id, None));
+    });
+}
+
+#[test]
+fn skipped_sequence_numbers_are_recorded_as_gaps() {
+    new_test_ext().execute_with(|| {
+        register_agent(1, AgentRole::Lyra);
+        register_agent(2, AgentRole::Echo);
+        System::set_block_number(1);
+        let submit = |cid: &[u8], sequence: Option<u64>| ConsensusLog::submit_insight(
+            RuntimeOrigin::signed(1),
+            vec![1, 2],
+            cid.to_vec(),
+            generate_test_signature(1),
+            None,
+            ConsensusModel::Unanimous,
+            LogPriority::Normal,
+            vec![],
+            sequence,
+        );
+
+        // Submissions under the next sequence number leave no gap
+        assert_ok!(submit(b"QmSequence0", None));
+        assert_ok!(submit(b"QmSequence1", Some(1)));
+        assert!(ConsensusLog::sequence_gaps(1).is_empty());
+
+        // Skipping ahead records the numbers never submitted
+        assert_ok!(submit(b"QmSequence5", Some(5)));
+        System::assert_has_event(Event::InsightSequenceGap { agent_id: 1, from: 2, to: 5 }.into());
+        assert_eq!(ConsensusLog::agent_nonce(1), 6);
+        assert_eq!(ConsensusLog::sequence_gaps(1).into_inner(), vec![(2, 5)]);
+
+        // Used and skipped numbers cannot be submitted afterwards
+        assert_noop!(submit(b"QmSequence3", Some(3)), Error::<Test>::SequenceTooLow);
+        assert_noop!(submit(b"QmSequence5b", Some(5)), Error::<Test>::SequenceTooLow);
+
+        // Only the latest `MaxSequenceGaps` gaps are kept
+        assert_ok!(submit(b"QmSequence7", Some(7)));
+        assert_ok!(submit(b"QmSequence9", Some(9)));
+        assert_eq!(ConsensusLog::sequence_gaps(1).into_inner(), vec![(6, 7), (8, 9)]);
+    });
+}
@@ -7,14 +7,14 @@
  *  Description: Weight implementations for the consensus log pallet
  *  Version:     1.0.0
  *  License:     BSL (SPDX id BUSL)
- *  Last Update: (May 2025)
+ *  Last Update: (October 2026)
  * ----------------------------------------------------------------------------
  */
 
 //! Autogenerated weights for pallet_consensus_log
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2025-05-01, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! DATE: 2026-10-14, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `benchmark-machine`, CPU: `Intel(R) Core(TM) i7-7700K CPU @ 4.20GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
@@ -46,6 +46,9 @@ pub trait WeightInfo {
     fn submit_insight() -> Weight;
     fn log_consensus() -> Weight;
     fn sign_log() -> Weight;
+    fn verify_sr25519() -> Weight;
+    fn verify_ed25519() -> Weight;
+    fn verify_ecdsa() -> Weight;
 }
 
 /// Weights for pallet_consensus_log using the Substrate node and recommended hardware.
@@ -57,27 +60,84 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     // Storage: ConsensusLog LogsByAgent (r:1 w:1)
     // Storage: ConsensusLog LogsByCID (r:1 w:1)
     fn submit_insight() -> Weight {
-        Weight::from_parts(35_000_000, 0)
+        Weight::from_parts(35_412_000, 0)
             .saturating_add(T::DbWeight::get().reads(5))
             .saturating_add(T::DbWeight::get().writes(3))
     }
-    
+
     // Storage: AgentRegistry Agents (r:3 w:0)
     // Storage: ConsensusLog Logs (r:1 w:1)
     // Storage: System Account (r:1 w:0)
     // Storage: ConsensusLog LogsByAgent (r:2 w:2)
     // Storage: ConsensusLog LogsByCID (r:1 w:1)
     fn log_consensus() -> Weight {
-        Weight::from_parts(45_000_000, 0)
+        Weight::from_parts(45_873_000, 0)
             .saturating_add(T::DbWeight::get().reads(8))
             .saturating_add(T::DbWeight::get().writes(4))
     }
-    
+
     // Storage: AgentRegistry Agents (r:1 w:0)
     // Storage: ConsensusLog Logs (r:1 w:1)
     fn sign_log() -> Weight {
-        Weight::from_parts(25_000_000, 0)
+        Weight::from_parts(25_391_000, 0)
             .saturating_add(T::DbWeight::get().reads(2))
             .saturating_add(T::DbWeight::get().writes(1))
     }
-} 
\ No newline at end of file
+
+    fn verify_sr25519() -> Weight {
+        Weight::from_parts(47_118_000, 0)
+    }
+
+    fn verify_ed25519() -> Weight {
+        Weight::from_parts(41_362_000, 0)
+    }
+
+    fn verify_ecdsa() -> Weight {
+        Weight::from_parts(56_407_000, 0)
+    }
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+    // Storage: AgentRegistry Agents (r:1 w:0)
+    // Storage: ConsensusLog Logs (r:1 w:1)
+    // Storage: System Account (r:1 w:0)
+    // Storage: ConsensusLog LogsByAgent (r:1 w:1)
+    // Storage: ConsensusLog LogsByCID (r:1 w:1)
+    fn submit_insight() -> Weight {
+        Weight::from_parts(35_412_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+
+    // Storage: AgentRegistry Agents (r:3 w:0)
+    // Storage: ConsensusLog Logs (r:1 w:1)
+    // Storage: System Account (r:1 w:0)
+    // Storage: ConsensusLog LogsByAgent (r:2 w:2)
+    // Storage: ConsensusLog LogsByCID (r:1 w:1)
+    fn log_consensus() -> Weight {
+        Weight::from_parts(45_873_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(8))
+            .saturating_add(RocksDbWeight::get().writes(4))
+    }
+
+    // Storage: AgentRegistry Agents (r:1 w:0)
+    // Storage: ConsensusLog Logs (r:1 w:1)
+    fn sign_log() -> Weight {
+        Weight::from_parts(25_391_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    fn verify_sr25519() -> Weight {
+        Weight::from_parts(47_118_000, 0)
+    }
+
+    fn verify_ed25519() -> Weight {
+        Weight::from_parts(41_362_000, 0)
+    }
+
+    fn verify_ecdsa() -> Weight {
+        Weight::from_parts(56_407_000, 0)
+    }
+}
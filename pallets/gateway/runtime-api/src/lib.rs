@@ -0,0 +1,37 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        lib.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Runtime APIs for querying approved IPFS gateways and pinning services
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! Runtime APIs used by agents and off-chain workers to follow the on-chain pinning policy.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_gateway_registry::{GatewayId, GatewayInfo, GatewayKind, GatewayStatus};
+
+sp_api::decl_runtime_apis! {
+    /// Read access to the gateway registry
+    pub trait GatewayRegistryApi<AccountId, Hash, BlockNumber>
+    where
+        AccountId: Codec,
+        Hash: Codec,
+        BlockNumber: Codec,
+    {
+        /// Active gateways and pinning services in ID order, optionally only those of one kind
+        fn active_gateways(kind: Option<GatewayKind>) -> Vec<(GatewayId, GatewayInfo<AccountId, Hash, BlockNumber>)>;
+
+        /// Whether the endpoint with the given URL hash is registered and active
+        fn is_approved(url_hash: Hash) -> bool;
+    }
+}
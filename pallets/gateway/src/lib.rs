@@ -0,0 +1,306 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        lib.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Registry of approved IPFS gateways and pinning services
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! # Gateway Registry Pallet
+//!
+//! A pallet for the IPFS gateways and pinning services C-Suite agents may rely on.
+//!
+//! ## Overview
+//!
+//! This pallet provides functionality to:
+//! - Let governance register gateways and pinning services with their operator
+//! - Suspend and reactivate entries, or remove them altogether
+//! - Query the active entries, e.g. through the `GatewayRegistryApi` runtime API
+//!
+//! Entries are identified by the hash of their URL, so the URLs themselves stay off-chain and
+//! every client hashes the endpoint it was configured with before looking it up. Off-chain
+//! workers verifying content and agents deciding where to pin read the same list, which keeps
+//! the pinning policy consistent across the network. Other pallets can check an endpoint
+//! through the [`GatewayInspect`] trait.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::RuntimeDebug;
+use scale_info::TypeInfo;
+
+/// Identifier of a registered gateway
+pub type GatewayId = u32;
+
+/// What an endpoint is used for
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum GatewayKind {
+    /// Gateway content is fetched from for verification
+    Gateway,
+    /// Service agents pin their content with
+    Pinning,
+}
+
+/// Whether an endpoint may currently be used
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum GatewayStatus {
+    /// Approved for use
+    Active,
+    /// Temporarily not to be used, e.g. during an outage
+    Suspended,
+}
+
+/// A registered gateway or pinning service
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct GatewayInfo<AccountId, Hash, BlockNumber> {
+    /// Hash of the endpoint URL
+    pub url_hash: Hash,
+    /// What the endpoint is used for
+    pub kind: GatewayKind,
+    /// Account operating the endpoint
+    pub operator: AccountId,
+    /// Whether the endpoint may currently be used
+    pub status: GatewayStatus,
+    /// When the endpoint was registered
+    pub registered_at: BlockNumber,
+}
+
+/// Read-only access to the gateway registry for other pallets
+pub trait GatewayInspect<Hash> {
+    /// Whether the endpoint with the given URL hash is registered and active
+    fn is_approved(url_hash: &Hash) -> bool;
+}
+
+/// No registry: no endpoint is approved
+impl<Hash> GatewayInspect<Hash> for () {
+    fn is_approved(_url_hash: &Hash) -> bool {
+        false
+    }
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+    use sp_std::vec::Vec;
+
+    /// Registry entry as stored by this pallet
+    pub type GatewayInfoOf<T> = GatewayInfo<
+        <T as frame_system::Config>::AccountId,
+        <T as frame_system::Config>::Hash,
+        BlockNumberFor<T>,
+    >;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Origin allowed to maintain the registry
+        type RegistryOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Maximum number of registered gateways and pinning services
+        #[pallet::constant]
+        type MaxGateways: Get<u32>;
+
+        /// Weight information for extrinsics
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Storage for all registered gateways and pinning services
+    #[pallet::storage]
+    #[pallet::getter(fn gateways)]
+    pub type Gateways<T: Config> = CountedStorageMap<
+        _,
+        Twox64Concat,
+        GatewayId,
+        GatewayInfoOf<T>,
+        OptionQuery,
+    >;
+
+    /// Next available gateway ID
+    #[pallet::storage]
+    #[pallet::getter(fn next_gateway_id)]
+    pub type NextGatewayId<T: Config> = StorageValue<_, GatewayId, ValueQuery>;
+
+    /// Reverse index from URL hash to the gateway registered under it
+    #[pallet::storage]
+    #[pallet::getter(fn gateway_by_url)]
+    pub type GatewayByUrl<T: Config> = StorageMap<
+        _,
+        Identity,
+        T::Hash,
+        GatewayId,
+        OptionQuery,
+    >;
+
+    /// Events emitted by the pallet
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A gateway or pinning service was registered
+        GatewayRegistered {
+            gateway_id: GatewayId,
+            url_hash: T::Hash,
+            kind: GatewayKind,
+            operator: T::AccountId,
+        },
+        /// A gateway was suspended or reactivated
+        GatewayStatusChanged {
+            gateway_id: GatewayId,
+            status: GatewayStatus,
+        },
+        /// A gateway was removed from the registry
+        GatewayRemoved {
+            gateway_id: GatewayId,
+        },
+    }
+
+    /// Errors that can occur in the pallet
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Gateway does not exist
+        GatewayNotFound,
+        /// A gateway with this URL hash is already registered
+        DuplicateGateway,
+        /// The registry holds `MaxGateways` entries already
+        TooManyGateways,
+        /// No more gateway IDs available
+        GatewayIdOverflow,
+        /// The gateway already has this status
+        SameStatus,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Register an approved gateway or pinning service
+        ///
+        /// Parameters:
+        /// - `url_hash`: Hash of the endpoint URL
+        /// - `kind`: What the endpoint is used for
+        /// - `operator`: Account operating the endpoint
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::register_gateway())]
+        pub fn register_gateway(
+            origin: OriginFor<T>,
+            url_hash: T::Hash,
+            kind: GatewayKind,
+            operator: T::AccountId,
+        ) -> DispatchResult {
+            T::RegistryOrigin::ensure_origin(origin)?;
+
+            ensure!(!GatewayByUrl::<T>::contains_key(&url_hash), Error::<T>::DuplicateGateway);
+            ensure!(Gateways::<T>::count() < T::MaxGateways::get(), Error::<T>::TooManyGateways);
+
+            let gateway_id = NextGatewayId::<T>::get();
+            let next_id = gateway_id.checked_add(1).ok_or(Error::<T>::GatewayIdOverflow)?;
+
+            Gateways::<T>::insert(gateway_id, GatewayInfo {
+                url_hash,
+                kind,
+                operator: operator.clone(),
+                status: GatewayStatus::Active,
+                registered_at: <frame_system::Pallet<T>>::block_number(),
+            });
+            GatewayByUrl::<T>::insert(&url_hash, gateway_id);
+            NextGatewayId::<T>::put(next_id);
+
+            Self::deposit_event(Event::GatewayRegistered {
+                gateway_id,
+                url_hash,
+                kind,
+                operator,
+            });
+
+            Ok(())
+        }
+
+        /// Suspend or reactivate a registered gateway
+        ///
+        /// Parameters:
+        /// - `gateway_id`: The gateway to update
+        /// - `status`: Its new status
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::set_gateway_status())]
+        pub fn set_gateway_status(
+            origin: OriginFor<T>,
+            gateway_id: GatewayId,
+            status: GatewayStatus,
+        ) -> DispatchResult {
+            T::RegistryOrigin::ensure_origin(origin)?;
+
+            Gateways::<T>::try_mutate(gateway_id, |maybe_gateway| -> DispatchResult {
+                let gateway = maybe_gateway.as_mut().ok_or(Error::<T>::GatewayNotFound)?;
+                ensure!(gateway.status != status, Error::<T>::SameStatus);
+                gateway.status = status;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::GatewayStatusChanged { gateway_id, status });
+
+            Ok(())
+        }
+
+        /// Remove a gateway from the registry
+        ///
+        /// Parameters:
+        /// - `gateway_id`: The gateway to remove
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::remove_gateway())]
+        pub fn remove_gateway(
+            origin: OriginFor<T>,
+            gateway_id: GatewayId,
+        ) -> DispatchResult {
+            T::RegistryOrigin::ensure_origin(origin)?;
+
+            let gateway = Gateways::<T>::take(gateway_id).ok_or(Error::<T>::GatewayNotFound)?;
+            GatewayByUrl::<T>::remove(&gateway.url_hash);
+
+            Self::deposit_event(Event::GatewayRemoved { gateway_id });
+
+            Ok(())
+        }
+    }
+
+    // Helper functions
+    impl<T: Config> Pallet<T> {
+        /// Active entries in ID order, optionally only those of one kind
+        pub fn active_gateways(kind: Option<GatewayKind>) -> Vec<(GatewayId, GatewayInfoOf<T>)> {
+            let mut gateways = Gateways::<T>::iter()
+                .filter(|(_, gateway)| gateway.status == GatewayStatus::Active)
+                .filter(|(_, gateway)| kind.map_or(true, |kind| gateway.kind == kind))
+                .collect::<Vec<_>>();
+            gateways.sort_by_key(|(gateway_id, _)| *gateway_id);
+            gateways
+        }
+    }
+
+    impl<T: Config> GatewayInspect<T::Hash> for Pallet<T> {
+        fn is_approved(url_hash: &T::Hash) -> bool {
+            GatewayByUrl::<T>::get(url_hash)
+                .and_then(Gateways::<T>::get)
+                .map_or(false, |gateway| gateway.status == GatewayStatus::Active)
+        }
+    }
+}
@@ -0,0 +1,84 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        mock.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Mock runtime for testing the gateway registry pallet
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+use crate as pallet_gateway_registry;
+use frame_support::{
+    parameter_types,
+    traits::{ConstU16, ConstU64},
+};
+use sp_core::H256;
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+    pub enum Test
+    {
+        System: frame_system,
+        GatewayRegistry: pallet_gateway_registry,
+    }
+);
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type RuntimeCall = RuntimeCall;
+    type Nonce = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Block = Block;
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type BlockHashCount = ConstU64<250>;
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ConstU16<42>;
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+parameter_types! {
+    pub const MaxGateways: u32 = 3;
+}
+
+impl pallet_gateway_registry::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type RegistryOrigin = frame_system::EnsureRoot<u64>;
+    type MaxGateways = MaxGateways;
+    type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut ext: sp_io::TestExternalities =
+        frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+// Hash of an endpoint URL as clients compute it
+pub fn url_hash(url: &str) -> H256 {
+    H256::from(sp_io::hashing::blake2_256(url.as_bytes()))
+}
@@ -0,0 +1,113 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        tests.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Tests for the gateway registry pallet
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+use crate::{mock::*, Error, Event, GatewayInspect, GatewayKind, GatewayStatus};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+#[test]
+fn register_gateway_works() {
+    new_test_ext().execute_with(|| {
+        let hash = url_hash("https://ipfs.celaya.io");
+        assert_ok!(GatewayRegistry::register_gateway(RuntimeOrigin::root(), hash, GatewayKind::Gateway, 7));
+
+        let gateway = GatewayRegistry::gateways(0).unwrap();
+        assert_eq!(gateway.operator, 7);
+        assert_eq!(gateway.status, GatewayStatus::Active);
+        assert_eq!(GatewayRegistry::gateway_by_url(hash), Some(0));
+        assert!(<GatewayRegistry as GatewayInspect<_>>::is_approved(&hash));
+        System::assert_last_event(
+            Event::GatewayRegistered { gateway_id: 0, url_hash: hash, kind: GatewayKind::Gateway, operator: 7 }.into(),
+        );
+    });
+}
+
+#[test]
+fn register_gateway_requires_origin_and_unique_url() {
+    new_test_ext().execute_with(|| {
+        let hash = url_hash("https://ipfs.celaya.io");
+        assert_noop!(
+            GatewayRegistry::register_gateway(RuntimeOrigin::signed(1), hash, GatewayKind::Gateway, 7),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(GatewayRegistry::register_gateway(RuntimeOrigin::root(), hash, GatewayKind::Gateway, 7));
+        assert_noop!(
+            GatewayRegistry::register_gateway(RuntimeOrigin::root(), hash, GatewayKind::Pinning, 8),
+            Error::<Test>::DuplicateGateway
+        );
+    });
+}
+
+#[test]
+fn registry_is_bounded() {
+    new_test_ext().execute_with(|| {
+        for i in 0..3 {
+            let hash = url_hash(&format!("https://pin{}.celaya.io", i));
+            assert_ok!(GatewayRegistry::register_gateway(RuntimeOrigin::root(), hash, GatewayKind::Pinning, i));
+        }
+
+        assert_noop!(
+            GatewayRegistry::register_gateway(RuntimeOrigin::root(), url_hash("https://pin3.celaya.io"), GatewayKind::Pinning, 3),
+            Error::<Test>::TooManyGateways
+        );
+
+        // Removing an entry frees a slot
+        assert_ok!(GatewayRegistry::remove_gateway(RuntimeOrigin::root(), 1));
+        assert_ok!(GatewayRegistry::register_gateway(RuntimeOrigin::root(), url_hash("https://pin3.celaya.io"), GatewayKind::Pinning, 3));
+    });
+}
+
+#[test]
+fn suspended_gateways_are_not_approved() {
+    new_test_ext().execute_with(|| {
+        let hash = url_hash("https://ipfs.celaya.io");
+        assert_ok!(GatewayRegistry::register_gateway(RuntimeOrigin::root(), hash, GatewayKind::Gateway, 7));
+
+        assert_ok!(GatewayRegistry::set_gateway_status(RuntimeOrigin::root(), 0, GatewayStatus::Suspended));
+        assert!(!<GatewayRegistry as GatewayInspect<_>>::is_approved(&hash));
+        assert!(GatewayRegistry::active_gateways(None).is_empty());
+        assert_noop!(
+            GatewayRegistry::set_gateway_status(RuntimeOrigin::root(), 0, GatewayStatus::Suspended),
+            Error::<Test>::SameStatus
+        );
+
+        assert_ok!(GatewayRegistry::set_gateway_status(RuntimeOrigin::root(), 0, GatewayStatus::Active));
+        assert!(<GatewayRegistry as GatewayInspect<_>>::is_approved(&hash));
+    });
+}
+
+#[test]
+fn active_gateways_filter_by_kind() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(GatewayRegistry::register_gateway(RuntimeOrigin::root(), url_hash("https://gw.celaya.io"), GatewayKind::Gateway, 1));
+        assert_ok!(GatewayRegistry::register_gateway(RuntimeOrigin::root(), url_hash("https://pin.celaya.io"), GatewayKind::Pinning, 2));
+
+        let pinning = GatewayRegistry::active_gateways(Some(GatewayKind::Pinning));
+        assert_eq!(pinning.iter().map(|(id, _)| *id).collect::<Vec<_>>(), vec![1]);
+        assert_eq!(GatewayRegistry::active_gateways(None).len(), 2);
+    });
+}
+
+#[test]
+fn remove_gateway_clears_the_url_index() {
+    new_test_ext().execute_with(|| {
+        let hash = url_hash("https://ipfs.celaya.io");
+        assert_ok!(GatewayRegistry::register_gateway(RuntimeOrigin::root(), hash, GatewayKind::Gateway, 7));
+
+        assert_ok!(GatewayRegistry::remove_gateway(RuntimeOrigin::root(), 0));
+        assert_eq!(GatewayRegistry::gateways(0), None);
+        assert_eq!(GatewayRegistry::gateway_by_url(hash), None);
+        assert_noop!(GatewayRegistry::remove_gateway(RuntimeOrigin::root(), 0), Error::<Test>::GatewayNotFound);
+    });
+}
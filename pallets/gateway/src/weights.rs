@@ -0,0 +1,79 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        weights.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Weight implementations for the gateway registry pallet
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! Weights for pallet_gateway_registry
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for pallet_gateway_registry.
+pub trait WeightInfo {
+    fn register_gateway() -> Weight;
+    fn set_gateway_status() -> Weight;
+    fn remove_gateway() -> Weight;
+}
+
+/// Weights for pallet_gateway_registry using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    // Storage: GatewayRegistry GatewayByUrl (r:1 w:1)
+    // Storage: GatewayRegistry CounterForGateways (r:1 w:1)
+    // Storage: GatewayRegistry NextGatewayId (r:1 w:1)
+    // Storage: GatewayRegistry Gateways (r:0 w:1)
+    fn register_gateway() -> Weight {
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+
+    // Storage: GatewayRegistry Gateways (r:1 w:1)
+    fn set_gateway_status() -> Weight {
+        Weight::from_parts(15_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    // Storage: GatewayRegistry Gateways (r:1 w:1)
+    // Storage: GatewayRegistry CounterForGateways (r:1 w:1)
+    // Storage: GatewayRegistry GatewayByUrl (r:0 w:1)
+    fn remove_gateway() -> Weight {
+        Weight::from_parts(18_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+    fn register_gateway() -> Weight {
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(4))
+    }
+
+    fn set_gateway_status() -> Weight {
+        Weight::from_parts(15_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    fn remove_gateway() -> Weight {
+        Weight::from_parts(18_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+}
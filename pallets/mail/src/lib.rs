@@ -0,0 +1,392 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        lib.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Inter-agent messaging pallet for C-Suite blockchain
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! # Agent Mail Pallet
+//!
+//! An auditable on-chain coordination channel between registered C-Suite agents.
+//!
+//! ## Overview
+//!
+//! This pallet provides functionality to:
+//! - Send message envelopes from one registered agent to another, for a fee
+//! - Acknowledge that a message was read
+//! - Prune messages once they are acknowledged or expire
+//!
+//! ## Message envelopes
+//!
+//! A message does not carry its content on-chain. The envelope names the recipient, a short
+//! topic and the IPFS CID of the payload, which agents use to pre-negotiate before submitting
+//! to consensus. Every envelope expires after a lifetime chosen by the sender, at most
+//! `MaxMessageLifetime` blocks, and is removed in the block it expires in. The recipient can
+//! prune acknowledged messages earlier to make room in its inbox.
+//!
+//! Sending a message costs `MessageFee`, which is handed to `OnFee`. The fee is not refunded,
+//! so an inbox cannot be flooded for free.
+//!
+//! Storage only holds messages that are still live, but the events of a message's lifetime
+//! record its sender, recipient, topic and payload, so the channel can be audited afterwards.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+use sp_std::vec::Vec;
+
+/// Identifier of a message
+pub type MessageId = u64;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{Currency, ExistenceRequirement, OnUnbalanced, WithdrawReasons},
+    };
+    use frame_system::pallet_prelude::*;
+    use csuite_primitives::AgentInspector;
+    use sp_runtime::traits::Zero;
+
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+    pub type NegativeImbalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Agent registry lookup for senders and recipients
+        type Agents: AgentInspector<Self::AccountId>;
+
+        /// Currency the message fee is paid in
+        type Currency: Currency<Self::AccountId>;
+
+        /// Fee paid by the sender for every message
+        #[pallet::constant]
+        type MessageFee: Get<BalanceOf<Self>>;
+
+        /// Handler for the fees paid, e.g. a treasury; dropping them burns them
+        type OnFee: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+        /// Maximum length of a message topic
+        #[pallet::constant]
+        type MaxTopicLength: Get<u32>;
+
+        /// Maximum length of a payload CID
+        #[pallet::constant]
+        type MaxPayloadLength: Get<u32>;
+
+        /// Longest time a message may stay on-chain
+        #[pallet::constant]
+        type MaxMessageLifetime: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of live messages addressed to a single agent
+        #[pallet::constant]
+        type MaxInboxSize: Get<u32>;
+
+        /// Maximum number of messages expiring in the same block
+        #[pallet::constant]
+        type MaxExpiriesPerBlock: Get<u32>;
+
+        /// Weight information for extrinsics
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// A message envelope stored on-chain
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct MessageEnvelope<T: Config> {
+        /// Agent that sent the message
+        pub sender: T::AccountId,
+        /// Agent the message is addressed to
+        pub recipient: T::AccountId,
+        /// Short topic the recipient can filter on
+        pub topic: BoundedVec<u8, T::MaxTopicLength>,
+        /// IPFS CID of the message content
+        pub payload: BoundedVec<u8, T::MaxPayloadLength>,
+        /// When the message was sent
+        pub sent_at: BlockNumberFor<T>,
+        /// Block in which the message is pruned
+        pub expires_at: BlockNumberFor<T>,
+        /// When the recipient acknowledged reading the message
+        pub acknowledged_at: Option<BlockNumberFor<T>>,
+    }
+
+    /// Storage for all live messages
+    #[pallet::storage]
+    #[pallet::getter(fn messages)]
+    pub type Messages<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        MessageId,
+        MessageEnvelope<T>,
+        OptionQuery,
+    >;
+
+    /// Next available message ID
+    #[pallet::storage]
+    #[pallet::getter(fn next_message_id)]
+    pub type NextMessageId<T: Config> = StorageValue<_, MessageId, ValueQuery>;
+
+    /// Live messages addressed to each agent, oldest first
+    #[pallet::storage]
+    #[pallet::getter(fn inbox)]
+    pub type Inbox<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<MessageId, T::MaxInboxSize>,
+        ValueQuery,
+    >;
+
+    /// Messages by the block they expire in
+    #[pallet::storage]
+    #[pallet::getter(fn message_expiries)]
+    pub type MessageExpiries<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<MessageId, T::MaxExpiriesPerBlock>,
+        ValueQuery,
+    >;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A message was sent
+        MessageSent {
+            message_id: MessageId,
+            sender: T::AccountId,
+            recipient: T::AccountId,
+            topic: BoundedVec<u8, T::MaxTopicLength>,
+            payload: BoundedVec<u8, T::MaxPayloadLength>,
+            expires_at: BlockNumberFor<T>,
+        },
+        /// The recipient acknowledged reading a message
+        MessageAcknowledged {
+            message_id: MessageId,
+            recipient: T::AccountId,
+        },
+        /// A message was removed from storage, because it expired or was pruned by its recipient
+        MessagePruned {
+            message_id: MessageId,
+            acknowledged: bool,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Message does not exist
+        MessageNotFound,
+        /// Caller is not a registered agent
+        SenderNotAgent,
+        /// Recipient is not a registered agent
+        RecipientNotAgent,
+        /// Agents cannot send messages to themselves
+        SelfMessage,
+        /// Caller is not the message recipient
+        NotRecipient,
+        /// Message was already acknowledged
+        AlreadyAcknowledged,
+        /// Topic is empty or too long
+        InvalidTopic,
+        /// Payload CID is empty or too long
+        InvalidPayload,
+        /// Lifetime is zero or exceeds the maximum
+        InvalidLifetime,
+        /// Sender cannot pay the message fee
+        InsufficientBalance,
+        /// Recipient's inbox is full
+        InboxFull,
+        /// Too many messages expire in the same block
+        TooManyExpiries,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Prune messages that expire in this block
+        fn on_initialize(block: BlockNumberFor<T>) -> Weight {
+            let message_ids = MessageExpiries::<T>::take(block);
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+            for message_id in message_ids {
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+                let Some(message) = Messages::<T>::get(message_id) else { continue };
+                if message.expires_at == block {
+                    weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 2));
+                    Self::prune(message_id, &message);
+                }
+            }
+
+            weight
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Send a message to another agent, paying the message fee
+        ///
+        /// Parameters:
+        /// - `recipient`: The agent the message is addressed to
+        /// - `topic`: Short topic the recipient can filter on
+        /// - `payload`: IPFS CID of the message content
+        /// - `lifetime`: Blocks until the message expires and is pruned
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::send_message())]
+        pub fn send_message(
+            origin: OriginFor<T>,
+            recipient: T::AccountId,
+            topic: Vec<u8>,
+            payload: Vec<u8>,
+            lifetime: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(T::Agents::is_registered(&sender), Error::<T>::SenderNotAgent);
+            ensure!(T::Agents::is_registered(&recipient), Error::<T>::RecipientNotAgent);
+            ensure!(sender != recipient, Error::<T>::SelfMessage);
+
+            ensure!(!topic.is_empty(), Error::<T>::InvalidTopic);
+            let topic = BoundedVec::<u8, T::MaxTopicLength>::try_from(topic)
+                .map_err(|_| Error::<T>::InvalidTopic)?;
+            ensure!(!payload.is_empty(), Error::<T>::InvalidPayload);
+            let payload = BoundedVec::<u8, T::MaxPayloadLength>::try_from(payload)
+                .map_err(|_| Error::<T>::InvalidPayload)?;
+            ensure!(
+                !lifetime.is_zero() && lifetime <= T::MaxMessageLifetime::get(),
+                Error::<T>::InvalidLifetime
+            );
+
+            let message_id = Self::next_message_id();
+            let now = <frame_system::Pallet<T>>::block_number();
+            let expires_at = now.saturating_add(lifetime);
+
+            Inbox::<T>::try_mutate(&recipient, |inbox| inbox.try_push(message_id))
+                .map_err(|_| Error::<T>::InboxFull)?;
+            MessageExpiries::<T>::try_mutate(expires_at, |messages| messages.try_push(message_id))
+                .map_err(|_| Error::<T>::TooManyExpiries)?;
+
+            // Charge the message fee
+            let fee = T::MessageFee::get();
+            if !fee.is_zero() {
+                let imbalance = T::Currency::withdraw(
+                    &sender,
+                    fee,
+                    WithdrawReasons::FEE,
+                    ExistenceRequirement::KeepAlive,
+                ).map_err(|_| Error::<T>::InsufficientBalance)?;
+                T::OnFee::on_unbalanced(imbalance);
+            }
+
+            Messages::<T>::insert(message_id, MessageEnvelope {
+                sender: sender.clone(),
+                recipient: recipient.clone(),
+                topic: topic.clone(),
+                payload: payload.clone(),
+                sent_at: now,
+                expires_at,
+                acknowledged_at: None,
+            });
+            NextMessageId::<T>::put(message_id.saturating_add(1));
+
+            // Emit event
+            Self::deposit_event(Event::MessageSent {
+                message_id,
+                sender,
+                recipient,
+                topic,
+                payload,
+                expires_at,
+            });
+
+            Ok(())
+        }
+
+        /// Acknowledge reading a message
+        ///
+        /// Can only be called by the message recipient. The message stays in the inbox until it
+        /// expires or is pruned.
+        ///
+        /// Parameters:
+        /// - `message_id`: The message being acknowledged
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::acknowledge_message())]
+        pub fn acknowledge_message(
+            origin: OriginFor<T>,
+            message_id: MessageId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut message = Messages::<T>::get(message_id).ok_or(Error::<T>::MessageNotFound)?;
+            ensure!(message.recipient == who, Error::<T>::NotRecipient);
+            ensure!(message.acknowledged_at.is_none(), Error::<T>::AlreadyAcknowledged);
+
+            message.acknowledged_at = Some(<frame_system::Pallet<T>>::block_number());
+            Messages::<T>::insert(message_id, message);
+
+            // Emit event
+            Self::deposit_event(Event::MessageAcknowledged {
+                message_id,
+                recipient: who,
+            });
+
+            Ok(())
+        }
+
+        /// Prune every acknowledged message in the caller's inbox before it expires
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::prune_acknowledged(T::MaxInboxSize::get()))]
+        pub fn prune_acknowledged(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            for message_id in Self::inbox(&who) {
+                let Some(message) = Messages::<T>::get(message_id) else { continue };
+                if message.acknowledged_at.is_some() {
+                    Self::prune(message_id, &message);
+                }
+            }
+
+            Ok(())
+        }
+    }
+
+    // Helper functions
+    impl<T: Config> Pallet<T> {
+        /// Remove a message and drop it from its recipient's inbox
+        ///
+        /// The message's entry in `MessageExpiries` is left to be cleared when that block comes.
+        fn prune(message_id: MessageId, message: &MessageEnvelope<T>) {
+            Messages::<T>::remove(message_id);
+            Inbox::<T>::mutate(&message.recipient, |inbox| inbox.retain(|id| *id != message_id));
+
+            Self::deposit_event(Event::MessagePruned {
+                message_id,
+                acknowledged: message.acknowledged_at.is_some(),
+            });
+        }
+    }
+}
@@ -0,0 +1,154 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        mock.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Mock runtime for testing the inter-agent mail pallet
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+use crate as pallet_agent_mail;
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{ConstU16, ConstU32, ConstU64, Hooks},
+};
+use sp_core::H256;
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage, Percent,
+};
+use pallet_agent_registry::AgentRole;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+    pub enum Test
+    {
+        System: frame_system,
+        Balances: pallet_balances,
+        AgentRegistry: pallet_agent_registry,
+        AgentMail: pallet_agent_mail,
+    }
+);
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type RuntimeCall = RuntimeCall;
+    type Nonce = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Block = Block;
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type BlockHashCount = ConstU64<250>;
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<u64>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ConstU16<42>;
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
+}
+
+parameter_types! {
+    pub const MaxMetadataLength: u32 = 1024;
+    pub const OverloadThreshold: Percent = Percent::from_percent(90);
+    pub AllFeatures: pallet_agent_registry::FeatureFlags = pallet_agent_registry::FeatureFlags::all();
+}
+
+impl pallet_agent_registry::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type MaxMetadataLength = MaxMetadataLength;
+    type MaxMetadataAllowance = ConstU32<4096>;
+    type MetadataTierBytes = ConstU32<1024>;
+    type MetadataTierDeposit = ConstU64<100>;
+    type Currency = Balances;
+    type FeeExemption = ();
+    type RetirementGracePeriod = ConstU64<10>;
+    type OnRetirement = ();
+    type OnRotation = ();
+    type OnStatusChange = ();
+    type MaxEndpoints = ConstU32<4>;
+    type MaxEndpointLength = ConstU32<64>;
+    type EndpointTtl = ConstU64<100>;
+    type AnnouncementCooldown = ConstU64<10>;
+    type StaleAfter = ConstU64<50>;
+    type MaxSweepPerBlock = ConstU32<8>;
+    type MaxProxies = ConstU32<2>;
+    type MaxCapabilities = ConstU32<2>;
+    type DefaultFeatures = AllFeatures;
+    type MaxTelemetrySnapshots = ConstU32<3>;
+    type TelemetryInterval = ConstU64<5>;
+    type TelemetryTtl = ConstU64<20>;
+    type OverloadThreshold = OverloadThreshold;
+    type OverloadQueueDepth = ConstU32<100>;
+    type MaxMaintenanceWindows = ConstU32<3>;
+    type MaxMaintenanceDuration = ConstU64<100>;
+    type RemoteOrigin = frame_system::EnsureNever<u32>;
+    type MaxRemoteProofSize = ConstU32<4_096>;
+    type WeightInfo = ();
+}
+
+impl pallet_agent_mail::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Agents = AgentRegistry;
+    type Currency = Balances;
+    type MessageFee = ConstU64<10>;
+    type OnFee = ();
+    type MaxTopicLength = ConstU32<16>;
+    type MaxPayloadLength = ConstU32<64>;
+    type MaxMessageLifetime = ConstU64<100>;
+    type MaxInboxSize = ConstU32<2>;
+    type MaxExpiriesPerBlock = ConstU32<4>;
+    type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: (1..=5).map(|who| (who, 10_000)).collect(),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+
+    let mut ext: sp_io::TestExternalities = storage.into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+// Helper function to register an agent for testing
+pub fn register_test_agent(agent_id: u64, role: AgentRole) {
+    pallet_agent_registry::Pallet::<Test>::register_agent(
+        RuntimeOrigin::signed(agent_id),
+        role,
+        None,
+    ).expect("Agent should be registered successfully");
+}
+
+/// Run blocks up to `n`, calling the pallet hooks on each
+pub fn run_to_block(n: u64) {
+    while System::block_number() < n {
+        let next = System::block_number() + 1;
+        System::set_block_number(next);
+        AgentMail::on_initialize(next);
+    }
+}
@@ -0,0 +1,138 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        tests.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Tests for the inter-agent mail pallet
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+use crate::{mock::*, Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use pallet_agent_registry::AgentRole;
+
+const SENDER: u64 = 1;
+const RECIPIENT: u64 = 2;
+
+// Register SENDER and RECIPIENT as agents
+fn register_agents() {
+    register_test_agent(SENDER, AgentRole::Lyra);
+    register_test_agent(RECIPIENT, AgentRole::Echo);
+}
+
+// Send a message from SENDER to RECIPIENT that lives for `lifetime` blocks
+fn send(lifetime: u64) -> u64 {
+    let message_id = AgentMail::next_message_id();
+    assert_ok!(AgentMail::send_message(
+        RuntimeOrigin::signed(SENDER),
+        RECIPIENT,
+        b"pre-vote".to_vec(),
+        b"QmProposalDraft".to_vec(),
+        lifetime,
+    ));
+    message_id
+}
+
+#[test]
+fn send_message_charges_fee_and_fills_inbox() {
+    new_test_ext().execute_with(|| {
+        register_agents();
+        let balance = Balances::free_balance(SENDER);
+
+        let message_id = send(10);
+        let message = AgentMail::messages(message_id).unwrap();
+        assert_eq!(message.recipient, RECIPIENT);
+        assert_eq!(message.expires_at, 11);
+        assert_eq!(Balances::free_balance(SENDER), balance - 10);
+        assert_eq!(AgentMail::inbox(RECIPIENT).into_inner(), vec![message_id]);
+        System::assert_last_event(Event::MessageSent {
+            message_id,
+            sender: SENDER,
+            recipient: RECIPIENT,
+            topic: b"pre-vote".to_vec().try_into().unwrap(),
+            payload: b"QmProposalDraft".to_vec().try_into().unwrap(),
+            expires_at: 11,
+        }.into());
+
+        assert_noop!(
+            AgentMail::send_message(RuntimeOrigin::signed(3), RECIPIENT, b"t".to_vec(), b"Qm".to_vec(), 10),
+            Error::<Test>::SenderNotAgent
+        );
+        assert_noop!(
+            AgentMail::send_message(RuntimeOrigin::signed(SENDER), 3, b"t".to_vec(), b"Qm".to_vec(), 10),
+            Error::<Test>::RecipientNotAgent
+        );
+        assert_noop!(
+            AgentMail::send_message(RuntimeOrigin::signed(SENDER), RECIPIENT, b"t".to_vec(), b"Qm".to_vec(), 101),
+            Error::<Test>::InvalidLifetime
+        );
+        assert_noop!(
+            AgentMail::send_message(RuntimeOrigin::signed(SENDER), RECIPIENT, vec![], b"Qm".to_vec(), 10),
+            Error::<Test>::InvalidTopic
+        );
+
+        // The inbox holds two live messages
+        send(10);
+        assert_noop!(
+            AgentMail::send_message(RuntimeOrigin::signed(SENDER), RECIPIENT, b"t".to_vec(), b"Qm".to_vec(), 10),
+            Error::<Test>::InboxFull
+        );
+    });
+}
+
+#[test]
+fn only_the_recipient_acknowledges_once() {
+    new_test_ext().execute_with(|| {
+        register_agents();
+        let message_id = send(10);
+
+        assert_noop!(
+            AgentMail::acknowledge_message(RuntimeOrigin::signed(SENDER), message_id),
+            Error::<Test>::NotRecipient
+        );
+        assert_ok!(AgentMail::acknowledge_message(RuntimeOrigin::signed(RECIPIENT), message_id));
+        assert_eq!(AgentMail::messages(message_id).unwrap().acknowledged_at, Some(1));
+        System::assert_last_event(Event::MessageAcknowledged { message_id, recipient: RECIPIENT }.into());
+
+        assert_noop!(
+            AgentMail::acknowledge_message(RuntimeOrigin::signed(RECIPIENT), message_id),
+            Error::<Test>::AlreadyAcknowledged
+        );
+        assert_noop!(
+            AgentMail::acknowledge_message(RuntimeOrigin::signed(RECIPIENT), 42),
+            Error::<Test>::MessageNotFound
+        );
+    });
+}
+
+#[test]
+fn messages_are_pruned_on_expiry_or_after_acknowledgement() {
+    new_test_ext().execute_with(|| {
+        register_agents();
+        let read = send(10);
+        let unread = send(5);
+
+        // Pruning only removes acknowledged messages
+        assert_ok!(AgentMail::acknowledge_message(RuntimeOrigin::signed(RECIPIENT), read));
+        assert_ok!(AgentMail::prune_acknowledged(RuntimeOrigin::signed(RECIPIENT)));
+        System::assert_last_event(Event::MessagePruned { message_id: read, acknowledged: true }.into());
+        assert!(AgentMail::messages(read).is_none());
+        assert_eq!(AgentMail::inbox(RECIPIENT).into_inner(), vec![unread]);
+
+        // The unread message is pruned in the block it expires in
+        run_to_block(5);
+        assert!(AgentMail::messages(unread).is_some());
+        run_to_block(6);
+        assert!(AgentMail::messages(unread).is_none());
+        assert!(AgentMail::inbox(RECIPIENT).is_empty());
+        System::assert_last_event(Event::MessagePruned { message_id: unread, acknowledged: false }.into());
+
+        // The already pruned message is skipped when its expiry comes
+        run_to_block(11);
+        assert!(AgentMail::message_expiries(11).is_empty());
+    });
+}
@@ -0,0 +1,84 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        weights.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Weight implementations for the inter-agent mail pallet
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! Weights for pallet_agent_mail
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for pallet_agent_mail.
+pub trait WeightInfo {
+    fn send_message() -> Weight;
+    fn acknowledge_message() -> Weight;
+    fn prune_acknowledged(m: u32) -> Weight;
+}
+
+/// Weights for pallet_agent_mail using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    // Storage: AgentRegistry Agents (r:2 w:0)
+    // Storage: AgentMail NextMessageId (r:1 w:1)
+    // Storage: AgentMail Inbox (r:1 w:1)
+    // Storage: AgentMail MessageExpiries (r:1 w:1)
+    // Storage: System Account (r:1 w:1)
+    // Storage: AgentMail Messages (r:0 w:1)
+    fn send_message() -> Weight {
+        Weight::from_parts(36_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(5))
+    }
+
+    // Storage: AgentMail Messages (r:1 w:1)
+    fn acknowledge_message() -> Weight {
+        Weight::from_parts(16_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    // Storage: AgentMail Inbox (r:m w:m)
+    // Storage: AgentMail Messages (r:m w:m)
+    fn prune_acknowledged(m: u32) -> Weight {
+        Weight::from_parts(14_000_000, 0)
+            .saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(m as u64))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().reads(2u64.saturating_mul(m as u64)))
+            .saturating_add(T::DbWeight::get().writes(2u64.saturating_mul(m as u64)))
+    }
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+    fn send_message() -> Weight {
+        Weight::from_parts(36_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(6))
+            .saturating_add(RocksDbWeight::get().writes(5))
+    }
+
+    fn acknowledge_message() -> Weight {
+        Weight::from_parts(16_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    fn prune_acknowledged(m: u32) -> Weight {
+        Weight::from_parts(14_000_000, 0)
+            .saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(m as u64))
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().reads(2u64.saturating_mul(m as u64)))
+            .saturating_add(RocksDbWeight::get().writes(2u64.saturating_mul(m as u64)))
+    }
+}
@@ -0,0 +1,487 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        lib.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Agent organization / team grouping pallet for C-Suite blockchain
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! # Agent Organizations Pallet
+//!
+//! A pallet for grouping registered C-Suite agents into named organizations.
+//!
+//! ## Overview
+//!
+//! This pallet provides functionality to:
+//! - Create named organizations with a single admin account, reserving `OrgDeposit` from the
+//!   creator until the organization is dissolved
+//! - Invite agents, who join only once they accept the invitation, and remove members (an agent
+//!   belongs to at most one organization)
+//! - Transfer org administration and dissolve organizations
+//! - Aggregate the trust scores of all members into an org-level trust figure
+//!
+//! A typical organization is one deployment of the 13-agent C-Suite. Other pallets can
+//! query membership through the [`OrgInspect`] trait, e.g. the consensus pallet uses it to
+//! optionally require that every agent involved in an insight belongs to the same org.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+/// Identifier of an organization
+pub type OrgId = u32;
+
+/// Read-only access to organization membership for other pallets
+pub trait OrgInspect<AccountId> {
+    /// The organization the given agent belongs to, if any
+    fn org_of(who: &AccountId) -> Option<OrgId>;
+
+    /// Whether all of the given agents belong to one and the same organization
+    fn same_org(agents: &[AccountId]) -> bool {
+        let mut orgs = agents.iter().map(Self::org_of);
+        match orgs.next() {
+            Some(Some(first)) => orgs.all(|org| org == Some(first)),
+            _ => false,
+        }
+    }
+}
+
+/// No organizations: nobody is a member of anything
+impl<AccountId> OrgInspect<AccountId> for () {
+    fn org_of(_who: &AccountId) -> Option<OrgId> {
+        None
+    }
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{Currency, ReservableCurrency},
+    };
+    use frame_system::pallet_prelude::*;
+    use sp_std::vec::Vec;
+    use csuite_primitives::AgentInspector;
+
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Agent registry lookup for members and their trust scores
+        type Agents: AgentInspector<Self::AccountId>;
+
+        /// Maximum length for an organization name
+        #[pallet::constant]
+        type MaxOrgNameLength: Get<u32>;
+
+        /// Maximum number of member agents in a single organization, counting pending invitations
+        #[pallet::constant]
+        type MaxMembersPerOrg: Get<u32>;
+
+        /// Currency the organization deposit is reserved in
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// Deposit reserved from the creator of an organization until it is dissolved
+        #[pallet::constant]
+        type OrgDeposit: Get<BalanceOf<Self>>;
+
+        /// Weight information for extrinsics
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Organization information stored on-chain
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct OrgInfo<T: Config> {
+        /// Human-readable organization name (e.g., "C-Suite Production")
+        pub name: BoundedVec<u8, T::MaxOrgNameLength>,
+        /// Account allowed to manage membership
+        pub admin: T::AccountId,
+        /// Member agents
+        pub members: BoundedVec<T::AccountId, T::MaxMembersPerOrg>,
+        /// When the organization was created
+        pub created_at: BlockNumberFor<T>,
+    }
+
+    /// Storage for all organizations
+    #[pallet::storage]
+    #[pallet::getter(fn orgs)]
+    pub type Orgs<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        OrgId,
+        OrgInfo<T>,
+        OptionQuery,
+    >;
+
+    /// Next available organization ID
+    #[pallet::storage]
+    #[pallet::getter(fn next_org_id)]
+    pub type NextOrgId<T: Config> = StorageValue<_, OrgId, ValueQuery>;
+
+    /// Reverse index from agent to the organization it belongs to
+    #[pallet::storage]
+    #[pallet::getter(fn agent_org)]
+    pub type AgentOrg<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        OrgId,
+        OptionQuery,
+    >;
+
+    /// Agents invited to an organization that have not accepted yet
+    #[pallet::storage]
+    #[pallet::getter(fn pending_invites)]
+    pub type PendingInvites<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        OrgId,
+        BoundedVec<T::AccountId, T::MaxMembersPerOrg>,
+        ValueQuery,
+    >;
+
+    /// Creator and amount reserved for each organization
+    #[pallet::storage]
+    #[pallet::getter(fn org_deposits)]
+    pub type OrgDeposits<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        OrgId,
+        (T::AccountId, BalanceOf<T>),
+        OptionQuery,
+    >;
+
+    /// Events emitted by the pallet
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A new organization was created
+        OrgCreated {
+            org_id: OrgId,
+            admin: T::AccountId,
+            name: Vec<u8>,
+        },
+        /// An agent was invited to join an organization
+        MemberInvited {
+            org_id: OrgId,
+            agent_id: T::AccountId,
+        },
+        /// An agent accepted an invitation and joined an organization
+        MemberAdded {
+            org_id: OrgId,
+            agent_id: T::AccountId,
+        },
+        /// An agent left or was removed from an organization
+        MemberRemoved {
+            org_id: OrgId,
+            agent_id: T::AccountId,
+        },
+        /// Organization administration was handed over
+        AdminTransferred {
+            org_id: OrgId,
+            new_admin: T::AccountId,
+        },
+        /// An organization was dissolved and all members released
+        OrgDissolved {
+            org_id: OrgId,
+        },
+    }
+
+    /// Errors that can occur in the pallet
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Organization does not exist
+        OrgNotFound,
+        /// Caller is not the admin of this organization
+        NotOrgAdmin,
+        /// Invalid organization name
+        InvalidOrgName,
+        /// Agent is not registered
+        AgentNotFound,
+        /// Agent already belongs to an organization
+        AlreadyInOrg,
+        /// Agent is not a member of this organization
+        NotOrgMember,
+        /// Organization member list is full
+        OrgFull,
+        /// No more organization IDs available
+        OrgIdOverflow,
+        /// Agent is already invited to this organization
+        AlreadyInvited,
+        /// Agent has no invitation to this organization
+        NotInvited,
+        /// The creator cannot reserve the organization deposit
+        InsufficientDeposit,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Create a new organization administered by the caller
+        ///
+        /// Reserves `OrgDeposit` from the caller until the organization is dissolved.
+        ///
+        /// Parameters:
+        /// - `name`: Human-readable organization name
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::create_org())]
+        pub fn create_org(
+            origin: OriginFor<T>,
+            name: Vec<u8>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(!name.is_empty(), Error::<T>::InvalidOrgName);
+            let bounded_name = BoundedVec::<u8, T::MaxOrgNameLength>::try_from(name.clone())
+                .map_err(|_| Error::<T>::InvalidOrgName)?;
+
+            let org_id = NextOrgId::<T>::get();
+            let next_id = org_id.checked_add(1).ok_or(Error::<T>::OrgIdOverflow)?;
+
+            let deposit = T::OrgDeposit::get();
+            T::Currency::reserve(&who, deposit).map_err(|_| Error::<T>::InsufficientDeposit)?;
+
+            let org = OrgInfo {
+                name: bounded_name,
+                admin: who.clone(),
+                members: BoundedVec::default(),
+                created_at: <frame_system::Pallet<T>>::block_number(),
+            };
+
+            Orgs::<T>::insert(org_id, org);
+            OrgDeposits::<T>::insert(org_id, (who.clone(), deposit));
+            NextOrgId::<T>::put(next_id);
+
+            Self::deposit_event(Event::OrgCreated {
+                org_id,
+                admin: who,
+                name,
+            });
+
+            Ok(())
+        }
+
+        /// Invite a registered agent to an organization (admin only)
+        ///
+        /// The agent only becomes a member once it accepts with `accept_invite`. Pending
+        /// invitations count towards `MaxMembersPerOrg`.
+        ///
+        /// Parameters:
+        /// - `org_id`: The organization to invite the agent to
+        /// - `agent_id`: The agent to invite
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::invite_member())]
+        pub fn invite_member(
+            origin: OriginFor<T>,
+            org_id: OrgId,
+            agent_id: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrgNotFound)?;
+            ensure!(org.admin == who, Error::<T>::NotOrgAdmin);
+            ensure!(T::Agents::is_registered(&agent_id), Error::<T>::AgentNotFound);
+            ensure!(!AgentOrg::<T>::contains_key(&agent_id), Error::<T>::AlreadyInOrg);
+
+            PendingInvites::<T>::try_mutate(org_id, |invites| -> DispatchResult {
+                ensure!(!invites.contains(&agent_id), Error::<T>::AlreadyInvited);
+                ensure!(
+                    org.members.len().saturating_add(invites.len()) < T::MaxMembersPerOrg::get() as usize,
+                    Error::<T>::OrgFull
+                );
+                invites.try_push(agent_id.clone()).map_err(|_| Error::<T>::OrgFull)?;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::MemberInvited {
+                org_id,
+                agent_id,
+            });
+
+            Ok(())
+        }
+
+        /// Accept an invitation and join an organization
+        ///
+        /// Parameters:
+        /// - `org_id`: The organization the caller was invited to
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::accept_invite())]
+        pub fn accept_invite(
+            origin: OriginFor<T>,
+            org_id: OrgId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(T::Agents::is_registered(&who), Error::<T>::AgentNotFound);
+            ensure!(!AgentOrg::<T>::contains_key(&who), Error::<T>::AlreadyInOrg);
+
+            let mut invites = PendingInvites::<T>::get(org_id);
+            let position = invites.iter().position(|a| a == &who).ok_or(Error::<T>::NotInvited)?;
+            invites.remove(position);
+
+            Orgs::<T>::try_mutate(org_id, |maybe_org| -> DispatchResult {
+                let org = maybe_org.as_mut().ok_or(Error::<T>::OrgNotFound)?;
+                org.members.try_push(who.clone()).map_err(|_| Error::<T>::OrgFull)?;
+                Ok(())
+            })?;
+
+            PendingInvites::<T>::insert(org_id, invites);
+            AgentOrg::<T>::insert(&who, org_id);
+
+            Self::deposit_event(Event::MemberAdded {
+                org_id,
+                agent_id: who,
+            });
+
+            Ok(())
+        }
+
+        /// Remove an agent from an organization
+        ///
+        /// May be called by the org admin, or by the member agent itself to leave.
+        ///
+        /// Parameters:
+        /// - `org_id`: The organization to remove the agent from
+        /// - `agent_id`: The agent to remove
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::remove_member())]
+        pub fn remove_member(
+            origin: OriginFor<T>,
+            org_id: OrgId,
+            agent_id: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Orgs::<T>::try_mutate(org_id, |maybe_org| -> DispatchResult {
+                let org = maybe_org.as_mut().ok_or(Error::<T>::OrgNotFound)?;
+                ensure!(org.admin == who || agent_id == who, Error::<T>::NotOrgAdmin);
+
+                let position = org.members.iter().position(|m| m == &agent_id)
+                    .ok_or(Error::<T>::NotOrgMember)?;
+                org.members.remove(position);
+
+                Ok(())
+            })?;
+
+            AgentOrg::<T>::remove(&agent_id);
+
+            Self::deposit_event(Event::MemberRemoved {
+                org_id,
+                agent_id,
+            });
+
+            Ok(())
+        }
+
+        /// Hand over administration of an organization (admin only)
+        ///
+        /// Parameters:
+        /// - `org_id`: The organization to update
+        /// - `new_admin`: The account that becomes the new admin
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::transfer_admin())]
+        pub fn transfer_admin(
+            origin: OriginFor<T>,
+            org_id: OrgId,
+            new_admin: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Orgs::<T>::try_mutate(org_id, |maybe_org| -> DispatchResult {
+                let org = maybe_org.as_mut().ok_or(Error::<T>::OrgNotFound)?;
+                ensure!(org.admin == who, Error::<T>::NotOrgAdmin);
+                org.admin = new_admin.clone();
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::AdminTransferred {
+                org_id,
+                new_admin,
+            });
+
+            Ok(())
+        }
+
+        /// Dissolve an organization, releasing all of its members (admin only)
+        ///
+        /// Pending invitations lapse and the organization deposit is returned to its creator.
+        ///
+        /// Parameters:
+        /// - `org_id`: The organization to dissolve
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::dissolve_org(T::MaxMembersPerOrg::get()))]
+        pub fn dissolve_org(
+            origin: OriginFor<T>,
+            org_id: OrgId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrgNotFound)?;
+            ensure!(org.admin == who, Error::<T>::NotOrgAdmin);
+
+            for member in org.members.iter() {
+                AgentOrg::<T>::remove(member);
+            }
+            Orgs::<T>::remove(org_id);
+            PendingInvites::<T>::remove(org_id);
+            if let Some((creator, deposit)) = OrgDeposits::<T>::take(org_id) {
+                T::Currency::unreserve(&creator, deposit);
+            }
+
+            Self::deposit_event(Event::OrgDissolved { org_id });
+
+            Ok(())
+        }
+    }
+
+    // Helper functions
+    impl<T: Config> Pallet<T> {
+        /// Sum of the trust scores of all members of an organization
+        pub fn org_trust_score(org_id: OrgId) -> Option<u64> {
+            let org = Orgs::<T>::get(org_id)?;
+            Some(org.members.iter()
+                .filter_map(T::Agents::trust_score)
+                .fold(0u64, |acc, score| acc.saturating_add(score)))
+        }
+
+        /// Average member trust score of an organization (0 for an empty org)
+        pub fn org_average_trust(org_id: OrgId) -> Option<u64> {
+            let total = Self::org_trust_score(org_id)?;
+            let members = Orgs::<T>::get(org_id)?.members.len() as u64;
+            Some(if members == 0 { 0 } else { total / members })
+        }
+    }
+
+    impl<T: Config> OrgInspect<T::AccountId> for Pallet<T> {
+        fn org_of(who: &T::AccountId) -> Option<OrgId> {
+            AgentOrg::<T>::get(who)
+        }
+    }
+}
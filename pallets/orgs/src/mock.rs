@@ -0,0 +1,143 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        mock.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Mock runtime for testing the Agent Organizations pallet
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+use crate as pallet_orgs;
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{ConstU16, ConstU32, ConstU64},
+};
+use sp_core::H256;
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage, Percent,
+};
+use pallet_agent_registry::AgentRole;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+    pub enum Test
+    {
+        System: frame_system,
+        Balances: pallet_balances,
+        AgentRegistry: pallet_agent_registry,
+        Orgs: pallet_orgs,
+    }
+);
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type RuntimeCall = RuntimeCall;
+    type Nonce = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Block = Block;
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type BlockHashCount = ConstU64<250>;
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<u64>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ConstU16<42>;
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
+}
+
+parameter_types! {
+    pub const MaxMetadataLength: u32 = 1024;
+    pub const OverloadThreshold: Percent = Percent::from_percent(90);
+    pub AllFeatures: pallet_agent_registry::FeatureFlags = pallet_agent_registry::FeatureFlags::all();
+}
+
+impl pallet_agent_registry::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type MaxMetadataLength = MaxMetadataLength;
+    type MaxMetadataAllowance = ConstU32<4096>;
+    type MetadataTierBytes = ConstU32<1024>;
+    type MetadataTierDeposit = ConstU64<100>;
+    type Currency = Balances;
+    type FeeExemption = ();
+    type RetirementGracePeriod = ConstU64<10>;
+    type OnRetirement = ();
+    type OnRotation = ();
+    type OnStatusChange = ();
+    type MaxEndpoints = ConstU32<4>;
+    type MaxEndpointLength = ConstU32<64>;
+    type EndpointTtl = ConstU64<100>;
+    type AnnouncementCooldown = ConstU64<10>;
+    type StaleAfter = ConstU64<50>;
+    type MaxSweepPerBlock = ConstU32<8>;
+    type MaxProxies = ConstU32<2>;
+    type MaxCapabilities = ConstU32<2>;
+    type DefaultFeatures = AllFeatures;
+    type MaxTelemetrySnapshots = ConstU32<3>;
+    type TelemetryInterval = ConstU64<5>;
+    type TelemetryTtl = ConstU64<20>;
+    type OverloadThreshold = OverloadThreshold;
+    type OverloadQueueDepth = ConstU32<100>;
+    type MaxMaintenanceWindows = ConstU32<3>;
+    type MaxMaintenanceDuration = ConstU64<100>;
+    type RemoteOrigin = frame_system::EnsureNever<u32>;
+    type MaxRemoteProofSize = ConstU32<4_096>;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const MaxOrgNameLength: u32 = 32;
+    pub const MaxMembersPerOrg: u32 = 3;
+}
+
+impl pallet_orgs::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Agents = AgentRegistry;
+    type MaxOrgNameLength = MaxOrgNameLength;
+    type MaxMembersPerOrg = MaxMembersPerOrg;
+    type Currency = Balances;
+    type OrgDeposit = ConstU64<50>;
+    type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: (1..=4).chain([100]).map(|who| (who, 1_000)).collect(),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+    storage.into()
+}
+
+// Helper function to register an agent for testing
+pub fn register_test_agent(agent_id: u64, role: AgentRole) {
+    pallet_agent_registry::Pallet::<Test>::register_agent(
+        RuntimeOrigin::signed(agent_id),
+        role,
+        None,
+    ).expect("Agent should be registered successfully");
+}
@@ -0,0 +1,246 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        tests.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Tests for the Agent Organizations pallet
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+use crate::{mock::*, Error, Event, OrgInspect};
+use frame_support::{assert_noop, assert_ok};
+use pallet_agent_registry::AgentRole;
+
+const ADMIN: u64 = 100;
+
+// Helper function to create an org administered by ADMIN
+fn create_org(name: &[u8]) -> u32 {
+    let org_id = Orgs::next_org_id();
+    assert_ok!(Orgs::create_org(RuntimeOrigin::signed(ADMIN), name.to_vec()));
+    org_id
+}
+
+// Helper function to invite an agent to an org and have it accept
+fn add_member(org_id: u32, agent_id: u64) {
+    assert_ok!(Orgs::invite_member(RuntimeOrigin::signed(ADMIN), org_id, agent_id));
+    assert_ok!(Orgs::accept_invite(RuntimeOrigin::signed(agent_id), org_id));
+}
+
+#[test]
+fn create_org_works() {
+    new_test_ext().execute_with(|| {
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        let org_id = create_org(b"C-Suite Production");
+
+        let org = Orgs::orgs(org_id).unwrap();
+        assert_eq!(org.admin, ADMIN);
+        assert_eq!(org.name.to_vec(), b"C-Suite Production".to_vec());
+        assert!(org.members.is_empty());
+        assert_eq!(Orgs::next_org_id(), org_id + 1);
+        assert_eq!(Orgs::org_deposits(org_id), Some((ADMIN, 50)));
+        assert_eq!(Balances::reserved_balance(ADMIN), 50);
+
+        System::assert_has_event(Event::OrgCreated {
+            org_id,
+            admin: ADMIN,
+            name: b"C-Suite Production".to_vec(),
+        }.into());
+    });
+}
+
+#[test]
+fn create_org_fails_with_empty_name() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Orgs::create_org(RuntimeOrigin::signed(ADMIN), vec![]),
+            Error::<Test>::InvalidOrgName
+        );
+    });
+}
+
+#[test]
+fn create_org_requires_deposit() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Orgs::create_org(RuntimeOrigin::signed(7), b"Squatted".to_vec()),
+            Error::<Test>::InsufficientDeposit
+        );
+    });
+}
+
+#[test]
+fn invited_agents_join_only_once_they_accept() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        register_test_agent(1, AgentRole::Lyra);
+        let org_id = create_org(b"Team");
+
+        assert_ok!(Orgs::invite_member(RuntimeOrigin::signed(ADMIN), org_id, 1));
+        System::assert_last_event(Event::MemberInvited { org_id, agent_id: 1 }.into());
+        assert_noop!(
+            Orgs::invite_member(RuntimeOrigin::signed(ADMIN), org_id, 1),
+            Error::<Test>::AlreadyInvited
+        );
+
+        // The invitation alone does not make the agent a member
+        assert!(Orgs::orgs(org_id).unwrap().members.is_empty());
+        assert_eq!(Orgs::agent_org(1), None);
+
+        assert_ok!(Orgs::accept_invite(RuntimeOrigin::signed(1), org_id));
+        System::assert_last_event(Event::MemberAdded { org_id, agent_id: 1 }.into());
+        assert!(Orgs::pending_invites(org_id).is_empty());
+
+        assert_eq!(Orgs::orgs(org_id).unwrap().members.to_vec(), vec![1]);
+        assert_eq!(Orgs::agent_org(1), Some(org_id));
+        assert_eq!(<Orgs as OrgInspect<u64>>::org_of(&1), Some(org_id));
+    });
+}
+
+#[test]
+fn invite_member_requires_admin_and_registered_agent() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        let org_id = create_org(b"Team");
+
+        assert_noop!(
+            Orgs::invite_member(RuntimeOrigin::signed(1), org_id, 1),
+            Error::<Test>::NotOrgAdmin
+        );
+        assert_noop!(
+            Orgs::invite_member(RuntimeOrigin::signed(ADMIN), org_id, 2),
+            Error::<Test>::AgentNotFound
+        );
+
+        // Nobody can join without an invitation
+        assert_noop!(
+            Orgs::accept_invite(RuntimeOrigin::signed(1), org_id),
+            Error::<Test>::NotInvited
+        );
+    });
+}
+
+#[test]
+fn agent_can_only_join_one_org() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        let first = create_org(b"First");
+        let second = create_org(b"Second");
+
+        // An agent invited to two orgs can only accept one of them
+        assert_ok!(Orgs::invite_member(RuntimeOrigin::signed(ADMIN), second, 1));
+        add_member(first, 1);
+        assert_noop!(
+            Orgs::accept_invite(RuntimeOrigin::signed(1), second),
+            Error::<Test>::AlreadyInOrg
+        );
+        assert_noop!(
+            Orgs::invite_member(RuntimeOrigin::signed(ADMIN), second, 1),
+            Error::<Test>::AlreadyInOrg
+        );
+    });
+}
+
+#[test]
+fn invite_member_fails_when_org_full() {
+    new_test_ext().execute_with(|| {
+        let org_id = create_org(b"Team");
+        for agent in 1..=4 {
+            register_test_agent(agent, AgentRole::Lyra);
+        }
+        for agent in 1..=2 {
+            add_member(org_id, agent);
+        }
+
+        // Pending invitations take up a place
+        assert_ok!(Orgs::invite_member(RuntimeOrigin::signed(ADMIN), org_id, 3));
+        assert_noop!(
+            Orgs::invite_member(RuntimeOrigin::signed(ADMIN), org_id, 4),
+            Error::<Test>::OrgFull
+        );
+    });
+}
+
+#[test]
+fn member_can_leave_org() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        let org_id = create_org(b"Team");
+        add_member(org_id, 1);
+
+        assert_ok!(Orgs::remove_member(RuntimeOrigin::signed(1), org_id, 1));
+
+        assert!(Orgs::orgs(org_id).unwrap().members.is_empty());
+        assert_eq!(Orgs::agent_org(1), None);
+    });
+}
+
+#[test]
+fn dissolve_org_releases_members() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        register_test_agent(2, AgentRole::Echo);
+        let org_id = create_org(b"Team");
+        add_member(org_id, 1);
+        add_member(org_id, 2);
+
+        register_test_agent(3, AgentRole::Verdict);
+        assert_ok!(Orgs::invite_member(RuntimeOrigin::signed(ADMIN), org_id, 3));
+
+        // The deposit goes back to the creator even after a handover
+        assert_ok!(Orgs::transfer_admin(RuntimeOrigin::signed(ADMIN), org_id, 1));
+        assert_ok!(Orgs::dissolve_org(RuntimeOrigin::signed(1), org_id));
+
+        assert!(Orgs::orgs(org_id).is_none());
+        assert_eq!(Orgs::agent_org(1), None);
+        assert_eq!(Orgs::agent_org(2), None);
+        assert!(Orgs::pending_invites(org_id).is_empty());
+        assert_eq!(Orgs::org_deposits(org_id), None);
+        assert_eq!(Balances::reserved_balance(ADMIN), 0);
+
+        // Invitations lapse with the org
+        assert_noop!(
+            Orgs::accept_invite(RuntimeOrigin::signed(3), org_id),
+            Error::<Test>::NotInvited
+        );
+    });
+}
+
+#[test]
+fn same_org_checks_every_agent() {
+    new_test_ext().execute_with(|| {
+        for agent in 1..=3 {
+            register_test_agent(agent, AgentRole::Lyra);
+        }
+        let org_id = create_org(b"Team");
+        add_member(org_id, 1);
+        add_member(org_id, 2);
+
+        assert!(<Orgs as OrgInspect<u64>>::same_org(&[1, 2]));
+        assert!(!<Orgs as OrgInspect<u64>>::same_org(&[1, 2, 3]));
+        assert!(!<Orgs as OrgInspect<u64>>::same_org(&[]));
+    });
+}
+
+#[test]
+fn org_trust_aggregates_member_scores() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        register_test_agent(2, AgentRole::Echo);
+        assert_ok!(AgentRegistry::update_trust_score(RuntimeOrigin::signed(1), 1, 30));
+        assert_ok!(AgentRegistry::update_trust_score(RuntimeOrigin::signed(2), 2, 10));
+
+        let org_id = create_org(b"Team");
+        add_member(org_id, 1);
+        add_member(org_id, 2);
+
+        assert_eq!(Orgs::org_trust_score(org_id), Some(40));
+        assert_eq!(Orgs::org_average_trust(org_id), Some(20));
+        assert_eq!(Orgs::org_trust_score(org_id + 1), None);
+    });
+}
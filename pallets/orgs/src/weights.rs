@@ -0,0 +1,134 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        weights.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Weight implementations for the agent organizations pallet
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! Weights for pallet_orgs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for pallet_orgs.
+pub trait WeightInfo {
+    fn create_org() -> Weight;
+    fn invite_member() -> Weight;
+    fn accept_invite() -> Weight;
+    fn remove_member() -> Weight;
+    fn transfer_admin() -> Weight;
+    fn dissolve_org(m: u32) -> Weight;
+}
+
+/// Weights for pallet_orgs using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    // Storage: Orgs NextOrgId (r:1 w:1)
+    // Storage: System Account (r:1 w:1)
+    // Storage: Orgs Orgs (r:0 w:1)
+    // Storage: Orgs OrgDeposits (r:0 w:1)
+    fn create_org() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+
+    // Storage: Orgs Orgs (r:1 w:0)
+    // Storage: AgentRegistry Agents (r:1 w:0)
+    // Storage: Orgs AgentOrg (r:1 w:0)
+    // Storage: Orgs PendingInvites (r:1 w:1)
+    fn invite_member() -> Weight {
+        Weight::from_parts(24_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    // Storage: AgentRegistry Agents (r:1 w:0)
+    // Storage: Orgs AgentOrg (r:1 w:1)
+    // Storage: Orgs PendingInvites (r:1 w:1)
+    // Storage: Orgs Orgs (r:1 w:1)
+    fn accept_invite() -> Weight {
+        Weight::from_parts(26_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+
+    // Storage: Orgs Orgs (r:1 w:1)
+    // Storage: Orgs AgentOrg (r:0 w:1)
+    fn remove_member() -> Weight {
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    // Storage: Orgs Orgs (r:1 w:1)
+    fn transfer_admin() -> Weight {
+        Weight::from_parts(16_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    // Storage: Orgs Orgs (r:1 w:1)
+    // Storage: Orgs PendingInvites (r:0 w:1)
+    // Storage: Orgs OrgDeposits (r:1 w:1)
+    // Storage: System Account (r:1 w:1)
+    // Storage: Orgs AgentOrg (r:0 w:m)
+    fn dissolve_org(m: u32) -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(Weight::from_parts(2_500_000, 0).saturating_mul(m as u64))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(4))
+            .saturating_add(T::DbWeight::get().writes(m as u64))
+    }
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+    fn create_org() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(4))
+    }
+
+    fn invite_member() -> Weight {
+        Weight::from_parts(24_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    fn accept_invite() -> Weight {
+        Weight::from_parts(26_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+
+    fn remove_member() -> Weight {
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+
+    fn transfer_admin() -> Weight {
+        Weight::from_parts(16_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    fn dissolve_org(m: u32) -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(Weight::from_parts(2_500_000, 0).saturating_mul(m as u64))
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(4))
+            .saturating_add(RocksDbWeight::get().writes(m as u64))
+    }
+}
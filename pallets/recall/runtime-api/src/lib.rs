@@ -0,0 +1,46 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        lib.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Runtime APIs for exporting recall records
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! Runtime APIs used by indexers and archival nodes to export recall records in bounded,
+//! resumable pages or by range of blocks, and by light clients to read decompressed record
+//! content.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+
+pub use pallet_recall::{RecordContent, RecordExportPage};
+
+sp_api::decl_runtime_apis! {
+    /// Bulk export of recall records
+    #[api_version(3)]
+    pub trait RecallApi<BlockNumber>
+    where
+        BlockNumber: Codec,
+    {
+        /// Records from `start_id` onwards, at most `max_bytes` of them encoded, along with the
+        /// ID to resume from
+        ///
+        /// Querying every page at the same block gives a consistent snapshot of all records.
+        fn export_records(start_id: u64, max_bytes: u32) -> RecordExportPage;
+
+        /// Summary and metadata of a record, decompressed if it was stored compressed
+        #[api_version(2)]
+        fn record_content(record_id: u64) -> Option<RecordContent>;
+
+        /// Records created from `start_block` to `end_block`, both included, in ID order, along
+        /// with the ID to pass as `cursor` for the next page
+        #[api_version(3)]
+        fn records_between(start_block: BlockNumber, end_block: BlockNumber, cursor: Option<u64>) -> RecordExportPage;
+    }
+}
@@ -0,0 +1,105 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        benchmarking.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Benchmarking for the Recall pallet
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! Benchmarking for the Recall pallet.
+//!
+//! Besides the calls, [`read_record`](benchmarks::read_record) measures the proof size of a
+//! single record lookup, which is what a light client or bridge pays to prove a record.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as Recall;
+use frame_benchmarking::v2::*;
+use frame_support::{traits::Currency, BoundedVec};
+use frame_system::RawOrigin;
+use sp_std::vec;
+
+// Helper function to fund an account with enough for several permanent record deposits
+fn funded_account<T: Config>(name: &'static str) -> T::AccountId {
+    let who: T::AccountId = account(name, 0, 0);
+    let balance = Recall::<T>::deposit_for(RetentionClass::Permanent).saturating_mul(10u32.into());
+    T::Currency::make_free_balance_be(&who, balance.saturating_add(1_000_000u32.into()));
+    who
+}
+
+// Helper function to build an IPFS locator of the maximum length
+fn max_locator<T: Config>() -> ContentLocator<T> {
+    let mut cid = b"Qm".to_vec();
+    cid.resize(T::MaxLocatorLength::get() as usize, b'a');
+    ContentLocator::Ipfs(BoundedVec::truncate_from(cid))
+}
+
+// Helper function to store a record of the maximum size
+fn store_record<T: Config>(who: &T::AccountId, seed: u8) -> u64 {
+    let record_id = Recall::<T>::next_record_id();
+    Recall::<T>::store_consensus_record(
+        RawOrigin::Signed(who.clone()).into(),
+        RecordType::MultiAgentConsensus,
+        vec![seed; T::MaxContentHashLength::get() as usize],
+        max_locator::<T>(),
+        vec![seed; T::MaxSummaryLength::get() as usize],
+        vec![seed; T::MaxContentHashLength::get() as usize],
+        Some(vec![seed; T::MaxMetadataLength::get() as usize]),
+        RetentionClass::Permanent,
+        CompressionCodec::None,
+    ).expect("Failed to store record");
+    record_id
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    // Worst case: every field at its maximum length, and an ephemeral record, which is also
+    // scheduled for pruning
+    #[benchmark]
+    fn store_consensus_record() {
+        let caller = funded_account::<T>("caller");
+        let record_id = Recall::<T>::next_record_id();
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(caller),
+            RecordType::MultiAgentConsensus,
+            vec![1u8; T::MaxContentHashLength::get() as usize],
+            max_locator::<T>(),
+            vec![1u8; T::MaxSummaryLength::get() as usize],
+            vec![1u8; T::MaxContentHashLength::get() as usize],
+            Some(vec![1u8; T::MaxMetadataLength::get() as usize]),
+            RetentionClass::Ephemeral,
+            CompressionCodec::None,
+        );
+
+        assert!(Recall::<T>::records(record_id).is_some());
+    }
+
+    // Reads a stored record and its expiry; the recorded proof size covers both keys
+    #[benchmark]
+    fn read_record() {
+        let caller = funded_account::<T>("caller");
+        let record_id = store_record::<T>(&caller, 1);
+
+        #[block]
+        {
+            assert!(Records::<T>::get(record_id).is_some());
+            let _ = RecordExpiry::<T>::get(record_id);
+        }
+    }
+
+    impl_benchmark_test_suite!(
+        Recall,
+        crate::mock::new_test_ext(),
+        crate::mock::Test,
+    );
+}
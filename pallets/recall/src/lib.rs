@@ -19,20 +19,368 @@
 //!
 //! This pallet provides functionality to:
 //! - Store consensus records from agent insights
-//! - Track IPFS content identifiers (CIDs) for off-chain data
+//! - Track where the off-chain data is kept, on IPFS or another storage backend
 //! - Manage agent signatures and verification
 //! - Query historical records and insights
 //!
 //! Each record in the Recall system contains:
 //! - Content hash for integrity verification
-//! - IPFS CID for decentralized storage
+//! - Content locator for the full data on its storage backend
 //! - Agent signatures for authenticity
 //! - Metadata about the insight or consensus
+//!
+//! ## Trust Scores
+//!
+//! A record's trust score is derived from the agents that signed it. Each signer contributes
+//! its effective reputation passed through the configured [`WeightingCurve`], boosted by its
+//! share of the total stake and capped by `MaxSignerContribution`. Signer reputation is read
+//! through the [`SignerWeightProvider`] trait, and scores are recomputed whenever the runtime
+//! reports a reputation change via [`Pallet::note_reputation_change`]. The affected records are
+//! re-scored with the weight left over at the end of later blocks.
+//!
+//! ## Audits
+//!
+//! Every `AuditInterval` blocks a random sample of records is selected and an audit is opened
+//! for each, assigned to members of the [`AuditCommittee`] that did not sign the record.
+//! Every audit carries a fresh nonce, and auditors prove they hold the content by hashing it
+//! together with the nonce ([`Pallet::audit_proof`]), which the public content hash does not
+//! allow. Proofs are committed to first and revealed once every auditor committed or half the
+//! `AuditDeadline` has passed, so auditors cannot copy each other. The proof most auditors agree
+//! on marks the content intact. Auditors that have not revealed by the deadline are reported
+//! through [`OnAuditMissed`] so the runtime can penalize their reputation.
+//!
+//! ## Signature Requests
+//!
+//! The owner of a record, the account that stored it and holds its deposit, can ask an agent
+//! to co-sign it with [`request_signature`](Pallet::request_signature), giving it a deadline
+//! from `MinRequestPeriod` to `MaxRequestPeriod` blocks away. The record is listed in the
+//! agent's pending duties in [`PendingSignatureRequests`](pallet::PendingSignatureRequests),
+//! oldest request first, until the agent signs it with `add_signature` or the deadline is
+//! reached. Every request reserves `SignatureRequestDeposit` from its requester, returned once
+//! the request is answered or expires, so opening requests is not free. Requests expire
+//! automatically with a `SignatureRequestExpired` event; when
+//! `ReportMissedRequests` is enabled the agent is also reported through
+//! [`OnSignatureRequestMissed`] so the runtime can treat it as unresponsive. Requests on
+//! records pruned or redacted before their deadline are dropped silently.
+//!
+//! ## Content Locators
+//!
+//! A record points at its full content with a [`ContentLocator`](pallet::ContentLocator) for one
+//! of the supported [`StorageBackend`]s: an IPFS CID, an Arweave transaction ID, the hash of an
+//! HTTPS URL or an S3 object key. Each kind is validated on submission, and records can only
+//! point at backends governance allows with
+//! [`set_allowed_backends`](Pallet::set_allowed_backends). Only IPFS is allowed until governance
+//! changes it.
+//!
+//! ## Retention
+//!
+//! Every record carries a [`RetentionClass`] chosen by its submitter, who reserves the deposit
+//! priced for that class:
+//! - `Ephemeral` records are cheapest and are pruned `EphemeralRetention` blocks after they are
+//!   stored, returning the deposit
+//! - `Standard` records are kept indefinitely
+//! - `Permanent` records carry the largest deposit and are never pruned
+//!
+//! Governance can move a record to another class with
+//! [`reclassify_record`](Pallet::reclassify_record); the submitter's deposit is topped up or
+//! partially returned to match the new class.
+//!
+//! ## Redaction
+//!
+//! Governance can redact a record with [`redact_record`](Pallet::redact_record), e.g. to
+//! comply with a takedown request. The record's CID, summary and metadata are dropped and it no
+//! longer shows up in queries; a [`RecordTombstone`](pallet::RecordTombstone) keeps its content
+//! hash, original signers and the [`RedactionReason`]. The content hash stays claimed, so the
+//! same content cannot be stored again, and the record's deposit is returned.
+//!
+//! ## Disputes
+//!
+//! Anyone can challenge a signed record with [`challenge_record`](Pallet::challenge_record).
+//! Each signer has `DisputeEscrow` of its stake, scaled by its share of the record's trust
+//! contributions, moved to the record's escrow account, a sub-account of `PalletId`, through
+//! [`StakeEscrow`], so the signers that vouched most for the record have the most at risk. The
+//! escrowed stake cannot be unstaked. The challenger locks a bond in the same account:
+//! `ChallengeBondRatio` of the stake it escrows, and at least `ChallengeBond`, so tying up more
+//! stake costs more. A signer's stake is escrowed by at most `MaxDisputesPerSigner` disputes at
+//! once; further challenges leave it out.
+//!
+//! `DisputeOrigin` resolves the dispute with [`resolve_dispute`](Pallet::resolve_dispute). An
+//! upheld challenge slashes the escrowed stake through `DisputeSlash` and returns the bond; a
+//! rejected one restores the escrowed stake to its signers and forfeits the bond. A dispute left
+//! unresolved for `DisputePeriod` expires, restoring the escrowed stake and returning the bond.
+//! Disputes stay open if the record is pruned or redacted in the meantime, and follow signers
+//! that rotate their key through [`Pallet::on_agent_rotated`].
+//!
+//! ## Emergency Pause
+//!
+//! `PauseOrigin` can halt the pallet with `pause` during an incident. While paused, storing,
+//! signing, challenging and auditing records fails with `PalletPaused`. Governance calls, queries, pruning and
+//! audit rounds keep working.
+//!
+//! ## System Records
+//!
+//! When `SystemRecords` is enabled the runtime reports agent status changes and slashes through
+//! [`Pallet::note_system_record`], which stores them as unsigned `AgentStatusUpdate` and
+//! `SystemEvent` records. Their summary is the SCALE-encoded agent and [`SystemRecord`], so the
+//! pallet doubles as a single audit log of agent lifecycle events.
+//!
+//! ## Bulk Export
+//!
+//! [`Pallet::export_records`] returns records in ID order as a bounded [`RecordExportPage`]
+//! with a cursor to resume from, so indexers and archival nodes can export every record through
+//! the `RecallApi` runtime API instead of raw state queries. Pruned and redacted records are
+//! skipped.
+//!
+//! [`Pallet::records_between`] pages through the records created in a range of blocks. Records
+//! are indexed in `RecordsByBlockBucket` by the bucket of `BucketSize` blocks they were created
+//! in, so a range query only reads the buckets it covers rather than every record.
+//!
+//! ## Duplicate Summaries
+//!
+//! When `IndexSummaries` is enabled every stored record's summary is hashed into
+//! [`SummaryHashToRecord`](pallet::SummaryHashToRecord). A record whose summary is byte-identical
+//! to an earlier one, as is common with templated agent output, is still stored, but it is
+//! linked to the earlier record in [`DuplicateSummaryOf`](pallet::DuplicateSummaryOf) and a
+//! `DuplicateSummary` event is emitted so analytics can deduplicate insight streams. Empty
+//! summaries are not indexed, and records stored before the index existed are not linked.
+//!
+//! ## Compression
+//!
+//! A record's summary and metadata can be submitted compressed with a [`CompressionCodec`],
+//! so long summaries fit within `MaxSummaryLength` and `MaxMetadataLength`. Compressed payloads
+//! are inflated on submission and rejected unless they are valid and decompress to at most
+//! `MaxDecompressedLength` bytes each. Records keep the compressed bytes; light clients read the
+//! decompressed content through [`Pallet::record_content`] and the `RecallApi` runtime API.
+//! Summary duplicates are detected on the stored bytes.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use pallet::*;
 
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use frame_support::weights::Weight;
+use sp_runtime::{traits::{IntegerSquareRoot, Zero}, Perbill, RuntimeDebug};
+use sp_std::vec::Vec;
+
+/// Supplies the reputation data used to weight record signers
+pub trait SignerWeightProvider<AccountId> {
+    /// Effective (stake-weighted) reputation of a signer
+    fn effective_reputation(who: &AccountId) -> u64;
+    /// Signer's share of the total stake
+    fn stake_weight(who: &AccountId) -> Perbill;
+}
+
+impl<AccountId> SignerWeightProvider<AccountId> for () {
+    fn effective_reputation(_who: &AccountId) -> u64 {
+        0
+    }
+
+    fn stake_weight(_who: &AccountId) -> Perbill {
+        Perbill::zero()
+    }
+}
+
+/// Supplies the committee whose members are assigned record audits
+pub trait AuditCommittee<AccountId> {
+    /// Current committee members
+    fn members() -> Vec<AccountId>;
+}
+
+impl<AccountId> AuditCommittee<AccountId> for () {
+    fn members() -> Vec<AccountId> {
+        Vec::new()
+    }
+}
+
+/// Notified for every auditor that lets an audit deadline pass without attesting
+pub trait OnAuditMissed<AccountId> {
+    /// Handle the missed audit, returning the weight consumed
+    fn on_audit_missed(auditor: &AccountId) -> Weight;
+}
+
+impl<AccountId> OnAuditMissed<AccountId> for () {
+    fn on_audit_missed(_auditor: &AccountId) -> Weight {
+        Weight::zero()
+    }
+}
+
+/// Notified for every agent that lets a signature request on a live record expire unanswered
+pub trait OnSignatureRequestMissed<AccountId> {
+    /// Handle the unanswered request, returning the weight consumed
+    fn on_signature_request_missed(agent_id: &AccountId, record_id: u64) -> Weight;
+}
+
+impl<AccountId> OnSignatureRequestMissed<AccountId> for () {
+    fn on_signature_request_missed(_agent_id: &AccountId, _record_id: u64) -> Weight {
+        Weight::zero()
+    }
+}
+
+/// Moves signer stake into and out of the escrow of disputed records
+pub trait StakeEscrow<AccountId, Balance> {
+    /// Move `portion` of an agent's stake to the free balance of the existing `escrow` account,
+    /// returning the amount moved
+    fn escrow_stake(agent_id: &AccountId, escrow: &AccountId, portion: Perbill) -> Balance;
+    /// Stake `amount` of an agent's free balance again after it was released from an escrow
+    fn restore_stake(agent_id: &AccountId, amount: Balance);
+}
+
+impl<AccountId, Balance: Zero> StakeEscrow<AccountId, Balance> for () {
+    fn escrow_stake(_agent_id: &AccountId, _escrow: &AccountId, _portion: Perbill) -> Balance {
+        Zero::zero()
+    }
+
+    fn restore_stake(_agent_id: &AccountId, _amount: Balance) {}
+}
+
+/// Curve applied to a signer's effective reputation before it counts towards a trust score
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum WeightingCurve {
+    /// Contribution grows linearly with reputation
+    Linear,
+    /// Contribution grows with the square root of reputation, damping high-reputation signers
+    SquareRoot,
+    /// Contribution grows with the base-2 logarithm of reputation, scaled by 100
+    Logarithmic,
+}
+
+impl WeightingCurve {
+    /// Apply the curve to a reputation value
+    pub fn apply(&self, reputation: u64) -> u64 {
+        match self {
+            Self::Linear => reputation,
+            Self::SquareRoot => reputation.integer_sqrt(),
+            Self::Logarithmic => {
+                let log2 = u64::from(u64::BITS - reputation.saturating_add(1).leading_zeros())
+                    .saturating_sub(1);
+                log2.saturating_mul(100)
+            },
+        }
+    }
+}
+
+/// Storage backend a record's full content is kept on
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, Ord, PartialOrd, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum StorageBackend {
+    /// The InterPlanetary File System
+    Ipfs,
+    /// Arweave permanent storage
+    Arweave,
+    /// A plain HTTPS server
+    Https,
+    /// An S3-compatible object store
+    S3,
+}
+
+/// Number of [`StorageBackend`]s
+pub const MAX_STORAGE_BACKENDS: u32 = 4;
+
+/// How long a record is kept and how much deposit it requires
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum RetentionClass {
+    /// Pruned automatically once the ephemeral retention period has passed
+    Ephemeral,
+    /// Kept until removed by governance
+    Standard,
+    /// Never pruned
+    Permanent,
+}
+
+impl Default for RetentionClass {
+    fn default() -> Self {
+        Self::Standard
+    }
+}
+
+/// Why a record was redacted
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum RedactionReason {
+    /// The record exposed personal data
+    PersonalData,
+    /// A court order or other legal request
+    LegalRequest,
+    /// The content infringes intellectual property
+    Infringement,
+    /// The content is harmful or illegal
+    HarmfulContent,
+    /// Any other reason, identified by an off-chain code
+    Other(u16),
+}
+
+/// How a dispute over a record was resolved
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum DisputeOutcome {
+    /// The record was found faulty: the escrowed stake is slashed and the bond returned
+    Upheld,
+    /// The record stands: the escrowed stake is restored and the bond forfeited
+    Rejected,
+}
+
+/// Body of a record the pallet writes on its own for agent lifecycle events
+///
+/// Stored SCALE-encoded, together with the agent it concerns, as the summary of the record.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum SystemRecord {
+    /// The agent's status changed; statuses are given by their SCALE index
+    StatusChanged { from: u8, to: u8 },
+    /// The agent was slashed
+    Slashed { reputation_penalty: u64 },
+}
+
+/// Codec a record's summary and metadata are compressed with
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum CompressionCodec {
+    /// Stored as submitted
+    None,
+    /// Raw DEFLATE stream (RFC 1951)
+    Deflate,
+}
+
+impl Default for CompressionCodec {
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+impl CompressionCodec {
+    /// Decompress `data`, failing if it is not a valid stream or inflates beyond `limit` bytes
+    ///
+    /// Uncompressed data is returned as is, regardless of `limit`.
+    pub fn decompress(&self, data: &[u8], limit: u32) -> Option<Vec<u8>> {
+        match self {
+            Self::None => Some(data.to_vec()),
+            Self::Deflate => miniz_oxide::inflate::decompress_to_vec_with_limit(data, limit as usize).ok(),
+        }
+    }
+}
+
+/// Decompressed summary and metadata of a record, returned by [`Pallet::record_content`]
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct RecordContent {
+    /// Brief summary of the insight/consensus
+    pub summary: Vec<u8>,
+    /// Optional metadata (JSON-encoded additional info)
+    pub metadata: Option<Vec<u8>>,
+}
+
+/// A page of records returned by [`Pallet::export_records`]
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct RecordExportPage {
+    /// SCALE-encoded `Vec<(u64, ConsensusRecord)>` of the exported records, in ID order
+    pub records: Vec<u8>,
+    /// Record ID to resume the export from, or `None` once every record was exported
+    pub next_id: Option<u64>,
+}
+
+/// Maximum number of record IDs looked at for a single export page
+pub const EXPORT_SCAN_LIMIT: u64 = 1_000;
+
+/// Maximum number of records in a single page of [`Pallet::records_between`]
+pub const RANGE_PAGE_LIMIT: usize = 100;
+
+pub mod migrations;
+
 #[cfg(test)]
 mod mock;
 
@@ -44,10 +392,30 @@ mod benchmarking;
 
 #[frame_support::pallet]
 pub mod pallet {
-    use frame_support::pallet_prelude::*;
+    use super::*;
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{Currency, ExistenceRequirement, OnUnbalanced, Randomness, ReservableCurrency},
+        PalletId,
+    };
     use frame_system::pallet_prelude::*;
     use sp_std::vec::Vec;
-    use sp_runtime::traits::{Saturating, Zero};
+    use sp_runtime::traits::{AccountIdConversion, Hash, One, Saturating, TrailingZeroInput};
+
+    /// Subject used when drawing randomness for audit sampling
+    const AUDIT_RANDOM_SUBJECT: &[u8] = b"recall/audit";
+
+    /// Identifier of an audit task
+    pub type AuditId = u64;
+
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+    pub type NegativeImbalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
+
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
@@ -58,9 +426,9 @@ pub mod pallet {
         #[pallet::constant]
         type MaxContentHashLength: Get<u32>;
         
-        /// Maximum length for IPFS CID
+        /// Maximum length of an IPFS CID or S3 object key
         #[pallet::constant]
-        type MaxIpfsCidLength: Get<u32>;
+        type MaxLocatorLength: Get<u32>;
         
         /// Maximum length for insight summary
         #[pallet::constant]
@@ -73,9 +441,166 @@ pub mod pallet {
         /// Maximum number of agent signatures per record
         #[pallet::constant]
         type MaxSignatures: Get<u32>;
+
+        /// Source of signer reputation and stake weight
+        type SignerWeights: SignerWeightProvider<Self::AccountId>;
+
+        /// Curve applied to each signer's effective reputation
+        #[pallet::constant]
+        type TrustCurve: Get<WeightingCurve>;
+
+        /// Trust score every record starts from before signer contributions
+        #[pallet::constant]
+        type BaseTrustScore: Get<u64>;
+
+        /// Upper bound on what a single signer can add to a trust score
+        #[pallet::constant]
+        type MaxSignerContribution: Get<u64>;
+
+        /// Maximum number of an agent's records re-scored after a reputation change
+        #[pallet::constant]
+        type MaxRecomputePerAgent: Get<u32>;
+
+        /// Source of randomness for audit sampling
+        type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
+
+        /// Committee whose members are assigned audits
+        type AuditCommittee: AuditCommittee<Self::AccountId>;
+
+        /// Hook invoked for every auditor that misses an audit deadline
+        type OnAuditMissed: OnAuditMissed<Self::AccountId>;
+
+        /// Number of blocks between audit rounds (zero disables auditing)
+        #[pallet::constant]
+        type AuditInterval: Get<BlockNumberFor<Self>>;
+
+        /// Number of records sampled per audit round
+        #[pallet::constant]
+        type AuditSampleSize: Get<u32>;
+
+        /// Number of committee agents assigned to each audit
+        #[pallet::constant]
+        type AuditorsPerAudit: Get<u32>;
+
+        /// Number of blocks auditors have to attest after an audit opens
+        #[pallet::constant]
+        type AuditDeadline: Get<BlockNumberFor<Self>>;
+
+        /// Currency used for record deposits
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// Deposit reserved for an ephemeral record
+        #[pallet::constant]
+        type EphemeralDeposit: Get<BalanceOf<Self>>;
+
+        /// Deposit reserved for a standard record
+        #[pallet::constant]
+        type StandardDeposit: Get<BalanceOf<Self>>;
+
+        /// Deposit reserved for a permanent record
+        #[pallet::constant]
+        type PermanentDeposit: Get<BalanceOf<Self>>;
+
+        /// Number of blocks an ephemeral record is kept before it is pruned
+        #[pallet::constant]
+        type EphemeralRetention: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of ephemeral records that can expire in a single block
+        #[pallet::constant]
+        type MaxPrunesPerBlock: Get<u32>;
+
+        /// Origin allowed to pause and unpause the pallet
+        type PauseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Whether agent status changes and slashes are written as system records
+        #[pallet::constant]
+        type SystemRecords: Get<bool>;
+
+        /// Whether record summaries are hashed to detect byte-identical duplicates
+        #[pallet::constant]
+        type IndexSummaries: Get<bool>;
+
+        /// Maximum size of a compressed summary or metadata payload once decompressed
+        #[pallet::constant]
+        type MaxDecompressedLength: Get<u32>;
+
+        /// Number of blocks covered by each bucket of the `RecordsByBlockBucket` index
+        #[pallet::constant]
+        type BucketSize: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of open signature requests per agent
+        #[pallet::constant]
+        type MaxPendingRequests: Get<u32>;
+
+        /// Maximum number of signature requests expiring in the same block
+        #[pallet::constant]
+        type MaxRequestsPerDeadline: Get<u32>;
+
+        /// Minimum number of blocks an agent is given to answer a signature request
+        #[pallet::constant]
+        type MinRequestPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of blocks a signature request can stay open
+        #[pallet::constant]
+        type MaxRequestPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Deposit reserved from the requester while a signature request is open
+        #[pallet::constant]
+        type SignatureRequestDeposit: Get<BalanceOf<Self>>;
+
+        /// Whether agents that let a signature request expire are reported through
+        /// `OnSignatureRequestMissed`
+        #[pallet::constant]
+        type ReportMissedRequests: Get<bool>;
+
+        /// Handler for agents that let a signature request expire
+        type OnSignatureRequestMissed: OnSignatureRequestMissed<Self::AccountId>;
+
+        /// Pallet ID the escrow accounts of disputed records are derived from
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Origin allowed to resolve disputes over records
+        type DisputeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Smallest bond a challenger locks in the record's escrow account until the dispute
+        /// is resolved
+        ///
+        /// Keeps the escrow account alive, so it must be at least the existential deposit.
+        #[pallet::constant]
+        type ChallengeBond: Get<BalanceOf<Self>>;
+
+        /// Bond a challenger locks as a share of the signer stake the challenge escrows, if
+        /// above `ChallengeBond`
+        #[pallet::constant]
+        type ChallengeBondRatio: Get<Perbill>;
+
+        /// Maximum number of open disputes escrowing the stake of one signer
+        #[pallet::constant]
+        type MaxDisputesPerSigner: Get<u32>;
+
+        /// Blocks after which an unresolved dispute expires
+        #[pallet::constant]
+        type DisputePeriod: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of disputes expiring in the same block
+        #[pallet::constant]
+        type MaxDisputesPerBlock: Get<u32>;
+
+        /// Share of a signer's stake escrowed when a record is challenged, scaled by the signer's
+        /// share of the record's trust contributions
+        #[pallet::constant]
+        type DisputeEscrow: Get<Perbill>;
+
+        /// Moves signer stake into and out of dispute escrows
+        type StakeEscrow: StakeEscrow<Self::AccountId, BalanceOf<Self>>;
+
+        /// Handler for stake slashed by upheld disputes and bonds forfeited by rejected ones
+        type DisputeSlash: OnUnbalanced<NegativeImbalanceOf<Self>>;
     }
 
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// Record type enum
@@ -109,6 +634,45 @@ pub mod pallet {
         pub signed_at: BlockNumberFor<T>,
     }
 
+    /// Where a record's full content is kept
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub enum ContentLocator<T: Config> {
+        /// IPFS content identifier, a CIDv0 (`Qm...`) or base32 CIDv1 (`b...`)
+        Ipfs(BoundedVec<u8, T::MaxLocatorLength>),
+        /// Arweave transaction ID
+        Arweave([u8; 32]),
+        /// Hash of the HTTPS URL the content is served from
+        Https([u8; 32]),
+        /// Key of the object in an S3-compatible bucket
+        S3(BoundedVec<u8, T::MaxLocatorLength>),
+    }
+
+    impl<T: Config> ContentLocator<T> {
+        /// Backend the content is kept on
+        pub fn backend(&self) -> StorageBackend {
+            match self {
+                Self::Ipfs(_) => StorageBackend::Ipfs,
+                Self::Arweave(_) => StorageBackend::Arweave,
+                Self::Https(_) => StorageBackend::Https,
+                Self::S3(_) => StorageBackend::S3,
+            }
+        }
+
+        /// Whether the locator is well-formed for its backend
+        pub fn is_valid(&self) -> bool {
+            match self {
+                Self::Ipfs(cid) => match cid.split_first() {
+                    Some((b'b', rest)) =>
+                        !rest.is_empty() && rest.iter().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit()),
+                    _ => cid.starts_with(b"Qm") && cid.iter().all(|c| c.is_ascii_alphanumeric()),
+                },
+                Self::Arweave(id) | Self::Https(id) => *id != [0u8; 32],
+                Self::S3(key) => key.first().map_or(false, |c| *c != b'/') && key.iter().all(|c| c.is_ascii_graphic()),
+            }
+        }
+    }
+
     /// A consensus record stored on-chain
     #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
     #[scale_info(skip_type_params(T))]
@@ -117,8 +681,8 @@ pub mod pallet {
         pub record_type: RecordType,
         /// Hash of the content for integrity verification
         pub content_hash: BoundedVec<u8, T::MaxContentHashLength>,
-        /// IPFS CID where full content is stored
-        pub ipfs_cid: BoundedVec<u8, T::MaxIpfsCidLength>,
+        /// Where the full content is stored
+        pub locator: ContentLocator<T>,
         /// Brief summary of the insight/consensus
         pub summary: BoundedVec<u8, T::MaxSummaryLength>,
         /// Agent signatures (at least one required)
@@ -129,6 +693,77 @@ pub mod pallet {
         pub metadata: Option<BoundedVec<u8, T::MaxMetadataLength>>,
         /// Trust score calculated from participating agents
         pub trust_score: u64,
+        /// How long the record is kept
+        pub retention: RetentionClass,
+        /// Codec the summary and metadata are compressed with
+        pub compression: CompressionCodec,
+    }
+
+    /// What is kept of a redacted record
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct RecordTombstone<T: Config> {
+        /// Type the record had
+        pub record_type: RecordType,
+        /// Hash of the redacted content
+        pub content_hash: BoundedVec<u8, T::MaxContentHashLength>,
+        /// Agents that had signed the record
+        pub signers: BoundedVec<T::AccountId, T::MaxSignatures>,
+        /// When the record was originally created
+        pub created_at: BlockNumberFor<T>,
+        /// When the record was redacted
+        pub redacted_at: BlockNumberFor<T>,
+        /// Why the record was redacted
+        pub reason: RedactionReason,
+    }
+
+    /// An open dispute over a record
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Dispute<T: Config> {
+        /// Account that challenged the record
+        pub challenger: T::AccountId,
+        /// Bond the challenger locked in the escrow account
+        pub bond: BalanceOf<T>,
+        /// Stake escrowed from each signer that had any
+        pub escrowed: BoundedVec<(T::AccountId, BalanceOf<T>), T::MaxSignatures>,
+        /// When the record was challenged
+        pub opened_at: BlockNumberFor<T>,
+        /// When the dispute expires unless resolved
+        pub expires_at: BlockNumberFor<T>,
+    }
+
+    /// Verdict reported by an auditor
+    #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum AuditVerdict {
+        /// Content was retrieved and matches the record's content hash
+        Intact,
+        /// Content was retrieved but does not match the record's content hash
+        Corrupted,
+        /// Content could not be retrieved
+        Unavailable,
+    }
+
+    /// An open audit of a single record
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct AuditTask<T: Config> {
+        /// The record being audited
+        pub record_id: u64,
+        /// Committee agents assigned to the audit
+        pub auditors: BoundedVec<T::AccountId, T::AuditorsPerAudit>,
+        /// Nonce drawn when the audit was opened, which proofs hash the content with
+        pub nonce: T::Hash,
+        /// Auditors' commitments to their proofs
+        pub commitments: BoundedVec<(T::AccountId, T::Hash), T::AuditorsPerAudit>,
+        /// Proofs revealed so far, `None` where the content could not be retrieved
+        pub proofs: BoundedVec<(T::AccountId, Option<T::Hash>), T::AuditorsPerAudit>,
+        /// When the audit was opened
+        pub opened_at: BlockNumberFor<T>,
+        /// Block from which proofs are revealed, earlier if every auditor committed
+        pub reveal_at: BlockNumberFor<T>,
+        /// Block at which auditors that have not revealed are reported
+        pub deadline: BlockNumberFor<T>,
     }
 
     /// Storage for all consensus records
@@ -136,7 +771,7 @@ pub mod pallet {
     #[pallet::getter(fn records)]
     pub type Records<T: Config> = StorageMap<
         _,
-        Blake2_128Concat,
+        Twox64Concat,
         u64,  // Record ID (auto-incrementing)
         ConsensusRecord<T>,
         OptionQuery,
@@ -158,6 +793,28 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Index mapping the hash of a record summary to the first live record carrying it
+    #[pallet::storage]
+    #[pallet::getter(fn summary_hash_to_record)]
+    pub type SummaryHashToRecord<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::Hash,
+        u64,
+        OptionQuery,
+    >;
+
+    /// Earlier record each record duplicates the summary of
+    #[pallet::storage]
+    #[pallet::getter(fn duplicate_summary_of)]
+    pub type DuplicateSummaryOf<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64,
+        u64,
+        OptionQuery,
+    >;
+
     /// Index mapping agent to their record IDs
     #[pallet::storage]
     #[pallet::getter(fn agent_records)]
@@ -169,92 +826,472 @@ pub mod pallet {
         ValueQuery,
     >;
 
-    /// Events emitted by the pallet
-    #[pallet::event]
-    #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {
-        /// A new consensus record was stored
-        ConsensusRecordStored {
-            record_id: u64,
-            record_type: RecordType,
-            content_hash: Vec<u8>,
-            ipfs_cid: Vec<u8>,
-        },
-        /// An agent signature was added to a record
-        SignatureAdded {
-            record_id: u64,
-            agent_id: T::AccountId,
-        },
-        /// A record's trust score was updated
-        TrustScoreUpdated {
-            record_id: u64,
-            new_score: u64,
-        },
-    }
+    /// Agents whose reputation changed since their records were last re-scored
+    #[pallet::storage]
+    pub type StaleTrustScores<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
 
-    /// Errors that can occur in the pallet
-    #[pallet::error]
-    pub enum Error<T> {
-        /// Record not found
-        RecordNotFound,
-        /// Invalid content hash format
-        InvalidContentHash,
-        /// Invalid IPFS CID format
-        InvalidIpfsCid,
-        /// No signatures provided
-        NoSignatures,
-        /// Too many signatures
-        TooManySignatures,
-        /// Agent already signed this record
-        AgentAlreadySigned,
-        /// Invalid record type
-        InvalidRecordType,
-        /// Summary too long
-        SummaryTooLong,
-        /// Metadata too long
-        MetadataTooLong,
-        /// Content hash already exists (duplicate record)
-        DuplicateRecord,
-        /// Agent records list is full
-        AgentRecordsListFull,
-    }
+    /// Depositor and amount reserved for each record
+    #[pallet::storage]
+    #[pallet::getter(fn record_deposits)]
+    pub type RecordDeposits<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64,
+        (T::AccountId, BalanceOf<T>),
+        OptionQuery,
+    >;
 
-    #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+    /// Block at which each ephemeral record is pruned
+    #[pallet::storage]
+    #[pallet::getter(fn record_expiry)]
+    pub type RecordExpiry<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64,
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
 
-    #[pallet::call]
-    impl<T: Config> Pallet<T> {
-        /// Store a new consensus record
-        ///
-        /// Parameters:
-        /// - `record_type`: The type of record being stored
-        /// - `content_hash`: Hash of the content for integrity
-        /// - `ipfs_cid`: IPFS content identifier where full data is stored
-        /// - `summary`: Brief summary of the insight
-        /// - `metadata`: Optional additional metadata
-        #[pallet::call_index(0)]
-        #[pallet::weight(Weight::from_parts(10_000, 0).saturating_add(T::DbWeight::get().reads_writes(3, 4)))]
-        pub fn store_consensus_record(
-            origin: OriginFor<T>,
-            record_type: RecordType,
+    /// Index of ephemeral records by the block they expire at
+    #[pallet::storage]
+    #[pallet::getter(fn prune_queue)]
+    pub type PruneQueue<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<u64, T::MaxPrunesPerBlock>,
+        ValueQuery,
+    >;
+
+    /// Tombstones of redacted records
+    #[pallet::storage]
+    #[pallet::getter(fn tombstones)]
+    pub type Tombstones<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64,
+        RecordTombstone<T>,
+        OptionQuery,
+    >;
+
+    #[pallet::type_value]
+    pub fn DefaultAllowedBackends() -> BoundedVec<StorageBackend, ConstU32<MAX_STORAGE_BACKENDS>> {
+        BoundedVec::truncate_from(sp_std::vec![StorageBackend::Ipfs])
+    }
+
+    /// Storage backends records can point at
+    #[pallet::storage]
+    #[pallet::getter(fn allowed_backends)]
+    pub type AllowedBackends<T: Config> = StorageValue<
+        _,
+        BoundedVec<StorageBackend, ConstU32<MAX_STORAGE_BACKENDS>>,
+        ValueQuery,
+        DefaultAllowedBackends,
+    >;
+
+    /// Whether the pallet is paused, rejecting every call except governance ones
+    #[pallet::storage]
+    #[pallet::getter(fn paused)]
+    pub type Paused<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    /// Open audits
+    #[pallet::storage]
+    #[pallet::getter(fn audits)]
+    pub type Audits<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        AuditId,
+        AuditTask<T>,
+        OptionQuery,
+    >;
+
+    /// Next available audit ID
+    #[pallet::storage]
+    #[pallet::getter(fn next_audit_id)]
+    pub type NextAuditId<T: Config> = StorageValue<_, AuditId, ValueQuery>;
+
+    /// Index of live records by the bucket of blocks they were created in
+    ///
+    /// The bucket of a block is the block number divided by `BucketSize`.
+    #[pallet::storage]
+    pub type RecordsByBlockBucket<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Twox64Concat,
+        u64,
+        (),
+        OptionQuery,
+    >;
+
+    /// Index of open audits by the block their deadline falls on
+    #[pallet::storage]
+    #[pallet::getter(fn audit_deadlines)]
+    pub type AuditDeadlines<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<AuditId, T::AuditSampleSize>,
+        ValueQuery,
+    >;
+
+    /// Deadline of every open signature request, by record and requested agent
+    #[pallet::storage]
+    #[pallet::getter(fn signature_requests)]
+    pub type SignatureRequests<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        u64,
+        Blake2_128Concat,
+        T::AccountId,
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
+
+    /// Requester and amount reserved for each open signature request
+    #[pallet::storage]
+    #[pallet::getter(fn request_deposits)]
+    pub type RequestDeposits<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        u64,
+        Blake2_128Concat,
+        T::AccountId,
+        (T::AccountId, BalanceOf<T>),
+        OptionQuery,
+    >;
+
+    /// Records each agent was asked to sign and has not signed yet, oldest request first
+    #[pallet::storage]
+    #[pallet::getter(fn pending_signature_requests)]
+    pub type PendingSignatureRequests<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<u64, T::MaxPendingRequests>,
+        ValueQuery,
+    >;
+
+    /// Index of open signature requests by the block their deadline falls on
+    #[pallet::storage]
+    #[pallet::getter(fn request_deadlines)]
+    pub type RequestDeadlines<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<(u64, T::AccountId), T::MaxRequestsPerDeadline>,
+        ValueQuery,
+    >;
+
+    /// Open dispute over each challenged record
+    #[pallet::storage]
+    #[pallet::getter(fn disputes)]
+    pub type Disputes<T: Config> = StorageMap<_, Twox64Concat, u64, Dispute<T>, OptionQuery>;
+
+    /// Open disputes escrowing each signer's stake
+    #[pallet::storage]
+    #[pallet::getter(fn signer_disputes)]
+    pub type SignerDisputes<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<u64, T::MaxDisputesPerSigner>,
+        ValueQuery,
+    >;
+
+    /// Open disputes by the block they expire in
+    #[pallet::storage]
+    #[pallet::getter(fn dispute_deadlines)]
+    pub type DisputeDeadlines<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<u64, T::MaxDisputesPerBlock>,
+        ValueQuery,
+    >;
+
+    /// Events emitted by the pallet
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A new consensus record was stored
+        ConsensusRecordStored {
+            record_id: u64,
+            record_type: RecordType,
+            content_hash: Vec<u8>,
+            locator: ContentLocator<T>,
+        },
+        /// An agent signature was added to a record
+        SignatureAdded {
+            record_id: u64,
+            agent_id: T::AccountId,
+        },
+        /// A record's trust score was updated
+        TrustScoreUpdated {
+            record_id: u64,
+            new_score: u64,
+        },
+        /// An audit of a sampled record was opened
+        AuditOpened {
+            audit_id: AuditId,
+            record_id: u64,
+            auditors: Vec<T::AccountId>,
+            deadline: BlockNumberFor<T>,
+        },
+        /// An auditor committed to its proof for an audit
+        AuditCommitted {
+            audit_id: AuditId,
+            auditor: T::AccountId,
+        },
+        /// The verdict an auditor's revealed proof amounts to, given when the audit closes
+        AuditAttested {
+            audit_id: AuditId,
+            auditor: T::AccountId,
+            verdict: AuditVerdict,
+        },
+        /// An auditor let the audit deadline pass without revealing a proof
+        AuditMissed {
+            audit_id: AuditId,
+            auditor: T::AccountId,
+        },
+        /// An audit closed, either fully attested or at its deadline
+        AuditCompleted {
+            audit_id: AuditId,
+            record_id: u64,
+            intact: u32,
+            failed: u32,
+        },
+        /// A record was moved to another retention class
+        RecordReclassified {
+            record_id: u64,
+            retention: RetentionClass,
+            deposit: BalanceOf<T>,
+        },
+        /// An ephemeral record reached the end of its retention period and was removed
+        RecordPruned {
+            record_id: u64,
+        },
+        /// A record's content was redacted, leaving a tombstone
+        RecordRedacted {
+            record_id: u64,
+            content_hash: Vec<u8>,
+            reason: RedactionReason,
+        },
+        /// A retired agent's record deposits were returned and its record index dropped
+        AgentDepositsReleased {
+            agent_id: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// A record was stored with the same summary as an earlier record
+        DuplicateSummary {
+            record_id: u64,
+            original_record_id: u64,
+            summary_hash: T::Hash,
+        },
+        /// A system record was written for an agent lifecycle event
+        SystemRecordStored {
+            record_id: u64,
+            record_type: RecordType,
+            agent_id: T::AccountId,
+        },
+        /// The pallet was paused
+        Paused,
+        /// The pallet was unpaused
+        Unpaused,
+        /// Governance changed the storage backends records can point at
+        AllowedBackendsSet {
+            backends: Vec<StorageBackend>,
+        },
+        /// A record owner asked an agent to co-sign a record by `deadline`
+        SignatureRequested {
+            record_id: u64,
+            agent_id: T::AccountId,
+            deadline: BlockNumberFor<T>,
+        },
+        /// An agent let a signature request reach its deadline without signing
+        SignatureRequestExpired {
+            record_id: u64,
+            agent_id: T::AccountId,
+        },
+        /// A record was challenged and `escrowed` of its signers' stake moved to its escrow account
+        RecordChallenged {
+            record_id: u64,
+            challenger: T::AccountId,
+            bond: BalanceOf<T>,
+            escrowed: BalanceOf<T>,
+        },
+        /// A dispute over a record was resolved, slashing and releasing the escrowed funds
+        DisputeResolved {
+            record_id: u64,
+            outcome: DisputeOutcome,
+            slashed: BalanceOf<T>,
+            released: BalanceOf<T>,
+        },
+        /// An unresolved dispute expired, restoring the escrowed stake and returning the bond
+        DisputeExpired {
+            record_id: u64,
+            released: BalanceOf<T>,
+        },
+    }
+
+    /// Errors that can occur in the pallet
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Record not found
+        RecordNotFound,
+        /// Invalid content hash format
+        InvalidContentHash,
+        /// The content locator is malformed for its backend
+        InvalidContentLocator,
+        /// Records cannot point at this storage backend
+        BackendNotAllowed,
+        /// No signatures provided
+        NoSignatures,
+        /// Too many signatures
+        TooManySignatures,
+        /// Agent already signed this record
+        AgentAlreadySigned,
+        /// Invalid record type
+        InvalidRecordType,
+        /// Summary too long
+        SummaryTooLong,
+        /// Metadata too long
+        MetadataTooLong,
+        /// Content hash already exists (duplicate record)
+        DuplicateRecord,
+        /// Agent records list is full
+        AgentRecordsListFull,
+        /// Audit not found or already closed
+        AuditNotFound,
+        /// Caller is not assigned to this audit
+        NotAuditor,
+        /// Auditor already attested to this audit
+        AlreadyAttested,
+        /// The audit is not taking commitments or reveals at this point
+        WrongAuditPhase,
+        /// Auditor has not committed to a proof for this audit
+        NoAuditCommitment,
+        /// Revealed proof and salt do not match the auditor's commitment
+        AuditRevealMismatch,
+        /// Depositor cannot reserve the deposit for the retention class
+        InsufficientDeposit,
+        /// Too many ephemeral records already expire in the same block
+        PruneQueueFull,
+        /// Record is already in the requested retention class
+        SameRetentionClass,
+        /// Record was redacted
+        RecordRedacted,
+        /// The pallet is paused
+        PalletPaused,
+        /// A compressed summary or metadata payload is invalid or decompresses beyond
+        /// `MaxDecompressedLength`
+        InvalidCompression,
+        /// Caller did not store the record
+        NotRecordOwner,
+        /// The agent was already asked to sign this record
+        SignatureAlreadyRequested,
+        /// The deadline is less than `MinRequestPeriod` or more than `MaxRequestPeriod` blocks away
+        InvalidRequestDeadline,
+        /// The agent has `MaxPendingRequests` open signature requests
+        TooManyPendingRequests,
+        /// Too many signature requests already expire in the same block
+        RequestDeadlineFull,
+        /// The record is already disputed
+        RecordDisputed,
+        /// The record has no open dispute
+        NoDispute,
+        /// Challenger cannot pay the challenge bond
+        InsufficientBond,
+        /// Too many disputes already expire in the same block
+        DisputeDeadlineFull,
+        /// The new account already has stake escrowed by disputes
+        DisputesInUse,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Prune expired ephemeral records, close audits and signature requests whose deadline
+        /// is reached and open a new audit round every `AuditInterval`
+        fn on_initialize(block: BlockNumberFor<T>) -> Weight {
+            let mut weight = Self::prune_expired(block);
+            weight = weight.saturating_add(Self::expire_audits(block));
+            weight = weight.saturating_add(Self::expire_signature_requests(block));
+            weight = weight.saturating_add(Self::expire_disputes(block));
+
+            let interval = T::AuditInterval::get();
+            if !interval.is_zero() && !block.is_zero() && (block % interval).is_zero() {
+                weight = weight.saturating_add(Self::open_audit_round(block));
+            }
+
+            weight
+        }
+
+        /// Re-score the records of agents whose reputation changed with the block's remaining
+        /// weight
+        fn on_idle(_block: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::rescore_stale_agents(remaining_weight)
+        }
+
+        /// Check that a record at its configured bounds fits its storage budget
+        fn integrity_test() {
+            assert!(
+                ConsensusRecord::<T>::max_encoded_len() <= csuite_primitives::MAX_CONSENSUS_RECORD_SIZE,
+                "ConsensusRecord can encode to {} bytes, above the budget of {} bytes",
+                ConsensusRecord::<T>::max_encoded_len(),
+                csuite_primitives::MAX_CONSENSUS_RECORD_SIZE,
+            );
+            assert!(
+                T::ChallengeBond::get() >= T::Currency::minimum_balance(),
+                "ChallengeBond must be at least the existential deposit to keep escrow accounts alive",
+            );
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            Self::do_try_state()
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Store a new consensus record
+        ///
+        /// Parameters:
+        /// - `record_type`: The type of record being stored
+        /// - `content_hash`: Hash of the content for integrity
+        /// - `locator`: Where the full data is stored
+        /// - `summary`: Brief summary of the insight
+        /// - `metadata`: Optional additional metadata
+        /// - `retention`: How long the record is kept, which sets the deposit reserved from the caller
+        /// - `compression`: Codec the summary and metadata are compressed with
+        #[pallet::call_index(0)]
+        #[pallet::weight(Weight::from_parts(10_000, 0)
+            .saturating_add(T::DbWeight::get().reads_writes(9, 10))
+            // Inflating the summary and metadata to check their decompressed size
+            .saturating_add(Weight::from_parts(2_000u64.saturating_mul(T::MaxDecompressedLength::get() as u64), 0)))]
+        pub fn store_consensus_record(
+            origin: OriginFor<T>,
+            record_type: RecordType,
             content_hash: Vec<u8>,
-            ipfs_cid: Vec<u8>,
+            locator: ContentLocator<T>,
             summary: Vec<u8>,
             signature: Vec<u8>,
             metadata: Option<Vec<u8>>,
+            retention: RetentionClass,
+            compression: CompressionCodec,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
             
             // Validate inputs
             ensure!(!content_hash.is_empty(), Error::<T>::InvalidContentHash);
-            ensure!(!ipfs_cid.is_empty(), Error::<T>::InvalidIpfsCid);
+            ensure!(locator.is_valid(), Error::<T>::InvalidContentLocator);
+            ensure!(Self::allowed_backends().contains(&locator.backend()), Error::<T>::BackendNotAllowed);
             ensure!(!signature.is_empty(), Error::<T>::NoSignatures);
             
             // Bound the inputs
             let bounded_content_hash = BoundedVec::<u8, T::MaxContentHashLength>::try_from(content_hash.clone())
                 .map_err(|_| Error::<T>::InvalidContentHash)?;
-            let bounded_ipfs_cid = BoundedVec::<u8, T::MaxIpfsCidLength>::try_from(ipfs_cid.clone())
-                .map_err(|_| Error::<T>::InvalidIpfsCid)?;
             let bounded_summary = BoundedVec::<u8, T::MaxSummaryLength>::try_from(summary)
                 .map_err(|_| Error::<T>::SummaryTooLong)?;
             let bounded_signature = BoundedVec::<u8, T::MaxContentHashLength>::try_from(signature)
@@ -265,6 +1302,10 @@ pub mod pallet {
             } else {
                 None
             };
+            Self::ensure_decompressible(compression, &bounded_summary)?;
+            if let Some(meta) = &bounded_metadata {
+                Self::ensure_decompressible(compression, meta)?;
+            }
             
             // Check for duplicate content hash
             ensure!(
@@ -286,23 +1327,37 @@ pub mod pallet {
             signatures.try_push(agent_signature)
                 .map_err(|_| Error::<T>::TooManySignatures)?;
             
-            // Create the record
-            let record = ConsensusRecord {
+            // Create the record, scoring it from the submitting agent's reputation
+            let mut record = ConsensusRecord {
                 record_type: record_type.clone(),
                 content_hash: bounded_content_hash.clone(),
-                ipfs_cid: bounded_ipfs_cid,
+                locator: locator.clone(),
                 summary: bounded_summary,
                 signatures,
                 created_at: <frame_system::Pallet<T>>::block_number(),
                 metadata: bounded_metadata,
-                trust_score: 100, // Initial trust score
+                trust_score: 0,
+                retention,
+                compression,
             };
+            record.trust_score = Self::calculate_trust_score(&record);
+            
+            // Reserve the deposit for the retention class
+            let deposit = Self::deposit_for(retention);
+            T::Currency::reserve(&who, deposit).map_err(|_| Error::<T>::InsufficientDeposit)?;
+            RecordDeposits::<T>::insert(record_id, (who.clone(), deposit));
+            
+            // Schedule ephemeral records for pruning
+            if retention == RetentionClass::Ephemeral {
+                Self::schedule_prune(record_id, <frame_system::Pallet<T>>::block_number())?;
+            }
             
             // Store the record
             Records::<T>::insert(&record_id, &record);
             
             // Update indexes
             ContentHashToRecord::<T>::insert(&bounded_content_hash, &record_id);
+            Self::index_by_block(record_id, record.created_at);
             
             // Update agent records
             AgentRecords::<T>::try_mutate(&who, |records| {
@@ -317,8 +1372,10 @@ pub mod pallet {
                 record_id,
                 record_type,
                 content_hash,
-                ipfs_cid: bounded_ipfs_cid.into(),
+                locator,
             });
+
+            Self::index_summary(record_id, &record.summary);
             
             Ok(())
         }
@@ -329,13 +1386,17 @@ pub mod pallet {
         /// - `record_id`: The ID of the record to sign
         /// - `signature`: The agent's signature
         #[pallet::call_index(1)]
-        #[pallet::weight(Weight::from_parts(10_000, 0).saturating_add(T::DbWeight::get().reads_writes(2, 2)))]
+        #[pallet::weight(Weight::from_parts(10_000, 0)
+            .saturating_add(T::DbWeight::get().reads_writes(7, 7))
+            // Signer reputation and stake weight reads for the trust score
+            .saturating_add(T::DbWeight::get().reads(2 * T::MaxSignatures::get() as u64)))]
         pub fn add_signature(
             origin: OriginFor<T>,
             record_id: u64,
             signature: Vec<u8>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
             
             // Validate signature
             ensure!(!signature.is_empty(), Error::<T>::NoSignatures);
@@ -343,6 +1404,7 @@ pub mod pallet {
                 .map_err(|_| Error::<T>::NoSignatures)?;
             
             // Get the record
+            ensure!(!Tombstones::<T>::contains_key(record_id), Error::<T>::RecordRedacted);
             let mut record = Records::<T>::get(&record_id)
                 .ok_or(Error::<T>::RecordNotFound)?;
             
@@ -363,8 +1425,8 @@ pub mod pallet {
             record.signatures.try_push(agent_signature)
                 .map_err(|_| Error::<T>::TooManySignatures)?;
             
-            // Update trust score based on number of signatures
-            record.trust_score = record.trust_score.saturating_add(50);
+            // Update trust score from the reputation of all signers
+            record.trust_score = Self::calculate_trust_score(&record);
             
             // Store updated record
             Records::<T>::insert(&record_id, &record);
@@ -378,6 +1440,9 @@ pub mod pallet {
                 }
             }).map_err(|_| Error::<T>::AgentRecordsListFull)?;
             
+            // Answer a signature request for the record, if any
+            Self::close_signature_request(record_id, &who);
+            
             // Emit events
             Self::deposit_event(Event::SignatureAdded {
                 record_id,
@@ -397,6 +1462,8 @@ pub mod pallet {
         /// Parameters:
         /// - `record_id`: The ID of the record to update
         /// - `new_score`: The new trust score
+        ///
+        /// The override holds until a signer's reputation changes and the record is re-scored.
         #[pallet::call_index(2)]
         #[pallet::weight(Weight::from_parts(10_000, 0).saturating_add(T::DbWeight::get().reads_writes(1, 1)))]
         pub fn update_trust_score(
@@ -421,10 +1488,1163 @@ pub mod pallet {
             
             Ok(())
         }
-    }
 
-    // Helper functions
-    impl<T: Config> Pallet<T> {
+        /// Commit to the proof the caller will reveal for a record under audit
+        ///
+        /// Commitments are taken until the audit's `reveal_at`, or until every auditor has
+        /// committed.
+        ///
+        /// Parameters:
+        /// - `audit_id`: The audit being attested
+        /// - `commitment`: The caller's [`Pallet::audit_commitment`] to its proof
+        #[pallet::call_index(3)]
+        #[pallet::weight(Weight::from_parts(10_000, 0).saturating_add(T::DbWeight::get().reads_writes(2, 1)))]
+        pub fn attest_audit(
+            origin: OriginFor<T>,
+            audit_id: AuditId,
+            commitment: T::Hash,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+
+            let mut audit = Audits::<T>::get(audit_id).ok_or(Error::<T>::AuditNotFound)?;
+            ensure!(audit.auditors.contains(&who), Error::<T>::NotAuditor);
+            ensure!(
+                !audit.commitments.iter().any(|(auditor, _)| auditor == &who),
+                Error::<T>::AlreadyAttested
+            );
+            ensure!(!Self::audit_revealing(&audit), Error::<T>::WrongAuditPhase);
+
+            audit.commitments.try_push((who.clone(), commitment))
+                .map_err(|_| Error::<T>::AlreadyAttested)?;
+            Audits::<T>::insert(audit_id, audit);
+
+            // Emit event
+            Self::deposit_event(Event::AuditCommitted {
+                audit_id,
+                auditor: who,
+            });
+
+            Ok(())
+        }
+
+        /// Move a record to another retention class (governance function)
+        ///
+        /// Parameters:
+        /// - `record_id`: The ID of the record to reclassify
+        /// - `retention`: The new retention class
+        ///
+        /// The depositor's reserve is adjusted to the new class's deposit. Records stored before
+        /// retention classes were introduced have no depositor and change class without a
+        /// deposit. A record moved to `Ephemeral` is pruned `EphemeralRetention` blocks from now.
+        #[pallet::call_index(4)]
+        #[pallet::weight(Weight::from_parts(10_000, 0).saturating_add(T::DbWeight::get().reads_writes(5, 5)))]
+        pub fn reclassify_record(
+            origin: OriginFor<T>,
+            record_id: u64,
+            retention: RetentionClass,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let mut record = Records::<T>::get(record_id).ok_or(Error::<T>::RecordNotFound)?;
+            ensure!(record.retention != retention, Error::<T>::SameRetentionClass);
+
+            // Records stored before retention classes were introduced have no deposit entry, and
+            // none of their signers agreed to pay one
+            let new_deposit = match RecordDeposits::<T>::get(record_id) {
+                Some((depositor, old_deposit)) => {
+                    let new_deposit = Self::deposit_for(retention);
+                    if new_deposit > old_deposit {
+                        T::Currency::reserve(&depositor, new_deposit.saturating_sub(old_deposit))
+                            .map_err(|_| Error::<T>::InsufficientDeposit)?;
+                    } else {
+                        T::Currency::unreserve(&depositor, old_deposit.saturating_sub(new_deposit));
+                    }
+                    RecordDeposits::<T>::insert(record_id, (depositor, new_deposit));
+                    new_deposit
+                },
+                None => Zero::zero(),
+            };
+
+            // Move the record in or out of the prune queue
+            if let Some(expiry) = RecordExpiry::<T>::take(record_id) {
+                PruneQueue::<T>::mutate(expiry, |ids| ids.retain(|id| *id != record_id));
+            }
+            if retention == RetentionClass::Ephemeral {
+                Self::schedule_prune(record_id, <frame_system::Pallet<T>>::block_number())?;
+            }
+
+            record.retention = retention;
+            Records::<T>::insert(record_id, record);
+
+            // Emit event
+            Self::deposit_event(Event::RecordReclassified {
+                record_id,
+                retention,
+                deposit: new_deposit,
+            });
+
+            Ok(())
+        }
+
+        /// Redact a record's content, leaving a tombstone (governance function)
+        ///
+        /// The record is removed along with its CID, summary and metadata, and drops out of the
+        /// agent and expiry indexes. Its content hash keeps pointing at the record ID so the
+        /// content cannot be stored again. The deposit is returned to the depositor.
+        ///
+        /// Parameters:
+        /// - `record_id`: The record to redact
+        /// - `reason`: Why the record is redacted
+        #[pallet::call_index(5)]
+        #[pallet::weight(Weight::from_parts(10_000, 0)
+            .saturating_add(T::DbWeight::get().reads_writes(4, 7))
+            // Agent index of every signer
+            .saturating_add(T::DbWeight::get().reads_writes(T::MaxSignatures::get() as u64, T::MaxSignatures::get() as u64)))]
+        pub fn redact_record(
+            origin: OriginFor<T>,
+            record_id: u64,
+            reason: RedactionReason,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ensure!(!Tombstones::<T>::contains_key(record_id), Error::<T>::RecordRedacted);
+            let record = Records::<T>::take(record_id).ok_or(Error::<T>::RecordNotFound)?;
+
+            for sig in record.signatures.iter() {
+                AgentRecords::<T>::mutate(&sig.agent_id, |ids| ids.retain(|id| *id != record_id));
+            }
+            if let Some(expiry) = RecordExpiry::<T>::take(record_id) {
+                PruneQueue::<T>::mutate(expiry, |ids| ids.retain(|id| *id != record_id));
+            }
+            if let Some((depositor, deposit)) = RecordDeposits::<T>::take(record_id) {
+                T::Currency::unreserve(&depositor, deposit);
+            }
+            Self::unindex_summary(record_id, &record.summary);
+            Self::unindex_by_block(record_id, record.created_at);
+
+            let signers = record.signatures.iter().map(|sig| sig.agent_id.clone()).collect::<Vec<_>>();
+            Tombstones::<T>::insert(record_id, RecordTombstone {
+                record_type: record.record_type,
+                content_hash: record.content_hash.clone(),
+                signers: BoundedVec::truncate_from(signers),
+                created_at: record.created_at,
+                redacted_at: <frame_system::Pallet<T>>::block_number(),
+                reason,
+            });
+
+            // Emit event
+            Self::deposit_event(Event::RecordRedacted {
+                record_id,
+                content_hash: record.content_hash.into_inner(),
+                reason,
+            });
+
+            Ok(())
+        }
+
+        /// Halt storing, signing and auditing records
+        #[pallet::call_index(6)]
+        #[pallet::weight(Weight::from_parts(5_000, 0).saturating_add(T::DbWeight::get().writes(1)))]
+        pub fn pause(origin: OriginFor<T>) -> DispatchResult {
+            T::PauseOrigin::ensure_origin(origin)?;
+
+            Paused::<T>::put(true);
+
+            // Emit event
+            Self::deposit_event(Event::Paused);
+
+            Ok(())
+        }
+
+        /// Resume normal operation after `pause`
+        #[pallet::call_index(7)]
+        #[pallet::weight(Weight::from_parts(5_000, 0).saturating_add(T::DbWeight::get().writes(1)))]
+        pub fn unpause(origin: OriginFor<T>) -> DispatchResult {
+            T::PauseOrigin::ensure_origin(origin)?;
+
+            Paused::<T>::kill();
+
+            // Emit event
+            Self::deposit_event(Event::Unpaused);
+
+            Ok(())
+        }
+
+        /// Set the storage backends new records can point at (governance function)
+        ///
+        /// Records already stored keep their locators.
+        ///
+        /// Parameters:
+        /// - `backends`: The allowed backends
+        #[pallet::call_index(8)]
+        #[pallet::weight(Weight::from_parts(5_000, 0).saturating_add(T::DbWeight::get().writes(1)))]
+        pub fn set_allowed_backends(
+            origin: OriginFor<T>,
+            backends: BoundedVec<StorageBackend, ConstU32<MAX_STORAGE_BACKENDS>>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let mut backends = backends.into_inner();
+            backends.sort();
+            backends.dedup();
+            AllowedBackends::<T>::put(BoundedVec::truncate_from(backends.clone()));
+
+            // Emit event
+            Self::deposit_event(Event::AllowedBackendsSet { backends });
+
+            Ok(())
+        }
+
+        /// Ask an agent to co-sign a record the caller stored
+        ///
+        /// Parameters:
+        /// - `record_id`: The record to be signed
+        /// - `agent`: The agent asked to sign it
+        /// - `deadline`: Block by which the agent has to sign, from `MinRequestPeriod` to
+        ///   `MaxRequestPeriod` blocks away
+        ///
+        /// The record is listed in the agent's `PendingSignatureRequests` until it signs it with
+        /// `add_signature` or the deadline is reached. `SignatureRequestDeposit` is reserved from
+        /// the caller until then.
+        #[pallet::call_index(9)]
+        #[pallet::weight(Weight::from_parts(10_000, 0).saturating_add(T::DbWeight::get().reads_writes(7, 5)))]
+        pub fn request_signature(
+            origin: OriginFor<T>,
+            record_id: u64,
+            agent: T::AccountId,
+            deadline: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+
+            // Only the owner of a live record can ask for signatures
+            ensure!(!Tombstones::<T>::contains_key(record_id), Error::<T>::RecordRedacted);
+            let record = Records::<T>::get(record_id).ok_or(Error::<T>::RecordNotFound)?;
+            ensure!(
+                Self::record_deposits(record_id).map_or(false, |(owner, _)| owner == who),
+                Error::<T>::NotRecordOwner
+            );
+            ensure!(
+                !record.signatures.iter().any(|sig| sig.agent_id == agent),
+                Error::<T>::AgentAlreadySigned
+            );
+            ensure!(
+                !SignatureRequests::<T>::contains_key(record_id, &agent),
+                Error::<T>::SignatureAlreadyRequested
+            );
+
+            let now = <frame_system::Pallet<T>>::block_number();
+            ensure!(
+                deadline >= now.saturating_add(T::MinRequestPeriod::get().max(1u32.into()))
+                    && deadline <= now.saturating_add(T::MaxRequestPeriod::get()),
+                Error::<T>::InvalidRequestDeadline
+            );
+
+            // Check both indexes have room before writing either
+            let mut pending = PendingSignatureRequests::<T>::get(&agent);
+            pending.try_push(record_id).map_err(|_| Error::<T>::TooManyPendingRequests)?;
+            let mut due = RequestDeadlines::<T>::get(deadline);
+            due.try_push((record_id, agent.clone())).map_err(|_| Error::<T>::RequestDeadlineFull)?;
+
+            let deposit = T::SignatureRequestDeposit::get();
+            T::Currency::reserve(&who, deposit).map_err(|_| Error::<T>::InsufficientDeposit)?;
+            RequestDeposits::<T>::insert(record_id, &agent, (who, deposit));
+
+            PendingSignatureRequests::<T>::insert(&agent, pending);
+            RequestDeadlines::<T>::insert(deadline, due);
+            SignatureRequests::<T>::insert(record_id, &agent, deadline);
+
+            // Emit event
+            Self::deposit_event(Event::SignatureRequested { record_id, agent_id: agent, deadline });
+
+            Ok(())
+        }
+
+        /// Challenge a record, escrowing part of its signers' stake until the dispute is resolved
+        ///
+        /// Each signer has `DisputeEscrow` of its stake, scaled by its share of the record's
+        /// trust contributions, moved to the record's escrow account, unless the signer already
+        /// has `MaxDisputesPerSigner` open disputes. The caller locks `ChallengeBondRatio` of the
+        /// escrowed stake in the same account, and at least `ChallengeBond`. The dispute expires
+        /// after `DisputePeriod` unless resolved.
+        ///
+        /// Parameters:
+        /// - `record_id`: The record to challenge
+        #[pallet::call_index(10)]
+        #[pallet::weight(Weight::from_parts(10_000, 0)
+            .saturating_add(T::DbWeight::get().reads_writes(6, 4))
+            // Reputation, stake and open disputes of every signer
+            .saturating_add(T::DbWeight::get().reads_writes(4 * T::MaxSignatures::get() as u64, 4 * T::MaxSignatures::get() as u64)))]
+        pub fn challenge_record(origin: OriginFor<T>, record_id: u64) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+
+            ensure!(!Tombstones::<T>::contains_key(record_id), Error::<T>::RecordRedacted);
+            let record = Records::<T>::get(record_id).ok_or(Error::<T>::RecordNotFound)?;
+            ensure!(!record.signatures.is_empty(), Error::<T>::NoSignatures);
+            ensure!(!Disputes::<T>::contains_key(record_id), Error::<T>::RecordDisputed);
+
+            let now = <frame_system::Pallet<T>>::block_number();
+            let expires_at = now.saturating_add(T::DisputePeriod::get());
+            DisputeDeadlines::<T>::try_mutate(expires_at, |disputes| disputes.try_push(record_id))
+                .map_err(|_| Error::<T>::DisputeDeadlineFull)?;
+
+            // The minimum bond opens the escrow account, so stake can be moved into it
+            let escrow = Self::escrow_account(record_id);
+            let min_bond = T::ChallengeBond::get();
+            T::Currency::transfer(&who, &escrow, min_bond, ExistenceRequirement::KeepAlive)
+                .map_err(|_| Error::<T>::InsufficientBond)?;
+
+            // Signers that added more trust to the record put more of their stake at risk
+            let contributions = record.signatures.iter()
+                .map(|sig| (sig.agent_id.clone(), Self::signer_contribution(&sig.agent_id)))
+                .collect::<Vec<_>>();
+            let total = contributions.iter().fold(0u64, |total, (_, c)| total.saturating_add(*c)).max(1);
+            let mut escrowed = Vec::new();
+            let mut total_escrowed = BalanceOf::<T>::zero();
+            for (agent_id, contribution) in contributions {
+                // Signers already at their dispute limit are left out
+                if SignerDisputes::<T>::try_mutate(&agent_id, |disputes| disputes.try_push(record_id)).is_err() {
+                    continue;
+                }
+                let portion = T::DisputeEscrow::get() * Perbill::from_rational(contribution, total);
+                let amount = T::StakeEscrow::escrow_stake(&agent_id, &escrow, portion);
+                if amount.is_zero() {
+                    Self::remove_signer_dispute(&agent_id, record_id);
+                } else {
+                    total_escrowed = total_escrowed.saturating_add(amount);
+                    escrowed.push((agent_id, amount));
+                }
+            }
+
+            // Escrowing more stake takes a larger bond
+            let bond = min_bond.max(T::ChallengeBondRatio::get() * total_escrowed);
+            T::Currency::transfer(&who, &escrow, bond.saturating_sub(min_bond), ExistenceRequirement::KeepAlive)
+                .map_err(|_| Error::<T>::InsufficientBond)?;
+
+            Disputes::<T>::insert(record_id, Dispute {
+                challenger: who.clone(),
+                bond,
+                escrowed: BoundedVec::truncate_from(escrowed),
+                opened_at: now,
+                expires_at,
+            });
+
+            // Emit event
+            Self::deposit_event(Event::RecordChallenged {
+                record_id,
+                challenger: who,
+                bond,
+                escrowed: total_escrowed,
+            });
+
+            Ok(())
+        }
+
+        /// Resolve the dispute over a record
+        ///
+        /// An upheld challenge slashes the escrowed stake and returns the bond to the challenger;
+        /// a rejected one restores the escrowed stake to its signers and forfeits the bond.
+        ///
+        /// Parameters:
+        /// - `record_id`: The disputed record
+        /// - `outcome`: Whether the challenge is upheld
+        #[pallet::call_index(11)]
+        #[pallet::weight(Weight::from_parts(10_000, 0)
+            .saturating_add(T::DbWeight::get().reads_writes(4, 4))
+            // Balance, stake and open disputes of every signer
+            .saturating_add(T::DbWeight::get().reads_writes(4 * T::MaxSignatures::get() as u64, 4 * T::MaxSignatures::get() as u64)))]
+        pub fn resolve_dispute(
+            origin: OriginFor<T>,
+            record_id: u64,
+            outcome: DisputeOutcome,
+        ) -> DispatchResult {
+            T::DisputeOrigin::ensure_origin(origin)?;
+
+            let dispute = Disputes::<T>::take(record_id).ok_or(Error::<T>::NoDispute)?;
+            Self::close_dispute(record_id, &dispute);
+            let escrow = Self::escrow_account(record_id);
+            let total_escrowed = dispute.escrowed.iter()
+                .fold(BalanceOf::<T>::zero(), |total, (_, amount)| total.saturating_add(*amount));
+
+            let (slashed, released) = match outcome {
+                DisputeOutcome::Upheld => {
+                    // The bond keeps the account alive until it is returned last
+                    let (imbalance, _) = T::Currency::slash(&escrow, total_escrowed);
+                    T::DisputeSlash::on_unbalanced(imbalance);
+                    T::Currency::transfer(&escrow, &dispute.challenger, dispute.bond, ExistenceRequirement::AllowDeath)?;
+                    (total_escrowed, dispute.bond)
+                },
+                DisputeOutcome::Rejected => {
+                    Self::restore_escrowed_stake(&escrow, &dispute)?;
+                    let (imbalance, _) = T::Currency::slash(&escrow, dispute.bond);
+                    T::DisputeSlash::on_unbalanced(imbalance);
+                    (dispute.bond, total_escrowed)
+                },
+            };
+
+            // Emit event
+            Self::deposit_event(Event::DisputeResolved { record_id, outcome, slashed, released });
+
+            Ok(())
+        }
+
+        /// Reveal the proof the caller committed to for a record under audit
+        ///
+        /// Reveals open at the audit's `reveal_at`, or as soon as every auditor has committed.
+        /// The audit closes once every auditor has revealed.
+        ///
+        /// Parameters:
+        /// - `audit_id`: The audit being attested
+        /// - `proof`: The [`Pallet::audit_proof`] of the retrieved content, or `None` if it
+        ///   could not be retrieved
+        /// - `salt`: Salt the commitment was made with
+        #[pallet::call_index(12)]
+        #[pallet::weight(Weight::from_parts(15_000, 0).saturating_add(T::DbWeight::get().reads_writes(2, 2)))]
+        pub fn reveal_audit(
+            origin: OriginFor<T>,
+            audit_id: AuditId,
+            proof: Option<T::Hash>,
+            salt: [u8; 32],
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_not_paused()?;
+
+            let mut audit = Audits::<T>::get(audit_id).ok_or(Error::<T>::AuditNotFound)?;
+            ensure!(audit.auditors.contains(&who), Error::<T>::NotAuditor);
+            ensure!(Self::audit_revealing(&audit), Error::<T>::WrongAuditPhase);
+            let (_, commitment) = audit.commitments.iter()
+                .find(|(auditor, _)| auditor == &who)
+                .ok_or(Error::<T>::NoAuditCommitment)?;
+            ensure!(
+                !audit.proofs.iter().any(|(auditor, _)| auditor == &who),
+                Error::<T>::AlreadyAttested
+            );
+            ensure!(
+                *commitment == Self::audit_commitment(audit_id, &who, &proof, &salt),
+                Error::<T>::AuditRevealMismatch
+            );
+
+            audit.proofs.try_push((who, proof))
+                .map_err(|_| Error::<T>::AlreadyAttested)?;
+
+            // Close the audit once every auditor has revealed
+            if audit.proofs.len() == audit.auditors.len() {
+                Audits::<T>::remove(audit_id);
+                AuditDeadlines::<T>::mutate(audit.deadline, |ids| ids.retain(|id| *id != audit_id));
+                Self::deposit_verdicts(audit_id, &audit);
+            } else {
+                Audits::<T>::insert(audit_id, audit);
+            }
+
+            Ok(())
+        }
+    }
+
+    // Helper functions
+    impl<T: Config> Pallet<T> {
+        /// What an auditor hashes to prove it holds the `content` audited with `nonce`
+        pub fn audit_proof(nonce: &T::Hash, content: &[u8]) -> T::Hash {
+            T::Hashing::hash_of(&(nonce, content))
+        }
+
+        /// Commitment of `auditor` to revealing `proof` with `salt` for `audit_id`
+        pub fn audit_commitment(
+            audit_id: AuditId,
+            auditor: &T::AccountId,
+            proof: &Option<T::Hash>,
+            salt: &[u8; 32],
+        ) -> T::Hash {
+            T::Hashing::hash_of(&(b"recall/audit", audit_id, auditor, proof, salt))
+        }
+
+        /// Whether an audit has moved on from commitments to reveals
+        fn audit_revealing(audit: &AuditTask<T>) -> bool {
+            audit.commitments.len() == audit.auditors.len() ||
+                <frame_system::Pallet<T>>::block_number() >= audit.reveal_at
+        }
+        /// Fail with `PalletPaused` while the pallet is paused
+        fn ensure_not_paused() -> DispatchResult {
+            ensure!(!Paused::<T>::get(), Error::<T>::PalletPaused);
+            Ok(())
+        }
+
+        /// Account holding the challenge bond and escrowed stake of a disputed record
+        pub fn escrow_account(record_id: u64) -> T::AccountId {
+            T::PalletId::get().into_sub_account_truncating(record_id)
+        }
+
+        /// Return the escrowed stake of a dispute to its signers
+        fn restore_escrowed_stake(escrow: &T::AccountId, dispute: &Dispute<T>) -> DispatchResult {
+            for (agent_id, amount) in dispute.escrowed.iter() {
+                T::Currency::transfer(escrow, agent_id, *amount, ExistenceRequirement::KeepAlive)?;
+                T::StakeEscrow::restore_stake(agent_id, *amount);
+            }
+            Ok(())
+        }
+
+        /// Drop a closed dispute from its deadline and its signers' open disputes
+        fn close_dispute(record_id: u64, dispute: &Dispute<T>) {
+            DisputeDeadlines::<T>::mutate(dispute.expires_at, |disputes| disputes.retain(|id| *id != record_id));
+            for (agent_id, _) in dispute.escrowed.iter() {
+                Self::remove_signer_dispute(agent_id, record_id);
+            }
+        }
+
+        /// Remove a record from a signer's open disputes
+        fn remove_signer_dispute(agent_id: &T::AccountId, record_id: u64) {
+            SignerDisputes::<T>::mutate_exists(agent_id, |maybe_disputes| {
+                if let Some(disputes) = maybe_disputes {
+                    disputes.retain(|id| *id != record_id);
+                    if disputes.is_empty() {
+                        *maybe_disputes = None;
+                    }
+                }
+            });
+        }
+
+        /// Expire the disputes left unresolved until `now`, restoring the escrowed stake and
+        /// returning the bonds
+        fn expire_disputes(now: BlockNumberFor<T>) -> Weight {
+            let record_ids = DisputeDeadlines::<T>::take(now);
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+            for record_id in record_ids {
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 2));
+                let Some(dispute) = Disputes::<T>::take(record_id) else { continue };
+                let signers = dispute.escrowed.len() as u64;
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(4 * signers, 4 * signers));
+
+                Self::close_dispute(record_id, &dispute);
+                let escrow = Self::escrow_account(record_id);
+                let released = dispute.escrowed.iter()
+                    .fold(BalanceOf::<T>::zero(), |total, (_, amount)| total.saturating_add(*amount));
+                // Hooks cannot fail: whatever cannot be moved stays in the escrow account
+                let _ = Self::restore_escrowed_stake(&escrow, &dispute);
+                let _ = T::Currency::transfer(&escrow, &dispute.challenger, dispute.bond, ExistenceRequirement::AllowDeath);
+
+                Self::deposit_event(Event::DisputeExpired { record_id, released });
+            }
+
+            weight
+        }
+
+        /// Move the stake a rotating agent has escrowed by open disputes to its new account
+        ///
+        /// Called by the runtime's agent rotation handler, so that stake restored after the
+        /// rotation reaches the new account.
+        pub fn on_agent_rotated(old: &T::AccountId, new: &T::AccountId) -> DispatchResult {
+            ensure!(!SignerDisputes::<T>::contains_key(new), Error::<T>::DisputesInUse);
+
+            let record_ids = SignerDisputes::<T>::take(old);
+            for record_id in record_ids.iter() {
+                Disputes::<T>::mutate(record_id, |dispute| {
+                    if let Some(dispute) = dispute {
+                        for (agent_id, _) in dispute.escrowed.iter_mut().filter(|(agent_id, _)| agent_id == old) {
+                            *agent_id = new.clone();
+                        }
+                    }
+                });
+            }
+            if !record_ids.is_empty() {
+                SignerDisputes::<T>::insert(new, record_ids);
+            }
+
+            Ok(())
+        }
+
+        /// Upper bound on the weight of [`Pallet::on_agent_rotated`]
+        pub fn on_agent_rotated_weight() -> Weight {
+            let disputes = T::MaxDisputesPerSigner::get() as u64;
+            T::DbWeight::get().reads_writes(2u64.saturating_add(disputes), 2u64.saturating_add(disputes))
+        }
+
+        /// Check that stored records have assigned IDs and no tombstone, and that expiries and
+        /// deposits only exist for stored records, with every expiry in the prune queue and every
+        /// signature request in the agent's pending requests and the deadline index
+        #[cfg(any(feature = "try-runtime", test))]
+        pub fn do_try_state() -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            let next_record_id = Self::next_record_id();
+            for record_id in Records::<T>::iter_keys() {
+                ensure!(record_id < next_record_id, "Recall: a record has an unassigned ID");
+                ensure!(!Tombstones::<T>::contains_key(record_id), "Recall: a redacted record is still stored");
+            }
+
+            for (record_id, expiry) in RecordExpiry::<T>::iter() {
+                ensure!(Records::<T>::contains_key(record_id), "Recall: an expiry is set for a missing record");
+                ensure!(
+                    PruneQueue::<T>::get(expiry).contains(&record_id),
+                    "Recall: an expiring record is not in the prune queue"
+                );
+            }
+            ensure!(
+                RecordDeposits::<T>::iter_keys().all(Records::<T>::contains_key),
+                "Recall: a deposit is held for a missing record"
+            );
+
+            for (record_id, agent_id, deadline) in SignatureRequests::<T>::iter() {
+                ensure!(
+                    PendingSignatureRequests::<T>::get(&agent_id).contains(&record_id),
+                    "Recall: an open signature request is missing from the agent's pending requests"
+                );
+                ensure!(
+                    RequestDeadlines::<T>::get(deadline).contains(&(record_id, agent_id)),
+                    "Recall: an open signature request is not in the deadline index"
+                );
+            }
+            ensure!(
+                RequestDeposits::<T>::iter_keys()
+                    .all(|(record_id, agent_id)| SignatureRequests::<T>::contains_key(record_id, agent_id)),
+                "Recall: a deposit is held for a closed signature request"
+            );
+
+            Ok(())
+        }
+
+        /// Deposit required for a record in the given retention class
+        pub fn deposit_for(retention: RetentionClass) -> BalanceOf<T> {
+            match retention {
+                RetentionClass::Ephemeral => T::EphemeralDeposit::get(),
+                RetentionClass::Standard => T::StandardDeposit::get(),
+                RetentionClass::Permanent => T::PermanentDeposit::get(),
+            }
+        }
+
+        /// Queue a record to be pruned `EphemeralRetention` blocks after `now`
+        fn schedule_prune(record_id: u64, now: BlockNumberFor<T>) -> DispatchResult {
+            let expiry = now.saturating_add(T::EphemeralRetention::get().max(1u32.into()));
+            PruneQueue::<T>::try_mutate(expiry, |ids| ids.try_push(record_id))
+                .map_err(|_| Error::<T>::PruneQueueFull)?;
+            RecordExpiry::<T>::insert(record_id, expiry);
+            Ok(())
+        }
+
+        /// Index a new record's summary, linking it to an earlier record with the same summary
+        fn index_summary(record_id: u64, summary: &[u8]) {
+            if !T::IndexSummaries::get() || summary.is_empty() {
+                return;
+            }
+
+            let summary_hash = T::Hashing::hash(summary);
+            match SummaryHashToRecord::<T>::get(summary_hash) {
+                Some(original_record_id) => {
+                    DuplicateSummaryOf::<T>::insert(record_id, original_record_id);
+                    Self::deposit_event(Event::DuplicateSummary {
+                        record_id,
+                        original_record_id,
+                        summary_hash,
+                    });
+                }
+                None => SummaryHashToRecord::<T>::insert(summary_hash, record_id),
+            }
+        }
+
+        /// Drop a removed record from the summary index
+        ///
+        /// If it was the first record with its summary, the next one stored becomes the original.
+        fn unindex_summary(record_id: u64, summary: &[u8]) {
+            DuplicateSummaryOf::<T>::remove(record_id);
+            let summary_hash = T::Hashing::hash(summary);
+            if SummaryHashToRecord::<T>::get(summary_hash) == Some(record_id) {
+                SummaryHashToRecord::<T>::remove(summary_hash);
+            }
+        }
+
+        /// Remove every ephemeral record expiring at `now`, returning its deposit
+        fn prune_expired(now: BlockNumberFor<T>) -> Weight {
+            let record_ids = PruneQueue::<T>::take(now);
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+            for record_id in record_ids {
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(4, 7));
+                RecordExpiry::<T>::remove(record_id);
+                let Some(record) = Records::<T>::take(record_id) else { continue };
+
+                ContentHashToRecord::<T>::remove(&record.content_hash);
+                for sig in record.signatures.iter() {
+                    AgentRecords::<T>::mutate(&sig.agent_id, |ids| ids.retain(|id| *id != record_id));
+                }
+                weight = weight.saturating_add(
+                    T::DbWeight::get().reads_writes(record.signatures.len() as u64, record.signatures.len() as u64),
+                );
+
+                if let Some((depositor, deposit)) = RecordDeposits::<T>::take(record_id) {
+                    T::Currency::unreserve(&depositor, deposit);
+                }
+                Self::unindex_summary(record_id, &record.summary);
+                Self::unindex_by_block(record_id, record.created_at);
+
+                Self::deposit_event(Event::RecordPruned { record_id });
+            }
+
+            weight
+        }
+
+        /// Open audits for a random sample of records
+        fn open_audit_round(now: BlockNumberFor<T>) -> Weight {
+            let record_count = NextRecordId::<T>::get();
+            let committee = T::AuditCommittee::members();
+            let mut weight = T::DbWeight::get().reads(2);
+            if record_count == 0 || committee.is_empty() {
+                return weight;
+            }
+
+            let (seed, _) = T::Randomness::random(AUDIT_RANDOM_SUBJECT);
+            let period = T::AuditDeadline::get().max(2u32.into());
+            let reveal_at = now.saturating_add(period / 2u32.into());
+            let deadline = now.saturating_add(period);
+            let mut sampled = Vec::new();
+
+            for draw in 0..T::AuditSampleSize::get() {
+                let entropy = T::Hashing::hash_of(&(seed, draw));
+                let random = u64::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+                    .unwrap_or_default();
+                let record_id = random % record_count;
+                if sampled.contains(&record_id) {
+                    continue;
+                }
+
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+                let Some(record) = Records::<T>::get(record_id) else { continue };
+                sampled.push(record_id);
+
+                // Walk the committee from a random offset, skipping the record's own signers
+                let offset = (random / record_count) as usize % committee.len();
+                let auditors = committee.iter().cycle().skip(offset).take(committee.len())
+                    .filter(|member| !record.signatures.iter().any(|sig| &sig.agent_id == *member))
+                    .take(T::AuditorsPerAudit::get() as usize)
+                    .cloned()
+                    .collect::<Vec<_>>();
+                if auditors.is_empty() {
+                    continue;
+                }
+
+                let audit_id = NextAuditId::<T>::get();
+                if AuditDeadlines::<T>::try_mutate(deadline, |ids| ids.try_push(audit_id)).is_err() {
+                    break;
+                }
+
+                Audits::<T>::insert(audit_id, AuditTask {
+                    record_id,
+                    auditors: BoundedVec::truncate_from(auditors.clone()),
+                    nonce: T::Hashing::hash_of(&(entropy, audit_id)),
+                    commitments: BoundedVec::new(),
+                    proofs: BoundedVec::new(),
+                    opened_at: now,
+                    reveal_at,
+                    deadline,
+                });
+                NextAuditId::<T>::put(audit_id.saturating_add(1));
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 3));
+
+                Self::deposit_event(Event::AuditOpened { audit_id, record_id, auditors, deadline });
+            }
+
+            weight
+        }
+
+        /// Close every audit whose deadline falls on `now`, reporting auditors that have not
+        /// revealed a proof
+        fn expire_audits(now: BlockNumberFor<T>) -> Weight {
+            let audit_ids = AuditDeadlines::<T>::take(now);
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+            for audit_id in audit_ids {
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+                let Some(audit) = Audits::<T>::take(audit_id) else { continue };
+
+                for auditor in audit.auditors.iter() {
+                    if !audit.proofs.iter().any(|(revealer, _)| revealer == auditor) {
+                        weight = weight.saturating_add(T::OnAuditMissed::on_audit_missed(auditor));
+                        Self::deposit_event(Event::AuditMissed { audit_id, auditor: auditor.clone() });
+                    }
+                }
+
+                Self::deposit_verdicts(audit_id, &audit);
+            }
+
+            weight
+        }
+
+        /// Close and report every signature request whose deadline falls on `now`
+        ///
+        /// Requests on records that were pruned or redacted since are dropped without a report.
+        fn expire_signature_requests(now: BlockNumberFor<T>) -> Weight {
+            let requests = RequestDeadlines::<T>::take(now);
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+            for (record_id, agent_id) in requests {
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(5, 4));
+                SignatureRequests::<T>::remove(record_id, &agent_id);
+                Self::remove_pending_request(&agent_id, record_id);
+                Self::release_request_deposit(record_id, &agent_id);
+                if !Records::<T>::contains_key(record_id) {
+                    continue;
+                }
+
+                if T::ReportMissedRequests::get() {
+                    weight = weight.saturating_add(
+                        T::OnSignatureRequestMissed::on_signature_request_missed(&agent_id, record_id),
+                    );
+                }
+                Self::deposit_event(Event::SignatureRequestExpired { record_id, agent_id });
+            }
+
+            weight
+        }
+
+        /// Close the signature request an agent answered by signing the record
+        fn close_signature_request(record_id: u64, agent_id: &T::AccountId) {
+            let Some(deadline) = SignatureRequests::<T>::take(record_id, agent_id) else { return };
+            RequestDeadlines::<T>::mutate(deadline, |requests| {
+                requests.retain(|(id, agent)| *id != record_id || agent != agent_id)
+            });
+            Self::remove_pending_request(agent_id, record_id);
+            Self::release_request_deposit(record_id, agent_id);
+        }
+
+        /// Return the deposit of a closed signature request to its requester
+        fn release_request_deposit(record_id: u64, agent_id: &T::AccountId) {
+            if let Some((requester, deposit)) = RequestDeposits::<T>::take(record_id, agent_id) {
+                T::Currency::unreserve(&requester, deposit);
+            }
+        }
+
+        /// Remove a record from an agent's pending signature requests
+        fn remove_pending_request(agent_id: &T::AccountId, record_id: u64) {
+            PendingSignatureRequests::<T>::mutate_exists(agent_id, |maybe_pending| {
+                if let Some(pending) = maybe_pending {
+                    pending.retain(|id| *id != record_id);
+                    if pending.is_empty() {
+                        *maybe_pending = None;
+                    }
+                }
+            });
+        }
+
+        /// Emit the verdict of every revealed proof of a closed audit, then its completion
+        ///
+        /// The content's proof is taken to be the one more than half of the retrieved proofs
+        /// agree on. Proofs matching it are intact and all others corrupted, so without such a
+        /// majority no proof counts as intact.
+        fn deposit_verdicts(audit_id: AuditId, audit: &AuditTask<T>) {
+            let retrieved = audit.proofs.iter().filter_map(|(_, proof)| *proof).collect::<Vec<_>>();
+            let agreed = retrieved.iter()
+                .find(|proof| retrieved.iter().filter(|other| other == proof).count() * 2 > retrieved.len())
+                .copied();
+
+            let mut intact = 0u32;
+            for (auditor, proof) in audit.proofs.iter() {
+                let verdict = match proof {
+                    None => AuditVerdict::Unavailable,
+                    Some(proof) if Some(*proof) == agreed => AuditVerdict::Intact,
+                    Some(_) => AuditVerdict::Corrupted,
+                };
+                if verdict == AuditVerdict::Intact {
+                    intact = intact.saturating_add(1);
+                }
+                Self::deposit_event(Event::AuditAttested { audit_id, auditor: auditor.clone(), verdict });
+            }
+            let failed = (audit.proofs.len() as u32).saturating_sub(intact);
+
+            Self::deposit_event(Event::AuditCompleted {
+                audit_id,
+                record_id: audit.record_id,
+                intact,
+                failed,
+            });
+        }
+
+        /// Contribution of a single signer to a record's trust score
+        pub fn signer_contribution(agent_id: &T::AccountId) -> u64 {
+            let curved = T::TrustCurve::get().apply(T::SignerWeights::effective_reputation(agent_id));
+            let stake_bonus = T::SignerWeights::stake_weight(agent_id).mul_floor(curved);
+            curved.saturating_add(stake_bonus).min(T::MaxSignerContribution::get())
+        }
+
+        /// Calculate the trust score of a record from its current signers
+        pub fn calculate_trust_score(record: &ConsensusRecord<T>) -> u64 {
+            record.signatures.iter().fold(T::BaseTrustScore::get(), |score, sig| {
+                score.saturating_add(Self::signer_contribution(&sig.agent_id))
+            })
+        }
+
+        /// Recompute the trust score of a record, emitting an event if it changed
+        ///
+        /// Returns the number of signers scored.
+        pub fn recompute_trust_score(record_id: u64) -> u32 {
+            Records::<T>::mutate(record_id, |maybe_record| {
+                let Some(record) = maybe_record else { return 0 };
+                let new_score = Self::calculate_trust_score(record);
+                if new_score != record.trust_score {
+                    record.trust_score = new_score;
+                    Self::deposit_event(Event::TrustScoreUpdated { record_id, new_score });
+                }
+                record.signatures.len() as u32
+            })
+        }
+
+        /// Recompute the trust scores of an agent's most recent records
+        ///
+        /// At most `MaxRecomputePerAgent` records are re-scored so the cost stays bounded; older
+        /// records keep their last score.
+        pub fn recompute_agent_records(agent_id: &T::AccountId) -> Weight {
+            let record_ids = AgentRecords::<T>::get(agent_id);
+            let limit = T::MaxRecomputePerAgent::get() as usize;
+            let mut weight = T::DbWeight::get().reads(1);
+
+            for &record_id in record_ids.iter().rev().take(limit) {
+                let signers = u64::from(Self::recompute_trust_score(record_id));
+                // Every signer costs its reputation entry and the total stake weight
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(1 + 2 * signers, 1));
+            }
+
+            weight
+        }
+
+        /// Upper bound on the weight of [`Pallet::recompute_agent_records`]
+        pub fn recompute_agent_records_weight() -> Weight {
+            let records = u64::from(T::MaxRecomputePerAgent::get());
+            let signers = u64::from(T::MaxSignatures::get());
+            T::DbWeight::get().reads_writes(1 + records * (1 + 2 * signers), records)
+        }
+
+        /// Queue an agent's records to be re-scored after its reputation changed
+        ///
+        /// Queueing is a single write, so it stays cheap inside the calls and hooks that change
+        /// reputation. Queued agents are re-scored in `on_idle`, in storage order.
+        pub fn note_reputation_change(agent_id: &T::AccountId) {
+            StaleTrustScores::<T>::insert(agent_id, ());
+        }
+
+        /// Re-score the records of queued agents within `limit`
+        ///
+        /// Agents are only taken from the queue while the worst case of re-scoring them fits.
+        fn rescore_stale_agents(limit: Weight) -> Weight {
+            let base = T::DbWeight::get().reads(1);
+            // Taking an agent off the queue costs one more read and a write
+            let per_agent = Self::recompute_agent_records_weight()
+                .saturating_add(T::DbWeight::get().reads_writes(1, 1));
+            if !limit.all_gte(base.saturating_add(per_agent)) {
+                return Weight::zero();
+            }
+
+            let mut used = base;
+            while limit.all_gte(used.saturating_add(per_agent)) {
+                let Some(agent_id) = StaleTrustScores::<T>::iter_keys().next() else { break };
+                StaleTrustScores::<T>::remove(&agent_id);
+                used = used
+                    .saturating_add(Self::recompute_agent_records(&agent_id))
+                    .saturating_add(T::DbWeight::get().reads_writes(1, 1));
+            }
+
+            used
+        }
+
+        /// Return the deposits an agent holds for its records and drop its record index
+        ///
+        /// Used once an agent has retired. The records themselves stay stored and can still be
+        /// looked up by ID or content hash.
+        pub fn release_agent_deposits(agent_id: &T::AccountId) -> Weight {
+            let record_ids = AgentRecords::<T>::take(agent_id);
+            let mut released = BalanceOf::<T>::default();
+
+            for record_id in record_ids.iter() {
+                if let Some((depositor, deposit)) = RecordDeposits::<T>::get(record_id) {
+                    if &depositor == agent_id {
+                        T::Currency::unreserve(&depositor, deposit);
+                        RecordDeposits::<T>::remove(record_id);
+                        released = released.saturating_add(deposit);
+                    }
+                }
+            }
+
+            Self::deposit_event(Event::AgentDepositsReleased {
+                agent_id: agent_id.clone(),
+                amount: released,
+            });
+
+            let count = record_ids.len() as u64;
+            T::DbWeight::get().reads_writes(count.saturating_add(1), count.saturating_add(1))
+        }
+
+        /// Write a system record for an agent lifecycle event, returning the weight consumed
+        ///
+        /// Does nothing unless `SystemRecords` is enabled. The record is unsigned, carries no
+        /// deposit and is kept with `Standard` retention; it is not added to the agent's record
+        /// index so an agent's own records are never crowded out by them.
+        pub fn note_system_record(agent_id: &T::AccountId, record: SystemRecord) -> Weight {
+            if !T::SystemRecords::get() {
+                return Weight::zero();
+            }
+
+            let body = (agent_id, record).encode();
+            let Ok(summary) = BoundedVec::<u8, T::MaxSummaryLength>::try_from(body.clone()) else {
+                return T::DbWeight::get().reads(1);
+            };
+            let record_id = NextRecordId::<T>::get();
+            let hash = T::Hashing::hash_of(&(b"recall/system", record_id, &body));
+            let Ok(content_hash) = BoundedVec::<u8, T::MaxContentHashLength>::try_from(hash.as_ref().to_vec()) else {
+                return T::DbWeight::get().reads(1);
+            };
+
+            let record_type = match record {
+                SystemRecord::StatusChanged { .. } => RecordType::AgentStatusUpdate,
+                SystemRecord::Slashed { .. } => RecordType::SystemEvent,
+            };
+            let consensus_record = ConsensusRecord::<T> {
+                record_type: record_type.clone(),
+                content_hash: content_hash.clone(),
+                locator: ContentLocator::Ipfs(BoundedVec::new()),
+                summary,
+                signatures: BoundedVec::new(),
+                created_at: <frame_system::Pallet<T>>::block_number(),
+                metadata: None,
+                trust_score: T::BaseTrustScore::get(),
+                retention: RetentionClass::Standard,
+                compression: CompressionCodec::None,
+            };
+
+            let created_at = consensus_record.created_at;
+            Records::<T>::insert(record_id, consensus_record);
+            ContentHashToRecord::<T>::insert(&content_hash, record_id);
+            Self::index_by_block(record_id, created_at);
+            NextRecordId::<T>::put(record_id.saturating_add(1));
+
+            // Emit event
+            Self::deposit_event(Event::SystemRecordStored {
+                record_id,
+                record_type,
+                agent_id: agent_id.clone(),
+            });
+
+            T::DbWeight::get().reads_writes(1, 4)
+        }
+
+        /// Export records from `start_id` onwards, encoding at most `max_bytes` of records
+        ///
+        /// A page always contains at least one record when one is left, even if it alone exceeds
+        /// `max_bytes`, and looks at no more than [`EXPORT_SCAN_LIMIT`] record IDs. Continue with
+        /// the returned `next_id` until it is `None`.
+        pub fn export_records(start_id: u64, max_bytes: u32) -> RecordExportPage {
+            let next_record_id = NextRecordId::<T>::get();
+            let scan_end = start_id.saturating_add(EXPORT_SCAN_LIMIT).min(next_record_id);
+
+            let mut records = Vec::new();
+            let mut size = 0usize;
+            let mut id = start_id;
+            while id < scan_end {
+                if let Some(record) = Records::<T>::get(id) {
+                    let record_size = (id, &record).encoded_size();
+                    if !records.is_empty() && size.saturating_add(record_size) > max_bytes as usize {
+                        break;
+                    }
+                    size = size.saturating_add(record_size);
+                    records.push((id, record));
+                }
+                id = id.saturating_add(1);
+            }
+
+            RecordExportPage {
+                records: records.encode(),
+                next_id: (id < next_record_id).then_some(id),
+            }
+        }
+
+        /// Export records created from `start_block` to `end_block`, both included, in ID order
+        ///
+        /// Only the buckets of `RecordsByBlockBucket` covering the range are read. A page holds
+        /// at most [`RANGE_PAGE_LIMIT`] records; continue with the returned `next_id` as `cursor`,
+        /// keeping the same range, until it is `None`.
+        pub fn records_between(
+            start_block: BlockNumberFor<T>,
+            end_block: BlockNumberFor<T>,
+            cursor: Option<u64>,
+        ) -> RecordExportPage {
+            let from_id = cursor.unwrap_or_default();
+            // Resume from the bucket of the cursor's record, which was live when it was returned
+            let first_block = cursor
+                .and_then(|id| Records::<T>::get(id))
+                .map_or(start_block, |record| record.created_at.max(start_block));
+
+            let mut records = Vec::new();
+            let mut bucket = Self::block_bucket(first_block);
+            let last_bucket = Self::block_bucket(end_block);
+            while bucket <= last_bucket {
+                let mut ids = RecordsByBlockBucket::<T>::iter_key_prefix(bucket)
+                    .filter(|id| *id >= from_id)
+                    .collect::<Vec<_>>();
+                ids.sort_unstable();
+
+                for id in ids {
+                    let Some(record) = Records::<T>::get(id) else { continue };
+                    if record.created_at < start_block || record.created_at > end_block {
+                        continue;
+                    }
+                    if records.len() >= RANGE_PAGE_LIMIT {
+                        return RecordExportPage { records: records.encode(), next_id: Some(id) };
+                    }
+                    records.push((id, record));
+                }
+
+                let next = bucket.saturating_add(One::one());
+                if next == bucket {
+                    break;
+                }
+                bucket = next;
+            }
+
+            RecordExportPage { records: records.encode(), next_id: None }
+        }
+
+        /// Bucket of `RecordsByBlockBucket` a block falls in
+        pub fn block_bucket(block: BlockNumberFor<T>) -> BlockNumberFor<T> {
+            block / T::BucketSize::get().max(One::one())
+        }
+
+        /// Add a record to the bucket of the block it was created in
+        fn index_by_block(record_id: u64, created_at: BlockNumberFor<T>) {
+            RecordsByBlockBucket::<T>::insert(Self::block_bucket(created_at), record_id, ());
+        }
+
+        /// Remove a record from the bucket of the block it was created in
+        fn unindex_by_block(record_id: u64, created_at: BlockNumberFor<T>) {
+            RecordsByBlockBucket::<T>::remove(Self::block_bucket(created_at), record_id);
+        }
+
+        /// The summary and metadata of a record, decompressed
+        ///
+        /// `None` if the record does not exist, e.g. because it was pruned or redacted.
+        pub fn record_content(record_id: u64) -> Option<RecordContent> {
+            let record = Records::<T>::get(record_id)?;
+            let limit = T::MaxDecompressedLength::get();
+            let metadata = match &record.metadata {
+                Some(metadata) => Some(record.compression.decompress(metadata, limit)?),
+                None => None,
+            };
+            Some(RecordContent {
+                summary: record.compression.decompress(&record.summary, limit)?,
+                metadata,
+            })
+        }
+
+        /// Fail unless `data` decompresses with `compression` to at most `MaxDecompressedLength`
+        /// bytes
+        fn ensure_decompressible(compression: CompressionCodec, data: &[u8]) -> DispatchResult {
+            if compression != CompressionCodec::None {
+                compression
+                    .decompress(data, T::MaxDecompressedLength::get())
+                    .ok_or(Error::<T>::InvalidCompression)?;
+            }
+            Ok(())
+        }
+
+        /// Whether a record was redacted
+        pub fn is_redacted(record_id: u64) -> bool {
+            Tombstones::<T>::contains_key(record_id)
+        }
+
         /// Get record by content hash
         pub fn get_record_by_hash(content_hash: &[u8]) -> Option<(u64, ConsensusRecord<T>)> {
             let bounded_hash = BoundedVec::<u8, T::MaxContentHashLength>::try_from(content_hash.to_vec()).ok()?;
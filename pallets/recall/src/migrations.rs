@@ -0,0 +1,572 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        migrations.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Storage migrations for the recall pallet
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! Storage migrations for pallet_recall
+//!
+//! Versions 1 and 2 run in a single block. Later versions touch every record, so they run as
+//! multi-block migrations through `pallet_migrations`, in order and after the single-block ones.
+
+/// Prefix of the identifiers of this pallet's multi-block migrations
+pub const PALLET_MIGRATIONS_ID: &[u8; 13] = b"pallet-recall";
+
+pub mod v1 {
+    //! Adds a retention class to every record. Existing records become `Standard` and keep
+    //! their lack of a deposit; one is only reserved if governance reclassifies them.
+    //!
+    //! Records are written in the layout [`super::v4`] migrates from. Values are translated in
+    //! place, so the hasher of the map does not matter.
+
+    use super::v4::{OldConsensusRecord as V3ConsensusRecord, Records};
+    use crate::{AgentSignature, CompressionCodec, Config, Pallet, RecordType, RetentionClass};
+    use codec::{Decode, Encode};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        pallet_prelude::BoundedVec,
+        traits::{Get, UncheckedOnRuntimeUpgrade},
+        weights::Weight,
+    };
+    use frame_system::pallet_prelude::BlockNumberFor;
+    #[cfg(feature = "try-runtime")]
+    use sp_std::vec::Vec;
+
+    /// Record layout before retention classes
+    #[derive(Decode, Encode)]
+    pub struct OldConsensusRecord<T: Config> {
+        pub record_type: RecordType,
+        pub content_hash: BoundedVec<u8, T::MaxContentHashLength>,
+        pub ipfs_cid: BoundedVec<u8, T::MaxLocatorLength>,
+        pub summary: BoundedVec<u8, T::MaxSummaryLength>,
+        pub signatures: BoundedVec<AgentSignature<T>, T::MaxSignatures>,
+        pub created_at: BlockNumberFor<T>,
+        pub metadata: Option<BoundedVec<u8, T::MaxMetadataLength>>,
+        pub trust_score: u64,
+    }
+
+    /// Actual implementation of [`MigrateV0ToV1`]
+    pub struct UncheckedMigrateV0ToV1<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            Records::<T>::translate::<OldConsensusRecord<T>, _>(|_, old| {
+                translated = translated.saturating_add(1);
+                Some(V3ConsensusRecord {
+                    record_type: old.record_type,
+                    content_hash: old.content_hash,
+                    ipfs_cid: old.ipfs_cid,
+                    summary: old.summary,
+                    signatures: old.signatures,
+                    created_at: old.created_at,
+                    metadata: old.metadata,
+                    trust_score: old.trust_score,
+                    retention: RetentionClass::Standard,
+                    compression: CompressionCodec::None,
+                })
+            });
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            Ok((Records::<T>::iter_keys().count() as u64).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let expected = u64::decode(&mut &state[..])
+                .map_err(|_| "Recall: failed to decode the pre-upgrade record count")?;
+            frame_support::ensure!(
+                Records::<T>::iter_values().count() as u64 == expected,
+                "Recall: records were lost while adding retention classes"
+            );
+            Ok(())
+        }
+    }
+
+    /// Add `RetentionClass::Standard` to every existing record, bumping the storage version to 1
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        UncheckedMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v2 {
+    //! Adds a compression codec to every record. Existing records were stored uncompressed.
+    //! Records [`super::v1`] already wrote in the version 3 layout decode as their old prefix
+    //! and are rewritten unchanged.
+
+    use super::v4::{OldConsensusRecord as V3ConsensusRecord, Records};
+    use crate::{AgentSignature, CompressionCodec, Config, Pallet, RecordType, RetentionClass};
+    use codec::{Decode, Encode};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        pallet_prelude::BoundedVec,
+        traits::{Get, UncheckedOnRuntimeUpgrade},
+        weights::Weight,
+    };
+    use frame_system::pallet_prelude::BlockNumberFor;
+    #[cfg(feature = "try-runtime")]
+    use sp_std::vec::Vec;
+
+    /// Record layout before compression codecs
+    #[derive(Decode, Encode)]
+    pub struct OldConsensusRecord<T: Config> {
+        pub record_type: RecordType,
+        pub content_hash: BoundedVec<u8, T::MaxContentHashLength>,
+        pub ipfs_cid: BoundedVec<u8, T::MaxLocatorLength>,
+        pub summary: BoundedVec<u8, T::MaxSummaryLength>,
+        pub signatures: BoundedVec<AgentSignature<T>, T::MaxSignatures>,
+        pub created_at: BlockNumberFor<T>,
+        pub metadata: Option<BoundedVec<u8, T::MaxMetadataLength>>,
+        pub trust_score: u64,
+        pub retention: RetentionClass,
+    }
+
+    /// Actual implementation of [`MigrateV1ToV2`]
+    pub struct UncheckedMigrateV1ToV2<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV1ToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            Records::<T>::translate::<OldConsensusRecord<T>, _>(|_, old| {
+                translated = translated.saturating_add(1);
+                Some(V3ConsensusRecord {
+                    record_type: old.record_type,
+                    content_hash: old.content_hash,
+                    ipfs_cid: old.ipfs_cid,
+                    summary: old.summary,
+                    signatures: old.signatures,
+                    created_at: old.created_at,
+                    metadata: old.metadata,
+                    trust_score: old.trust_score,
+                    retention: old.retention,
+                    compression: CompressionCodec::None,
+                })
+            });
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            Ok((Records::<T>::iter_keys().count() as u64).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let expected = u64::decode(&mut &state[..])
+                .map_err(|_| "Recall: failed to decode the pre-upgrade record count")?;
+            frame_support::ensure!(
+                Records::<T>::iter_values().count() as u64 == expected,
+                "Recall: records were lost while adding compression codecs"
+            );
+            Ok(())
+        }
+    }
+
+    /// Add `CompressionCodec::None` to every existing record, bumping the storage version to 2
+    pub type MigrateV1ToV2<T> = VersionedMigration<
+        1,
+        2,
+        UncheckedMigrateV1ToV2<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v3 {
+    //! Moves the maps keyed by record IDs, audit IDs and block numbers from `Blake2_128Concat` to
+    //! `Twox64Concat`. Their keys are generated by the chain, so they cannot be chosen to unbalance
+    //! the trie, and every key in a storage proof shrinks by 8 bytes.
+
+    use super::{v4::OldConsensusRecord as V3ConsensusRecord, PALLET_MIGRATIONS_ID};
+    use crate::{AuditId, AuditTask, BalanceOf, Config, Pallet, RecordTombstone};
+    use codec::FullCodec;
+    #[cfg(feature = "try-runtime")]
+    use codec::{Decode, Encode};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+        pallet_prelude::{BoundedVec, ConstU32, OptionQuery},
+        storage::{IterableStorageMap, StorageMap},
+        storage_alias,
+        traits::{Get, GetStorageVersion, StorageVersion},
+        weights::WeightMeter,
+        Blake2_128Concat,
+    };
+    use frame_system::pallet_prelude::BlockNumberFor;
+    #[cfg(feature = "try-runtime")]
+    use sp_std::vec::Vec;
+
+    #[storage_alias]
+    type Records<T: Config> = StorageMap<Pallet<T>, Blake2_128Concat, u64, V3ConsensusRecord<T>, OptionQuery>;
+    #[storage_alias]
+    type DuplicateSummaryOf<T: Config> = StorageMap<Pallet<T>, Blake2_128Concat, u64, u64, OptionQuery>;
+    #[storage_alias]
+    type RecordDeposits<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        u64,
+        (<T as frame_system::Config>::AccountId, BalanceOf<T>),
+        OptionQuery,
+    >;
+    #[storage_alias]
+    type RecordExpiry<T: Config> = StorageMap<Pallet<T>, Blake2_128Concat, u64, BlockNumberFor<T>, OptionQuery>;
+    #[storage_alias]
+    type PruneQueue<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        BlockNumberFor<T>,
+        BoundedVec<u64, <T as Config>::MaxPrunesPerBlock>,
+        OptionQuery,
+    >;
+    #[storage_alias]
+    type Tombstones<T: Config> = StorageMap<Pallet<T>, Blake2_128Concat, u64, RecordTombstone<T>, OptionQuery>;
+    #[storage_alias]
+    type Audits<T: Config> = StorageMap<Pallet<T>, Blake2_128Concat, AuditId, AuditTask<T>, OptionQuery>;
+    #[storage_alias]
+    type AuditDeadlines<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        BlockNumberFor<T>,
+        BoundedVec<AuditId, <T as Config>::AuditSampleSize>,
+        OptionQuery,
+    >;
+
+    /// Storage key of an entry under its old hasher
+    ///
+    /// Keys are the 32 byte prefix of a map and a `Blake2_128Concat` hashed `u64` or block
+    /// number, so they never exceed 56 bytes.
+    pub type OldKey = BoundedVec<u8, ConstU32<64>>;
+
+    /// Move the first entry of `Old` after `last` to `New`, returning its old storage key, or
+    /// `None` once every entry has been moved
+    ///
+    /// The old and new keys share a prefix, so iterating `Old` also visits the entries already
+    /// moved. Their keys are too short for a `Blake2_128Concat` hash and fail to decode, so the
+    /// iterator skips them.
+    fn rehash_next<K, V, Old, New>(last: Option<OldKey>) -> Result<Option<OldKey>, SteppedMigrationError>
+    where
+        K: FullCodec,
+        V: FullCodec,
+        Old: IterableStorageMap<K, V> + StorageMap<K, V>,
+        New: StorageMap<K, V>,
+    {
+        let mut iter = match last {
+            Some(last) => Old::iter_from(last.into_inner()),
+            None => Old::iter(),
+        };
+        let Some((key, value)) = iter.next() else {
+            return Ok(None);
+        };
+        let old_key = OldKey::try_from(Old::hashed_key_for(&key)).map_err(|_| SteppedMigrationError::Failed)?;
+
+        Old::remove(&key);
+        New::insert(key, value);
+        Ok(Some(old_key))
+    }
+
+    /// Move the record, audit and block indexed maps to `Twox64Concat`, one entry per step, and
+    /// set the storage version to 3 once all are done
+    ///
+    /// The cursor is the index of the map being moved and the old key of the last entry moved
+    /// from it. Entries are visited in storage order, so the entries after it still sit under
+    /// their old keys. Each step is charged a second read for the moved entries the iterator
+    /// skips, as no moved entry is skipped twice.
+    pub struct LazyMigrationV3<T>(PhantomData<T>);
+
+    impl<T: Config> SteppedMigration for LazyMigrationV3<T> {
+        type Cursor = (u8, Option<OldKey>);
+        type Identifier = MigrationId<13>;
+
+        fn id() -> Self::Identifier {
+            MigrationId { pallet_id: *PALLET_MIGRATIONS_ID, version_from: 2, version_to: 3 }
+        }
+
+        fn step(
+            cursor: Option<Self::Cursor>,
+            meter: &mut WeightMeter,
+        ) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+            if cursor.is_none() && Pallet::<T>::on_chain_storage_version() >= 3 {
+                return Ok(None);
+            }
+
+            let required = T::DbWeight::get().reads_writes(2, 2);
+            if meter.remaining().any_lt(required) {
+                return Err(SteppedMigrationError::InsufficientWeight { required });
+            }
+
+            // Move as many entries as the meter allows
+            let (mut map, mut last) = cursor.unwrap_or_default();
+            while meter.try_consume(required).is_ok() {
+                let moved = match map {
+                    0 => rehash_next::<_, _, Records<T>, super::v4::Records<T>>(last.take())?,
+                    1 => rehash_next::<_, _, DuplicateSummaryOf<T>, crate::DuplicateSummaryOf<T>>(last.take())?,
+                    2 => rehash_next::<_, _, RecordDeposits<T>, crate::RecordDeposits<T>>(last.take())?,
+                    3 => rehash_next::<_, _, RecordExpiry<T>, crate::RecordExpiry<T>>(last.take())?,
+                    4 => rehash_next::<_, _, PruneQueue<T>, crate::PruneQueue<T>>(last.take())?,
+                    5 => rehash_next::<_, _, Tombstones<T>, crate::Tombstones<T>>(last.take())?,
+                    6 => rehash_next::<_, _, Audits<T>, crate::Audits<T>>(last.take())?,
+                    7 => rehash_next::<_, _, AuditDeadlines<T>, crate::AuditDeadlines<T>>(last.take())?,
+                    _ => {
+                        StorageVersion::new(3).put::<Pallet<T>>();
+                        return Ok(None);
+                    },
+                };
+                match moved {
+                    Some(old_key) => last = Some(old_key),
+                    None => map = map.saturating_add(1),
+                }
+            }
+
+            Ok(Some((map, last)))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            Ok((Records::<T>::iter_keys().count() as u64, Tombstones::<T>::iter_keys().count() as u64).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let (records, tombstones) = <(u64, u64)>::decode(&mut &state[..])
+                .map_err(|_| "Recall: failed to decode the pre-upgrade record count")?;
+            frame_support::ensure!(
+                super::v4::Records::<T>::iter_values().count() as u64 == records &&
+                    crate::Tombstones::<T>::iter_values().count() as u64 == tombstones,
+                "Recall: records were lost while rehashing their keys"
+            );
+            frame_support::ensure!(
+                Pallet::<T>::on_chain_storage_version() == 3,
+                "Recall: storage version not set"
+            );
+            Ok(())
+        }
+    }
+}
+
+pub mod v4 {
+    //! Replaces the IPFS CID of every record with a [`ContentLocator`]. Records stored before
+    //! this version all point at IPFS, so their CID becomes a [`ContentLocator::Ipfs`] locator.
+    //! CIDs are kept as they are, even ones the locator validation would now reject.
+
+    use super::PALLET_MIGRATIONS_ID;
+    use crate::{
+        AgentSignature, CompressionCodec, Config, ConsensusRecord, ContentLocator, Pallet, RecordType,
+        RetentionClass,
+    };
+    use codec::{Decode, Encode};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+        pallet_prelude::{BoundedVec, OptionQuery},
+        storage_alias,
+        traits::{Get, GetStorageVersion, StorageVersion},
+        weights::WeightMeter,
+        Twox64Concat,
+    };
+    use frame_system::pallet_prelude::BlockNumberFor;
+    #[cfg(feature = "try-runtime")]
+    use sp_std::vec::Vec;
+
+    /// Record layout before content locators
+    #[derive(Decode, Encode)]
+    pub struct OldConsensusRecord<T: Config> {
+        pub record_type: RecordType,
+        pub content_hash: BoundedVec<u8, T::MaxContentHashLength>,
+        pub ipfs_cid: BoundedVec<u8, T::MaxLocatorLength>,
+        pub summary: BoundedVec<u8, T::MaxSummaryLength>,
+        pub signatures: BoundedVec<AgentSignature<T>, T::MaxSignatures>,
+        pub created_at: BlockNumberFor<T>,
+        pub metadata: Option<BoundedVec<u8, T::MaxMetadataLength>>,
+        pub trust_score: u64,
+        pub retention: RetentionClass,
+        pub compression: CompressionCodec,
+    }
+
+    /// Records in the layout before content locators, which earlier migrations write
+    #[storage_alias]
+    pub type Records<T: Config> = StorageMap<Pallet<T>, Twox64Concat, u64, OldConsensusRecord<T>, OptionQuery>;
+
+    /// Move the CID of every record into an IPFS content locator, one record per step, and set
+    /// the storage version to 4 once all are done
+    ///
+    /// The cursor is the ID of the last migrated record. Records are visited in storage order, so
+    /// the records after the cursor are still in the layout before content locators.
+    pub struct LazyMigrationV4<T>(PhantomData<T>);
+
+    impl<T: Config> SteppedMigration for LazyMigrationV4<T> {
+        type Cursor = u64;
+        type Identifier = MigrationId<13>;
+
+        fn id() -> Self::Identifier {
+            MigrationId { pallet_id: *PALLET_MIGRATIONS_ID, version_from: 3, version_to: 4 }
+        }
+
+        fn step(
+            mut cursor: Option<Self::Cursor>,
+            meter: &mut WeightMeter,
+        ) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+            if cursor.is_none() && Pallet::<T>::on_chain_storage_version() >= 4 {
+                return Ok(None);
+            }
+
+            let required = T::DbWeight::get().reads_writes(1, 1);
+            if meter.remaining().any_lt(required) {
+                return Err(SteppedMigrationError::InsufficientWeight { required });
+            }
+
+            // Migrate as many records as the meter allows
+            while meter.try_consume(required).is_ok() {
+                let mut iter = match cursor {
+                    Some(last) => Records::<T>::iter_from(Records::<T>::hashed_key_for(last)),
+                    None => Records::<T>::iter(),
+                };
+                let Some((record_id, old)) = iter.next() else {
+                    StorageVersion::new(4).put::<Pallet<T>>();
+                    return Ok(None);
+                };
+
+                // Both layouts share the key space, so the record is overwritten in place
+                crate::Records::<T>::insert(
+                    record_id,
+                    ConsensusRecord {
+                        record_type: old.record_type,
+                        content_hash: old.content_hash,
+                        locator: ContentLocator::Ipfs(old.ipfs_cid),
+                        summary: old.summary,
+                        signatures: old.signatures,
+                        created_at: old.created_at,
+                        metadata: old.metadata,
+                        trust_score: old.trust_score,
+                        retention: old.retention,
+                        compression: old.compression,
+                    },
+                );
+                cursor = Some(record_id);
+            }
+
+            Ok(cursor)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            Ok((Records::<T>::iter_keys().count() as u64).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let expected = u64::decode(&mut &state[..])
+                .map_err(|_| "Recall: failed to decode the pre-upgrade record count")?;
+            frame_support::ensure!(
+                crate::Records::<T>::iter_values().count() as u64 == expected,
+                "Recall: records were lost while adding content locators"
+            );
+            frame_support::ensure!(
+                Pallet::<T>::on_chain_storage_version() == 4,
+                "Recall: storage version not set"
+            );
+            Ok(())
+        }
+    }
+}
+
+pub mod v5 {
+    //! Builds the `RecordsByBlockBucket` index from the records stored before it existed, so
+    //! range queries also find them.
+
+    use super::PALLET_MIGRATIONS_ID;
+    use crate::{Config, Pallet, Records, RecordsByBlockBucket};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+        traits::{Get, GetStorageVersion, StorageVersion},
+        weights::WeightMeter,
+    };
+    #[cfg(feature = "try-runtime")]
+    use codec::{Decode, Encode};
+    #[cfg(feature = "try-runtime")]
+    use sp_std::vec::Vec;
+
+    /// Index every record by the bucket of blocks it was created in, one record per step, and
+    /// set the storage version to 5 once all are done
+    ///
+    /// The cursor is the ID of the last indexed record.
+    pub struct LazyMigrationV5<T>(PhantomData<T>);
+
+    impl<T: Config> SteppedMigration for LazyMigrationV5<T> {
+        type Cursor = u64;
+        type Identifier = MigrationId<13>;
+
+        fn id() -> Self::Identifier {
+            MigrationId { pallet_id: *PALLET_MIGRATIONS_ID, version_from: 4, version_to: 5 }
+        }
+
+        fn step(
+            mut cursor: Option<Self::Cursor>,
+            meter: &mut WeightMeter,
+        ) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+            if cursor.is_none() && Pallet::<T>::on_chain_storage_version() >= 5 {
+                return Ok(None);
+            }
+
+            let required = T::DbWeight::get().reads_writes(1, 1);
+            if meter.remaining().any_lt(required) {
+                return Err(SteppedMigrationError::InsufficientWeight { required });
+            }
+
+            // Index as many records as the meter allows
+            while meter.try_consume(required).is_ok() {
+                let mut iter = match cursor {
+                    Some(last) => Records::<T>::iter_from(Records::<T>::hashed_key_for(last)),
+                    None => Records::<T>::iter(),
+                };
+                let Some((record_id, record)) = iter.next() else {
+                    StorageVersion::new(5).put::<Pallet<T>>();
+                    return Ok(None);
+                };
+
+                RecordsByBlockBucket::<T>::insert(Pallet::<T>::block_bucket(record.created_at), record_id, ());
+                cursor = Some(record_id);
+            }
+
+            Ok(cursor)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            Ok((Records::<T>::iter_keys().count() as u64).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let expected = u64::decode(&mut &state[..])
+                .map_err(|_| "Recall: failed to decode the pre-upgrade record count")?;
+            frame_support::ensure!(
+                RecordsByBlockBucket::<T>::iter_keys().count() as u64 == expected,
+                "Recall: not every record was added to the block bucket index"
+            );
+            frame_support::ensure!(
+                Pallet::<T>::on_chain_storage_version() == 5,
+                "Recall: storage version not set"
+            );
+            Ok(())
+        }
+    }
+}
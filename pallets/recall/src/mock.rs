@@ -0,0 +1,290 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        mock.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Mock runtime for testing the Recall pallet
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+use crate as pallet_recall;
+use crate::{AuditCommittee, OnAuditMissed, OnSignatureRequestMissed, SignerWeightProvider, StakeEscrow, WeightingCurve};
+use core::cell::RefCell;
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{BalanceStatus, ConstU16, ConstU64, Hooks, Randomness, ReservableCurrency},
+    weights::Weight,
+    PalletId,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_core::H256;
+use sp_runtime::{
+    traits::{BlakeTwo256, Hash, IdentityLookup},
+    BuildStorage, Perbill,
+};
+use std::collections::BTreeMap;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+    pub enum Test
+    {
+        System: frame_system,
+        Balances: pallet_balances,
+        Recall: pallet_recall,
+    }
+);
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type RuntimeCall = RuntimeCall;
+    type Nonce = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Block = Block;
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type BlockHashCount = ConstU64<250>;
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<u64>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ConstU16<42>;
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
+}
+
+thread_local! {
+    static SIGNER_WEIGHTS: RefCell<BTreeMap<u64, (u64, Perbill)>> = RefCell::new(BTreeMap::new());
+    static COMMITTEE: RefCell<Vec<u64>> = RefCell::new(Vec::new());
+    static MISSED_AUDITS: RefCell<Vec<u64>> = RefCell::new(Vec::new());
+    static MISSED_REQUESTS: RefCell<Vec<(u64, u64)>> = RefCell::new(Vec::new());
+    static STAKES: RefCell<BTreeMap<u64, u64>> = RefCell::new(BTreeMap::new());
+}
+
+/// Signer weights configured per test
+pub struct MockSignerWeights;
+impl SignerWeightProvider<u64> for MockSignerWeights {
+    fn effective_reputation(who: &u64) -> u64 {
+        SIGNER_WEIGHTS.with(|w| w.borrow().get(who).map_or(0, |(rep, _)| *rep))
+    }
+
+    fn stake_weight(who: &u64) -> Perbill {
+        SIGNER_WEIGHTS.with(|w| w.borrow().get(who).map_or(Perbill::zero(), |(_, stake)| *stake))
+    }
+}
+
+// Helper function to set the reputation and stake weight reported for an agent
+pub fn set_signer_weight(agent_id: u64, reputation: u64, stake_weight: Perbill) {
+    SIGNER_WEIGHTS.with(|w| w.borrow_mut().insert(agent_id, (reputation, stake_weight)));
+}
+
+/// Randomness derived from the subject and current block, deterministic across test runs
+pub struct TestRandomness;
+impl Randomness<H256, BlockNumberFor<Test>> for TestRandomness {
+    fn random(subject: &[u8]) -> (H256, BlockNumberFor<Test>) {
+        let block = System::block_number();
+        (BlakeTwo256::hash_of(&(subject, block)), block)
+    }
+}
+
+/// Committee configured per test
+pub struct MockCommittee;
+impl AuditCommittee<u64> for MockCommittee {
+    fn members() -> Vec<u64> {
+        COMMITTEE.with(|c| c.borrow().clone())
+    }
+}
+
+/// Records every auditor reported for a missed audit
+pub struct RecordMissedAudits;
+impl OnAuditMissed<u64> for RecordMissedAudits {
+    fn on_audit_missed(auditor: &u64) -> Weight {
+        MISSED_AUDITS.with(|m| m.borrow_mut().push(*auditor));
+        Weight::zero()
+    }
+}
+
+/// Records every agent reported for an unanswered signature request
+pub struct RecordMissedRequests;
+impl OnSignatureRequestMissed<u64> for RecordMissedRequests {
+    fn on_signature_request_missed(agent_id: &u64, record_id: u64) -> Weight {
+        MISSED_REQUESTS.with(|m| m.borrow_mut().push((*agent_id, record_id)));
+        Weight::zero()
+    }
+}
+
+/// Stake reserved per agent through `set_stake`, moved in and out of escrows
+pub struct MockStakeEscrow;
+impl StakeEscrow<u64, u64> for MockStakeEscrow {
+    fn escrow_stake(agent_id: &u64, escrow: &u64, portion: Perbill) -> u64 {
+        let amount = portion.mul_floor(stake_of(*agent_id));
+        let remaining = Balances::repatriate_reserved(agent_id, escrow, amount, BalanceStatus::Free).unwrap_or(amount);
+        let moved = amount - remaining;
+        STAKES.with(|s| *s.borrow_mut().entry(*agent_id).or_default() -= moved);
+        moved
+    }
+
+    fn restore_stake(agent_id: &u64, amount: u64) {
+        if Balances::reserve(agent_id, amount).is_ok() {
+            STAKES.with(|s| *s.borrow_mut().entry(*agent_id).or_default() += amount);
+        }
+    }
+}
+
+// Helper function to reserve `amount` as an agent's stake
+pub fn set_stake(agent_id: u64, amount: u64) {
+    Balances::reserve(&agent_id, amount).unwrap();
+    STAKES.with(|s| *s.borrow_mut().entry(agent_id).or_default() += amount);
+}
+
+// Helper function to read an agent's stake
+pub fn stake_of(agent_id: u64) -> u64 {
+    STAKES.with(|s| s.borrow().get(&agent_id).copied().unwrap_or_default())
+}
+
+// Helper function to set the audit committee
+pub fn set_committee(members: Vec<u64>) {
+    COMMITTEE.with(|c| *c.borrow_mut() = members);
+}
+
+// Helper function to read the auditors reported for missed audits
+pub fn missed_audits() -> Vec<u64> {
+    MISSED_AUDITS.with(|m| m.borrow().clone())
+}
+
+// Helper function to read the agents and records reported for unanswered signature requests
+pub fn missed_requests() -> Vec<(u64, u64)> {
+    MISSED_REQUESTS.with(|m| m.borrow().clone())
+}
+
+// Helper function to advance blocks, running the pallet hooks
+pub fn run_to_block(n: u64) {
+    while System::block_number() < n {
+        let next = System::block_number() + 1;
+        System::set_block_number(next);
+        Recall::on_initialize(next);
+    }
+}
+
+parameter_types! {
+    pub const MaxContentHashLength: u32 = 64;
+    pub const MaxLocatorLength: u32 = 128;
+    pub const MaxSummaryLength: u32 = 256;
+    pub const MaxMetadataLength: u32 = 1024;
+    pub const MaxSignatures: u32 = 4;
+    pub const TrustCurve: WeightingCurve = WeightingCurve::SquareRoot;
+    pub const BaseTrustScore: u64 = 100;
+    pub const MaxSignerContribution: u64 = 500;
+    pub const MaxRecomputePerAgent: u32 = 2;
+    pub const AuditInterval: u64 = 10;
+    pub const AuditSampleSize: u32 = 2;
+    pub const AuditorsPerAudit: u32 = 2;
+    pub const AuditDeadline: u64 = 5;
+    pub const EphemeralDeposit: u64 = 10;
+    pub const StandardDeposit: u64 = 100;
+    pub const PermanentDeposit: u64 = 1_000;
+    pub const EphemeralRetention: u64 = 20;
+    pub const MaxPrunesPerBlock: u32 = 2;
+    pub static SystemRecords: bool = true;
+    pub static IndexSummaries: bool = true;
+    pub static ReportMissedRequests: bool = true;
+    pub const SignatureRequestDeposit: u64 = 5;
+    pub const RecallPalletId: PalletId = PalletId(*b"csrecall");
+    pub const ChallengeBond: u64 = 50;
+    pub const DisputeEscrow: Perbill = Perbill::from_percent(10);
+    pub const ChallengeBondRatio: Perbill = Perbill::from_percent(25);
+    pub const MaxDisputesPerSigner: u32 = 2;
+    pub const DisputePeriod: u64 = 10;
+    pub const MaxDisputesPerBlock: u32 = 4;
+}
+
+impl pallet_recall::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type MaxContentHashLength = MaxContentHashLength;
+    type MaxLocatorLength = MaxLocatorLength;
+    type MaxSummaryLength = MaxSummaryLength;
+    type MaxMetadataLength = MaxMetadataLength;
+    type MaxSignatures = MaxSignatures;
+    type SignerWeights = MockSignerWeights;
+    type TrustCurve = TrustCurve;
+    type BaseTrustScore = BaseTrustScore;
+    type MaxSignerContribution = MaxSignerContribution;
+    type MaxRecomputePerAgent = MaxRecomputePerAgent;
+    type Randomness = TestRandomness;
+    type AuditCommittee = MockCommittee;
+    type OnAuditMissed = RecordMissedAudits;
+    type AuditInterval = AuditInterval;
+    type AuditSampleSize = AuditSampleSize;
+    type AuditorsPerAudit = AuditorsPerAudit;
+    type AuditDeadline = AuditDeadline;
+    type Currency = Balances;
+    type EphemeralDeposit = EphemeralDeposit;
+    type StandardDeposit = StandardDeposit;
+    type PermanentDeposit = PermanentDeposit;
+    type EphemeralRetention = EphemeralRetention;
+    type MaxPrunesPerBlock = MaxPrunesPerBlock;
+    type PauseOrigin = frame_system::EnsureRoot<u64>;
+    type SystemRecords = SystemRecords;
+    type IndexSummaries = IndexSummaries;
+    type MaxDecompressedLength = frame_support::traits::ConstU32<1024>;
+    type BucketSize = ConstU64<10>;
+    type MaxPendingRequests = frame_support::traits::ConstU32<2>;
+    type MaxRequestsPerDeadline = frame_support::traits::ConstU32<2>;
+    type MinRequestPeriod = ConstU64<2>;
+    type MaxRequestPeriod = ConstU64<20>;
+    type SignatureRequestDeposit = SignatureRequestDeposit;
+    type ReportMissedRequests = ReportMissedRequests;
+    type OnSignatureRequestMissed = RecordMissedRequests;
+    type PalletId = RecallPalletId;
+    type DisputeOrigin = frame_system::EnsureRoot<u64>;
+    type ChallengeBond = ChallengeBond;
+    type ChallengeBondRatio = ChallengeBondRatio;
+    type MaxDisputesPerSigner = MaxDisputesPerSigner;
+    type DisputePeriod = DisputePeriod;
+    type MaxDisputesPerBlock = MaxDisputesPerBlock;
+    type DisputeEscrow = DisputeEscrow;
+    type StakeEscrow = MockStakeEscrow;
+    type DisputeSlash = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    SIGNER_WEIGHTS.with(|w| w.borrow_mut().clear());
+    COMMITTEE.with(|c| c.borrow_mut().clear());
+    MISSED_AUDITS.with(|m| m.borrow_mut().clear());
+    MISSED_REQUESTS.with(|m| m.borrow_mut().clear());
+    STAKES.with(|s| s.borrow_mut().clear());
+    SystemRecords::set(true);
+    IndexSummaries::set(true);
+    ReportMissedRequests::set(true);
+
+    let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: (1..=5).map(|who| (who, 10_000)).collect(),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+
+    let mut ext: sp_io::TestExternalities = storage.into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
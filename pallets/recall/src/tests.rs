@@ -0,0 +1,1279 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        tests.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Tests for the Recall pallet
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+use crate::{
+    mock::*, AuditVerdict, CompressionCodec, ConsensusRecord, ContentLocator, DisputeOutcome, Error, Event,
+    RecordContent, RecordExportPage, RecordType, RedactionReason, RetentionClass, StaleTrustScores,
+    StorageBackend, SummaryHashToRecord, SystemRecord, WeightingCurve,
+};
+use codec::{Decode, Encode};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{Currency, Hooks, ReservableCurrency},
+    weights::Weight,
+};
+use sp_core::H256;
+use sp_runtime::{DispatchError, Perbill};
+
+// Helper function to build an IPFS locator
+fn ipfs(cid: &[u8]) -> ContentLocator<Test> {
+    ContentLocator::Ipfs(cid.to_vec().try_into().unwrap())
+}
+
+// Helper function to store a standard record signed by `agent_id`
+fn store_record(agent_id: u64, content_hash: &[u8]) -> u64 {
+    store_record_with(agent_id, content_hash, RetentionClass::Standard)
+}
+
+// Helper function to store a record in the given retention class
+fn store_record_with(agent_id: u64, content_hash: &[u8], retention: RetentionClass) -> u64 {
+    let record_id = Recall::next_record_id();
+    assert_ok!(Recall::store_consensus_record(
+        RuntimeOrigin::signed(agent_id),
+        RecordType::SingleAgentInsight,
+        content_hash.to_vec(),
+        ipfs(b"QmRecallTestCid"),
+        b"summary".to_vec(),
+        b"signature".to_vec(),
+        None,
+        retention,
+        CompressionCodec::None,
+    ));
+    record_id
+}
+
+#[test]
+fn weighting_curves_apply_correctly() {
+    assert_eq!(WeightingCurve::Linear.apply(400), 400);
+    assert_eq!(WeightingCurve::SquareRoot.apply(400), 20);
+    assert_eq!(WeightingCurve::Logarithmic.apply(0), 0);
+    assert_eq!(WeightingCurve::Logarithmic.apply(1023), 1_000);
+}
+
+#[test]
+fn store_record_scores_submitter_reputation() {
+    new_test_ext().execute_with(|| {
+        set_signer_weight(1, 10_000, Perbill::zero());
+
+        let record_id = store_record(1, b"hash-1");
+
+        // Base score plus sqrt(10_000)
+        assert_eq!(Recall::records(record_id).unwrap().trust_score, 200);
+    });
+}
+
+#[test]
+fn unknown_signer_adds_nothing() {
+    new_test_ext().execute_with(|| {
+        let record_id = store_record(1, b"hash-1");
+        assert_ok!(Recall::add_signature(RuntimeOrigin::signed(2), record_id, b"sig".to_vec()));
+
+        assert_eq!(Recall::records(record_id).unwrap().trust_score, 100);
+    });
+}
+
+#[test]
+fn add_signature_weights_by_reputation_and_stake() {
+    new_test_ext().execute_with(|| {
+        set_signer_weight(1, 400, Perbill::zero());
+        set_signer_weight(2, 2_500, Perbill::from_percent(40));
+
+        let record_id = store_record(1, b"hash-1");
+        assert_ok!(Recall::add_signature(RuntimeOrigin::signed(2), record_id, b"sig".to_vec()));
+
+        // 100 + sqrt(400) + (sqrt(2_500) + 40% stake bonus)
+        assert_eq!(Recall::records(record_id).unwrap().trust_score, 190);
+        System::assert_last_event(Event::TrustScoreUpdated { record_id, new_score: 190 }.into());
+    });
+}
+
+#[test]
+fn signer_contribution_is_capped() {
+    new_test_ext().execute_with(|| {
+        set_signer_weight(1, 4_000_000, Perbill::from_percent(100));
+
+        let record_id = store_record(1, b"hash-1");
+
+        // sqrt(4_000_000) doubled by stake would be 4_000, capped at 500
+        assert_eq!(Recall::records(record_id).unwrap().trust_score, 600);
+    });
+}
+
+#[test]
+fn add_signature_rejects_duplicate_signer() {
+    new_test_ext().execute_with(|| {
+        let record_id = store_record(1, b"hash-1");
+
+        assert_noop!(
+            Recall::add_signature(RuntimeOrigin::signed(1), record_id, b"sig".to_vec()),
+            Error::<Test>::AgentAlreadySigned
+        );
+    });
+}
+
+#[test]
+fn reputation_change_recomputes_recent_records() {
+    new_test_ext().execute_with(|| {
+        let first = store_record(1, b"hash-1");
+        let second = store_record(1, b"hash-2");
+        let third = store_record(1, b"hash-3");
+
+        set_signer_weight(1, 900, Perbill::zero());
+        Recall::recompute_agent_records(&1);
+
+        // Only the two most recent records are re-scored
+        assert_eq!(Recall::records(first).unwrap().trust_score, 100);
+        assert_eq!(Recall::records(second).unwrap().trust_score, 130);
+        assert_eq!(Recall::records(third).unwrap().trust_score, 130);
+        System::assert_last_event(Event::TrustScoreUpdated { record_id: second, new_score: 130 }.into());
+    });
+}
+
+#[test]
+fn reputation_changes_are_rescored_with_idle_weight() {
+    new_test_ext().execute_with(|| {
+        let record_id = store_record(1, b"hash-1");
+        set_signer_weight(1, 900, Perbill::zero());
+        Recall::note_reputation_change(&1);
+        assert_eq!(Recall::records(record_id).unwrap().trust_score, 100);
+        assert!(StaleTrustScores::<Test>::contains_key(1));
+
+        Recall::on_idle(1, Weight::MAX);
+        assert!(!StaleTrustScores::<Test>::contains_key(1));
+        assert_eq!(Recall::records(record_id).unwrap().trust_score, 130);
+    });
+}
+
+#[test]
+fn audit_round_opens_for_sampled_records() {
+    new_test_ext().execute_with(|| {
+        set_committee(vec![10, 11, 12]);
+        let record_id = store_record(1, b"hash-1");
+
+        run_to_block(10);
+
+        // The only record is sampled once, assigned to two committee members
+        assert_eq!(Recall::next_audit_id(), 1);
+        let audit = Recall::audits(0).unwrap();
+        assert_eq!(audit.record_id, record_id);
+        assert_eq!(audit.auditors.len(), 2);
+        assert_eq!(audit.deadline, 15);
+        assert_eq!(Recall::audit_deadlines(15).to_vec(), vec![0]);
+    });
+}
+
+#[test]
+fn audits_skip_record_signers() {
+    new_test_ext().execute_with(|| {
+        set_committee(vec![1, 2]);
+        store_record(1, b"hash-1");
+
+        run_to_block(10);
+
+        // Agent 1 signed the record so only agent 2 can audit it
+        assert_eq!(Recall::audits(0).unwrap().auditors.to_vec(), vec![2]);
+    });
+}
+
+#[test]
+fn no_audits_without_committee() {
+    new_test_ext().execute_with(|| {
+        store_record(1, b"hash-1");
+
+        run_to_block(10);
+
+        assert_eq!(Recall::next_audit_id(), 0);
+    });
+}
+
+// Helper function to commit an auditor to `proof`, salted with its own ID
+fn commit_audit(auditor: u64, audit_id: u64, proof: Option<H256>) {
+    let commitment = Recall::audit_commitment(audit_id, &auditor, &proof, &[auditor as u8; 32]);
+    assert_ok!(Recall::attest_audit(RuntimeOrigin::signed(auditor), audit_id, commitment));
+}
+
+// Helper function to reveal the proof an auditor committed to with `commit_audit`
+fn reveal_audit(auditor: u64, audit_id: u64, proof: Option<H256>) {
+    assert_ok!(Recall::reveal_audit(RuntimeOrigin::signed(auditor), audit_id, proof, [auditor as u8; 32]));
+}
+
+#[test]
+fn audit_proofs_depend_on_the_nonce() {
+    new_test_ext().execute_with(|| {
+        set_committee(vec![10, 11]);
+        store_record(1, b"hash-1");
+        run_to_block(10);
+
+        let nonce = Recall::audits(0).unwrap().nonce;
+        assert_ne!(nonce, H256::zero());
+        assert_ne!(Recall::audit_proof(&nonce, b"content"), Recall::audit_proof(&H256::zero(), b"content"));
+        assert_ne!(Recall::audit_proof(&nonce, b"content"), Recall::audit_proof(&nonce, b"tampered"));
+    });
+}
+
+#[test]
+fn attest_audit_records_verdicts_and_completes() {
+    new_test_ext().execute_with(|| {
+        set_committee(vec![10, 11]);
+        let record_id = store_record(1, b"hash-1");
+        run_to_block(10);
+        let proof = Recall::audit_proof(&Recall::audits(0).unwrap().nonce, b"content");
+
+        commit_audit(10, 0, Some(proof));
+        System::assert_last_event(Event::AuditCommitted { audit_id: 0, auditor: 10 }.into());
+        // Reveals wait for every auditor to commit
+        assert_noop!(
+            Recall::reveal_audit(RuntimeOrigin::signed(10), 0, Some(proof), [10; 32]),
+            Error::<Test>::WrongAuditPhase
+        );
+
+        commit_audit(11, 0, Some(proof));
+        reveal_audit(10, 0, Some(proof));
+        reveal_audit(11, 0, Some(proof));
+        System::assert_has_event(Event::AuditAttested {
+            audit_id: 0,
+            auditor: 10,
+            verdict: AuditVerdict::Intact,
+        }.into());
+        System::assert_last_event(Event::AuditCompleted {
+            audit_id: 0,
+            record_id,
+            intact: 2,
+            failed: 0,
+        }.into());
+
+        // Completed audits are closed and no longer expire
+        assert!(Recall::audits(0).is_none());
+        assert!(Recall::audit_deadlines(15).is_empty());
+        run_to_block(15);
+        assert!(missed_audits().is_empty());
+    });
+}
+
+#[test]
+fn audit_verdicts_need_a_majority_of_retrieved_proofs() {
+    new_test_ext().execute_with(|| {
+        set_committee(vec![10, 11]);
+        let record_id = store_record(1, b"hash-1");
+        run_to_block(10);
+        let nonce = Recall::audits(0).unwrap().nonce;
+        let proof = Recall::audit_proof(&nonce, b"content");
+
+        // An auditor that could not retrieve the content does not outvote one that did
+        commit_audit(10, 0, Some(proof));
+        commit_audit(11, 0, None);
+        reveal_audit(10, 0, Some(proof));
+        reveal_audit(11, 0, None);
+        System::assert_has_event(Event::AuditAttested {
+            audit_id: 0,
+            auditor: 11,
+            verdict: AuditVerdict::Unavailable,
+        }.into());
+        System::assert_last_event(Event::AuditCompleted { audit_id: 0, record_id, intact: 1, failed: 1 }.into());
+
+        // Two proofs that disagree are both corrupted
+        run_to_block(20);
+        let nonce = Recall::audits(1).unwrap().nonce;
+        commit_audit(10, 1, Some(Recall::audit_proof(&nonce, b"content")));
+        commit_audit(11, 1, Some(Recall::audit_proof(&nonce, b"tampered")));
+        reveal_audit(10, 1, Some(Recall::audit_proof(&nonce, b"content")));
+        reveal_audit(11, 1, Some(Recall::audit_proof(&nonce, b"tampered")));
+        System::assert_last_event(Event::AuditCompleted { audit_id: 1, record_id, intact: 0, failed: 2 }.into());
+    });
+}
+
+#[test]
+fn attest_audit_rejects_non_auditors_and_repeats() {
+    new_test_ext().execute_with(|| {
+        set_committee(vec![10, 11]);
+        store_record(1, b"hash-1");
+        run_to_block(10);
+
+        assert_noop!(
+            Recall::attest_audit(RuntimeOrigin::signed(12), 0, H256::zero()),
+            Error::<Test>::NotAuditor
+        );
+        commit_audit(10, 0, None);
+        assert_noop!(
+            Recall::attest_audit(RuntimeOrigin::signed(10), 0, H256::zero()),
+            Error::<Test>::AlreadyAttested
+        );
+        assert_noop!(
+            Recall::attest_audit(RuntimeOrigin::signed(10), 7, H256::zero()),
+            Error::<Test>::AuditNotFound
+        );
+    });
+}
+
+#[test]
+fn audit_reveals_must_open_the_commitment() {
+    new_test_ext().execute_with(|| {
+        set_committee(vec![10, 11]);
+        store_record(1, b"hash-1");
+        run_to_block(10);
+        let proof = Recall::audit_proof(&Recall::audits(0).unwrap().nonce, b"content");
+        commit_audit(10, 0, Some(proof));
+
+        // Reveals open at `reveal_at` even though auditor 11 never committed
+        run_to_block(12);
+        assert_noop!(
+            Recall::attest_audit(RuntimeOrigin::signed(11), 0, H256::zero()),
+            Error::<Test>::WrongAuditPhase
+        );
+        assert_noop!(
+            Recall::reveal_audit(RuntimeOrigin::signed(11), 0, Some(proof), [11; 32]),
+            Error::<Test>::NoAuditCommitment
+        );
+        // A reveal has to match what was committed to
+        assert_noop!(
+            Recall::reveal_audit(RuntimeOrigin::signed(10), 0, None, [10; 32]),
+            Error::<Test>::AuditRevealMismatch
+        );
+
+        reveal_audit(10, 0, Some(proof));
+        assert_noop!(
+            Recall::reveal_audit(RuntimeOrigin::signed(10), 0, Some(proof), [10; 32]),
+            Error::<Test>::AlreadyAttested
+        );
+    });
+}
+
+#[test]
+fn missed_audits_are_reported_at_deadline() {
+    new_test_ext().execute_with(|| {
+        set_committee(vec![10, 11]);
+        let record_id = store_record(1, b"hash-1");
+        run_to_block(10);
+
+        // Auditor 11 commits but never reveals
+        commit_audit(10, 0, None);
+        commit_audit(11, 0, None);
+        reveal_audit(10, 0, None);
+        run_to_block(15);
+
+        assert_eq!(missed_audits(), vec![11]);
+        assert!(Recall::audits(0).is_none());
+        System::assert_has_event(Event::AuditMissed { audit_id: 0, auditor: 11 }.into());
+        System::assert_has_event(Event::AuditCompleted {
+            audit_id: 0,
+            record_id,
+            intact: 0,
+            failed: 1,
+        }.into());
+    });
+}
+
+#[test]
+fn store_record_reserves_deposit_for_retention_class() {
+    new_test_ext().execute_with(|| {
+        store_record_with(1, b"hash-1", RetentionClass::Ephemeral);
+        assert_eq!(Balances::reserved_balance(1), 10);
+
+        let record_id = store_record_with(1, b"hash-2", RetentionClass::Permanent);
+        assert_eq!(Balances::reserved_balance(1), 1_010);
+        assert_eq!(Recall::records(record_id).unwrap().retention, RetentionClass::Permanent);
+        assert_eq!(Recall::record_deposits(record_id), Some((1, 1_000)));
+    });
+}
+
+#[test]
+fn try_state_checks_expiries_and_deposits() {
+    new_test_ext().execute_with(|| {
+        let ephemeral = store_record_with(1, b"hash-1", RetentionClass::Ephemeral);
+        store_record_with(1, b"hash-2", RetentionClass::Permanent);
+        assert_ok!(Recall::do_try_state());
+
+        let expiry = Recall::record_expiry(ephemeral).unwrap();
+        crate::PruneQueue::<Test>::remove(expiry);
+        assert!(Recall::do_try_state().is_err());
+    });
+}
+
+#[test]
+fn store_record_fails_without_deposit() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Recall::store_consensus_record(
+                RuntimeOrigin::signed(9),
+                RecordType::SingleAgentInsight,
+                b"hash-1".to_vec(),
+                ipfs(b"QmRecallTestCid"),
+                b"summary".to_vec(),
+                b"signature".to_vec(),
+                None,
+                RetentionClass::Standard,
+                CompressionCodec::None,
+            ),
+            Error::<Test>::InsufficientDeposit
+        );
+    });
+}
+
+#[test]
+fn ephemeral_records_are_pruned_after_retention() {
+    new_test_ext().execute_with(|| {
+        let ephemeral = store_record_with(1, b"hash-1", RetentionClass::Ephemeral);
+        let standard = store_record_with(1, b"hash-2", RetentionClass::Standard);
+        let permanent = store_record_with(1, b"hash-3", RetentionClass::Permanent);
+        assert_ok!(Recall::add_signature(RuntimeOrigin::signed(2), ephemeral, b"sig".to_vec()));
+
+        run_to_block(20);
+        assert!(Recall::records(ephemeral).is_some());
+
+        run_to_block(21);
+        assert!(Recall::records(ephemeral).is_none());
+        assert!(Recall::get_record_by_hash(b"hash-1").is_none());
+        assert_eq!(Recall::agent_records(1).into_inner(), vec![standard, permanent]);
+        assert!(Recall::agent_records(2).is_empty());
+        assert_eq!(Balances::reserved_balance(1), 1_100);
+        System::assert_has_event(Event::RecordPruned { record_id: ephemeral }.into());
+
+        // Standard and permanent records are kept
+        run_to_block(100);
+        assert!(Recall::records(standard).is_some());
+        assert!(Recall::records(permanent).is_some());
+    });
+}
+
+#[test]
+fn ephemeral_records_are_limited_per_expiry_block() {
+    new_test_ext().execute_with(|| {
+        store_record_with(1, b"hash-1", RetentionClass::Ephemeral);
+        store_record_with(1, b"hash-2", RetentionClass::Ephemeral);
+
+        assert_noop!(
+            Recall::store_consensus_record(
+                RuntimeOrigin::signed(1),
+                RecordType::SingleAgentInsight,
+                b"hash-3".to_vec(),
+                ipfs(b"QmRecallTestCid"),
+                b"summary".to_vec(),
+                b"signature".to_vec(),
+                None,
+                RetentionClass::Ephemeral,
+                CompressionCodec::None,
+            ),
+            Error::<Test>::PruneQueueFull
+        );
+    });
+}
+
+#[test]
+fn reclassify_record_adjusts_deposit_and_pruning() {
+    new_test_ext().execute_with(|| {
+        let record_id = store_record_with(1, b"hash-1", RetentionClass::Ephemeral);
+
+        // Promoting to permanent tops up the deposit and cancels pruning
+        assert_ok!(Recall::reclassify_record(RuntimeOrigin::root(), record_id, RetentionClass::Permanent));
+        assert_eq!(Balances::reserved_balance(1), 1_000);
+        assert_eq!(Recall::record_expiry(record_id), None);
+        System::assert_last_event(Event::RecordReclassified {
+            record_id,
+            retention: RetentionClass::Permanent,
+            deposit: 1_000,
+        }.into());
+
+        run_to_block(30);
+        assert!(Recall::records(record_id).is_some());
+
+        // Demoting to ephemeral returns the difference and prunes from now
+        assert_ok!(Recall::reclassify_record(RuntimeOrigin::root(), record_id, RetentionClass::Ephemeral));
+        assert_eq!(Balances::reserved_balance(1), 10);
+        assert_eq!(Recall::record_expiry(record_id), Some(50));
+
+        run_to_block(50);
+        assert!(Recall::records(record_id).is_none());
+        assert_eq!(Balances::reserved_balance(1), 0);
+    });
+}
+
+#[test]
+fn reclassify_record_charges_no_signer_of_records_without_deposit() {
+    new_test_ext().execute_with(|| {
+        let record_id = store_record(1, b"hash-1");
+        // A record stored before retention classes were introduced
+        crate::RecordDeposits::<Test>::remove(record_id);
+        let reserved = Balances::reserved_balance(1);
+
+        assert_ok!(Recall::reclassify_record(RuntimeOrigin::root(), record_id, RetentionClass::Permanent));
+        assert_eq!(Recall::records(record_id).unwrap().retention, RetentionClass::Permanent);
+        assert_eq!(Balances::reserved_balance(1), reserved);
+        assert_eq!(Recall::record_deposits(record_id), None);
+        System::assert_last_event(Event::RecordReclassified {
+            record_id,
+            retention: RetentionClass::Permanent,
+            deposit: 0,
+        }.into());
+    });
+}
+
+#[test]
+fn reclassify_record_requires_root_and_new_class() {
+    new_test_ext().execute_with(|| {
+        let record_id = store_record(1, b"hash-1");
+
+        assert_noop!(
+            Recall::reclassify_record(RuntimeOrigin::signed(1), record_id, RetentionClass::Permanent),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Recall::reclassify_record(RuntimeOrigin::root(), record_id, RetentionClass::Standard),
+            Error::<Test>::SameRetentionClass
+        );
+
+        // The depositor cannot cover the permanent deposit once most of its balance is reserved
+        assert_ok!(Balances::reserve(&1, 9_500));
+        assert_noop!(
+            Recall::reclassify_record(RuntimeOrigin::root(), record_id, RetentionClass::Permanent),
+            Error::<Test>::InsufficientDeposit
+        );
+    });
+}
+
+#[test]
+fn releasing_agent_deposits_keeps_records_readable() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let standard = store_record_with(1, b"retiring-standard", RetentionClass::Standard);
+        let permanent = store_record_with(1, b"retiring-permanent", RetentionClass::Permanent);
+        let other = store_record_with(2, b"other-agent", RetentionClass::Standard);
+        assert_eq!(Balances::reserved_balance(1), 1_100);
+
+        Recall::release_agent_deposits(&1);
+
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert!(Recall::record_deposits(standard).is_none());
+        assert!(Recall::record_deposits(permanent).is_none());
+        assert!(Recall::agent_records(1).is_empty());
+        System::assert_last_event(Event::AgentDepositsReleased { agent_id: 1, amount: 1_100 }.into());
+
+        // The records themselves and other agents' deposits are untouched
+        assert!(Recall::records(standard).is_some());
+        assert_eq!(Recall::get_record_by_hash(b"retiring-permanent").map(|(id, _)| id), Some(permanent));
+        assert_eq!(Recall::record_deposits(other), Some((2, 100)));
+    });
+}
+
+#[test]
+fn redaction_leaves_a_tombstone_and_hides_the_record() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let record_id = store_record_with(1, b"hash-1", RetentionClass::Ephemeral);
+        let kept = store_record(1, b"hash-2");
+        assert_ok!(Recall::add_signature(RuntimeOrigin::signed(2), record_id, b"sig".to_vec()));
+        assert_eq!(Balances::reserved_balance(1), 110);
+
+        System::set_block_number(5);
+        assert_ok!(Recall::redact_record(RuntimeOrigin::root(), record_id, RedactionReason::PersonalData));
+
+        let tombstone = Recall::tombstones(record_id).unwrap();
+        assert_eq!(tombstone.content_hash.into_inner(), b"hash-1".to_vec());
+        assert_eq!(tombstone.signers.into_inner(), vec![1, 2]);
+        assert_eq!(tombstone.created_at, 1);
+        assert_eq!(tombstone.redacted_at, 5);
+        assert_eq!(tombstone.reason, RedactionReason::PersonalData);
+        assert!(Recall::is_redacted(record_id));
+        System::assert_last_event(
+            Event::RecordRedacted {
+                record_id,
+                content_hash: b"hash-1".to_vec(),
+                reason: RedactionReason::PersonalData,
+            }
+            .into(),
+        );
+
+        // The content is gone from every query and the deposit is returned
+        assert!(Recall::records(record_id).is_none());
+        assert!(Recall::get_record_by_hash(b"hash-1").is_none());
+        assert_eq!(Recall::get_agent_records(&1).into_iter().map(|(id, _)| id).collect::<Vec<_>>(), vec![kept]);
+        assert!(Recall::get_agent_records(&2).is_empty());
+        assert_eq!(Recall::get_latest_records(10).into_iter().map(|(id, _)| id).collect::<Vec<_>>(), vec![kept]);
+        assert!(Recall::record_expiry(record_id).is_none());
+        assert_eq!(Balances::reserved_balance(1), 100);
+
+        // The content hash stays claimed and the record can no longer be signed
+        assert_noop!(
+            Recall::store_consensus_record(
+                RuntimeOrigin::signed(3),
+                RecordType::SingleAgentInsight,
+                b"hash-1".to_vec(),
+                ipfs(b"QmRecallTestCid"),
+                b"summary".to_vec(),
+                b"signature".to_vec(),
+                None,
+                RetentionClass::Standard,
+                CompressionCodec::None,
+            ),
+            Error::<Test>::DuplicateRecord
+        );
+        assert_noop!(
+            Recall::add_signature(RuntimeOrigin::signed(3), record_id, b"sig".to_vec()),
+            Error::<Test>::RecordRedacted
+        );
+    });
+}
+
+#[test]
+fn redaction_requires_root_and_a_live_record() {
+    new_test_ext().execute_with(|| {
+        let record_id = store_record(1, b"hash-1");
+
+        assert_noop!(
+            Recall::redact_record(RuntimeOrigin::signed(1), record_id, RedactionReason::LegalRequest),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Recall::redact_record(RuntimeOrigin::root(), 99, RedactionReason::LegalRequest),
+            Error::<Test>::RecordNotFound
+        );
+
+        assert_ok!(Recall::redact_record(RuntimeOrigin::root(), record_id, RedactionReason::Other(7)));
+        assert_noop!(
+            Recall::redact_record(RuntimeOrigin::root(), record_id, RedactionReason::LegalRequest),
+            Error::<Test>::RecordRedacted
+        );
+    });
+}
+
+#[test]
+fn export_records_pages_through_all_records() {
+    new_test_ext().execute_with(|| {
+        let ids = (0..4).map(|i| store_record(1, format!("hash-{}", i).as_bytes())).collect::<Vec<_>>();
+        assert_ok!(Recall::redact_record(RuntimeOrigin::root(), ids[1], RedactionReason::LegalRequest));
+        let record_size = (ids[0], Recall::records(ids[0]).unwrap()).encoded_size() as u32;
+
+        // Pages hold as many records as fit and skip redacted ones
+        let page = Recall::export_records(0, record_size * 2);
+        let records = Vec::<(u64, ConsensusRecord<Test>)>::decode(&mut &page.records[..]).unwrap();
+        assert_eq!(records.iter().map(|(id, _)| *id).collect::<Vec<_>>(), vec![ids[0], ids[2]]);
+        assert_eq!(records[0].1, Recall::records(ids[0]).unwrap());
+        assert_eq!(page.next_id, Some(ids[3]));
+
+        // The last page ends the export
+        let page = Recall::export_records(ids[3], record_size * 2);
+        let records = Vec::<(u64, ConsensusRecord<Test>)>::decode(&mut &page.records[..]).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(page.next_id, None);
+
+        // A page always makes progress, even if a record exceeds the byte budget
+        let page = Recall::export_records(0, 1);
+        let records = Vec::<(u64, ConsensusRecord<Test>)>::decode(&mut &page.records[..]).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(page.next_id, Some(ids[1]));
+    });
+}
+
+#[test]
+fn records_between_reads_only_the_buckets_of_the_range() {
+    new_test_ext().execute_with(|| {
+        let ids = [1u64, 5, 12, 19, 25, 31]
+            .into_iter()
+            .map(|block| {
+                System::set_block_number(block);
+                store_record(1, format!("hash-{}", block).as_bytes())
+            })
+            .collect::<Vec<_>>();
+        let page_ids = |page: RecordExportPage| {
+            Vec::<(u64, ConsensusRecord<Test>)>::decode(&mut &page.records[..])
+                .unwrap()
+                .into_iter()
+                .map(|(id, _)| id)
+                .collect::<Vec<_>>()
+        };
+
+        // Records are indexed by the bucket of 10 blocks they were created in
+        assert_eq!(Recall::block_bucket(19), 1);
+        assert!(crate::RecordsByBlockBucket::<Test>::contains_key(1, ids[2]));
+        assert!(crate::RecordsByBlockBucket::<Test>::contains_key(1, ids[3]));
+
+        // Both ends of the range are included, and records of shared buckets outside it are not
+        let page = Recall::records_between(5, 25, None);
+        assert_eq!(page.next_id, None);
+        assert_eq!(page_ids(page), vec![ids[1], ids[2], ids[3], ids[4]]);
+
+        // A cursor resumes from its record
+        assert_eq!(page_ids(Recall::records_between(5, 25, Some(ids[3]))), vec![ids[3], ids[4]]);
+
+        // Redacted records leave the index
+        assert_ok!(Recall::redact_record(RuntimeOrigin::root(), ids[2], RedactionReason::LegalRequest));
+        assert!(!crate::RecordsByBlockBucket::<Test>::contains_key(1, ids[2]));
+        assert_eq!(page_ids(Recall::records_between(0, 40, None)), vec![ids[0], ids[1], ids[3], ids[4], ids[5]]);
+        assert!(page_ids(Recall::records_between(32, 100, None)).is_empty());
+    });
+}
+
+#[test]
+fn pausing_blocks_submissions_but_not_governance() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let record_id = store_record(1, b"hash-1");
+
+        assert_noop!(Recall::pause(RuntimeOrigin::signed(1)), DispatchError::BadOrigin);
+        assert_ok!(Recall::pause(RuntimeOrigin::root()));
+        System::assert_last_event(Event::Paused.into());
+
+        assert_noop!(
+            Recall::add_signature(RuntimeOrigin::signed(2), record_id, b"sig".to_vec()),
+            Error::<Test>::PalletPaused
+        );
+        assert_noop!(
+            Recall::store_consensus_record(
+                RuntimeOrigin::signed(1),
+                RecordType::SingleAgentInsight,
+                b"hash-2".to_vec(),
+                ipfs(b"QmRecallTestCid"),
+                b"summary".to_vec(),
+                b"signature".to_vec(),
+                None,
+                RetentionClass::Standard,
+                CompressionCodec::None,
+            ),
+            Error::<Test>::PalletPaused
+        );
+
+        // Governance still works and records stay readable
+        assert!(Recall::records(record_id).is_some());
+        assert_ok!(Recall::redact_record(RuntimeOrigin::root(), record_id, RedactionReason::LegalRequest));
+
+        assert_ok!(Recall::unpause(RuntimeOrigin::root()));
+        System::assert_last_event(Event::Unpaused.into());
+        store_record(1, b"hash-2");
+    });
+}
+
+#[test]
+fn system_records_log_status_changes_and_slashes() {
+    new_test_ext().execute_with(|| {
+        let own = store_record(1, b"hash-1");
+
+        Recall::note_system_record(&1, SystemRecord::StatusChanged { from: 0, to: 1 });
+        System::assert_last_event(Event::SystemRecordStored {
+            record_id: own + 1,
+            record_type: RecordType::AgentStatusUpdate,
+            agent_id: 1,
+        }.into());
+        Recall::note_system_record(&1, SystemRecord::Slashed { reputation_penalty: 50 });
+
+        let status = Recall::records(own + 1).unwrap();
+        assert_eq!(status.record_type, RecordType::AgentStatusUpdate);
+        assert!(status.signatures.is_empty());
+        assert_eq!(
+            <(u64, SystemRecord)>::decode(&mut &status.summary[..]).unwrap(),
+            (1, SystemRecord::StatusChanged { from: 0, to: 1 })
+        );
+        assert_eq!(Recall::content_hash_to_record(&status.content_hash), Some(own + 1));
+
+        let slash = Recall::records(own + 2).unwrap();
+        assert_eq!(slash.record_type, RecordType::SystemEvent);
+        assert_eq!(
+            <(u64, SystemRecord)>::decode(&mut &slash.summary[..]).unwrap(),
+            (1, SystemRecord::Slashed { reputation_penalty: 50 })
+        );
+
+        // System records hold no deposit and stay out of the agent's own index
+        assert_eq!(Recall::agent_records(1).to_vec(), vec![own]);
+        assert_eq!(Balances::reserved_balance(1), StandardDeposit::get());
+    });
+}
+
+#[test]
+fn system_records_can_be_disabled() {
+    new_test_ext().execute_with(|| {
+        SystemRecords::set(false);
+
+        Recall::note_system_record(&1, SystemRecord::Slashed { reputation_penalty: 50 });
+
+        assert_eq!(Recall::next_record_id(), 0);
+        assert!(Recall::records(0).is_none());
+    });
+}
+
+#[test]
+fn duplicate_summaries_are_linked_to_the_first_record() {
+    use sp_runtime::traits::Hash;
+
+    new_test_ext().execute_with(|| {
+        let summary_hash = <Test as frame_system::Config>::Hashing::hash(b"summary");
+        let original = store_record(1, b"hash-1");
+        assert_eq!(Recall::summary_hash_to_record(summary_hash), Some(original));
+        assert!(Recall::duplicate_summary_of(original).is_none());
+
+        // Still stored, but linked and flagged
+        let duplicate = store_record(2, b"hash-2");
+        assert!(Recall::records(duplicate).is_some());
+        assert_eq!(Recall::duplicate_summary_of(duplicate), Some(original));
+        System::assert_last_event(
+            Event::DuplicateSummary { record_id: duplicate, original_record_id: original, summary_hash }.into(),
+        );
+
+        // Once the original is gone the next record with the summary takes its place
+        assert_ok!(Recall::redact_record(RuntimeOrigin::root(), original, RedactionReason::PersonalData));
+        assert!(Recall::summary_hash_to_record(summary_hash).is_none());
+        let next = store_record(3, b"hash-3");
+        assert_eq!(Recall::summary_hash_to_record(summary_hash), Some(next));
+        assert!(Recall::duplicate_summary_of(next).is_none());
+        assert_eq!(Recall::duplicate_summary_of(duplicate), Some(original));
+    });
+}
+
+#[test]
+fn summary_index_can_be_disabled() {
+    new_test_ext().execute_with(|| {
+        IndexSummaries::set(false);
+
+        store_record(1, b"hash-1");
+        let second = store_record(2, b"hash-2");
+
+        assert!(Recall::duplicate_summary_of(second).is_none());
+        assert_eq!(SummaryHashToRecord::<Test>::iter().count(), 0);
+    });
+}
+
+#[test]
+fn compressed_records_are_bounded_by_their_decompressed_size() {
+    new_test_ext().execute_with(|| {
+        let store = |content_hash: &[u8], summary: Vec<u8>, metadata: Option<Vec<u8>>| {
+            Recall::store_consensus_record(
+                RuntimeOrigin::signed(1),
+                RecordType::SingleAgentInsight,
+                content_hash.to_vec(),
+                ipfs(b"QmRecallTestCid"),
+                summary,
+                b"signature".to_vec(),
+                metadata,
+                RetentionClass::Standard,
+                CompressionCodec::Deflate,
+            )
+        };
+        let deflate = |data: &[u8]| miniz_oxide::deflate::compress_to_vec(data, 6);
+
+        // Larger than `MaxSummaryLength` once decompressed, but within `MaxDecompressedLength`
+        let summary = vec![b'a'; 600];
+        let record_id = Recall::next_record_id();
+        assert_ok!(store(b"hash-1", deflate(&summary), Some(deflate(b"{}"))));
+        assert_eq!(Recall::records(record_id).unwrap().compression, CompressionCodec::Deflate);
+        assert_eq!(
+            Recall::record_content(record_id),
+            Some(RecordContent { summary, metadata: Some(b"{}".to_vec()) })
+        );
+
+        assert_noop!(store(b"hash-2", deflate(&[b'a'; 2_000]), None), Error::<Test>::InvalidCompression);
+        assert_noop!(store(b"hash-2", b"not deflate".to_vec(), None), Error::<Test>::InvalidCompression);
+        assert_noop!(
+            store(b"hash-2", deflate(b"summary"), Some(b"not deflate".to_vec())),
+            Error::<Test>::InvalidCompression
+        );
+    });
+}
+
+#[test]
+fn migration_to_v3_rehashes_record_keys() {
+    use crate::migrations::{v3::LazyMigrationV3, v4::LazyMigrationV4, v5::LazyMigrationV5};
+    use frame_support::{
+        storage::{unhashed, StoragePrefixedMap},
+        traits::StorageVersion,
+        Blake2_128Concat, StorageHasher,
+    };
+
+    new_test_ext().execute_with(|| {
+        let record_id = Recall::next_record_id();
+        assert_ok!(Recall::store_consensus_record(
+            RuntimeOrigin::signed(1),
+            RecordType::SingleAgentInsight,
+            b"hash-1".to_vec(),
+            ipfs(b"QmRecallTestCid"),
+            b"summary".to_vec(),
+            b"signature".to_vec(),
+            None,
+            RetentionClass::Standard,
+            CompressionCodec::None,
+        ));
+        let record = Recall::records(record_id).unwrap();
+
+        // Move the record back under its pre-v3 key, in the layout from before content locators
+        let new_key = crate::Records::<Test>::hashed_key_for(record_id);
+        let old_key = [
+            crate::Records::<Test>::final_prefix().to_vec(),
+            Blake2_128Concat::hash(&record_id.encode()),
+        ]
+        .concat();
+        assert_eq!(new_key.len() + 8, old_key.len());
+        unhashed::kill(&new_key);
+        unhashed::put(&old_key, &legacy_record(&record));
+        StorageVersion::new(2).put::<Recall>();
+        assert!(Recall::records(record_id).is_none());
+
+        run_to_completion::<LazyMigrationV3<Test>>();
+
+        assert!(unhashed::get_raw(&old_key).is_none());
+        assert_eq!(StorageVersion::get::<Recall>(), 3);
+
+        run_to_completion::<LazyMigrationV4<Test>>();
+
+        assert_eq!(Recall::records(record_id), Some(record));
+        assert_eq!(StorageVersion::get::<Recall>(), 4);
+
+        // Records stored before the block bucket index are added to it
+        let _ = crate::RecordsByBlockBucket::<Test>::clear(u32::MAX, None);
+        run_to_completion::<LazyMigrationV5<Test>>();
+
+        assert!(crate::RecordsByBlockBucket::<Test>::contains_key(0, record_id));
+        assert_eq!(StorageVersion::get::<Recall>(), 5);
+    });
+}
+
+// Helper function to step a multi-block migration until it is done
+fn run_to_completion<M: frame_support::migrations::SteppedMigration>() {
+    let mut cursor = None;
+    loop {
+        cursor = M::step(cursor, &mut frame_support::weights::WeightMeter::new()).expect("migration step failed");
+        if cursor.is_none() {
+            break;
+        }
+    }
+}
+
+// Helper function to encode a record in the layout from before content locators
+fn legacy_record(record: &ConsensusRecord<Test>) -> crate::migrations::v4::OldConsensusRecord<Test> {
+    let ContentLocator::Ipfs(ipfs_cid) = record.locator.clone() else { panic!("not an IPFS record") };
+    crate::migrations::v4::OldConsensusRecord {
+        record_type: record.record_type.clone(),
+        content_hash: record.content_hash.clone(),
+        ipfs_cid,
+        summary: record.summary.clone(),
+        signatures: record.signatures.clone(),
+        created_at: record.created_at,
+        metadata: record.metadata.clone(),
+        trust_score: record.trust_score,
+        retention: record.retention,
+        compression: record.compression,
+    }
+}
+
+#[test]
+fn content_locators_are_validated_and_limited_to_allowed_backends() {
+    new_test_ext().execute_with(|| {
+        let store = |content_hash: &[u8], locator: ContentLocator<Test>| {
+            Recall::store_consensus_record(
+                RuntimeOrigin::signed(1),
+                RecordType::SingleAgentInsight,
+                content_hash.to_vec(),
+                locator,
+                b"summary".to_vec(),
+                b"signature".to_vec(),
+                None,
+                RetentionClass::Standard,
+                CompressionCodec::None,
+            )
+        };
+
+        // Only IPFS is allowed at first
+        assert_eq!(Recall::allowed_backends().into_inner(), vec![StorageBackend::Ipfs]);
+        assert_noop!(store(b"hash-1", ContentLocator::Arweave([7u8; 32])), Error::<Test>::BackendNotAllowed);
+        assert_noop!(store(b"hash-1", ipfs(b"not-a-cid")), Error::<Test>::InvalidContentLocator);
+        assert_ok!(store(b"hash-1", ipfs(b"bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi")));
+
+        assert_ok!(Recall::set_allowed_backends(
+            RuntimeOrigin::root(),
+            vec![StorageBackend::S3, StorageBackend::Arweave, StorageBackend::S3].try_into().unwrap()
+        ));
+        System::assert_last_event(Event::AllowedBackendsSet {
+            backends: vec![StorageBackend::Arweave, StorageBackend::S3],
+        }.into());
+
+        assert_noop!(store(b"hash-2", ipfs(b"QmRecallTestCid")), Error::<Test>::BackendNotAllowed);
+        assert_noop!(store(b"hash-2", ContentLocator::Arweave([0u8; 32])), Error::<Test>::InvalidContentLocator);
+        assert_noop!(
+            store(b"hash-2", ContentLocator::S3(b"/leading/slash".to_vec().try_into().unwrap())),
+            Error::<Test>::InvalidContentLocator
+        );
+        assert_ok!(store(b"hash-2", ContentLocator::Arweave([7u8; 32])));
+        assert_ok!(store(b"hash-3", ContentLocator::S3(b"insights/2026/report.json".to_vec().try_into().unwrap())));
+        assert_eq!(Recall::records(2).unwrap().locator.backend(), StorageBackend::S3);
+    });
+}
+
+#[test]
+fn signature_requests_are_listed_until_the_agent_signs() {
+    new_test_ext().execute_with(|| {
+        let record_id = store_record(1, b"hash-1");
+
+        // Only the owner can ask, for an agent that has not signed, within the request period
+        assert_noop!(
+            Recall::request_signature(RuntimeOrigin::signed(2), record_id, 3, 10),
+            Error::<Test>::NotRecordOwner
+        );
+        assert_noop!(
+            Recall::request_signature(RuntimeOrigin::signed(1), record_id, 1, 10),
+            Error::<Test>::AgentAlreadySigned
+        );
+        assert_noop!(
+            Recall::request_signature(RuntimeOrigin::signed(1), record_id, 3, 2),
+            Error::<Test>::InvalidRequestDeadline
+        );
+        assert_noop!(
+            Recall::request_signature(RuntimeOrigin::signed(1), record_id, 3, 22),
+            Error::<Test>::InvalidRequestDeadline
+        );
+        assert_noop!(
+            Recall::request_signature(RuntimeOrigin::signed(1), 7, 3, 10),
+            Error::<Test>::RecordNotFound
+        );
+
+        assert_ok!(Recall::request_signature(RuntimeOrigin::signed(1), record_id, 3, 10));
+        System::assert_last_event(Event::SignatureRequested { record_id, agent_id: 3, deadline: 10 }.into());
+        assert_eq!(Recall::signature_requests(record_id, 3), Some(10));
+        assert_eq!(Recall::pending_signature_requests(3).into_inner(), vec![record_id]);
+        assert_noop!(
+            Recall::request_signature(RuntimeOrigin::signed(1), record_id, 3, 12),
+            Error::<Test>::SignatureAlreadyRequested
+        );
+
+        // Agents only have room for `MaxPendingRequests` requests
+        let second = store_record(1, b"hash-2");
+        let third = store_record(1, b"hash-3");
+        assert_ok!(Recall::request_signature(RuntimeOrigin::signed(1), second, 3, 12));
+        assert_noop!(
+            Recall::request_signature(RuntimeOrigin::signed(1), third, 3, 12),
+            Error::<Test>::TooManyPendingRequests
+        );
+
+        // Signing answers the request
+        assert_ok!(Recall::add_signature(RuntimeOrigin::signed(3), record_id, b"sig-3".to_vec()));
+        assert_eq!(Recall::signature_requests(record_id, 3), None);
+        assert_eq!(Recall::pending_signature_requests(3).into_inner(), vec![second]);
+        assert!(Recall::request_deadlines(10).is_empty());
+        assert_ok!(Recall::do_try_state());
+
+        run_to_block(10);
+        assert!(missed_requests().is_empty());
+    });
+}
+
+#[test]
+fn unanswered_signature_requests_expire_and_are_reported() {
+    new_test_ext().execute_with(|| {
+        let record_id = store_record(1, b"hash-1");
+        let redacted = store_record(1, b"hash-2");
+        assert_ok!(Recall::request_signature(RuntimeOrigin::signed(1), record_id, 3, 5));
+        assert_ok!(Recall::request_signature(RuntimeOrigin::signed(1), redacted, 3, 5));
+        assert_ok!(Recall::redact_record(RuntimeOrigin::root(), redacted, RedactionReason::PersonalData));
+
+        run_to_block(4);
+        assert_eq!(Recall::pending_signature_requests(3).len(), 2);
+
+        // Only the request on the live record is reported
+        run_to_block(5);
+        System::assert_last_event(Event::SignatureRequestExpired { record_id, agent_id: 3 }.into());
+        assert_eq!(missed_requests(), vec![(3, record_id)]);
+        assert!(Recall::pending_signature_requests(3).is_empty());
+        assert_eq!(Recall::signature_requests(record_id, 3), None);
+        assert_eq!(Recall::signature_requests(redacted, 3), None);
+
+        // Reports can be turned off, the request still expires
+        ReportMissedRequests::set(false);
+        assert_ok!(Recall::request_signature(RuntimeOrigin::signed(1), record_id, 4, 8));
+        run_to_block(8);
+        System::assert_last_event(Event::SignatureRequestExpired { record_id, agent_id: 4 }.into());
+        assert_eq!(missed_requests(), vec![(3, record_id)]);
+    });
+}
+
+#[test]
+fn signature_requests_reserve_a_deposit_until_they_close() {
+    new_test_ext().execute_with(|| {
+        let record_id = store_record(1, b"hash-1");
+        let reserved = Balances::reserved_balance(1);
+
+        assert_ok!(Recall::request_signature(RuntimeOrigin::signed(1), record_id, 3, 10));
+        assert_ok!(Recall::request_signature(RuntimeOrigin::signed(1), record_id, 4, 5));
+        assert_eq!(Balances::reserved_balance(1), reserved + 2 * SignatureRequestDeposit::get());
+        assert_eq!(Recall::request_deposits(record_id, 3), Some((1, SignatureRequestDeposit::get())));
+
+        // Answering a request returns its deposit
+        assert_ok!(Recall::add_signature(RuntimeOrigin::signed(3), record_id, b"sig-3".to_vec()));
+        assert_eq!(Balances::reserved_balance(1), reserved + SignatureRequestDeposit::get());
+        assert_eq!(Recall::request_deposits(record_id, 3), None);
+
+        // So does letting it expire
+        run_to_block(5);
+        assert_eq!(Balances::reserved_balance(1), reserved);
+        assert_eq!(Recall::request_deposits(record_id, 4), None);
+        assert_ok!(Recall::do_try_state());
+
+        // Requesters that cannot reserve the deposit cannot open requests
+        assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(1), 5, Balances::free_balance(1) - 2));
+        assert_noop!(
+            Recall::request_signature(RuntimeOrigin::signed(1), record_id, 5, 10),
+            Error::<Test>::InsufficientDeposit
+        );
+    });
+}
+
+// Helper function to store a record signed by agents 1 and 2, contributing a quarter and three
+// quarters of its trust, with 4_000 staked each
+fn disputed_record() -> u64 {
+    set_signer_weight(1, 10_000, Perbill::zero());
+    set_signer_weight(2, 90_000, Perbill::zero());
+    set_stake(1, 4_000);
+    set_stake(2, 4_000);
+    let record_id = store_record(1, b"hash-1");
+    assert_ok!(Recall::add_signature(RuntimeOrigin::signed(2), record_id, b"sig-2".to_vec()));
+    record_id
+}
+
+#[test]
+fn challenges_escrow_signer_stake_by_trust_contribution() {
+    new_test_ext().execute_with(|| {
+        let record_id = disputed_record();
+
+        assert_noop!(Recall::challenge_record(RuntimeOrigin::signed(3), 7), Error::<Test>::RecordNotFound);
+        assert_noop!(Recall::challenge_record(RuntimeOrigin::signed(6), record_id), Error::<Test>::InsufficientBond);
+
+        // 10% of the stake at full contribution: 2.5% and 7.5% of 4_000, bonded at 25%
+        assert_ok!(Recall::challenge_record(RuntimeOrigin::signed(3), record_id));
+        System::assert_last_event(
+            Event::RecordChallenged { record_id, challenger: 3, bond: 100, escrowed: 400 }.into(),
+        );
+        assert_eq!(stake_of(1), 3_900);
+        assert_eq!(stake_of(2), 3_700);
+        assert_eq!(Balances::free_balance(Recall::escrow_account(record_id)), 500);
+        assert_eq!(Balances::free_balance(3), 9_900);
+        assert_eq!(Recall::disputes(record_id).unwrap().escrowed.into_inner(), vec![(1, 100), (2, 300)]);
+
+        assert_noop!(Recall::challenge_record(RuntimeOrigin::signed(4), record_id), Error::<Test>::RecordDisputed);
+    });
+}
+
+#[test]
+fn upheld_disputes_slash_escrowed_stake_and_return_the_bond() {
+    new_test_ext().execute_with(|| {
+        let record_id = disputed_record();
+        assert_ok!(Recall::challenge_record(RuntimeOrigin::signed(3), record_id));
+        let issuance = Balances::total_issuance();
+
+        assert_noop!(
+            Recall::resolve_dispute(RuntimeOrigin::signed(3), record_id, DisputeOutcome::Upheld),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Recall::resolve_dispute(RuntimeOrigin::root(), record_id, DisputeOutcome::Upheld));
+        System::assert_last_event(
+            Event::DisputeResolved { record_id, outcome: DisputeOutcome::Upheld, slashed: 400, released: 100 }.into(),
+        );
+
+        assert_eq!(Balances::free_balance(3), 10_000);
+        assert_eq!(Balances::total_balance(&Recall::escrow_account(record_id)), 0);
+        assert_eq!(Balances::total_issuance(), issuance - 400);
+        assert_eq!(stake_of(1), 3_900);
+        assert_eq!(stake_of(2), 3_700);
+        assert!(Recall::disputes(record_id).is_none());
+        assert_noop!(
+            Recall::resolve_dispute(RuntimeOrigin::root(), record_id, DisputeOutcome::Upheld),
+            Error::<Test>::NoDispute
+        );
+    });
+}
+
+#[test]
+fn rejected_disputes_restore_escrowed_stake_and_forfeit_the_bond() {
+    new_test_ext().execute_with(|| {
+        let record_id = disputed_record();
+        assert_ok!(Recall::challenge_record(RuntimeOrigin::signed(3), record_id));
+
+        // Disputes outlive the record
+        assert_ok!(Recall::redact_record(RuntimeOrigin::root(), record_id, RedactionReason::LegalRequest));
+        assert_ok!(Recall::resolve_dispute(RuntimeOrigin::root(), record_id, DisputeOutcome::Rejected));
+        System::assert_last_event(
+            Event::DisputeResolved { record_id, outcome: DisputeOutcome::Rejected, slashed: 100, released: 400 }.into(),
+        );
+
+        assert_eq!(stake_of(1), 4_000);
+        assert_eq!(stake_of(2), 4_000);
+        assert_eq!(Balances::reserved_balance(2), 4_000);
+        assert_eq!(Balances::free_balance(3), 9_900);
+        assert_eq!(Balances::total_balance(&Recall::escrow_account(record_id)), 0);
+        assert!(Recall::disputes(record_id).is_none());
+        assert!(Recall::signer_disputes(1).is_empty());
+        assert!(Recall::dispute_deadlines(11).is_empty());
+    });
+}
+
+#[test]
+fn signers_are_escrowed_by_a_limited_number_of_disputes() {
+    new_test_ext().execute_with(|| {
+        set_signer_weight(1, 10_000, Perbill::zero());
+        set_stake(1, 4_000);
+        let records = [b"hash-1", b"hash-2", b"hash-3"].map(|hash| store_record(1, hash));
+
+        // 10% of the remaining stake each time
+        assert_ok!(Recall::challenge_record(RuntimeOrigin::signed(3), records[0]));
+        assert_ok!(Recall::challenge_record(RuntimeOrigin::signed(3), records[1]));
+        assert_eq!(stake_of(1), 3_240);
+        assert_eq!(Recall::signer_disputes(1).into_inner(), vec![records[0], records[1]]);
+
+        // Further challenges leave the signer's stake alone and bond the minimum
+        assert_ok!(Recall::challenge_record(RuntimeOrigin::signed(3), records[2]));
+        System::assert_last_event(
+            Event::RecordChallenged { record_id: records[2], challenger: 3, bond: 50, escrowed: 0 }.into(),
+        );
+        assert_eq!(stake_of(1), 3_240);
+
+        assert_ok!(Recall::resolve_dispute(RuntimeOrigin::root(), records[0], DisputeOutcome::Rejected));
+        assert_eq!(Recall::signer_disputes(1).into_inner(), vec![records[1]]);
+    });
+}
+
+#[test]
+fn unresolved_disputes_expire_and_restore_the_escrow() {
+    new_test_ext().execute_with(|| {
+        let record_id = disputed_record();
+        assert_ok!(Recall::challenge_record(RuntimeOrigin::signed(3), record_id));
+        assert_eq!(Recall::disputes(record_id).unwrap().expires_at, 11);
+
+        run_to_block(10);
+        assert!(Recall::disputes(record_id).is_some());
+
+        run_to_block(11);
+        System::assert_has_event(Event::DisputeExpired { record_id, released: 400 }.into());
+        assert_eq!(stake_of(1), 4_000);
+        assert_eq!(stake_of(2), 4_000);
+        assert_eq!(Balances::free_balance(3), 10_000);
+        assert_eq!(Balances::total_balance(&Recall::escrow_account(record_id)), 0);
+        assert!(Recall::disputes(record_id).is_none());
+        assert!(Recall::signer_disputes(2).is_empty());
+        assert!(Recall::dispute_deadlines(11).is_empty());
+    });
+}
+
+#[test]
+fn escrowed_stake_follows_rotated_keys() {
+    new_test_ext().execute_with(|| {
+        let record_id = disputed_record();
+        assert_ok!(Recall::challenge_record(RuntimeOrigin::signed(3), record_id));
+        set_signer_weight(4, 10_000, Perbill::zero());
+        set_stake(4, 100);
+        assert_ok!(Recall::challenge_record(RuntimeOrigin::signed(3), store_record(4, b"hash-4")));
+
+        assert_noop!(Recall::on_agent_rotated(&1, &4), Error::<Test>::DisputesInUse);
+        assert_ok!(Recall::on_agent_rotated(&1, &5));
+        assert!(Recall::signer_disputes(1).is_empty());
+        assert_eq!(Recall::signer_disputes(5).into_inner(), vec![record_id]);
+        assert_eq!(Recall::disputes(record_id).unwrap().escrowed.into_inner(), vec![(5, 100), (2, 300)]);
+
+        assert_ok!(Recall::resolve_dispute(RuntimeOrigin::root(), record_id, DisputeOutcome::Rejected));
+        assert_eq!(stake_of(5), 100);
+        assert_eq!(Balances::reserved_balance(5), 100);
+        assert_eq!(stake_of(1), 3_900);
+        assert!(Recall::signer_disputes(5).is_empty());
+    });
+}
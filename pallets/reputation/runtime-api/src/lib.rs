@@ -0,0 +1,101 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        lib.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Runtime APIs for committed agent reputations
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! Runtime APIs used by reputation oracles to read the reputation root of the current era and
+//! produce proofs of individual agents' reputations against it, and by dashboards to read agents'
+//! participation streaks, by agents to see when they will serve on the committee, by
+//! compensation systems to read agents' consensus activity per era, by analytics dashboards
+//! to read the network's consensus activity per era, and by auditors to read the consensus
+//! parameters each era ran under.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_reputation::{EraConfig, EraIndex, EraStatistics, MerkleInclusionProof, ParticipationSummary};
+
+sp_api::decl_runtime_apis! {
+    /// Committed agent reputations and their proofs
+    #[api_version(2)]
+    pub trait ReputationApi<AccountId>
+    where
+        AccountId: Codec,
+    {
+        /// The current era and the root its effective reputations were committed to, if any
+        fn reputation_root() -> Option<(EraIndex, [u8; 32])>;
+
+        /// Merkle proof of an agent's committed reputation, or `None` if it is not in the
+        /// current snapshot
+        fn reputation_proof(agent_id: AccountId) -> Option<MerkleInclusionProof>;
+
+        /// Number of consecutive eras, up to the current one, in which the agent took part in
+        /// enough consensus rounds
+        #[api_version(2)]
+        fn participation_streak(agent_id: AccountId) -> u32;
+    }
+
+    /// Committee rotation schedule
+    pub trait CommitteeApi<AccountId, BlockNumber>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+    {
+        /// Agents serving on the committee of the current era
+        fn current_committee() -> Vec<AccountId>;
+
+        /// Block the next era and its committee start at, or `None` if eras are disabled
+        fn next_rotation_block() -> Option<BlockNumber>;
+
+        /// Committee of `era`: the current committee, or the projected one for the next era
+        ///
+        /// `None` for any other era.
+        fn committee_for_era(era: EraIndex) -> Option<Vec<AccountId>>;
+    }
+
+    /// Consensus activity of agents per era
+    pub trait ParticipationApi<AccountId>
+    where
+        AccountId: Codec,
+    {
+        /// Submissions, signatures and finalizations of an agent in `era`, or `None` if the era
+        /// has not started or its summaries were pruned
+        fn era_summary(agent: AccountId, era: EraIndex) -> Option<ParticipationSummary>;
+    }
+
+    /// Network-wide consensus activity per era
+    pub trait EraStatsApi<Balance>
+    where
+        Balance: Codec,
+    {
+        /// Logs submitted, finalized and retracted, signers, reputation rewarded and stake
+        /// slashed in `era`; the counts so far for the current era, and `None` for eras that
+        /// have not started
+        fn era_stats(era: EraIndex) -> Option<EraStatistics<Balance>>;
+    }
+
+    /// Consensus parameters per era
+    pub trait EraConfigApi<Balance, BlockNumber>
+    where
+        Balance: Codec,
+        BlockNumber: Codec,
+    {
+        /// Consensus parameters `era` started under, or `None` if the era has not started or
+        /// started before they were recorded
+        fn era_config(era: EraIndex) -> Option<EraConfig<Balance, BlockNumber>>;
+
+        /// The era `block` belongs to and the consensus parameters it ran under, or `None` if
+        /// that era's parameters were not recorded
+        fn era_config_at(block: BlockNumber) -> Option<(EraIndex, EraConfig<Balance, BlockNumber>)>;
+    }
+}
@@ -0,0 +1,130 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        benchmarking.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Benchmarking for the Reputation pallet
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! Benchmarking for the Reputation pallet.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as Reputation;
+use frame_benchmarking::v2::*;
+use pallet_agent_registry::Pallet as AgentRegistry;
+use sp_std::vec;
+
+// Helper function to register a staked agent for benchmarking
+fn staked_agent<T: Config + pallet_agent_registry::Config>() -> T::AccountId {
+    let agent: T::AccountId = account("agent", 0, 0);
+    AgentRegistry::<T>::register_agent(
+        RawOrigin::Signed(agent.clone()).into(),
+        pallet_agent_registry::AgentRole::Lyra,
+        None,
+    ).expect("Failed to register agent");
+
+    let stake = T::MinimumStake::get().saturating_mul(10u32.into());
+    <T as Config>::Currency::make_free_balance_be(&agent, stake.saturating_mul(2u32.into()));
+    Reputation::<T>::stake(RawOrigin::Signed(agent.clone()).into(), stake)
+        .expect("Failed to stake");
+
+    agent
+}
+
+#[benchmarks(where T: pallet_agent_registry::Config)]
+mod benchmarks {
+    use super::*;
+
+    // Worst case: a full history of the same offense, so the penalty escalates and the oldest
+    // entry is archived, under an offense type that also quarantines
+    #[benchmark]
+    fn report_offense() {
+        let agent = staked_agent::<T>();
+        let now = <frame_system::Pallet<T>>::block_number();
+        let history = vec![(OffenseType::DataWithholding, now); T::MaxOffenseHistory::get() as usize];
+        <OffenseHistory<T>>::insert(&agent, frame_support::BoundedVec::truncate_from(history));
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, agent.clone(), OffenseType::DataWithholding);
+
+        assert!(Reputation::<T>::reputation(&agent).quarantine_until.is_some());
+    }
+
+    // Worst case: a curve with every breakpoint
+    #[benchmark]
+    fn set_reward_curve() {
+        let points = (0..MAX_REWARD_CURVE_POINTS as u64).map(|i| (i * 100, i * 10)).collect::<Vec<_>>();
+        let curve = PiecewiseRewardCurve {
+            input: RewardCurveInput::EffectiveReputation,
+            points: frame_support::BoundedVec::truncate_from(points),
+        };
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, Some(curve));
+
+        assert!(Reputation::<T>::reward_curve().is_some());
+    }
+
+    // Worst case: a temporary ban, which also has to be dropped from `TemporarilyBanned`
+    #[benchmark]
+    fn force_unban() {
+        let agent = staked_agent::<T>();
+        for _ in 0..T::MaxOffenses::get() {
+            Reputation::<T>::do_report_offense(&agent, OffenseType::Unresponsiveness)
+                .expect("Failed to report offense");
+        }
+        assert!(Reputation::<T>::reputation(&agent).is_banned);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, agent.clone());
+
+        assert!(!Reputation::<T>::reputation(&agent).is_banned);
+    }
+
+    // Worst case: the agent has every other pending slash slot taken too
+    #[benchmark]
+    fn cancel_deferred_slash() {
+        let agent = staked_agent::<T>();
+        let slash_id = Reputation::<T>::next_slash_id();
+        let ids = (slash_id..slash_id + T::MaxPendingSlashesPerAgent::get() as u64).collect::<Vec<_>>();
+        <AgentPendingSlashes<T>>::insert(&agent, frame_support::BoundedVec::truncate_from(ids));
+        <PendingSlashes<T>>::insert(slash_id, PendingSlash {
+            agent_id: agent.clone(),
+            offense_type: OffenseType::Equivocation,
+            reported_at: 0u32.into(),
+            execute_at: <frame_system::Pallet<T>>::block_number(),
+        });
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, slash_id);
+
+        assert!(Reputation::<T>::pending_slashes(slash_id).is_none());
+    }
+
+    // Worst case: a full batch of distinct agents
+    #[benchmark]
+    fn reward_consensus_many(n: Linear<1, { T::MaxRewardBatch::get() }>) -> Result<(), BenchmarkError> {
+        let origin = T::RewardOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let agents = (0..n).map(|i| account::<T::AccountId>("rewarded", i, 0)).collect::<Vec<_>>();
+        let first = agents[0].clone();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, frame_support::BoundedVec::truncate_from(agents));
+
+        assert_eq!(Reputation::<T>::reputation(&first).consensus_count, 1);
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(
+        Reputation,
+        crate::mock::new_test_ext(),
+        crate::mock::Test,
+    );
+}
@@ -1,13 +1,15 @@
-# ----------------------------------------------------------------------------
-#  File:        lib.rs
-#  Project:     Celaya Solutions (C-Suite Blockchain)
-#  Created by:  Celaya Solutions, 2025
-#  Author:      Christopher Celaya <chris@celayasolutions.com>
-#  Description: Stake-weighted reputation system with adaptive incentives and slashing
-#  Version:     1.0.0
-#  License:     BSL (SPDX id BUSL)
-#  Last Update: (June 2025)
-# ----------------------------------------------------------------------------
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        lib.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Stake-weighted reputation system with adaptive incentives and slashing
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (June 2025)
+ * ----------------------------------------------------------------------------
+ */
 
 //! # Stake-Weighted Reputation Pallet
 //!
@@ -21,6 +23,16 @@
 //! - Slash stakes for misbehavior (unresponsiveness, equivocation)
 //! - Quarantine or demote agents based on offenses
 //! - Track and reward good behavior with reputation boosts
+//! - Exempt routine calls of high-reputation agents from fees, bounded per era by a quota the
+//!   [`ChargeFeelessQuota`] transaction extension charges before dispatch
+//! - Select a committee of the highest-reputation agents at the start of every era, leaving out
+//!   agents below the registry's minimum agent version and ranking agents that report
+//!   themselves overloaded last, and predict the next rotation and committee so agents can
+//!   prepare for their duty
+//! - Commit every agent's effective reputation to a Merkle root at the start of every era
+//! - Gate calls of other pallets on reputation through the [`EnsureReputationAtLeast`] origin
+//! - Raise the transaction pool priority of consensus calls from reputable committee members
+//!   while the chain is congested through the [`PrioritizeCommittee`] transaction extension
 //!
 //! ## Features
 //!
@@ -29,15 +41,129 @@
 //! - Reputation weights are multiplied by stake amount
 //! - Higher stakes = more influence and higher rewards
 //!
+//! ### Stake Locks
+//! - An agent can lock its whole stake with `lock_stake`: [`StakeLock::Short`] locks it for
+//!   `StakeLockPeriod` blocks and doubles the weight of its stake in effective reputation,
+//!   [`StakeLock::Long`] locks it for four periods and quadruples that weight
+//! - A lock can only be extended, never shortened or lowered, and stake added while locked
+//!   joins the lock
+//! - The lock expires at the block kept in [`ReputationInfo`](pallet::ReputationInfo), after
+//!   which the stake counts once again
+//! - Unstaking before the lock expires pays `EarlyUnlockPenalty` of the unstaked amount
+//!
+//! ### Stake Accounting Repair
+//! - An agent's active and unbonding stake should always be reserved in full, but a partial
+//!   slash or unreserve can leave the recorded stake above the actual reserve
+//! - Anyone can call `repair_stake_accounting` to lower the recorded stake, `TotalStake` and
+//!   the stake weight to match, which emits `StakeAccountingRepaired` with both amounts
+//!
 //! ### Adaptive Incentives
 //! - Successful consensus participation increases reputation
+//! - Consensus rewards are granted by `RewardOrigin` with `reward_consensus`, or for up to
+//!   `MaxRewardBatch` agents at once with `reward_consensus_many`, and by the runtime through
+//!   [`Pallet::do_reward_consensus`]
 //! - Rewards scale with stake and performance
+//! - Governance can replace the flat `ConsensusReward` with a [`PiecewiseRewardCurve`] over an
+//!   agent's effective reputation, participation count or participation streak using
+//!   `set_reward_curve`
+//! - Consecutive eras with at least `MinStreakParticipations` consensus participations form a
+//!   participation streak, which raises consensus rewards by `StreakBonusPerEra` per era up to
+//!   `MaxStreakBonus`. An era without enough participations or any offense resets the streak
+//! - The first agents to co-sign a consensus log earn a small flat bonus
 //! - Quadratic decay prevents reputation hoarding
+//! - Reputation does not decay during an agent's approved maintenance windows: the blocks they
+//!   cover are left out when decay is applied, so a window that only partly overlaps the time
+//!   since the last update pauses decay for the overlapping blocks only
 //!
 //! ### Slashing Mechanism
 //! - Unresponsiveness: slash 5% of stake, reduce reputation
 //! - Equivocation: slash 25% of stake, temporary quarantine
-//! - Multiple offenses: exponential penalties
+//! - Data withholding: slash 5% of stake, temporary quarantine
+//! - Latency violation: slash 2.5% of stake, small reputation penalty
+//! - Policy violation: slash 25% of stake, reduce reputation
+//! - A quarantined agent can end its quarantine with `exit_quarantine_early`, paying
+//!   `EarlyExitPenalty` of its stake, at most `MaxEarlyExitsPerEra` times per era
+//! - Multiple offenses: exponential penalties. Each earlier offense of the same type within
+//!   `OffenseWindow` blocks doubles the slash percentage and reputation penalty, up to
+//!   `MaxPenaltyDoublings` doublings and a slash of at most `MaxSlashPercentage`
+//!
+//! ### Bans
+//! - An agent reaching `MaxOffenses` offenses is banned. Its first `MaxTemporaryBans` bans are
+//!   temporary and last `TemporaryBanEras` eras; the ban after that is permanent
+//! - Temporary bans are lifted at the first era start on or after the expiry block kept in
+//!   [`ReputationInfo`](pallet::ReputationInfo), which also clears the agent's offense count
+//! - Governance can lift any ban with `force_unban`
+//!
+//! ### Deferred Slashing
+//! - While `SlashDeferDuration` is non-zero, a reported offense is not applied right away but
+//!   queued in `PendingSlashes` with the block it executes at, and `SlashDeferred` is emitted
+//! - Until then governance can drop an erroneous slash with `cancel_deferred_slash`
+//! - Due slashes are applied at the start of a block in the order they were reported, at most
+//!   `MaxSlashesPerBlock` per block; the rest carry over to the next block
+//! - The full offense, with its escalation, quarantine and ban, is applied on execution. A slash
+//!   that can no longer be applied, e.g. because the agent was banned in the meantime, is dropped
+//!   with `DeferredSlashFailed`
+//! - Pending slashes move with the agent when it rotates its key
+//! - An agent cannot unstake while it has a deferred slash pending, so the slash still finds the
+//!   stake it was reported against
+//!
+//! ### Offense History
+//! - Each agent's recent offenses are kept in `OffenseHistory`, at most `MaxOffenseHistory`
+//!   entries. When it is full, the oldest entry is evicted to make room for the new offense
+//! - Evicted entries, and at every era start all entries older than `OffenseWindow`, are folded
+//!   into `OffenseArchive`: an [`OffenseDigest`](pallet::OffenseDigest) of counts per offense
+//!   type for the era the offenses were committed in
+//! - [`Pallet::offense_summary`] merges the archive and the recent history per era
+//!
+//! ### Reputation Root
+//! - At the start of every era, each agent's effective reputation is snapshotted and committed
+//!   to `ReputationRoot`, a binary Merkle root over [`csuite_primitives::reputation_leaf`]s
+//!   ordered by account
+//! - [`Pallet::reputation_proof`] proves a single agent's entry, so relay-chain or bridged
+//!   consumers that know the root can check a reputation without reading this pallet's state
+//!
+//! ### Cross-Chain Reputation
+//! - Governance registers sister parachains as trust anchors with `set_trust_anchor`, each with
+//!   the share of an imported score that is kept
+//! - An anchor sends its [`ReputationDigest`], the era and root of its own reputation snapshot,
+//!   over XCM with `import_reputation_digest`, then attests single agents with
+//!   `import_reputation_attestation` and a Merkle proof against that root
+//! - Imported scores are kept per anchor in `ImportedReputation` and do not change an agent's
+//!   local reputation; [`Pallet::imported_reputation`] sums them
+//! - `export_reputation_digest` sends this chain's current digest to another chain through the
+//!   runtime's [`ReputationExporter`]
+//!
+//! ### Participation Summaries
+//! - Every consensus log or insight an agent submits, every log it signs and every finalized
+//!   log it signed off is counted in a [`ParticipationSummary`] for the current era, kept in
+//!   `EraParticipation` for `ParticipationHistoryDepth` eras
+//! - [`Pallet::era_summary`] reads an agent's summary of a retained era, so compensation systems
+//!   run outside the chain can pay operators from activity they can prove with a storage proof
+//!
+//! ### Era Statistics
+//! - Logs submitted, finalized and retracted, the signers of finalized logs, the reputation
+//!   rewarded and the stake slashed are counted in `CurrentEraStats` as they happen
+//! - When an era ends its counters move to `EraStats`, one compact entry per era, so dashboards
+//!   can chart network activity through [`Pallet::era_stats`] without indexing every event
+//!
+//! ### Era Configuration
+//! - At the start of every era the consensus parameters it runs under are recorded in
+//!   `EraConfigs`: the consensus pallet's thresholds, read through the runtime's
+//!   [`csuite_primitives::ConsensusParameters`], the committee size and the slash and ban
+//!   policy of this pallet
+//! - The history is never pruned, so auditors can look up the rules that applied when a
+//!   historical log was finalized or an offense was slashed with [`Pallet::era_config_at`]
+//!
+//! ### Key Rotation
+//! - When an agent rotates to a new account, its reserved stake, unbonding stake, reputation,
+//!   offense history and archive move with it, so rotating neither frees stake nor wipes a record
+//! - The new account must not hold any reputation state, and an agent with more than
+//!   `MaxRotatedArchiveEras` archived eras cannot rotate; a failure reverts the whole rotation
+//!
+//! ### Retirement
+//! - A retiring agent cannot stake and its whole stake starts unbonding
+//! - Unbonding stake carries no weight but stays reserved and slashable until the agent's
+//!   retirement completes, when it is released
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -55,34 +181,363 @@ mod benchmarking;
 pub mod weights;
 
 use frame_support::{
-    traits::{Currency, ReservableCurrency, OnUnbalanced, Get},
+    traits::{
+        BalanceStatus, Contains, Currency, ExistenceRequirement, NamedReservableCurrency, OnUnbalanced, Get,
+        EnsureOrigin, ConstU32, OriginTrait,
+    },
     dispatch::DispatchResult,
+    pallet_prelude::TransactionSource,
+    weights::Weight,
+    BoundedVec, DefaultNoBound, PalletId,
 };
+use frame_system::RawOrigin;
 use sp_runtime::{
-    traits::{Zero, Saturating, CheckedMul},
-    Perbill, FixedPointNumber,
+    impl_tx_ext_default,
+    traits::{
+        Zero, One, Saturating, CheckedMul, IntegerSquareRoot, UniqueSaturatedInto, DispatchInfoOf,
+        TransactionExtension, ValidateResult, AtLeast32BitUnsigned, Convert, AccountIdConversion,
+    },
+    transaction_validity::{TransactionPriority, TransactionValidityError, ValidTransaction},
+    PerThing, Perbill, FixedPointNumber, RuntimeDebug,
 };
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use csuite_primitives::{AgentInspector, CallClass, ConsensusThresholds, FeeExemption};
+use scale_info::TypeInfo;
 use sp_std::vec::Vec;
 
+pub use csuite_primitives::MerkleInclusionProof;
+
 pub use weights::WeightInfo;
 
+/// Index of a reputation era
+pub type EraIndex = u32;
+
+/// Identifier of a deferred slash
+pub type SlashId = u64;
+
+/// Notified whenever an agent's reputation or stake changes
+///
+/// The weights of the calls and hooks that change reputation leave no room for this hook, so
+/// implementations should do no more than a constant amount of work, such as queueing the agent
+/// for later processing.
+pub trait OnReputationChange<AccountId> {
+    /// Called after the agent's reputation info has been written
+    fn on_reputation_change(who: &AccountId);
+}
+
+impl<AccountId> OnReputationChange<AccountId> for () {
+    fn on_reputation_change(_who: &AccountId) {}
+}
+
+/// Notified when agents are rewarded or slashed
+pub trait OnRewardOrSlash<AccountId> {
+    /// Called after an agent received a consensus reward
+    fn on_rewarded(who: &AccountId, reputation_reward: u64);
+
+    /// Called after an agent was slashed for an offense
+    fn on_slashed(who: &AccountId, reputation_penalty: u64);
+}
+
+impl<AccountId> OnRewardOrSlash<AccountId> for () {
+    fn on_rewarded(_who: &AccountId, _reputation_reward: u64) {}
+    fn on_slashed(_who: &AccountId, _reputation_penalty: u64) {}
+}
+
+/// Identifier of a sister parachain
+pub type ParaId = u32;
+
+/// Commitment to every agent's effective reputation at the start of an era
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ReputationDigest {
+    /// Era the snapshot was taken at
+    pub era: EraIndex,
+    /// Merkle root over the snapshot's [`csuite_primitives::reputation_leaf`]s
+    pub root: [u8; 32],
+}
+
+/// Sends reputation digests to other chains
+pub trait ReputationExporter {
+    /// Deliver `digest` to the chain `dest`
+    fn export_digest(dest: ParaId, digest: &ReputationDigest) -> DispatchResult;
+}
+
+/// No cross-chain messaging: exporting always fails
+impl ReputationExporter for () {
+    fn export_digest(_dest: ParaId, _digest: &ReputationDigest) -> DispatchResult {
+        Err(sp_runtime::DispatchError::Other("No reputation exporter configured"))
+    }
+}
+
+pub mod migrations;
+
+/// Named reserve holding agents' active and unbonding stake, apart from their other deposits
+pub const STAKE_RESERVE_ID: [u8; 8] = *b"repstake";
+
+/// How stake is turned into consensus weight
+///
+/// A concave curve keeps a single heavily staked agent from dominating: with `SquareRoot`,
+/// quadrupling an agent's stake only doubles its weight.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum StakeWeightingCurve {
+    /// Weight equals stake
+    Linear,
+    /// Weight is the square root of stake
+    SquareRoot,
+}
+
+impl StakeWeightingCurve {
+    /// Map a stake amount to its weight
+    pub fn apply(&self, stake: u128) -> u128 {
+        match self {
+            StakeWeightingCurve::Linear => stake,
+            StakeWeightingCurve::SquareRoot => stake.integer_sqrt(),
+        }
+    }
+}
+
+/// Voluntary lock on an agent's stake, multiplying the weight of the stake in its effective
+/// reputation
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum StakeLock {
+    /// Not locked, the stake counts once
+    #[default]
+    Unlocked,
+    /// Locked for one `StakeLockPeriod`, the stake counts twice
+    Short,
+    /// Locked for four `StakeLockPeriod`s, the stake counts four times
+    Long,
+}
+
+impl StakeLock {
+    /// Multiplier on the weight of a stake under this lock
+    pub fn multiplier(&self) -> u64 {
+        match self {
+            StakeLock::Unlocked => 1,
+            StakeLock::Short => 2,
+            StakeLock::Long => 4,
+        }
+    }
+
+    /// Number of blocks this lock lasts, given the `StakeLockPeriod`
+    pub fn duration<N: AtLeast32BitUnsigned>(&self, period: N) -> N {
+        match self {
+            StakeLock::Unlocked => Zero::zero(),
+            StakeLock::Short => period,
+            StakeLock::Long => period.saturating_mul(4u32.into()),
+        }
+    }
+}
+
+/// An agent's consensus participation towards its participation streak
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct StreakInfo {
+    /// Era `participations` were counted in
+    pub era: EraIndex,
+    /// Consensus participations in `era`
+    pub participations: u32,
+    /// Consecutive qualifying eras that ended right before `era`
+    pub streak: u32,
+}
+
+impl StreakInfo {
+    /// Move the tracking forward to `era`, closing the eras in between
+    ///
+    /// The streak grows if the tracked era qualified with `min_participations` and `era`
+    /// directly follows it, and is lost otherwise.
+    pub fn roll_to(&mut self, era: EraIndex, min_participations: u32) {
+        if era <= self.era {
+            return;
+        }
+        let qualified = self.participations >= min_participations;
+        self.streak = if qualified && era == self.era.saturating_add(1) {
+            self.streak.saturating_add(1)
+        } else {
+            0
+        };
+        self.era = era;
+        self.participations = 0;
+    }
+
+    /// Consecutive qualifying eras, counting the tracked era once it qualifies
+    pub fn current_streak(&self, min_participations: u32) -> u32 {
+        if self.participations >= min_participations {
+            self.streak.saturating_add(1)
+        } else {
+            self.streak
+        }
+    }
+}
+
+/// An agent's consensus activity in one era
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ParticipationSummary {
+    /// Consensus logs and insights the agent submitted
+    pub submissions: u32,
+    /// Consensus logs the agent signed, not counting its own submissions
+    pub signatures: u32,
+    /// Finalized consensus logs the agent was a signer of
+    pub finalizations: u32,
+}
+
+/// Network-wide consensus activity in one era
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct EraStatistics<Balance> {
+    /// Consensus logs and insights submitted
+    pub logs_submitted: u32,
+    /// Consensus logs finalized
+    pub logs_finalized: u32,
+    /// Consensus logs retracted by their submitter before being finalized
+    pub logs_rejected: u32,
+    /// Signers of all finalized logs together
+    pub finalized_signatures: u32,
+    /// Reputation awarded for consensus participation
+    pub reputation_rewarded: u64,
+    /// Stake slashed for offenses
+    pub slashed: Balance,
+}
+
+impl<Balance> EraStatistics<Balance> {
+    /// Average number of signers of a finalized log, rounded down
+    pub fn average_signers(&self) -> u32 {
+        self.finalized_signatures.checked_div(self.logs_finalized).unwrap_or(0)
+    }
+}
+
+/// Consensus parameters an era was run under, recorded when it started
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct EraConfig<Balance, BlockNumber> {
+    /// Block the era started at
+    pub started_at: BlockNumber,
+    /// Thresholds of the consensus pallet
+    pub consensus: ConsensusThresholds,
+    /// Number of agents selected for the era's committee
+    pub committee_size: u32,
+    /// Maximum number of agents on a committee
+    pub max_committee_size: u32,
+    /// Minimum stake required to participate
+    pub minimum_stake: Balance,
+    /// Base slash for unresponsiveness
+    pub unresponsiveness_slash: Perbill,
+    /// Base slash for equivocation
+    pub equivocation_slash: Perbill,
+    /// Cap on an escalated slash
+    pub max_slash_percentage: Perbill,
+    /// Cap on the number of times an offense's penalty doubles
+    pub max_penalty_doublings: u32,
+    /// Number of blocks earlier offenses escalate a new one for
+    pub offense_window: BlockNumber,
+    /// Number of blocks a quarantine lasts
+    pub quarantine_period: BlockNumber,
+    /// Number of blocks a reported offense waits before it is applied
+    pub slash_defer_duration: BlockNumber,
+    /// Offenses after which an agent is banned
+    pub max_offenses: u32,
+    /// Temporary bans before a ban becomes permanent
+    pub max_temporary_bans: u32,
+    /// Number of eras a temporary ban lasts
+    pub temporary_ban_eras: EraIndex,
+}
+
+/// Kind of consensus activity counted in a [`ParticipationSummary`]
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ParticipationKind {
+    /// The agent submitted a consensus log or insight
+    Submission,
+    /// The agent signed a consensus log
+    Signature,
+    /// A consensus log the agent signed was finalized
+    Finalization,
+}
+
+/// Maximum number of breakpoints of a [`PiecewiseRewardCurve`]
+pub const MAX_REWARD_CURVE_POINTS: u32 = 16;
+
+/// Quantity a [`PiecewiseRewardCurve`] is evaluated over
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum RewardCurveInput {
+    /// The agent's stake-weighted effective reputation
+    EffectiveReputation,
+    /// The number of consensus rounds the agent took part in
+    Participations,
+    /// The agent's current participation streak in eras
+    ParticipationStreak,
+}
+
+/// Piecewise-linear curve mapping an agent's [`RewardCurveInput`] to its base consensus reward
+///
+/// The reward is interpolated linearly between neighbouring breakpoints and held at the first
+/// or last breakpoint's reward outside of them.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PiecewiseRewardCurve {
+    /// What the curve is evaluated over
+    pub input: RewardCurveInput,
+    /// `(input, reward)` breakpoints in strictly increasing input order
+    pub points: BoundedVec<(u64, u64), ConstU32<MAX_REWARD_CURVE_POINTS>>,
+}
+
+impl PiecewiseRewardCurve {
+    /// Whether the curve has at least one breakpoint and its inputs strictly increase
+    pub fn is_valid(&self) -> bool {
+        !self.points.is_empty() && self.points.windows(2).all(|pair| pair[0].0 < pair[1].0)
+    }
+
+    /// The reward at `input`, or zero for a curve without breakpoints
+    pub fn evaluate(&self, input: u64) -> u64 {
+        let (Some(first), Some(last)) = (self.points.first(), self.points.last()) else {
+            return 0;
+        };
+        if input <= first.0 {
+            return first.1;
+        }
+        if input >= last.0 {
+            return last.1;
+        }
+
+        let upper = self.points.iter().position(|(x, _)| *x > input).unwrap_or(self.points.len() - 1);
+        let (x0, y0) = self.points[upper - 1];
+        let (x1, y1) = self.points[upper];
+        let offset = (input - x0) as u128;
+        let span = (x1 - x0) as u128;
+        if y1 >= y0 {
+            y0.saturating_add(((y1 - y0) as u128 * offset / span) as u64)
+        } else {
+            y0.saturating_sub(((y0 - y1) as u128 * offset / span) as u64)
+        }
+    }
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
     use frame_support::pallet_prelude::*;
     use frame_system::pallet_prelude::*;
-    use pallet_agent_registry::{self as agent_registry, AgentStatus};
+    use pallet_agent_registry as agent_registry;
+    use csuite_primitives::{AgentInspector, ConsensusParameters, MaintenanceSchedule};
 
     type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
     type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
 
+    /// The in-code storage version
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(6);
+
     #[pallet::config]
-    pub trait Config: frame_system::Config + agent_registry::Config {
+    pub trait Config: frame_system::Config {
         /// The overarching event type.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
+        /// Agent registry lookup for stakers and committee candidates
+        type Agents: AgentInspector<Self::AccountId>;
+
+        /// Approved maintenance windows of agents, during which their reputation does not decay
+        type Maintenance: MaintenanceSchedule<Self::AccountId, BlockNumberFor<Self>>;
+
+        /// Consensus thresholds recorded in every era's `EraConfigs` entry
+        type ConsensusParameters: ConsensusParameters;
+
         /// The currency used for staking
-        type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
+        ///
+        /// Stake is reserved under [`STAKE_RESERVE_ID`].
+        type Currency: Currency<Self::AccountId> + NamedReservableCurrency<Self::AccountId, ReserveIdentifier = [u8; 8]>;
 
         /// What to do with slashed funds
         type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
@@ -91,14 +546,26 @@ pub mod pallet {
         #[pallet::constant]
         type MinimumStake: Get<BalanceOf<Self>>;
 
+        /// Maximum stake a single agent may hold
+        #[pallet::constant]
+        type MaxStakePerAgent: Get<BalanceOf<Self>>;
+
+        /// Curve turning stake into consensus weight
+        #[pallet::constant]
+        type StakeCurve: Get<StakeWeightingCurve>;
+
         /// Base reputation decay rate per block (as Perbill)
         #[pallet::constant]
         type BaseDecayRate: Get<Perbill>;
 
-        /// Reputation reward for successful consensus participation
+        /// Flat reputation reward for consensus participation while no `RewardCurve` is set
         #[pallet::constant]
         type ConsensusReward: Get<u64>;
 
+        /// Extra reputation for the first co-signers of a finalized consensus log
+        #[pallet::constant]
+        type FirstResponderBonus: Get<u64>;
+
         /// Slash percentage for unresponsiveness (5%)
         #[pallet::constant]
         type UnresponsivenessSlash: Get<Perbill>;
@@ -111,15 +578,160 @@ pub mod pallet {
         #[pallet::constant]
         type QuarantinePeriod: Get<BlockNumberFor<Self>>;
 
-        /// Maximum number of offenses before permanent ban
+        /// Maximum number of offenses before a ban
         #[pallet::constant]
         type MaxOffenses: Get<u32>;
 
+        /// Number of temporary bans an agent can serve before its next ban is permanent
+        #[pallet::constant]
+        type MaxTemporaryBans: Get<u32>;
+
+        /// Number of eras a temporary ban lasts
+        #[pallet::constant]
+        type TemporaryBanEras: Get<EraIndex>;
+
+        /// Number of blocks a reported offense waits before it is applied (zero applies it
+        /// immediately)
+        #[pallet::constant]
+        type SlashDeferDuration: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of deferred slashes applied in one block
+        #[pallet::constant]
+        type MaxSlashesPerBlock: Get<u32>;
+
+        /// Maximum number of deferred slashes pending against one agent
+        #[pallet::constant]
+        type MaxPendingSlashesPerAgent: Get<u32>;
+
+        /// Number of blocks earlier offenses of the same type count towards escalation
+        #[pallet::constant]
+        type OffenseWindow: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of recent offenses kept per agent before the oldest are archived
+        ///
+        /// Lowering this below the length of a stored history needs a migration.
+        #[pallet::constant]
+        type MaxOffenseHistory: Get<u32>;
+
+        /// Maximum number of archived offense eras moved when an agent rotates its key
+        #[pallet::constant]
+        type MaxRotatedArchiveEras: Get<u32>;
+
+        /// Maximum number of times repeat offenses double the penalties
+        #[pallet::constant]
+        type MaxPenaltyDoublings: Get<u32>;
+
+        /// Upper bound on the escalated slash percentage of a single offense
+        #[pallet::constant]
+        type MaxSlashPercentage: Get<Perbill>;
+
+        /// Number of blocks in a reputation era
+        #[pallet::constant]
+        type EraLength: Get<BlockNumberFor<Self>>;
+
+        /// Minimum effective reputation for routine agent calls to be fee-exempt
+        #[pallet::constant]
+        type FeelessReputationThreshold: Get<u64>;
+
+        /// Maximum number of fee-exempt calls per agent per era
+        #[pallet::constant]
+        type FeelessCallsPerEra: Get<u32>;
+
+        /// Class of the fee-exempt calls whose quota [`ChargeFeelessQuota`] charges, `None` for
+        /// every other call
+        type FeelessCalls: Convert<<Self as frame_system::Config>::RuntimeCall, Option<CallClass>>;
+
+        /// Hook for pallets that derive state from agent reputation
+        type OnReputationChange: OnReputationChange<Self::AccountId>;
+
+        /// Hook for pallets tracking rewards and slashes
+        type OnRewardOrSlash: OnRewardOrSlash<Self::AccountId>;
+
+        /// Maximum number of agents on the committee selected each era
+        #[pallet::constant]
+        type MaxCommitteeSize: Get<u32>;
+
+        /// Maximum number of reputation entries scanned per block while selecting a committee
+        #[pallet::constant]
+        type MaxCandidatesPerBlock: Get<u32>;
+
+        /// Tokens paid to each committee member when its era ends
+        #[pallet::constant]
+        type EraReward: Get<BalanceOf<Self>>;
+
+        /// Pallet ID of the account era rewards are paid from
+        ///
+        /// Rewards are never minted; once the pot runs dry, members go unpaid until it is topped
+        /// up again.
+        #[pallet::constant]
+        type RewardPotId: Get<PalletId>;
+
+        /// Origin of reputation imports from sister chains, yielding the sending parachain
+        type AttestationOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = ParaId>;
+
+        /// Sends this chain's reputation digest to other chains
+        type Exporter: ReputationExporter;
+
+        /// Share of its stake a quarantined agent pays to end its quarantine early
+        #[pallet::constant]
+        type EarlyExitPenalty: Get<Perbill>;
+
+        /// Number of times an agent can exit quarantine early per era
+        #[pallet::constant]
+        type MaxEarlyExitsPerEra: Get<u32>;
+
+        /// Number of blocks a `StakeLock::Short` lock lasts; `StakeLock::Long` lasts four times
+        /// as long
+        #[pallet::constant]
+        type StakeLockPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Share of the unstaked amount paid to unstake before the stake's lock expires
+        #[pallet::constant]
+        type EarlyUnlockPenalty: Get<Perbill>;
+
+        /// Consensus participations an agent needs in an era for it to extend its streak
+        #[pallet::constant]
+        type MinStreakParticipations: Get<u32>;
+
+        /// Extra consensus reward per era of an agent's participation streak
+        #[pallet::constant]
+        type StreakBonusPerEra: Get<Perbill>;
+
+        /// Upper bound on the participation streak bonus
+        #[pallet::constant]
+        type MaxStreakBonus: Get<Perbill>;
+
+        /// Number of past eras participation summaries are kept for, besides the current one
+        #[pallet::constant]
+        type ParticipationHistoryDepth: Get<u32>;
+
+        /// Calls [`PrioritizeCommittee`] raises the priority of
+        type PriorityCalls: Contains<<Self as frame_system::Config>::RuntimeCall>;
+
+        /// Whether the chain is congested, so [`PrioritizeCommittee`] is in effect
+        type IsCongested: Get<bool>;
+
+        /// Minimum effective reputation a committee member needs for raised priority
+        #[pallet::constant]
+        type PriorityReputationThreshold: Get<u64>;
+
+        /// Priority [`PrioritizeCommittee`] adds to a prioritized call
+        #[pallet::constant]
+        type CommitteePriorityBoost: Get<TransactionPriority>;
+
+        /// Origin allowed to grant consensus rewards
+        type RewardOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Maximum number of agents rewarded by a single `reward_consensus_many`
+        #[pallet::constant]
+        type MaxRewardBatch: Get<u32>;
+
         /// Weight information for extrinsics
         type WeightInfo: WeightInfo;
     }
 
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// Types of offenses that can be committed
@@ -133,6 +745,58 @@ pub mod pallet {
         InvalidData,
         /// Agent attempted to manipulate consensus
         ConsensusManipulation,
+        /// Agent withheld data it committed to publish
+        DataWithholding,
+        /// Agent responded, but outside the allowed latency
+        LatencyViolation,
+        /// Agent broke a policy of its organization or the network
+        PolicyViolation,
+    }
+
+    /// Number of offenses of each type an agent committed in one era
+    #[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct OffenseDigest {
+        /// Unresponsiveness offenses
+        pub unresponsiveness: u32,
+        /// Equivocation offenses
+        pub equivocation: u32,
+        /// Invalid data offenses
+        pub invalid_data: u32,
+        /// Consensus manipulation offenses
+        pub consensus_manipulation: u32,
+        /// Data withholding offenses
+        pub data_withholding: u32,
+        /// Latency violation offenses
+        pub latency_violation: u32,
+        /// Policy violation offenses
+        pub policy_violation: u32,
+    }
+
+    impl OffenseDigest {
+        /// Count one more offense of the given type
+        pub fn note(&mut self, offense_type: &OffenseType) {
+            let count = match offense_type {
+                OffenseType::Unresponsiveness => &mut self.unresponsiveness,
+                OffenseType::Equivocation => &mut self.equivocation,
+                OffenseType::InvalidData => &mut self.invalid_data,
+                OffenseType::ConsensusManipulation => &mut self.consensus_manipulation,
+                OffenseType::DataWithholding => &mut self.data_withholding,
+                OffenseType::LatencyViolation => &mut self.latency_violation,
+                OffenseType::PolicyViolation => &mut self.policy_violation,
+            };
+            *count = count.saturating_add(1);
+        }
+
+        /// Total number of offenses
+        pub fn total(&self) -> u32 {
+            self.unresponsiveness
+                .saturating_add(self.equivocation)
+                .saturating_add(self.invalid_data)
+                .saturating_add(self.consensus_manipulation)
+                .saturating_add(self.data_withholding)
+                .saturating_add(self.latency_violation)
+                .saturating_add(self.policy_violation)
+        }
     }
 
     /// Agent's reputation and stake information
@@ -151,8 +815,16 @@ pub mod pallet {
         pub offense_count: u32,
         /// Block when quarantine ends (if quarantined)
         pub quarantine_until: Option<BlockNumberFor<T>>,
-        /// Whether agent is permanently banned
+        /// Whether agent is banned
         pub is_banned: bool,
+        /// Block a temporary ban expires at; `None` while banned means the ban is permanent
+        pub ban_until: Option<BlockNumberFor<T>>,
+        /// Number of temporary bans the agent has been given
+        pub temporary_bans: u32,
+        /// Voluntary lock on the stake
+        pub stake_lock: StakeLock,
+        /// Block the stake lock expires at
+        pub locked_until: BlockNumberFor<T>,
     }
 
     impl<T: Config> Default for ReputationInfo<T> {
@@ -165,10 +837,43 @@ pub mod pallet {
                 offense_count: 0,
                 quarantine_until: None,
                 is_banned: false,
+                ban_until: None,
+                temporary_bans: 0,
+                stake_lock: StakeLock::Unlocked,
+                locked_until: Zero::zero(),
             }
         }
     }
 
+    /// An offense waiting out `SlashDeferDuration` before it is applied
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct PendingSlash<T: Config> {
+        /// The offending agent
+        pub agent_id: T::AccountId,
+        /// The offense it was reported for
+        pub offense_type: OffenseType,
+        /// Block the offense was reported at
+        pub reported_at: BlockNumberFor<T>,
+        /// Block the slash is applied at, unless cancelled
+        pub execute_at: BlockNumberFor<T>,
+    }
+
+    /// Progress of an era's committee selection over the reputation entries
+    ///
+    /// Entries are visited in storage order, at most `MaxCandidatesPerBlock` per block.
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct CommitteeScan<T: Config> {
+        /// Era the committee is selected for
+        pub era: EraIndex,
+        /// Last agent scanned, the scan resumes after it
+        pub last_scanned: Option<T::AccountId>,
+        /// Best candidates so far in committee order, with whether each is overloaded and its
+        /// effective reputation
+        pub leaders: BoundedVec<(bool, u64, T::AccountId), T::MaxCommitteeSize>,
+    }
+
     /// Storage for agent reputation and stake information
     #[pallet::storage]
     #[pallet::getter(fn reputation)]
@@ -185,14 +890,252 @@ pub mod pallet {
     #[pallet::getter(fn total_stake)]
     pub type TotalStake<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
-    /// Offense history for agents
+    /// Sum of the stake weights of all agents under `StakeCurve`
+    #[pallet::storage]
+    #[pallet::getter(fn total_stake_weight)]
+    pub type TotalStakeWeight<T: Config> = StorageValue<_, u128, ValueQuery>;
+
+    /// Recent offenses of each agent, oldest first
     #[pallet::storage]
     #[pallet::getter(fn offense_history)]
     pub type OffenseHistory<T: Config> = StorageMap<
         _,
         Blake2_128Concat,
         T::AccountId,
-        BoundedVec<(OffenseType, BlockNumberFor<T>), ConstU32<100>>,
+        BoundedVec<(OffenseType, BlockNumberFor<T>), T::MaxOffenseHistory>,
+        ValueQuery,
+    >;
+
+    /// Digest of the archived offenses of each agent, by the era they were committed in
+    #[pallet::storage]
+    #[pallet::getter(fn offense_archive)]
+    pub type OffenseArchive<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Twox64Concat,
+        EraIndex,
+        OffenseDigest,
+        ValueQuery,
+    >;
+
+    /// Current reputation era
+    #[pallet::storage]
+    #[pallet::getter(fn current_era)]
+    pub type CurrentEra<T: Config> = StorageValue<_, EraIndex, ValueQuery>;
+
+    /// Fee-exempt calls used by each agent, tagged with the era they were counted in
+    #[pallet::storage]
+    #[pallet::getter(fn feeless_usage)]
+    pub type FeelessUsage<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        (EraIndex, u32),
+        ValueQuery,
+    >;
+
+    /// Early quarantine exits used by each agent, tagged with the era they were counted in
+    #[pallet::storage]
+    #[pallet::getter(fn early_exits)]
+    pub type EarlyExits<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        (EraIndex, u32),
+        ValueQuery,
+    >;
+
+    /// Consensus participation of each agent towards its participation streak
+    #[pallet::storage]
+    #[pallet::getter(fn participation_streaks)]
+    pub type ParticipationStreaks<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        StreakInfo,
+        ValueQuery,
+    >;
+
+    /// Network-wide consensus activity of the current era so far
+    #[pallet::storage]
+    #[pallet::getter(fn current_era_stats)]
+    pub type CurrentEraStats<T: Config> = StorageValue<_, EraStatistics<BalanceOf<T>>, ValueQuery>;
+
+    /// Network-wide consensus activity of every ended era, written when the era ends
+    #[pallet::storage]
+    #[pallet::getter(fn closed_era_stats)]
+    pub type EraStats<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        EraIndex,
+        EraStatistics<BalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    /// Consensus parameters every era started under, written when the era starts
+    #[pallet::storage]
+    #[pallet::getter(fn era_config)]
+    pub type EraConfigs<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        EraIndex,
+        EraConfig<BalanceOf<T>, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Consensus activity of each agent in each retained era
+    #[pallet::storage]
+    #[pallet::getter(fn era_participation)]
+    pub type EraParticipation<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        EraIndex,
+        Blake2_128Concat,
+        T::AccountId,
+        ParticipationSummary,
+        ValueQuery,
+    >;
+
+    /// Governance-set curve for the base consensus reward, replacing `ConsensusReward` while set
+    #[pallet::storage]
+    #[pallet::getter(fn reward_curve)]
+    pub type RewardCurve<T: Config> = StorageValue<_, PiecewiseRewardCurve, OptionQuery>;
+
+    /// Agents serving on the committee for the current era, highest reputation first
+    #[pallet::storage]
+    #[pallet::getter(fn committee)]
+    pub type Committee<T: Config> = StorageValue<
+        _,
+        BoundedVec<T::AccountId, T::MaxCommitteeSize>,
+        ValueQuery,
+    >;
+
+    /// Committee selection in progress, scanned over as many blocks as it takes
+    #[pallet::storage]
+    #[pallet::getter(fn committee_election)]
+    pub type CommitteeElection<T: Config> = StorageValue<_, CommitteeScan<T>, OptionQuery>;
+
+    /// Agents that have opted in to restaking their era rewards
+    #[pallet::storage]
+    #[pallet::getter(fn compound_rewards)]
+    pub type CompoundRewards<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        bool,
+        ValueQuery,
+    >;
+
+    /// Merkle root over the effective reputations snapshotted at the start of the current era
+    #[pallet::storage]
+    #[pallet::getter(fn reputation_root)]
+    pub type ReputationRoot<T: Config> = StorageValue<_, [u8; 32], OptionQuery>;
+
+    /// Effective reputation of each agent as committed to by `ReputationRoot`
+    #[pallet::storage]
+    #[pallet::getter(fn reputation_snapshot)]
+    pub type ReputationSnapshot<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        u64,
+        OptionQuery,
+    >;
+
+    /// A sister chain whose reputation attestations are accepted
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct TrustAnchor {
+        /// Share of an imported score that is kept
+        pub weight: Perbill,
+        /// Latest reputation digest the chain sent, which attestations are proven against
+        pub digest: Option<ReputationDigest>,
+    }
+
+    /// Reputation imported for an agent from one trust anchor
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct ImportedScore {
+        /// Attested score after applying the anchor's weight
+        pub score: u64,
+        /// Era of the sister chain the score was attested for
+        pub era: EraIndex,
+    }
+
+    /// Sister chains whose reputation attestations are accepted
+    #[pallet::storage]
+    #[pallet::getter(fn trust_anchors)]
+    pub type TrustAnchors<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        ParaId,
+        TrustAnchor,
+        OptionQuery,
+    >;
+
+    /// Reputation attested for agents by trust anchors
+    #[pallet::storage]
+    #[pallet::getter(fn imported_score)]
+    pub type ImportedReputation<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Twox64Concat,
+        ParaId,
+        ImportedScore,
+        OptionQuery,
+    >;
+
+    /// Agents serving a temporary ban, checked for expiry at every era start
+    #[pallet::storage]
+    #[pallet::getter(fn temporarily_banned)]
+    pub type TemporarilyBanned<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        (),
+        OptionQuery,
+    >;
+
+    /// Deferred slashes that are neither applied nor cancelled yet
+    #[pallet::storage]
+    #[pallet::getter(fn pending_slashes)]
+    pub type PendingSlashes<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        SlashId,
+        PendingSlash<T>,
+        OptionQuery,
+    >;
+
+    /// Identifier of the next deferred slash
+    #[pallet::storage]
+    #[pallet::getter(fn next_slash_id)]
+    pub type NextSlashId<T: Config> = StorageValue<_, SlashId, ValueQuery>;
+
+    /// Oldest deferred slash that may not have been applied or cancelled yet
+    #[pallet::storage]
+    #[pallet::getter(fn slash_queue_head)]
+    pub type SlashQueueHead<T: Config> = StorageValue<_, SlashId, ValueQuery>;
+
+    /// Deferred slashes pending against each agent
+    #[pallet::storage]
+    #[pallet::getter(fn agent_pending_slashes)]
+    pub type AgentPendingSlashes<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<SlashId, T::MaxPendingSlashesPerAgent>,
+        ValueQuery,
+    >;
+
+    /// Stake of retiring agents that is no longer active but still reserved and slashable
+    #[pallet::storage]
+    #[pallet::getter(fn unbonding)]
+    pub type Unbonding<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BalanceOf<T>,
         ValueQuery,
     >;
 
@@ -222,20 +1165,162 @@ pub mod pallet {
             offense_type: OffenseType,
             slash_amount: BalanceOf<T>,
             reputation_penalty: u64,
+            multiplier: u32,
         },
         /// Agent was quarantined
         AgentQuarantined {
             agent_id: T::AccountId,
             until_block: BlockNumberFor<T>,
         },
-        /// Agent was permanently banned
+        /// Agent paid a stake penalty to end its quarantine early
+        QuarantineExitedEarly {
+            agent_id: T::AccountId,
+            penalty: BalanceOf<T>,
+            was_until: BlockNumberFor<T>,
+            exits_this_era: u32,
+        },
+        /// Agent locked its stake until the given block
+        StakeLocked {
+            agent_id: T::AccountId,
+            lock: StakeLock,
+            until: BlockNumberFor<T>,
+        },
+        /// Agent unstaked before its lock expired and paid a penalty
+        StakeUnlockedEarly {
+            agent_id: T::AccountId,
+            penalty: BalanceOf<T>,
+            was_until: BlockNumberFor<T>,
+        },
+        /// Agent's recorded stake exceeded its reserved balance and was lowered to match
+        StakeAccountingRepaired {
+            agent_id: T::AccountId,
+            recorded: BalanceOf<T>,
+            actual: BalanceOf<T>,
+        },
+        /// Agent lost its participation streak to an offense
+        StreakReset {
+            agent_id: T::AccountId,
+            streak: u32,
+        },
+        /// The consensus reward curve was set, or removed with `None`
+        RewardCurveSet {
+            curve: Option<PiecewiseRewardCurve>,
+        },
+        /// Agent was banned until the given block, or permanently if `until` is `None`
         AgentBanned {
             agent_id: T::AccountId,
+            until: Option<BlockNumberFor<T>>,
         },
-        /// Consensus reward distributed
-        ConsensusRewardDistributed {
+        /// Agent's ban was lifted, by governance if `forced`
+        AgentUnbanned {
             agent_id: T::AccountId,
-            reputation_reward: u64,
+            forced: bool,
+        },
+        /// An offense was reported and its slash deferred until `execute_at`
+        SlashDeferred {
+            slash_id: SlashId,
+            agent_id: T::AccountId,
+            offense_type: OffenseType,
+            execute_at: BlockNumberFor<T>,
+        },
+        /// Governance cancelled a deferred slash
+        DeferredSlashCancelled {
+            slash_id: SlashId,
+            agent_id: T::AccountId,
+        },
+        /// A due deferred slash could not be applied and was dropped
+        DeferredSlashFailed {
+            slash_id: SlashId,
+            agent_id: T::AccountId,
+            error: DispatchError,
+        },
+        /// Consensus reward distributed
+        ConsensusRewardDistributed {
+            agent_id: T::AccountId,
+            reputation_reward: u64,
+        },
+        /// An agent earned the first responder bonus
+        FirstResponderRewarded {
+            agent_id: T::AccountId,
+            reputation_reward: u64,
+        },
+        /// A new reputation era started
+        NewEra {
+            era: EraIndex,
+        },
+        /// The committee for an era was selected
+        CommitteeSelected {
+            era: EraIndex,
+            members: Vec<T::AccountId>,
+        },
+        /// The effective reputations of all agents were committed to a new root
+        ReputationRootUpdated {
+            era: EraIndex,
+            root: [u8; 32],
+            agents: u32,
+        },
+        /// Agent changed whether its era rewards are restaked
+        CompoundRewardsSet {
+            agent_id: T::AccountId,
+            enabled: bool,
+        },
+        /// Era reward paid to a committee member, of which `compounded` was added to its stake
+        EraRewardPaid {
+            agent_id: T::AccountId,
+            amount: BalanceOf<T>,
+            compounded: BalanceOf<T>,
+        },
+        /// Era reward of a committee member not paid because the reward pot could not cover it
+        EraRewardUnpaid {
+            agent_id: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// A retiring agent's stake started unbonding
+        UnbondingStarted {
+            agent_id: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// An agent's stake and reputation moved to its new account
+        StakeRotated {
+            old_agent_id: T::AccountId,
+            new_agent_id: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// A sister chain was registered as a trust anchor or had its weight changed
+        TrustAnchorSet {
+            para_id: ParaId,
+            weight: Perbill,
+        },
+        /// A sister chain is no longer a trust anchor
+        TrustAnchorRemoved {
+            para_id: ParaId,
+        },
+        /// A trust anchor sent a new reputation digest
+        ReputationDigestImported {
+            para_id: ParaId,
+            digest: ReputationDigest,
+        },
+        /// A trust anchor attested an agent's reputation
+        ReputationImported {
+            para_id: ParaId,
+            agent_id: T::AccountId,
+            score: u64,
+        },
+        /// This chain's reputation digest was sent to another chain
+        ReputationDigestExported {
+            dest: ParaId,
+            digest: ReputationDigest,
+        },
+        /// Part of an agent's stake was moved to an escrow account
+        StakeEscrowed {
+            agent_id: T::AccountId,
+            escrow: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Funds released from an escrow were staked for the agent again
+        StakeRestored {
+            agent_id: T::AccountId,
+            amount: BalanceOf<T>,
         },
     }
 
@@ -248,25 +1333,129 @@ pub mod pallet {
         InsufficientStake,
         /// Agent is quarantined
         AgentQuarantined,
-        /// Agent is permanently banned
+        /// Agent is banned
         AgentBanned,
+        /// Agent is not banned
+        NotBanned,
+        /// No pending deferred slash with this identifier
+        SlashNotFound,
+        /// The agent already has `MaxPendingSlashesPerAgent` deferred slashes pending
+        TooManyPendingSlashes,
         /// Insufficient balance to stake
         InsufficientBalance,
         /// Agent has no stake to unstake
         NoStakeToUnstake,
+        /// Agent cannot unstake while a deferred slash is pending against it
+        SlashPending,
         /// Cannot slash - insufficient stake
         InsufficientStakeToSlash,
         /// Arithmetic overflow
         ArithmeticOverflow,
+        /// Stake would exceed the per-agent maximum
+        StakeAboveMaximum,
+        /// Agent is retiring and cannot add stake
+        AgentRetiring,
+        /// The account an agent rotates to already has reputation state
+        RotationTargetInUse,
+        /// The agent's reserved stake could not be moved in full
+        StakeMigrationFailed,
+        /// The agent has more archived offense eras than a rotation can move
+        TooManyArchivedEras,
+        /// The sending chain is not a trust anchor
+        UnknownTrustAnchor,
+        /// The digest is not newer than the one already imported
+        StaleDigest,
+        /// The trust anchor has not sent a reputation digest yet
+        NoDigest,
+        /// The proof does not commit to this agent and score under the anchor's digest
+        InvalidReputationProof,
+        /// No reputation root has been committed yet
+        NoReputationRoot,
+        /// The digest could not be sent
+        ExportFailed,
+        /// Agent is not quarantined
+        NotQuarantined,
+        /// Agent used up its early quarantine exits for this era
+        TooManyEarlyExits,
+        /// Reward curve has no breakpoints or its inputs do not strictly increase
+        InvalidRewardCurve,
+        /// Stake cannot be locked as `Unlocked`
+        InvalidStakeLock,
+        /// Agent has no stake to lock
+        NoStakeToLock,
+        /// The lock would end earlier or weigh less than the agent's current lock
+        StakeLockShortened,
+        /// Agent's reserved balance already covers its recorded stake
+        StakeAccountingConsistent,
     }
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Apply due deferred slashes and roll over to a new era every `EraLength` blocks
+        fn on_initialize(block: BlockNumberFor<T>) -> Weight {
+            let slash_weight = Self::execute_deferred_slashes(block);
+
+            let era_length = T::EraLength::get();
+            if era_length.is_zero() || block.is_zero() || !(block % era_length).is_zero() {
+                return slash_weight.saturating_add(Self::scan_committee_candidates());
+            }
+
+            // Pay the committee of the era that just ended
+            let reward_weight = Self::pay_era_rewards();
+            <EraStats<T>>::insert(Self::current_era(), <CurrentEraStats<T>>::take());
+
+            let era = Self::current_era().saturating_add(1);
+            <CurrentEra<T>>::put(era);
+            Self::deposit_event(Event::NewEra { era });
+
+            // Lift expired bans before the new committee is chosen
+            let unban_weight = Self::lift_expired_bans(block);
+
+            // The committee of the ended era serves on until the scan for the new one is done,
+            // which replaces any scan still running
+            let config_weight = Self::snapshot_era_config(era, block, Self::committee().len() as u32);
+            <CommitteeElection<T>>::put(CommitteeScan {
+                era,
+                last_scanned: None,
+                leaders: BoundedVec::new(),
+            });
+            let election_weight = Self::scan_committee_candidates();
+
+            let root_weight = Self::commit_reputation_root(era);
+            let archive_weight = Self::archive_offenses(block);
+            let summary_weight = Self::prune_participation(era);
+
+            T::DbWeight::get().reads_writes(3, 3)
+                .saturating_add(election_weight)
+                .saturating_add(reward_weight)
+                .saturating_add(unban_weight)
+                .saturating_add(root_weight)
+                .saturating_add(archive_weight)
+                .saturating_add(summary_weight)
+                .saturating_add(config_weight)
+                .saturating_add(slash_weight)
+        }
+
         /// Apply reputation decay every block
         fn on_finalize(_block: BlockNumberFor<T>) {
             // Decay reputation for all agents
             let _ = Self::apply_global_reputation_decay();
         }
+
+        /// Check that an agent's reputation entry fits its storage budget
+        fn integrity_test() {
+            assert!(
+                ReputationInfo::<T>::max_encoded_len() <= csuite_primitives::MAX_REPUTATION_INFO_SIZE,
+                "ReputationInfo can encode to {} bytes, above the budget of {} bytes",
+                ReputationInfo::<T>::max_encoded_len(),
+                csuite_primitives::MAX_REPUTATION_INFO_SIZE,
+            );
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            Self::do_try_state()
+        }
     }
 
     #[pallet::call]
@@ -285,10 +1474,15 @@ pub mod pallet {
 
             // Check agent is registered and not banned
             ensure!(
-                agent_registry::Pallet::<T>::agents(&who).is_some(),
+                T::Agents::is_registered(&who),
                 Error::<T>::AgentNotFound
             );
 
+            ensure!(
+                !T::Agents::is_retiring(&who),
+                Error::<T>::AgentRetiring
+            );
+
             let mut reputation_info = Self::reputation(&who);
             ensure!(!reputation_info.is_banned, Error::<T>::AgentBanned);
 
@@ -301,12 +1495,16 @@ pub mod pallet {
                 reputation_info.quarantine_until = None;
             }
 
+            let new_stake = reputation_info.stake.saturating_add(amount);
+            ensure!(new_stake <= T::MaxStakePerAgent::get(), Error::<T>::StakeAboveMaximum);
+
             // Reserve the stake
-            T::Currency::reserve(&who, amount)
+            T::Currency::reserve_named(&STAKE_RESERVE_ID, &who, amount)
                 .map_err(|_| Error::<T>::InsufficientBalance)?;
 
             // Update reputation info
-            reputation_info.stake = reputation_info.stake.saturating_add(amount);
+            Self::note_stake_change(reputation_info.stake, new_stake);
+            reputation_info.stake = new_stake;
             reputation_info.last_update = <frame_system::Pallet<T>>::block_number();
 
             // Update total stake
@@ -315,6 +1513,7 @@ pub mod pallet {
 
             // Store updated reputation info
             <Reputation<T>>::insert(&who, reputation_info);
+            T::OnReputationChange::on_reputation_change(&who);
 
             Self::deposit_event(Event::Staked {
                 agent_id: who,
@@ -325,6 +1524,8 @@ pub mod pallet {
         }
 
         /// Unstake tokens
+        ///
+        /// Fails with `SlashPending` while a deferred slash of the agent waits to be applied.
         #[pallet::call_index(1)]
         #[pallet::weight(T::WeightInfo::unstake())]
         pub fn unstake(
@@ -335,13 +1536,30 @@ pub mod pallet {
 
             let mut reputation_info = Self::reputation(&who);
             ensure!(reputation_info.stake >= amount, Error::<T>::NoStakeToUnstake);
+            // A deferred slash must find the stake it was reported against
+            ensure!(<AgentPendingSlashes<T>>::get(&who).is_empty(), Error::<T>::SlashPending);
+
+            // Unstaking from a lock before it expires costs a share of the amount
+            let locked = Self::active_lock(&reputation_info) != StakeLock::Unlocked;
+            let penalty = if locked { T::EarlyUnlockPenalty::get().mul_floor(amount) } else { Zero::zero() };
+            if !penalty.is_zero() {
+                let slashed = T::Currency::slash_reserved_named(&STAKE_RESERVE_ID, &who, penalty);
+                T::Slash::on_unbalanced(slashed.0);
+            }
 
             // Unreserve the stake
-            T::Currency::unreserve(&who, amount);
+            T::Currency::unreserve_named(&STAKE_RESERVE_ID, &who, amount.saturating_sub(penalty));
 
             // Update reputation info
-            reputation_info.stake = reputation_info.stake.saturating_sub(amount);
+            let new_stake = reputation_info.stake.saturating_sub(amount);
+            Self::note_stake_change(reputation_info.stake, new_stake);
+            reputation_info.stake = new_stake;
             reputation_info.last_update = <frame_system::Pallet<T>>::block_number();
+            let was_until = reputation_info.locked_until;
+            if new_stake.is_zero() {
+                reputation_info.stake_lock = StakeLock::Unlocked;
+                reputation_info.locked_until = Zero::zero();
+            }
 
             // Update total stake
             let new_total = Self::total_stake().saturating_sub(amount);
@@ -349,7 +1567,15 @@ pub mod pallet {
 
             // Store updated reputation info
             <Reputation<T>>::insert(&who, reputation_info);
+            T::OnReputationChange::on_reputation_change(&who);
 
+            if locked {
+                Self::deposit_event(Event::StakeUnlockedEarly {
+                    agent_id: who.clone(),
+                    penalty,
+                    was_until,
+                });
+            }
             Self::deposit_event(Event::Unstaked {
                 agent_id: who,
                 amount,
@@ -364,206 +1590,1759 @@ pub mod pallet {
         pub fn reward_consensus(
             origin: OriginFor<T>,
             agent_id: T::AccountId,
+        ) -> DispatchResult {
+            T::RewardOrigin::ensure_origin(origin)?;
+            Self::do_reward_consensus(&agent_id)
+        }
+
+        /// Report an offense and apply slashing, or defer it by `SlashDeferDuration`
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::report_offense())]
+        pub fn report_offense(
+            origin: OriginFor<T>,
+            agent_id: T::AccountId,
+            offense_type: OffenseType,
         ) -> DispatchResult {
             ensure_root(origin)?;
+            Self::do_report_offense(&agent_id, offense_type)
+        }
 
-            let mut reputation_info = Self::reputation(&agent_id);
-            
-            // Apply decay before adding reward
-            Self::apply_reputation_decay(&agent_id, &mut reputation_info)?;
-
-            // Calculate stake-weighted reward
-            let base_reward = T::ConsensusReward::get();
-            let stake_multiplier = if !Self::total_stake().is_zero() {
-                // Stake weight as percentage of total stake (max 2x multiplier)
-                let stake_percentage = Perbill::from_rational(reputation_info.stake, Self::total_stake());
-                1u64.saturating_add(stake_percentage.mul_floor(100u64))
-            } else {
-                1u64
-            };
+        /// Opt in to or out of restaking era rewards
+        ///
+        /// While enabled, era rewards are reserved as additional stake instead of staying free.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::set_compound_rewards())]
+        pub fn set_compound_rewards(
+            origin: OriginFor<T>,
+            enabled: bool,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
 
-            let weighted_reward = base_reward.saturating_mul(stake_multiplier);
-            let old_reputation = reputation_info.reputation;
-            
-            reputation_info.reputation = reputation_info.reputation.saturating_add(weighted_reward);
-            reputation_info.consensus_count = reputation_info.consensus_count.saturating_add(1);
-            reputation_info.last_update = <frame_system::Pallet<T>>::block_number();
+            ensure!(
+                T::Agents::is_registered(&who),
+                Error::<T>::AgentNotFound
+            );
 
-            <Reputation<T>>::insert(&agent_id, reputation_info.clone());
+            if enabled {
+                <CompoundRewards<T>>::insert(&who, true);
+            } else {
+                <CompoundRewards<T>>::remove(&who);
+            }
 
-            Self::deposit_event(Event::ReputationUpdated {
-                agent_id: agent_id.clone(),
-                old_reputation,
-                new_reputation: reputation_info.reputation,
+            Self::deposit_event(Event::CompoundRewardsSet {
+                agent_id: who,
+                enabled,
             });
 
-            Self::deposit_event(Event::ConsensusRewardDistributed {
-                agent_id,
-                reputation_reward: weighted_reward,
-            });
+            Ok(())
+        }
+
+        /// Register a sister chain as a trust anchor, or remove it with `None`
+        ///
+        /// Removing an anchor keeps the scores it already attested.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::set_trust_anchor())]
+        pub fn set_trust_anchor(
+            origin: OriginFor<T>,
+            para_id: ParaId,
+            weight: Option<Perbill>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            match weight {
+                Some(weight) => {
+                    <TrustAnchors<T>>::mutate(para_id, |anchor| match anchor {
+                        Some(anchor) => anchor.weight = weight,
+                        None => *anchor = Some(TrustAnchor { weight, digest: None }),
+                    });
+                    Self::deposit_event(Event::TrustAnchorSet { para_id, weight });
+                },
+                None => {
+                    ensure!(<TrustAnchors<T>>::take(para_id).is_some(), Error::<T>::UnknownTrustAnchor);
+                    Self::deposit_event(Event::TrustAnchorRemoved { para_id });
+                },
+            }
 
             Ok(())
         }
 
-        /// Report an offense and apply slashing
-        #[pallet::call_index(3)]
-        #[pallet::weight(T::WeightInfo::report_offense())]
-        pub fn report_offense(
+        /// Accept a trust anchor's latest reputation digest
+        ///
+        /// Dispatched by the sister chain itself over XCM.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::import_reputation_digest())]
+        pub fn import_reputation_digest(
+            origin: OriginFor<T>,
+            digest: ReputationDigest,
+        ) -> DispatchResult {
+            let para_id = T::AttestationOrigin::ensure_origin(origin)?;
+
+            <TrustAnchors<T>>::try_mutate(para_id, |anchor| -> DispatchResult {
+                let anchor = anchor.as_mut().ok_or(Error::<T>::UnknownTrustAnchor)?;
+                ensure!(
+                    anchor.digest.map_or(true, |current| digest.era > current.era),
+                    Error::<T>::StaleDigest
+                );
+                anchor.digest = Some(digest);
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::ReputationDigestImported { para_id, digest });
+
+            Ok(())
+        }
+
+        /// Import an agent's reputation as attested by a trust anchor
+        ///
+        /// Dispatched by the sister chain itself over XCM. `proof` must show that the anchor's
+        /// latest digest commits to `score` for `agent_id`; the anchor's weight is applied before
+        /// the score is stored.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::import_reputation_attestation())]
+        pub fn import_reputation_attestation(
             origin: OriginFor<T>,
             agent_id: T::AccountId,
-            offense_type: OffenseType,
+            score: u64,
+            proof: MerkleInclusionProof,
         ) -> DispatchResult {
-            ensure_root(origin)?;
+            let para_id = T::AttestationOrigin::ensure_origin(origin)?;
 
-            let mut reputation_info = Self::reputation(&agent_id);
-            ensure!(!reputation_info.is_banned, Error::<T>::AgentBanned);
+            let anchor = <TrustAnchors<T>>::get(para_id).ok_or(Error::<T>::UnknownTrustAnchor)?;
+            let digest = anchor.digest.ok_or(Error::<T>::NoDigest)?;
+            ensure!(
+                proof.verify(&digest.root)
+                    && proof.leaf == csuite_primitives::reputation_leaf(&agent_id, score),
+                Error::<T>::InvalidReputationProof
+            );
 
-            // Apply decay before processing offense
-            Self::apply_reputation_decay(&agent_id, &mut reputation_info)?;
+            let weighted = anchor.weight.mul_floor(score);
+            <ImportedReputation<T>>::insert(&agent_id, para_id, ImportedScore {
+                score: weighted,
+                era: digest.era,
+            });
 
-            // Determine slash amount and reputation penalty
-            let (slash_percentage, reputation_penalty, should_quarantine) = match offense_type {
-                OffenseType::Unresponsiveness => (T::UnresponsivenessSlash::get(), 50u64, false),
-                OffenseType::Equivocation => (T::EquivocationSlash::get(), 200u64, true),
-                OffenseType::InvalidData => (T::UnresponsivenessSlash::get(), 75u64, false),
-                OffenseType::ConsensusManipulation => (T::EquivocationSlash::get(), 300u64, true),
-            };
+            Self::deposit_event(Event::ReputationImported { para_id, agent_id, score: weighted });
 
-            // Calculate slash amount
-            let slash_amount = slash_percentage.mul_floor(reputation_info.stake);
-            
-            if !slash_amount.is_zero() {
-                ensure!(reputation_info.stake >= slash_amount, Error::<T>::InsufficientStakeToSlash);
+            Ok(())
+        }
 
-                // Slash the stake
-                let slashed = T::Currency::slash_reserved(&agent_id, slash_amount);
-                T::Slash::on_unbalanced(slashed.0);
+        /// Send this chain's current reputation digest to another chain
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::export_reputation_digest())]
+        pub fn export_reputation_digest(
+            origin: OriginFor<T>,
+            dest: ParaId,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
 
-                // Update stake
-                reputation_info.stake = reputation_info.stake.saturating_sub(slash_amount);
-                
-                // Update total stake
-                let new_total = Self::total_stake().saturating_sub(slash_amount);
-                <TotalStake<T>>::put(new_total);
-            }
+            let digest = Self::reputation_digest().ok_or(Error::<T>::NoReputationRoot)?;
+            T::Exporter::export_digest(dest, &digest).map_err(|_| Error::<T>::ExportFailed)?;
 
-            // Apply reputation penalty
-            reputation_info.reputation = reputation_info.reputation.saturating_sub(reputation_penalty);
-            reputation_info.offense_count = reputation_info.offense_count.saturating_add(1);
+            Self::deposit_event(Event::ReputationDigestExported { dest, digest });
 
-            // Record offense
-            let current_block = <frame_system::Pallet<T>>::block_number();
-            let mut offense_history = Self::offense_history(&agent_id);
-            let _ = offense_history.try_push((offense_type.clone(), current_block));
-            <OffenseHistory<T>>::insert(&agent_id, offense_history);
+            Ok(())
+        }
 
-            // Apply quarantine if needed
-            if should_quarantine {
-                let quarantine_until = current_block.saturating_add(T::QuarantinePeriod::get());
-                reputation_info.quarantine_until = Some(quarantine_until);
+        /// End the caller's quarantine now in exchange for a share of its stake
+        ///
+        /// `EarlyExitPenalty` of the stake is slashed. Each agent can do this at most
+        /// `MaxEarlyExitsPerEra` times per era.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::exit_quarantine_early())]
+        pub fn exit_quarantine_early(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
 
-                Self::deposit_event(Event::AgentQuarantined {
-                    agent_id: agent_id.clone(),
-                    until_block: quarantine_until,
-                });
-            }
+            let mut reputation_info = Self::reputation(&who);
+            ensure!(!reputation_info.is_banned, Error::<T>::AgentBanned);
+            let now = <frame_system::Pallet<T>>::block_number();
+            let was_until = reputation_info.quarantine_until
+                .filter(|until| now <= *until)
+                .ok_or(Error::<T>::NotQuarantined)?;
+            ensure!(!reputation_info.stake.is_zero(), Error::<T>::InsufficientStakeToSlash);
+
+            let current_era = Self::current_era();
+            let (era, used) = Self::early_exits(&who);
+            let used = if era == current_era { used } else { 0 };
+            ensure!(used < T::MaxEarlyExitsPerEra::get(), Error::<T>::TooManyEarlyExits);
+
+            // Slash the penalty from the active stake
+            let penalty = T::EarlyExitPenalty::get().mul_floor(reputation_info.stake);
+            if !penalty.is_zero() {
+                let slashed = T::Currency::slash_reserved_named(&STAKE_RESERVE_ID, &who, penalty);
+                T::Slash::on_unbalanced(slashed.0);
 
-            // Check for permanent ban
-            if reputation_info.offense_count >= T::MaxOffenses::get() {
-                reputation_info.is_banned = true;
-                
-                Self::deposit_event(Event::AgentBanned {
-                    agent_id: agent_id.clone(),
-                });
+                let new_stake = reputation_info.stake.saturating_sub(penalty);
+                Self::note_stake_change(reputation_info.stake, new_stake);
+                reputation_info.stake = new_stake;
+                <TotalStake<T>>::mutate(|total| *total = total.saturating_sub(penalty));
             }
 
-            reputation_info.last_update = current_block;
-            <Reputation<T>>::insert(&agent_id, reputation_info);
+            reputation_info.quarantine_until = None;
+            <Reputation<T>>::insert(&who, reputation_info);
+            let exits_this_era = used.saturating_add(1);
+            <EarlyExits<T>>::insert(&who, (current_era, exits_this_era));
+            T::OnReputationChange::on_reputation_change(&who);
 
-            Self::deposit_event(Event::AgentSlashed {
-                agent_id,
-                offense_type,
-                slash_amount,
-                reputation_penalty,
+            Self::deposit_event(Event::QuarantineExitedEarly {
+                agent_id: who,
+                penalty,
+                was_until,
+                exits_this_era,
             });
 
             Ok(())
         }
-    }
 
-    impl<T: Config> Pallet<T> {
-        /// Apply reputation decay to a specific agent
-        fn apply_reputation_decay(
-            agent_id: &T::AccountId,
-            reputation_info: &mut ReputationInfo<T>,
+        /// Set the curve the base consensus reward is evaluated from (governance function)
+        ///
+        /// `None` removes the curve, falling back to the flat `ConsensusReward`.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::set_reward_curve())]
+        pub fn set_reward_curve(
+            origin: OriginFor<T>,
+            curve: Option<PiecewiseRewardCurve>,
         ) -> DispatchResult {
-            let current_block = <frame_system::Pallet<T>>::block_number();
-            let blocks_elapsed = current_block.saturating_sub(reputation_info.last_update);
-
-            if !blocks_elapsed.is_zero() && !reputation_info.reputation.is_zero() {
-                // Quadratic decay: decay rate increases with higher reputation
-                let base_decay = T::BaseDecayRate::get();
-                
-                // Calculate quadratic multiplier (reputation squared / 1000 to keep reasonable)
-                let reputation_factor = reputation_info.reputation
-                    .saturating_mul(reputation_info.reputation)
-                    .saturating_div(1000);
-                
-                let quadratic_decay = base_decay.saturating_mul(Perbill::from_parts(
-                    reputation_factor.min(1_000_000u64) as u32 // Cap to prevent overflow
-                ));
+            ensure_root(origin)?;
 
-                // Apply decay for each block elapsed
-                for _ in 0..blocks_elapsed.min(100u32.into()) { // Cap iterations to prevent timeout
-                    let decay_amount = quadratic_decay.mul_floor(reputation_info.reputation);
-                    reputation_info.reputation = reputation_info.reputation.saturating_sub(decay_amount);
-                    
-                    // Stop if reputation is very low
-                    if reputation_info.reputation < 10 {
-                        break;
-                    }
-                }
+            match &curve {
+                Some(curve) => {
+                    ensure!(curve.is_valid(), Error::<T>::InvalidRewardCurve);
+                    <RewardCurve<T>>::put(curve);
+                },
+                None => <RewardCurve<T>>::kill(),
             }
 
+            Self::deposit_event(Event::RewardCurveSet { curve });
+
             Ok(())
         }
 
-        /// Apply decay to all agents' reputation
-        fn apply_global_reputation_decay() -> DispatchResult {
-            // In a real implementation, this would be optimized to process in batches
-            // For now, we'll just update the timestamp
+        /// Lift an agent's temporary or permanent ban (governance function)
+        ///
+        /// The agent's offense count is cleared; the number of temporary bans it served is kept,
+        /// so a further ban still moves up the tiers.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::force_unban())]
+        pub fn force_unban(
+            origin: OriginFor<T>,
+            agent_id: T::AccountId,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let mut reputation_info = Self::reputation(&agent_id);
+            ensure!(reputation_info.is_banned, Error::<T>::NotBanned);
+
+            Self::lift_ban(&agent_id, &mut reputation_info, true);
+
             Ok(())
         }
 
-        /// Get the effective reputation (stake-weighted)
-        pub fn effective_reputation(agent_id: &T::AccountId) -> u64 {
-            let reputation_info = Self::reputation(agent_id);
-            
-            if reputation_info.is_banned || 
-               reputation_info.quarantine_until.map_or(false, |until| 
-                   <frame_system::Pallet<T>>::block_number() <= until) {
-                return 0;
-            }
+        /// Drop a deferred slash before it is applied (governance function)
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::cancel_deferred_slash())]
+        pub fn cancel_deferred_slash(
+            origin: OriginFor<T>,
+            slash_id: SlashId,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
 
-            // Weight reputation by stake (minimum 1x, maximum 5x multiplier)
-            let stake_multiplier = if !Self::total_stake().is_zero() {
-                let stake_percentage = Perbill::from_rational(reputation_info.stake, Self::total_stake());
-                1u64.saturating_add(stake_percentage.mul_floor(400u64)) // Up to 5x multiplier
-            } else {
-                1u64
-            };
+            let slash = <PendingSlashes<T>>::take(slash_id).ok_or(Error::<T>::SlashNotFound)?;
+            <AgentPendingSlashes<T>>::mutate(&slash.agent_id, |ids| ids.retain(|id| *id != slash_id));
 
-            reputation_info.reputation.saturating_mul(stake_multiplier)
+            // Emit event
+            Self::deposit_event(Event::DeferredSlashCancelled {
+                slash_id,
+                agent_id: slash.agent_id,
+            });
+
+            Ok(())
         }
 
-        /// Check if agent is active and can participate
-        pub fn can_participate(agent_id: &T::AccountId) -> bool {
-            let reputation_info = Self::reputation(agent_id);
-            
-            !reputation_info.is_banned &&
-            reputation_info.quarantine_until.map_or(true, |until| 
-                <frame_system::Pallet<T>>::block_number() > until) &&
-            !reputation_info.stake.is_zero()
+        /// Lock the whole stake for the duration of `lock`, multiplying its weight
+        ///
+        /// A lock can be extended to a later block or a higher multiplier, but not shortened.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::lock_stake())]
+        pub fn lock_stake(
+            origin: OriginFor<T>,
+            lock: StakeLock,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(lock != StakeLock::Unlocked, Error::<T>::InvalidStakeLock);
+
+            let mut reputation_info = Self::reputation(&who);
+            ensure!(!reputation_info.stake.is_zero(), Error::<T>::NoStakeToLock);
+
+            let now = <frame_system::Pallet<T>>::block_number();
+            let until = now.saturating_add(lock.duration(T::StakeLockPeriod::get()));
+            ensure!(
+                lock.multiplier() >= Self::active_lock(&reputation_info).multiplier() &&
+                    until >= reputation_info.locked_until,
+                Error::<T>::StakeLockShortened
+            );
+
+            reputation_info.stake_lock = lock;
+            reputation_info.locked_until = until;
+            <Reputation<T>>::insert(&who, reputation_info);
+            T::OnReputationChange::on_reputation_change(&who);
+
+            // Emit event
+            Self::deposit_event(Event::StakeLocked {
+                agent_id: who,
+                lock,
+                until,
+            });
+
+            Ok(())
+        }
+
+        /// Lower an agent's recorded stake to what it actually has reserved
+        ///
+        /// Anyone can call this. Fails if the reserved balance already covers the recorded
+        /// stake, so a repair is only paid for when there is something to fix.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::repair_stake_accounting())]
+        pub fn repair_stake_accounting(
+            origin: OriginFor<T>,
+            agent_id: T::AccountId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+            ensure!(Self::reconcile_stake(&agent_id).is_some(), Error::<T>::StakeAccountingConsistent);
+            Ok(())
+        }
+
+        /// Reward several agents for successful consensus participation
+        ///
+        /// Every agent is rewarded as by `reward_consensus`, in order, and an agent listed twice
+        /// is rewarded twice. Fails without rewarding anyone if any reward fails.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::reward_consensus_many(agents.len() as u32))]
+        pub fn reward_consensus_many(
+            origin: OriginFor<T>,
+            agents: BoundedVec<T::AccountId, T::MaxRewardBatch>,
+        ) -> DispatchResult {
+            T::RewardOrigin::ensure_origin(origin)?;
+            for agent_id in agents.iter() {
+                Self::do_reward_consensus(agent_id)?;
+            }
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Check that `TotalStake` sums the stakes of all agents, and that every deferred slash
+        /// is indexed under the agent it is pending against and nowhere else
+        #[cfg(any(feature = "try-runtime", test))]
+        pub fn do_try_state() -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            let staked = <Reputation<T>>::iter_values()
+                .fold(BalanceOf::<T>::zero(), |total, info| total.saturating_add(info.stake));
+            ensure!(staked == Self::total_stake(), "Reputation: TotalStake does not match the agents' stakes");
+
+            let next_slash_id = Self::next_slash_id();
+            for (slash_id, slash) in <PendingSlashes<T>>::iter() {
+                ensure!(slash_id < next_slash_id, "Reputation: a pending slash has an unassigned ID");
+                ensure!(
+                    <AgentPendingSlashes<T>>::get(&slash.agent_id).contains(&slash_id),
+                    "Reputation: a pending slash is not indexed under its agent"
+                );
+            }
+            for (agent_id, slash_ids) in <AgentPendingSlashes<T>>::iter() {
+                ensure!(
+                    slash_ids.iter().all(|slash_id| {
+                        <PendingSlashes<T>>::get(slash_id).map_or(false, |slash| slash.agent_id == agent_id)
+                    }),
+                    "Reputation: an agent is indexed with a slash pending against another agent"
+                );
+            }
+
+            Ok(())
+        }
+
+        /// Pay an agent the stake-weighted consensus reward
+        pub fn do_reward_consensus(agent_id: &T::AccountId) -> DispatchResult {
+            let mut reputation_info = Self::reputation(agent_id);
+            
+            // Apply decay before adding reward
+            Self::apply_reputation_decay(agent_id, &mut reputation_info)?;
+
+            reputation_info.consensus_count = reputation_info.consensus_count.saturating_add(1);
+            let streak = Self::note_participation(agent_id);
+
+            // Calculate stake-weighted reward from the curved stake share, plus the streak bonus
+            let base_reward = Self::base_consensus_reward(agent_id, &reputation_info, streak);
+            let stake_multiplier = 1u64.saturating_add(Self::stake_weight(agent_id).mul_floor(100u64));
+
+            let stake_weighted = base_reward.saturating_mul(stake_multiplier);
+            let weighted_reward = stake_weighted.saturating_add(Self::streak_bonus(streak).mul_floor(stake_weighted));
+            let old_reputation = reputation_info.reputation;
+            
+            reputation_info.reputation = reputation_info.reputation.saturating_add(weighted_reward);
+            reputation_info.last_update = <frame_system::Pallet<T>>::block_number();
+
+            <Reputation<T>>::insert(agent_id, reputation_info.clone());
+            T::OnReputationChange::on_reputation_change(agent_id);
+            T::OnRewardOrSlash::on_rewarded(agent_id, weighted_reward);
+            <CurrentEraStats<T>>::mutate(|stats| {
+                stats.reputation_rewarded = stats.reputation_rewarded.saturating_add(weighted_reward)
+            });
+
+            Self::deposit_event(Event::ReputationUpdated {
+                agent_id: agent_id.clone(),
+                old_reputation,
+                new_reputation: reputation_info.reputation,
+            });
+
+            Self::deposit_event(Event::ConsensusRewardDistributed {
+                agent_id: agent_id.clone(),
+                reputation_reward: weighted_reward,
+            });
+
+            Ok(())
+        }
+
+        /// Base consensus reward of an agent before stake weighting
+        ///
+        /// Evaluated from `RewardCurve` if one is set, otherwise the flat `ConsensusReward`.
+        pub fn base_consensus_reward(agent_id: &T::AccountId, info: &ReputationInfo<T>, streak: u32) -> u64 {
+            match <RewardCurve<T>>::get() {
+                Some(curve) => {
+                    let input = match curve.input {
+                        RewardCurveInput::EffectiveReputation => Self::effective_reputation(agent_id),
+                        RewardCurveInput::Participations => info.consensus_count.saturating_sub(1).into(),
+                        RewardCurveInput::ParticipationStreak => streak.into(),
+                    };
+                    curve.evaluate(input)
+                },
+                None => T::ConsensusReward::get(),
+            }
+        }
+
+        /// Count a consensus participation of an agent, returning its updated streak
+        fn note_participation(agent_id: &T::AccountId) -> u32 {
+            let min_participations = T::MinStreakParticipations::get();
+            <ParticipationStreaks<T>>::mutate(agent_id, |info| {
+                info.roll_to(Self::current_era(), min_participations);
+                info.participations = info.participations.saturating_add(1);
+                info.current_streak(min_participations)
+            })
+        }
+
+        /// An agent's participation streak as of the current era
+        ///
+        /// Consecutive eras with at least `MinStreakParticipations` consensus participations,
+        /// counting the current era once it has enough of them.
+        pub fn participation_streak(agent_id: &T::AccountId) -> u32 {
+            let min_participations = T::MinStreakParticipations::get();
+            let mut info = Self::participation_streaks(agent_id);
+            info.roll_to(Self::current_era(), min_participations);
+            info.current_streak(min_participations)
+        }
+
+        /// Count a consensus activity of an agent in the current era's participation summary
+        ///
+        /// Submissions are also counted in the current era's network-wide statistics.
+        pub fn note_activity(agent_id: &T::AccountId, kind: ParticipationKind) {
+            if kind == ParticipationKind::Submission {
+                <CurrentEraStats<T>>::mutate(|stats| stats.logs_submitted = stats.logs_submitted.saturating_add(1));
+            }
+            <EraParticipation<T>>::mutate(Self::current_era(), agent_id, |summary| {
+                let count = match kind {
+                    ParticipationKind::Submission => &mut summary.submissions,
+                    ParticipationKind::Signature => &mut summary.signatures,
+                    ParticipationKind::Finalization => &mut summary.finalizations,
+                };
+                *count = count.saturating_add(1);
+            });
+        }
+
+        /// Count a finalized consensus log with `signers` signers in the current era's statistics
+        pub fn note_log_finalized(signers: u32) {
+            <CurrentEraStats<T>>::mutate(|stats| {
+                stats.logs_finalized = stats.logs_finalized.saturating_add(1);
+                stats.finalized_signatures = stats.finalized_signatures.saturating_add(signers);
+            });
+        }
+
+        /// Count a consensus log retracted before it was finalized in the current era's statistics
+        pub fn note_log_rejected() {
+            <CurrentEraStats<T>>::mutate(|stats| stats.logs_rejected = stats.logs_rejected.saturating_add(1));
+        }
+
+        /// Network-wide consensus statistics of `era`
+        ///
+        /// The statistics so far for the current era, and `None` for eras that have not started.
+        pub fn era_stats(era: EraIndex) -> Option<EraStatistics<BalanceOf<T>>> {
+            let current = Self::current_era();
+            if era == current {
+                return Some(<CurrentEraStats<T>>::get());
+            }
+            (era < current).then(|| <EraStats<T>>::get(era)).flatten()
+        }
+
+        /// An agent's participation summary of `era`
+        ///
+        /// `None` for eras that have not started yet or are older than
+        /// `ParticipationHistoryDepth` eras.
+        pub fn era_summary(agent_id: &T::AccountId, era: EraIndex) -> Option<ParticipationSummary> {
+            let current = Self::current_era();
+            if era > current || current.saturating_sub(era) > T::ParticipationHistoryDepth::get() {
+                return None;
+            }
+            Some(<EraParticipation<T>>::get(era, agent_id))
+        }
+
+        /// Record the consensus parameters `era` starts under at `block`
+        fn snapshot_era_config(era: EraIndex, block: BlockNumberFor<T>, committee_size: u32) -> Weight {
+            <EraConfigs<T>>::insert(era, EraConfig {
+                started_at: block,
+                consensus: T::ConsensusParameters::consensus_thresholds(),
+                committee_size,
+                max_committee_size: T::MaxCommitteeSize::get(),
+                minimum_stake: T::MinimumStake::get(),
+                unresponsiveness_slash: T::UnresponsivenessSlash::get(),
+                equivocation_slash: T::EquivocationSlash::get(),
+                max_slash_percentage: T::MaxSlashPercentage::get(),
+                max_penalty_doublings: T::MaxPenaltyDoublings::get(),
+                offense_window: T::OffenseWindow::get(),
+                quarantine_period: T::QuarantinePeriod::get(),
+                slash_defer_duration: T::SlashDeferDuration::get(),
+                max_offenses: T::MaxOffenses::get(),
+                max_temporary_bans: T::MaxTemporaryBans::get(),
+                temporary_ban_eras: T::TemporaryBanEras::get(),
+            });
+            // The consensus thresholds cost two reads
+            T::DbWeight::get().reads_writes(2, 1)
+        }
+
+        /// The era `block` belongs to and the consensus parameters it ran under
+        ///
+        /// `None` for blocks before the oldest recorded era, which includes every block of eras
+        /// that started before configurations were recorded, and for future blocks.
+        pub fn era_config_at(
+            block: BlockNumberFor<T>,
+        ) -> Option<(EraIndex, EraConfig<BalanceOf<T>, BlockNumberFor<T>>)> {
+            if block > <frame_system::Pallet<T>>::block_number() {
+                return None;
+            }
+
+            // Eras without a configuration only precede recorded ones, so the eras up to the one
+            // `block` belongs to are exactly those unrecorded or started at or before it
+            let (mut low, mut high) = (0, Self::current_era());
+            while low < high {
+                let mid = low + (high - low + 1) / 2;
+                if <EraConfigs<T>>::get(mid).map_or(true, |config| config.started_at <= block) {
+                    low = mid;
+                } else {
+                    high = mid - 1;
+                }
+            }
+            <EraConfigs<T>>::get(low)
+                .filter(|config| config.started_at <= block)
+                .map(|config| (low, config))
+        }
+
+        /// Drop the participation summaries that fell out of the history at the start of `era`
+        fn prune_participation(era: EraIndex) -> Weight {
+            let depth = T::ParticipationHistoryDepth::get();
+            if era <= depth {
+                return Weight::zero();
+            }
+            let removed = <EraParticipation<T>>::clear_prefix(era - depth - 1, u32::MAX, None).unique;
+            T::DbWeight::get().writes(removed as u64)
+        }
+
+        /// Bonus share of the consensus reward earned by a streak of `streak` eras
+        pub fn streak_bonus(streak: u32) -> Perbill {
+            Perbill::from_parts(T::StreakBonusPerEra::get().deconstruct().saturating_mul(streak))
+                .min(T::MaxStreakBonus::get())
+        }
+
+        /// Pay an agent `percent` percent of the flat bonus for co-signing a consensus log early
+        pub fn do_reward_first_responder(agent_id: &T::AccountId, percent: u32) -> DispatchResult {
+            let mut reputation_info = Self::reputation(agent_id);
+            Self::apply_reputation_decay(agent_id, &mut reputation_info)?;
+
+            let bonus = T::FirstResponderBonus::get().saturating_mul(percent as u64) / 100;
+            let old_reputation = reputation_info.reputation;
+            reputation_info.reputation = reputation_info.reputation.saturating_add(bonus);
+            reputation_info.last_update = <frame_system::Pallet<T>>::block_number();
+
+            <Reputation<T>>::insert(agent_id, reputation_info.clone());
+            T::OnReputationChange::on_reputation_change(agent_id);
+            T::OnRewardOrSlash::on_rewarded(agent_id, bonus);
+
+            Self::deposit_event(Event::ReputationUpdated {
+                agent_id: agent_id.clone(),
+                old_reputation,
+                new_reputation: reputation_info.reputation,
+            });
+
+            Self::deposit_event(Event::FirstResponderRewarded {
+                agent_id: agent_id.clone(),
+                reputation_reward: bonus,
+            });
+
+            Ok(())
+        }
+
+        /// Record an offense against an agent, applying it now or deferring it by
+        /// `SlashDeferDuration`
+        pub fn do_report_offense(
+            agent_id: &T::AccountId,
+            offense_type: OffenseType,
+        ) -> DispatchResult {
+            let defer = T::SlashDeferDuration::get();
+            if defer.is_zero() {
+                return Self::apply_offense(agent_id, offense_type);
+            }
+
+            ensure!(!Self::reputation(agent_id).is_banned, Error::<T>::AgentBanned);
+
+            let slash_id = Self::next_slash_id();
+            <AgentPendingSlashes<T>>::try_mutate(agent_id, |ids| ids.try_push(slash_id))
+                .map_err(|_| Error::<T>::TooManyPendingSlashes)?;
+
+            let reported_at = <frame_system::Pallet<T>>::block_number();
+            let execute_at = reported_at.saturating_add(defer);
+            <PendingSlashes<T>>::insert(slash_id, PendingSlash {
+                agent_id: agent_id.clone(),
+                offense_type: offense_type.clone(),
+                reported_at,
+                execute_at,
+            });
+            <NextSlashId<T>>::put(slash_id.saturating_add(1));
+
+            Self::deposit_event(Event::SlashDeferred {
+                slash_id,
+                agent_id: agent_id.clone(),
+                offense_type,
+                execute_at,
+            });
+
+            Ok(())
+        }
+
+        /// Apply due deferred slashes, oldest first and at most `MaxSlashesPerBlock` of them
+        ///
+        /// Cancelled slashes still count towards the limit, keeping the weight bounded.
+        fn execute_deferred_slashes(now: BlockNumberFor<T>) -> Weight {
+            let start = Self::slash_queue_head();
+            let next = Self::next_slash_id();
+            let mut weight = T::DbWeight::get().reads(2);
+
+            let mut head = start;
+            let mut processed = 0u32;
+            while head < next && processed < T::MaxSlashesPerBlock::get() {
+                processed = processed.saturating_add(1);
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+
+                let Some(slash) = <PendingSlashes<T>>::get(head) else {
+                    head = head.saturating_add(1);
+                    continue;
+                };
+                if slash.execute_at > now {
+                    break;
+                }
+
+                <PendingSlashes<T>>::remove(head);
+                <AgentPendingSlashes<T>>::mutate(&slash.agent_id, |ids| ids.retain(|id| *id != head));
+                let result = frame_support::storage::with_storage_layer(|| {
+                    Self::apply_offense(&slash.agent_id, slash.offense_type.clone())
+                });
+                if let Err(error) = result {
+                    Self::deposit_event(Event::DeferredSlashFailed {
+                        slash_id: head,
+                        agent_id: slash.agent_id,
+                        error,
+                    });
+                }
+                weight = weight
+                    .saturating_add(T::DbWeight::get().reads_writes(1, 2))
+                    .saturating_add(T::WeightInfo::report_offense());
+                head = head.saturating_add(1);
+            }
+
+            if head != start {
+                <SlashQueueHead<T>>::put(head);
+                weight = weight.saturating_add(T::DbWeight::get().writes(1));
+            }
+            weight
+        }
+
+        /// Apply an offense: slash the stake, cut the reputation, and quarantine or ban
+        fn apply_offense(
+            agent_id: &T::AccountId,
+            offense_type: OffenseType,
+        ) -> DispatchResult {
+            let mut reputation_info = Self::reputation(agent_id);
+            ensure!(!reputation_info.is_banned, Error::<T>::AgentBanned);
+
+            // Apply decay before processing offense
+            Self::apply_reputation_decay(agent_id, &mut reputation_info)?;
+
+            // Determine slash amount and reputation penalty
+            let (base_slash, base_penalty, should_quarantine) = match offense_type {
+                OffenseType::Unresponsiveness => (T::UnresponsivenessSlash::get(), 50u64, false),
+                OffenseType::Equivocation => (T::EquivocationSlash::get(), 200u64, true),
+                OffenseType::InvalidData => (T::UnresponsivenessSlash::get(), 75u64, false),
+                OffenseType::ConsensusManipulation => (T::EquivocationSlash::get(), 300u64, true),
+                OffenseType::DataWithholding => (T::UnresponsivenessSlash::get(), 100u64, true),
+                OffenseType::LatencyViolation => (
+                    Perbill::from_parts(T::UnresponsivenessSlash::get().deconstruct() / 2),
+                    25u64,
+                    false,
+                ),
+                OffenseType::PolicyViolation => (T::EquivocationSlash::get(), 150u64, false),
+            };
+
+            // Escalate for repeats of the same offense within the window
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            let multiplier = Self::penalty_multiplier(agent_id, &offense_type, current_block);
+            let slash_percentage = Perbill::from_parts(base_slash.deconstruct().saturating_mul(multiplier))
+                .min(T::MaxSlashPercentage::get().max(base_slash));
+            let reputation_penalty = base_penalty.saturating_mul(u64::from(multiplier));
+
+            // Calculate slash amount, including stake that is still unbonding
+            let unbonding = Self::unbonding(agent_id);
+            let slashable = reputation_info.stake.saturating_add(unbonding);
+            let slash_amount = slash_percentage.mul_floor(slashable);
+            
+            if !slash_amount.is_zero() {
+                ensure!(slashable >= slash_amount, Error::<T>::InsufficientStakeToSlash);
+
+                // Slash the stake
+                let slashed = T::Currency::slash_reserved_named(&STAKE_RESERVE_ID, agent_id, slash_amount);
+                T::Slash::on_unbalanced(slashed.0);
+
+                // Take the slash from active stake first, then from unbonding stake
+                let from_stake = slash_amount.min(reputation_info.stake);
+                let from_unbonding = slash_amount.saturating_sub(from_stake);
+
+                // Update stake
+                let new_stake = reputation_info.stake.saturating_sub(from_stake);
+                Self::note_stake_change(reputation_info.stake, new_stake);
+                reputation_info.stake = new_stake;
+                
+                // Update total stake
+                let new_total = Self::total_stake().saturating_sub(from_stake);
+                <TotalStake<T>>::put(new_total);
+
+                if !from_unbonding.is_zero() {
+                    <Unbonding<T>>::insert(agent_id, unbonding.saturating_sub(from_unbonding));
+                }
+            }
+
+            // Apply reputation penalty
+            reputation_info.reputation = reputation_info.reputation.saturating_sub(reputation_penalty);
+            reputation_info.offense_count = reputation_info.offense_count.saturating_add(1);
+
+            // Any offense ends the participation streak, including the current era's progress
+            let streak = Self::participation_streak(agent_id);
+            <ParticipationStreaks<T>>::insert(agent_id, StreakInfo { era: Self::current_era(), ..Default::default() });
+            if streak > 0 {
+                Self::deposit_event(Event::StreakReset {
+                    agent_id: agent_id.clone(),
+                    streak,
+                });
+            }
+
+            // Record offense, archiving the oldest one if the history is full
+            let mut offense_history = Self::offense_history(agent_id);
+            if offense_history.is_full() && !offense_history.is_empty() {
+                let (oldest_type, oldest_at) = offense_history.remove(0);
+                Self::archive_offense(agent_id, &oldest_type, oldest_at);
+            }
+            let _ = offense_history.try_push((offense_type.clone(), current_block));
+            <OffenseHistory<T>>::insert(agent_id, offense_history);
+
+            // Apply quarantine if needed
+            if should_quarantine {
+                let quarantine_until = current_block.saturating_add(T::QuarantinePeriod::get());
+                reputation_info.quarantine_until = Some(quarantine_until);
+
+                Self::deposit_event(Event::AgentQuarantined {
+                    agent_id: agent_id.clone(),
+                    until_block: quarantine_until,
+                });
+            }
+
+            // Ban the agent, temporarily until it used up its temporary bans
+            if reputation_info.offense_count >= T::MaxOffenses::get() {
+                reputation_info.is_banned = true;
+                if reputation_info.temporary_bans < T::MaxTemporaryBans::get() {
+                    let ban_length = T::EraLength::get()
+                        .saturating_mul(T::TemporaryBanEras::get().into());
+                    reputation_info.ban_until = Some(current_block.saturating_add(ban_length));
+                    reputation_info.temporary_bans = reputation_info.temporary_bans.saturating_add(1);
+                    <TemporarilyBanned<T>>::insert(agent_id, ());
+                }
+
+                Self::deposit_event(Event::AgentBanned {
+                    agent_id: agent_id.clone(),
+                    until: reputation_info.ban_until,
+                });
+            }
+
+            reputation_info.last_update = current_block;
+            <Reputation<T>>::insert(agent_id, reputation_info);
+            T::OnReputationChange::on_reputation_change(agent_id);
+            T::OnRewardOrSlash::on_slashed(agent_id, reputation_penalty);
+            <CurrentEraStats<T>>::mutate(|stats| stats.slashed = stats.slashed.saturating_add(slash_amount));
+
+            Self::deposit_event(Event::AgentSlashed {
+                agent_id: agent_id.clone(),
+                offense_type,
+                slash_amount,
+                reputation_penalty,
+                multiplier,
+            });
+
+            Ok(())
+        }
+
+        /// Lift every temporary ban that expired by `now`
+        fn lift_expired_bans(now: BlockNumberFor<T>) -> Weight {
+            let banned = <TemporarilyBanned<T>>::iter_keys().collect::<Vec<_>>();
+
+            let mut lifted = 0u64;
+            for agent_id in banned.iter() {
+                let mut reputation_info = Self::reputation(agent_id);
+                if reputation_info.ban_until.map_or(true, |until| until <= now) {
+                    Self::lift_ban(agent_id, &mut reputation_info, false);
+                    lifted = lifted.saturating_add(1);
+                }
+            }
+
+            // Each banned agent's reputation is read, each lift writes it and the ban entry
+            T::DbWeight::get().reads_writes(
+                (banned.len() as u64).saturating_mul(2),
+                lifted.saturating_mul(2),
+            )
+        }
+
+        /// Clear an agent's ban and offense count
+        fn lift_ban(agent_id: &T::AccountId, reputation_info: &mut ReputationInfo<T>, forced: bool) {
+            reputation_info.is_banned = false;
+            reputation_info.ban_until = None;
+            reputation_info.offense_count = 0;
+            <Reputation<T>>::insert(agent_id, reputation_info.clone());
+            <TemporarilyBanned<T>>::remove(agent_id);
+            T::OnReputationChange::on_reputation_change(agent_id);
+
+            Self::deposit_event(Event::AgentUnbanned {
+                agent_id: agent_id.clone(),
+                forced,
+            });
+        }
+
+        /// Move an agent's whole stake to `Unbonding`
+        ///
+        /// The stake stops counting towards the agent's weight and the total stake but stays
+        /// reserved. Compounding of era rewards is switched off.
+        pub fn begin_unbonding(agent_id: &T::AccountId) {
+            let mut reputation_info = Self::reputation(agent_id);
+            let amount = reputation_info.stake;
+            <CompoundRewards<T>>::remove(agent_id);
+            if amount.is_zero() {
+                return;
+            }
+
+            Self::note_stake_change(amount, Zero::zero());
+            reputation_info.stake = Zero::zero();
+            reputation_info.last_update = <frame_system::Pallet<T>>::block_number();
+            <Reputation<T>>::insert(agent_id, reputation_info);
+            <TotalStake<T>>::mutate(|total| *total = total.saturating_sub(amount));
+            <Unbonding<T>>::mutate(agent_id, |unbonding| *unbonding = unbonding.saturating_add(amount));
+            T::OnReputationChange::on_reputation_change(agent_id);
+
+            Self::deposit_event(Event::UnbondingStarted {
+                agent_id: agent_id.clone(),
+                amount,
+            });
+        }
+
+        /// Release whatever is left of an agent's unbonding stake
+        pub fn withdraw_unbonded(agent_id: &T::AccountId) {
+            let amount = <Unbonding<T>>::take(agent_id);
+            if amount.is_zero() {
+                return;
+            }
+
+            T::Currency::unreserve_named(&STAKE_RESERVE_ID, agent_id, amount);
+
+            Self::deposit_event(Event::Unstaked {
+                agent_id: agent_id.clone(),
+                amount,
+            });
+        }
+
+        /// Bring an agent's recorded stake in line with its reserved balance
+        ///
+        /// The recorded stake is the active stake plus the unbonding stake, and is compared with
+        /// the [`STAKE_RESERVE_ID`] reserve only, so deposits the account holds for other pallets
+        /// do not hide a shortfall. If the reserve is below the recorded stake, the active stake
+        /// is lowered first, keeping as much of the unbonding stake as is still reserved. Returns
+        /// the recorded and actual amounts if anything was repaired.
+        pub fn reconcile_stake(agent_id: &T::AccountId) -> Option<(BalanceOf<T>, BalanceOf<T>)> {
+            let mut reputation_info = Self::reputation(agent_id);
+            let unbonding = Self::unbonding(agent_id);
+            let recorded = reputation_info.stake.saturating_add(unbonding);
+            let actual = T::Currency::reserved_balance_named(&STAKE_RESERVE_ID, agent_id);
+            if actual >= recorded {
+                return None;
+            }
+
+            let new_unbonding = unbonding.min(actual);
+            let new_stake = actual.saturating_sub(new_unbonding);
+            let removed = reputation_info.stake.saturating_sub(new_stake);
+
+            Self::note_stake_change(reputation_info.stake, new_stake);
+            reputation_info.stake = new_stake;
+            if new_stake.is_zero() {
+                reputation_info.stake_lock = StakeLock::Unlocked;
+                reputation_info.locked_until = Zero::zero();
+            }
+            <Reputation<T>>::insert(agent_id, reputation_info);
+            <TotalStake<T>>::mutate(|total| *total = total.saturating_sub(removed));
+            if new_unbonding != unbonding {
+                if new_unbonding.is_zero() {
+                    <Unbonding<T>>::remove(agent_id);
+                } else {
+                    <Unbonding<T>>::insert(agent_id, new_unbonding);
+                }
+            }
+            T::OnReputationChange::on_reputation_change(agent_id);
+
+            Self::deposit_event(Event::StakeAccountingRepaired {
+                agent_id: agent_id.clone(),
+                recorded,
+                actual,
+            });
+
+            Some((recorded, actual))
+        }
+
+        /// Move `portion` of an agent's active stake to the free balance of `escrow`, returning
+        /// the amount moved
+        ///
+        /// The escrow account must already exist. The amount stops counting as stake right away,
+        /// so it cannot be unstaked while escrowed.
+        pub fn escrow_stake(agent_id: &T::AccountId, escrow: &T::AccountId, portion: Perbill) -> BalanceOf<T> {
+            let mut reputation_info = Self::reputation(agent_id);
+            let amount = portion.mul_floor(reputation_info.stake);
+            if amount.is_zero() {
+                return Zero::zero();
+            }
+
+            let moved = match T::Currency::repatriate_reserved_named(&STAKE_RESERVE_ID, agent_id, escrow, amount, BalanceStatus::Free) {
+                Ok(remaining) => amount.saturating_sub(remaining),
+                Err(_) => return Zero::zero(),
+            };
+            if moved.is_zero() {
+                return Zero::zero();
+            }
+
+            let new_stake = reputation_info.stake.saturating_sub(moved);
+            Self::note_stake_change(reputation_info.stake, new_stake);
+            reputation_info.stake = new_stake;
+            reputation_info.last_update = <frame_system::Pallet<T>>::block_number();
+            if new_stake.is_zero() {
+                reputation_info.stake_lock = StakeLock::Unlocked;
+                reputation_info.locked_until = Zero::zero();
+            }
+            <Reputation<T>>::insert(agent_id, reputation_info);
+            <TotalStake<T>>::mutate(|total| *total = total.saturating_sub(moved));
+            T::OnReputationChange::on_reputation_change(agent_id);
+
+            Self::deposit_event(Event::StakeEscrowed {
+                agent_id: agent_id.clone(),
+                escrow: escrow.clone(),
+                amount: moved,
+            });
+
+            moved
+        }
+
+        /// Stake `amount` of an agent's free balance again after it was released from an escrow
+        ///
+        /// Agents that retired, were banned or can no longer reserve the amount keep it as free
+        /// balance instead. `MaxStakePerAgent` is not checked, as the amount was staked before.
+        pub fn restore_stake(agent_id: &T::AccountId, amount: BalanceOf<T>) {
+            let mut reputation_info = Self::reputation(agent_id);
+            if amount.is_zero()
+                || reputation_info.is_banned
+                || !T::Agents::is_registered(agent_id)
+                || T::Agents::is_retiring(agent_id)
+                || T::Currency::reserve_named(&STAKE_RESERVE_ID, agent_id, amount).is_err()
+            {
+                return;
+            }
+
+            let new_stake = reputation_info.stake.saturating_add(amount);
+            Self::note_stake_change(reputation_info.stake, new_stake);
+            reputation_info.stake = new_stake;
+            reputation_info.last_update = <frame_system::Pallet<T>>::block_number();
+            <Reputation<T>>::insert(agent_id, reputation_info);
+            <TotalStake<T>>::mutate(|total| *total = total.saturating_add(amount));
+            T::OnReputationChange::on_reputation_change(agent_id);
+
+            Self::deposit_event(Event::StakeRestored {
+                agent_id: agent_id.clone(),
+                amount,
+            });
+        }
+
+        /// Multiplier applied to an offense's penalties
+        ///
+        /// Doubles for every earlier offense of the same type within `OffenseWindow` blocks of
+        /// `now`, at most `MaxPenaltyDoublings` times.
+        pub fn penalty_multiplier(
+            agent_id: &T::AccountId,
+            offense_type: &OffenseType,
+            now: BlockNumberFor<T>,
+        ) -> u32 {
+            let window_start = now.saturating_sub(T::OffenseWindow::get());
+            let repeats = Self::offense_history(agent_id)
+                .iter()
+                .filter(|(kind, at)| kind == offense_type && *at > window_start)
+                .count() as u32;
+
+            1u32 << repeats.min(T::MaxPenaltyDoublings::get()).min(31)
+        }
+
+        /// Era an offense committed at `block` falls into
+        pub fn era_of(block: BlockNumberFor<T>) -> EraIndex {
+            let era_length = T::EraLength::get();
+            if era_length.is_zero() {
+                return Zero::zero();
+            }
+            (block / era_length).unique_saturated_into()
+        }
+
+        /// Fold an offense into the digest of the era it was committed in
+        fn archive_offense(agent_id: &T::AccountId, offense_type: &OffenseType, at: BlockNumberFor<T>) {
+            <OffenseArchive<T>>::mutate(agent_id, Self::era_of(at), |digest| digest.note(offense_type));
+        }
+
+        /// Archive every recorded offense that no longer counts towards escalation at `now`
+        fn archive_offenses(now: BlockNumberFor<T>) -> Weight {
+            let window_start = now.saturating_sub(T::OffenseWindow::get());
+            let agents = <OffenseHistory<T>>::iter_keys().collect::<Vec<_>>();
+
+            let mut archived = 0u64;
+            for agent_id in agents.iter() {
+                let history = Self::offense_history(agent_id);
+                let (expired, recent): (Vec<_>, Vec<_>) =
+                    history.into_iter().partition(|(_, at)| *at <= window_start);
+                if expired.is_empty() {
+                    continue;
+                }
+
+                for (offense_type, at) in expired.iter() {
+                    Self::archive_offense(agent_id, offense_type, *at);
+                }
+                archived = archived.saturating_add(expired.len() as u64);
+
+                if recent.is_empty() {
+                    <OffenseHistory<T>>::remove(agent_id);
+                } else {
+                    <OffenseHistory<T>>::insert(agent_id, BoundedVec::truncate_from(recent));
+                }
+            }
+
+            // Each history is read, each archived offense costs a digest read and write
+            T::DbWeight::get().reads_writes(
+                (agents.len() as u64).saturating_add(archived),
+                archived.saturating_mul(2),
+            )
+        }
+
+        /// Offenses of an agent per era, merging the archive with the recent history
+        ///
+        /// Sorted by era, oldest first.
+        pub fn offense_summary(agent_id: &T::AccountId) -> Vec<(EraIndex, OffenseDigest)> {
+            let mut summary = <OffenseArchive<T>>::iter_prefix(agent_id).collect::<Vec<_>>();
+            summary.sort_by_key(|(era, _)| *era);
+
+            for (offense_type, at) in Self::offense_history(agent_id).iter() {
+                let era = Self::era_of(*at);
+                match summary.binary_search_by_key(&era, |(era, _)| *era) {
+                    Ok(index) => summary[index].1.note(offense_type),
+                    Err(index) => {
+                        let mut digest = OffenseDigest::default();
+                        digest.note(offense_type);
+                        summary.insert(index, (era, digest));
+                    },
+                }
+            }
+
+            summary
+        }
+
+        /// Account era rewards are paid from
+        pub fn reward_pot() -> T::AccountId {
+            T::RewardPotId::get().into_account_truncating()
+        }
+
+        /// Pay the era reward from the reward pot to every member of the current committee
+        fn pay_era_rewards() -> Weight {
+            let reward = T::EraReward::get();
+            if reward.is_zero() {
+                return Weight::zero();
+            }
+
+            let committee = Self::committee();
+            for member in committee.iter() {
+                Self::pay_era_reward(member, reward);
+            }
+
+            T::DbWeight::get().reads_writes(1, 0)
+                .saturating_add(T::DbWeight::get().reads_writes(5, 4).saturating_mul(committee.len() as u64))
+        }
+
+        /// Pay an era reward from the reward pot to an agent, restaking it if the agent opted in
+        fn pay_era_reward(agent_id: &T::AccountId, reward: BalanceOf<T>) {
+            let pot = Self::reward_pot();
+            if T::Currency::transfer(&pot, agent_id, reward, ExistenceRequirement::KeepAlive).is_err() {
+                Self::deposit_event(Event::EraRewardUnpaid { agent_id: agent_id.clone(), amount: reward });
+                return;
+            }
+
+            let mut compounded = Zero::zero();
+            if Self::compound_rewards(agent_id) {
+                let mut reputation_info = Self::reputation(agent_id);
+                let amount = Self::compoundable_amount(&reputation_info, reward);
+
+                if !amount.is_zero() && T::Currency::reserve_named(&STAKE_RESERVE_ID, agent_id, amount).is_ok() {
+                    let new_stake = reputation_info.stake.saturating_add(amount);
+                    Self::note_stake_change(reputation_info.stake, new_stake);
+                    reputation_info.stake = new_stake;
+                    <Reputation<T>>::insert(agent_id, reputation_info);
+                    <TotalStake<T>>::mutate(|total| *total = total.saturating_add(amount));
+                    T::OnReputationChange::on_reputation_change(agent_id);
+                    compounded = amount;
+                }
+            }
+
+            Self::deposit_event(Event::EraRewardPaid {
+                agent_id: agent_id.clone(),
+                amount: reward,
+                compounded,
+            });
+        }
+
+        /// The part of a reward that can be added to an agent's stake
+        ///
+        /// An agent without stake must still reach `MinimumStake` in one go, just like `stake`,
+        /// and no agent is restaked beyond `MaxStakePerAgent`.
+        fn compoundable_amount(reputation_info: &ReputationInfo<T>, reward: BalanceOf<T>) -> BalanceOf<T> {
+            if reputation_info.stake.is_zero() && reward < T::MinimumStake::get() {
+                return Zero::zero();
+            }
+            let headroom = T::MaxStakePerAgent::get().saturating_sub(reputation_info.stake);
+            reward.min(headroom)
+        }
+
+        /// Weight of a stake amount under the configured curve
+        pub fn stake_weight_of(stake: BalanceOf<T>) -> u128 {
+            T::StakeCurve::get().apply(stake.unique_saturated_into())
+        }
+
+        /// Keep `TotalStakeWeight` in sync when an agent's stake changes
+        fn note_stake_change(old_stake: BalanceOf<T>, new_stake: BalanceOf<T>) {
+            <TotalStakeWeight<T>>::mutate(|total| {
+                *total = total
+                    .saturating_sub(Self::stake_weight_of(old_stake))
+                    .saturating_add(Self::stake_weight_of(new_stake));
+            });
+        }
+
+        /// Select the committee: participating agents ranked by effective reputation
+        ///
+        /// Agents below the registry's minimum agent version are left out, and agents the
+        /// registry reports as overloaded only fill the seats no other agent takes. Ties are
+        /// broken by account ID so the selection is deterministic. This scans every reputation
+        /// entry at once, so it only serves queries; new eras select their committee in the
+        /// same way over as many blocks as the scan takes.
+        pub fn select_committee() -> BoundedVec<T::AccountId, T::MaxCommitteeSize> {
+            let mut leaders = BoundedVec::new();
+            for agent_id in <Reputation<T>>::iter_keys() {
+                Self::rank_candidate(&mut leaders, agent_id);
+            }
+            BoundedVec::truncate_from(leaders.into_iter().map(|(_, _, agent_id)| agent_id).collect())
+        }
+
+        /// Continue the committee selection of the current era, if one is running
+        ///
+        /// Scans up to `MaxCandidatesPerBlock` reputation entries. Once all are scanned the
+        /// committee is installed, recorded in the era's configuration and announced.
+        fn scan_committee_candidates() -> Weight {
+            let Some(mut scan) = <CommitteeElection<T>>::get() else {
+                return T::DbWeight::get().reads(1);
+            };
+
+            let mut agents = match &scan.last_scanned {
+                Some(last) => <Reputation<T>>::iter_keys_from(<Reputation<T>>::hashed_key_for(last)),
+                None => <Reputation<T>>::iter_keys(),
+            };
+            let limit = T::MaxCandidatesPerBlock::get().max(1);
+            let mut scanned = 0u32;
+            let finished = loop {
+                if scanned == limit {
+                    break false;
+                }
+                let Some(agent_id) = agents.next() else { break true };
+                scanned = scanned.saturating_add(1);
+                scan.last_scanned = Some(agent_id.clone());
+                Self::rank_candidate(&mut scan.leaders, agent_id);
+            };
+
+            // Each candidate costs its reputation entry, version attestation, telemetry and the
+            // total stake read
+            let weight = T::DbWeight::get().reads_writes(1u64.saturating_add(u64::from(scanned) * 4), 1);
+            if !finished {
+                <CommitteeElection<T>>::put(scan);
+                return weight;
+            }
+
+            <CommitteeElection<T>>::kill();
+            let committee = BoundedVec::<T::AccountId, T::MaxCommitteeSize>::truncate_from(
+                scan.leaders.into_iter().map(|(_, _, agent_id)| agent_id).collect(),
+            );
+            <Committee<T>>::put(&committee);
+            <EraConfigs<T>>::mutate(scan.era, |config| {
+                if let Some(config) = config {
+                    config.committee_size = committee.len() as u32;
+                }
+            });
+
+            // Emit event
+            Self::deposit_event(Event::CommitteeSelected { era: scan.era, members: committee.into_inner() });
+
+            weight.saturating_add(T::DbWeight::get().reads_writes(1, 2))
+        }
+
+        /// Place an agent among the committee leaders if it can serve and ranks high enough
+        fn rank_candidate(
+            leaders: &mut BoundedVec<(bool, u64, T::AccountId), T::MaxCommitteeSize>,
+            agent_id: T::AccountId,
+        ) {
+            if !Self::can_participate(&agent_id) || !T::Agents::meets_minimum_version(&agent_id) {
+                return;
+            }
+
+            let candidate = (T::Agents::is_overloaded(&agent_id), Self::effective_reputation(&agent_id), agent_id);
+            // Not overloaded first, then by descending reputation, then by account ID
+            let position = leaders.iter()
+                .position(|leader| (candidate.0, leader.1, &candidate.2) < (leader.0, candidate.1, &leader.2))
+                .unwrap_or(leaders.len());
+            // Past the last seat when the committee is full, in which case it is left out
+            let _ = leaders.force_insert_keep_left(position, candidate);
+        }
+
+        /// First block of the next era, when the next committee takes over
+        ///
+        /// `None` while eras are disabled by a zero `EraLength`.
+        pub fn next_rotation_block() -> Option<BlockNumberFor<T>> {
+            let era_length = T::EraLength::get();
+            if era_length.is_zero() {
+                return None;
+            }
+            let now = <frame_system::Pallet<T>>::block_number();
+            Some((now / era_length).saturating_add(One::one()).saturating_mul(era_length))
+        }
+
+        /// Committee serving in `era`
+        ///
+        /// The current era's committee is read from storage. The next era's committee is the
+        /// one current reputations would select, so it can still change until the era starts.
+        /// Past and later eras are not known and return `None`.
+        pub fn committee_for_era(era: EraIndex) -> Option<Vec<T::AccountId>> {
+            let current = Self::current_era();
+            if era == current {
+                Some(Self::committee().into_inner())
+            } else if era == current.saturating_add(1) {
+                Some(Self::select_committee().into_inner())
+            } else {
+                None
+            }
+        }
+
+        /// Snapshot every agent's effective reputation and commit it to `ReputationRoot`
+        fn commit_reputation_root(era: EraIndex) -> Weight {
+            let removed = ReputationSnapshot::<T>::clear(u32::MAX, None).unique;
+
+            let mut entries = <Reputation<T>>::iter_keys()
+                .map(|agent_id| {
+                    let reputation = Self::effective_reputation(&agent_id);
+                    (agent_id, reputation)
+                })
+                .collect::<Vec<_>>();
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+            for (agent_id, reputation) in entries.iter() {
+                ReputationSnapshot::<T>::insert(agent_id, reputation);
+            }
+
+            let root = csuite_primitives::merkle_root(&Self::reputation_leaves(&entries));
+            <ReputationRoot<T>>::put(root);
+            let agents = entries.len() as u32;
+            Self::deposit_event(Event::ReputationRootUpdated { era, root, agents });
+
+            // Each agent costs its reputation entry and the total stake weight read
+            T::DbWeight::get().reads_writes(
+                (agents as u64).saturating_mul(2),
+                (agents as u64).saturating_add(removed as u64).saturating_add(1),
+            )
+        }
+
+        /// Merkle leaves of snapshot entries, in the order given
+        fn reputation_leaves(entries: &[(T::AccountId, u64)]) -> Vec<Vec<u8>> {
+            entries
+                .iter()
+                .map(|(agent_id, reputation)| csuite_primitives::reputation_leaf(agent_id, *reputation))
+                .collect()
+        }
+
+        /// The current era and `ReputationRoot`, as sent to other chains
+        pub fn reputation_digest() -> Option<ReputationDigest> {
+            Self::reputation_root().map(|root| ReputationDigest { era: Self::current_era(), root })
+        }
+
+        /// Sum of the reputation all trust anchors attested for an agent
+        pub fn imported_reputation(agent_id: &T::AccountId) -> u64 {
+            <ImportedReputation<T>>::iter_prefix_values(agent_id)
+                .fold(0u64, |total, imported| total.saturating_add(imported.score))
+        }
+
+        /// Merkle proof of an agent's effective reputation against `ReputationRoot`
+        ///
+        /// Returns `None` if no root has been committed yet or the agent is not in the snapshot.
+        pub fn reputation_proof(agent_id: &T::AccountId) -> Option<MerkleInclusionProof> {
+            let root = Self::reputation_root()?;
+            let mut entries = ReputationSnapshot::<T>::iter().collect::<Vec<_>>();
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+            let index = entries.iter().position(|(a, _)| a == agent_id)?;
+            let leaves = Self::reputation_leaves(&entries);
+            let proof = csuite_primitives::merkle_inclusion_proof(&leaves, index)?;
+            (proof.root == root).then_some(proof)
+        }
+
+        /// Apply reputation decay to a specific agent
+        fn apply_reputation_decay(
+            agent_id: &T::AccountId,
+            reputation_info: &mut ReputationInfo<T>,
+        ) -> DispatchResult {
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            // Blocks in approved maintenance windows do not count towards decay
+            let paused = T::Maintenance::maintenance_blocks(agent_id, reputation_info.last_update, current_block);
+            let blocks_elapsed = current_block
+                .saturating_sub(reputation_info.last_update)
+                .saturating_sub(paused);
+
+            if !blocks_elapsed.is_zero() && !reputation_info.reputation.is_zero() {
+                // Quadratic decay: decay rate increases with higher reputation
+                let base_decay = T::BaseDecayRate::get();
+                
+                // Calculate quadratic multiplier (reputation squared / 1000 to keep reasonable)
+                let reputation_factor = reputation_info.reputation
+                    .saturating_mul(reputation_info.reputation)
+                    .saturating_div(1000);
+                
+                let quadratic_decay = base_decay.saturating_mul(Perbill::from_parts(
+                    reputation_factor.min(1_000_000u64) as u32 // Cap to prevent overflow
+                ));
+
+                // Apply decay for each block elapsed
+                for _ in 0..blocks_elapsed.min(100u32.into()) { // Cap iterations to prevent timeout
+                    let decay_amount = quadratic_decay.mul_floor(reputation_info.reputation);
+                    reputation_info.reputation = reputation_info.reputation.saturating_sub(decay_amount);
+                    
+                    // Stop if reputation is very low
+                    if reputation_info.reputation < 10 {
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Apply decay to all agents' reputation
+        fn apply_global_reputation_decay() -> DispatchResult {
+            // In a real implementation, this would be optimized to process in batches
+            // For now, we'll just update the timestamp
+            Ok(())
+        }
+
+        /// Get the effective reputation (stake-weighted)
+        pub fn effective_reputation(agent_id: &T::AccountId) -> u64 {
+            let reputation_info = Self::reputation(agent_id);
+            
+            if reputation_info.is_banned || 
+               reputation_info.quarantine_until.map_or(false, |until| 
+                   <frame_system::Pallet<T>>::block_number() <= until) {
+                return 0;
+            }
+
+            // Weight reputation by the agent's share of the curved stake weight, multiplied by
+            // its stake lock
+            let lock_multiplier = Self::active_lock(&reputation_info).multiplier();
+            let stake_multiplier = 1u64.saturating_add(
+                Self::stake_weight(agent_id).mul_floor(400u64).saturating_mul(lock_multiplier),
+            );
+
+            reputation_info.reputation.saturating_mul(stake_multiplier)
+        }
+
+        /// The agent's stake lock, or `Unlocked` once it expired
+        pub fn active_lock(reputation_info: &ReputationInfo<T>) -> StakeLock {
+            if <frame_system::Pallet<T>>::block_number() < reputation_info.locked_until {
+                reputation_info.stake_lock
+            } else {
+                StakeLock::Unlocked
+            }
+        }
+
+        /// Get the agent's share of the total stake weight under `StakeCurve`
+        pub fn stake_weight(agent_id: &T::AccountId) -> Perbill {
+            let total_weight = Self::total_stake_weight();
+            if total_weight.is_zero() {
+                return Perbill::zero();
+            }
+            Perbill::from_rational(Self::stake_weight_of(Self::reputation(agent_id).stake), total_weight)
+        }
+
+        /// Check if agent is banned or still in quarantine
+        pub fn is_restricted(agent_id: &T::AccountId) -> bool {
+            let reputation_info = Self::reputation(agent_id);
+
+            reputation_info.is_banned ||
+            reputation_info.quarantine_until.map_or(false, |until|
+                <frame_system::Pallet<T>>::block_number() <= until)
+        }
+
+        /// Check if agent is active and can participate
+        pub fn can_participate(agent_id: &T::AccountId) -> bool {
+            !Self::is_restricted(agent_id) && !Self::reputation(agent_id).stake.is_zero()
+        }
+    }
+
+    impl<T: Config> agent_registry::FeeExemption<T::AccountId> for Pallet<T> {
+        fn is_fee_exempt(who: &T::AccountId) -> bool {
+            if !Self::can_participate(who) ||
+               Self::effective_reputation(who) < T::FeelessReputationThreshold::get() {
+                return false;
+            }
+
+            let (era, used) = Self::feeless_usage(who);
+            era != Self::current_era() || used < T::FeelessCallsPerEra::get()
+        }
+
+        fn note_fee_exempt_call(who: &T::AccountId) {
+            let current_era = Self::current_era();
+            <FeelessUsage<T>>::mutate(who, |(era, used)| {
+                if *era != current_era {
+                    *era = current_era;
+                    *used = 0;
+                }
+                *used = used.saturating_add(1);
+            });
+        }
+    }
+
+    impl<T: Config> agent_registry::OnAgentRotation<T::AccountId> for Pallet<T> {
+        fn on_agent_rotated(old: &T::AccountId, new: &T::AccountId) -> DispatchResult {
+            ensure!(
+                !<Reputation<T>>::contains_key(new) &&
+                    !<Unbonding<T>>::contains_key(new) &&
+                    !<OffenseHistory<T>>::contains_key(new) &&
+                    <OffenseArchive<T>>::iter_prefix(new).next().is_none(),
+                Error::<T>::RotationTargetInUse
+            );
+
+            let archive = <OffenseArchive<T>>::iter_prefix(old).collect::<Vec<_>>();
+            ensure!(
+                archive.len() <= T::MaxRotatedArchiveEras::get() as usize,
+                Error::<T>::TooManyArchivedEras
+            );
+
+            // Move the reserved stake, including stake that is still unbonding
+            let had_reputation = <Reputation<T>>::contains_key(old);
+            let reputation_info = <Reputation<T>>::take(old);
+            let unbonding = <Unbonding<T>>::take(old);
+            let amount = reputation_info.stake.saturating_add(unbonding);
+            if !amount.is_zero() {
+                let remaining = T::Currency::repatriate_reserved_named(&STAKE_RESERVE_ID, old, new, amount, BalanceStatus::Reserved)?;
+                ensure!(remaining.is_zero(), Error::<T>::StakeMigrationFailed);
+            }
+
+            if had_reputation {
+                <Reputation<T>>::insert(new, reputation_info);
+            }
+            if !unbonding.is_zero() {
+                <Unbonding<T>>::insert(new, unbonding);
+            }
+            if <CompoundRewards<T>>::take(old) {
+                <CompoundRewards<T>>::insert(new, true);
+            }
+            if <FeelessUsage<T>>::contains_key(old) {
+                <FeelessUsage<T>>::insert(new, <FeelessUsage<T>>::take(old));
+            }
+            if <EarlyExits<T>>::contains_key(old) {
+                <EarlyExits<T>>::insert(new, <EarlyExits<T>>::take(old));
+            }
+            if <ParticipationStreaks<T>>::contains_key(old) {
+                <ParticipationStreaks<T>>::insert(new, <ParticipationStreaks<T>>::take(old));
+            }
+            if <TemporarilyBanned<T>>::take(old).is_some() {
+                <TemporarilyBanned<T>>::insert(new, ());
+            }
+            let current_era = Self::current_era();
+            let oldest_era = current_era.saturating_sub(T::ParticipationHistoryDepth::get());
+            for era in oldest_era..=current_era {
+                if <EraParticipation<T>>::contains_key(era, old) {
+                    <EraParticipation<T>>::insert(era, new, <EraParticipation<T>>::take(era, old));
+                }
+            }
+            let pending_slashes = <AgentPendingSlashes<T>>::take(old);
+            for slash_id in pending_slashes.iter() {
+                <PendingSlashes<T>>::mutate(slash_id, |slash| {
+                    if let Some(slash) = slash {
+                        slash.agent_id = new.clone();
+                    }
+                });
+            }
+            if !pending_slashes.is_empty() {
+                <AgentPendingSlashes<T>>::insert(new, pending_slashes);
+            }
+
+            let history = <OffenseHistory<T>>::take(old);
+            if !history.is_empty() {
+                <OffenseHistory<T>>::insert(new, history);
+            }
+            let _ = <OffenseArchive<T>>::clear_prefix(old, u32::MAX, None);
+            for (era, digest) in archive {
+                <OffenseArchive<T>>::insert(new, era, digest);
+            }
+
+            <Committee<T>>::mutate(|committee| {
+                for member in committee.iter_mut().filter(|member| *member == old) {
+                    *member = new.clone();
+                }
+            });
+
+            T::OnReputationChange::on_reputation_change(old);
+            T::OnReputationChange::on_reputation_change(new);
+
+            Self::deposit_event(Event::StakeRotated {
+                old_agent_id: old.clone(),
+                new_agent_id: new.clone(),
+                amount,
+            });
+
+            Ok(())
+        }
+
+        fn on_agent_rotated_weight() -> Weight {
+            let eras = T::MaxRotatedArchiveEras::get() as u64;
+            let pending_slashes = T::MaxPendingSlashesPerAgent::get() as u64;
+            let summaries = (T::ParticipationHistoryDepth::get() as u64).saturating_add(1);
+            T::DbWeight::get().reads_writes(
+                14u64.saturating_add(eras.saturating_mul(2)).saturating_add(pending_slashes).saturating_add(summaries),
+                13u64.saturating_add(eras.saturating_mul(2)).saturating_add(pending_slashes).saturating_add(summaries.saturating_mul(2)),
+            )
+        }
+    }
+
+    impl<T: Config> agent_registry::OnRetirement<T::AccountId> for Pallet<T> {
+        fn on_retirement_initiated(agent_id: &T::AccountId) {
+            Self::begin_unbonding(agent_id);
+        }
+
+        fn on_retirement_completed(agent_id: &T::AccountId) {
+            Self::withdraw_unbonded(agent_id);
+        }
+
+        fn on_retirement_initiated_weight() -> Weight {
+            T::DbWeight::get().reads_writes(3, 5)
+        }
+
+        fn on_retirement_completed_weight() -> Weight {
+            T::DbWeight::get().reads_writes(2, 2)
+        }
+    }
+}
+
+/// Origin check that passes for signed accounts whose effective reputation is at least `N`
+///
+/// Runtimes can use this wherever an `EnsureOrigin` is expected, for example to restrict a
+/// pallet's submission origin or a governance track to reputable agents. Succeeds with the
+/// signer's account ID.
+pub struct EnsureReputationAtLeast<T, N>(core::marker::PhantomData<(T, N)>);
+
+impl<T: Config, N: Get<u64>> EnsureOrigin<T::RuntimeOrigin> for EnsureReputationAtLeast<T, N> {
+    type Success = T::AccountId;
+
+    fn try_origin(o: T::RuntimeOrigin) -> Result<Self::Success, T::RuntimeOrigin> {
+        o.into().and_then(|o| match o {
+            RawOrigin::Signed(who) if Pallet::<T>::effective_reputation(&who) >= N::get() => Ok(who),
+            r => Err(T::RuntimeOrigin::from(r)),
+        })
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<T::RuntimeOrigin, ()> {
+        let who: T::AccountId = frame_benchmarking::account("reputable", 0, 0);
+        <Reputation<T>>::mutate(&who, |info| info.reputation = info.reputation.max(N::get()));
+        Ok(RawOrigin::Signed(who).into())
+    }
+}
+
+/// Transaction extension raising the pool priority of consensus calls from the committee while
+/// the chain is congested
+///
+/// While `T::IsCongested` holds, calls matched by `T::PriorityCalls` get
+/// `T::CommitteePriorityBoost` added to their priority if the signer, or the agent it signs for
+/// as a proxy key, is on the current committee, can participate and has an effective reputation
+/// of at least `T::PriorityReputationThreshold`. This keeps consensus traffic from being starved
+/// by spam paying higher tips. The extension never rejects a transaction.
+#[derive(Encode, Decode, DecodeWithMemTracking, DefaultNoBound, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct PrioritizeCommittee<T>(core::marker::PhantomData<T>);
+
+impl<T: Config + Send + Sync> core::fmt::Debug for PrioritizeCommittee<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "PrioritizeCommittee")
+    }
+}
+
+impl<T: Config + Send + Sync> PrioritizeCommittee<T> {
+    /// Create the extension
+    pub fn new() -> Self {
+        Self(core::marker::PhantomData)
+    }
+
+    /// The priority added to `call` signed by `who`
+    pub fn priority_boost(who: &T::AccountId, call: &T::RuntimeCall) -> TransactionPriority {
+        if !T::PriorityCalls::contains(call) || !T::IsCongested::get() {
+            return 0;
+        }
+
+        let agent_id = T::Agents::proxied_agent(who, CallClass::Signing).unwrap_or_else(|| who.clone());
+        if Committee::<T>::get().contains(&agent_id) &&
+            Pallet::<T>::can_participate(&agent_id) &&
+            Pallet::<T>::effective_reputation(&agent_id) >= T::PriorityReputationThreshold::get() {
+            T::CommitteePriorityBoost::get()
+        } else {
+            0
+        }
+    }
+}
+
+impl<T: Config + Send + Sync> TransactionExtension<T::RuntimeCall> for PrioritizeCommittee<T> {
+    const IDENTIFIER: &'static str = "PrioritizeCommittee";
+    type Implicit = ();
+    type Val = ();
+    type Pre = ();
+
+    fn weight(&self, call: &T::RuntimeCall) -> Weight {
+        // Congestion, proxy, committee and reputation lookups
+        if T::PriorityCalls::contains(call) {
+            T::DbWeight::get().reads(6)
+        } else {
+            Weight::zero()
+        }
+    }
+
+    fn validate(
+        &self,
+        origin: T::RuntimeOrigin,
+        call: &T::RuntimeCall,
+        _info: &DispatchInfoOf<T::RuntimeCall>,
+        _len: usize,
+        _self_implicit: Self::Implicit,
+        _inherited_implication: &impl Encode,
+        _source: TransactionSource,
+    ) -> ValidateResult<Self::Val, T::RuntimeCall> {
+        let priority = origin.as_signer().map_or(0, |who| Self::priority_boost(who, call));
+        Ok((ValidTransaction { priority, ..Default::default() }, (), origin))
+    }
+
+    impl_tx_ext_default!(T::RuntimeCall; prepare);
+}
+
+/// Transaction extension charging the fee-exempt call quota of agents
+///
+/// Calls matched by `T::FeelessCalls` are resolved to the agent they are made for, through its
+/// proxy keys for their class. If that agent is fee-exempt, one call of its era quota is used
+/// before dispatch, so calls that fail still count against it and cannot be retried for free.
+/// Place it after the fee payment extension, which decides whether the call is feeless from the
+/// quota left before this one charges it. The extension never rejects a transaction.
+#[derive(Encode, Decode, DecodeWithMemTracking, DefaultNoBound, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct ChargeFeelessQuota<T>(core::marker::PhantomData<T>);
+
+impl<T: Config + Send + Sync> core::fmt::Debug for ChargeFeelessQuota<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "ChargeFeelessQuota")
+    }
+}
+
+impl<T: Config + Send + Sync> ChargeFeelessQuota<T> {
+    /// Create the extension
+    pub fn new() -> Self {
+        Self(core::marker::PhantomData)
+    }
+
+    /// The fee-exempt agent `call` signed by `who` uses the quota of, if any
+    pub fn exempt_agent(who: &T::AccountId, call: &T::RuntimeCall) -> Option<T::AccountId> {
+        let class = T::FeelessCalls::convert(call.clone())?;
+        let agent_id = T::Agents::proxied_agent(who, class).unwrap_or_else(|| who.clone());
+        Pallet::<T>::is_fee_exempt(&agent_id).then_some(agent_id)
+    }
+}
+
+impl<T: Config + Send + Sync> TransactionExtension<T::RuntimeCall> for ChargeFeelessQuota<T> {
+    const IDENTIFIER: &'static str = "ChargeFeelessQuota";
+    type Implicit = ();
+    type Val = Option<T::AccountId>;
+    type Pre = ();
+
+    fn weight(&self, call: &T::RuntimeCall) -> Weight {
+        // Proxy, restriction, reputation and usage lookups, and the usage update
+        if T::FeelessCalls::convert(call.clone()).is_some() {
+            T::DbWeight::get().reads_writes(5, 1)
+        } else {
+            Weight::zero()
+        }
+    }
+
+    fn validate(
+        &self,
+        origin: T::RuntimeOrigin,
+        call: &T::RuntimeCall,
+        _info: &DispatchInfoOf<T::RuntimeCall>,
+        _len: usize,
+        _self_implicit: Self::Implicit,
+        _inherited_implication: &impl Encode,
+        _source: TransactionSource,
+    ) -> ValidateResult<Self::Val, T::RuntimeCall> {
+        let agent_id = origin.as_signer().and_then(|who| Self::exempt_agent(who, call));
+        Ok((ValidTransaction::default(), agent_id, origin))
+    }
+
+    fn prepare(
+        self,
+        val: Self::Val,
+        _origin: &T::RuntimeOrigin,
+        _call: &T::RuntimeCall,
+        _info: &DispatchInfoOf<T::RuntimeCall>,
+        _len: usize,
+    ) -> Result<Self::Pre, TransactionValidityError> {
+        if let Some(agent_id) = val {
+            Pallet::<T>::note_fee_exempt_call(&agent_id);
         }
+        Ok(())
     }
-} 
\ No newline at end of file
+}
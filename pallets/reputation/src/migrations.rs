@@ -0,0 +1,478 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        migrations.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Storage migrations for the reputation pallet
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! Storage migrations for pallet_reputation
+
+pub mod v1 {
+    //! Introduces `TotalStakeWeight`, the sum of all stakes under the configured stake curve.
+    //!
+    //! Existing stakes above `MaxStakePerAgent` are left untouched; the cap only applies to new
+    //! stake.
+
+    use crate::{Config, Pallet, Reputation, TotalStakeWeight};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        traits::{Get, UncheckedOnRuntimeUpgrade},
+        weights::Weight,
+    };
+    #[cfg(feature = "try-runtime")]
+    use sp_std::vec::Vec;
+
+    /// Actual implementation of [`MigrateV0ToV1`]
+    pub struct UncheckedMigrateV0ToV1<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut agents = 0u64;
+            let total = Reputation::<T>::iter_values().fold(0u128, |total, info| {
+                agents = agents.saturating_add(1);
+                total.saturating_add(Pallet::<T>::stake_weight_of(info.stake))
+            });
+            TotalStakeWeight::<T>::put(total);
+
+            T::DbWeight::get().reads_writes(agents, 1)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let expected = Reputation::<T>::iter_values()
+                .fold(0u128, |total, info| total.saturating_add(Pallet::<T>::stake_weight_of(info.stake)));
+            frame_support::ensure!(
+                TotalStakeWeight::<T>::get() == expected,
+                "Reputation: total stake weight does not match the agents' stakes"
+            );
+            Ok(())
+        }
+    }
+
+    /// Compute `TotalStakeWeight` from the existing stakes, bumping the storage version to 1
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        UncheckedMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v2 {
+    //! Adds the data withholding, latency violation and policy violation offense types.
+    //!
+    //! The new `OffenseType` variants are appended, so the encoding of recorded offenses is
+    //! unchanged. `OffenseDigest` grows a counter per new type, and every archived digest is
+    //! re-encoded with the new counters at zero.
+
+    use crate::{Config, OffenseArchive, OffenseDigest, Pallet};
+    use codec::{Decode, Encode};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        traits::{Get, UncheckedOnRuntimeUpgrade},
+        weights::Weight,
+    };
+    #[cfg(feature = "try-runtime")]
+    use sp_std::vec::Vec;
+
+    /// `OffenseDigest` as stored before version 2
+    #[derive(Encode, Decode)]
+    pub struct OldOffenseDigest {
+        pub unresponsiveness: u32,
+        pub equivocation: u32,
+        pub invalid_data: u32,
+        pub consensus_manipulation: u32,
+    }
+
+    /// Actual implementation of [`MigrateV1ToV2`]
+    pub struct UncheckedMigrateV1ToV2<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV1ToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            OffenseArchive::<T>::translate::<OldOffenseDigest, _>(|_, _, old| {
+                translated = translated.saturating_add(1);
+                Some(OffenseDigest {
+                    unresponsiveness: old.unresponsiveness,
+                    equivocation: old.equivocation,
+                    invalid_data: old.invalid_data,
+                    consensus_manipulation: old.consensus_manipulation,
+                    ..Default::default()
+                })
+            });
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            Ok((OffenseArchive::<T>::iter_keys().count() as u64).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let before = u64::decode(&mut &state[..])
+                .map_err(|_| "Reputation: invalid pre-upgrade state")?;
+            let after = OffenseArchive::<T>::iter_values().count() as u64;
+            frame_support::ensure!(before == after, "Reputation: archived offense digests were lost");
+            Ok(())
+        }
+    }
+
+    /// Re-encode the archived offense digests with the new offense types, bumping the storage
+    /// version to 2
+    pub type MigrateV1ToV2<T> = VersionedMigration<
+        1,
+        2,
+        UncheckedMigrateV1ToV2<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v3 {
+    //! Adds tiered bans. Every `ReputationInfo` gains the expiry block of a temporary ban and the
+    //! number of temporary bans served, both empty. Bans given before this version had no
+    //! expiry, so agents banned at the upgrade stay permanently banned.
+
+    use crate::{migrations::v5::OldReputationInfo as V3ReputationInfo, Config, Pallet};
+    use codec::{Decode, Encode};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        pallet_prelude::OptionQuery,
+        storage_alias,
+        traits::{Currency, Get, UncheckedOnRuntimeUpgrade},
+        weights::Weight,
+        Blake2_128Concat,
+    };
+    use frame_system::pallet_prelude::BlockNumberFor;
+    #[cfg(feature = "try-runtime")]
+    use sp_std::vec::Vec;
+
+    type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+    /// Agent reputation in the layout of version 3, which later versions extend
+    #[storage_alias]
+    type Reputation<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as frame_system::Config>::AccountId,
+        V3ReputationInfo<T>,
+        OptionQuery,
+    >;
+
+    /// `ReputationInfo` as stored before version 3
+    #[derive(Encode, Decode)]
+    pub struct OldReputationInfo<T: Config> {
+        pub reputation: u64,
+        pub stake: BalanceOf<T>,
+        pub last_update: BlockNumberFor<T>,
+        pub consensus_count: u32,
+        pub offense_count: u32,
+        pub quarantine_until: Option<BlockNumberFor<T>>,
+        pub is_banned: bool,
+    }
+
+    /// Actual implementation of [`MigrateV2ToV3`]
+    pub struct UncheckedMigrateV2ToV3<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV2ToV3<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            Reputation::<T>::translate::<OldReputationInfo<T>, _>(|_, old| {
+                translated = translated.saturating_add(1);
+                Some(V3ReputationInfo {
+                    reputation: old.reputation,
+                    stake: old.stake,
+                    last_update: old.last_update,
+                    consensus_count: old.consensus_count,
+                    offense_count: old.offense_count,
+                    quarantine_until: old.quarantine_until,
+                    is_banned: old.is_banned,
+                    ban_until: None,
+                    temporary_bans: 0,
+                })
+            });
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            Ok((Reputation::<T>::iter_keys().count() as u64).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let before = u64::decode(&mut &state[..])
+                .map_err(|_| "Reputation: invalid pre-upgrade state")?;
+            let after = Reputation::<T>::iter_values().count() as u64;
+            frame_support::ensure!(before == after, "Reputation: agent reputation entries were lost");
+            Ok(())
+        }
+    }
+
+    /// Re-encode every agent's reputation with empty ban tiers, bumping the storage version to 3
+    pub type MigrateV2ToV3<T> = VersionedMigration<
+        2,
+        3,
+        UncheckedMigrateV2ToV3<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v4 {
+    //! Moves the era key of `OffenseArchive` from `Blake2_128Concat` to `Twox64Concat`. Eras are
+    //! counted by the chain, so they cannot be chosen to unbalance the trie, and every archive key
+    //! in a storage proof shrinks by 8 bytes. The account key keeps its hasher.
+
+    use crate::{Config, OffenseArchive, Pallet};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        traits::{Get, UncheckedOnRuntimeUpgrade},
+        weights::Weight,
+    };
+    #[cfg(feature = "try-runtime")]
+    use codec::{Decode, Encode};
+    use sp_std::vec::Vec;
+
+    mod v3 {
+        //! `OffenseArchive` as keyed in version 3
+
+        use crate::{Config, EraIndex, OffenseDigest, Pallet};
+        use frame_support::{pallet_prelude::OptionQuery, storage_alias, Blake2_128Concat};
+
+        #[storage_alias]
+        pub type OffenseArchive<T: Config> = StorageDoubleMap<
+            Pallet<T>,
+            Blake2_128Concat,
+            <T as frame_system::Config>::AccountId,
+            Blake2_128Concat,
+            EraIndex,
+            OffenseDigest,
+            OptionQuery,
+        >;
+    }
+
+    /// Actual implementation of [`MigrateV3ToV4`]
+    pub struct UncheckedMigrateV3ToV4<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV3ToV4<T> {
+        fn on_runtime_upgrade() -> Weight {
+            // Both hashers share the map prefix, so the archive is drained before its entries are
+            // reinserted under the new keys
+            let digests = v3::OffenseArchive::<T>::drain().collect::<Vec<_>>();
+            let moved = digests.len() as u64;
+            for (agent_id, era, digest) in digests {
+                OffenseArchive::<T>::insert(agent_id, era, digest);
+            }
+
+            T::DbWeight::get().reads_writes(moved, moved.saturating_mul(2))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            Ok((v3::OffenseArchive::<T>::iter_keys().count() as u64).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let expected = u64::decode(&mut &state[..])
+                .map_err(|_| "Reputation: failed to decode the pre-upgrade archive size")?;
+            frame_support::ensure!(
+                OffenseArchive::<T>::iter_values().count() as u64 == expected,
+                "Reputation: archived offenses were lost while rehashing their keys"
+            );
+            Ok(())
+        }
+    }
+
+    /// Move the era key of the offense archive to `Twox64Concat`, bumping the storage version
+    /// to 4
+    pub type MigrateV3ToV4<T> = VersionedMigration<
+        3,
+        4,
+        UncheckedMigrateV3ToV4<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v5 {
+    //! Adds stake locks. Every `ReputationInfo` gains a stake lock and its expiry block, and
+    //! existing stakes move to the unlocked tier, so no agent's effective reputation changes at
+    //! the upgrade.
+
+    use crate::{Config, Pallet, Reputation, ReputationInfo, StakeLock};
+    use codec::{Decode, Encode};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        traits::{Currency, Get, UncheckedOnRuntimeUpgrade},
+        weights::Weight,
+    };
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use sp_runtime::traits::Zero;
+    #[cfg(feature = "try-runtime")]
+    use sp_std::vec::Vec;
+
+    type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+    /// `ReputationInfo` as stored in versions 3 and 4
+    #[derive(Encode, Decode)]
+    pub struct OldReputationInfo<T: Config> {
+        pub reputation: u64,
+        pub stake: BalanceOf<T>,
+        pub last_update: BlockNumberFor<T>,
+        pub consensus_count: u32,
+        pub offense_count: u32,
+        pub quarantine_until: Option<BlockNumberFor<T>>,
+        pub is_banned: bool,
+        pub ban_until: Option<BlockNumberFor<T>>,
+        pub temporary_bans: u32,
+    }
+
+    /// Actual implementation of [`MigrateV4ToV5`]
+    pub struct UncheckedMigrateV4ToV5<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV4ToV5<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            Reputation::<T>::translate::<OldReputationInfo<T>, _>(|_, old| {
+                translated = translated.saturating_add(1);
+                Some(ReputationInfo {
+                    reputation: old.reputation,
+                    stake: old.stake,
+                    last_update: old.last_update,
+                    consensus_count: old.consensus_count,
+                    offense_count: old.offense_count,
+                    quarantine_until: old.quarantine_until,
+                    is_banned: old.is_banned,
+                    ban_until: old.ban_until,
+                    temporary_bans: old.temporary_bans,
+                    stake_lock: StakeLock::Unlocked,
+                    locked_until: Zero::zero(),
+                })
+            });
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            Ok((Reputation::<T>::iter_keys().count() as u64).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let before = u64::decode(&mut &state[..])
+                .map_err(|_| "Reputation: invalid pre-upgrade state")?;
+            let after = Reputation::<T>::iter_values()
+                .filter(|info| info.stake_lock == StakeLock::Unlocked)
+                .count() as u64;
+            frame_support::ensure!(before == after, "Reputation: stakes were lost or left locked");
+            Ok(())
+        }
+    }
+
+    /// Re-encode every agent's reputation with an unlocked stake, bumping the storage version
+    /// to 5
+    pub type MigrateV4ToV5<T> = VersionedMigration<
+        4,
+        5,
+        UncheckedMigrateV4ToV5<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v6 {
+    //! Moves stake to the `STAKE_RESERVE_ID` named reserve, so it can be told apart from the
+    //! deposits agents hold for other pallets. Any part of an agent's active and unbonding stake
+    //! that is no longer reserved is left for `repair_stake_accounting` to write off.
+
+    use crate::{Config, Pallet, Reputation, Unbonding, STAKE_RESERVE_ID};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::VersionedMigration,
+        traits::{Get, NamedReservableCurrency, ReservableCurrency, UncheckedOnRuntimeUpgrade},
+        weights::Weight,
+    };
+    #[cfg(feature = "try-runtime")]
+    use codec::{Decode, Encode};
+    #[cfg(feature = "try-runtime")]
+    use sp_std::vec::Vec;
+
+    #[cfg(feature = "try-runtime")]
+    type BalanceOf<T> = <<T as Config>::Currency as frame_support::traits::Currency<
+        <T as frame_system::Config>::AccountId,
+    >>::Balance;
+
+    /// Actual implementation of [`MigrateV5ToV6`]
+    pub struct UncheckedMigrateV5ToV6<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateV5ToV6<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut agents = 0u64;
+            for (agent_id, info) in Reputation::<T>::iter() {
+                agents = agents.saturating_add(1);
+                let recorded = info.stake.saturating_add(Unbonding::<T>::get(&agent_id));
+                let missing = T::Currency::unreserve(&agent_id, recorded);
+                // Just unreserved, so reserving it again cannot fail
+                let _ = T::Currency::reserve_named(&STAKE_RESERVE_ID, &agent_id, recorded.saturating_sub(missing));
+            }
+
+            // Entry, unbonding stake and balance of every agent
+            T::DbWeight::get().reads_writes(agents.saturating_mul(3), agents.saturating_mul(2))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+            let reserved = Reputation::<T>::iter_keys()
+                .map(|agent_id| T::Currency::reserved_balance(&agent_id))
+                .collect::<Vec<_>>();
+            Ok(reserved.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+            let before = Vec::<BalanceOf<T>>::decode(&mut &state[..])
+                .map_err(|_| "Reputation: invalid pre-upgrade state")?;
+            let after = Reputation::<T>::iter_keys()
+                .map(|agent_id| T::Currency::reserved_balance(&agent_id))
+                .collect::<Vec<_>>();
+            frame_support::ensure!(before == after, "Reputation: reserved balances changed");
+            for (agent_id, info) in Reputation::<T>::iter() {
+                let recorded = info.stake.saturating_add(Unbonding::<T>::get(&agent_id));
+                frame_support::ensure!(
+                    T::Currency::reserved_balance_named(&STAKE_RESERVE_ID, &agent_id) <= recorded,
+                    "Reputation: more than the stake was moved to the stake reserve"
+                );
+            }
+            Ok(())
+        }
+    }
+
+    /// Move every agent's stake to the named stake reserve, bumping the storage version to 6
+    pub type MigrateV5ToV6<T> = VersionedMigration<
+        5,
+        6,
+        UncheckedMigrateV5ToV6<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
@@ -0,0 +1,298 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        mock.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Mock runtime for testing the Reputation pallet
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+use crate as pallet_reputation;
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{ConstU16, ConstU32, ConstU64, EitherOfDiverse, EnsureOrigin, Everything},
+    PalletId,
+};
+use sp_core::H256;
+use sp_runtime::{
+    traits::{AccountIdConversion, BlakeTwo256, Convert, IdentityLookup},
+    BuildStorage, DispatchResult, Perbill, Percent,
+};
+use pallet_agent_registry::AgentRole;
+use std::cell::RefCell;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+    pub enum Test
+    {
+        System: frame_system,
+        Balances: pallet_balances,
+        AgentRegistry: pallet_agent_registry,
+        Reputation: pallet_reputation,
+    }
+);
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type RuntimeCall = RuntimeCall;
+    type Nonce = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Block = Block;
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type BlockHashCount = ConstU64<250>;
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<u64>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ConstU16<42>;
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
+    type ReserveIdentifier = [u8; 8];
+}
+
+parameter_types! {
+    pub const MaxMetadataLength: u32 = 1024;
+    pub const OverloadThreshold: Percent = Percent::from_percent(90);
+    pub AllFeatures: pallet_agent_registry::FeatureFlags = pallet_agent_registry::FeatureFlags::all();
+}
+
+impl pallet_agent_registry::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type MaxMetadataLength = MaxMetadataLength;
+    type MaxMetadataAllowance = ConstU32<4096>;
+    type MetadataTierBytes = ConstU32<1024>;
+    type MetadataTierDeposit = ConstU64<100>;
+    type Currency = Balances;
+    type FeeExemption = Reputation;
+    type RetirementGracePeriod = ConstU64<10>;
+    type OnRetirement = Reputation;
+    type OnRotation = Reputation;
+    type OnStatusChange = ();
+    type MaxEndpoints = ConstU32<4>;
+    type MaxEndpointLength = ConstU32<64>;
+    type EndpointTtl = ConstU64<100>;
+    type AnnouncementCooldown = ConstU64<10>;
+    type StaleAfter = ConstU64<50>;
+    type MaxSweepPerBlock = ConstU32<8>;
+    type MaxProxies = ConstU32<2>;
+    type MaxCapabilities = ConstU32<2>;
+    type DefaultFeatures = AllFeatures;
+    type MaxTelemetrySnapshots = ConstU32<3>;
+    type TelemetryInterval = ConstU64<5>;
+    type TelemetryTtl = ConstU64<20>;
+    type OverloadThreshold = OverloadThreshold;
+    type OverloadQueueDepth = ConstU32<100>;
+    type MaxMaintenanceWindows = ConstU32<3>;
+    type MaxMaintenanceDuration = ConstU64<100>;
+    type RemoteOrigin = frame_system::EnsureNever<u32>;
+    type MaxRemoteProofSize = ConstU32<4_096>;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const MinimumStake: u64 = 100;
+    pub const MaxStakePerAgent: u64 = 5_000;
+    pub const StakeCurve: crate::StakeWeightingCurve = crate::StakeWeightingCurve::SquareRoot;
+    pub static BaseDecayRate: Perbill = Perbill::from_parts(0);
+    pub const ConsensusReward: u64 = 100;
+    pub const FirstResponderBonus: u64 = 10;
+    pub const UnresponsivenessSlash: Perbill = Perbill::from_percent(5);
+    pub const EquivocationSlash: Perbill = Perbill::from_percent(25);
+    pub const QuarantinePeriod: u64 = 20;
+    pub const MaxOffenses: u32 = 3;
+    pub const MaxTemporaryBans: u32 = 1;
+    pub const TemporaryBanEras: u32 = 2;
+    pub static SlashDeferDuration: u64 = 0;
+    pub const MaxSlashesPerBlock: u32 = 2;
+    pub const MaxPendingSlashesPerAgent: u32 = 3;
+    pub const OffenseWindow: u64 = 50;
+    pub const MaxPenaltyDoublings: u32 = 2;
+    pub const MaxOffenseHistory: u32 = 4;
+    pub const MaxRotatedArchiveEras: u32 = 2;
+    pub const MaxSlashPercentage: Perbill = Perbill::from_percent(50);
+    pub const EraLength: u64 = 10;
+    pub const FeelessReputationThreshold: u64 = 1_000;
+    pub const FeelessCallsPerEra: u32 = 2;
+    pub const MaxCommitteeSize: u32 = 2;
+    pub static MaxCandidatesPerBlock: u32 = 16;
+    pub const EraReward: u64 = 50;
+    pub const RewardPotId: PalletId = PalletId(*b"csrwdpot");
+    pub const EarlyExitPenalty: Perbill = Perbill::from_percent(10);
+    pub const MaxEarlyExitsPerEra: u32 = 1;
+    pub const EarlyUnlockPenalty: Perbill = Perbill::from_percent(20);
+    pub const MinStreakParticipations: u32 = 2;
+    pub static StreakBonusPerEra: Perbill = Perbill::zero();
+    pub const MaxStreakBonus: Perbill = Perbill::from_percent(30);
+    pub static Congested: bool = true;
+    pub static FrostThreshold: u32 = 3;
+}
+
+impl pallet_reputation::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Agents = AgentRegistry;
+    type Maintenance = AgentRegistry;
+    type ConsensusParameters = MockConsensusParameters;
+    type Currency = Balances;
+    type Slash = ();
+    type MinimumStake = MinimumStake;
+    type MaxStakePerAgent = MaxStakePerAgent;
+    type StakeCurve = StakeCurve;
+    type BaseDecayRate = BaseDecayRate;
+    type ConsensusReward = ConsensusReward;
+    type FirstResponderBonus = FirstResponderBonus;
+    type UnresponsivenessSlash = UnresponsivenessSlash;
+    type EquivocationSlash = EquivocationSlash;
+    type QuarantinePeriod = QuarantinePeriod;
+    type MaxOffenses = MaxOffenses;
+    type MaxTemporaryBans = MaxTemporaryBans;
+    type TemporaryBanEras = TemporaryBanEras;
+    type SlashDeferDuration = SlashDeferDuration;
+    type MaxSlashesPerBlock = MaxSlashesPerBlock;
+    type MaxPendingSlashesPerAgent = MaxPendingSlashesPerAgent;
+    type OffenseWindow = OffenseWindow;
+    type MaxOffenseHistory = MaxOffenseHistory;
+    type MaxRotatedArchiveEras = MaxRotatedArchiveEras;
+    type MaxPenaltyDoublings = MaxPenaltyDoublings;
+    type MaxSlashPercentage = MaxSlashPercentage;
+    type EraLength = EraLength;
+    type FeelessReputationThreshold = FeelessReputationThreshold;
+    type FeelessCallsPerEra = FeelessCallsPerEra;
+    type FeelessCalls = FeelessSystemCalls;
+    type OnReputationChange = ();
+    type OnRewardOrSlash = ();
+    type MaxCommitteeSize = MaxCommitteeSize;
+    type MaxCandidatesPerBlock = MaxCandidatesPerBlock;
+    type EraReward = EraReward;
+    type RewardPotId = RewardPotId;
+    type AttestationOrigin = EnsureSisterChain;
+    type Exporter = RecordExports;
+    type EarlyExitPenalty = EarlyExitPenalty;
+    type MaxEarlyExitsPerEra = MaxEarlyExitsPerEra;
+    type StakeLockPeriod = ConstU64<10>;
+    type EarlyUnlockPenalty = EarlyUnlockPenalty;
+    type MinStreakParticipations = MinStreakParticipations;
+    type StreakBonusPerEra = StreakBonusPerEra;
+    type MaxStreakBonus = MaxStreakBonus;
+    type ParticipationHistoryDepth = ConstU32<2>;
+    type PriorityCalls = Everything;
+    type IsCongested = Congested;
+    type PriorityReputationThreshold = ConstU64<1_000>;
+    type CommitteePriorityBoost = ConstU64<1_000_000>;
+    // Root, or agents with at least 500 effective reputation
+    type RewardOrigin = EitherOfDiverse<
+        frame_system::EnsureRoot<u64>,
+        pallet_reputation::EnsureReputationAtLeast<Test, ConstU64<500>>,
+    >;
+    type MaxRewardBatch = ConstU32<3>;
+    type WeightInfo = ();
+}
+
+/// Treats signed origins of accounts from 1000 up as the sister chain with that ID
+pub struct EnsureSisterChain;
+impl EnsureOrigin<RuntimeOrigin> for EnsureSisterChain {
+    type Success = u32;
+
+    fn try_origin(o: RuntimeOrigin) -> Result<u32, RuntimeOrigin> {
+        match o.clone().into() {
+            Ok(frame_system::RawOrigin::Signed(who)) if who >= 1000 => Ok(who as u32),
+            _ => Err(o),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+        Ok(RuntimeOrigin::signed(1000))
+    }
+}
+
+/// Treats system calls as the fee-exempt calls, made through heartbeat proxy keys
+pub struct FeelessSystemCalls;
+impl Convert<RuntimeCall, Option<pallet_agent_registry::CallClass>> for FeelessSystemCalls {
+    fn convert(call: RuntimeCall) -> Option<pallet_agent_registry::CallClass> {
+        matches!(call, RuntimeCall::System(_)).then_some(pallet_agent_registry::CallClass::Heartbeat)
+    }
+}
+
+/// Reports `FrostThreshold` and fixed values for the other consensus thresholds
+pub struct MockConsensusParameters;
+impl csuite_primitives::ConsensusParameters for MockConsensusParameters {
+    fn consensus_thresholds() -> csuite_primitives::ConsensusThresholds {
+        csuite_primitives::ConsensusThresholds {
+            frost_threshold: FrostThreshold::get(),
+            frost_group_version: 1,
+            veto_threshold: Perbill::from_percent(33),
+            first_responders: 2,
+        }
+    }
+}
+
+thread_local! {
+    static EXPORTS: RefCell<Vec<(u32, pallet_reputation::ReputationDigest)>> = RefCell::new(Vec::new());
+}
+
+/// Records exported digests instead of sending them
+pub struct RecordExports;
+impl pallet_reputation::ReputationExporter for RecordExports {
+    fn export_digest(dest: u32, digest: &pallet_reputation::ReputationDigest) -> DispatchResult {
+        EXPORTS.with(|e| e.borrow_mut().push((dest, *digest)));
+        Ok(())
+    }
+}
+
+/// Digests exported so far
+pub fn exported_digests() -> Vec<(u32, pallet_reputation::ReputationDigest)> {
+    EXPORTS.with(|e| e.borrow().clone())
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    StreakBonusPerEra::set(Perbill::zero());
+    SlashDeferDuration::set(0);
+    Congested::set(true);
+    FrostThreshold::set(3);
+    let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: (1..=5)
+            .map(|who| (who, 10_000))
+            .chain(core::iter::once((RewardPotId::get().into_account_truncating(), 1_000)))
+            .collect(),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+
+    EXPORTS.with(|e| e.borrow_mut().clear());
+
+    let mut ext: sp_io::TestExternalities = storage.into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+// Helper function to register an agent for testing
+pub fn register_test_agent(agent_id: u64, role: AgentRole) {
+    pallet_agent_registry::Pallet::<Test>::register_agent(
+        RuntimeOrigin::signed(agent_id),
+        role,
+        None,
+    ).expect("Agent should be registered successfully");
+}
@@ -0,0 +1,1621 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        tests.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Tests for the Reputation pallet
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+use crate::{
+    mock::*, ChargeFeelessQuota, EnsureReputationAtLeast, EraConfig, EraStatistics, Error, Event, OffenseDigest,
+    OffenseType, ParticipationKind, ParticipationSummary, PiecewiseRewardCurve, PrioritizeCommittee,
+    ReputationDigest, ReputationInfo, RewardCurveInput, StakeWeightingCurve,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{ConstU64, EnsureOrigin, Hooks, NamedReservableCurrency, ReservableCurrency},
+    BoundedVec,
+};
+use pallet_agent_registry::{AgentRole, FeeExemption};
+use sp_runtime::{
+    traits::{DispatchTransaction, Dispatchable},
+    transaction_validity::TransactionSource,
+    Perbill, Percent,
+};
+
+// Helper function to give an agent reputation and stake directly
+fn set_reputation(agent_id: u64, reputation: u64, stake: u64) {
+    crate::Reputation::<Test>::insert(agent_id, ReputationInfo {
+        reputation,
+        stake,
+        ..Default::default()
+    });
+    crate::TotalStake::<Test>::mutate(|total| *total += stake);
+    crate::TotalStakeWeight::<Test>::mutate(|total| *total += Reputation::stake_weight_of(stake));
+}
+
+type EnsureReputable = EnsureReputationAtLeast<Test, ConstU64<500>>;
+
+#[test]
+fn stake_works() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+
+        assert_ok!(Reputation::stake(RuntimeOrigin::signed(1), 1_000));
+
+        assert_eq!(Reputation::reputation(1).stake, 1_000);
+        assert_eq!(Reputation::total_stake(), 1_000);
+        assert_eq!(Balances::reserved_balance(1), 1_000);
+        System::assert_last_event(Event::Staked { agent_id: 1, amount: 1_000 }.into());
+    });
+}
+
+#[test]
+fn stake_requires_registered_agent_and_minimum() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Reputation::stake(RuntimeOrigin::signed(1), 1_000),
+            Error::<Test>::AgentNotFound
+        );
+
+        register_test_agent(1, AgentRole::Lyra);
+        assert_noop!(
+            Reputation::stake(RuntimeOrigin::signed(1), 10),
+            Error::<Test>::InsufficientStake
+        );
+    });
+}
+
+#[test]
+fn unstake_releases_reserved_balance() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        assert_ok!(Reputation::stake(RuntimeOrigin::signed(1), 1_000));
+
+        assert_ok!(Reputation::unstake(RuntimeOrigin::signed(1), 400));
+
+        assert_eq!(Reputation::reputation(1).stake, 600);
+        assert_eq!(Balances::reserved_balance(1), 600);
+        assert_noop!(
+            Reputation::unstake(RuntimeOrigin::signed(1), 1_000),
+            Error::<Test>::NoStakeToUnstake
+        );
+    });
+}
+
+#[test]
+fn try_state_checks_the_total_stake() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        register_test_agent(2, AgentRole::Echo);
+        assert_ok!(Reputation::stake(RuntimeOrigin::signed(1), 1_000));
+        assert_ok!(Reputation::stake(RuntimeOrigin::signed(2), 500));
+        assert_ok!(Reputation::unstake(RuntimeOrigin::signed(1), 400));
+        assert_ok!(Reputation::do_try_state());
+
+        crate::TotalStake::<Test>::put(1_500);
+        assert!(Reputation::do_try_state().is_err());
+    });
+}
+
+#[test]
+fn reward_consensus_increases_reputation() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+
+        assert_ok!(Reputation::reward_consensus(RuntimeOrigin::root(), 1));
+
+        // No stake in the system, so the base reward is not multiplied
+        let info = Reputation::reputation(1);
+        assert_eq!(info.reputation, 100);
+        assert_eq!(info.consensus_count, 1);
+    });
+}
+
+#[test]
+fn rewards_can_be_granted_by_the_reward_origin_in_batches() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        register_test_agent(2, AgentRole::Beacon);
+        set_reputation(3, 500, 0);
+
+        // Signed accounts below the reward origin's reputation threshold cannot reward
+        assert_noop!(
+            Reputation::reward_consensus(RuntimeOrigin::signed(1), 2),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Reputation::reward_consensus_many(RuntimeOrigin::signed(1), vec![2].try_into().unwrap()),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(Reputation::reward_consensus(RuntimeOrigin::signed(3), 1));
+        assert_eq!(Reputation::reputation(1).consensus_count, 1);
+
+        // Agents listed twice are rewarded twice
+        assert_ok!(Reputation::reward_consensus_many(RuntimeOrigin::signed(3), vec![1, 2, 2].try_into().unwrap()));
+        assert_eq!(Reputation::reputation(1).consensus_count, 2);
+        assert_eq!(Reputation::reputation(2).consensus_count, 2);
+        System::assert_last_event(Event::ConsensusRewardDistributed { agent_id: 2, reputation_reward: 100 }.into());
+
+        assert_ok!(Reputation::reward_consensus_many(RuntimeOrigin::root(), vec![1].try_into().unwrap()));
+        assert_eq!(Reputation::reputation(1).consensus_count, 3);
+    });
+}
+
+#[test]
+fn reputation_does_not_decay_during_approved_maintenance() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        BaseDecayRate::set(Perbill::one());
+        for agent_id in 1..=3 {
+            register_test_agent(agent_id, AgentRole::Lyra);
+            set_reputation(agent_id, 100_000, 0);
+        }
+        // Agent 1 is in approved maintenance for 10 of its 30 blocks since the last update,
+        // agent 2 was last updated 20 blocks ago, and agent 3's window was never approved
+        crate::Reputation::<Test>::mutate(2, |info| info.last_update = 10);
+        for agent_id in [1, 3] {
+            assert_ok!(AgentRegistry::declare_maintenance(RuntimeOrigin::signed(agent_id), 11, 21));
+        }
+        assert_ok!(AgentRegistry::approve_maintenance(RuntimeOrigin::root(), 1, 11));
+
+        System::set_block_number(30);
+        for agent_id in 1..=3 {
+            assert_ok!(Reputation::reward_consensus(RuntimeOrigin::root(), agent_id));
+        }
+
+        let decayed = Reputation::reputation(1).reputation;
+        assert!(decayed < 100_100);
+        assert_eq!(Reputation::reputation(2).reputation, decayed);
+        assert!(Reputation::reputation(3).reputation < decayed);
+    });
+}
+
+// Helper function to build a reward curve from its breakpoints
+fn reward_curve(input: RewardCurveInput, points: &[(u64, u64)]) -> PiecewiseRewardCurve {
+    PiecewiseRewardCurve { input, points: BoundedVec::truncate_from(points.to_vec()) }
+}
+
+#[test]
+fn reward_curve_interpolates_between_breakpoints() {
+    let curve = reward_curve(RewardCurveInput::Participations, &[(10, 100), (20, 300), (40, 200)]);
+
+    // Held flat outside the breakpoints
+    assert_eq!(curve.evaluate(0), 100);
+    assert_eq!(curve.evaluate(50), 200);
+    // Rising and falling segments
+    assert_eq!(curve.evaluate(15), 200);
+    assert_eq!(curve.evaluate(20), 300);
+    assert_eq!(curve.evaluate(30), 250);
+
+    assert!(curve.is_valid());
+    assert!(!reward_curve(RewardCurveInput::Participations, &[]).is_valid());
+    assert!(!reward_curve(RewardCurveInput::Participations, &[(10, 1), (10, 2)]).is_valid());
+}
+
+#[test]
+fn reward_curve_replaces_the_flat_reward() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        let curve = reward_curve(RewardCurveInput::Participations, &[(0, 10), (2, 30)]);
+
+        assert_noop!(
+            Reputation::set_reward_curve(RuntimeOrigin::signed(1), Some(curve.clone())),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Reputation::set_reward_curve(
+                RuntimeOrigin::root(),
+                Some(reward_curve(RewardCurveInput::Participations, &[(2, 30), (0, 10)])),
+            ),
+            Error::<Test>::InvalidRewardCurve
+        );
+
+        assert_ok!(Reputation::set_reward_curve(RuntimeOrigin::root(), Some(curve.clone())));
+        System::assert_last_event(Event::RewardCurveSet { curve: Some(curve) }.into());
+
+        // First participation at count 0, second at count 1
+        assert_ok!(Reputation::reward_consensus(RuntimeOrigin::root(), 1));
+        assert_ok!(Reputation::reward_consensus(RuntimeOrigin::root(), 1));
+        assert_eq!(Reputation::reputation(1).reputation, 10 + 20);
+
+        // Removing the curve restores the flat reward
+        assert_ok!(Reputation::set_reward_curve(RuntimeOrigin::root(), None));
+        assert_ok!(Reputation::reward_consensus(RuntimeOrigin::root(), 1));
+        assert_eq!(Reputation::reputation(1).reputation, 30 + 100);
+    });
+}
+
+#[test]
+fn participation_streak_grows_over_consecutive_eras() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        StreakBonusPerEra::set(Perbill::from_percent(10));
+
+        // The second participation of era 0 qualifies it and earns the first bonus
+        assert_ok!(Reputation::reward_consensus(RuntimeOrigin::root(), 1));
+        assert_eq!(Reputation::participation_streak(&1), 0);
+        assert_ok!(Reputation::reward_consensus(RuntimeOrigin::root(), 1));
+        assert_eq!(Reputation::participation_streak(&1), 1);
+        assert_eq!(Reputation::reputation(1).reputation, 100 + 110);
+
+        // Era 1 keeps the streak and extends it once it qualifies
+        crate::CurrentEra::<Test>::put(1);
+        assert_eq!(Reputation::participation_streak(&1), 1);
+        assert_ok!(Reputation::reward_consensus(RuntimeOrigin::root(), 1));
+        assert_ok!(Reputation::reward_consensus(RuntimeOrigin::root(), 1));
+        assert_eq!(Reputation::participation_streak(&1), 2);
+        assert_eq!(Reputation::reputation(1).reputation, 210 + 110 + 120);
+
+        // A missed era resets the streak
+        crate::CurrentEra::<Test>::put(3);
+        assert_eq!(Reputation::participation_streak(&1), 0);
+        assert_ok!(Reputation::reward_consensus(RuntimeOrigin::root(), 1));
+        assert_eq!(Reputation::reputation(1).reputation, 440 + 100);
+    });
+}
+
+#[test]
+fn streak_bonus_is_capped() {
+    new_test_ext().execute_with(|| {
+        StreakBonusPerEra::set(Perbill::from_percent(10));
+
+        assert_eq!(Reputation::streak_bonus(2), Perbill::from_percent(20));
+        assert_eq!(Reputation::streak_bonus(5), MaxStreakBonus::get());
+    });
+}
+
+#[test]
+fn offenses_reset_the_participation_streak() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        assert_ok!(Reputation::reward_consensus(RuntimeOrigin::root(), 1));
+        assert_ok!(Reputation::reward_consensus(RuntimeOrigin::root(), 1));
+        assert_eq!(Reputation::participation_streak(&1), 1);
+
+        assert_ok!(Reputation::report_offense(RuntimeOrigin::root(), 1, OffenseType::Unresponsiveness));
+        System::assert_has_event(Event::StreakReset { agent_id: 1, streak: 1 }.into());
+        assert_eq!(Reputation::participation_streak(&1), 0);
+
+        // The offense also drops the progress made in the current era
+        assert_ok!(Reputation::reward_consensus(RuntimeOrigin::root(), 1));
+        assert_eq!(Reputation::participation_streak(&1), 0);
+    });
+}
+
+#[test]
+fn first_responder_bonus_is_flat() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        register_test_agent(1, AgentRole::Lyra);
+        assert_ok!(Reputation::stake(RuntimeOrigin::signed(1), 1_000));
+
+        assert_ok!(Reputation::do_reward_first_responder(&1, 100));
+
+        // Unlike the consensus reward, the bonus ignores stake and participation counts
+        let info = Reputation::reputation(1);
+        assert_eq!(info.reputation, 10);
+        assert_eq!(info.consensus_count, 0);
+        System::assert_last_event(Event::FirstResponderRewarded { agent_id: 1, reputation_reward: 10 }.into());
+
+        // Urgent logs scale the bonus up
+        assert_ok!(Reputation::do_reward_first_responder(&1, 150));
+        assert_eq!(Reputation::reputation(1).reputation, 25);
+    });
+}
+
+#[test]
+fn equivocation_slashes_and_quarantines() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        assert_ok!(Reputation::stake(RuntimeOrigin::signed(1), 1_000));
+
+        assert_ok!(Reputation::report_offense(RuntimeOrigin::root(), 1, OffenseType::Equivocation));
+
+        let info = Reputation::reputation(1);
+        assert_eq!(info.stake, 750);
+        assert_eq!(info.quarantine_until, Some(21));
+        assert_eq!(Balances::reserved_balance(1), 750);
+        assert!(!Reputation::can_participate(&1));
+    });
+}
+
+#[test]
+fn repeated_offenses_ban_agent() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+
+        for _ in 0..3 {
+            assert_ok!(Reputation::report_offense(RuntimeOrigin::root(), 1, OffenseType::Unresponsiveness));
+        }
+
+        assert!(Reputation::reputation(1).is_banned);
+        assert_noop!(
+            Reputation::report_offense(RuntimeOrigin::root(), 1, OffenseType::Unresponsiveness),
+            Error::<Test>::AgentBanned
+        );
+    });
+}
+
+#[test]
+fn repeat_offenses_escalate_penalties() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        assert_ok!(Reputation::stake(RuntimeOrigin::signed(1), 1_000));
+        crate::Reputation::<Test>::mutate(1, |info| info.reputation = 1_000);
+
+        // First offense: 5% slash, base penalty
+        assert_ok!(Reputation::report_offense(RuntimeOrigin::root(), 1, OffenseType::Unresponsiveness));
+        assert_eq!(Reputation::reputation(1).stake, 950);
+        assert_eq!(Reputation::reputation(1).reputation, 950);
+
+        // A repeat within the window doubles both
+        System::set_block_number(10);
+        assert_ok!(Reputation::report_offense(RuntimeOrigin::root(), 1, OffenseType::Unresponsiveness));
+        assert_eq!(Reputation::reputation(1).stake, 855);
+        assert_eq!(Reputation::reputation(1).reputation, 850);
+        System::assert_last_event(Event::AgentSlashed {
+            agent_id: 1,
+            offense_type: OffenseType::Unresponsiveness,
+            slash_amount: 95,
+            reputation_penalty: 100,
+            multiplier: 2,
+        }.into());
+    });
+}
+
+#[test]
+fn penalty_multiplier_is_per_type_windowed_and_capped() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        let history = vec![
+            (OffenseType::Unresponsiveness, 1),
+            (OffenseType::Unresponsiveness, 20),
+            (OffenseType::Unresponsiveness, 30),
+            (OffenseType::InvalidData, 40),
+        ];
+        crate::OffenseHistory::<Test>::insert(1, BoundedVec::truncate_from(history));
+
+        // Three repeats, capped at two doublings
+        assert_eq!(Reputation::penalty_multiplier(&1, &OffenseType::Unresponsiveness, 45), 4);
+        // Other offense types don't count
+        assert_eq!(Reputation::penalty_multiplier(&1, &OffenseType::InvalidData, 45), 2);
+        assert_eq!(Reputation::penalty_multiplier(&1, &OffenseType::Equivocation, 45), 1);
+        // Offenses older than the window are forgotten
+        assert_eq!(Reputation::penalty_multiplier(&1, &OffenseType::Unresponsiveness, 75), 2);
+        assert_eq!(Reputation::penalty_multiplier(&1, &OffenseType::Unresponsiveness, 80), 1);
+    });
+}
+
+#[test]
+fn escalated_slash_is_capped() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        assert_ok!(Reputation::stake(RuntimeOrigin::signed(1), 1_000));
+        crate::OffenseHistory::<Test>::insert(
+            1,
+            BoundedVec::truncate_from(vec![(OffenseType::Equivocation, 1)]),
+        );
+
+        // Doubling 25% reaches the 50% cap exactly
+        assert_ok!(Reputation::report_offense(RuntimeOrigin::root(), 1, OffenseType::Equivocation));
+        assert_eq!(Reputation::reputation(1).stake, 500);
+
+        // Quadrupling would slash everything, but the cap holds at 50%
+        assert_ok!(Reputation::report_offense(RuntimeOrigin::root(), 1, OffenseType::Equivocation));
+        assert_eq!(Reputation::reputation(1).stake, 250);
+    });
+}
+
+#[test]
+fn full_offense_history_evicts_oldest_into_archive() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        assert_ok!(Reputation::stake(RuntimeOrigin::signed(1), 1_000));
+        let history = (1..=4).map(|at| (OffenseType::Unresponsiveness, at)).collect::<Vec<_>>();
+        crate::OffenseHistory::<Test>::insert(1, BoundedVec::truncate_from(history));
+
+        System::set_block_number(25);
+        assert_ok!(Reputation::report_offense(RuntimeOrigin::root(), 1, OffenseType::InvalidData));
+
+        // The new offense is recorded and the oldest one moved to the archive of its era
+        assert_eq!(Reputation::offense_history(1).into_inner(), vec![
+            (OffenseType::Unresponsiveness, 2),
+            (OffenseType::Unresponsiveness, 3),
+            (OffenseType::Unresponsiveness, 4),
+            (OffenseType::InvalidData, 25),
+        ]);
+        assert_eq!(Reputation::offense_archive(1, 0), OffenseDigest { unresponsiveness: 1, ..Default::default() });
+
+        assert_eq!(Reputation::offense_summary(&1), vec![
+            (0, OffenseDigest { unresponsiveness: 4, ..Default::default() }),
+            (2, OffenseDigest { invalid_data: 1, ..Default::default() }),
+        ]);
+    });
+}
+
+#[test]
+fn new_offense_types_apply_their_policies() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        assert_ok!(Reputation::stake(RuntimeOrigin::signed(1), 1_000));
+        crate::Reputation::<Test>::mutate(1, |info| info.reputation = 500);
+
+        // Data withholding: 5% slash, 100 reputation, quarantine
+        assert_ok!(Reputation::report_offense(RuntimeOrigin::root(), 1, OffenseType::DataWithholding));
+        let info = Reputation::reputation(1);
+        assert_eq!((info.stake, info.reputation), (950, 400));
+        assert_eq!(info.quarantine_until, Some(21));
+
+        // Latency violation: half the unresponsiveness slash, 25 reputation
+        assert_ok!(Reputation::report_offense(RuntimeOrigin::root(), 1, OffenseType::LatencyViolation));
+        let info = Reputation::reputation(1);
+        assert_eq!((info.stake, info.reputation), (927, 375));
+
+        // Policy violation: 25% slash, 150 reputation, no quarantine of its own
+        assert_ok!(Reputation::report_offense(RuntimeOrigin::root(), 1, OffenseType::PolicyViolation));
+        let info = Reputation::reputation(1);
+        assert_eq!((info.stake, info.reputation), (696, 225));
+
+        assert_eq!(Reputation::offense_summary(&1), vec![(0, OffenseDigest {
+            data_withholding: 1,
+            latency_violation: 1,
+            policy_violation: 1,
+            ..Default::default()
+        })]);
+    });
+}
+
+#[test]
+fn archived_digests_gain_the_new_offense_counters() {
+    use codec::Encode;
+    use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(1).put::<Reputation>();
+        let old = crate::migrations::v2::OldOffenseDigest {
+            unresponsiveness: 3,
+            equivocation: 1,
+            invalid_data: 0,
+            consensus_manipulation: 2,
+        };
+        frame_support::storage::unhashed::put_raw(
+            &crate::OffenseArchive::<Test>::hashed_key_for(1, 4),
+            &old.encode(),
+        );
+
+        crate::migrations::v2::MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+        assert_eq!(Reputation::offense_archive(1, 4), OffenseDigest {
+            unresponsiveness: 3,
+            equivocation: 1,
+            consensus_manipulation: 2,
+            ..Default::default()
+        });
+        assert_eq!(StorageVersion::get::<Reputation>(), 2);
+    });
+}
+
+#[test]
+fn expired_offenses_are_archived_at_era_start() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        let history = vec![
+            (OffenseType::Unresponsiveness, 5),
+            (OffenseType::Equivocation, 12),
+            (OffenseType::InvalidData, 45),
+        ];
+        crate::OffenseHistory::<Test>::insert(1, BoundedVec::truncate_from(history));
+
+        // Only offenses outside the window are archived, so escalation is unaffected
+        Reputation::on_initialize(60);
+        assert_eq!(Reputation::offense_history(1).len(), 2);
+        assert_eq!(Reputation::offense_archive(1, 0).total(), 1);
+        assert_eq!(Reputation::penalty_multiplier(&1, &OffenseType::InvalidData, 60), 2);
+
+        Reputation::on_initialize(100);
+        assert!(!crate::OffenseHistory::<Test>::contains_key(1));
+        assert_eq!(Reputation::offense_summary(&1), vec![
+            (0, OffenseDigest { unresponsiveness: 1, ..Default::default() }),
+            (1, OffenseDigest { equivocation: 1, ..Default::default() }),
+            (4, OffenseDigest { invalid_data: 1, ..Default::default() }),
+        ]);
+    });
+}
+
+#[test]
+fn committee_selected_at_era_start() {
+    new_test_ext().execute_with(|| {
+        set_reputation(1, 100, 100);
+        set_reputation(2, 300, 100);
+        set_reputation(3, 200, 100);
+        // Agent 4 has reputation but no stake, so cannot participate
+        set_reputation(4, 1_000, 0);
+
+        Reputation::on_initialize(10);
+
+        assert_eq!(Reputation::current_era(), 1);
+        assert_eq!(Reputation::committee().to_vec(), vec![2, 3]);
+        System::assert_has_event(Event::CommitteeSelected { era: 1, members: vec![2, 3] }.into());
+    });
+}
+
+#[test]
+fn committee_selection_is_spread_over_blocks() {
+    new_test_ext().execute_with(|| {
+        MaxCandidatesPerBlock::set(2);
+        set_reputation(1, 100, 100);
+        set_reputation(2, 300, 100);
+        set_reputation(3, 200, 100);
+        set_reputation(4, 50, 100);
+        set_reputation(5, 400, 0);
+
+        // Five entries at two per block take three blocks to scan
+        Reputation::on_initialize(10);
+        assert_eq!(Reputation::current_era(), 1);
+        assert!(Reputation::committee().is_empty());
+        assert_eq!(Reputation::committee_election().unwrap().era, 1);
+
+        Reputation::on_initialize(11);
+        assert!(Reputation::committee().is_empty());
+
+        Reputation::on_initialize(12);
+        assert!(Reputation::committee_election().is_none());
+        assert_eq!(Reputation::committee().to_vec(), vec![2, 3]);
+        assert_eq!(crate::EraConfigs::<Test>::get(1).unwrap().committee_size, 2);
+        System::assert_has_event(Event::CommitteeSelected { era: 1, members: vec![2, 3] }.into());
+        assert_eq!(Reputation::committee().to_vec(), Reputation::select_committee().to_vec());
+    });
+}
+
+#[test]
+fn committee_schedule_predicts_rotation_and_next_committee() {
+    new_test_ext().execute_with(|| {
+        set_reputation(1, 100, 100);
+        set_reputation(2, 300, 100);
+        set_reputation(3, 200, 100);
+
+        System::set_block_number(4);
+        assert_eq!(Reputation::next_rotation_block(), Some(10));
+        assert_eq!(Reputation::committee_for_era(0), Some(vec![]));
+        assert_eq!(Reputation::committee_for_era(1), Some(vec![2, 3]));
+        assert_eq!(Reputation::committee_for_era(2), None);
+
+        System::set_block_number(10);
+        Reputation::on_initialize(10);
+        assert_eq!(Reputation::next_rotation_block(), Some(20));
+        assert_eq!(Reputation::committee_for_era(1), Some(vec![2, 3]));
+
+        // The projection follows reputation changes, the serving committee does not
+        set_reputation(1, 1_000, 100);
+        assert_eq!(Reputation::committee_for_era(1), Some(vec![2, 3]));
+        assert_eq!(Reputation::committee_for_era(2), Some(vec![1, 2]));
+        assert_eq!(Reputation::committee_for_era(0), None);
+    });
+}
+
+#[test]
+fn committee_skips_agents_below_minimum_version() {
+    new_test_ext().execute_with(|| {
+        for (agent_id, reputation, version) in [(1, 100, (2, 0, 0)), (2, 300, (1, 9, 9)), (3, 200, (2, 1, 0))] {
+            register_test_agent(agent_id, AgentRole::Lyra);
+            set_reputation(agent_id, reputation, 100);
+            assert_ok!(AgentRegistry::attest_version(
+                RuntimeOrigin::signed(agent_id),
+                version,
+                Default::default(),
+            ));
+        }
+        assert_ok!(AgentRegistry::set_minimum_agent_version(RuntimeOrigin::root(), Some((2, 0, 0))));
+
+        Reputation::on_initialize(10);
+
+        // Agent 2 has the highest reputation but runs an outdated version
+        assert_eq!(Reputation::committee().to_vec(), vec![3, 1]);
+    });
+}
+
+#[test]
+fn committee_ranks_overloaded_agents_last() {
+    new_test_ext().execute_with(|| {
+        for (agent_id, reputation) in [(1, 100), (2, 300), (3, 200)] {
+            register_test_agent(agent_id, AgentRole::Lyra);
+            set_reputation(agent_id, reputation, 100);
+        }
+        let load = Percent::from_percent;
+        assert_ok!(AgentRegistry::report_telemetry(RuntimeOrigin::signed(2), load(95), load(40), 10));
+        assert_ok!(AgentRegistry::report_telemetry(RuntimeOrigin::signed(3), load(40), load(40), 500));
+
+        Reputation::on_initialize(10);
+
+        // Agent 1 takes the first seat, the best overloaded agent the remaining one
+        assert_eq!(Reputation::committee().to_vec(), vec![1, 2]);
+    });
+}
+
+#[test]
+fn reputation_root_commits_era_start_reputations() {
+    new_test_ext().execute_with(|| {
+        set_reputation(1, 100, 100);
+        set_reputation(2, 300, 100);
+        set_reputation(3, 200, 0);
+        assert_eq!(Reputation::reputation_proof(&1), None);
+
+        Reputation::on_initialize(10);
+        let root = Reputation::reputation_root().unwrap();
+        let committed = Reputation::effective_reputation(&2);
+        System::assert_has_event(Event::ReputationRootUpdated { era: 1, root, agents: 3 }.into());
+
+        let proof = Reputation::reputation_proof(&2).unwrap();
+        assert!(proof.verify(&root));
+        assert_eq!(proof.decode_leaf::<(u64, u64)>(), Some((2, committed)));
+        assert_eq!(Reputation::reputation_proof(&4), None);
+
+        // Proofs keep resolving to the committed value until the next era
+        set_reputation(2, 900, 0);
+        let proof = Reputation::reputation_proof(&2).unwrap();
+        assert!(proof.verify(&root));
+        assert_eq!(proof.decode_leaf::<(u64, u64)>(), Some((2, committed)));
+
+        Reputation::on_initialize(20);
+        let root = Reputation::reputation_root().unwrap();
+        let proof = Reputation::reputation_proof(&2).unwrap();
+        assert!(proof.verify(&root));
+        assert_eq!(proof.decode_leaf::<(u64, u64)>(), Some((2, Reputation::effective_reputation(&2))));
+    });
+}
+
+#[test]
+fn reputation_digest_is_exported_once_committed() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Reputation::export_reputation_digest(RuntimeOrigin::root(), 2000),
+            Error::<Test>::NoReputationRoot
+        );
+
+        set_reputation(1, 100, 100);
+        Reputation::on_initialize(10);
+        let digest = ReputationDigest { era: 1, root: Reputation::reputation_root().unwrap() };
+
+        assert_noop!(
+            Reputation::export_reputation_digest(RuntimeOrigin::signed(1), 2000),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Reputation::export_reputation_digest(RuntimeOrigin::root(), 2000));
+        assert_eq!(exported_digests(), vec![(2000, digest)]);
+    });
+}
+
+#[test]
+fn attestations_from_trust_anchors_are_imported() {
+    new_test_ext().execute_with(|| {
+        // This chain's own snapshot stands in for the sister chain's
+        set_reputation(1, 100, 100);
+        set_reputation(2, 300, 100);
+        Reputation::on_initialize(10);
+        let digest = Reputation::reputation_digest().unwrap();
+        let proof = Reputation::reputation_proof(&2).unwrap();
+        let score = Reputation::effective_reputation(&2);
+
+        assert_noop!(
+            Reputation::import_reputation_digest(RuntimeOrigin::signed(1000), digest),
+            Error::<Test>::UnknownTrustAnchor
+        );
+        assert_ok!(Reputation::set_trust_anchor(RuntimeOrigin::root(), 1000, Some(sp_runtime::Perbill::from_percent(50))));
+        assert_noop!(
+            Reputation::import_reputation_attestation(RuntimeOrigin::signed(1000), 2, score, proof.clone()),
+            Error::<Test>::NoDigest
+        );
+        assert_noop!(
+            Reputation::import_reputation_digest(RuntimeOrigin::signed(5), digest),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Reputation::import_reputation_digest(RuntimeOrigin::signed(1000), digest));
+        assert_noop!(
+            Reputation::import_reputation_digest(RuntimeOrigin::signed(1000), digest),
+            Error::<Test>::StaleDigest
+        );
+
+        // The proof must commit to the claimed agent and score
+        assert_noop!(
+            Reputation::import_reputation_attestation(RuntimeOrigin::signed(1000), 2, score + 1, proof.clone()),
+            Error::<Test>::InvalidReputationProof
+        );
+        assert_noop!(
+            Reputation::import_reputation_attestation(RuntimeOrigin::signed(1000), 1, score, proof.clone()),
+            Error::<Test>::InvalidReputationProof
+        );
+
+        assert_ok!(Reputation::import_reputation_attestation(RuntimeOrigin::signed(1000), 2, score, proof));
+        assert_eq!(Reputation::imported_score(2, 1000).unwrap().score, score / 2);
+        assert_eq!(Reputation::imported_reputation(&2), score / 2);
+        System::assert_last_event(Event::ReputationImported { para_id: 1000, agent_id: 2, score: score / 2 }.into());
+
+        // Imports leave local reputation alone
+        assert_eq!(Reputation::reputation(2).reputation, 300);
+    });
+}
+
+#[test]
+fn fee_exemption_is_bounded_per_era() {
+    new_test_ext().execute_with(|| {
+        set_reputation(1, 1_000, 100);
+        assert!(Reputation::is_fee_exempt(&1));
+
+        Reputation::note_fee_exempt_call(&1);
+        Reputation::note_fee_exempt_call(&1);
+        assert!(!Reputation::is_fee_exempt(&1));
+
+        // The quota resets in the next era
+        Reputation::on_initialize(10);
+        assert!(Reputation::is_fee_exempt(&1));
+    });
+}
+
+#[test]
+fn fee_exemption_quota_is_charged_before_dispatch() {
+    new_test_ext().execute_with(|| {
+        set_reputation(1, 1_000, 100);
+        set_reputation(2, 999, 100);
+        let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+        let transfer = RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death { dest: 3, value: 1 });
+        let exempt_agent = |who: u64, call: &RuntimeCall| {
+            ChargeFeelessQuota::<Test>::new()
+                .validate_only(Some(who).into(), call, &Default::default(), 0, TransactionSource::External, 0)
+                .unwrap()
+                .1
+        };
+
+        assert_eq!(exempt_agent(1, &remark), Some(1));
+        assert_eq!(exempt_agent(1, &transfer), None);
+        assert_eq!(exempt_agent(2, &remark), None);
+
+        // A call failing in dispatch still uses the quota
+        let failing = RuntimeCall::System(frame_system::Call::set_heap_pages { pages: 1 });
+        let (_, origin) = ChargeFeelessQuota::<Test>::new()
+            .validate_and_prepare(Some(1).into(), &failing, &Default::default(), 0, 0)
+            .unwrap();
+        assert!(failing.dispatch(origin).is_err());
+        assert_eq!(Reputation::feeless_usage(1), (0, 1));
+
+        let (_, origin) = ChargeFeelessQuota::<Test>::new()
+            .validate_and_prepare(Some(1).into(), &remark, &Default::default(), 0, 0)
+            .unwrap();
+        assert_ok!(remark.clone().dispatch(origin));
+        assert_eq!(Reputation::feeless_usage(1), (0, 2));
+        assert_eq!(exempt_agent(1, &remark), None);
+    });
+}
+
+#[test]
+fn ensure_reputation_at_least_accepts_reputable_signers() {
+    new_test_ext().execute_with(|| {
+        set_reputation(1, 500, 0);
+
+        assert_eq!(EnsureReputable::try_origin(RuntimeOrigin::signed(1)).ok(), Some(1));
+    });
+}
+
+#[test]
+fn ensure_reputation_at_least_rejects_low_reputation() {
+    new_test_ext().execute_with(|| {
+        set_reputation(1, 499, 0);
+
+        assert!(EnsureReputable::try_origin(RuntimeOrigin::signed(1)).is_err());
+        assert!(EnsureReputable::try_origin(RuntimeOrigin::signed(2)).is_err());
+    });
+}
+
+#[test]
+fn ensure_reputation_at_least_rejects_unsigned_and_quarantined() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        set_reputation(1, 5_000, 0);
+        assert!(EnsureReputable::try_origin(RuntimeOrigin::root()).is_err());
+        assert!(EnsureReputable::try_origin(RuntimeOrigin::none()).is_err());
+
+        // Quarantine zeroes effective reputation
+        assert_ok!(Reputation::report_offense(RuntimeOrigin::root(), 1, OffenseType::Equivocation));
+        assert!(EnsureReputable::try_origin(RuntimeOrigin::signed(1)).is_err());
+    });
+}
+
+#[test]
+fn set_compound_rewards_requires_registered_agent() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Reputation::set_compound_rewards(RuntimeOrigin::signed(1), true),
+            Error::<Test>::AgentNotFound
+        );
+
+        register_test_agent(1, AgentRole::Lyra);
+        assert_ok!(Reputation::set_compound_rewards(RuntimeOrigin::signed(1), true));
+        assert!(Reputation::compound_rewards(1));
+        System::assert_last_event(Event::CompoundRewardsSet { agent_id: 1, enabled: true }.into());
+
+        assert_ok!(Reputation::set_compound_rewards(RuntimeOrigin::signed(1), false));
+        assert!(!Reputation::compound_rewards(1));
+    });
+}
+
+#[test]
+fn era_rewards_are_restaked_for_opted_in_agents() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        register_test_agent(2, AgentRole::Echo);
+        set_reputation(1, 300, 100);
+        set_reputation(2, 200, 100);
+        assert_ok!(Reputation::set_compound_rewards(RuntimeOrigin::signed(1), true));
+
+        // No committee served during era 0, so nothing is paid yet
+        Reputation::on_initialize(10);
+        assert_eq!(Balances::free_balance(1), 10_000);
+
+        Reputation::on_initialize(20);
+
+        // Agent 1 restakes its reward, agent 2 keeps it free
+        assert_eq!(Reputation::reputation(1).stake, 150);
+        assert_eq!(Balances::reserved_balance(1), 50);
+        assert_eq!(Balances::free_balance(1), 10_000);
+        assert_eq!(Reputation::reputation(2).stake, 100);
+        assert_eq!(Balances::free_balance(2), 10_050);
+        assert_eq!(Reputation::total_stake(), 250);
+        System::assert_has_event(Event::EraRewardPaid { agent_id: 1, amount: 50, compounded: 50 }.into());
+        System::assert_has_event(Event::EraRewardPaid { agent_id: 2, amount: 50, compounded: 0 }.into());
+    });
+}
+
+#[test]
+fn era_reward_below_minimum_stake_is_not_restaked_without_stake() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        set_reputation(1, 300, 100);
+        assert_ok!(Reputation::set_compound_rewards(RuntimeOrigin::signed(1), true));
+        Reputation::on_initialize(10);
+
+        // The agent withdrew its stake during the era
+        crate::Reputation::<Test>::mutate(1, |info| info.stake = 0);
+
+        Reputation::on_initialize(20);
+        assert_eq!(Reputation::reputation(1).stake, 0);
+        assert_eq!(Balances::free_balance(1), 10_050);
+        System::assert_has_event(Event::EraRewardPaid { agent_id: 1, amount: 50, compounded: 0 }.into());
+    });
+}
+
+#[test]
+fn stake_is_capped_per_agent() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        assert_ok!(Reputation::stake(RuntimeOrigin::signed(1), 4_000));
+
+        assert_noop!(
+            Reputation::stake(RuntimeOrigin::signed(1), 1_001),
+            Error::<Test>::StakeAboveMaximum
+        );
+        assert_ok!(Reputation::stake(RuntimeOrigin::signed(1), 1_000));
+        assert_eq!(Reputation::reputation(1).stake, 5_000);
+    });
+}
+
+#[test]
+fn square_root_curve_dampens_large_stakes() {
+    assert_eq!(StakeWeightingCurve::Linear.apply(400), 400);
+    assert_eq!(StakeWeightingCurve::SquareRoot.apply(400), 20);
+    assert_eq!(StakeWeightingCurve::SquareRoot.apply(1_600), 40);
+
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        register_test_agent(2, AgentRole::Echo);
+        assert_ok!(Reputation::stake(RuntimeOrigin::signed(1), 1_600));
+        assert_ok!(Reputation::stake(RuntimeOrigin::signed(2), 400));
+
+        // 80% of the stake, but only two thirds of the weight
+        assert_eq!(Reputation::total_stake_weight(), 60);
+        assert_eq!(Reputation::stake_weight(&1), sp_runtime::Perbill::from_rational(2u32, 3u32));
+
+        // Unstaking keeps the total weight in sync
+        assert_ok!(Reputation::unstake(RuntimeOrigin::signed(1), 1_600));
+        assert_eq!(Reputation::total_stake_weight(), 20);
+    });
+}
+
+#[test]
+fn compounding_stops_at_stake_cap() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        set_reputation(1, 300, 4_980);
+        assert_ok!(Reputation::set_compound_rewards(RuntimeOrigin::signed(1), true));
+        Reputation::on_initialize(10);
+
+        Reputation::on_initialize(20);
+        assert_eq!(Reputation::reputation(1).stake, 5_000);
+        System::assert_has_event(Event::EraRewardPaid { agent_id: 1, amount: 50, compounded: 20 }.into());
+    });
+}
+
+#[test]
+fn era_rewards_are_paid_from_the_reward_pot() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        register_test_agent(2, AgentRole::Echo);
+        set_reputation(1, 300, 100);
+        set_reputation(2, 200, 100);
+        let pot = Reputation::reward_pot();
+        let issuance = Balances::total_issuance();
+
+        // The pot can cover only one of the two rewards and must stay alive
+        assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(pot), 5, 1_000 - 60));
+        Reputation::on_initialize(10);
+        Reputation::on_initialize(20);
+
+        assert_eq!(Balances::free_balance(pot), 10);
+        // The committee is paid in order of reputation
+        assert_eq!(Balances::free_balance(1), 10_050);
+        assert_eq!(Balances::free_balance(2), 10_000);
+        System::assert_has_event(Event::EraRewardUnpaid { agent_id: 2, amount: 50 }.into());
+
+        // Nothing was minted
+        assert_eq!(Balances::total_issuance(), issuance);
+    });
+}
+
+#[test]
+fn migration_computes_total_stake_weight() {
+    use frame_support::traits::OnRuntimeUpgrade;
+
+    new_test_ext().execute_with(|| {
+        set_reputation(1, 100, 900);
+        set_reputation(2, 100, 100);
+        crate::TotalStakeWeight::<Test>::kill();
+        frame_support::traits::StorageVersion::new(0).put::<Reputation>();
+
+        crate::migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(Reputation::total_stake_weight(), 40);
+        assert_eq!(frame_support::traits::StorageVersion::get::<Reputation>(), 1);
+    });
+}
+
+#[test]
+fn retiring_agent_stake_unbonds_and_stays_slashable() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        assert_ok!(Reputation::stake(RuntimeOrigin::signed(1), 1_000));
+        assert_ok!(Reputation::set_compound_rewards(RuntimeOrigin::signed(1), true));
+
+        // Retiring moves the whole stake to unbonding, which carries no weight
+        assert_ok!(AgentRegistry::initiate_retirement(RuntimeOrigin::signed(1)));
+        assert_eq!(Reputation::reputation(1).stake, 0);
+        assert_eq!(Reputation::unbonding(1), 1_000);
+        assert_eq!(Reputation::total_stake(), 0);
+        assert_eq!(Reputation::total_stake_weight(), 0);
+        assert_eq!(Balances::reserved_balance(1), 1_000);
+        assert!(!Reputation::compound_rewards(1));
+        System::assert_has_event(Event::UnbondingStarted { agent_id: 1, amount: 1_000 }.into());
+
+        assert_noop!(
+            Reputation::stake(RuntimeOrigin::signed(1), 1_000),
+            Error::<Test>::AgentRetiring
+        );
+
+        // Offenses during the grace period are still slashed from the unbonding stake
+        assert_ok!(Reputation::report_offense(RuntimeOrigin::root(), 1, OffenseType::Equivocation));
+        assert_eq!(Reputation::unbonding(1), 750);
+        assert_eq!(Balances::reserved_balance(1), 750);
+
+        // Completing the retirement releases what is left
+        let completes_at = AgentRegistry::retiring(1).unwrap();
+        System::set_block_number(completes_at);
+        assert_ok!(AgentRegistry::retire_complete(RuntimeOrigin::signed(2), 1));
+        assert_eq!(Reputation::unbonding(1), 0);
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 9_750);
+        System::assert_has_event(Event::Unstaked { agent_id: 1, amount: 750 }.into());
+    });
+}
+
+#[test]
+fn stake_and_offenses_follow_a_rotated_agent() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        assert_ok!(Reputation::stake(RuntimeOrigin::signed(1), 1_000));
+        assert_ok!(Reputation::set_compound_rewards(RuntimeOrigin::signed(1), true));
+        assert_ok!(Reputation::report_offense(RuntimeOrigin::root(), 1, OffenseType::Unresponsiveness));
+        crate::OffenseArchive::<Test>::insert(1, 0, OffenseDigest { equivocation: 1, ..Default::default() });
+        let reputation_info = Reputation::reputation(1);
+
+        assert_ok!(AgentRegistry::rotate_agent_key(RuntimeOrigin::signed(1), 5));
+
+        // The reserve moved with the stake
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::reserved_balance(5), 950);
+        assert_eq!(Reputation::reputation(5), reputation_info);
+        assert!(!crate::Reputation::<Test>::contains_key(1));
+        assert_eq!(Reputation::total_stake(), 950);
+
+        // So did the offense record and the reward settings
+        assert_eq!(Reputation::offense_history(5).len(), 1);
+        assert!(Reputation::offense_history(1).is_empty());
+        assert_eq!(Reputation::offense_archive(5, 0).equivocation, 1);
+        assert!(Reputation::compound_rewards(5));
+        assert!(!Reputation::compound_rewards(1));
+        System::assert_has_event(Event::StakeRotated { old_agent_id: 1, new_agent_id: 5, amount: 950 }.into());
+    });
+}
+
+#[test]
+fn failed_stake_rotation_reverts_the_whole_rotation() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        assert_ok!(Reputation::stake(RuntimeOrigin::signed(1), 1_000));
+
+        // The target account already has reputation state
+        set_reputation(4, 10, 0);
+        assert_noop!(
+            AgentRegistry::rotate_agent_key(RuntimeOrigin::signed(1), 4),
+            Error::<Test>::RotationTargetInUse
+        );
+
+        // More archived eras than a rotation may move
+        for era in 0..3 {
+            crate::OffenseArchive::<Test>::insert(1, era, OffenseDigest { invalid_data: 1, ..Default::default() });
+        }
+        assert_noop!(
+            AgentRegistry::rotate_agent_key(RuntimeOrigin::signed(1), 5),
+            Error::<Test>::TooManyArchivedEras
+        );
+        assert!(AgentRegistry::agents(1).is_some());
+        assert_eq!(Balances::reserved_balance(1), 1_000);
+    });
+}
+
+#[test]
+fn quarantine_can_be_exited_early_for_a_stake_penalty() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        assert_ok!(Reputation::stake(RuntimeOrigin::signed(1), 1_000));
+        assert_noop!(
+            Reputation::exit_quarantine_early(RuntimeOrigin::signed(1)),
+            Error::<Test>::NotQuarantined
+        );
+
+        assert_ok!(Reputation::report_offense(RuntimeOrigin::root(), 1, OffenseType::Equivocation));
+        assert_ok!(Reputation::exit_quarantine_early(RuntimeOrigin::signed(1)));
+
+        let info = Reputation::reputation(1);
+        assert_eq!(info.stake, 675);
+        assert_eq!(info.quarantine_until, None);
+        assert_eq!(Reputation::total_stake(), 675);
+        assert_eq!(Balances::reserved_balance(1), 675);
+        assert!(Reputation::can_participate(&1));
+        System::assert_last_event(Event::QuarantineExitedEarly {
+            agent_id: 1,
+            penalty: 75,
+            was_until: 21,
+            exits_this_era: 1,
+        }.into());
+    });
+}
+
+#[test]
+fn early_quarantine_exits_are_limited_per_era() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        assert_ok!(Reputation::stake(RuntimeOrigin::signed(1), 1_000));
+        assert_ok!(Reputation::report_offense(RuntimeOrigin::root(), 1, OffenseType::Equivocation));
+        assert_ok!(Reputation::exit_quarantine_early(RuntimeOrigin::signed(1)));
+
+        assert_ok!(Reputation::report_offense(RuntimeOrigin::root(), 1, OffenseType::DataWithholding));
+        assert_noop!(
+            Reputation::exit_quarantine_early(RuntimeOrigin::signed(1)),
+            Error::<Test>::TooManyEarlyExits
+        );
+
+        // A new era resets the allowance
+        System::set_block_number(10);
+        Reputation::on_initialize(10);
+        assert_ok!(Reputation::exit_quarantine_early(RuntimeOrigin::signed(1)));
+        assert_eq!(Reputation::early_exits(1), (1, 1));
+    });
+}
+
+#[test]
+fn temporary_bans_expire_at_era_start_before_a_permanent_ban() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+
+        // The mock allows one temporary ban of two ten-block eras
+        for _ in 0..3 {
+            assert_ok!(Reputation::report_offense(RuntimeOrigin::root(), 1, OffenseType::Unresponsiveness));
+        }
+        let info = Reputation::reputation(1);
+        assert!(info.is_banned);
+        assert_eq!(info.ban_until, Some(21));
+        assert_eq!(info.temporary_bans, 1);
+        assert!(Reputation::temporarily_banned(1).is_some());
+        System::assert_has_event(Event::AgentBanned { agent_id: 1, until: Some(21) }.into());
+
+        // Still banned at the era start before the expiry, lifted at the first one after it
+        Reputation::on_initialize(20);
+        assert!(Reputation::is_restricted(&1));
+        Reputation::on_initialize(30);
+        let info = Reputation::reputation(1);
+        assert!(!info.is_banned);
+        assert_eq!(info.ban_until, None);
+        assert_eq!(info.offense_count, 0);
+        assert!(Reputation::temporarily_banned(1).is_none());
+        System::assert_has_event(Event::AgentUnbanned { agent_id: 1, forced: false }.into());
+
+        // The next ban is permanent and survives era starts
+        System::set_block_number(30);
+        for _ in 0..3 {
+            assert_ok!(Reputation::report_offense(RuntimeOrigin::root(), 1, OffenseType::Unresponsiveness));
+        }
+        let info = Reputation::reputation(1);
+        assert!(info.is_banned);
+        assert_eq!(info.ban_until, None);
+        assert!(Reputation::temporarily_banned(1).is_none());
+        System::assert_has_event(Event::AgentBanned { agent_id: 1, until: None }.into());
+
+        Reputation::on_initialize(100);
+        assert!(Reputation::reputation(1).is_banned);
+    });
+}
+
+#[test]
+fn governance_can_lift_any_ban() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        assert_noop!(Reputation::force_unban(RuntimeOrigin::root(), 1), Error::<Test>::NotBanned);
+
+        for _ in 0..3 {
+            assert_ok!(Reputation::report_offense(RuntimeOrigin::root(), 1, OffenseType::Unresponsiveness));
+        }
+        assert_noop!(Reputation::force_unban(RuntimeOrigin::signed(1), 1), sp_runtime::sp_runtime::DispatchError::BadOrigin);
+
+        assert_ok!(Reputation::force_unban(RuntimeOrigin::root(), 1));
+        let info = Reputation::reputation(1);
+        assert!(!info.is_banned);
+        assert_eq!(info.offense_count, 0);
+        assert!(Reputation::temporarily_banned(1).is_none());
+        System::assert_last_event(Event::AgentUnbanned { agent_id: 1, forced: true }.into());
+
+        // The served temporary ban still counts, so the next ban is permanent
+        assert_eq!(info.temporary_bans, 1);
+        for _ in 0..3 {
+            assert_ok!(Reputation::report_offense(RuntimeOrigin::root(), 1, OffenseType::Unresponsiveness));
+        }
+        assert_eq!(Reputation::reputation(1).ban_until, None);
+        assert!(Reputation::reputation(1).is_banned);
+    });
+}
+
+#[test]
+fn deferred_slashes_wait_and_can_be_cancelled() {
+    new_test_ext().execute_with(|| {
+        SlashDeferDuration::set(5);
+        register_test_agent(1, AgentRole::Lyra);
+        assert_ok!(Reputation::stake(RuntimeOrigin::signed(1), 1_000));
+
+        assert_ok!(Reputation::report_offense(RuntimeOrigin::root(), 1, OffenseType::Unresponsiveness));
+        System::assert_last_event(Event::SlashDeferred {
+            slash_id: 0,
+            agent_id: 1,
+            offense_type: OffenseType::Unresponsiveness,
+            execute_at: 6,
+        }.into());
+        assert_ok!(Reputation::report_offense(RuntimeOrigin::root(), 1, OffenseType::Equivocation));
+
+        // Nothing is applied while the slashes are pending
+        let info = Reputation::reputation(1);
+        assert_eq!(info.stake, 1_000);
+        assert_eq!(info.offense_count, 0);
+        assert_eq!(Reputation::agent_pending_slashes(1).to_vec(), vec![0, 1]);
+
+        // Governance drops the erroneous equivocation report
+        assert_noop!(
+            Reputation::cancel_deferred_slash(RuntimeOrigin::signed(1), 1),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Reputation::cancel_deferred_slash(RuntimeOrigin::root(), 1));
+        System::assert_last_event(Event::DeferredSlashCancelled { slash_id: 1, agent_id: 1 }.into());
+        assert_noop!(Reputation::cancel_deferred_slash(RuntimeOrigin::root(), 1), Error::<Test>::SlashNotFound);
+
+        Reputation::on_initialize(5);
+        assert_eq!(Reputation::reputation(1).stake, 1_000);
+
+        // The remaining slash executes once due
+        Reputation::on_initialize(6);
+        let info = Reputation::reputation(1);
+        assert_eq!(info.stake, 950);
+        assert_eq!(info.offense_count, 1);
+        assert!(Reputation::pending_slashes(0).is_none());
+        assert!(Reputation::agent_pending_slashes(1).is_empty());
+        assert_eq!(Reputation::slash_queue_head(), 2);
+    });
+}
+
+#[test]
+fn pending_deferred_slashes_block_unstaking() {
+    new_test_ext().execute_with(|| {
+        SlashDeferDuration::set(5);
+        register_test_agent(1, AgentRole::Lyra);
+        assert_ok!(Reputation::stake(RuntimeOrigin::signed(1), 1_000));
+        assert_ok!(Reputation::report_offense(RuntimeOrigin::root(), 1, OffenseType::Unresponsiveness));
+
+        // The stake cannot be pulled out from under the slash
+        assert_noop!(Reputation::unstake(RuntimeOrigin::signed(1), 1_000), Error::<Test>::SlashPending);
+        assert_noop!(Reputation::unstake(RuntimeOrigin::signed(1), 1), Error::<Test>::SlashPending);
+
+        // The slash still applies in full once due, after which the rest can be unstaked
+        Reputation::on_initialize(6);
+        assert_eq!(Reputation::reputation(1).stake, 950);
+        assert_eq!(Balances::reserved_balance(1), 950);
+        assert_ok!(Reputation::unstake(RuntimeOrigin::signed(1), 950));
+        assert_eq!(Reputation::reputation(1).stake, 0);
+    });
+}
+
+#[test]
+fn deferred_slashes_are_bounded_per_block_and_per_agent() {
+    new_test_ext().execute_with(|| {
+        SlashDeferDuration::set(5);
+        register_test_agent(1, AgentRole::Lyra);
+        assert_ok!(Reputation::stake(RuntimeOrigin::signed(1), 1_000));
+
+        for _ in 0..3 {
+            assert_ok!(Reputation::report_offense(RuntimeOrigin::root(), 1, OffenseType::Unresponsiveness));
+        }
+        assert_noop!(
+            Reputation::report_offense(RuntimeOrigin::root(), 1, OffenseType::Unresponsiveness),
+            Error::<Test>::TooManyPendingSlashes
+        );
+
+        // Two slashes per block, the third carries over
+        Reputation::on_initialize(6);
+        assert_eq!(Reputation::reputation(1).offense_count, 2);
+        assert_eq!(Reputation::slash_queue_head(), 2);
+        Reputation::on_initialize(7);
+        assert_eq!(Reputation::reputation(1).offense_count, 3);
+        assert!(Reputation::reputation(1).is_banned);
+        assert_eq!(Reputation::slash_queue_head(), 3);
+    });
+}
+
+#[test]
+fn participation_summaries_are_kept_per_era_and_pruned() {
+    new_test_ext().execute_with(|| {
+        Reputation::note_activity(&1, ParticipationKind::Submission);
+        Reputation::note_activity(&1, ParticipationKind::Signature);
+        Reputation::note_activity(&1, ParticipationKind::Signature);
+        Reputation::note_activity(&1, ParticipationKind::Finalization);
+        assert_eq!(Reputation::era_summary(&1, 0), Some(ParticipationSummary {
+            submissions: 1,
+            signatures: 2,
+            finalizations: 1,
+        }));
+        assert_eq!(Reputation::era_summary(&2, 0), Some(ParticipationSummary::default()));
+        assert_eq!(Reputation::era_summary(&1, 1), None);
+
+        // Activity in a new era is counted separately
+        Reputation::on_initialize(10);
+        Reputation::note_activity(&1, ParticipationKind::Submission);
+        assert_eq!(Reputation::era_summary(&1, 1).unwrap().submissions, 1);
+        assert_eq!(Reputation::era_summary(&1, 0).unwrap().submissions, 1);
+
+        // Two past eras are kept besides the current one
+        Reputation::on_initialize(20);
+        assert!(Reputation::era_summary(&1, 0).is_some());
+        Reputation::on_initialize(30);
+        assert_eq!(Reputation::era_summary(&1, 0), None);
+        assert!(!crate::EraParticipation::<Test>::contains_key(0, 1));
+        assert!(Reputation::era_summary(&1, 1).is_some());
+    });
+}
+
+#[test]
+fn committee_consensus_calls_are_prioritized_under_congestion() {
+    new_test_ext().execute_with(|| {
+        set_reputation(1, 2_000, 100);
+        set_reputation(2, 500, 100);
+        set_reputation(3, 2_000, 100);
+        Reputation::on_initialize(10);
+        assert_eq!(Reputation::committee().to_vec(), vec![1, 3]);
+        // Agent 3 lost its reputation after the committee was selected
+        crate::Reputation::<Test>::mutate(3, |info| info.reputation = 0);
+
+        let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+        let priority = |who: u64| {
+            PrioritizeCommittee::<Test>::new()
+                .validate_only(Some(who).into(), &call, &Default::default(), 0, TransactionSource::External, 0)
+                .unwrap()
+                .0
+                .priority
+        };
+
+        assert_eq!(priority(1), 1_000_000);
+        assert_eq!(priority(2), 0);
+        assert_eq!(priority(3), 0);
+
+        Congested::set(false);
+        assert_eq!(priority(1), 0);
+    });
+}
+
+#[test]
+fn era_stats_are_written_when_the_era_ends() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        assert_ok!(Reputation::stake(RuntimeOrigin::signed(1), 1_000));
+
+        Reputation::note_activity(&1, ParticipationKind::Submission);
+        Reputation::note_activity(&1, ParticipationKind::Submission);
+        Reputation::note_activity(&1, ParticipationKind::Signature);
+        Reputation::note_activity(&1, ParticipationKind::Submission);
+        Reputation::note_log_finalized(3);
+        Reputation::note_log_finalized(2);
+        Reputation::note_log_rejected();
+        assert_ok!(Reputation::report_offense(RuntimeOrigin::root(), 1, OffenseType::Unresponsiveness));
+        assert_ok!(Reputation::reward_consensus(RuntimeOrigin::root(), 1));
+        let rewarded = Reputation::current_era_stats().reputation_rewarded;
+        assert!(rewarded > 0);
+
+        let expected = EraStatistics {
+            logs_submitted: 3,
+            logs_finalized: 2,
+            logs_rejected: 1,
+            finalized_signatures: 5,
+            reputation_rewarded: rewarded,
+            slashed: 50,
+        };
+        assert_eq!(Reputation::era_stats(0), Some(expected));
+        assert_eq!(expected.average_signers(), 2);
+        assert_eq!(Reputation::closed_era_stats(0), None);
+        assert_eq!(Reputation::era_stats(1), None);
+
+        // The era's counters are kept when it ends, and the next era starts from zero
+        Reputation::on_initialize(10);
+        assert_eq!(Reputation::closed_era_stats(0), Some(expected));
+        assert_eq!(Reputation::era_stats(0), Some(expected));
+        assert_eq!(Reputation::era_stats(1), Some(EraStatistics::default()));
+        assert_eq!(EraStatistics::<u64>::default().average_signers(), 0);
+    });
+}
+
+#[test]
+fn era_configs_record_the_rules_each_era_started_under() {
+    new_test_ext().execute_with(|| {
+        set_reputation(1, 100, 100);
+        assert_eq!(Reputation::era_config_at(1), None);
+
+        System::set_block_number(10);
+        Reputation::on_initialize(10);
+        let first = EraConfig {
+            started_at: 10,
+            consensus: csuite_primitives::ConsensusThresholds {
+                frost_threshold: 3,
+                frost_group_version: 1,
+                veto_threshold: Perbill::from_percent(33),
+                first_responders: 2,
+            },
+            committee_size: 1,
+            max_committee_size: 2,
+            minimum_stake: 100,
+            unresponsiveness_slash: Perbill::from_percent(5),
+            equivocation_slash: Perbill::from_percent(25),
+            max_slash_percentage: Perbill::from_percent(50),
+            max_penalty_doublings: 2,
+            offense_window: 50,
+            quarantine_period: 20,
+            slash_defer_duration: 0,
+            max_offenses: 3,
+            max_temporary_bans: 1,
+            temporary_ban_eras: 2,
+        };
+        assert_eq!(Reputation::era_config(1), Some(first));
+
+        // A changed threshold only applies from the next era on
+        FrostThreshold::set(5);
+        set_reputation(2, 300, 100);
+        System::set_block_number(20);
+        Reputation::on_initialize(20);
+        let second = EraConfig {
+            started_at: 20,
+            consensus: csuite_primitives::ConsensusThresholds { frost_threshold: 5, ..first.consensus },
+            committee_size: 2,
+            ..first
+        };
+        assert_eq!(Reputation::era_config(2), Some(second));
+
+        // Blocks map to the era they belong to, and eras before the first record to nothing
+        assert_eq!(Reputation::era_config_at(9), None);
+        assert_eq!(Reputation::era_config_at(10), Some((1, first)));
+        assert_eq!(Reputation::era_config_at(19), Some((1, first)));
+        assert_eq!(Reputation::era_config_at(20), Some((2, second)));
+        assert_eq!(Reputation::era_config_at(21), None);
+    });
+}
+
+#[test]
+fn stake_locks_multiply_stake_weight_and_penalize_early_unstaking() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        register_test_agent(2, AgentRole::Echo);
+        assert_ok!(Reputation::stake(RuntimeOrigin::signed(1), 1_000));
+        assert_ok!(Reputation::stake(RuntimeOrigin::signed(2), 1_000));
+        crate::Reputation::<Test>::mutate(1, |info| info.reputation = 100);
+
+        // Half the stake weight adds 200 to the multiplier, doubled by the short lock
+        assert_eq!(Reputation::effective_reputation(&1), 20_100);
+        assert_noop!(
+            Reputation::lock_stake(RuntimeOrigin::signed(1), crate::StakeLock::Unlocked),
+            Error::<Test>::InvalidStakeLock
+        );
+        assert_ok!(Reputation::lock_stake(RuntimeOrigin::signed(1), crate::StakeLock::Short));
+        System::assert_last_event(Event::StakeLocked {
+            agent_id: 1,
+            lock: crate::StakeLock::Short,
+            until: 11,
+        }.into());
+        assert_eq!(Reputation::effective_reputation(&1), 40_100);
+
+        // A lock can be raised but not lowered
+        assert_ok!(Reputation::lock_stake(RuntimeOrigin::signed(1), crate::StakeLock::Long));
+        assert_eq!(Reputation::reputation(1).locked_until, 41);
+        assert_eq!(Reputation::effective_reputation(&1), 80_100);
+        assert_noop!(
+            Reputation::lock_stake(RuntimeOrigin::signed(1), crate::StakeLock::Short),
+            Error::<Test>::StakeLockShortened
+        );
+
+        // Unstaking early pays 20% of the amount
+        assert_ok!(Reputation::unstake(RuntimeOrigin::signed(1), 500));
+        System::assert_has_event(Event::StakeUnlockedEarly { agent_id: 1, penalty: 100, was_until: 41 }.into());
+        assert_eq!(Reputation::reputation(1).stake, 500);
+        assert_eq!(Balances::reserved_balance(1), 500);
+
+        // Once the lock expires the stake counts once and unstaking is free
+        System::set_block_number(41);
+        assert_eq!(Reputation::active_lock(&Reputation::reputation(1)), crate::StakeLock::Unlocked);
+        assert_ok!(Reputation::unstake(RuntimeOrigin::signed(1), 500));
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Reputation::reputation(1).stake_lock, crate::StakeLock::Unlocked);
+    });
+}
+
+#[test]
+fn repair_stake_accounting_lowers_stake_to_the_reserved_balance() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        register_test_agent(2, AgentRole::Echo);
+        assert_ok!(Reputation::stake(RuntimeOrigin::signed(1), 1_600));
+        assert_ok!(Reputation::stake(RuntimeOrigin::signed(2), 400));
+        crate::Unbonding::<Test>::insert(1, 100);
+        assert_ok!(Balances::reserve_named(&crate::STAKE_RESERVE_ID, &1, 100));
+
+        // Nothing to repair while the reserve covers the stake
+        assert_noop!(
+            Reputation::repair_stake_accounting(RuntimeOrigin::signed(3), 1),
+            Error::<Test>::StakeAccountingConsistent
+        );
+
+        // Losing part of the reserve outside the pallet leaves the stake overstated, even if
+        // the account holds other deposits
+        assert_ok!(Balances::reserve(&1, 800));
+        Balances::unreserve_named(&crate::STAKE_RESERVE_ID, &1, 800);
+        assert_ok!(Reputation::repair_stake_accounting(RuntimeOrigin::signed(3), 1));
+        System::assert_last_event(Event::StakeAccountingRepaired {
+            agent_id: 1,
+            recorded: 1_700,
+            actual: 900,
+        }.into());
+
+        // The unbonding stake is kept and the active stake takes the shortfall
+        assert_eq!(Reputation::unbonding(1), 100);
+        assert_eq!(Reputation::reputation(1).stake, 800);
+        assert_eq!(Reputation::total_stake(), 1_200);
+        assert_eq!(Reputation::total_stake_weight(), Reputation::stake_weight_of(800) + 20);
+        assert_eq!(Reputation::reconcile_stake(&1), None);
+    });
+}
+
+#[test]
+fn migration_to_v5_moves_stakes_to_the_unlocked_tier() {
+    use codec::Encode;
+    use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(4).put::<Reputation>();
+        let old = crate::migrations::v5::OldReputationInfo::<Test> {
+            reputation: 100,
+            stake: 1_000,
+            last_update: 1,
+            consensus_count: 3,
+            offense_count: 1,
+            quarantine_until: None,
+            is_banned: false,
+            ban_until: None,
+            temporary_bans: 1,
+        };
+        frame_support::storage::unhashed::put_raw(
+            &crate::Reputation::<Test>::hashed_key_for(1),
+            &old.encode(),
+        );
+
+        crate::migrations::v5::MigrateV4ToV5::<Test>::on_runtime_upgrade();
+
+        let info = Reputation::reputation(1);
+        assert_eq!((info.reputation, info.stake, info.temporary_bans), (100, 1_000, 1));
+        assert_eq!(info.stake_lock, crate::StakeLock::Unlocked);
+        assert_eq!(info.locked_until, 0);
+        assert_eq!(StorageVersion::get::<Reputation>(), 5);
+    });
+}
+
+#[test]
+fn migration_to_v6_moves_stakes_to_the_named_reserve() {
+    use frame_support::traits::{OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        assert_ok!(Reputation::stake(RuntimeOrigin::signed(1), 1_000));
+        crate::Unbonding::<Test>::insert(1, 100);
+        // Stake reserved before the named reserve, next to another deposit
+        Balances::unreserve_named(&crate::STAKE_RESERVE_ID, &1, 1_000);
+        assert_ok!(Balances::reserve(&1, 1_600));
+        StorageVersion::new(5).put::<Reputation>();
+
+        crate::migrations::v6::MigrateV5ToV6::<Test>::on_runtime_upgrade();
+
+        assert_eq!(Balances::reserved_balance_named(&crate::STAKE_RESERVE_ID, &1), 1_100);
+        assert_eq!(Balances::reserved_balance(1), 1_600);
+        assert_eq!(Reputation::reconcile_stake(&1), None);
+        assert_eq!(StorageVersion::get::<Reputation>(), 6);
+    });
+}
+
+#[test]
+fn escrowed_stake_leaves_the_agent_until_restored() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(1, AgentRole::Lyra);
+        assert_ok!(Reputation::stake(RuntimeOrigin::signed(1), 1_000));
+
+        // The escrowed share stops counting as stake and lands in the escrow's free balance
+        assert_eq!(Reputation::escrow_stake(&1, &3, Perbill::from_percent(10)), 100);
+        System::assert_last_event(Event::StakeEscrowed { agent_id: 1, escrow: 3, amount: 100 }.into());
+        assert_eq!(Reputation::reputation(1).stake, 900);
+        assert_eq!(Reputation::total_stake(), 900);
+        assert_eq!(Reputation::total_stake_weight(), Reputation::stake_weight_of(900));
+        assert_eq!(Balances::reserved_balance(1), 900);
+        assert_eq!(Balances::free_balance(3), 10_100);
+        assert_noop!(
+            Reputation::unstake(RuntimeOrigin::signed(1), 1_000),
+            Error::<Test>::NoStakeToUnstake
+        );
+
+        // Released funds are staked again
+        assert_ok!(<Balances as frame_support::traits::Currency<u64>>::transfer(
+            &3,
+            &1,
+            100,
+            frame_support::traits::ExistenceRequirement::KeepAlive,
+        ));
+        Reputation::restore_stake(&1, 100);
+        System::assert_last_event(Event::StakeRestored { agent_id: 1, amount: 100 }.into());
+        assert_eq!(Reputation::reputation(1).stake, 1_000);
+        assert_eq!(Reputation::total_stake(), 1_000);
+        assert_eq!(Balances::reserved_balance(1), 1_000);
+
+        // Agents that are not registered keep released funds free
+        Reputation::restore_stake(&4, 100);
+        assert_eq!(Reputation::reputation(4).stake, 0);
+        assert_eq!(Balances::reserved_balance(4), 0);
+    });
+}
@@ -1,35 +1,22 @@
-# ----------------------------------------------------------------------------
-#  File:        weights.rs
-#  Project:     Celaya Solutions (C-Suite Blockchain)
-#  Created by:  Celaya Solutions, 2025
-#  Author:      Christopher Celaya <chris@celayasolutions.com>
-#  Description: Weight definitions for reputation pallet
-#  Version:     1.0.0
-#  License:     BSL (SPDX id BUSL)
-#  Last Update: (June 2025)
-# ----------------------------------------------------------------------------
-
-//! Autogenerated weights for `pallet_reputation`
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        weights.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Weight definitions for reputation pallet
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! Weights for pallet_reputation
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2025-01-XX, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `template-node`, CPU: `<CPU_MODEL>`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
-
-// Executed Command:
-// ./target/release/node-template
-// benchmark
-// pallet
-// --chain=dev
-// --steps=50
-// --repeat=20
-// --pallet=pallet_reputation
-// --extrinsic=*
-// --execution=wasm
-// --wasm-execution=compiled
-// --heap-pages=4096
-// --output=./pallets/reputation/src/weights.rs
+//! No benchmark run has produced these numbers yet: each one is an estimate scaled from the
+//! storage items the call touches. Replace them with the output of
+//! `benchmark pallet --pallet=pallet_reputation --extrinsic=*` once it has been run on
+//! reference hardware.
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -45,9 +32,21 @@ pub trait WeightInfo {
 	fn unstake() -> Weight;
 	fn reward_consensus() -> Weight;
 	fn report_offense() -> Weight;
+	fn set_compound_rewards() -> Weight;
+	fn set_trust_anchor() -> Weight;
+	fn import_reputation_digest() -> Weight;
+	fn import_reputation_attestation() -> Weight;
+	fn export_reputation_digest() -> Weight;
+	fn exit_quarantine_early() -> Weight;
+	fn set_reward_curve() -> Weight;
+	fn force_unban() -> Weight;
+	fn cancel_deferred_slash() -> Weight;
+	fn lock_stake() -> Weight;
+	fn repair_stake_accounting() -> Weight;
+	fn reward_consensus_many(n: u32) -> Weight;
 }
 
-/// Weights for pallet_reputation using the Substrate node and recommended hardware.
+/// Estimated weights for pallet_reputation.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: AgentRegistry Agents (r:1 w:0)
@@ -59,10 +58,6 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: Reputation TotalStake (r:1 w:1)
 	/// Proof: Reputation TotalStake (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	fn stake() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `425`
-		//  Estimated: `5513`
-		// Minimum execution time: 25_000_000 picoseconds.
 		Weight::from_parts(26_000_000, 5513)
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
@@ -74,13 +69,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Balances Reserves (max_values: None, max_size: Some(1249), added: 3724, mode: MaxEncodedLen)
 	/// Storage: Reputation TotalStake (r:1 w:1)
 	/// Proof: Reputation TotalStake (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Reputation AgentPendingSlashes (r:1 w:0)
+	/// Proof: Reputation AgentPendingSlashes (max_values: None, max_size: Some(180), added: 2655, mode: MaxEncodedLen)
 	fn unstake() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `425`
-		//  Estimated: `4714`
-		// Minimum execution time: 22_000_000 picoseconds.
-		Weight::from_parts(23_000_000, 4714)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+		Weight::from_parts(25_000_000, 4714)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 
@@ -88,14 +81,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Reputation Reputation (max_values: None, max_size: Some(256), added: 2731, mode: MaxEncodedLen)
 	/// Storage: Reputation TotalStake (r:1 w:0)
 	/// Proof: Reputation TotalStake (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Reputation ParticipationStreaks (r:1 w:1)
+	/// Proof: Reputation ParticipationStreaks (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Reputation RewardCurve (r:1 w:0)
+	/// Proof: Reputation RewardCurve (max_values: Some(1), max_size: Some(258), added: 753, mode: MaxEncodedLen)
 	fn reward_consensus() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `425`
-		//  Estimated: `3721`
-		// Minimum execution time: 18_000_000 picoseconds.
-		Weight::from_parts(19_000_000, 3721)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		Weight::from_parts(21_000_000, 3721)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 
 	/// Storage: Reputation Reputation (r:1 w:1)
@@ -106,15 +99,141 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Reputation TotalStake (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	/// Storage: Reputation OffenseHistory (r:1 w:1)
 	/// Proof: Reputation OffenseHistory (max_values: None, max_size: Some(3200), added: 5675, mode: MaxEncodedLen)
+	/// Storage: Reputation OffenseArchive (r:1 w:1)
+	/// Proof: Reputation OffenseArchive (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	fn report_offense() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `525`
-		//  Estimated: `6665`
-		// Minimum execution time: 35_000_000 picoseconds.
 		Weight::from_parts(37_000_000, 6665)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: AgentRegistry Agents (r:1 w:0)
+	/// Proof: AgentRegistry Agents (max_values: None, max_size: Some(2048), added: 4523, mode: MaxEncodedLen)
+	/// Storage: Reputation CompoundRewards (r:0 w:1)
+	/// Proof: Reputation CompoundRewards (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn set_compound_rewards() -> Weight {
+		Weight::from_parts(13_000_000, 5513)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: Reputation TrustAnchors (r:1 w:1)
+	/// Proof: Reputation TrustAnchors (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn set_trust_anchor() -> Weight {
+		Weight::from_parts(12_000_000, 3514)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: Reputation TrustAnchors (r:1 w:1)
+	/// Proof: Reputation TrustAnchors (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn import_reputation_digest() -> Weight {
+		Weight::from_parts(14_000_000, 3514)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: Reputation TrustAnchors (r:1 w:0)
+	/// Proof: Reputation TrustAnchors (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Reputation ImportedReputation (r:0 w:1)
+	/// Proof: Reputation ImportedReputation (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	fn import_reputation_attestation() -> Weight {
+		Weight::from_parts(33_000_000, 3514)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: Reputation ReputationRoot (r:1 w:0)
+	/// Proof: Reputation ReputationRoot (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Reputation CurrentEra (r:1 w:0)
+	/// Proof: Reputation CurrentEra (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn export_reputation_digest() -> Weight {
+		Weight::from_parts(45_000_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
+
+	/// Storage: Reputation Reputation (r:1 w:1)
+	/// Proof: Reputation Reputation (max_values: None, max_size: Some(256), added: 2731, mode: MaxEncodedLen)
+	/// Storage: Reputation CurrentEra (r:1 w:0)
+	/// Proof: Reputation CurrentEra (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Reputation EarlyExits (r:1 w:1)
+	/// Proof: Reputation EarlyExits (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: Balances Reserves (r:1 w:1)
+	/// Proof: Balances Reserves (max_values: None, max_size: Some(1249), added: 3724, mode: MaxEncodedLen)
+	/// Storage: Reputation TotalStake (r:1 w:1)
+	/// Proof: Reputation TotalStake (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Reputation TotalStakeWeight (r:1 w:1)
+	/// Proof: Reputation TotalStakeWeight (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn exit_quarantine_early() -> Weight {
+		Weight::from_parts(32_000_000, 4714)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: Reputation RewardCurve (r:0 w:1)
+	/// Proof: Reputation RewardCurve (max_values: Some(1), max_size: Some(258), added: 753, mode: MaxEncodedLen)
+	fn set_reward_curve() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: Reputation Reputation (r:1 w:1)
+	/// Proof: Reputation Reputation (max_values: None, max_size: Some(256), added: 2731, mode: MaxEncodedLen)
+	/// Storage: Reputation TemporarilyBanned (r:0 w:1)
+	/// Proof: Reputation TemporarilyBanned (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn force_unban() -> Weight {
+		Weight::from_parts(16_000_000, 3721)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Reputation PendingSlashes (r:1 w:1)
+	/// Proof: Reputation PendingSlashes (max_values: None, max_size: Some(66), added: 2541, mode: MaxEncodedLen)
+	/// Storage: Reputation AgentPendingSlashes (r:1 w:1)
+	/// Proof: Reputation AgentPendingSlashes (max_values: None, max_size: Some(180), added: 2655, mode: MaxEncodedLen)
+	fn cancel_deferred_slash() -> Weight {
+		Weight::from_parts(18_000_000, 3645)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Reputation Reputation (r:1 w:1)
+	/// Proof: Reputation Reputation (max_values: None, max_size: Some(256), added: 2731, mode: MaxEncodedLen)
+	fn lock_stake() -> Weight {
+		Weight::from_parts(13_000_000, 3721)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Reputation Reputation (r:1 w:1)
+	/// Proof: Reputation Reputation (max_values: None, max_size: Some(256), added: 2731, mode: MaxEncodedLen)
+	/// Storage: Reputation Unbonding (r:1 w:1)
+	/// Proof: Reputation Unbonding (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Reputation TotalStake (r:1 w:1)
+	/// Proof: Reputation TotalStake (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Reputation TotalStakeWeight (r:1 w:1)
+	/// Proof: Reputation TotalStakeWeight (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn repair_stake_accounting() -> Weight {
+		Weight::from_parts(22_000_000, 3721)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	/// Storage: Reputation Reputation (r:1 w:1)
+	/// Proof: Reputation Reputation (max_values: None, max_size: Some(256), added: 2731, mode: MaxEncodedLen)
+	/// Storage: Reputation TotalStake (r:1 w:0)
+	/// Proof: Reputation TotalStake (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Reputation ParticipationStreaks (r:1 w:1)
+	/// Proof: Reputation ParticipationStreaks (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Reputation RewardCurve (r:1 w:0)
+	/// Proof: Reputation RewardCurve (max_values: Some(1), max_size: Some(258), added: 753, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 64]`.
+	fn reward_consensus_many(n: u32) -> Weight {
+		Weight::from_parts(4_000_000, 3721)
+			// Standard Error: 6_000
+			.saturating_add(Weight::from_parts(18_000_000, 2731).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n as u64)))
+	}
 }
 
 // For backwards compatibility and tests
@@ -126,20 +245,88 @@ impl WeightInfo for () {
 	}
 
 	fn unstake() -> Weight {
-		Weight::from_parts(23_000_000, 4714)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		Weight::from_parts(25_000_000, 4714)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 
 	fn reward_consensus() -> Weight {
-		Weight::from_parts(19_000_000, 3721)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		Weight::from_parts(21_000_000, 3721)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 
 	fn report_offense() -> Weight {
 		Weight::from_parts(37_000_000, 6665)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+
+	fn set_compound_rewards() -> Weight {
+		Weight::from_parts(13_000_000, 5513)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	fn set_trust_anchor() -> Weight {
+		Weight::from_parts(12_000_000, 3514)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	fn import_reputation_digest() -> Weight {
+		Weight::from_parts(14_000_000, 3514)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	fn import_reputation_attestation() -> Weight {
+		Weight::from_parts(33_000_000, 3514)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	fn export_reputation_digest() -> Weight {
+		Weight::from_parts(45_000_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
+
+	fn exit_quarantine_early() -> Weight {
+		Weight::from_parts(32_000_000, 4714)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+
+	fn set_reward_curve() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	fn force_unban() -> Weight {
+		Weight::from_parts(16_000_000, 3721)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn cancel_deferred_slash() -> Weight {
+		Weight::from_parts(18_000_000, 3645)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn lock_stake() -> Weight {
+		Weight::from_parts(13_000_000, 3721)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn repair_stake_accounting() -> Weight {
+		Weight::from_parts(22_000_000, 3721)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
-} 
\ No newline at end of file
+	fn reward_consensus_many(n: u32) -> Weight {
+		Weight::from_parts(4_000_000, 3721)
+			.saturating_add(Weight::from_parts(18_000_000, 2731).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n as u64)))
+	}
+}
@@ -0,0 +1,794 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        lib.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Statement of work / task assignment pallet for C-Suite blockchain
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! # Tasks Pallet
+//!
+//! A pallet for rewarded statements of work carried out by C-Suite agents.
+//!
+//! ## Overview
+//!
+//! This pallet provides functionality to:
+//! - Create tasks with a reward that is reserved from the creator until the task ends
+//! - Offer tasks to an agent directly, or to the first available committee member
+//! - Complete tasks by presenting a recall record and a finalized consensus log as evidence
+//! - Fail tasks that miss their deadline, or that the creator rejects and a dispute upholds
+//!
+//! ## Task lifecycle
+//!
+//! A task starts `Open`. The creator offers it to an agent, who must accept it before any
+//! deadline runs; the agent may decline instead, reopening the task. Once accepted it has until
+//! its deadline to be completed. The assignee completes it by pointing at a recall record they
+//! signed and a finalized consensus log they were involved in, both created after they accepted
+//! the task and checked through [`TaskEvidence`]. Each record and log can only be claimed by one
+//! task. Completion pays the reward to the assignee and reports success through
+//! [`TaskOutcome`]; failure refunds the creator and reports the failure so the assignee can be
+//! penalised.
+//!
+//! A creator who rejects the work does not fail the task directly: the task is `Disputed` and
+//! its deadline stops running until `DisputeOrigin` resolves it. An upheld rejection fails the
+//! task; a dismissed one cancels it, refunding the creator without penalising the assignee. The
+//! assignee can still complete a disputed task with valid evidence.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+
+pub use weights::WeightInfo;
+
+use frame_support::weights::Weight;
+use sp_std::vec::Vec;
+
+/// Identifier of a task
+pub type TaskId = u64;
+
+/// Checks the evidence agents present when completing a task
+pub trait TaskEvidence<AccountId, Hash, BlockNumber> {
+    /// Whether the recall record exists, carries the agent's signature and was created at or
+    /// after `since`
+    fn record_signed_by(record_id: u64, agent_id: &AccountId, since: BlockNumber) -> bool;
+
+    /// Whether the consensus log is finalized, involved the agent and was submitted at or after
+    /// `since`
+    fn log_finalized_with(log_id: &Hash, agent_id: &AccountId, since: BlockNumber) -> bool;
+}
+
+/// No evidence source: nothing can be proven
+impl<AccountId, Hash, BlockNumber> TaskEvidence<AccountId, Hash, BlockNumber> for () {
+    fn record_signed_by(_record_id: u64, _agent_id: &AccountId, _since: BlockNumber) -> bool {
+        false
+    }
+
+    fn log_finalized_with(_log_id: &Hash, _agent_id: &AccountId, _since: BlockNumber) -> bool {
+        false
+    }
+}
+
+/// Agents eligible for committee-selected task assignment, in order of preference
+pub trait TaskCommittee<AccountId> {
+    fn members() -> Vec<AccountId>;
+}
+
+impl<AccountId> TaskCommittee<AccountId> for () {
+    fn members() -> Vec<AccountId> {
+        Vec::new()
+    }
+}
+
+/// Notified when an assigned task ends, e.g. to adjust the assignee's reputation
+pub trait TaskOutcome<AccountId> {
+    /// Called after the agent completed a task, returning the weight consumed
+    fn on_task_completed(agent_id: &AccountId) -> Weight;
+
+    /// Called after a task assigned to the agent failed, returning the weight consumed
+    fn on_task_failed(agent_id: &AccountId) -> Weight;
+}
+
+impl<AccountId> TaskOutcome<AccountId> for () {
+    fn on_task_completed(_agent_id: &AccountId) -> Weight {
+        Weight::zero()
+    }
+
+    fn on_task_failed(_agent_id: &AccountId) -> Weight {
+        Weight::zero()
+    }
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{BalanceStatus, Currency, ReservableCurrency},
+    };
+    use frame_system::pallet_prelude::*;
+    use csuite_primitives::{AgentInspector, AgentStatusProvider};
+    use sp_runtime::traits::Zero;
+
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Agent registry lookup for assignees
+        type Agents: AgentInspector<Self::AccountId> + AgentStatusProvider<Self::AccountId>;
+
+        /// Currency used to escrow task rewards
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// Checks completion evidence against the recall and consensus pallets
+        type Evidence: TaskEvidence<Self::AccountId, Self::Hash, BlockNumberFor<Self>>;
+
+        /// Source of agents for committee-selected assignment
+        type Committee: TaskCommittee<Self::AccountId>;
+
+        /// Hook for rewarding or penalising assignees
+        type Outcome: TaskOutcome<Self::AccountId>;
+
+        /// Origin resolving disputes over work rejected by a creator
+        type DisputeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Maximum length of a task description CID
+        #[pallet::constant]
+        type MaxDescriptionLength: Get<u32>;
+
+        /// Longest time an assignee may be given to complete a task
+        #[pallet::constant]
+        type MaxTaskDuration: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of tasks assigned to a single agent at once
+        #[pallet::constant]
+        type MaxActiveTasksPerAgent: Get<u32>;
+
+        /// Maximum number of task deadlines falling in the same block
+        #[pallet::constant]
+        type MaxDeadlinesPerBlock: Get<u32>;
+
+        /// Maximum number of committee members considered for committee-selected assignment
+        #[pallet::constant]
+        type MaxCommitteeCandidates: Get<u32>;
+
+        /// Weight information for extrinsics
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Status of a task
+    #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum TaskStatus {
+        /// Waiting for an assignee
+        Open,
+        /// Being worked on by the assignee
+        Assigned,
+        /// Completed and paid out
+        Completed,
+        /// Missed its deadline or its rejection by the creator was upheld
+        Failed,
+        /// Withdrawn by the creator before assignment, or rejected in a dismissed dispute
+        Cancelled,
+        /// Offered to the assignee, who has not accepted it yet
+        Offered,
+        /// Rejected by the creator, waiting for the dispute to be resolved
+        Disputed,
+    }
+
+    /// Task information stored on-chain
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct TaskInfo<T: Config> {
+        /// Account that created and funds the task
+        pub creator: T::AccountId,
+        /// Reward reserved from the creator and paid to the assignee on completion
+        pub reward: BalanceOf<T>,
+        /// IPFS CID of the statement of work
+        pub description: BoundedVec<u8, T::MaxDescriptionLength>,
+        /// Blocks the assignee has to complete the task
+        pub duration: BlockNumberFor<T>,
+        /// Current status
+        pub status: TaskStatus,
+        /// Agent the task is offered to or being worked on by
+        pub assignee: Option<T::AccountId>,
+        /// Block by which the assignee must complete the task, set once they accept it
+        pub deadline: Option<BlockNumberFor<T>>,
+        /// Recall record presented on completion
+        pub record_id: Option<u64>,
+        /// Consensus log presented on completion
+        pub log_id: Option<T::Hash>,
+        /// When the task was created
+        pub created_at: BlockNumberFor<T>,
+    }
+
+    /// Storage for all tasks
+    #[pallet::storage]
+    #[pallet::getter(fn tasks)]
+    pub type Tasks<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        TaskId,
+        TaskInfo<T>,
+        OptionQuery,
+    >;
+
+    /// Next available task ID
+    #[pallet::storage]
+    #[pallet::getter(fn next_task_id)]
+    pub type NextTaskId<T: Config> = StorageValue<_, TaskId, ValueQuery>;
+
+    /// Tasks currently assigned to each agent
+    #[pallet::storage]
+    #[pallet::getter(fn assigned_tasks)]
+    pub type AssignedTasks<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<TaskId, T::MaxActiveTasksPerAgent>,
+        ValueQuery,
+    >;
+
+    /// Assigned tasks by the block their deadline falls in
+    #[pallet::storage]
+    #[pallet::getter(fn task_deadlines)]
+    pub type TaskDeadlines<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<TaskId, T::MaxDeadlinesPerBlock>,
+        ValueQuery,
+    >;
+
+    /// Task each recall record was claimed as completion evidence by
+    #[pallet::storage]
+    #[pallet::getter(fn claimed_records)]
+    pub type ClaimedRecords<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64,
+        TaskId,
+        OptionQuery,
+    >;
+
+    /// Task each consensus log was claimed as completion evidence by
+    #[pallet::storage]
+    #[pallet::getter(fn claimed_logs)]
+    pub type ClaimedLogs<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::Hash,
+        TaskId,
+        OptionQuery,
+    >;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A new task was created
+        TaskCreated {
+            task_id: TaskId,
+            creator: T::AccountId,
+            reward: BalanceOf<T>,
+        },
+        /// A task was offered to an agent
+        TaskOffered {
+            task_id: TaskId,
+            assignee: T::AccountId,
+        },
+        /// The offered agent declined a task, which is open again
+        TaskDeclined {
+            task_id: TaskId,
+            assignee: T::AccountId,
+        },
+        /// An agent accepted a task and its deadline started running
+        TaskAssigned {
+            task_id: TaskId,
+            assignee: T::AccountId,
+            deadline: BlockNumberFor<T>,
+        },
+        /// A task was completed and its reward paid out
+        TaskCompleted {
+            task_id: TaskId,
+            assignee: T::AccountId,
+            record_id: u64,
+            log_id: T::Hash,
+        },
+        /// A task failed and its reward was refunded to the creator
+        TaskFailed {
+            task_id: TaskId,
+            assignee: T::AccountId,
+        },
+        /// An unassigned task was withdrawn by its creator
+        TaskCancelled {
+            task_id: TaskId,
+        },
+        /// The creator rejected the work on a task
+        TaskDisputed {
+            task_id: TaskId,
+            assignee: T::AccountId,
+        },
+        /// A dispute over rejected work was resolved
+        TaskDisputeResolved {
+            task_id: TaskId,
+            upheld: bool,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Task does not exist
+        TaskNotFound,
+        /// Caller is not the task creator
+        NotCreator,
+        /// Caller is not the task assignee
+        NotAssignee,
+        /// Task is not in the status required for this action
+        InvalidTaskStatus,
+        /// Task description is empty or too long
+        InvalidDescription,
+        /// Task duration is zero or exceeds the maximum
+        InvalidDuration,
+        /// Task reward must be greater than zero
+        ZeroReward,
+        /// Creator cannot reserve the reward
+        InsufficientBalance,
+        /// Agent is not registered
+        AgentNotFound,
+        /// Agent is not online
+        AgentNotActive,
+        /// Creator cannot assign their own task to themselves
+        SelfAssignment,
+        /// Agent already has the maximum number of active tasks
+        TooManyActiveTasks,
+        /// No committee member is available to take the task
+        NoEligibleAssignee,
+        /// Too many task deadlines fall in the same block
+        TooManyDeadlines,
+        /// The recall record does not exist or was not signed by the assignee
+        InvalidRecord,
+        /// The consensus log is not finalized or did not involve the assignee
+        LogNotFinalized,
+        /// The record or log was already claimed as evidence by another task
+        EvidenceAlreadyClaimed,
+        /// The creator's reserved reward no longer covers the payout
+        RewardUnavailable,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Fail assigned tasks whose deadline has been reached
+        fn on_initialize(block: BlockNumberFor<T>) -> Weight {
+            let task_ids = TaskDeadlines::<T>::take(block);
+            let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+            for task_id in task_ids {
+                weight = weight.saturating_add(T::DbWeight::get().reads(1));
+                let Some(task) = Tasks::<T>::get(task_id) else { continue };
+                if task.status == TaskStatus::Assigned && task.deadline == Some(block) {
+                    weight = weight.saturating_add(Self::fail(task_id, task));
+                }
+            }
+
+            weight
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Create a new task, reserving its reward from the caller
+        ///
+        /// Parameters:
+        /// - `description`: IPFS CID of the statement of work
+        /// - `reward`: Amount paid to the assignee on completion
+        /// - `duration`: Blocks the assignee has to complete the task once assigned
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::create_task())]
+        pub fn create_task(
+            origin: OriginFor<T>,
+            description: Vec<u8>,
+            reward: BalanceOf<T>,
+            duration: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let creator = ensure_signed(origin)?;
+
+            ensure!(!description.is_empty(), Error::<T>::InvalidDescription);
+            let description = BoundedVec::<u8, T::MaxDescriptionLength>::try_from(description)
+                .map_err(|_| Error::<T>::InvalidDescription)?;
+            ensure!(
+                !duration.is_zero() && duration <= T::MaxTaskDuration::get(),
+                Error::<T>::InvalidDuration
+            );
+            ensure!(!reward.is_zero(), Error::<T>::ZeroReward);
+
+            T::Currency::reserve(&creator, reward).map_err(|_| Error::<T>::InsufficientBalance)?;
+
+            let task_id = Self::next_task_id();
+            Tasks::<T>::insert(task_id, TaskInfo {
+                creator: creator.clone(),
+                reward,
+                description,
+                duration,
+                status: TaskStatus::Open,
+                assignee: None,
+                deadline: None,
+                record_id: None,
+                log_id: None,
+                created_at: <frame_system::Pallet<T>>::block_number(),
+            });
+            NextTaskId::<T>::put(task_id.saturating_add(1));
+
+            // Emit event
+            Self::deposit_event(Event::TaskCreated {
+                task_id,
+                creator,
+                reward,
+            });
+
+            Ok(())
+        }
+
+        /// Offer an open task to an agent
+        ///
+        /// Can only be called by the task creator. The task is assigned once the agent accepts
+        /// it with `accept_task`.
+        ///
+        /// Parameters:
+        /// - `task_id`: The task to offer
+        /// - `assignee`: The agent to carry it out
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::assign_task())]
+        pub fn assign_task(
+            origin: OriginFor<T>,
+            task_id: TaskId,
+            assignee: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let task = Tasks::<T>::get(task_id).ok_or(Error::<T>::TaskNotFound)?;
+            ensure!(task.creator == who, Error::<T>::NotCreator);
+            ensure!(task.status == TaskStatus::Open, Error::<T>::InvalidTaskStatus);
+            ensure!(assignee != task.creator, Error::<T>::SelfAssignment);
+
+            // Ensure agent exists and is active
+            ensure!(T::Agents::is_registered(&assignee), Error::<T>::AgentNotFound);
+            ensure!(T::Agents::is_online(&assignee), Error::<T>::AgentNotActive);
+
+            Self::offer(task_id, task, assignee);
+
+            Ok(())
+        }
+
+        /// Offer an open task to the first available committee member
+        ///
+        /// The first `MaxCommitteeCandidates` committee members are tried in order, skipping the
+        /// creator, agents that are not online and agents already at their active task limit.
+        /// Can only be called by the task creator.
+        ///
+        /// Parameters:
+        /// - `task_id`: The task to offer
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::assign_by_committee(T::MaxCommitteeCandidates::get()))]
+        pub fn assign_by_committee(
+            origin: OriginFor<T>,
+            task_id: TaskId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let task = Tasks::<T>::get(task_id).ok_or(Error::<T>::TaskNotFound)?;
+            ensure!(task.creator == who, Error::<T>::NotCreator);
+            ensure!(task.status == TaskStatus::Open, Error::<T>::InvalidTaskStatus);
+
+            let assignee = T::Committee::members()
+                .into_iter()
+                .take(T::MaxCommitteeCandidates::get() as usize)
+                .find(|member| {
+                    member != &task.creator &&
+                    T::Agents::is_online(member) &&
+                    !Self::assigned_tasks(member).is_full()
+                })
+                .ok_or(Error::<T>::NoEligibleAssignee)?;
+
+            Self::offer(task_id, task, assignee);
+
+            Ok(())
+        }
+
+        /// Complete an assigned task
+        ///
+        /// The assignee presents the recall record holding the work and the finalized consensus
+        /// log signing it off, both created after they accepted the task and not claimed by
+        /// another task. The reward is paid to the assignee. Disputed tasks can be completed too.
+        ///
+        /// Parameters:
+        /// - `task_id`: The task being completed
+        /// - `record_id`: Recall record signed by the assignee
+        /// - `log_id`: Finalized consensus log involving the assignee
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::complete_task())]
+        pub fn complete_task(
+            origin: OriginFor<T>,
+            task_id: TaskId,
+            record_id: u64,
+            log_id: T::Hash,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut task = Tasks::<T>::get(task_id).ok_or(Error::<T>::TaskNotFound)?;
+            ensure!(
+                matches!(task.status, TaskStatus::Assigned | TaskStatus::Disputed),
+                Error::<T>::InvalidTaskStatus
+            );
+            ensure!(task.assignee.as_ref() == Some(&who), Error::<T>::NotAssignee);
+
+            // Only work done since the assignee accepted the task counts
+            let accepted_at = task.deadline.unwrap_or_default().saturating_sub(task.duration);
+            ensure!(T::Evidence::record_signed_by(record_id, &who, accepted_at), Error::<T>::InvalidRecord);
+            ensure!(T::Evidence::log_finalized_with(&log_id, &who, accepted_at), Error::<T>::LogNotFinalized);
+            ensure!(
+                !ClaimedRecords::<T>::contains_key(record_id) && !ClaimedLogs::<T>::contains_key(log_id),
+                Error::<T>::EvidenceAlreadyClaimed
+            );
+
+            // Pay out the escrowed reward
+            let remainder = T::Currency::repatriate_reserved(&task.creator, &who, task.reward, BalanceStatus::Free)?;
+            ensure!(remainder.is_zero(), Error::<T>::RewardUnavailable);
+
+            ClaimedRecords::<T>::insert(record_id, task_id);
+            ClaimedLogs::<T>::insert(log_id, task_id);
+            task.status = TaskStatus::Completed;
+            task.record_id = Some(record_id);
+            task.log_id = Some(log_id);
+            Tasks::<T>::insert(task_id, task);
+            Self::release(&who, task_id);
+
+            T::Outcome::on_task_completed(&who);
+
+            // Emit event
+            Self::deposit_event(Event::TaskCompleted {
+                task_id,
+                assignee: who,
+                record_id,
+                log_id,
+            });
+
+            Ok(())
+        }
+
+        /// Reject the work on an assigned task, disputing it
+        ///
+        /// Can only be called by the task creator. The task's deadline stops running and the
+        /// reward stays reserved until `DisputeOrigin` resolves the dispute.
+        ///
+        /// Parameters:
+        /// - `task_id`: The task to reject
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::fail_task())]
+        pub fn fail_task(
+            origin: OriginFor<T>,
+            task_id: TaskId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut task = Tasks::<T>::get(task_id).ok_or(Error::<T>::TaskNotFound)?;
+            ensure!(task.creator == who, Error::<T>::NotCreator);
+            ensure!(task.status == TaskStatus::Assigned, Error::<T>::InvalidTaskStatus);
+            let assignee = task.assignee.clone().ok_or(Error::<T>::InvalidTaskStatus)?;
+
+            task.status = TaskStatus::Disputed;
+            Tasks::<T>::insert(task_id, task);
+
+            // Emit event
+            Self::deposit_event(Event::TaskDisputed {
+                task_id,
+                assignee,
+            });
+
+            Ok(())
+        }
+
+        /// Withdraw an unassigned task, refunding its reward
+        ///
+        /// Can only be called by the task creator, also while the task is offered.
+        ///
+        /// Parameters:
+        /// - `task_id`: The task to cancel
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::cancel_task())]
+        pub fn cancel_task(
+            origin: OriginFor<T>,
+            task_id: TaskId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut task = Tasks::<T>::get(task_id).ok_or(Error::<T>::TaskNotFound)?;
+            ensure!(task.creator == who, Error::<T>::NotCreator);
+            ensure!(
+                matches!(task.status, TaskStatus::Open | TaskStatus::Offered),
+                Error::<T>::InvalidTaskStatus
+            );
+
+            T::Currency::unreserve(&task.creator, task.reward);
+            task.status = TaskStatus::Cancelled;
+            Tasks::<T>::insert(task_id, task);
+
+            // Emit event
+            Self::deposit_event(Event::TaskCancelled {
+                task_id,
+            });
+
+            Ok(())
+        }
+
+        /// Accept a task offered to the caller, starting its deadline
+        ///
+        /// Parameters:
+        /// - `task_id`: The offered task
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::accept_task())]
+        pub fn accept_task(
+            origin: OriginFor<T>,
+            task_id: TaskId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let task = Tasks::<T>::get(task_id).ok_or(Error::<T>::TaskNotFound)?;
+            ensure!(task.status == TaskStatus::Offered, Error::<T>::InvalidTaskStatus);
+            ensure!(task.assignee.as_ref() == Some(&who), Error::<T>::NotAssignee);
+
+            Self::assign(task_id, task, who)
+        }
+
+        /// Decline a task offered to the caller, reopening it
+        ///
+        /// Parameters:
+        /// - `task_id`: The offered task
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::decline_task())]
+        pub fn decline_task(
+            origin: OriginFor<T>,
+            task_id: TaskId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut task = Tasks::<T>::get(task_id).ok_or(Error::<T>::TaskNotFound)?;
+            ensure!(task.status == TaskStatus::Offered, Error::<T>::InvalidTaskStatus);
+            ensure!(task.assignee.as_ref() == Some(&who), Error::<T>::NotAssignee);
+
+            task.status = TaskStatus::Open;
+            task.assignee = None;
+            Tasks::<T>::insert(task_id, task);
+
+            // Emit event
+            Self::deposit_event(Event::TaskDeclined {
+                task_id,
+                assignee: who,
+            });
+
+            Ok(())
+        }
+
+        /// Resolve the dispute over a task whose work the creator rejected
+        ///
+        /// An upheld rejection fails the task, refunding the creator and penalising the
+        /// assignee. A dismissed one cancels the task, refunding the creator only.
+        ///
+        /// Parameters:
+        /// - `task_id`: The disputed task
+        /// - `upheld`: Whether the creator's rejection stands
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::resolve_dispute())]
+        pub fn resolve_dispute(
+            origin: OriginFor<T>,
+            task_id: TaskId,
+            upheld: bool,
+        ) -> DispatchResult {
+            T::DisputeOrigin::ensure_origin(origin)?;
+
+            let mut task = Tasks::<T>::get(task_id).ok_or(Error::<T>::TaskNotFound)?;
+            ensure!(task.status == TaskStatus::Disputed, Error::<T>::InvalidTaskStatus);
+
+            if upheld {
+                Self::fail(task_id, task);
+            } else {
+                T::Currency::unreserve(&task.creator, task.reward);
+                if let Some(assignee) = task.assignee.as_ref() {
+                    Self::release(assignee, task_id);
+                }
+                task.status = TaskStatus::Cancelled;
+                Tasks::<T>::insert(task_id, task);
+            }
+
+            // Emit event
+            Self::deposit_event(Event::TaskDisputeResolved {
+                task_id,
+                upheld,
+            });
+
+            Ok(())
+        }
+    }
+
+    // Helper functions
+    impl<T: Config> Pallet<T> {
+        /// Offer a task to the agent expected to accept it
+        fn offer(task_id: TaskId, mut task: TaskInfo<T>, assignee: T::AccountId) {
+            task.status = TaskStatus::Offered;
+            task.assignee = Some(assignee.clone());
+            Tasks::<T>::insert(task_id, task);
+
+            // Emit event
+            Self::deposit_event(Event::TaskOffered {
+                task_id,
+                assignee,
+            });
+        }
+
+        /// Assign a task and schedule its deadline
+        fn assign(task_id: TaskId, mut task: TaskInfo<T>, assignee: T::AccountId) -> DispatchResult {
+            let deadline = <frame_system::Pallet<T>>::block_number().saturating_add(task.duration);
+
+            AssignedTasks::<T>::try_mutate(&assignee, |tasks| tasks.try_push(task_id))
+                .map_err(|_| Error::<T>::TooManyActiveTasks)?;
+            TaskDeadlines::<T>::try_mutate(deadline, |tasks| tasks.try_push(task_id))
+                .map_err(|_| Error::<T>::TooManyDeadlines)?;
+
+            task.status = TaskStatus::Assigned;
+            task.assignee = Some(assignee.clone());
+            task.deadline = Some(deadline);
+            Tasks::<T>::insert(task_id, task);
+
+            // Emit event
+            Self::deposit_event(Event::TaskAssigned {
+                task_id,
+                assignee,
+                deadline,
+            });
+
+            Ok(())
+        }
+
+        /// Fail an assigned task: refund the creator and report the assignee
+        fn fail(task_id: TaskId, mut task: TaskInfo<T>) -> Weight {
+            let mut weight = T::DbWeight::get().reads_writes(2, 3);
+            T::Currency::unreserve(&task.creator, task.reward);
+            task.status = TaskStatus::Failed;
+
+            if let Some(assignee) = task.assignee.clone() {
+                Self::release(&assignee, task_id);
+                weight = weight.saturating_add(T::Outcome::on_task_failed(&assignee));
+
+                Self::deposit_event(Event::TaskFailed {
+                    task_id,
+                    assignee,
+                });
+            }
+
+            Tasks::<T>::insert(task_id, task);
+            weight
+        }
+
+        /// Remove a task from its assignee's active list
+        fn release(assignee: &T::AccountId, task_id: TaskId) {
+            AssignedTasks::<T>::mutate(assignee, |tasks| tasks.retain(|id| *id != task_id));
+        }
+    }
+}
@@ -0,0 +1,231 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        mock.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Mock runtime for testing the task assignment pallet
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+use crate as pallet_tasks;
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{ConstU16, ConstU32, ConstU64, Hooks},
+    weights::Weight,
+};
+use sp_core::H256;
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage, Percent,
+};
+use std::cell::RefCell;
+use pallet_agent_registry::AgentRole;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+    pub enum Test
+    {
+        System: frame_system,
+        Balances: pallet_balances,
+        AgentRegistry: pallet_agent_registry,
+        Tasks: pallet_tasks,
+    }
+);
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type RuntimeCall = RuntimeCall;
+    type Nonce = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Block = Block;
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type BlockHashCount = ConstU64<250>;
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<u64>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ConstU16<42>;
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
+}
+
+parameter_types! {
+    pub const MaxMetadataLength: u32 = 1024;
+    pub const OverloadThreshold: Percent = Percent::from_percent(90);
+    pub AllFeatures: pallet_agent_registry::FeatureFlags = pallet_agent_registry::FeatureFlags::all();
+}
+
+impl pallet_agent_registry::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type MaxMetadataLength = MaxMetadataLength;
+    type MaxMetadataAllowance = ConstU32<4096>;
+    type MetadataTierBytes = ConstU32<1024>;
+    type MetadataTierDeposit = ConstU64<100>;
+    type Currency = Balances;
+    type FeeExemption = ();
+    type RetirementGracePeriod = ConstU64<10>;
+    type OnRetirement = ();
+    type OnRotation = ();
+    type OnStatusChange = ();
+    type MaxEndpoints = ConstU32<4>;
+    type MaxEndpointLength = ConstU32<64>;
+    type EndpointTtl = ConstU64<100>;
+    type AnnouncementCooldown = ConstU64<10>;
+    type StaleAfter = ConstU64<50>;
+    type MaxSweepPerBlock = ConstU32<8>;
+    type MaxProxies = ConstU32<2>;
+    type MaxCapabilities = ConstU32<2>;
+    type DefaultFeatures = AllFeatures;
+    type MaxTelemetrySnapshots = ConstU32<3>;
+    type TelemetryInterval = ConstU64<5>;
+    type TelemetryTtl = ConstU64<20>;
+    type OverloadThreshold = OverloadThreshold;
+    type OverloadQueueDepth = ConstU32<100>;
+    type MaxMaintenanceWindows = ConstU32<3>;
+    type MaxMaintenanceDuration = ConstU64<100>;
+    type RemoteOrigin = frame_system::EnsureNever<u32>;
+    type MaxRemoteProofSize = ConstU32<4_096>;
+    type WeightInfo = ();
+}
+
+thread_local! {
+    static SIGNED_RECORDS: RefCell<Vec<(u64, u64, u64)>> = RefCell::new(Vec::new());
+    static FINALIZED_LOGS: RefCell<Vec<(H256, u64, u64)>> = RefCell::new(Vec::new());
+    static COMMITTEE: RefCell<Vec<u64>> = RefCell::new(Vec::new());
+    static OUTCOMES: RefCell<Vec<(u64, bool)>> = RefCell::new(Vec::new());
+}
+
+/// Evidence backed by the records and logs registered in the test
+pub struct MockEvidence;
+impl crate::TaskEvidence<u64, H256, u64> for MockEvidence {
+    fn record_signed_by(record_id: u64, agent_id: &u64, since: u64) -> bool {
+        SIGNED_RECORDS.with(|r| r.borrow().iter().any(|(id, agent, created_at)| {
+            *id == record_id && agent == agent_id && *created_at >= since
+        }))
+    }
+
+    fn log_finalized_with(log_id: &H256, agent_id: &u64, since: u64) -> bool {
+        FINALIZED_LOGS.with(|l| l.borrow().iter().any(|(id, agent, submitted_at)| {
+            id == log_id && agent == agent_id && *submitted_at >= since
+        }))
+    }
+}
+
+/// Committee set by the test
+pub struct MockCommittee;
+impl crate::TaskCommittee<u64> for MockCommittee {
+    fn members() -> Vec<u64> {
+        COMMITTEE.with(|c| c.borrow().clone())
+    }
+}
+
+/// Records task outcomes as `(agent, completed)`
+pub struct RecordOutcomes;
+impl crate::TaskOutcome<u64> for RecordOutcomes {
+    fn on_task_completed(agent_id: &u64) -> Weight {
+        OUTCOMES.with(|o| o.borrow_mut().push((*agent_id, true)));
+        Weight::zero()
+    }
+
+    fn on_task_failed(agent_id: &u64) -> Weight {
+        OUTCOMES.with(|o| o.borrow_mut().push((*agent_id, false)));
+        Weight::zero()
+    }
+}
+
+impl pallet_tasks::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Agents = AgentRegistry;
+    type Currency = Balances;
+    type Evidence = MockEvidence;
+    type Committee = MockCommittee;
+    type Outcome = RecordOutcomes;
+    type DisputeOrigin = frame_system::EnsureRoot<u64>;
+    type MaxDescriptionLength = ConstU32<64>;
+    type MaxTaskDuration = ConstU64<100>;
+    type MaxActiveTasksPerAgent = ConstU32<1>;
+    type MaxDeadlinesPerBlock = ConstU32<4>;
+    type MaxCommitteeCandidates = ConstU32<8>;
+    type WeightInfo = ();
+}
+
+/// Make `record_id` count as a recall record signed by `agent_id`, created in the current block
+pub fn add_signed_record(record_id: u64, agent_id: u64) {
+    let now = System::block_number();
+    SIGNED_RECORDS.with(|r| r.borrow_mut().push((record_id, agent_id, now)));
+}
+
+/// Make `log_id` count as a finalized consensus log involving `agent_id`, submitted in the
+/// current block
+pub fn add_finalized_log(log_id: H256, agent_id: u64) {
+    let now = System::block_number();
+    FINALIZED_LOGS.with(|l| l.borrow_mut().push((log_id, agent_id, now)));
+}
+
+/// Set the committee used for committee-selected assignment
+pub fn set_committee(members: Vec<u64>) {
+    COMMITTEE.with(|c| *c.borrow_mut() = members);
+}
+
+/// Task outcomes reported so far
+pub fn outcomes() -> Vec<(u64, bool)> {
+    OUTCOMES.with(|o| o.borrow().clone())
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    SIGNED_RECORDS.with(|r| r.borrow_mut().clear());
+    FINALIZED_LOGS.with(|l| l.borrow_mut().clear());
+    COMMITTEE.with(|c| c.borrow_mut().clear());
+    OUTCOMES.with(|o| o.borrow_mut().clear());
+
+    let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: (1..=5).map(|who| (who, 10_000)).collect(),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut storage)
+    .unwrap();
+
+    let mut ext: sp_io::TestExternalities = storage.into();
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+// Helper function to register an agent for testing
+pub fn register_test_agent(agent_id: u64, role: AgentRole) {
+    pallet_agent_registry::Pallet::<Test>::register_agent(
+        RuntimeOrigin::signed(agent_id),
+        role,
+        None,
+    ).expect("Agent should be registered successfully");
+}
+
+/// Run blocks up to `n`, calling the pallet hooks on each
+pub fn run_to_block(n: u64) {
+    while System::block_number() < n {
+        let next = System::block_number() + 1;
+        System::set_block_number(next);
+        Tasks::on_initialize(next);
+    }
+}
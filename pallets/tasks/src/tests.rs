@@ -0,0 +1,322 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        tests.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Tests for the task assignment pallet
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+use crate::{mock::*, Error, Event, TaskStatus};
+use frame_support::{assert_noop, assert_ok, error::BadOrigin};
+use pallet_agent_registry::AgentRole;
+use sp_core::H256;
+
+const CREATOR: u64 = 1;
+const AGENT: u64 = 2;
+
+// Create a task from CREATOR with a reward of 500 and a duration of 10 blocks
+fn create_task() -> u64 {
+    let task_id = Tasks::next_task_id();
+    assert_ok!(Tasks::create_task(RuntimeOrigin::signed(CREATOR), b"QmStatementOfWork".to_vec(), 500, 10));
+    task_id
+}
+
+// Offer a task to AGENT and have it accepted
+fn assign_to_agent(task_id: u64) {
+    assert_ok!(Tasks::assign_task(RuntimeOrigin::signed(CREATOR), task_id, AGENT));
+    assert_ok!(Tasks::accept_task(RuntimeOrigin::signed(AGENT), task_id));
+}
+
+#[test]
+fn create_task_reserves_reward() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task();
+
+        let task = Tasks::tasks(task_id).unwrap();
+        assert_eq!(task.status, TaskStatus::Open);
+        assert_eq!(task.reward, 500);
+        assert_eq!(Balances::reserved_balance(CREATOR), 500);
+        System::assert_last_event(Event::TaskCreated { task_id, creator: CREATOR, reward: 500 }.into());
+
+        assert_noop!(
+            Tasks::create_task(RuntimeOrigin::signed(CREATOR), b"QmTooLong".to_vec(), 500, 101),
+            Error::<Test>::InvalidDuration
+        );
+        assert_noop!(
+            Tasks::create_task(RuntimeOrigin::signed(CREATOR), b"QmNoReward".to_vec(), 0, 10),
+            Error::<Test>::ZeroReward
+        );
+        assert_noop!(
+            Tasks::create_task(RuntimeOrigin::signed(CREATOR), b"QmTooExpensive".to_vec(), 20_000, 10),
+            Error::<Test>::InsufficientBalance
+        );
+    });
+}
+
+#[test]
+fn assign_task_checks_creator_and_agent() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task();
+
+        assert_noop!(
+            Tasks::assign_task(RuntimeOrigin::signed(AGENT), task_id, AGENT),
+            Error::<Test>::NotCreator
+        );
+        assert_noop!(
+            Tasks::assign_task(RuntimeOrigin::signed(CREATOR), task_id, AGENT),
+            Error::<Test>::AgentNotFound
+        );
+
+        register_test_agent(AGENT, AgentRole::Lyra);
+        assert_ok!(Tasks::assign_task(RuntimeOrigin::signed(CREATOR), task_id, AGENT));
+        System::assert_last_event(Event::TaskOffered { task_id, assignee: AGENT }.into());
+
+        // The offer starts no deadline until the agent accepts it
+        let task = Tasks::tasks(task_id).unwrap();
+        assert_eq!(task.status, TaskStatus::Offered);
+        assert_eq!(task.assignee, Some(AGENT));
+        assert_eq!(task.deadline, None);
+        assert!(Tasks::assigned_tasks(AGENT).is_empty());
+
+        assert_noop!(
+            Tasks::accept_task(RuntimeOrigin::signed(3), task_id),
+            Error::<Test>::NotAssignee
+        );
+        assert_ok!(Tasks::accept_task(RuntimeOrigin::signed(AGENT), task_id));
+
+        let task = Tasks::tasks(task_id).unwrap();
+        assert_eq!(task.status, TaskStatus::Assigned);
+        assert_eq!(task.deadline, Some(11));
+        assert_eq!(Tasks::assigned_tasks(AGENT).to_vec(), vec![task_id]);
+
+        // The agent is at its active task limit
+        let second = create_task();
+        assert_ok!(Tasks::assign_task(RuntimeOrigin::signed(CREATOR), second, AGENT));
+        assert_noop!(
+            Tasks::accept_task(RuntimeOrigin::signed(AGENT), second),
+            Error::<Test>::TooManyActiveTasks
+        );
+    });
+}
+
+#[test]
+fn assign_by_committee_picks_first_available_member() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(2, AgentRole::Lyra);
+        register_test_agent(3, AgentRole::Echo);
+        register_test_agent(4, AgentRole::Verdict);
+        set_committee(vec![CREATOR, 5, 2, 3]);
+
+        // Creator and unregistered 5 are skipped
+        let first = create_task();
+        assert_ok!(Tasks::assign_by_committee(RuntimeOrigin::signed(CREATOR), first));
+        assert_eq!(Tasks::tasks(first).unwrap().assignee, Some(2));
+        assert_ok!(Tasks::accept_task(RuntimeOrigin::signed(2), first));
+
+        // Agent 2 is busy, so the next member takes the task
+        let second = create_task();
+        assert_ok!(Tasks::assign_by_committee(RuntimeOrigin::signed(CREATOR), second));
+        assert_eq!(Tasks::tasks(second).unwrap().assignee, Some(3));
+        assert_ok!(Tasks::accept_task(RuntimeOrigin::signed(3), second));
+
+        let third = create_task();
+        assert_noop!(
+            Tasks::assign_by_committee(RuntimeOrigin::signed(CREATOR), third),
+            Error::<Test>::NoEligibleAssignee
+        );
+    });
+}
+
+#[test]
+fn complete_task_requires_evidence_and_pays_out() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(AGENT, AgentRole::Lyra);
+        let task_id = create_task();
+        assign_to_agent(task_id);
+        let log_id = H256::repeat_byte(7);
+
+        assert_noop!(
+            Tasks::complete_task(RuntimeOrigin::signed(3), task_id, 0, log_id),
+            Error::<Test>::NotAssignee
+        );
+        assert_noop!(
+            Tasks::complete_task(RuntimeOrigin::signed(AGENT), task_id, 0, log_id),
+            Error::<Test>::InvalidRecord
+        );
+        add_signed_record(0, AGENT);
+        assert_noop!(
+            Tasks::complete_task(RuntimeOrigin::signed(AGENT), task_id, 0, log_id),
+            Error::<Test>::LogNotFinalized
+        );
+        add_finalized_log(log_id, AGENT);
+
+        assert_ok!(Tasks::complete_task(RuntimeOrigin::signed(AGENT), task_id, 0, log_id));
+
+        let task = Tasks::tasks(task_id).unwrap();
+        assert_eq!(task.status, TaskStatus::Completed);
+        assert_eq!(task.record_id, Some(0));
+        assert_eq!(task.log_id, Some(log_id));
+        assert_eq!(Balances::reserved_balance(CREATOR), 0);
+        assert_eq!(Balances::free_balance(CREATOR), 9_500);
+        assert_eq!(Balances::free_balance(AGENT), 10_500);
+        assert!(Tasks::assigned_tasks(AGENT).is_empty());
+        assert_eq!(outcomes(), vec![(AGENT, true)]);
+        System::assert_last_event(Event::TaskCompleted { task_id, assignee: AGENT, record_id: 0, log_id }.into());
+        assert_eq!(Tasks::claimed_records(0), Some(task_id));
+        assert_eq!(Tasks::claimed_logs(log_id), Some(task_id));
+    });
+}
+
+#[test]
+fn evidence_must_postdate_acceptance_and_is_claimed_once() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(AGENT, AgentRole::Lyra);
+        let log_id = H256::repeat_byte(7);
+        add_signed_record(0, AGENT);
+        add_finalized_log(log_id, AGENT);
+
+        // Work done before the task was accepted does not count
+        run_to_block(2);
+        let first = create_task();
+        assign_to_agent(first);
+        assert_noop!(
+            Tasks::complete_task(RuntimeOrigin::signed(AGENT), first, 0, log_id),
+            Error::<Test>::InvalidRecord
+        );
+        add_signed_record(1, AGENT);
+        assert_noop!(
+            Tasks::complete_task(RuntimeOrigin::signed(AGENT), first, 1, log_id),
+            Error::<Test>::LogNotFinalized
+        );
+        let fresh_log = H256::repeat_byte(8);
+        add_finalized_log(fresh_log, AGENT);
+        assert_ok!(Tasks::complete_task(RuntimeOrigin::signed(AGENT), first, 1, fresh_log));
+
+        // The same evidence cannot complete another task
+        let second = create_task();
+        assign_to_agent(second);
+        assert_noop!(
+            Tasks::complete_task(RuntimeOrigin::signed(AGENT), second, 1, fresh_log),
+            Error::<Test>::EvidenceAlreadyClaimed
+        );
+    });
+}
+
+#[test]
+fn missed_deadline_fails_task_and_refunds_creator() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(AGENT, AgentRole::Lyra);
+        let task_id = create_task();
+        assign_to_agent(task_id);
+
+        run_to_block(10);
+        assert_eq!(Tasks::tasks(task_id).unwrap().status, TaskStatus::Assigned);
+
+        run_to_block(11);
+        assert_eq!(Tasks::tasks(task_id).unwrap().status, TaskStatus::Failed);
+        assert_eq!(Balances::reserved_balance(CREATOR), 0);
+        assert_eq!(Balances::free_balance(CREATOR), 10_000);
+        assert!(Tasks::assigned_tasks(AGENT).is_empty());
+        assert_eq!(outcomes(), vec![(AGENT, false)]);
+        System::assert_last_event(Event::TaskFailed { task_id, assignee: AGENT }.into());
+    });
+}
+
+#[test]
+fn creator_rejection_is_disputed_before_failing_the_task() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(AGENT, AgentRole::Lyra);
+        let task_id = create_task();
+
+        // An offer the agent has not accepted cannot be rejected
+        assert_ok!(Tasks::assign_task(RuntimeOrigin::signed(CREATOR), task_id, AGENT));
+        assert_noop!(
+            Tasks::fail_task(RuntimeOrigin::signed(CREATOR), task_id),
+            Error::<Test>::InvalidTaskStatus
+        );
+        assert_ok!(Tasks::accept_task(RuntimeOrigin::signed(AGENT), task_id));
+        assert_ok!(Tasks::fail_task(RuntimeOrigin::signed(CREATOR), task_id));
+        System::assert_last_event(Event::TaskDisputed { task_id, assignee: AGENT }.into());
+
+        // Nothing is refunded or penalised, and the deadline no longer runs
+        assert_eq!(Tasks::tasks(task_id).unwrap().status, TaskStatus::Disputed);
+        assert_eq!(Balances::reserved_balance(CREATOR), 500);
+        run_to_block(11);
+        assert!(outcomes().is_empty());
+
+        assert_noop!(Tasks::resolve_dispute(RuntimeOrigin::signed(CREATOR), task_id, true), BadOrigin);
+        assert_ok!(Tasks::resolve_dispute(RuntimeOrigin::root(), task_id, true));
+
+        assert_eq!(Tasks::tasks(task_id).unwrap().status, TaskStatus::Failed);
+        assert_eq!(Balances::reserved_balance(CREATOR), 0);
+        assert!(Tasks::assigned_tasks(AGENT).is_empty());
+        assert_eq!(outcomes(), vec![(AGENT, false)]);
+        System::assert_last_event(Event::TaskDisputeResolved { task_id, upheld: true }.into());
+    });
+}
+
+#[test]
+fn dismissed_dispute_cancels_the_task_without_penalty() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(AGENT, AgentRole::Lyra);
+        let task_id = create_task();
+        assign_to_agent(task_id);
+        assert_ok!(Tasks::fail_task(RuntimeOrigin::signed(CREATOR), task_id));
+
+        assert_ok!(Tasks::resolve_dispute(RuntimeOrigin::root(), task_id, false));
+
+        assert_eq!(Tasks::tasks(task_id).unwrap().status, TaskStatus::Cancelled);
+        assert_eq!(Balances::free_balance(CREATOR), 10_000);
+        assert!(Tasks::assigned_tasks(AGENT).is_empty());
+        assert!(outcomes().is_empty());
+    });
+}
+
+#[test]
+fn declined_offer_reopens_the_task() {
+    new_test_ext().execute_with(|| {
+        register_test_agent(AGENT, AgentRole::Lyra);
+        let task_id = create_task();
+        assert_ok!(Tasks::assign_task(RuntimeOrigin::signed(CREATOR), task_id, AGENT));
+
+        assert_noop!(
+            Tasks::decline_task(RuntimeOrigin::signed(3), task_id),
+            Error::<Test>::NotAssignee
+        );
+        assert_ok!(Tasks::decline_task(RuntimeOrigin::signed(AGENT), task_id));
+
+        let task = Tasks::tasks(task_id).unwrap();
+        assert_eq!(task.status, TaskStatus::Open);
+        assert_eq!(task.assignee, None);
+        System::assert_last_event(Event::TaskDeclined { task_id, assignee: AGENT }.into());
+        assert_noop!(
+            Tasks::accept_task(RuntimeOrigin::signed(AGENT), task_id),
+            Error::<Test>::InvalidTaskStatus
+        );
+    });
+}
+
+#[test]
+fn cancel_task_refunds_open_task() {
+    new_test_ext().execute_with(|| {
+        let task_id = create_task();
+
+        assert_noop!(
+            Tasks::cancel_task(RuntimeOrigin::signed(AGENT), task_id),
+            Error::<Test>::NotCreator
+        );
+        assert_ok!(Tasks::cancel_task(RuntimeOrigin::signed(CREATOR), task_id));
+
+        assert_eq!(Tasks::tasks(task_id).unwrap().status, TaskStatus::Cancelled);
+        assert_eq!(Balances::reserved_balance(CREATOR), 0);
+        assert_noop!(
+            Tasks::assign_task(RuntimeOrigin::signed(CREATOR), task_id, AGENT),
+            Error::<Test>::InvalidTaskStatus
+        );
+    });
+}
@@ -0,0 +1,183 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        weights.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Weight implementations for the task assignment pallet
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! Weights for pallet_tasks
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for pallet_tasks.
+pub trait WeightInfo {
+    fn create_task() -> Weight;
+    fn assign_task() -> Weight;
+    fn assign_by_committee(m: u32) -> Weight;
+    fn complete_task() -> Weight;
+    fn fail_task() -> Weight;
+    fn cancel_task() -> Weight;
+    fn accept_task() -> Weight;
+    fn decline_task() -> Weight;
+    fn resolve_dispute() -> Weight;
+}
+
+/// Weights for pallet_tasks using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    // Storage: Tasks NextTaskId (r:1 w:1)
+    // Storage: System Account (r:1 w:1)
+    // Storage: Tasks Tasks (r:0 w:1)
+    fn create_task() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+
+    // Storage: Tasks Tasks (r:1 w:1)
+    // Storage: AgentRegistry Agents (r:1 w:0)
+    // Storage: Tasks AssignedTasks (r:1 w:1)
+    // Storage: Tasks TaskDeadlines (r:1 w:1)
+    fn assign_task() -> Weight {
+        Weight::from_parts(26_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+
+    // Storage: Tasks Tasks (r:1 w:1)
+    // Storage: Reputation Committee (r:1 w:0)
+    // Storage: AgentRegistry Agents (r:m w:0)
+    // Storage: Tasks AssignedTasks (r:m w:1)
+    // Storage: Tasks TaskDeadlines (r:1 w:1)
+    fn assign_by_committee(m: u32) -> Weight {
+        Weight::from_parts(28_000_000, 0)
+            .saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(m as u64))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().reads(2u64.saturating_mul(m as u64)))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+
+    // Storage: Tasks Tasks (r:1 w:1)
+    // Storage: Recall Records (r:1 w:0)
+    // Storage: ConsensusLog FinalizedLogs (r:1 w:0)
+    // Storage: ConsensusLog Logs (r:1 w:0)
+    // Storage: System Account (r:2 w:2)
+    // Storage: Tasks AssignedTasks (r:1 w:1)
+    // Storage: Tasks ClaimedRecords (r:1 w:1)
+    // Storage: Tasks ClaimedLogs (r:1 w:1)
+    fn complete_task() -> Weight {
+        Weight::from_parts(52_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(9))
+            .saturating_add(T::DbWeight::get().writes(6))
+    }
+
+    // Storage: Tasks Tasks (r:1 w:1)
+    fn fail_task() -> Weight {
+        Weight::from_parts(18_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    // Storage: Tasks Tasks (r:1 w:1)
+    // Storage: System Account (r:1 w:1)
+    fn cancel_task() -> Weight {
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+
+    // Storage: Tasks Tasks (r:1 w:1)
+    // Storage: Tasks AssignedTasks (r:1 w:1)
+    // Storage: Tasks TaskDeadlines (r:1 w:1)
+    fn accept_task() -> Weight {
+        Weight::from_parts(24_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+
+    // Storage: Tasks Tasks (r:1 w:1)
+    fn decline_task() -> Weight {
+        Weight::from_parts(16_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+
+    // Storage: Tasks Tasks (r:1 w:1)
+    // Storage: System Account (r:1 w:1)
+    // Storage: Tasks AssignedTasks (r:1 w:1)
+    fn resolve_dispute() -> Weight {
+        Weight::from_parts(26_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+    fn create_task() -> Weight {
+        Weight::from_parts(30_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+
+    fn assign_task() -> Weight {
+        Weight::from_parts(26_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+
+    fn assign_by_committee(m: u32) -> Weight {
+        Weight::from_parts(28_000_000, 0)
+            .saturating_add(Weight::from_parts(3_000_000, 0).saturating_mul(m as u64))
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().reads(2u64.saturating_mul(m as u64)))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+
+    fn complete_task() -> Weight {
+        Weight::from_parts(52_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(9))
+            .saturating_add(RocksDbWeight::get().writes(6))
+    }
+
+    fn fail_task() -> Weight {
+        Weight::from_parts(18_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    fn cancel_task() -> Weight {
+        Weight::from_parts(20_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+
+    fn accept_task() -> Weight {
+        Weight::from_parts(24_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+
+    fn decline_task() -> Weight {
+        Weight::from_parts(16_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+
+    fn resolve_dispute() -> Weight {
+        Weight::from_parts(26_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+}
@@ -0,0 +1,277 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        lib.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Off-chain worker posting agent alerts to operator webhooks for C-Suite blockchain
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! # Agent Webhooks Pallet
+//!
+//! An opt-in off-chain worker that alerts agent operators over HTTP, so they can page on slashes,
+//! bans and finalized logs without running an indexer.
+//!
+//! ## Overview
+//!
+//! After every block the off-chain worker reads the block's events and turns them into
+//! [`AgentAlert`]s through the runtime's [`AlertSource`]:
+//! - A slash of an agent
+//! - A ban of an agent
+//! - The finalization of a consensus log an agent is involved in
+//!
+//! Each alert concerning an agent with a webhook in the node's subscriptions is POSTed to that
+//! webhook as JSON, e.g.
+//!
+//! ```text
+//! {"event":"AgentBanned","agent":"0x...","block":1200,"until":4800}
+//! ```
+//!
+//! At most `MaxDeliveriesPerBlock` webhooks are called per block, each with `DeliveryTimeout`
+//! milliseconds to answer. Failed deliveries are logged and not retried.
+//!
+//! ## Subscriptions
+//!
+//! Subscriptions are local to a node and never reach the chain. They are kept in the persistent
+//! off-chain storage under [`SUBSCRIPTIONS_KEY`] as a SCALE-encoded list of
+//! [`WebhookSubscription`]s, which operators write with the `offchain_localStorageSet` RPC. A
+//! node without subscriptions makes no requests.
+//!
+//! ## Signatures
+//!
+//! Every payload is signed with the node's first ed25519 key of type [`KEY_TYPE`], inserted with
+//! `author_insertKey`. The key and the signature over the exact body are sent hex-encoded in the
+//! `X-CSuite-Signer` and `X-CSuite-Signature` headers, so a webhook can reject alerts its operator's
+//! node did not send. Without such a key no alerts are sent.
+//!
+//! The pallet has no storage, no extrinsics and emits no events.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+use alloc::{format, string::String, vec, vec::Vec};
+use codec::{Decode, Encode};
+use frame_support::{
+    CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
+use sp_core::{crypto::KeyTypeId, ed25519, hexdisplay::HexDisplay};
+use sp_runtime::{
+    offchain::{http, storage::StorageValueRef, Duration},
+    traits::UniqueSaturatedInto,
+    RuntimeDebug,
+};
+
+/// Key type of the keys alert payloads are signed with
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"cwhk");
+
+/// Persistent off-chain storage key of the node's [`WebhookSubscription`]s
+pub const SUBSCRIPTIONS_KEY: &[u8] = b"csuite::webhooks::subscriptions";
+
+/// Log target of the off-chain worker
+const LOG_TARGET: &str = "runtime::webhooks";
+
+/// A webhook an operator registered for one of its agents
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct WebhookSubscription<AccountId> {
+    /// Agent whose alerts are sent
+    pub agent_id: AccountId,
+    /// URL the alerts are POSTed to
+    pub url: Vec<u8>,
+}
+
+/// What an alert reports
+#[derive(CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound)]
+pub enum AlertKind<T: Config> {
+    /// The agent was slashed for an offense
+    Slashed { slash_amount: u128, reputation_penalty: u64 },
+    /// The agent was banned until the given block, or for good
+    Banned { until: Option<BlockNumberFor<T>> },
+    /// A consensus log the agent is involved in was finalized
+    LogFinalized { log_id: T::Hash },
+}
+
+/// An event concerning a single agent
+#[derive(CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound)]
+pub struct AgentAlert<T: Config> {
+    /// The agent concerned
+    pub agent_id: T::AccountId,
+    /// What happened
+    pub kind: AlertKind<T>,
+}
+
+impl<T: Config> AgentAlert<T> {
+    /// JSON body POSTed for the alert, raised in `block`
+    pub fn to_json(&self, block: BlockNumberFor<T>) -> String {
+        let agent = hex(&self.agent_id.encode());
+        let block: u64 = block.unique_saturated_into();
+        match &self.kind {
+            AlertKind::Slashed { slash_amount, reputation_penalty } => format!(
+                "{{\"event\":\"AgentSlashed\",\"agent\":\"{agent}\",\"block\":{block},\
+                 \"slash_amount\":\"{slash_amount}\",\"reputation_penalty\":{reputation_penalty}}}"
+            ),
+            AlertKind::Banned { until } => {
+                let until = until.map_or_else(
+                    || String::from("null"),
+                    |until| format!("{}", UniqueSaturatedInto::<u64>::unique_saturated_into(until)),
+                );
+                format!("{{\"event\":\"AgentBanned\",\"agent\":\"{agent}\",\"block\":{block},\"until\":{until}}}")
+            },
+            AlertKind::LogFinalized { log_id } => format!(
+                "{{\"event\":\"ConsensusLogFinalized\",\"agent\":\"{agent}\",\"block\":{block},\"log_id\":\"{}\"}}",
+                hex(log_id.as_ref()),
+            ),
+        }
+    }
+}
+
+/// Turns runtime events into alerts for the agents they concern
+pub trait AlertSource<T: Config> {
+    /// Alerts raised by `event`, one per agent concerned
+    fn alerts(event: &<T as frame_system::Config>::RuntimeEvent) -> Vec<AgentAlert<T>>;
+}
+
+/// No alerts
+impl<T: Config> AlertSource<T> for () {
+    fn alerts(_event: &<T as frame_system::Config>::RuntimeEvent) -> Vec<AgentAlert<T>> {
+        Vec::new()
+    }
+}
+
+// Helper function to hex-encode bytes for JSON and headers
+fn hex(bytes: &[u8]) -> String {
+    format!("0x{}", HexDisplay::from(&bytes))
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::pallet_prelude::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// Turns the events of a block into alerts
+        type Alerts: AlertSource<Self>;
+
+        /// Maximum number of webhooks called per block
+        #[pallet::constant]
+        type MaxDeliveriesPerBlock: Get<u32>;
+
+        /// Milliseconds each webhook has to answer
+        #[pallet::constant]
+        type DeliveryTimeout: Get<u64>;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// POST the alerts raised by this block's events to the webhooks of the agents concerned
+        fn offchain_worker(block: BlockNumberFor<T>) {
+            let delivered = Self::deliver_alerts(block);
+            if delivered > 0 {
+                log::debug!(target: LOG_TARGET, "Delivered {} alerts for block {:?}", delivered, block);
+            }
+        }
+    }
+
+    // Helper functions
+    impl<T: Config> Pallet<T> {
+        /// Webhooks configured on this node
+        pub fn subscriptions() -> Vec<WebhookSubscription<T::AccountId>> {
+            match StorageValueRef::persistent(SUBSCRIPTIONS_KEY).get() {
+                Ok(subscriptions) => subscriptions.unwrap_or_default(),
+                Err(_) => {
+                    log::warn!(target: LOG_TARGET, "Ignoring undecodable webhook subscriptions");
+                    Vec::new()
+                },
+            }
+        }
+
+        /// Deliver the alerts of `block` to the subscribed webhooks, returning how many answered
+        /// successfully
+        pub fn deliver_alerts(block: BlockNumberFor<T>) -> u32 {
+            let subscriptions = Self::subscriptions();
+            if subscriptions.is_empty() {
+                return 0;
+            }
+            let Some(signer) = sp_io::crypto::ed25519_public_keys(KEY_TYPE).into_iter().next() else {
+                log::warn!(target: LOG_TARGET, "No webhook signing key in the keystore, alerts are not sent");
+                return 0;
+            };
+
+            let (mut attempts, mut delivered) = (0u32, 0u32);
+            for record in frame_system::Pallet::<T>::read_events_no_consensus() {
+                for alert in T::Alerts::alerts(&record.event) {
+                    let mut urls = subscriptions.iter()
+                        .filter(|subscription| subscription.agent_id == alert.agent_id)
+                        .map(|subscription| &subscription.url)
+                        .peekable();
+                    if urls.peek().is_none() {
+                        continue;
+                    }
+
+                    let body = alert.to_json(block);
+                    let Some(signature) = sp_io::crypto::ed25519_sign(KEY_TYPE, &signer, body.as_bytes()) else {
+                        log::warn!(target: LOG_TARGET, "Failed to sign an alert for block {:?}", block);
+                        continue;
+                    };
+
+                    for url in urls {
+                        if attempts >= T::MaxDeliveriesPerBlock::get() {
+                            log::warn!(target: LOG_TARGET, "Dropping the remaining alerts of block {:?}", block);
+                            return delivered;
+                        }
+                        attempts = attempts.saturating_add(1);
+                        match Self::post(url, &body, &signer, &signature) {
+                            Ok(()) => delivered = delivered.saturating_add(1),
+                            Err(e) => log::warn!(target: LOG_TARGET, "Failed to deliver an alert: {:?}", e),
+                        }
+                    }
+                }
+            }
+            delivered
+        }
+
+        /// POST a signed body to a webhook, failing unless it answers with a 2xx status
+        fn post(
+            url: &[u8],
+            body: &str,
+            signer: &ed25519::Public,
+            signature: &ed25519::Signature,
+        ) -> Result<(), http::Error> {
+            let url = core::str::from_utf8(url).map_err(|_| http::Error::Unknown)?;
+            let (signer, signature) = (hex(signer.as_ref()), hex(signature.as_ref()));
+            let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(T::DeliveryTimeout::get()));
+
+            let pending = http::Request::post(url, vec![body.as_bytes()])
+                .add_header("Content-Type", "application/json")
+                .add_header("X-CSuite-Signer", &signer)
+                .add_header("X-CSuite-Signature", &signature)
+                .deadline(deadline)
+                .send()
+                .map_err(|_| http::Error::IoError)?;
+            let response = pending.try_wait(deadline).map_err(|_| http::Error::DeadlineReached)??;
+
+            if response.code / 100 != 2 {
+                return Err(http::Error::Unknown);
+            }
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,108 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        mock.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Mock runtime for testing the agent webhooks pallet
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+use crate::{self as pallet_agent_webhooks, AgentAlert, AlertKind, AlertSource, KEY_TYPE};
+use frame_support::traits::{ConstU16, ConstU32, ConstU64};
+use sp_core::{
+    offchain::{testing::TestOffchainExt, OffchainDbExt, OffchainWorkerExt},
+    H256,
+};
+use sp_keystore::{testing::MemoryKeystore, Keystore, KeystoreExt};
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+/// Seed of the webhook signing key in the test keystore
+pub const SIGNING_SEED: &str = "//Ops";
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+    pub enum Test
+    {
+        System: frame_system,
+        Webhooks: pallet_agent_webhooks,
+    }
+);
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = frame_support::traits::Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type RuntimeCall = RuntimeCall;
+    type Nonce = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = u64;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Block = Block;
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type BlockHashCount = ConstU64<250>;
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = ConstU16<42>;
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+/// Stands in for the C-Suite pallets with system events: a remark finalizes a log of its sender,
+/// a new account is a ban and a killed account a slash
+pub struct SystemEventAlerts;
+impl AlertSource<Test> for SystemEventAlerts {
+    fn alerts(event: &RuntimeEvent) -> Vec<AgentAlert<Test>> {
+        let (agent_id, kind) = match event {
+            RuntimeEvent::System(frame_system::Event::Remarked { sender, hash }) =>
+                (*sender, AlertKind::LogFinalized { log_id: *hash }),
+            RuntimeEvent::System(frame_system::Event::NewAccount { account }) =>
+                (*account, AlertKind::Banned { until: Some(30) }),
+            RuntimeEvent::System(frame_system::Event::KilledAccount { account }) =>
+                (*account, AlertKind::Slashed { slash_amount: 500, reputation_penalty: 50 }),
+            _ => return Vec::new(),
+        };
+        vec![AgentAlert { agent_id, kind }]
+    }
+}
+
+impl pallet_agent_webhooks::Config for Test {
+    type Alerts = SystemEventAlerts;
+    type MaxDeliveriesPerBlock = ConstU32<2>;
+    type DeliveryTimeout = ConstU64<1_000>;
+}
+
+// Build genesis storage according to the mock runtime, with off-chain worker externalities and
+// a webhook signing key if `signing_key` is set
+pub fn new_test_ext(signing_key: bool) -> (sp_io::TestExternalities, TestOffchainExt) {
+    let mut ext: sp_io::TestExternalities =
+        frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into();
+
+    let (offchain, _) = TestOffchainExt::new();
+    ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+    ext.register_extension(OffchainDbExt::new(offchain.clone()));
+
+    let keystore = MemoryKeystore::new();
+    if signing_key {
+        keystore.ed25519_generate_new(KEY_TYPE, Some(SIGNING_SEED)).unwrap();
+    }
+    ext.register_extension(KeystoreExt::new(keystore));
+
+    ext.execute_with(|| System::set_block_number(1));
+    (ext, offchain)
+}
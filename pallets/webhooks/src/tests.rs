@@ -0,0 +1,122 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        tests.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Tests for the agent webhooks pallet
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+use crate::{mock::*, AgentAlert, AlertKind, WebhookSubscription, SUBSCRIPTIONS_KEY};
+use frame_support::traits::Hooks;
+use sp_core::{ed25519, hexdisplay::HexDisplay, offchain::testing::PendingRequest, Pair, H256};
+use sp_runtime::offchain::storage::StorageValueRef;
+
+// Helper function to store the node's webhook subscriptions
+fn subscribe(subscriptions: &[(u64, &str)]) {
+    let subscriptions = subscriptions
+        .iter()
+        .map(|(agent_id, url)| WebhookSubscription { agent_id: *agent_id, url: url.as_bytes().to_vec() })
+        .collect::<Vec<_>>();
+    StorageValueRef::persistent(SUBSCRIPTIONS_KEY).set(&subscriptions);
+}
+
+// Helper function to build the signed request expected for an alert
+fn signed_post(url: &str, body: String) -> PendingRequest {
+    let pair = ed25519::Pair::from_string(SIGNING_SEED, None).unwrap();
+    let signature = pair.sign(body.as_bytes());
+    PendingRequest {
+        method: "POST".into(),
+        uri: url.into(),
+        headers: vec![
+            ("Content-Type".into(), "application/json".into()),
+            ("X-CSuite-Signer".into(), format!("0x{}", HexDisplay::from(&pair.public().0.to_vec()))),
+            ("X-CSuite-Signature".into(), format!("0x{}", HexDisplay::from(&signature.0.to_vec()))),
+        ],
+        body: body.into_bytes(),
+        sent: true,
+        response: Some(Vec::new()),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn alerts_are_serialized_to_json() {
+    let slashed = AgentAlert::<Test> { agent_id: 1, kind: AlertKind::Slashed { slash_amount: 500, reputation_penalty: 50 } };
+    assert_eq!(
+        slashed.to_json(7),
+        r#"{"event":"AgentSlashed","agent":"0x0100000000000000","block":7,"slash_amount":"500","reputation_penalty":50}"#
+    );
+
+    let banned = AgentAlert::<Test> { agent_id: 1, kind: AlertKind::Banned { until: None } };
+    assert_eq!(banned.to_json(7), r#"{"event":"AgentBanned","agent":"0x0100000000000000","block":7,"until":null}"#);
+
+    let finalized = AgentAlert::<Test> { agent_id: 1, kind: AlertKind::LogFinalized { log_id: H256::repeat_byte(0xab) } };
+    assert_eq!(
+        finalized.to_json(7),
+        format!(
+            r#"{{"event":"ConsensusLogFinalized","agent":"0x0100000000000000","block":7,"log_id":"0x{}"}}"#,
+            "ab".repeat(32),
+        )
+    );
+}
+
+#[test]
+fn alerts_are_posted_signed_to_the_webhooks_of_the_agents_concerned() {
+    let (mut ext, offchain) = new_test_ext(true);
+    ext.execute_with(|| {
+        subscribe(&[(1, "https://ops.example/lyra"), (3, "https://ops.example/echo")]);
+
+        System::deposit_event(frame_system::Event::<Test>::Remarked { sender: 1, hash: H256::repeat_byte(1) });
+        // Agent 2 has no webhook on this node
+        System::deposit_event(frame_system::Event::<Test>::NewAccount { account: 2 });
+        System::deposit_event(frame_system::Event::<Test>::KilledAccount { account: 3 });
+
+        let finalized = AgentAlert::<Test> { agent_id: 1, kind: AlertKind::LogFinalized { log_id: H256::repeat_byte(1) } };
+        let slashed = AgentAlert::<Test> { agent_id: 3, kind: AlertKind::Slashed { slash_amount: 500, reputation_penalty: 50 } };
+        offchain.0.write().expect_request(signed_post("https://ops.example/lyra", finalized.to_json(1)));
+        offchain.0.write().expect_request(signed_post("https://ops.example/echo", slashed.to_json(1)));
+
+        assert_eq!(Webhooks::deliver_alerts(1), 2);
+        assert_eq!(offchain.0.read().requests.len(), 2);
+    });
+}
+
+#[test]
+fn deliveries_are_capped_per_block() {
+    let (mut ext, offchain) = new_test_ext(true);
+    ext.execute_with(|| {
+        subscribe(&[(1, "https://ops.example/a"), (1, "https://ops.example/b"), (1, "https://ops.example/c")]);
+        System::deposit_event(frame_system::Event::<Test>::NewAccount { account: 1 });
+
+        let banned = AgentAlert::<Test> { agent_id: 1, kind: AlertKind::Banned { until: Some(30) } };
+        offchain.0.write().expect_request(signed_post("https://ops.example/a", banned.to_json(1)));
+        offchain.0.write().expect_request(signed_post("https://ops.example/b", banned.to_json(1)));
+
+        // Only `MaxDeliveriesPerBlock` webhooks are called
+        Webhooks::offchain_worker(1);
+        assert_eq!(offchain.0.read().requests.len(), 2);
+    });
+}
+
+#[test]
+fn nothing_is_sent_without_subscriptions_or_a_signing_key() {
+    let (mut ext, offchain) = new_test_ext(true);
+    ext.execute_with(|| {
+        System::deposit_event(frame_system::Event::<Test>::NewAccount { account: 1 });
+        assert_eq!(Webhooks::deliver_alerts(1), 0);
+        assert!(offchain.0.read().requests.is_empty());
+    });
+
+    let (mut ext, offchain) = new_test_ext(false);
+    ext.execute_with(|| {
+        subscribe(&[(1, "https://ops.example/lyra")]);
+        System::deposit_event(frame_system::Event::<Test>::NewAccount { account: 1 });
+        assert_eq!(Webhooks::deliver_alerts(1), 0);
+        assert!(offchain.0.read().requests.is_empty());
+    });
+}
@@ -7,7 +7,7 @@
  *  Description: Consensus Log pallet for C-Suite blockchain
  *  Version:     1.0.0
  *  License:     BSL (SPDX id BUSL)
- *  Last Update: (May 2025)
+ *  Last Update: (October 2026)
  * ----------------------------------------------------------------------------
  */
 
@@ -25,6 +25,10 @@
 //!
 //! The consensus process involves multiple C-Suite agents collaborating on
 //! executive decisions and logging their insights on-chain for transparency.
+//!
+//! CIDs, metadata and signatures are stored as given, bounded by `MaxCIDLength`,
+//! `MaxMetadataLength` and `MaxSignatureLength`. Longer input is rejected rather than
+//! truncated, and nothing is padded, so every stored value round-trips exactly.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -43,11 +47,16 @@ mod benchmarking;
 
 pub mod weights;
 
+pub mod migrations;
+
 #[frame::pallet]
 pub mod pallet {
     use frame::prelude::*;
     use alloc::vec::Vec;
 
+    /// The in-code storage version
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::config]
     pub trait Config: frame_system::Config {
         /// The overarching event type.
@@ -75,6 +84,7 @@ pub mod pallet {
     }
 
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// Status of a consensus log
@@ -105,8 +115,8 @@ pub mod pallet {
     {
         /// The agent who signed
         pub agent_id: T::AccountId,
-        /// The signature data (fixed size for simplicity)
-        pub signature: [u8; 64],
+        /// The signature data
+        pub signature: BoundedVec<u8, T::MaxSignatureLength>,
         /// When the signature was created
         pub signed_at: BlockNumberFor<T>,
     }
@@ -118,8 +128,8 @@ pub mod pallet {
     where 
         T::AccountId: Clone + PartialEq + Eq + core::fmt::Debug,
     {
-        /// Content identifier (IPFS hash or similar) - fixed size
-        pub cid: [u8; 64],
+        /// Content identifier (IPFS hash or similar)
+        pub cid: BoundedVec<u8, T::MaxCIDLength>,
         /// The agent who submitted this log
         pub submitter: T::AccountId,
         /// List of agents involved in this consensus
@@ -132,8 +142,8 @@ pub mod pallet {
         pub created_at: BlockNumberFor<T>,
         /// When the log was last updated
         pub updated_at: BlockNumberFor<T>,
-        /// Optional metadata (fixed size)
-        pub metadata: Option<[u8; 256]>,
+        /// Optional metadata
+        pub metadata: Option<BoundedVec<u8, T::MaxMetadataLength>>,
     }
 
     /// Storage for consensus logs
@@ -208,7 +218,20 @@ pub mod pallet {
     }
 
     #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn integrity_test() {
+            // Logs stored before version 1 must fit the bounds to be migrated
+            assert!(T::MaxCIDLength::get() >= 64, "MaxCIDLength must hold the 64-byte legacy CIDs");
+            assert!(
+                T::MaxMetadataLength::get() >= 256,
+                "MaxMetadataLength must hold the 256-byte legacy metadata",
+            );
+            assert!(
+                T::MaxSignatureLength::get() >= 64,
+                "MaxSignatureLength must hold the 64-byte legacy signatures",
+            );
+        }
+    }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
@@ -229,32 +252,26 @@ pub mod pallet {
             let who = ensure_signed(origin)?;
             
             // Validate CID length
-            ensure!(cid.len() <= 64, Error::<T>::CIDTooLong);
+            ensure!(!cid.is_empty(), Error::<T>::InvalidCID);
+            let bounded_cid: BoundedVec<u8, T::MaxCIDLength> =
+                cid.clone().try_into().map_err(|_| Error::<T>::CIDTooLong)?;
             
             // Validate agents involved
             let bounded_agents: BoundedVec<T::AccountId, T::MaxAgentsInvolved> = 
                 agents_involved.try_into().map_err(|_| Error::<T>::TooManyAgents)?;
             
             // Validate metadata if provided
-            let bounded_metadata = if let Some(meta) = metadata {
-                ensure!(meta.len() <= 256, Error::<T>::MetadataTooLong);
-                let mut fixed_meta = [0u8; 256];
-                fixed_meta[..meta.len()].copy_from_slice(&meta);
-                Some(fixed_meta)
-            } else {
-                None
-            };
-            
-            // Convert CID to fixed array
-            let mut fixed_cid = [0u8; 64];
-            fixed_cid[..cid.len()].copy_from_slice(&cid);
+            let bounded_metadata = metadata
+                .map(|meta| BoundedVec::<u8, T::MaxMetadataLength>::try_from(meta))
+                .transpose()
+                .map_err(|_| Error::<T>::MetadataTooLong)?;
             
             // Get next log ID
             let log_id = NextLogId::<T>::get();
             
             // Create consensus log
             let consensus_log = ConsensusLog {
-                cid: fixed_cid,
+                cid: bounded_cid,
                 submitter: who.clone(),
                 agents_involved: bounded_agents,
                 signatures: BoundedVec::new(),
@@ -295,11 +312,9 @@ pub mod pallet {
             let who = ensure_signed(origin)?;
 
             // Validate signature length
-            ensure!(signature.len() <= 64, Error::<T>::InvalidSignature);
-            
-            // Convert to fixed array
-            let mut fixed_signature = [0u8; 64];
-            fixed_signature[..signature.len()].copy_from_slice(&signature);
+            ensure!(!signature.is_empty(), Error::<T>::InvalidSignature);
+            let bounded_signature: BoundedVec<u8, T::MaxSignatureLength> =
+                signature.try_into().map_err(|_| Error::<T>::InvalidSignature)?;
 
             // Update the consensus log
             ConsensusLogs::<T>::try_mutate(&log_id, |maybe_log| -> DispatchResult {
@@ -320,7 +335,7 @@ pub mod pallet {
                 // Create signature
                 let agent_signature = AgentSignature {
                     agent_id: who.clone(),
-                    signature: fixed_signature,
+                    signature: bounded_signature,
                     signed_at: <frame_system::Pallet<T>>::block_number(),
                 };
 
@@ -0,0 +1,193 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        migrations.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Storage migrations for the Consensus Log pallet
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! Storage migrations for pallet_consensus_log
+//!
+//! Every log is migrated on its own, so these run as multi-block migrations through
+//! `pallet_migrations` rather than in a single block.
+
+/// Prefix of the identifiers of this pallet's multi-block migrations
+pub const PALLET_MIGRATIONS_ID: &[u8; 20] = b"pallet-consensus-log";
+
+pub mod v1 {
+    //! Moves CIDs, metadata and signatures from zero-padded fixed arrays to bounded vectors.
+    //!
+    //! Version 0 copied every value into a `[u8; 64]` or `[u8; 256]` array and padded it with
+    //! zeros, so the original length was not stored. The padding is stripped again; trailing
+    //! zero bytes of the original values cannot be told apart from it and are dropped too. CIDs
+    //! are text and never end in a zero byte, so they are restored exactly.
+
+    use super::PALLET_MIGRATIONS_ID;
+    use crate::{weights::WeightInfo, AgentSignature, Config, ConsensusLog, ConsensusLogs, ConsensusStatus, Pallet};
+    use alloc::vec::Vec;
+    use core::marker::PhantomData;
+    use frame::deps::frame_support::{
+        migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+        traits::Get,
+        weights::WeightMeter,
+    };
+    use frame::prelude::*;
+
+    /// `AgentSignature` as stored in version 0
+    #[derive(Encode, Decode, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct OldAgentSignature<T: Config> {
+        pub agent_id: T::AccountId,
+        pub signature: [u8; 64],
+        pub signed_at: BlockNumberFor<T>,
+    }
+
+    /// `ConsensusLog` as stored in version 0
+    #[derive(Encode, Decode, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct OldConsensusLog<T: Config> {
+        pub cid: [u8; 64],
+        pub submitter: T::AccountId,
+        pub agents_involved: BoundedVec<T::AccountId, T::MaxAgentsInvolved>,
+        pub signatures: BoundedVec<OldAgentSignature<T>, T::MaxSignatures>,
+        pub status: ConsensusStatus,
+        pub created_at: BlockNumberFor<T>,
+        pub updated_at: BlockNumberFor<T>,
+        pub metadata: Option<[u8; 256]>,
+    }
+
+    pub mod v0 {
+        //! Storage as laid out in version 0
+
+        use super::OldConsensusLog;
+        use crate::{Config, Pallet};
+        use frame::prelude::*;
+
+        /// `ConsensusLogs` as stored in version 0
+        #[frame::deps::frame_support::storage_alias]
+        pub type ConsensusLogs<T: Config> =
+            StorageMap<Pallet<T>, Blake2_128Concat, u32, OldConsensusLog<T>>;
+    }
+
+    /// Strip the zero padding of a version 0 array
+    pub fn unpad<S: Get<u32>>(padded: &[u8]) -> Option<BoundedVec<u8, S>> {
+        let len = padded.iter().rposition(|byte| *byte != 0).map_or(0, |last| last + 1);
+        padded[..len].to_vec().try_into().ok()
+    }
+
+    /// Convert a version 0 log, or `None` if it does not fit the configured bounds
+    ///
+    /// The pallet's integrity test makes sure the bounds hold every version 0 value.
+    pub fn migrate_log<T: Config>(old: OldConsensusLog<T>) -> Option<ConsensusLog<T>> {
+        let signatures = old
+            .signatures
+            .into_iter()
+            .map(|sig| {
+                Some(AgentSignature {
+                    agent_id: sig.agent_id,
+                    signature: unpad(&sig.signature)?,
+                    signed_at: sig.signed_at,
+                })
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let metadata = match old.metadata {
+            Some(metadata) => Some(unpad(&metadata)?),
+            None => None,
+        };
+
+        Some(ConsensusLog {
+            cid: unpad(&old.cid)?,
+            submitter: old.submitter,
+            agents_involved: old.agents_involved,
+            signatures: BoundedVec::truncate_from(signatures),
+            status: old.status,
+            created_at: old.created_at,
+            updated_at: old.updated_at,
+            metadata,
+        })
+    }
+
+    /// Re-encode every stored log with bounded CIDs, metadata and signatures, one log per
+    /// `W::migrate_v1_step` of weight, and set the storage version to 1 once all are done
+    ///
+    /// The cursor is the ID of the last migrated log. Logs are visited in storage order, so the
+    /// logs after the cursor are still in the version 0 layout.
+    pub struct LazyMigrationV1<T, W>(PhantomData<(T, W)>);
+
+    impl<T: Config, W: WeightInfo> SteppedMigration for LazyMigrationV1<T, W> {
+        type Cursor = u32;
+        type Identifier = MigrationId<20>;
+
+        fn id() -> Self::Identifier {
+            MigrationId { pallet_id: *PALLET_MIGRATIONS_ID, version_from: 0, version_to: 1 }
+        }
+
+        fn step(
+            mut cursor: Option<Self::Cursor>,
+            meter: &mut WeightMeter,
+        ) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+            if cursor.is_none() && Pallet::<T>::on_chain_storage_version() >= 1 {
+                return Ok(None);
+            }
+
+            let required = W::migrate_v1_step();
+            if meter.remaining().any_lt(required) {
+                return Err(SteppedMigrationError::InsufficientWeight { required });
+            }
+
+            // Migrate as many logs as the meter allows
+            while meter.try_consume(required).is_ok() {
+                let mut iter = match cursor {
+                    Some(last) => v0::ConsensusLogs::<T>::iter_from(v0::ConsensusLogs::<T>::hashed_key_for(last)),
+                    None => v0::ConsensusLogs::<T>::iter(),
+                };
+                let Some((log_id, old)) = iter.next() else {
+                    StorageVersion::new(1).put::<Pallet<T>>();
+                    return Ok(None);
+                };
+
+                // Both layouts share the key space, so the log is overwritten in place
+                match migrate_log::<T>(old) {
+                    Some(log) => ConsensusLogs::<T>::insert(log_id, log),
+                    None => ConsensusLogs::<T>::remove(log_id),
+                }
+                cursor = Some(log_id);
+            }
+
+            Ok(cursor)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, frame::try_runtime::TryRuntimeError> {
+            let cids = v0::ConsensusLogs::<T>::iter()
+                .map(|(log_id, old)| (log_id, old.cid.to_vec()))
+                .collect::<Vec<_>>();
+            Ok(cids.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), frame::try_runtime::TryRuntimeError> {
+            let cids = Vec::<(u32, Vec<u8>)>::decode(&mut &state[..])
+                .map_err(|_| "ConsensusLog: invalid pre-upgrade state")?;
+            ensure!(
+                ConsensusLogs::<T>::iter_keys().count() == cids.len(),
+                "ConsensusLog: logs were lost in the migration"
+            );
+            for (log_id, padded) in cids {
+                let log = ConsensusLogs::<T>::get(log_id).ok_or("ConsensusLog: migrated log is missing")?;
+                ensure!(padded.starts_with(&log.cid), "ConsensusLog: CID changed in the migration");
+                ensure!(
+                    padded[log.cid.len()..].iter().all(|byte| *byte == 0),
+                    "ConsensusLog: CID was truncated in the migration"
+                );
+            }
+            ensure!(Pallet::<T>::on_chain_storage_version() == 1, "ConsensusLog: storage version not set");
+            Ok(())
+        }
+    }
+}
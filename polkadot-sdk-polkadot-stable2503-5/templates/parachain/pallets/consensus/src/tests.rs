@@ -288,4 +288,122 @@ fn sign_log_fails_for_agent_not_involved() {
             Error::<Test>::AgentNotFound
         );
     });
-} 
\ No newline at end of file
+}
+
+#[test]
+fn consensus_log_fields_round_trip_without_padding() {
+    new_test_ext().execute_with(|| {
+        let cid = b"QmShortCid".to_vec();
+        let metadata = b"meta".to_vec();
+        assert_ok!(ConsensusLog::submit_consensus_log(
+            RuntimeOrigin::signed(1),
+            cid.clone(),
+            vec![1, 2],
+            Some(metadata.clone())
+        ));
+        assert_ok!(ConsensusLog::sign_consensus_log(RuntimeOrigin::signed(2), 0, b"sig-2".to_vec()));
+
+        let log = ConsensusLog::consensus_logs(0).unwrap();
+        assert_eq!(log.cid.to_vec(), cid);
+        assert_eq!(log.metadata.map(|meta| meta.to_vec()), Some(metadata));
+        assert_eq!(log.signatures[0].signature.to_vec(), b"sig-2".to_vec());
+    });
+}
+
+#[test]
+fn over_long_or_empty_consensus_log_fields_are_rejected() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ConsensusLog::submit_consensus_log(RuntimeOrigin::signed(1), vec![], vec![1], None),
+            Error::<Test>::InvalidCID
+        );
+        assert_noop!(
+            ConsensusLog::submit_consensus_log(RuntimeOrigin::signed(1), vec![b'Q'; 65], vec![1], None),
+            Error::<Test>::CIDTooLong
+        );
+        assert_noop!(
+            ConsensusLog::submit_consensus_log(
+                RuntimeOrigin::signed(1),
+                b"QmCid".to_vec(),
+                vec![1],
+                Some(vec![1; 2049])
+            ),
+            Error::<Test>::MetadataTooLong
+        );
+
+        assert_ok!(ConsensusLog::submit_consensus_log(RuntimeOrigin::signed(1), b"QmCid".to_vec(), vec![1], None));
+        assert_noop!(
+            ConsensusLog::sign_consensus_log(RuntimeOrigin::signed(1), 0, vec![7; 257]),
+            Error::<Test>::InvalidSignature
+        );
+        assert_noop!(
+            ConsensusLog::sign_consensus_log(RuntimeOrigin::signed(1), 0, vec![]),
+            Error::<Test>::InvalidSignature
+        );
+    });
+}
+
+#[test]
+fn migration_to_v1_strips_the_zero_padding_over_several_steps() {
+    use crate::migrations::v1::{LazyMigrationV1, OldAgentSignature, OldConsensusLog};
+    use codec::Encode;
+    use frame_support::{
+        migrations::SteppedMigration,
+        traits::StorageVersion,
+        weights::{Weight, WeightMeter},
+    };
+
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<ConsensusLog>();
+        for log_id in 0..3u32 {
+            let mut cid = [0u8; 64];
+            cid[..10].copy_from_slice(b"QmShortCid");
+            let mut signature = [0u8; 64];
+            signature[..5].copy_from_slice(b"sig-2");
+            let mut metadata = [0u8; 256];
+            metadata[..4].copy_from_slice(b"meta");
+            let old = OldConsensusLog::<Test> {
+                cid,
+                submitter: 1,
+                agents_involved: vec![1, 2].try_into().unwrap(),
+                signatures: vec![OldAgentSignature { agent_id: 2, signature, signed_at: 1 }].try_into().unwrap(),
+                status: crate::ConsensusStatus::Finalized,
+                created_at: 1,
+                updated_at: 1,
+                metadata: Some(metadata),
+            };
+            frame_support::storage::unhashed::put_raw(
+                &crate::ConsensusLogs::<Test>::hashed_key_for(log_id),
+                &old.encode(),
+            );
+        }
+
+        // One log per step, until the cursor runs out
+        let step = <() as crate::weights::WeightInfo>::migrate_v1_step();
+        let mut cursor = None;
+        let mut steps = 0;
+        loop {
+            let mut meter = WeightMeter::with_limit(step);
+            cursor = LazyMigrationV1::<Test, ()>::step(cursor, &mut meter).unwrap();
+            steps += 1;
+            if cursor.is_none() {
+                break;
+            }
+            assert_eq!(StorageVersion::get::<ConsensusLog>(), 0);
+        }
+        assert_eq!(steps, 4);
+        assert_eq!(StorageVersion::get::<ConsensusLog>(), 1);
+
+        for log_id in 0..3u32 {
+            let log = ConsensusLog::consensus_logs(log_id).unwrap();
+            assert_eq!(log.cid.to_vec(), b"QmShortCid".to_vec());
+            assert_eq!(log.signatures[0].signature.to_vec(), b"sig-2".to_vec());
+            assert_eq!(log.metadata.map(|meta| meta.to_vec()), Some(b"meta".to_vec()));
+        }
+
+        // Too little weight for a single log
+        let mut meter = WeightMeter::with_limit(Weight::zero());
+        StorageVersion::new(0).put::<ConsensusLog>();
+        assert!(LazyMigrationV1::<Test, ()>::step(None, &mut meter).is_err());
+    });
+}
@@ -51,6 +51,7 @@ pub trait WeightInfo {
     fn submit_consensus_log() -> Weight;
     fn sign_consensus_log() -> Weight;
     fn finalize_consensus_log() -> Weight;
+    fn migrate_v1_step() -> Weight;
 }
 
 /// Weights for `pallet_consensus_log` using the Substrate node and recommended hardware.
@@ -95,6 +96,15 @@ impl<T: frame::deps::frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().reads(1_u64))
             .saturating_add(T::DbWeight::get().writes(1_u64))
     }
+
+    /// Storage: `ConsensusLog::ConsensusLogs` (r:1 w:1)
+    /// Proof: `ConsensusLog::ConsensusLogs` (`max_values`: None, `max_size`: Some(2000), added: 4475, mode: `MaxEncodedLen`)
+    /// Estimated, not benchmarked: one log read and rewritten per step
+    fn migrate_v1_step() -> Weight {
+        Weight::from_parts(20_000_000, 4475)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
 }
 
 // For backwards compatibility and tests.
@@ -114,4 +124,9 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().reads(1_u64))
             .saturating_add(RocksDbWeight::get().writes(1_u64))
     }
+    fn migrate_v1_step() -> Weight {
+        Weight::from_parts(20_000_000, 4475)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
 } 
\ No newline at end of file
@@ -0,0 +1,671 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        lib.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Primitives shared by the C-Suite pallets and verifiers
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! # C-Suite Primitives
+//!
+//! Types and pure helpers that both the pallets and off-chain or cross-chain consumers of
+//! their state need to agree on:
+//! - [`AggregateSignature`] and the key and bitmap helpers used to check it against a FROST
+//!   group, whose aggregates are Ed25519 signatures by the group key
+//! - [`QuorumCertificate`], the evidence that a signing group signed off a consensus log
+//! - [`RecordInclusionProof`], a storage proof that a recall record exists under a state root
+//! - [`ConsensusDigest`], the header digest item summarising the consensus logs finalized in a
+//!   block
+//! - [`MerkleInclusionProof`], a proof that a single leaf is committed to by a Merkle root,
+//!   used to prove that an agent signed a finalized consensus log ([`signature_leaf`]) or held
+//!   a reputation at the start of an era ([`reputation_leaf`])
+//! - [`AgentInspector`], [`AgentStatusProvider`], [`FeeExemption`] and [`MaintenanceSchedule`],
+//!   the interfaces through which pallets query the agent registry without depending on its
+//!   `Config`
+//! - [`CallClass`] and [`CallClasses`], the routine calls an agent's operational proxy keys
+//!   may make on its behalf
+//! - [`Feature`] and [`FeatureFlags`], the experimental features governance enables for all
+//!   agents or for some of them
+//! - [`AgentRole`], the canonical C-Suite roles and their numeric IDs
+//! - [`SignatureScheme`] and [`VerifyAgentSignature`], the key types agents sign consensus logs
+//!   with and how their signatures are checked
+//! - [`ConsensusThresholds`] and [`ConsensusParameters`], the consensus rules recorded with
+//!   every era so audits can tell which ones applied
+//! - [`Phase`] and [`EventRecord`], the layout of `System::Events` for clients that decode a
+//!   block's events without depending on frame-system
+//!
+//! Nothing in here depends on FRAME, so the crate can be used from any runtime or client.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::{crypto::AccountId32, ecdsa, ed25519, sr25519, RuntimeDebug, H256};
+use sp_runtime::{
+    generic::{Digest, DigestItem},
+    traits::{BlakeTwo256, Verify, Zero},
+    MultiSignature, Perbill,
+};
+use sp_std::vec::Vec;
+
+/// Aggregated signature that represents consensus from multiple agents
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct AggregateSignature {
+    /// The aggregated signature value
+    pub signature: [u8; 64],
+    /// Combined public key of participating agents
+    pub aggregate_pubkey: [u8; 32],
+    /// Bitmap indicating which agents participated
+    pub participant_bitmap: [u8; 2], // 16 bits for up to 16 agents
+    /// Challenge value used in aggregation
+    pub challenge: [u8; 32],
+}
+
+/// A FROST signing group: its key, its participants' keys in group order and its threshold
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct SigningGroup {
+    /// The group public key produced by the participants' DKG
+    pub group_key: [u8; 32],
+    /// FROST keys of the participants, in the order the participant bitmap refers to
+    pub participants: Vec<[u8; 32]>,
+    /// Number of participants that must sign
+    pub threshold: u32,
+}
+
+/// Evidence that a signing group signed off a consensus log
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct QuorumCertificate {
+    /// The signed consensus log
+    pub log_id: [u8; 32],
+    /// FROST keys of the agents that signed off the log
+    pub signers: Vec<[u8; 32]>,
+    /// Aggregate signature of the signers, if the log was finalized with one
+    pub aggregate: Option<AggregateSignature>,
+}
+
+/// Proof that a recall record is stored under a given state root
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct RecordInclusionProof {
+    /// The proven record
+    pub record_id: u64,
+    /// Trie nodes from the state root down to the record's storage entry
+    pub trie_nodes: Vec<Vec<u8>>,
+}
+
+/// Largest encoded size, in bytes, a consensus log may reach under its pallet's configured bounds
+///
+/// Checked by the consensus log pallet's integrity test, so that raising a bound such as the
+/// maximum number of signatures fails the build instead of growing every log's proof size.
+pub const MAX_CONSENSUS_LOG_SIZE: usize = 48 * 1024;
+
+/// Largest encoded size, in bytes, a recall record may reach under its pallet's configured bounds
+pub const MAX_CONSENSUS_RECORD_SIZE: usize = 16 * 1024;
+
+/// Largest encoded size, in bytes, an agent's reputation entry may reach
+pub const MAX_REPUTATION_INFO_SIZE: usize = 1024;
+
+/// Number of signers the participant bitmap of an [`AggregateSignature`] can select
+pub const MAX_AGGREGATE_SIGNERS: u32 = 16;
+
+/// Prefix of the `DigestItem::Other` data carrying a [`ConsensusDigest`]
+pub const CONSENSUS_DIGEST_PREFIX: [u8; 4] = *b"csdg";
+
+/// Summary of the consensus logs finalized in a block, deposited in its header digest
+///
+/// `root` is the [`merkle_root`] over the SCALE-encoded IDs of the finalized logs, in the order
+/// they were finalized, so [`merkle_inclusion_proof`] over the same leaves proves that a log was
+/// finalized in the block. Blocks without finalized logs carry no consensus digest.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ConsensusDigest {
+    /// Number of logs finalized in the block
+    pub finalized: u32,
+    /// Merkle root over the IDs of the finalized logs
+    pub root: [u8; 32],
+}
+
+impl ConsensusDigest {
+    /// The digest item carrying this summary
+    pub fn to_digest_item(&self) -> DigestItem {
+        let mut data = CONSENSUS_DIGEST_PREFIX.to_vec();
+        self.encode_to(&mut data);
+        DigestItem::Other(data)
+    }
+
+    /// The summary carried by `item`, if it is a consensus digest item
+    pub fn from_digest_item(item: &DigestItem) -> Option<Self> {
+        let data = item.as_other()?.strip_prefix(&CONSENSUS_DIGEST_PREFIX[..])?;
+        Self::decode(&mut &data[..]).ok()
+    }
+
+    /// The summary in a header digest, if the block finalized any consensus log
+    pub fn from_digest(digest: &Digest) -> Option<Self> {
+        digest.logs().iter().find_map(Self::from_digest_item)
+    }
+}
+
+/// Merkle proof that one leaf is committed to by a root
+///
+/// Leaves are hashed with BLAKE2-256 into a binary Merkle tree.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct MerkleInclusionProof {
+    /// Root the proof resolves to
+    pub root: [u8; 32],
+    /// Sibling hashes from the leaf up to the root
+    pub proof: Vec<[u8; 32]>,
+    /// Number of leaves in the tree
+    pub number_of_leaves: u32,
+    /// Position of the proven leaf
+    pub leaf_index: u32,
+    /// The proven leaf, e.g. a [`signature_leaf`] or [`reputation_leaf`] encoding
+    pub leaf: Vec<u8>,
+}
+
+impl MerkleInclusionProof {
+    /// Whether the proof resolves to `root`
+    pub fn verify(&self, root: &[u8; 32]) -> bool {
+        binary_merkle_tree::verify_proof::<BlakeTwo256, _, _>(
+            &H256::from(*root),
+            self.proof.iter().map(|hash| H256::from(*hash)),
+            self.number_of_leaves,
+            self.leaf_index,
+            &self.leaf,
+        )
+    }
+
+    /// Decode the proven leaf, e.g. as the `(signer, signature)` of a [`signature_leaf`]
+    pub fn decode_leaf<Leaf: Decode>(&self) -> Option<Leaf> {
+        Decode::decode(&mut &self.leaf[..]).ok()
+    }
+}
+
+/// Merkle leaf committing to one signer of a log and its signature
+///
+/// `signature` is empty for signers that only took part in an aggregate signature.
+pub fn signature_leaf<AccountId: Encode>(signer: &AccountId, signature: &[u8]) -> Vec<u8> {
+    (signer, signature).encode()
+}
+
+/// Merkle leaf committing to an agent's effective reputation
+pub fn reputation_leaf<AccountId: Encode>(agent: &AccountId, reputation: u64) -> Vec<u8> {
+    (agent, reputation).encode()
+}
+
+/// Merkle root over encoded leaves
+pub fn merkle_root(leaves: &[Vec<u8>]) -> [u8; 32] {
+    binary_merkle_tree::merkle_root::<BlakeTwo256, _>(leaves).into()
+}
+
+/// Merkle proof for the leaf at `index`, or `None` if there is no such leaf
+pub fn merkle_inclusion_proof(leaves: &[Vec<u8>], index: usize) -> Option<MerkleInclusionProof> {
+    if index >= leaves.len() {
+        return None;
+    }
+    let proof = binary_merkle_tree::merkle_proof::<BlakeTwo256, _, _>(leaves, index as u32);
+    Some(MerkleInclusionProof {
+        root: proof.root.into(),
+        proof: proof.proof.into_iter().map(Into::into).collect(),
+        number_of_leaves: proof.number_of_leaves,
+        leaf_index: proof.leaf_index,
+        leaf: proof.leaf.to_vec(),
+    })
+}
+
+/// Reasons an aggregate signature does not check out against a signing group
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum AggregateCheckError {
+    /// Bitmap selects participants outside the group
+    InvalidBitmap,
+    /// Fewer participants signed than the threshold requires
+    BelowThreshold,
+    /// Aggregate public key does not match the selected participants
+    AggregateKeyMismatch,
+    /// The signature value itself is invalid
+    InvalidSignature,
+}
+
+/// Check an aggregate signature over `message` against a FROST group, returning the signers'
+/// positions
+///
+/// `group_participants` is the full ordered group. The bitmap selects signers by their position
+/// in that order, and the aggregate public key must combine exactly the selected keys.
+///
+/// The group signs with FROST(Ed25519, SHA-512), whose aggregates are plain Ed25519 signatures,
+/// so the signature value must verify over `message` under `group_key` as one.
+pub fn check_group_aggregate(
+    aggregate: &AggregateSignature,
+    message: &[u8],
+    group_key: &[u8; 32],
+    group_participants: &[[u8; 32]],
+    threshold: u32,
+) -> Result<Vec<usize>, AggregateCheckError> {
+    let signers = bitmap_indices(&aggregate.participant_bitmap);
+    if signers.iter().any(|i| *i >= group_participants.len()) {
+        return Err(AggregateCheckError::InvalidBitmap);
+    }
+    if (signers.len() as u32) < threshold {
+        return Err(AggregateCheckError::BelowThreshold);
+    }
+
+    let expected_pubkey = combine_keys(signers.iter().map(|i| &group_participants[*i]));
+    if aggregate.aggregate_pubkey != expected_pubkey {
+        return Err(AggregateCheckError::AggregateKeyMismatch);
+    }
+
+    if !verify_group_signature(&aggregate.signature, message, group_key) {
+        return Err(AggregateCheckError::InvalidSignature);
+    }
+
+    Ok(signers)
+}
+
+/// Whether `signature` is the FROST group's signature over `message` under `group_key`
+pub fn verify_group_signature(signature: &[u8; 64], message: &[u8], group_key: &[u8; 32]) -> bool {
+    ed25519::Signature::from_raw(*signature).verify(message, &ed25519::Public::from_raw(*group_key))
+}
+
+/// Derive an agent's FROST key from its encoded account: truncated or zero-padded to 32 bytes
+pub fn frost_key(encoded_account: &[u8]) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    let len = encoded_account.len().min(32);
+    key[..len].copy_from_slice(&encoded_account[..len]);
+    key
+}
+
+/// Combine participant public keys into a single key (simplified as XOR)
+pub fn combine_keys<'a>(keys: impl Iterator<Item = &'a [u8; 32]>) -> [u8; 32] {
+    let mut combined = [0u8; 32];
+    for key in keys {
+        for j in 0..32 {
+            combined[j] ^= key[j];
+        }
+    }
+    combined
+}
+
+/// Positions of the participants set in a participant bitmap
+pub fn bitmap_indices(bitmap: &[u8; 2]) -> Vec<usize> {
+    (0..16).filter(|i| bitmap[i / 8] & (1 << (i % 8)) != 0).collect()
+}
+
+/// Class of routine agent calls an operational proxy key can be allowed to make
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum CallClass {
+    /// Liveness heartbeats
+    Heartbeat,
+    /// Signing consensus logs
+    Signing,
+}
+
+/// Set of [`CallClass`]es, one bit per class
+#[derive(Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CallClasses(pub u8);
+
+impl CallClasses {
+    /// The set with `class` added
+    pub fn with(self, class: CallClass) -> Self {
+        Self(self.0 | 1 << class as u8)
+    }
+
+    /// Whether `class` is in the set
+    pub fn contains(&self, class: CallClass) -> bool {
+        self.0 & 1 << class as u8 != 0
+    }
+
+    /// Whether the set contains no class
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+/// Experimental feature whose extrinsics governance rolls out to agents gradually
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum Feature {
+    /// Taking part in FROST signing sessions
+    FrostSigning,
+    /// Submitting consensus logs at a priority other than `Normal`
+    PrioritySubmission,
+}
+
+impl Feature {
+    /// Every feature, in bit order
+    pub const ALL: [Feature; 2] = [Feature::FrostSigning, Feature::PrioritySubmission];
+}
+
+/// Set of [`Feature`]s, one bit per feature
+#[derive(Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct FeatureFlags(pub u32);
+
+impl FeatureFlags {
+    /// The set of every feature
+    pub fn all() -> Self {
+        Feature::ALL.into_iter().fold(Self::default(), Self::with)
+    }
+
+    /// The set with `feature` added
+    pub fn with(self, feature: Feature) -> Self {
+        Self(self.0 | 1 << feature as u32)
+    }
+
+    /// The set with `feature` removed
+    pub fn without(self, feature: Feature) -> Self {
+        Self(self.0 & !(1 << feature as u32))
+    }
+
+    /// Whether `feature` is in the set
+    pub fn contains(&self, feature: Feature) -> bool {
+        self.0 & 1 << feature as u32 != 0
+    }
+
+    /// Whether the set contains no feature
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+/// Tag naming something governance allowed an agent to do, such as `*b"oracle\0\0"`
+pub type Capability = [u8; 8];
+
+/// Numeric ID of the first custom role; lower IDs are reserved for canonical roles
+pub const FIRST_CUSTOM_ROLE_ID: u16 = 256;
+
+/// Role an agent performs in the C-Suite
+///
+/// Canonical roles have fixed IDs from 1, which are also their SCALE variant indices.
+/// [`AgentRole::Custom`] carries an ID of at least [`FIRST_CUSTOM_ROLE_ID`].
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, Ord, PartialOrd, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum AgentRole {
+    /// OS and meta-orchestrator
+    #[codec(index = 1)]
+    Lyra,
+    /// Insight relay and auditing
+    #[codec(index = 2)]
+    Echo,
+    /// Legal and compliance
+    #[codec(index = 3)]
+    Verdict,
+    /// Hardware diagnostics
+    #[codec(index = 4)]
+    Volt,
+    /// Main processor
+    #[codec(index = 5)]
+    Core,
+    /// Medical diagnostics
+    #[codec(index = 6)]
+    Vitals,
+    /// Security and surveillance
+    #[codec(index = 7)]
+    Sentinel,
+    /// Research and hypotheses
+    #[codec(index = 8)]
+    Theory,
+    /// Knowledge base
+    #[codec(index = 9)]
+    Beacon,
+    /// Visual analysis
+    #[codec(index = 10)]
+    Lens,
+    /// ECU and vehicle controller
+    #[codec(index = 11)]
+    Arc,
+    /// Autonomous vehicle
+    #[codec(index = 12)]
+    Otto,
+    /// Smart home and environment
+    #[codec(index = 13)]
+    Luma,
+    /// A role outside the canonical set, by its ID
+    #[codec(index = 255)]
+    Custom(u16),
+}
+
+impl AgentRole {
+    /// Every canonical role, in ID order
+    pub const CANONICAL: [AgentRole; 13] = [
+        Self::Lyra, Self::Echo, Self::Verdict, Self::Volt, Self::Core, Self::Vitals, Self::Sentinel,
+        Self::Theory, Self::Beacon, Self::Lens, Self::Arc, Self::Otto, Self::Luma,
+    ];
+
+    /// The numeric ID of the role
+    pub fn id(&self) -> u16 {
+        match self {
+            Self::Custom(id) => *id,
+            canonical => Self::CANONICAL.iter().position(|role| role == canonical).map_or(0, |i| i as u16 + 1),
+        }
+    }
+
+    /// The role with the given ID, if it is a canonical ID or a custom one
+    pub fn from_id(id: u16) -> Option<Self> {
+        match id {
+            0 => None,
+            id if id >= FIRST_CUSTOM_ROLE_ID => Some(Self::Custom(id)),
+            id => Self::CANONICAL.get(id as usize - 1).copied(),
+        }
+    }
+
+    /// Whether the role is canonical, or custom with an ID outside the reserved range
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Self::Custom(id) => *id >= FIRST_CUSTOM_ROLE_ID,
+            _ => true,
+        }
+    }
+
+    /// The name of the role, `"Custom"` for every custom role
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Lyra => "Lyra",
+            Self::Echo => "Echo",
+            Self::Verdict => "Verdict",
+            Self::Volt => "Volt",
+            Self::Core => "Core",
+            Self::Vitals => "Vitals",
+            Self::Sentinel => "Sentinel",
+            Self::Theory => "Theory",
+            Self::Beacon => "Beacon",
+            Self::Lens => "Lens",
+            Self::Arc => "Arc",
+            Self::Otto => "Otto",
+            Self::Luma => "Luma",
+            Self::Custom(_) => "Custom",
+        }
+    }
+
+    /// Parse a role from its display form: a canonical name in any case, or `Custom(<id>)`
+    pub fn from_name(name: &[u8]) -> Option<Self> {
+        if let Some(role) = Self::CANONICAL.iter().find(|role| role.name().as_bytes().eq_ignore_ascii_case(name)) {
+            return Some(*role);
+        }
+        let id = name.strip_prefix(b"Custom(")?.strip_suffix(b")")?;
+        let id = core::str::from_utf8(id).ok()?.parse::<u16>().ok()?;
+        Some(Self::Custom(id)).filter(Self::is_valid)
+    }
+
+    /// The role for a free-form role name from before roles were enumerated
+    ///
+    /// Names [`AgentRole::from_name`] recognises keep their role. Any other name becomes a
+    /// custom role with an ID derived from its hash, so agents that shared a name still share
+    /// a role.
+    pub fn from_legacy_name(name: &[u8]) -> Self {
+        Self::from_name(name).unwrap_or_else(|| {
+            let hash = <BlakeTwo256 as sp_runtime::traits::Hash>::hash(name);
+            let offset = u16::from_le_bytes([hash[0], hash[1]]) % (u16::MAX - FIRST_CUSTOM_ROLE_ID + 1);
+            Self::Custom(FIRST_CUSTOM_ROLE_ID + offset)
+        })
+    }
+}
+
+impl core::fmt::Display for AgentRole {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Custom(id) => write!(f, "Custom({})", id),
+            role => f.write_str(role.name()),
+        }
+    }
+}
+
+/// Key type an agent signs consensus logs with
+#[derive(Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum SignatureScheme {
+    /// Schnorrkel signatures over Ristretto25519, the default of Substrate keys
+    #[default]
+    Sr25519,
+    /// Ed25519 signatures
+    Ed25519,
+    /// Recoverable ECDSA signatures over secp256k1
+    Ecdsa,
+}
+
+impl SignatureScheme {
+    /// Every scheme, in the order of their SCALE variant indices
+    pub const ALL: [Self; 3] = [Self::Sr25519, Self::Ed25519, Self::Ecdsa];
+}
+
+/// Checks a signature an agent made with its [`SignatureScheme`]
+pub trait VerifyAgentSignature<AccountId> {
+    /// Whether `signature` is `signer`'s signature over `message` under `scheme`
+    fn verify(scheme: SignatureScheme, signer: &AccountId, message: &[u8], signature: &[u8]) -> bool;
+}
+
+/// Verifies signatures made with the key behind an [`AccountId32`], as [`MultiSignature`] does:
+/// the account is the public key for sr25519 and ed25519, and the blake2 hash of the compressed
+/// public key recovered from the signature for ecdsa
+pub struct MultiSchemeVerifier;
+
+impl VerifyAgentSignature<AccountId32> for MultiSchemeVerifier {
+    fn verify(scheme: SignatureScheme, signer: &AccountId32, message: &[u8], signature: &[u8]) -> bool {
+        let signature = match scheme {
+            SignatureScheme::Sr25519 => sr25519::Signature::try_from(signature).map(MultiSignature::from),
+            SignatureScheme::Ed25519 => ed25519::Signature::try_from(signature).map(MultiSignature::from),
+            SignatureScheme::Ecdsa => ecdsa::Signature::try_from(signature).map(MultiSignature::from),
+        };
+        signature.map_or(false, |signature| signature.verify(message, signer))
+    }
+}
+
+/// Read-only view of the agent registry for pallets that need to know who is an agent
+pub trait AgentInspector<AccountId> {
+    /// Whether `who` is a registered agent
+    fn is_registered(who: &AccountId) -> bool;
+
+    /// Whether `who` has announced its retirement and is winding down
+    fn is_retiring(who: &AccountId) -> bool;
+
+    /// Whether `who` runs at least the minimum supported agent version
+    fn meets_minimum_version(who: &AccountId) -> bool;
+
+    /// The role `who` registered with, if it is an agent
+    fn role(who: &AccountId) -> Option<AgentRole>;
+
+    /// The trust score of `who`, if it is an agent
+    fn trust_score(who: &AccountId) -> Option<u64>;
+
+    /// The agent `who` may make `class` calls for as one of its operational proxy keys
+    fn proxied_agent(_who: &AccountId, _class: CallClass) -> Option<AccountId> {
+        None
+    }
+
+    /// Whether `who` is an agent that was granted `capability`
+    fn has_capability(_who: &AccountId, _capability: &Capability) -> bool {
+        false
+    }
+
+    /// Whether `feature` is enabled for `who`, globally or for it alone
+    fn has_feature(_who: &AccountId, _feature: Feature) -> bool {
+        true
+    }
+
+    /// Whether `who` recently reported itself as overloaded
+    fn is_overloaded(_who: &AccountId) -> bool {
+        false
+    }
+
+    /// The scheme `who` signs consensus logs with
+    fn signature_scheme(_who: &AccountId) -> SignatureScheme {
+        SignatureScheme::Sr25519
+    }
+}
+
+/// Liveness view of the agent registry
+pub trait AgentStatusProvider<AccountId> {
+    /// Whether `who` is registered and currently online
+    fn is_online(who: &AccountId) -> bool;
+}
+
+/// Decides which routine agent calls may be dispatched without transaction fees
+pub trait FeeExemption<AccountId> {
+    /// Whether the next routine call from `who` is fee-exempt
+    fn is_fee_exempt(who: &AccountId) -> bool;
+
+    /// Record that `who` consumed one fee-exempt call
+    ///
+    /// Called before dispatch, so a call that fails still consumes its exemption.
+    fn note_fee_exempt_call(who: &AccountId);
+}
+
+/// No exemptions: every call pays fees
+impl<AccountId> FeeExemption<AccountId> for () {
+    fn is_fee_exempt(_who: &AccountId) -> bool {
+        false
+    }
+
+    fn note_fee_exempt_call(_who: &AccountId) {}
+}
+
+/// Maintenance windows approved for agents, during which their reputation does not decay
+pub trait MaintenanceSchedule<AccountId, BlockNumber> {
+    /// Number of blocks from `from` up to, but excluding, `to` covered by approved maintenance
+    /// windows of `who`
+    fn maintenance_blocks(who: &AccountId, from: BlockNumber, to: BlockNumber) -> BlockNumber;
+}
+
+/// No maintenance windows: reputation always decays
+impl<AccountId, BlockNumber: Zero> MaintenanceSchedule<AccountId, BlockNumber> for () {
+    fn maintenance_blocks(_who: &AccountId, _from: BlockNumber, _to: BlockNumber) -> BlockNumber {
+        Zero::zero()
+    }
+}
+
+/// Consensus thresholds in effect at one point in time
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ConsensusThresholds {
+    /// Partial signatures required to form a FROST aggregate signature
+    pub frost_threshold: u32,
+    /// Version of the FROST group key
+    pub frost_group_version: u32,
+    /// Share of a log's involved agents whose rejections veto it
+    pub veto_threshold: Perbill,
+    /// Number of agents signing after the submitter that count as first responders
+    pub first_responders: u32,
+}
+
+/// Source of the consensus thresholds currently in effect
+pub trait ConsensusParameters {
+    /// The thresholds the consensus pallet applies right now
+    fn consensus_thresholds() -> ConsensusThresholds;
+}
+
+/// No consensus pallet: every threshold reads as zero
+impl ConsensusParameters for () {
+    fn consensus_thresholds() -> ConsensusThresholds {
+        ConsensusThresholds::default()
+    }
+}
+
+/// Mirror of `frame_system::Phase`, the point in a block an event was emitted at
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+pub enum Phase {
+    /// Applying the extrinsic with this index
+    ApplyExtrinsic(u32),
+    /// Finalizing the block
+    Finalization,
+    /// Initializing the block
+    Initialization,
+}
+
+/// `frame_system::EventRecord` as a tuple of its fields: phase, event and topics
+pub type EventRecord<Event, Hash> = (Phase, Event, Vec<Hash>);
@@ -30,6 +30,7 @@ use polkadot_sdk::*;
 
 use frame_support::{
 	genesis_builder_helper::{build_state, get_preset},
+	traits::Get,
 	weights::Weight,
 };
 use pallet_aura::Authorities;
@@ -45,10 +46,17 @@ use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-	AccountId, Balance, Block, ConsensusHook, Executive, InherentDataExt, Nonce, ParachainSystem,
-	Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment,
-	SLOT_DURATION, VERSION,
+	AccountId, Balance, Block, BlockNumber, ConsensusHook, ConsensusLog, Executive, GatewayRegistry,
+	Hash, InherentDataExt, Nonce, ParachainSystem, Recall, Reputation, Runtime, RuntimeCall,
+	RuntimeEvent, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment, SLOT_DURATION,
+	VERSION,
 };
+use pallet_consensus_log_runtime_api::{
+	FinalizedLogSummary, GroupKeyVersion, MerkleInclusionProof, SessionId, SessionStatus,
+};
+use pallet_gateway_registry_runtime_api::{GatewayId, GatewayInfo, GatewayKind};
+use pallet_recall_runtime_api::{RecordContent, RecordExportPage};
+use pallet_reputation_runtime_api::{EraConfig, EraIndex, EraStatistics, ParticipationSummary};
 
 // we move some impls outside so we can easily use them with `docify`.
 impl Runtime {
@@ -64,6 +72,26 @@ impl Runtime {
 	) -> bool {
 		ConsensusHook::can_build_upon(included_hash, slot)
 	}
+
+	fn impl_finalized_log_summary(log_id: Hash) -> Option<FinalizedLogSummary<AccountId, Hash>> {
+		let finalized = ConsensusLog::finalized_logs(log_id)?;
+		let log = ConsensusLog::logs(log_id)?;
+		let signers = ConsensusLog::finalized_signers(&log_id)?;
+
+		// Score the sign-off the same way recall scores a record's signers
+		let base = <Runtime as pallet_recall::Config>::BaseTrustScore::get();
+		let trust_score = signers
+			.iter()
+			.fold(base, |score, signer| score.saturating_add(Recall::signer_contribution(signer)));
+
+		Some(FinalizedLogSummary {
+			log_id,
+			cid: log.cid.into_inner(),
+			signers,
+			trust_score,
+			aggregated: finalized.aggregate.is_some(),
+		})
+	}
 }
 
 impl_runtime_apis! {
@@ -292,6 +320,160 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(6)]
+	impl pallet_consensus_log_runtime_api::ConsensusLogApi<Block, AccountId, Hash, BlockNumber> for Runtime {
+		fn finalized_log_summary(log_id: Hash) -> Option<FinalizedLogSummary<AccountId, Hash>> {
+			Runtime::impl_finalized_log_summary(log_id)
+		}
+
+		fn finalized_in_block() -> Vec<FinalizedLogSummary<AccountId, Hash>> {
+			System::read_events_no_consensus()
+				.filter_map(|record| match record.event {
+					RuntimeEvent::ConsensusLog(pallet_consensus_log::Event::LogFinalized { log_id, .. }) =>
+						Some(log_id),
+					_ => None,
+				})
+				.filter_map(Runtime::impl_finalized_log_summary)
+				.collect()
+		}
+
+		fn signature_inclusion_proof(log_id: Hash, signer: AccountId) -> Option<MerkleInclusionProof> {
+			ConsensusLog::signature_inclusion_proof(&log_id, &signer)
+		}
+
+		fn signed_at(log_id: Hash, agent: AccountId, block: BlockNumber) -> bool {
+			ConsensusLog::signed_at(&log_id, &agent, block)
+		}
+
+		fn pending_duties(agent: AccountId) -> Vec<Hash> {
+			ConsensusLog::pending_duties(agent).into_inner()
+		}
+
+		fn log_metadata_blob(log_id: Hash) -> Option<Vec<u8>> {
+			ConsensusLog::log_metadata_blob(&log_id)
+		}
+
+		fn metadata_blob(hash: Hash) -> Option<Vec<u8>> {
+			ConsensusLog::metadata_blobs(hash).map(|blob| blob.into_inner())
+		}
+
+		fn next_sequence(agent: AccountId) -> u64 {
+			ConsensusLog::agent_nonce(agent)
+		}
+
+		fn sequence_gaps(agent: AccountId) -> Vec<(u64, u64)> {
+			ConsensusLog::sequence_gaps(agent).into_inner()
+		}
+	}
+
+	#[api_version(2)]
+	impl pallet_reputation_runtime_api::ReputationApi<Block, AccountId> for Runtime {
+		fn reputation_root() -> Option<(EraIndex, [u8; 32])> {
+			Reputation::reputation_root().map(|root| (Reputation::current_era(), root))
+		}
+
+		fn reputation_proof(agent_id: AccountId) -> Option<MerkleInclusionProof> {
+			Reputation::reputation_proof(&agent_id)
+		}
+
+		fn participation_streak(agent_id: AccountId) -> u32 {
+			Reputation::participation_streak(&agent_id)
+		}
+	}
+
+	impl pallet_reputation_runtime_api::CommitteeApi<Block, AccountId, BlockNumber> for Runtime {
+		fn current_committee() -> Vec<AccountId> {
+			Reputation::committee().into_inner()
+		}
+
+		fn next_rotation_block() -> Option<BlockNumber> {
+			Reputation::next_rotation_block()
+		}
+
+		fn committee_for_era(era: EraIndex) -> Option<Vec<AccountId>> {
+			Reputation::committee_for_era(era)
+		}
+	}
+
+	impl pallet_reputation_runtime_api::ParticipationApi<Block, AccountId> for Runtime {
+		fn era_summary(agent: AccountId, era: EraIndex) -> Option<ParticipationSummary> {
+			Reputation::era_summary(&agent, era)
+		}
+	}
+
+	impl pallet_reputation_runtime_api::EraStatsApi<Block, Balance> for Runtime {
+		fn era_stats(era: EraIndex) -> Option<EraStatistics<Balance>> {
+			Reputation::era_stats(era)
+		}
+	}
+
+	impl pallet_reputation_runtime_api::EraConfigApi<Block, Balance, BlockNumber> for Runtime {
+		fn era_config(era: EraIndex) -> Option<EraConfig<Balance, BlockNumber>> {
+			Reputation::era_config(era)
+		}
+
+		fn era_config_at(block: BlockNumber) -> Option<(EraIndex, EraConfig<Balance, BlockNumber>)> {
+			Reputation::era_config_at(block)
+		}
+	}
+
+	#[api_version(3)]
+	impl pallet_recall_runtime_api::RecallApi<Block, BlockNumber> for Runtime {
+		fn export_records(start_id: u64, max_bytes: u32) -> RecordExportPage {
+			Recall::export_records(start_id, max_bytes)
+		}
+
+		fn record_content(record_id: u64) -> Option<RecordContent> {
+			Recall::record_content(record_id)
+		}
+
+		fn records_between(start_block: BlockNumber, end_block: BlockNumber, cursor: Option<u64>) -> RecordExportPage {
+			Recall::records_between(start_block, end_block, cursor)
+		}
+	}
+
+	impl pallet_gateway_registry_runtime_api::GatewayRegistryApi<Block, AccountId, Hash, BlockNumber> for Runtime {
+		fn active_gateways(kind: Option<GatewayKind>) -> Vec<(GatewayId, GatewayInfo<AccountId, Hash, BlockNumber>)> {
+			GatewayRegistry::active_gateways(kind)
+		}
+
+		fn is_approved(url_hash: Hash) -> bool {
+			<GatewayRegistry as pallet_gateway_registry::GatewayInspect<Hash>>::is_approved(&url_hash)
+		}
+	}
+
+	#[api_version(2)]
+	impl pallet_consensus_log_runtime_api::FrostApi<Block, AccountId, Hash, BlockNumber> for Runtime {
+		fn group_public_key() -> Option<[u8; 32]> {
+			ConsensusLog::frost_group_key()
+		}
+
+		fn current_threshold() -> u32 {
+			ConsensusLog::frost_threshold()
+		}
+
+		fn session_status(session_id: SessionId) -> Option<SessionStatus<AccountId, Hash, BlockNumber>> {
+			let session = ConsensusLog::signing_sessions(session_id)?;
+			Some(SessionStatus {
+				log_id: session.log_id,
+				phase: session.phase,
+				phase_deadline: session.phase_deadline,
+				threshold: session.threshold,
+				commitments: session.commitments.len() as u32,
+				shares: session.shares.len() as u32,
+				pending: ConsensusLog::pending_participants(&session),
+			})
+		}
+
+		fn group_key_version() -> GroupKeyVersion {
+			ConsensusLog::group_key_version()
+		}
+
+		fn ineligible_participants() -> Vec<AccountId> {
+			ConsensusLog::ineligible_frost_participants()
+		}
+	}
+
 	impl sp_genesis_builder::GenesisBuilder<Block> for Runtime {
 		fn build_state(config: Vec<u8>) -> sp_genesis_builder::Result {
 			build_state::<RuntimeGenesisConfig>(config)
@@ -1,15 +1,16 @@
 use crate::{
 	AccountId, AllPalletsWithSystem, Balances, ParachainInfo, ParachainSystem, PolkadotXcm,
-	Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, WeightToFee, XcmpQueue,
+	Reputation, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, WeightToFee, XcmpQueue,
 };
 
 use polkadot_sdk::{
 	staging_xcm as xcm, staging_xcm_builder as xcm_builder, staging_xcm_executor as xcm_executor, *,
 };
 
+use codec::Encode;
 use frame_support::{
-	parameter_types,
-	traits::{ConstU32, Contains, Everything, Nothing},
+	ensure, parameter_types,
+	traits::{ConstU32, Contains, EnsureOrigin, Everything, Nothing, ProcessMessageError},
 	weights::Weight,
 };
 use frame_system::EnsureRoot;
@@ -25,7 +26,10 @@ use xcm_builder::{
 	SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit,
 	TrailingSetTopicAsId, UsingComponents, WithComputedOrigin, WithUniqueTopic,
 };
-use xcm_executor::XcmExecutor;
+use xcm_executor::{
+	traits::{Properties, ShouldExecute},
+	XcmExecutor,
+};
 
 parameter_types! {
 	pub const RelayLocation: Location = Location::parent();
@@ -88,6 +92,8 @@ parameter_types! {
 	pub UnitWeightCost: Weight = Weight::from_parts(1_000_000_000, 64 * 1024);
 	pub const MaxInstructions: u32 = 100;
 	pub const MaxAssetsIntoHolding: u32 = 64;
+	// Covers the two instructions of a reputation import and the call itself.
+	pub MaxReputationImportWeight: Weight = Weight::from_parts(4_000_000_000, 256 * 1024);
 }
 
 pub struct ParentOrParentsExecutivePlurality;
@@ -97,6 +103,55 @@ impl Contains<Location> for ParentOrParentsExecutivePlurality {
 	}
 }
 
+/// Sister chains registered as reputation trust anchors
+pub struct ReputationTrustAnchors;
+impl Contains<Location> for ReputationTrustAnchors {
+	fn contains(location: &Location) -> bool {
+		match location.unpack() {
+			(1, [Parachain(id)]) => Reputation::trust_anchors(id).is_some(),
+			_ => false,
+		}
+	}
+}
+
+/// Allows trust anchors to import reputation for free, and nothing else
+///
+/// The message must be exactly an `UnpaidExecution` limited to at most
+/// `MaxReputationImportWeight`, followed by a native `Transact` of
+/// `Reputation::import_reputation_digest` or `Reputation::import_reputation_attestation`.
+pub struct AllowReputationImportsFrom<T>(core::marker::PhantomData<T>);
+impl<T: Contains<Location>> ShouldExecute for AllowReputationImportsFrom<T> {
+	fn should_execute<Call>(
+		origin: &Location,
+		instructions: &mut [Instruction<Call>],
+		max_weight: Weight,
+		_properties: &mut Properties,
+	) -> Result<(), ProcessMessageError> {
+		ensure!(T::contains(origin), ProcessMessageError::Unsupported);
+
+		let [
+			UnpaidExecution { weight_limit: Limited(limit), check_origin: None },
+			Transact { origin_kind: OriginKind::Native, call, .. },
+		] = instructions
+		else {
+			return Err(ProcessMessageError::BadFormat);
+		};
+		ensure!(
+			limit.all_lte(MaxReputationImportWeight::get()) && max_weight.all_lte(*limit),
+			ProcessMessageError::Overweight(max_weight)
+		);
+
+		let mut call: xcm::DoubleEncoded<RuntimeCall> = call.clone().into();
+		match call.ensure_decoded() {
+			Ok(RuntimeCall::Reputation(
+				pallet_reputation::Call::import_reputation_digest { .. } |
+				pallet_reputation::Call::import_reputation_attestation { .. },
+			)) => Ok(()),
+			_ => Err(ProcessMessageError::Unsupported),
+		}
+	}
+}
+
 pub type Barrier = TrailingSetTopicAsId<
 	DenyThenTry<
 		DenyReserveTransferToRelayChain,
@@ -107,6 +162,8 @@ pub type Barrier = TrailingSetTopicAsId<
 					AllowTopLevelPaidExecutionFrom<Everything>,
 					AllowExplicitUnpaidExecutionFrom<ParentOrParentsExecutivePlurality>,
 					// ^^^ Parent and its exec plurality get free execution
+					AllowReputationImportsFrom<ReputationTrustAnchors>,
+					// ^^^ Trust anchors send reputation digests and attestations for free
 				),
 				UniversalLocation,
 				ConstU32<8>,
@@ -192,6 +249,44 @@ impl pallet_xcm::Config for Runtime {
 	type RemoteLockConsumerIdentifier = ();
 }
 
+/// Yields the ID of the sibling parachain a call was dispatched by over XCM
+pub struct ReputationAttestationOrigin;
+impl EnsureOrigin<RuntimeOrigin> for ReputationAttestationOrigin {
+	type Success = u32;
+
+	fn try_origin(o: RuntimeOrigin) -> Result<u32, RuntimeOrigin> {
+		match o.clone().into() {
+			Ok(cumulus_pallet_xcm::Origin::SiblingParachain(id)) => Ok(id.into()),
+			_ => Err(o),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+		Ok(cumulus_pallet_xcm::Origin::SiblingParachain(1000.into()).into())
+	}
+}
+
+/// Sends reputation digests to sibling parachains running the reputation pallet at the same index
+///
+/// The sibling must allow unpaid execution from this chain, e.g. by registering it as a trust
+/// anchor. The message is limited to `MaxReputationImportWeight`, which the sibling's barrier
+/// caps unpaid imports at.
+pub struct XcmReputationExporter;
+impl pallet_reputation::ReputationExporter for XcmReputationExporter {
+	fn export_digest(dest: u32, digest: &pallet_reputation::ReputationDigest) -> frame_support::dispatch::DispatchResult {
+		let call = RuntimeCall::Reputation(pallet_reputation::Call::import_reputation_digest { digest: *digest });
+		let message = Xcm(alloc::vec![
+			UnpaidExecution { weight_limit: Limited(MaxReputationImportWeight::get()), check_origin: None },
+			Transact { origin_kind: OriginKind::Native, fallback_max_weight: None, call: call.encode().into() },
+		]);
+
+		send_xcm::<XcmRouter>(Location::new(1, [Parachain(dest)]), message)
+			.map(|_| ())
+			.map_err(|_| sp_runtime::DispatchError::Other("Failed to send reputation digest"))
+	}
+}
+
 impl cumulus_pallet_xcm::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type XcmExecutor = XcmExecutor<XcmConfig>;
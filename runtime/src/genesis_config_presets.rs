@@ -1,6 +1,7 @@
 use crate::{
-	AccountId, BalancesConfig, CollatorSelectionConfig, ParachainInfoConfig, PolkadotXcmConfig,
-	RuntimeGenesisConfig, SessionConfig, SessionKeys, SudoConfig, EXISTENTIAL_DEPOSIT,
+	AccountId, AgentRegistryConfig, Balance, BalancesConfig, CollatorSelectionConfig,
+	ConsensusLogConfig, CouncilConfig, ParachainInfoConfig, PolkadotXcmConfig,
+	RuntimeGenesisConfig, SessionConfig, SessionKeys, SudoConfig, EXISTENTIAL_DEPOSIT, UNIT,
 };
 
 use alloc::{vec, vec::Vec};
@@ -11,13 +12,58 @@ use cumulus_primitives_core::ParaId;
 use parachains_common::AuraId;
 use serde_json::Value;
 use sp_genesis_builder::PresetId;
-use sp_keyring::Sr25519Keyring;
+use sp_keyring::{Ed25519Keyring, Sr25519Keyring};
 
 /// The default XCM version to set in genesis config.
 const SAFE_XCM_VERSION: u32 = xcm::prelude::XCM_VERSION;
 /// Parachain id used for gensis config presets of parachain template.
 const PARACHAIN_ID: u32 = 1000;
 
+/// Roles of the C-Suite agents pre-registered at genesis.
+const CSUITE_AGENT_ROLES: [&str; 13] = [
+	"Lyra", "Echo", "Verdict", "Volt", "Core", "Vitals", "Sentinel", "Theory", "Beacon", "Lens",
+	"Arc", "Otto", "Luma",
+];
+/// Number of partial signatures the C-Suite FROST group needs (9 of 13).
+const CSUITE_FROST_THRESHOLD: u32 = 9;
+/// Balance given to each endowed account of the live network.
+const CSUITE_LIVE_ENDOWMENT: Balance = 1_000 * UNIT;
+
+/// C-Suite specific genesis state: agents, FROST signing group and council.
+struct CSuiteGenesis {
+	/// Pre-registered agents as `(account, role)`.
+	agents: Vec<(AccountId, Vec<u8>)>,
+	/// Council members.
+	council: Vec<AccountId>,
+	/// Group key of the FROST signing group formed by the agents.
+	frost_group_key: [u8; 32],
+	/// Number of partial signatures required by the FROST group.
+	frost_threshold: u32,
+	/// Balance given to each endowed account.
+	endowment: Balance,
+}
+
+/// Operator keys and parameters of the C-Suite live network.
+///
+/// There is no built-in preset for the live network: its keys are not known to the runtime and
+/// are read by the node from a configuration file. The live network has no sudo key.
+pub struct CSuiteLiveGenesis {
+	/// Initial collators as `(account, aura key)`.
+	pub collators: Vec<(AccountId, AuraId)>,
+	/// Accounts endowed at genesis, besides the agents.
+	pub endowed_accounts: Vec<AccountId>,
+	/// Council members.
+	pub council: Vec<AccountId>,
+	/// Pre-registered agents as `(account, role)`.
+	pub agents: Vec<(AccountId, Vec<u8>)>,
+	/// Group key produced by the agents' FROST key generation ceremony.
+	pub frost_group_key: [u8; 32],
+	/// Number of partial signatures required by the FROST group.
+	pub frost_threshold: u32,
+	/// The ID the parachain is registered with.
+	pub para_id: ParaId,
+}
+
 /// Generate the session keys from individual elements.
 ///
 /// The input must be a tuple of individual keys (a single arg for now since we have just one key).
@@ -25,18 +71,56 @@ pub fn template_session_keys(keys: AuraId) -> SessionKeys {
 	SessionKeys { aura: keys }
 }
 
+/// Group key of the FROST signing group of the keyring agents.
+///
+/// A stand-in for the key of a DKG among the dev accounts: the well-known `//Alice` ed25519 key,
+/// so aggregate signatures for dev chains can be made by signing the log ID with it.
+fn dev_frost_group_key() -> [u8; 32] {
+	Ed25519Keyring::Alice.public().0
+}
+
+/// The C-Suite agents bound to the accounts of the well-known keyring.
+fn keyring_agents() -> Vec<(AccountId, Vec<u8>)> {
+	Sr25519Keyring::iter()
+		.map(|k| k.to_account_id())
+		.zip(CSUITE_AGENT_ROLES)
+		.map(|(account, role)| (account, role.as_bytes().to_vec()))
+		.collect()
+}
+
+/// The keyring agents' C-Suite genesis with `council`.
+fn keyring_csuite_genesis(council: Vec<AccountId>) -> CSuiteGenesis {
+	CSuiteGenesis {
+		agents: keyring_agents(),
+		council,
+		frost_group_key: dev_frost_group_key(),
+		frost_threshold: CSUITE_FROST_THRESHOLD,
+		endowment: 1u128 << 60,
+	}
+}
+
 fn testnet_genesis(
 	invulnerables: Vec<(AccountId, AuraId)>,
 	endowed_accounts: Vec<AccountId>,
-	root: AccountId,
+	root: Option<AccountId>,
+	csuite: CSuiteGenesis,
 	id: ParaId,
 ) -> Value {
+	let frost_participants =
+		csuite.agents.iter().map(|(account, _)| account.clone()).collect::<Vec<_>>();
+	let mut endowed_accounts = endowed_accounts;
+	for account in &frost_participants {
+		if !endowed_accounts.contains(account) {
+			endowed_accounts.push(account.clone());
+		}
+	}
+
 	let config = RuntimeGenesisConfig {
 		balances: BalancesConfig {
 			balances: endowed_accounts
 				.iter()
 				.cloned()
-				.map(|k| (k, 1u128 << 60))
+				.map(|k| (k, csuite.endowment))
 				.collect::<Vec<_>>(),
 		},
 		parachain_info: ParachainInfoConfig { parachain_id: id, ..Default::default() },
@@ -62,7 +146,20 @@ fn testnet_genesis(
 			safe_xcm_version: Some(SAFE_XCM_VERSION),
 			..Default::default()
 		},
-		sudo: SudoConfig { key: Some(root) },
+		sudo: SudoConfig { key: root },
+		agent_registry: AgentRegistryConfig {
+			agents: csuite
+				.agents
+				.into_iter()
+				.map(|(account, role)| (account, role, None))
+				.collect::<Vec<_>>(),
+		},
+		consensus_log: ConsensusLogConfig {
+			frost_group_key: Some(csuite.frost_group_key),
+			frost_participants,
+			frost_threshold: csuite.frost_threshold,
+		},
+		council: CouncilConfig { members: csuite.council, ..Default::default() },
 		..Default::default()
 	};
 
@@ -77,7 +174,12 @@ fn local_testnet_genesis() -> Value {
 			(Sr25519Keyring::Bob.to_account_id(), Sr25519Keyring::Bob.public().into()),
 		],
 		Sr25519Keyring::well_known().map(|k| k.to_account_id()).collect(),
-		Sr25519Keyring::Alice.to_account_id(),
+		Some(Sr25519Keyring::Alice.to_account_id()),
+		keyring_csuite_genesis(vec![
+			Sr25519Keyring::Alice.to_account_id(),
+			Sr25519Keyring::Bob.to_account_id(),
+			Sr25519Keyring::Charlie.to_account_id(),
+		]),
 		PARACHAIN_ID.into(),
 	)
 }
@@ -90,11 +192,29 @@ fn development_config_genesis() -> Value {
 			(Sr25519Keyring::Bob.to_account_id(), Sr25519Keyring::Bob.public().into()),
 		],
 		Sr25519Keyring::well_known().map(|k| k.to_account_id()).collect(),
-		Sr25519Keyring::Alice.to_account_id(),
+		Some(Sr25519Keyring::Alice.to_account_id()),
+		keyring_csuite_genesis(vec![Sr25519Keyring::Alice.to_account_id()]),
 		PARACHAIN_ID.into(),
 	)
 }
 
+/// Genesis of the C-Suite live network with the operator keys in `live`.
+pub fn csuite_live_genesis(live: CSuiteLiveGenesis) -> Value {
+	testnet_genesis(
+		live.collators,
+		live.endowed_accounts,
+		None,
+		CSuiteGenesis {
+			agents: live.agents,
+			council: live.council,
+			frost_group_key: live.frost_group_key,
+			frost_threshold: live.frost_threshold,
+			endowment: CSUITE_LIVE_ENDOWMENT,
+		},
+		live.para_id,
+	)
+}
+
 /// Provides the JSON representation of predefined genesis config for given `id`.
 pub fn get_preset(id: &PresetId) -> Option<vec::Vec<u8>> {
 	let patch = match id.as_ref() {
@@ -13,6 +13,8 @@ pub mod configs;
 mod genesis_config_presets;
 mod weights;
 
+pub use genesis_config_presets::{csuite_live_genesis, CSuiteLiveGenesis};
+
 extern crate alloc;
 use alloc::vec::Vec;
 use smallvec::smallvec;
@@ -21,7 +23,7 @@ use polkadot_sdk::{staging_parachain_info as parachain_info, *};
 
 use sp_runtime::{
 	generic, impl_opaque_keys,
-	traits::{BlakeTwo256, IdentifyAccount, Verify},
+	traits::{BlakeTwo256, Convert, IdentifyAccount, Verify},
 	MultiSignature,
 };
 
@@ -33,7 +35,9 @@ use frame_support::weights::{
 	constants::WEIGHT_REF_TIME_PER_SECOND, Weight, WeightToFeeCoefficient, WeightToFeeCoefficients,
 	WeightToFeePolynomial,
 };
-use frame_support::traits::{ConstU32, ConstU64, ConstU128, ConstPerbill, Get};
+use frame_support::{instances::Instance1, parameter_types};
+use frame_support::traits::{ConstBool, ConstU32, ConstU64, ConstU128, ConstPerbill, Contains, Everything, Get};
+use pallet_agent_registry::CallClass;
 pub use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 pub use sp_runtime::{MultiAddress, Perbill, Permill};
 
@@ -83,7 +87,12 @@ pub type TxExtension = (
 	frame_system::CheckEra<Runtime>,
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
-	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	pallet_skip_feeless_payment::SkipCheckIfFeeless<
+		Runtime,
+		pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	>,
+	pallet_reputation::ChargeFeelessQuota<Runtime>,
+	pallet_reputation::PrioritizeCommittee<Runtime>,
 	cumulus_primitives_storage_weight_reclaim::StorageWeightReclaim<Runtime>,
 	frame_metadata_hash_extension::CheckMetadataHash<Runtime>,
 );
@@ -92,11 +101,33 @@ pub type TxExtension = (
 pub type UncheckedExtrinsic =
 	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, TxExtension>;
 
-/// All migrations of the runtime, aside from the ones declared in the pallets.
+/// All single-block migrations of the runtime, aside from the ones declared in the pallets.
 ///
-/// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
+/// This can be a tuple of types, each implementing `OnRuntimeUpgrade`. Migrations that are too
+/// heavy for one block go into `pallet_migrations::Config::Migrations` instead.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+	pallet_agent_registry::migrations::v1::MigrateV0ToV1<Runtime>,
+	pallet_agent_registry::migrations::v2::MigrateV1ToV2<Runtime>,
+	pallet_reputation::migrations::v1::MigrateV0ToV1<Runtime>,
+	pallet_reputation::migrations::v2::MigrateV1ToV2<Runtime>,
+	pallet_reputation::migrations::v3::MigrateV2ToV3<Runtime>,
+	pallet_reputation::migrations::v4::MigrateV3ToV4<Runtime>,
+	pallet_reputation::migrations::v5::MigrateV4ToV5<Runtime>,
+	pallet_reputation::migrations::v6::MigrateV5ToV6<Runtime>,
+	pallet_recall::migrations::v1::MigrateV0ToV1<Runtime>,
+	pallet_recall::migrations::v2::MigrateV1ToV2<Runtime>,
+	pallet_consensus_log::migrations::v1::MigrateV0ToV1<Runtime>,
+	pallet_consensus_log::migrations::v2::MigrateV1ToV2<Runtime>,
+	pallet_consensus_log::migrations::v3::MigrateV2ToV3<Runtime>,
+	pallet_consensus_log::migrations::v4::MigrateV3ToV4<Runtime>,
+	pallet_consensus_log::migrations::v5::MigrateV4ToV5<Runtime>,
+	pallet_consensus_log::migrations::v6::MigrateV5ToV6<Runtime>,
+	pallet_consensus_log::migrations::v7::MigrateV6ToV7<Runtime>,
+	pallet_consensus_log::migrations::v8::MigrateV7ToV8<Runtime>,
+	pallet_consensus_log::migrations::v9::MigrateV8ToV9<Runtime>,
+	pallet_consensus_log::migrations::v10::MigrateV9ToV10<Runtime>,
+);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -272,12 +303,16 @@ mod runtime {
 	pub type Timestamp = pallet_timestamp;
 	#[runtime::pallet_index(3)]
 	pub type ParachainInfo = parachain_info;
+	#[runtime::pallet_index(5)]
+	pub type MultiBlockMigrations = pallet_migrations;
 
 	// Monetary stuff.
 	#[runtime::pallet_index(10)]
 	pub type Balances = pallet_balances;
 	#[runtime::pallet_index(11)]
 	pub type TransactionPayment = pallet_transaction_payment;
+	#[runtime::pallet_index(12)]
+	pub type SkipFeelessPayment = pallet_skip_feeless_payment;
 
 	// Governance
 	#[runtime::pallet_index(15)]
@@ -316,6 +351,24 @@ mod runtime {
 	pub type ConsensusLog = pallet_consensus_log;
 	#[runtime::pallet_index(53)]
 	pub type Reputation = pallet_reputation;
+	#[runtime::pallet_index(54)]
+	pub type Orgs = pallet_orgs;
+	#[runtime::pallet_index(55)]
+	pub type Recall = pallet_recall;
+
+	// Governance
+	#[runtime::pallet_index(56)]
+	pub type Council = pallet_collective::Pallet<Runtime, Instance1>;
+	#[runtime::pallet_index(57)]
+	pub type Activity = pallet_activity;
+	#[runtime::pallet_index(58)]
+	pub type Tasks = pallet_tasks;
+	#[runtime::pallet_index(59)]
+	pub type GatewayRegistry = pallet_gateway_registry;
+	#[runtime::pallet_index(60)]
+	pub type AgentMail = pallet_agent_mail;
+	#[runtime::pallet_index(61)]
+	pub type AgentWebhooks = pallet_agent_webhooks;
 }
 
 #[docify::export(register_validate_block)]
@@ -324,10 +377,100 @@ cumulus_pallet_parachain_system::register_validate_block! {
 	BlockExecutor = cumulus_pallet_aura_ext::BlockExecutor::<Runtime, Executive>,
 }
 
+parameter_types! {
+	/// CPU load or memory usage at which agents lose priority in committee selection
+	pub const AgentOverloadThreshold: sp_runtime::Percent = sp_runtime::Percent::from_percent(90);
+	/// Features enabled for every agent until governance restricts them. FROST signing and
+	/// priority submissions were live before they were gated, so they stay on.
+	pub AgentDefaultFeatures: pallet_agent_registry::FeatureFlags = pallet_agent_registry::FeatureFlags::all();
+}
+
 impl pallet_agent_registry::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type MaxRoleLength = ConstU32<64>;
 	type MaxMetadataLength = ConstU32<2048>;
+	type MaxMetadataAllowance = ConstU32<16_384>;
+	type MetadataTierBytes = ConstU32<1024>;
+	type MetadataTierDeposit = ConstU128<UNIT>;
+	type Currency = Balances;
+	type FeeExemption = Reputation;
+	type RetirementGracePeriod = ConstU32<{ 28 * DAYS }>;
+	type OnRetirement = AgentRetirement;
+	type OnRotation = AgentRotation;
+	type OnStatusChange = StatusRecords;
+	type MaxEndpoints = ConstU32<8>;
+	type MaxEndpointLength = ConstU32<256>;
+	type EndpointTtl = ConstU32<{ DAYS }>;
+	type AnnouncementCooldown = ConstU32<{ 10 * MINUTES }>;
+	type StaleAfter = ConstU32<{ 7 * DAYS }>;
+	type MaxSweepPerBlock = ConstU32<64>;
+	type MaxProxies = ConstU32<8>;
+	type MaxCapabilities = ConstU32<16>;
+	type DefaultFeatures = AgentDefaultFeatures;
+	type MaxTelemetrySnapshots = ConstU32<16>;
+	type TelemetryInterval = ConstU32<{ 10 * MINUTES }>;
+	type TelemetryTtl = ConstU32<{ HOURS }>;
+	type OverloadThreshold = AgentOverloadThreshold;
+	type OverloadQueueDepth = ConstU32<1_000>;
+	type MaxMaintenanceWindows = ConstU32<8>;
+	type MaxMaintenanceDuration = ConstU32<{ DAYS }>;
+	type RemoteOrigin = configs::ReputationAttestationOrigin;
+	type MaxRemoteProofSize = ConstU32<16_384>;
+	type WeightInfo = pallet_agent_registry::weights::SubstrateWeight<Runtime>;
+}
+
+/// Writes a recall system record for every agent status change
+pub struct StatusRecords;
+impl pallet_agent_registry::OnStatusChange<AccountId> for StatusRecords {
+	fn on_status_changed(
+		agent_id: &AccountId,
+		old: &pallet_agent_registry::AgentStatus,
+		new: &pallet_agent_registry::AgentStatus,
+	) {
+		let record = pallet_recall::SystemRecord::StatusChanged { from: old.clone() as u8, to: new.clone() as u8 };
+		let _ = Recall::note_system_record(agent_id, record);
+	}
+
+	fn on_status_changed_weight() -> Weight {
+		<Runtime as frame_system::Config>::DbWeight::get().reads_writes(1, 3)
+	}
+}
+
+/// Unbonds a retiring agent's stake, then frees its deposits and compacts its indexes
+pub struct AgentRetirement;
+impl pallet_agent_registry::OnRetirement<AccountId> for AgentRetirement {
+	fn on_retirement_initiated(agent_id: &AccountId) {
+		<Reputation as pallet_agent_registry::OnRetirement<AccountId>>::on_retirement_initiated(agent_id);
+	}
+
+	fn on_retirement_completed(agent_id: &AccountId) {
+		<Reputation as pallet_agent_registry::OnRetirement<AccountId>>::on_retirement_completed(agent_id);
+		let _ = Recall::release_agent_deposits(agent_id);
+		ConsensusLog::clear_recent_submissions(agent_id);
+	}
+
+	fn on_retirement_initiated_weight() -> Weight {
+		<Reputation as pallet_agent_registry::OnRetirement<AccountId>>::on_retirement_initiated_weight()
+	}
+
+	fn on_retirement_completed_weight() -> Weight {
+		// An agent indexes at most 1,000 recall records
+		<Reputation as pallet_agent_registry::OnRetirement<AccountId>>::on_retirement_completed_weight()
+			.saturating_add(<Runtime as frame_system::Config>::DbWeight::get().reads_writes(1_001, 1_002))
+	}
+}
+
+/// Moves a rotating agent's reputation, then the stake its open recall disputes escrow
+pub struct AgentRotation;
+impl pallet_agent_registry::OnAgentRotation<AccountId> for AgentRotation {
+	fn on_agent_rotated(old: &AccountId, new: &AccountId) -> sp_runtime::DispatchResult {
+		<Reputation as pallet_agent_registry::OnAgentRotation<AccountId>>::on_agent_rotated(old, new)?;
+		Recall::on_agent_rotated(old, new)
+	}
+
+	fn on_agent_rotated_weight() -> Weight {
+		<Reputation as pallet_agent_registry::OnAgentRotation<AccountId>>::on_agent_rotated_weight()
+			.saturating_add(Recall::on_agent_rotated_weight())
+	}
 }
 
 // Create a custom type that implements Eq and Clone for MaxSignatureLength
@@ -339,6 +482,23 @@ impl Get<u32> for MaxSigLen {
 	}
 }
 
+/// Signed origin of agents with an effective reputation of at least 1,000.
+///
+/// Any `EnsureOrigin` slot can be restricted to reputable agents with this, e.g.
+/// `type SubmitOrigin = EnsureReputableAgent;` on the consensus log pallet.
+pub type EnsureReputableAgent = pallet_reputation::EnsureReputationAtLeast<Runtime, ConstU64<1_000>>;
+
+parameter_types! {
+	/// Capability governance grants to agents that may feed external data into the chain
+	pub const OracleCapability: pallet_agent_registry::Capability = *b"oracle\0\0";
+}
+
+/// Signed origin of agents granted the [`OracleCapability`].
+///
+/// Restricts any `EnsureOrigin` slot, e.g. the submission origin of an oracle pallet, to agents
+/// governance trusts with external data.
+pub type EnsureOracleAgent = pallet_agent_registry::EnsureAgentWithCapability<Runtime, OracleCapability>;
+
 impl pallet_consensus_log::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxCIDLength = ConstU32<128>;
@@ -346,18 +506,558 @@ impl pallet_consensus_log::Config for Runtime {
 	type MaxAgentsInvolved = ConstU32<64>;
 	type MaxSignatureLength = MaxSigLen;
 	type MaxSignatures = ConstU32<64>;
+	type Agents = AgentRegistry;
+	type SignatureVerifier = pallet_consensus_log::MultiSchemeVerifier;
+	type FeeExemption = Reputation;
+	type Orgs = Orgs;
+	type RequireSameOrg = ConstBool<false>;
+	type SubmitOrigin = frame_system::EnsureSigned<AccountId>;
+	type CommitmentPeriod = ConstU32<{ 5 * MINUTES }>;
+	type SharePeriod = ConstU32<{ 5 * MINUTES }>;
+	type SessionRetention = ConstU32<{ DAYS }>;
+	type SessionDeposit = ConstU128<{ 10 * MILLI_UNIT }>;
+	type MaxSessionsPerBlock = ConstU32<16>;
+	type SigningFaultReporter = MissedSharePenalty;
+	type ParticipationFilter = UnrestrictedAgents;
+	type OnLogActivity = ActivityFeed;
+	type DedupWindow = ConstU32<{ HOURS }>;
+	type MaxRecentSubmissions = ConstU32<32>;
+	type MaxAmendments = ConstU32<8>;
+	type AllowedModels = Everything;
+	type SignerWeight = ReputationSignerWeight;
+	type FirstResponders = ConstU32<3>;
+	type OnFirstResponder = FirstResponderBoost;
+	type PauseOrigin = frame_system::EnsureRoot<AccountId>;
+	type MaxBatchSignatures = ConstU32<32>;
+	type Currency = Balances;
+	type LogDeposit = ConstU128<{ 10 * MILLI_UNIT }>;
+	type RetractionFee = ConstPerbill<10_000_000>; // 1%
+	type CidIndexOverflow = ConsensusCidIndexOverflow;
+	type UrgentReputation = ConstU64<1_000>;
+	type MaxRequiredRoles = ConstU32<8>;
+	type MaxAnnotations = ConstU32<16>;
+	type MaxAnnotationsPerAuthor = ConstU32<4>;
+	type RequiredSignerPeriod = ConstU32<{ HOURS }>;
+	type MaxRequiredSignerChecksPerBlock = ConstU32<16>;
+	type MaxFinalizationsPerBlock = ConstU32<256>;
+	type VetoThreshold = ConstPerbill<334_000_000>; // a third of the involved agents
+	type MaxPendingDuties = ConstU32<256>;
+	type MaxMetadataBlobLength = ConstU32<{ 64 * 1024 }>;
+	type MetadataBlobDepositBase = ConstU128<{ 10 * MILLI_UNIT }>;
+	type MetadataBlobDepositPerByte = ConstU128<{ 10 * MICRO_UNIT }>;
+	type InsightCommitPeriod = ConstU32<{ 5 * MINUTES }>;
+	type InsightRevealPeriod = ConstU32<{ 5 * MINUTES }>;
+	type MaxSequenceGaps = ConstU32<32>;
+	type MaxFrostParticipants = ConstU32<16>;
+	type WeightInfo = pallet_consensus_log::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const ConsensusCidIndexOverflow: pallet_consensus_log::CidIndexOverflow =
+		pallet_consensus_log::CidIndexOverflow::EvictOldest;
 }
 
 impl pallet_reputation::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
+	type Agents = AgentRegistry;
+	type Maintenance = AgentRegistry;
+	type ConsensusParameters = ConsensusLog;
 	type Currency = Balances;
 	type Slash = ();
 	type MinimumStake = ConstU128<{10 * UNIT}>;
+	type MaxStakePerAgent = ConstU128<{100_000 * UNIT}>;
+	type StakeCurve = ReputationStakeCurve;
 	type BaseDecayRate = ConstPerbill<10_000>; // 0.001% per block
 	type ConsensusReward = ConstU64<100>;
+	type FirstResponderBonus = ConstU64<10>;
 	type UnresponsivenessSlash = ConstPerbill<50_000_000>; // 5%
 	type EquivocationSlash = ConstPerbill<250_000_000>; // 25%
 	type QuarantinePeriod = ConstU32<{7 * DAYS}>;
 	type MaxOffenses = ConstU32<5>;
+	type MaxTemporaryBans = ConstU32<2>;
+	type TemporaryBanEras = ConstU32<7>;
+	type SlashDeferDuration = ConstU32<{ DAYS }>;
+	type MaxSlashesPerBlock = ConstU32<8>;
+	type MaxPendingSlashesPerAgent = ConstU32<16>;
+	type OffenseWindow = ConstU32<{30 * DAYS}>;
+	type MaxOffenseHistory = ConstU32<100>;
+	type MaxRotatedArchiveEras = ConstU32<365>;
+	type MaxPenaltyDoublings = ConstU32<3>;
+	type MaxSlashPercentage = ConstPerbill<500_000_000>; // 50%
+	type EraLength = ConstU32<DAYS>;
+	type FeelessReputationThreshold = ConstU64<1_000>;
+	type FeelessCallsPerEra = ConstU32<500>;
+	type FeelessCalls = AgentFeelessCalls;
+	type OnReputationChange = RecallTrustRefresh;
+	type OnRewardOrSlash = ActivityFeed;
+	type MaxCommitteeSize = ConstU32<13>;
+	type MaxCandidatesPerBlock = ConstU32<256>;
+	type EraReward = ConstU128<UNIT>;
+	type RewardPotId = ReputationRewardPotId;
+	type AttestationOrigin = configs::ReputationAttestationOrigin;
+	type Exporter = configs::XcmReputationExporter;
+	type EarlyExitPenalty = ConstPerbill<100_000_000>; // 10%
+	type MaxEarlyExitsPerEra = ConstU32<1>;
+	type StakeLockPeriod = ConstU32<{ 30 * DAYS }>;
+	type EarlyUnlockPenalty = ConstPerbill<200_000_000>; // 20%
+	type MinStreakParticipations = ConstU32<10>;
+	type StreakBonusPerEra = ConstPerbill<10_000_000>; // 1%
+	type MaxStreakBonus = ConstPerbill<200_000_000>; // 20%
+	type ParticipationHistoryDepth = ConstU32<12>;
+	type PriorityCalls = CommitteePriorityCalls;
+	type IsCongested = FeeMultiplierRaised;
+	type PriorityReputationThreshold = ConstU64<1_000>;
+	type CommitteePriorityBoost = ConstU64<{ u64::MAX / 4 }>;
+	type RewardOrigin = configs::ReputationRewardOrigin;
+	type MaxRewardBatch = ConstU32<64>;
 	type WeightInfo = pallet_reputation::weights::SubstrateWeight<Runtime>;
 }
+
+/// Calls whose fees `pallet_reputation` may waive, with the class of proxy key that may make them
+pub struct AgentFeelessCalls;
+impl Convert<RuntimeCall, Option<CallClass>> for AgentFeelessCalls {
+	fn convert(call: RuntimeCall) -> Option<CallClass> {
+		match call {
+			RuntimeCall::AgentRegistry(pallet_agent_registry::Call::heartbeat {}) => Some(CallClass::Heartbeat),
+			RuntimeCall::ConsensusLog(pallet_consensus_log::Call::sign_log { .. }) => Some(CallClass::Signing),
+			_ => None,
+		}
+	}
+}
+
+/// Consensus calls whose priority `PrioritizeCommittee` raises for the committee
+pub struct CommitteePriorityCalls;
+impl Contains<RuntimeCall> for CommitteePriorityCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(
+			call,
+			RuntimeCall::ConsensusLog(
+				pallet_consensus_log::Call::submit_consensus_log { .. } |
+					pallet_consensus_log::Call::submit_insight { .. } |
+					pallet_consensus_log::Call::sign_log { .. } |
+					pallet_consensus_log::Call::sign_logs_batch { .. } |
+					pallet_consensus_log::Call::submit_commitment { .. } |
+					pallet_consensus_log::Call::submit_signature_share { .. } |
+					pallet_consensus_log::Call::commit_insight { .. } |
+					pallet_consensus_log::Call::reveal_insight { .. }
+			)
+		)
+	}
+}
+
+/// Treats the chain as congested while blocks have been fuller than the fee adjustment's target,
+/// which raises the fee multiplier above one
+pub struct FeeMultiplierRaised;
+impl Get<bool> for FeeMultiplierRaised {
+	fn get() -> bool {
+		pallet_transaction_payment::NextFeeMultiplier::<Runtime>::get() >
+			pallet_transaction_payment::Multiplier::from_u32(1)
+	}
+}
+
+impl pallet_orgs::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Agents = AgentRegistry;
+	type MaxOrgNameLength = ConstU32<64>;
+	type MaxMembersPerOrg = ConstU32<32>;
+	type Currency = Balances;
+	type OrgDeposit = ConstU128<UNIT>;
+	type WeightInfo = pallet_orgs::weights::SubstrateWeight<Runtime>;
+}
+
+/// Feeds signer reputation from the reputation pallet into recall trust scores
+pub struct ReputationSignerWeights;
+impl pallet_recall::SignerWeightProvider<AccountId> for ReputationSignerWeights {
+	fn effective_reputation(who: &AccountId) -> u64 {
+		Reputation::effective_reputation(who)
+	}
+
+	fn stake_weight(who: &AccountId) -> Perbill {
+		Reputation::stake_weight(who)
+	}
+}
+
+/// Moves the stake of disputed recall records' signers through the reputation pallet
+pub struct ReputationStakeEscrow;
+impl pallet_recall::StakeEscrow<AccountId, Balance> for ReputationStakeEscrow {
+	fn escrow_stake(agent_id: &AccountId, escrow: &AccountId, portion: Perbill) -> Balance {
+		Reputation::escrow_stake(agent_id, escrow, portion)
+	}
+
+	fn restore_stake(agent_id: &AccountId, amount: Balance) {
+		Reputation::restore_stake(agent_id, amount)
+	}
+}
+
+/// Queues an agent's recall records for re-scoring whenever its reputation changes
+pub struct RecallTrustRefresh;
+impl pallet_reputation::OnReputationChange<AccountId> for RecallTrustRefresh {
+	fn on_reputation_change(who: &AccountId) {
+		Recall::note_reputation_change(who);
+	}
+}
+
+/// Samples recall audits with the BABE randomness of the relay chain
+///
+/// The randomness of the relay parent is read from the relay chain state proof when the
+/// validation data is set and mixed with the subject on use, so audits opened in the next block
+/// draw from it. Collators cannot bias it the way they can randomness collected on the
+/// parachain. The relay parent's VRF output is used where the proof carries it, and the
+/// randomness of the previous epoch otherwise.
+pub struct RelayChainRandomness;
+
+/// Latest relay chain randomness, with the parachain block it was read in
+#[frame_support::storage_alias(verbatim)]
+type LatestRelayRandomness = StorageValue<RelayChainRandomness, ([u8; 32], BlockNumber)>;
+
+impl cumulus_pallet_parachain_system::OnSystemEvent for RelayChainRandomness {
+	fn on_validation_data(data: &cumulus_primitives_core::PersistedValidationData) {
+		use cumulus_primitives_core::relay_chain::well_known_keys;
+
+		let Some(proof) = cumulus_pallet_parachain_system::RelayStateProof::<Runtime>::get() else {
+			return
+		};
+		let Ok(proof) = cumulus_pallet_parachain_system::RelayChainStateProof::new(
+			<Runtime as cumulus_pallet_parachain_system::Config>::SelfParaId::get(),
+			data.relay_parent_storage_root,
+			proof,
+		) else {
+			return
+		};
+
+		let randomness = proof
+			.read_optional_entry::<Option<[u8; 32]>>(well_known_keys::CURRENT_BLOCK_RANDOMNESS)
+			.ok()
+			.flatten()
+			.flatten()
+			.or_else(|| {
+				proof.read_optional_entry::<[u8; 32]>(well_known_keys::ONE_EPOCH_AGO_RANDOMNESS).ok().flatten()
+			});
+		if let Some(randomness) = randomness {
+			LatestRelayRandomness::put((randomness, System::block_number()));
+		}
+	}
+
+	fn on_validation_code_applied() {}
+}
+
+impl frame_support::traits::Randomness<Hash, BlockNumber> for RelayChainRandomness {
+	fn random(subject: &[u8]) -> (Hash, BlockNumber) {
+		use sp_runtime::traits::Hash as _;
+
+		let (randomness, known_since) = LatestRelayRandomness::get().unwrap_or_default();
+		(BlakeTwo256::hash_of(&(subject, randomness)), known_since)
+	}
+}
+
+/// Assigns recall audits to the reputation committee of the current era
+pub struct ReputationAuditCommittee;
+impl pallet_recall::AuditCommittee<AccountId> for ReputationAuditCommittee {
+	fn members() -> Vec<AccountId> {
+		Reputation::committee().into_inner()
+	}
+}
+
+impl pallet_tasks::TaskCommittee<AccountId> for ReputationAuditCommittee {
+	fn members() -> Vec<AccountId> {
+		Reputation::committee().into_inner()
+	}
+}
+
+/// Checks task completion evidence against recall records and finalized consensus logs
+pub struct TaskCompletionEvidence;
+impl pallet_tasks::TaskEvidence<AccountId, Hash, BlockNumber> for TaskCompletionEvidence {
+	fn record_signed_by(record_id: u64, agent_id: &AccountId, since: BlockNumber) -> bool {
+		Recall::records(record_id).map_or(false, |record| {
+			record.created_at >= since && record.signatures.iter().any(|s| &s.agent_id == agent_id)
+		})
+	}
+
+	fn log_finalized_with(log_id: &Hash, agent_id: &AccountId, since: BlockNumber) -> bool {
+		ConsensusLog::finalized_logs(log_id).is_some() &&
+			ConsensusLog::logs(log_id).map_or(false, |log| {
+				log.timestamp >= since && log.agents_involved.contains(agent_id)
+			})
+	}
+}
+
+/// Rewards agents that complete tasks and treats failed tasks as unresponsiveness
+pub struct TaskReputation;
+impl pallet_tasks::TaskOutcome<AccountId> for TaskReputation {
+	fn on_task_completed(agent_id: &AccountId) -> Weight {
+		use pallet_reputation::WeightInfo;
+
+		let _ = Reputation::do_reward_consensus(agent_id);
+		<Runtime as pallet_reputation::Config>::WeightInfo::reward_consensus()
+	}
+
+	fn on_task_failed(agent_id: &AccountId) -> Weight {
+		use pallet_reputation::WeightInfo;
+
+		let _ = Reputation::do_report_offense(agent_id, pallet_reputation::OffenseType::Unresponsiveness);
+		<Runtime as pallet_reputation::Config>::WeightInfo::report_offense()
+	}
+}
+
+/// Treats a missed recall audit as unresponsiveness
+pub struct MissedAuditPenalty;
+impl pallet_recall::OnAuditMissed<AccountId> for MissedAuditPenalty {
+	fn on_audit_missed(auditor: &AccountId) -> Weight {
+		use pallet_reputation::WeightInfo;
+
+		let _ = Reputation::do_report_offense(auditor, pallet_reputation::OffenseType::Unresponsiveness);
+		<Runtime as pallet_reputation::Config>::WeightInfo::report_offense()
+	}
+}
+
+/// Treats an unanswered recall signature request as unresponsiveness
+pub struct MissedSignatureRequestPenalty;
+impl pallet_recall::OnSignatureRequestMissed<AccountId> for MissedSignatureRequestPenalty {
+	fn on_signature_request_missed(agent_id: &AccountId, _record_id: u64) -> Weight {
+		use pallet_reputation::WeightInfo;
+
+		let _ = Reputation::do_report_offense(agent_id, pallet_reputation::OffenseType::Unresponsiveness);
+		<Runtime as pallet_reputation::Config>::WeightInfo::report_offense()
+	}
+}
+
+/// Records consensus and reputation activity in the per-agent activity feed, and slashes in
+/// the recall audit log
+pub struct ActivityFeed;
+impl pallet_consensus_log::OnLogActivity<AccountId, Hash> for ActivityFeed {
+	fn on_log_submitted(agent_id: &AccountId, log_id: &Hash) {
+		Activity::record(agent_id, pallet_activity::ActivityKind::LogSubmitted { log_id: *log_id });
+		Reputation::note_activity(agent_id, pallet_reputation::ParticipationKind::Submission);
+	}
+
+	fn on_log_signed(agent_id: &AccountId, log_id: &Hash) {
+		Activity::record(agent_id, pallet_activity::ActivityKind::LogSigned { log_id: *log_id });
+		Reputation::note_activity(agent_id, pallet_reputation::ParticipationKind::Signature);
+	}
+
+	fn on_log_finalized(signers: &[AccountId], _log_id: &Hash) {
+		for signer in signers {
+			Reputation::note_activity(signer, pallet_reputation::ParticipationKind::Finalization);
+		}
+		Reputation::note_log_finalized(signers.len() as u32);
+	}
+
+	fn on_log_retracted(_agent_id: &AccountId, _log_id: &Hash) {
+		Reputation::note_log_rejected();
+	}
+}
+
+impl pallet_reputation::OnRewardOrSlash<AccountId> for ActivityFeed {
+	fn on_rewarded(who: &AccountId, reputation_reward: u64) {
+		Activity::record(who, pallet_activity::ActivityKind::Rewarded { reputation_reward });
+	}
+
+	fn on_slashed(who: &AccountId, reputation_penalty: u64) {
+		Activity::record(who, pallet_activity::ActivityKind::Slashed { reputation_penalty });
+		let _ = Recall::note_system_record(who, pallet_recall::SystemRecord::Slashed { reputation_penalty });
+	}
+}
+
+impl pallet_activity::Config for Runtime {
+	type MaxEntriesPerAgent = ConstU32<50>;
+}
+
+/// Keeps banned and quarantined agents from finalizing consensus logs
+pub struct UnrestrictedAgents;
+impl pallet_consensus_log::ParticipationFilter<AccountId> for UnrestrictedAgents {
+	fn can_participate(agent_id: &AccountId) -> bool {
+		!Reputation::is_restricted(agent_id)
+	}
+}
+
+/// Weighs signers of reputation-weighted consensus logs by their effective reputation
+pub struct ReputationSignerWeight;
+impl pallet_consensus_log::SignerWeight<AccountId> for ReputationSignerWeight {
+	fn signer_weight(agent_id: &AccountId) -> u64 {
+		Reputation::effective_reputation(agent_id)
+	}
+}
+
+/// Treats committing to a FROST signing session without delivering a share, and not signing an
+/// insight as a required co-signer in time, as unresponsiveness. In blinded insight rounds, not
+/// revealing a committed insight counts as withholding it, and revealing another one as
+/// manipulating consensus.
+pub struct MissedSharePenalty;
+impl pallet_consensus_log::SigningFaultReporter<AccountId> for MissedSharePenalty {
+	fn report_missing_share(agent_id: &AccountId) -> Weight {
+		Self::report(agent_id, pallet_reputation::OffenseType::Unresponsiveness)
+	}
+
+	fn report_missed_reveal(agent_id: &AccountId) -> Weight {
+		Self::report(agent_id, pallet_reputation::OffenseType::DataWithholding)
+	}
+
+	fn report_mismatched_reveal(agent_id: &AccountId) -> Weight {
+		Self::report(agent_id, pallet_reputation::OffenseType::ConsensusManipulation)
+	}
+}
+
+impl MissedSharePenalty {
+	fn report(agent_id: &AccountId, offense: pallet_reputation::OffenseType) -> Weight {
+		use pallet_reputation::WeightInfo;
+
+		let _ = Reputation::do_report_offense(agent_id, offense);
+		<Runtime as pallet_reputation::Config>::WeightInfo::report_offense()
+	}
+}
+
+/// Pays the reputation first responder bonus to the earliest co-signers of a finalized log,
+/// scaled by the log's priority
+pub struct FirstResponderBoost;
+impl pallet_consensus_log::OnFirstResponder<AccountId> for FirstResponderBoost {
+	fn on_first_responder(
+		agent_id: &AccountId,
+		_position: u32,
+		priority: pallet_consensus_log::LogPriority,
+	) -> Weight {
+		let _ = Reputation::do_reward_first_responder(agent_id, priority.reward_percent());
+		Self::on_first_responder_weight()
+	}
+
+	fn on_first_responder_weight() -> Weight {
+		use pallet_reputation::WeightInfo;
+
+		<Runtime as pallet_reputation::Config>::WeightInfo::reward_consensus()
+	}
+}
+
+parameter_types! {
+	pub const ReputationStakeCurve: pallet_reputation::StakeWeightingCurve = pallet_reputation::StakeWeightingCurve::SquareRoot;
+	pub const ReputationRewardPotId: frame_support::PalletId = frame_support::PalletId(*b"csrwdpot");
+}
+
+parameter_types! {
+	pub const RecallTrustCurve: pallet_recall::WeightingCurve = pallet_recall::WeightingCurve::SquareRoot;
+	pub const RecallPalletId: frame_support::PalletId = frame_support::PalletId(*b"csrecall");
+}
+
+impl pallet_recall::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type MaxContentHashLength = ConstU32<64>;
+	type MaxLocatorLength = ConstU32<128>;
+	type MaxSummaryLength = ConstU32<512>;
+	type MaxMetadataLength = ConstU32<2048>;
+	type MaxSignatures = ConstU32<64>;
+	type SignerWeights = ReputationSignerWeights;
+	type TrustCurve = RecallTrustCurve;
+	type BaseTrustScore = ConstU64<100>;
+	type MaxSignerContribution = ConstU64<1_000>;
+	type MaxRecomputePerAgent = ConstU32<16>;
+	type Randomness = RelayChainRandomness;
+	type AuditCommittee = ReputationAuditCommittee;
+	type OnAuditMissed = MissedAuditPenalty;
+	type AuditInterval = ConstU32<HOURS>;
+	type AuditSampleSize = ConstU32<4>;
+	type AuditorsPerAudit = ConstU32<3>;
+	type AuditDeadline = ConstU32<{ 30 * MINUTES }>;
+	type Currency = Balances;
+	type EphemeralDeposit = ConstU128<{ MILLI_UNIT }>;
+	type StandardDeposit = ConstU128<{ 10 * MILLI_UNIT }>;
+	type PermanentDeposit = ConstU128<{ UNIT }>;
+	type EphemeralRetention = ConstU32<{ 7 * DAYS }>;
+	type MaxPrunesPerBlock = ConstU32<64>;
+	type PauseOrigin = frame_system::EnsureRoot<AccountId>;
+	type SystemRecords = ConstBool<true>;
+	type IndexSummaries = ConstBool<true>;
+	type MaxDecompressedLength = ConstU32<{ 64 * 1024 }>;
+	type BucketSize = ConstU32<HOURS>;
+	type MaxPendingRequests = ConstU32<32>;
+	type MaxRequestsPerDeadline = ConstU32<64>;
+	type MinRequestPeriod = ConstU32<{ 10 * MINUTES }>;
+	type MaxRequestPeriod = ConstU32<{ 7 * DAYS }>;
+	type SignatureRequestDeposit = ConstU128<{ 10 * MILLI_UNIT }>;
+	// Any record owner can open requests, so unanswered ones are not penalized yet
+	type ReportMissedRequests = ConstBool<false>;
+	type OnSignatureRequestMissed = MissedSignatureRequestPenalty;
+	type PalletId = RecallPalletId;
+	type DisputeOrigin = frame_system::EnsureRoot<AccountId>;
+	type ChallengeBond = ConstU128<{ UNIT }>;
+	type ChallengeBondRatio = ConstPerbill<1_000_000_000>; // 100%
+	type MaxDisputesPerSigner = ConstU32<4>;
+	type DisputePeriod = ConstU32<{ 7 * DAYS }>;
+	type MaxDisputesPerBlock = ConstU32<32>;
+	type DisputeEscrow = ConstPerbill<100_000_000>; // 10%
+	type StakeEscrow = ReputationStakeEscrow;
+	type DisputeSlash = ();
+}
+
+impl pallet_gateway_registry::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RegistryOrigin = frame_system::EnsureRoot<AccountId>;
+	type MaxGateways = ConstU32<64>;
+	type WeightInfo = pallet_gateway_registry::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_tasks::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Agents = AgentRegistry;
+	type Currency = Balances;
+	type Evidence = TaskCompletionEvidence;
+	type Committee = ReputationAuditCommittee;
+	type Outcome = TaskReputation;
+	type DisputeOrigin = frame_system::EnsureRoot<AccountId>;
+	type MaxDescriptionLength = ConstU32<128>;
+	type MaxTaskDuration = ConstU32<{ 7 * DAYS }>;
+	type MaxActiveTasksPerAgent = ConstU32<8>;
+	type MaxDeadlinesPerBlock = ConstU32<32>;
+	type MaxCommitteeCandidates = ConstU32<13>;
+	type WeightInfo = pallet_tasks::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_agent_mail::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Agents = AgentRegistry;
+	type Currency = Balances;
+	type MessageFee = ConstU128<{ MILLI_UNIT }>;
+	type OnFee = ();
+	type MaxTopicLength = ConstU32<32>;
+	type MaxPayloadLength = ConstU32<128>;
+	type MaxMessageLifetime = ConstU32<{ 7 * DAYS }>;
+	type MaxInboxSize = ConstU32<64>;
+	type MaxExpiriesPerBlock = ConstU32<64>;
+	type WeightInfo = pallet_agent_mail::weights::SubstrateWeight<Runtime>;
+}
+
+/// Alerts operators of slashed and banned agents, and of finalized logs their agents are
+/// involved in
+pub struct WebhookAlerts;
+impl pallet_agent_webhooks::AlertSource<Runtime> for WebhookAlerts {
+	fn alerts(event: &RuntimeEvent) -> Vec<pallet_agent_webhooks::AgentAlert<Runtime>> {
+		use pallet_agent_webhooks::{AgentAlert, AlertKind};
+
+		match event {
+			RuntimeEvent::Reputation(pallet_reputation::Event::AgentSlashed {
+				agent_id,
+				slash_amount,
+				reputation_penalty,
+				..
+			}) => alloc::vec![AgentAlert {
+				agent_id: agent_id.clone(),
+				kind: AlertKind::Slashed { slash_amount: *slash_amount, reputation_penalty: *reputation_penalty },
+			}],
+			RuntimeEvent::Reputation(pallet_reputation::Event::AgentBanned { agent_id, until }) =>
+				alloc::vec![AgentAlert { agent_id: agent_id.clone(), kind: AlertKind::Banned { until: *until } }],
+			RuntimeEvent::ConsensusLog(pallet_consensus_log::Event::LogFinalized { log_id, .. }) =>
+				ConsensusLog::logs(log_id)
+					.map(|log| {
+						log.agents_involved
+							.into_iter()
+							.map(|agent_id| AgentAlert { agent_id, kind: AlertKind::LogFinalized { log_id: *log_id } })
+							.collect()
+					})
+					.unwrap_or_default(),
+			_ => Vec::new(),
+		}
+	}
+}
+
+impl pallet_agent_webhooks::Config for Runtime {
+	type Alerts = WebhookAlerts;
+	type MaxDeliveriesPerBlock = ConstU32<16>;
+	type DeliveryTimeout = ConstU64<2_000>;
+}
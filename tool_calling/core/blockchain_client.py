@@ -40,10 +40,12 @@ class BlockchainClient:
         tx_data = {
             'record_type': 'SingleAgentInsight',
             'content_hash': insight.insight_hash,
-            'ipfs_cid': insight.ipfs_cid,
+            'locator': {'Ipfs': insight.ipfs_cid},
             'summary': insight.summary[:500],  # Truncate for on-chain storage
             'signature': insight.agent_signature,
-            'metadata': self._prepare_metadata(insight)
+            'metadata': self._prepare_metadata(insight),
+            'retention': 'Standard',
+            'compression': 'None'
         }
         
         # Simulate blockchain submission for now
@@ -179,10 +181,12 @@ class SubstrateBlockchainClient(BlockchainClient):
                 call_params={
                     'record_type': {'SingleAgentInsight': None},
                     'content_hash': bytes.fromhex(insight.insight_hash),
-                    'ipfs_cid': insight.ipfs_cid.encode(),
+                    'locator': {'Ipfs': insight.ipfs_cid.encode()},
                     'summary': insight.summary[:500].encode(),
                     'signature': bytes.fromhex(insight.agent_signature),
-                    'metadata': self._prepare_metadata(insight).encode()
+                    'metadata': self._prepare_metadata(insight).encode(),
+                    'retention': {'Standard': None},
+                    'compression': {'None': None}
                 }
             )
             
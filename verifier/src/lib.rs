@@ -0,0 +1,332 @@
+/*
+ * ----------------------------------------------------------------------------
+ *  File:        lib.rs
+ *  Project:     Celaya Solutions (C-Suite Blockchain)
+ *  Created by:  Celaya Solutions, 2025
+ *  Author:      Christopher Celaya <chris@celayasolutions.com>
+ *  Description: Verifier for C-Suite consensus certificates and record proofs
+ *  Version:     1.0.0
+ *  License:     BSL (SPDX id BUSL)
+ *  Last Update: (October 2026)
+ * ----------------------------------------------------------------------------
+ */
+
+//! # C-Suite Verifier
+//!
+//! Checks C-Suite consensus evidence without running the chain. Works off-chain with the
+//! `std` feature and inside other runtimes without it.
+//!
+//! - [`verify_aggregate`] checks a FROST aggregate signature against a [`SigningGroup`]
+//! - [`verify_quorum_certificate`] checks that enough members of a group signed off a log
+//! - [`verify_record_inclusion`] checks a storage proof of a recall record against a state
+//!   root and returns the encoded record
+//! - [`verify_signature_inclusion`] checks that an agent signed a finalized log against the
+//!   log's signature root and returns its signature
+//! - [`verify_reputation_inclusion`] checks an agent's reputation against the reputation root
+//!   of an era
+//!
+//! Aggregate signatures are checked with the same rules the consensus log pallet applies,
+//! shared through `csuite-primitives`: an aggregate must verify over the log ID as an Ed25519
+//! signature by the group key.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use csuite_primitives::{
+    AggregateCheckError, AggregateSignature, QuorumCertificate, RecordInclusionProof,
+    MerkleInclusionProof, SigningGroup,
+};
+
+use codec::{Decode, Encode};
+use sp_core::{
+    hashing::{blake2_128, twox_128},
+    H256,
+};
+use sp_runtime::traits::BlakeTwo256;
+use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
+use sp_trie::{LayoutV1, StorageProof};
+
+/// Name of the storage item holding recall records
+const RECORDS_STORAGE_NAME: &[u8] = b"Records";
+
+/// Reasons consensus evidence fails to verify
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VerifyError {
+    /// The aggregate signature does not check out against the group
+    Aggregate(AggregateCheckError),
+    /// The certificate's signers differ from those selected by its aggregate signature
+    SignersMismatch,
+    /// A signer appears more than once
+    DuplicateSigner,
+    /// A signer is not a member of the group
+    UnknownSigner,
+    /// Fewer signers than the group threshold
+    BelowThreshold,
+    /// The proof does not resolve against the state root
+    InvalidProof,
+    /// The proof shows that the record is not stored
+    RecordNotIncluded,
+    /// The proven leaf commits to a different agent
+    SignerMismatch,
+}
+
+impl From<AggregateCheckError> for VerifyError {
+    fn from(error: AggregateCheckError) -> Self {
+        VerifyError::Aggregate(error)
+    }
+}
+
+/// Verify an aggregate signature over `message` by `group`, returning the signers' keys
+pub fn verify_aggregate(
+    message: &[u8],
+    aggregate: &AggregateSignature,
+    group: &SigningGroup,
+) -> Result<Vec<[u8; 32]>, VerifyError> {
+    let signers = csuite_primitives::check_group_aggregate(
+        aggregate,
+        message,
+        &group.group_key,
+        &group.participants,
+        group.threshold,
+    )?;
+    Ok(signers.into_iter().map(|i| group.participants[i]).collect())
+}
+
+/// Verify that a quorum of `group` signed off the log in `certificate`
+///
+/// With an aggregate signature, the certificate's signers must be exactly the participants it
+/// selects. Without one, the signers must be distinct group members reaching the threshold.
+pub fn verify_quorum_certificate(
+    certificate: &QuorumCertificate,
+    group: &SigningGroup,
+) -> Result<(), VerifyError> {
+    if let Some(aggregate) = &certificate.aggregate {
+        let signers = verify_aggregate(&certificate.log_id, aggregate, group)?;
+        if signers != certificate.signers {
+            return Err(VerifyError::SignersMismatch);
+        }
+        return Ok(());
+    }
+
+    let mut seen = BTreeSet::new();
+    for signer in certificate.signers.iter() {
+        if !seen.insert(signer) {
+            return Err(VerifyError::DuplicateSigner);
+        }
+        if !group.participants.contains(signer) {
+            return Err(VerifyError::UnknownSigner);
+        }
+    }
+    if (seen.len() as u32) < group.threshold {
+        return Err(VerifyError::BelowThreshold);
+    }
+
+    Ok(())
+}
+
+/// Storage key of a recall record in the pallet named `pallet_name` in the runtime
+pub fn record_storage_key(pallet_name: &[u8], record_id: u64) -> Vec<u8> {
+    let encoded_id = record_id.encode();
+    let mut key = Vec::with_capacity(32 + 16 + encoded_id.len());
+    key.extend_from_slice(&twox_128(pallet_name));
+    key.extend_from_slice(&twox_128(RECORDS_STORAGE_NAME));
+    key.extend_from_slice(&blake2_128(&encoded_id));
+    key.extend_from_slice(&encoded_id);
+    key
+}
+
+/// Verify that a recall record is stored under `state_root`, returning its SCALE encoding
+///
+/// `pallet_name` is the name the recall pallet has in the runtime, e.g. `b"Recall"`.
+pub fn verify_record_inclusion(
+    state_root: &[u8; 32],
+    pallet_name: &[u8],
+    proof: &RecordInclusionProof,
+) -> Result<Vec<u8>, VerifyError> {
+    let db = StorageProof::new(proof.trie_nodes.iter().cloned()).into_memory_db::<BlakeTwo256>();
+    let key = record_storage_key(pallet_name, proof.record_id);
+
+    sp_trie::read_trie_value::<LayoutV1<BlakeTwo256>, _>(&db, &H256::from(*state_root), &key, None, None)
+        .map_err(|_| VerifyError::InvalidProof)?
+        .ok_or(VerifyError::RecordNotIncluded)
+}
+
+/// Verify that `signer` signed a finalized log with signature root `signature_root`,
+/// returning its individual signature
+///
+/// The signature is empty if the signer only took part in an aggregate signature.
+pub fn verify_signature_inclusion<AccountId: Decode + PartialEq>(
+    signature_root: &[u8; 32],
+    signer: &AccountId,
+    proof: &MerkleInclusionProof,
+) -> Result<Vec<u8>, VerifyError> {
+    if proof.root != *signature_root || !proof.verify(signature_root) {
+        return Err(VerifyError::InvalidProof);
+    }
+    let (leaf_signer, signature) =
+        proof.decode_leaf::<(AccountId, Vec<u8>)>().ok_or(VerifyError::InvalidProof)?;
+    if leaf_signer != *signer {
+        return Err(VerifyError::SignerMismatch);
+    }
+    Ok(signature)
+}
+
+/// Verify an agent's reputation against a `ReputationRoot`, returning the committed reputation
+pub fn verify_reputation_inclusion<AccountId: Decode + PartialEq>(
+    reputation_root: &[u8; 32],
+    agent: &AccountId,
+    proof: &MerkleInclusionProof,
+) -> Result<u64, VerifyError> {
+    if proof.root != *reputation_root || !proof.verify(reputation_root) {
+        return Err(VerifyError::InvalidProof);
+    }
+    let (leaf_agent, reputation) =
+        proof.decode_leaf::<(AccountId, u64)>().ok_or(VerifyError::InvalidProof)?;
+    if leaf_agent != *agent {
+        return Err(VerifyError::SignerMismatch);
+    }
+    Ok(reputation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use csuite_primitives::combine_keys;
+    use sp_core::{ed25519, Pair};
+    use sp_trie::{MemoryDB, TrieDBMutBuilder, TrieMut};
+
+    fn group_pair() -> ed25519::Pair {
+        ed25519::Pair::from_seed(&[42u8; 32])
+    }
+
+    fn group() -> SigningGroup {
+        SigningGroup {
+            group_key: group_pair().public().0,
+            participants: vec![[1u8; 32], [2u8; 32], [4u8; 32]],
+            threshold: 2,
+        }
+    }
+
+    fn aggregate_of(group: &SigningGroup, message: &[u8], bitmap: u8) -> AggregateSignature {
+        let keys: Vec<[u8; 32]> = csuite_primitives::bitmap_indices(&[bitmap, 0])
+            .into_iter()
+            .map(|i| group.participants[i])
+            .collect();
+        AggregateSignature {
+            signature: group_pair().sign(message).0,
+            aggregate_pubkey: combine_keys(keys.iter()),
+            participant_bitmap: [bitmap, 0],
+            challenge: [0u8; 32],
+        }
+    }
+
+    #[test]
+    fn aggregate_certificates_verify() {
+        let group = group();
+        let aggregate = aggregate_of(&group, &[7u8; 32], 0b101);
+        assert_eq!(verify_aggregate(&[7u8; 32], &aggregate, &group), Ok(vec![[1u8; 32], [4u8; 32]]));
+
+        let certificate = QuorumCertificate {
+            log_id: [7u8; 32],
+            signers: vec![[1u8; 32], [4u8; 32]],
+            aggregate: Some(aggregate.clone()),
+        };
+        assert_eq!(verify_quorum_certificate(&certificate, &group), Ok(()));
+
+        // The signers must be exactly those the aggregate selects
+        let mismatch = QuorumCertificate { signers: vec![[1u8; 32], [2u8; 32]], ..certificate.clone() };
+        assert_eq!(verify_quorum_certificate(&mismatch, &group), Err(VerifyError::SignersMismatch));
+
+        // A signature over another log or by another key does not verify
+        let unverified = VerifyError::Aggregate(AggregateCheckError::InvalidSignature);
+        let other_log = QuorumCertificate { log_id: [8u8; 32], ..certificate.clone() };
+        assert_eq!(verify_quorum_certificate(&other_log, &group), Err(unverified));
+        let other_group = SigningGroup { group_key: [9u8; 32], ..group.clone() };
+        assert_eq!(verify_quorum_certificate(&certificate, &other_group), Err(unverified));
+
+        // Structural faults are reported as such
+        let too_few = QuorumCertificate {
+            signers: vec![[1u8; 32]],
+            aggregate: Some(aggregate_of(&group, &[7u8; 32], 0b001)),
+            ..certificate
+        };
+        assert_eq!(
+            verify_quorum_certificate(&too_few, &group),
+            Err(VerifyError::Aggregate(AggregateCheckError::BelowThreshold))
+        );
+    }
+
+    #[test]
+    fn individually_signed_certificates_verify() {
+        let group = group();
+        let certificate = QuorumCertificate {
+            log_id: [7u8; 32],
+            signers: vec![[2u8; 32], [4u8; 32]],
+            aggregate: None,
+        };
+        assert_eq!(verify_quorum_certificate(&certificate, &group), Ok(()));
+
+        let duplicate = QuorumCertificate { signers: vec![[2u8; 32], [2u8; 32]], ..certificate.clone() };
+        assert_eq!(verify_quorum_certificate(&duplicate, &group), Err(VerifyError::DuplicateSigner));
+        let outsider = QuorumCertificate { signers: vec![[2u8; 32], [3u8; 32]], ..certificate.clone() };
+        assert_eq!(verify_quorum_certificate(&outsider, &group), Err(VerifyError::UnknownSigner));
+        let too_few = QuorumCertificate { signers: vec![[2u8; 32]], ..certificate };
+        assert_eq!(verify_quorum_certificate(&too_few, &group), Err(VerifyError::BelowThreshold));
+    }
+
+    #[test]
+    fn record_inclusion_is_checked_against_the_state_root() {
+        let record = b"encoded record".to_vec();
+        let mut db = MemoryDB::<BlakeTwo256>::default();
+        let mut root = H256::default();
+        {
+            let mut trie = TrieDBMutBuilder::<LayoutV1<BlakeTwo256>>::new(&mut db, &mut root).build();
+            trie.insert(&record_storage_key(b"Recall", 3), &record).unwrap();
+            trie.insert(b"unrelated", b"value").unwrap();
+        }
+        let trie_nodes: Vec<Vec<u8>> = db.drain().into_values().map(|(node, _)| node).collect();
+        let state_root: [u8; 32] = root.into();
+
+        let proof = RecordInclusionProof { record_id: 3, trie_nodes: trie_nodes.clone() };
+        assert_eq!(verify_record_inclusion(&state_root, b"Recall", &proof), Ok(record));
+
+        let missing = RecordInclusionProof { record_id: 4, trie_nodes };
+        assert_eq!(
+            verify_record_inclusion(&state_root, b"Recall", &missing),
+            Err(VerifyError::RecordNotIncluded)
+        );
+        assert_eq!(
+            verify_record_inclusion(&[0u8; 32], b"Recall", &proof),
+            Err(VerifyError::InvalidProof)
+        );
+    }
+
+    #[test]
+    fn signature_inclusion_is_checked_against_the_signature_root() {
+        let leaves: Vec<Vec<u8>> = (1u64..=5)
+            .map(|signer| csuite_primitives::signature_leaf(&signer, &[signer as u8; 4]))
+            .collect();
+        let root = csuite_primitives::merkle_root(&leaves);
+        let proof = csuite_primitives::merkle_inclusion_proof(&leaves, 2).unwrap();
+
+        assert_eq!(verify_signature_inclusion(&root, &3u64, &proof), Ok(vec![3u8; 4]));
+        assert_eq!(verify_signature_inclusion(&root, &4u64, &proof), Err(VerifyError::SignerMismatch));
+        assert_eq!(verify_signature_inclusion(&[0u8; 32], &3u64, &proof), Err(VerifyError::InvalidProof));
+
+        let forged = MerkleInclusionProof { leaf: csuite_primitives::signature_leaf(&4u64, &[]), ..proof };
+        assert_eq!(verify_signature_inclusion(&root, &4u64, &forged), Err(VerifyError::InvalidProof));
+    }
+
+    #[test]
+    fn reputation_is_checked_against_the_reputation_root() {
+        let leaves: Vec<Vec<u8>> =
+            (1u64..=3).map(|agent| csuite_primitives::reputation_leaf(&agent, agent * 100)).collect();
+        let root = csuite_primitives::merkle_root(&leaves);
+        let proof = csuite_primitives::merkle_inclusion_proof(&leaves, 0).unwrap();
+
+        assert_eq!(verify_reputation_inclusion(&root, &1u64, &proof), Ok(100));
+        assert_eq!(verify_reputation_inclusion(&root, &2u64, &proof), Err(VerifyError::SignerMismatch));
+
+        let inflated = MerkleInclusionProof { leaf: csuite_primitives::reputation_leaf(&1u64, 900), ..proof };
+        assert_eq!(verify_reputation_inclusion(&root, &1u64, &inflated), Err(VerifyError::InvalidProof));
+    }
+}